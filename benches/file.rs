@@ -29,3 +29,25 @@ fn parse_file(b: &mut Bencher) {
     let tokens = TokenStream::from_str(&content).unwrap();
     b.iter(|| syn::parse2::<syn::File>(tokens.clone()));
 }
+
+#[bench]
+fn token_buffer(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let tokens = TokenStream::from_str(&content).unwrap();
+    b.iter(|| syn::buffer::TokenBuffer::new2(tokens.clone()));
+}
+
+// The same file several times over as the contents of separate modules, for
+// a stream of a few megabytes like those produced by code generators.
+#[bench]
+fn parse_large_stream(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let mut source = String::new();
+    for i in 0..20 {
+        source += &format!("mod m{} {{\n{}\n}}\n", i, content);
+    }
+    let tokens = TokenStream::from_str(&source).unwrap();
+    b.iter(|| syn::parse2::<syn::File>(tokens.clone()));
+}
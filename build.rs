@@ -1,11 +1,30 @@
 use std::env;
-use std::process::Command;
+use std::ffi::OsString;
+use std::fs;
+use std::iter;
+use std::path::Path;
+use std::process::{Command, Stdio};
 use std::str;
 
 // The rustc-cfg strings below are *not* public API. Please let us know by
 // opening a GitHub issue if your build environment requires some way to enable
 // these cfgs other than by executing our build script.
 fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    for var in &[
+        "RUSTC",
+        "RUSTC_WRAPPER",
+        "RUSTC_WORKSPACE_WRAPPER",
+        "RUSTFLAGS",
+        "CARGO_ENCODED_RUSTFLAGS",
+    ] {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    if env::var_os("CARGO_FEATURE_PROC_MACRO").is_some() && !proc_macro_available() {
+        println!("cargo:rustc-cfg=syn_no_proc_macro");
+    }
+
     let compiler = match rustc_version() {
         Some(compiler) => compiler,
         None => return,
@@ -23,6 +42,10 @@ fn main() {
         println!("cargo:rustc-cfg=syn_no_non_exhaustive");
     }
 
+    if compiler.minor < 45 {
+        println!("cargo:rustc-cfg=syn_no_hygiene");
+    }
+
     if compiler.minor < 56 {
         println!("cargo:rustc-cfg=syn_no_negative_literal_parse");
     }
@@ -49,3 +72,68 @@ fn rustc_version() -> Option<Compiler> {
     let nightly = version.contains("nightly") || version.ends_with("-dev");
     Some(Compiler { minor, nightly })
 }
+
+// Checks whether the `proc_macro` crate can be linked on the target by
+// compiling a tiny library that uses it. The probe only counts as having found
+// that `proc_macro` is missing if an equally tiny library without it compiles
+// in the same way; if neither compiles, something about the build environment
+// defeats the probe, and the result falls back to the list of targets known to
+// lack `proc_macro`, so that a crate enabling the "proc-macro" feature does not
+// lose `syn::parse` and friends on a target that has it.
+fn proc_macro_available() -> bool {
+    let control = "pub fn probe() {}\n";
+    let proc_macro = "extern crate proc_macro;\npub use proc_macro::TokenStream;\n";
+    match (probe("control", control), probe("proc_macro", proc_macro)) {
+        (_, Some(true)) => true,
+        (Some(true), Some(false)) => false,
+        _ => {
+            let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+            let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+            !(arch == "wasm32" && (os == "unknown" || os == "wasi"))
+        }
+    }
+}
+
+// Compiles `code` as a library for the target the way Cargo would invoke
+// rustc, returning whether it compiled, or `None` if rustc could not be run.
+fn probe(name: &str, code: &str) -> Option<bool> {
+    let rustc = env::var_os("RUSTC")?;
+    let out_dir = env::var_os("OUT_DIR")?;
+    let target = env::var_os("TARGET")?;
+
+    let crate_name = format!("syn_probe_{}", name);
+    let probe = Path::new(&out_dir).join(format!("{}.rs", crate_name));
+    fs::write(&probe, code).ok()?;
+
+    // Run through the same wrappers as Cargo's own rustc invocations, such as
+    // sccache, in case the bare compiler does not work without them.
+    let mut program = wrapper("RUSTC_WRAPPER")
+        .into_iter()
+        .chain(wrapper("RUSTC_WORKSPACE_WRAPPER"))
+        .chain(iter::once(rustc));
+    let mut cmd = Command::new(program.next().unwrap());
+    cmd.args(program)
+        .arg(format!("--crate-name={}", crate_name))
+        .arg("--crate-type=lib")
+        .arg("--emit=metadata")
+        .arg("--cap-lints=allow")
+        .arg("--target")
+        .arg(target)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(&probe)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Ok(rustflags) = env::var("CARGO_ENCODED_RUSTFLAGS") {
+        for flag in rustflags.split('\x1f').filter(|flag| !flag.is_empty()) {
+            cmd.arg(flag);
+        }
+    }
+
+    let status = cmd.status().ok()?;
+    Some(status.success())
+}
+
+fn wrapper(var: &str) -> Option<OsString> {
+    env::var_os(var).filter(|wrapper| !wrapper.is_empty())
+}
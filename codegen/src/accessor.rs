@@ -0,0 +1,283 @@
+use crate::{cfg, file, gen, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::BTreeSet;
+use syn_codegen::{Data, Definitions, Fields, Node, Type};
+
+const ACCESSOR_SRC: &str = "../src/gen/accessor.rs";
+
+fn rust_type(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Syn(t) | Type::Std(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            quote!(#ident)
+        }
+        Type::Ext(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            quote!(proc_macro2::#ident)
+        }
+        Type::Token(t) | Type::Group(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            quote!(token::#ident)
+        }
+        Type::Punctuated(p) => {
+            let element = rust_type(&p.element);
+            let punct = Ident::new(&p.punct, Span::call_site());
+            quote!(punctuated::Punctuated<#element, token::#punct>)
+        }
+        Type::Option(t) => {
+            let inner = rust_type(t);
+            quote!(Option<#inner>)
+        }
+        Type::Box(t) => {
+            let inner = rust_type(t);
+            quote!(Box<#inner>)
+        }
+        Type::Vec(t) => {
+            let inner = rust_type(t);
+            quote!(Vec<#inner>)
+        }
+        Type::Tuple(types) => {
+            let types = types.iter().map(rust_type);
+            quote!((#(#types),*))
+        }
+    }
+}
+
+// The type returned for a field, and the expression which borrows it from the
+// binding `name`. Boxes are looked through.
+fn borrow_field(ty: &Type, name: &Ident) -> (TokenStream, TokenStream) {
+    match ty {
+        Type::Box(inner) => {
+            let inner = rust_type(inner);
+            (quote!(&#inner), quote!(&**#name))
+        }
+        _ => {
+            let ty = rust_type(ty);
+            (quote!(&#ty), quote!(#name))
+        }
+    }
+}
+
+fn expand_enum_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    let variants = match &node.data {
+        Data::Enum(variants) => variants,
+        Data::Struct(_) | Data::Private => return TokenStream::new(),
+    };
+    if HANDWRITTEN_ENUMS.contains(&node.ident.as_str()) {
+        return TokenStream::new();
+    }
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+
+    let methods = variants.iter().map(|(variant_name, fields)| {
+        let variant = Ident::new(variant_name, Span::call_site());
+        let snake = gen::under_name(variant_name);
+        let is = format_ident!("is_{}", snake);
+        let is_doc = format!("Returns whether this is `{}::{}`.", node.ident, variant_name);
+
+        let mut cfg = None;
+        if node.ident == "Expr" {
+            if let Some(Type::Syn(ty)) = fields.first() {
+                if !lookup::node(defs, ty).features.any.contains("derive") {
+                    cfg = Some(quote! {
+                        #[cfg(feature = "full")]
+                        #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+                    });
+                }
+            }
+        }
+
+        let pattern = if fields.is_empty() {
+            quote!(#ident::#variant)
+        } else {
+            quote!(#ident::#variant(..))
+        };
+        let is_method = if is_handwritten(&node.ident, &is.to_string()) {
+            None
+        } else {
+            Some(quote! {
+                #cfg
+                #[doc = #is_doc]
+                pub fn #is(&self) -> bool {
+                    match self {
+                        #pattern => true,
+                        _ => false,
+                    }
+                }
+            })
+        };
+
+        if fields.is_empty() {
+            return quote!(#is_method);
+        }
+
+        let as_ = format_ident!("as_{}", snake);
+        let as_doc = format!(
+            "Returns the {} of `{}::{}`, or `None` if this is a different variant.",
+            if fields.len() == 1 { "field" } else { "fields" },
+            node.ident,
+            variant_name,
+        );
+        let bindings: Vec<Ident> = (0..fields.len()).map(|i| format_ident!("v{}", i)).collect();
+        let (types, borrows): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .zip(&bindings)
+            .map(|(ty, binding)| borrow_field(ty, binding))
+            .unzip();
+        let (ret, value) = if fields.len() == 1 {
+            (types[0].clone(), borrows[0].clone())
+        } else {
+            (quote!((#(#types),*)), quote!((#(#borrows),*)))
+        };
+
+        quote! {
+            #is_method
+
+            #cfg
+            #[doc = #as_doc]
+            pub fn #as_(&self) -> Option<#ret> {
+                match self {
+                    #ident::#variant(#(#bindings),*) => Some(#value),
+                    _ => None,
+                }
+            }
+        }
+    });
+
+    quote! {
+        #cfg_features
+        impl #ident {
+            #(#methods)*
+        }
+    }
+}
+
+// Methods which are written by hand, and so are not generated for the type.
+fn is_handwritten(type_name: &str, method: &str) -> bool {
+    HANDWRITTEN
+        .iter()
+        .any(|(ty, name)| *ty == type_name && *name == method)
+}
+
+const HANDWRITTEN: &[(&str, &str)] = &[
+    ("Visibility", "is_inherited"),
+    ("Visibility", "is_public"),
+];
+
+// Enums whose variants are told apart by methods written by hand, some of
+// which are private to the parser, and so get no generated accessors.
+const HANDWRITTEN_ENUMS: &[&str] = &["MacroDelimiter", "Member", "PathArguments"];
+
+// The structs held by the variants of the non-exhaustive enums, such as
+// `ExprCall` in `Expr::Call`. These are the nodes that get field accessors.
+fn variant_structs(defs: &Definitions) -> BTreeSet<&str> {
+    let mut structs = BTreeSet::new();
+    for node in &defs.types {
+        if let Data::Enum(variants) = &node.data {
+            if node.exhaustive {
+                continue;
+            }
+            for fields in variants.values() {
+                if let [Type::Syn(ty)] = fields.as_slice() {
+                    if let Data::Struct(_) = lookup::node(defs, ty).data {
+                        structs.insert(ty.as_str());
+                    }
+                }
+            }
+        }
+    }
+    structs
+}
+
+fn expand_struct_impl(node: &Node, fields: &Fields) -> TokenStream {
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+    let mut methods = TokenStream::new();
+
+    for (name, ty) in fields {
+        if let Type::Syn(t) = ty {
+            if t == "Reserved" {
+                continue;
+            }
+        }
+        let field = Ident::new(name, Span::call_site());
+        let cfg = cfg::field_features(node, name);
+        let getter = field.clone();
+        // A field named after a keyword, like `trait_`, has accessors named
+        // `trait_mut` and `set_trait`.
+        let base = name.trim_end_matches('_');
+        let getter_mut = format_ident!("{}_mut", base);
+        let setter = format_ident!("set_{}", base);
+        let (ty, store) = match ty {
+            Type::Box(inner) => (rust_type(inner), quote!(*self.#field = value)),
+            _ => (rust_type(ty), quote!(self.#field = value)),
+        };
+        if !is_handwritten(&node.ident, name) {
+            let doc = format!("Returns the `{}` field.", name);
+            methods.extend(quote! {
+                #cfg
+                #[doc = #doc]
+                pub fn #getter(&self) -> &#ty {
+                    &self.#field
+                }
+            });
+        }
+        let doc = format!("Returns a mutable reference to the `{}` field.", name);
+        methods.extend(quote! {
+            #cfg
+            #[doc = #doc]
+            pub fn #getter_mut(&mut self) -> &mut #ty {
+                &mut self.#field
+            }
+        });
+        if !is_handwritten(&node.ident, &setter.to_string()) {
+            let doc = format!("Replaces the `{}` field.", name);
+            methods.extend(quote! {
+                #cfg
+                #[doc = #doc]
+                pub fn #setter(&mut self, value: #ty) {
+                    #store;
+                }
+            });
+        }
+    }
+
+    quote! {
+        #cfg_features
+        impl #ident {
+            #methods
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let variant_structs = variant_structs(defs);
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        match &node.data {
+            Data::Enum(_) => impls.extend(expand_enum_impl(defs, node)),
+            Data::Struct(fields) => {
+                if variant_structs.contains(node.ident.as_str()) {
+                    impls.extend(expand_struct_impl(node, fields));
+                }
+            }
+            Data::Private => {}
+        }
+    }
+
+    file::write(
+        ACCESSOR_SRC,
+        quote! {
+            #![allow(clippy::match_like_matches_macro)]
+
+            use crate::*;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn_codegen::Features;
+use std::collections::BTreeSet;
+use syn_codegen::{Data, Features, Node};
 
 pub fn features(features: &Features) -> TokenStream {
     let features = &features.any;
@@ -10,3 +11,29 @@ pub fn features(features: &Features) -> TokenStream {
         _ => quote!(#[cfg(any(#(feature = #features),*))]),
     }
 }
+
+pub fn field_features(node: &Node, field: &str) -> TokenStream {
+    match node.field_features.get(field) {
+        Some(field_features) => features(field_features),
+        None => TokenStream::new(),
+    }
+}
+
+// A generated method of a struct whose fields are all cfg gated uses none of
+// its parameters when none of those features are enabled.
+pub fn allow_unused_if_gated(node: &Node) -> TokenStream {
+    let fields = match &node.data {
+        Data::Struct(fields) if !fields.is_empty() => fields,
+        _ => return TokenStream::new(),
+    };
+    let mut features = BTreeSet::new();
+    for f in fields.keys() {
+        match node.field_features.get(f) {
+            Some(field_features) => features.extend(field_features.any.iter()),
+            None => return TokenStream::new(),
+        }
+    }
+    quote! {
+        #[cfg_attr(not(any(#(feature = #features),*)), allow(unused_variables, unused_mut))]
+    }
+}
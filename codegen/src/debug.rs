@@ -63,7 +63,9 @@ fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
         Data::Struct(fields) => {
             let fields = fields.keys().map(|f| {
                 let ident = Ident::new(f, Span::call_site());
+                let cfg = cfg::field_features(node, f);
                 quote! {
+                    #cfg
                     formatter.field(#f, &self.#ident);
                 }
             });
@@ -81,22 +81,41 @@ fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
         }
         Data::Struct(fields) => {
             let mut comparisons = Vec::new();
+            let mut gated = TokenStream::new();
             for (f, ty) in fields {
                 if always_eq(ty) {
                     continue;
                 }
                 let ident = Ident::new(f, Span::call_site());
-                comparisons.push(match ty {
-                    Type::Ext(ty) if ty == "TokenStream" => {
-                        quote!(TokenStreamHelper(&self.#ident) == TokenStreamHelper(&other.#ident))
-                    }
-                    _ => quote!(self.#ident == other.#ident),
-                });
+                let (this, other) = match ty {
+                    Type::Ext(ty) if ty == "TokenStream" => (
+                        quote!(TokenStreamHelper(&self.#ident)),
+                        quote!(TokenStreamHelper(&other.#ident)),
+                    ),
+                    _ => (quote!(self.#ident), quote!(other.#ident)),
+                };
+                // A field that is not always present cannot be part of the
+                // chain of comparisons, so it is compared up front.
+                let cfg = cfg::field_features(node, f);
+                if cfg.is_empty() {
+                    comparisons.push(quote!(#this == #other));
+                } else {
+                    gated.extend(quote! {
+                        #cfg
+                        if #this != #other {
+                            return false;
+                        }
+                    });
+                }
             }
-            if comparisons.is_empty() {
+            let comparisons = if comparisons.is_empty() {
                 quote!(true)
             } else {
                 quote!(#(#comparisons)&&*)
+            };
+            quote! {
+                #gated
+                #comparisons
             }
         }
         Data::Private => unreachable!(),
@@ -123,6 +142,7 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
     }
 
     let body = expand_impl_body(defs, node);
+    let allow_unused = cfg::allow_unused_if_gated(node);
     let other = if body.to_string() == "true" {
         quote!(_other)
     } else {
@@ -135,6 +155,7 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
         #cfg_features
         #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
         impl PartialEq for #ident {
+            #allow_unused
             fn eq(&self, #other: &Self) -> bool {
                 #body
             }
@@ -151,7 +172,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
     file::write(
         DEBUG_SRC,
         quote! {
-            #[cfg(any(feature = "derive", feature = "full"))]
+            #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
             use crate::tt::TokenStreamHelper;
             use crate::*;
 
@@ -1,4 +1,4 @@
-use crate::{file, full, gen};
+use crate::{cfg, file, full, gen};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -173,6 +173,7 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
             for (field, ty) in fields {
                 let id = Ident::new(&field, Span::call_site());
                 let ref_toks = quote!(node.#id);
+                let cfg = cfg::field_features(s, field);
 
                 if let Type::Syn(ty) = ty {
                     if ty == "Reserved" {
@@ -186,6 +187,7 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
                 let fold = visit(&ty, &s.features, defs, &ref_toks).unwrap_or(ref_toks);
 
                 fold_fields.extend(quote! {
+                    #cfg
                     #id: #fold,
                 });
             }
@@ -260,9 +262,9 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             #![allow(unreachable_code, unused_variables)]
             #![allow(clippy::match_wildcard_for_single_variants)]
 
-            #[cfg(any(feature = "full", feature = "derive"))]
+            #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
             use crate::gen::helper::fold::*;
-            #[cfg(any(feature = "full", feature = "derive"))]
+            #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
             use crate::token::{Brace, Bracket, Group, Paren};
             use crate::*;
             use proc_macro2::Span;
@@ -10,7 +10,7 @@ pub fn get_macro() -> TokenStream {
             };
         }
 
-        #[cfg(all(feature = "derive", not(feature = "full")))]
+        #[cfg(all(any(feature = "derive", feature = "parse-only"), not(feature = "full")))]
         macro_rules! full {
             ($e:expr) => {
                 unreachable!()
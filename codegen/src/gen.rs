@@ -1,6 +1,7 @@
 use crate::cfg;
 use inflections::Inflect;
 use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::BTreeMap;
 use syn_codegen::{Data, Definitions, Features, Node};
 
 pub const TERMINAL_TYPES: &[&str] = &["Span", "Ident"];
@@ -19,6 +20,7 @@ pub fn traverse(
             ident: terminal.to_string(),
             features: Features::default(),
             data: Data::Private,
+            field_features: BTreeMap::new(),
             exhaustive: true,
         });
     }
@@ -109,7 +109,9 @@ fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
                         val = quote!(TokenStreamHelper(&#val));
                     }
                 }
+                let cfg = cfg::field_features(node, f);
                 Some(quote! {
+                    #cfg
                     #val.hash(state);
                 })
             })
@@ -131,6 +133,7 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
     let cfg_features = cfg::features(&node.features);
 
     let body = expand_impl_body(defs, node);
+    let allow_unused = cfg::allow_unused_if_gated(node);
     let state = if body.is_empty() {
         quote!(_state)
     } else {
@@ -141,6 +144,7 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
         #cfg_features
         #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
         impl Hash for #ident {
+            #allow_unused
             fn hash<H>(&self, #state: &mut H)
             where
                 H: Hasher,
@@ -160,7 +164,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
     file::write(
         DEBUG_SRC,
         quote! {
-            #[cfg(any(feature = "derive", feature = "full"))]
+            #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
             use crate::tt::TokenStreamHelper;
             use crate::*;
             use std::hash::{Hash, Hasher};
@@ -0,0 +1,161 @@
+use crate::{cfg, file, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+
+const KIND_SRC: &str = "../src/gen/kind.rs";
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    let variants = match &node.data {
+        Data::Enum(variants) => variants,
+        Data::Struct(_) | Data::Private => return TokenStream::new(),
+    };
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let kind = format_ident!("{}Kind", node.ident);
+    let cfg_features = cfg::features(&node.features);
+    let doc_cfg = if node.features.any.is_empty() {
+        None
+    } else {
+        let features = &node.features.any;
+        Some(quote! {
+            #[cfg_attr(doc_cfg, doc(cfg(any(#(feature = #features),*))))]
+        })
+    };
+
+    let enum_doc = format!(
+        "Fieldless counterpart of [`{}`], naming one of its variants.",
+        node.ident,
+    );
+    let method_doc = format!(
+        "Returns the variant of this `{}` as a fieldless [`{}`].",
+        node.ident, kind,
+    );
+    let non_exhaustive = if node.exhaustive {
+        None
+    } else {
+        Some(quote!(#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]))
+    };
+
+    let names: Vec<Ident> = variants
+        .keys()
+        .map(|variant| Ident::new(variant, Span::call_site()))
+        .collect();
+    let strings = variants.keys();
+
+    let arms = variants.iter().map(|(variant_name, fields)| {
+        let variant = Ident::new(variant_name, Span::call_site());
+        if fields.is_empty() {
+            quote! {
+                #ident::#variant => #kind::#variant,
+            }
+        } else {
+            let mut cfg = None;
+            if node.ident == "Expr" {
+                if let Type::Syn(ty) = &fields[0] {
+                    if !lookup::node(defs, ty).features.any.contains("derive") {
+                        cfg = Some(quote!(#[cfg(feature = "full")]));
+                    }
+                }
+            }
+            quote! {
+                #cfg
+                #ident::#variant(..) => #kind::#variant,
+            }
+        }
+    });
+    let nonexhaustive_arm = if node.exhaustive {
+        None
+    } else if node.ident == "Expr" {
+        Some(quote! {
+            #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+            _ => unreachable!(),
+        })
+    } else {
+        Some(quote! {
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        })
+    };
+
+    quote! {
+        #[doc = #enum_doc]
+        #cfg_features
+        #doc_cfg
+        #non_exhaustive
+        pub enum #kind {
+            #(#names,)*
+        }
+
+        #cfg_features
+        impl #ident {
+            #[doc = #method_doc]
+            pub fn kind(&self) -> #kind {
+                match self {
+                    #(#arms)*
+                    #nonexhaustive_arm
+                }
+            }
+        }
+
+        #cfg_features
+        impl Copy for #kind {}
+
+        #cfg_features
+        impl Clone for #kind {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        #cfg_features
+        impl Eq for #kind {}
+
+        #cfg_features
+        impl PartialEq for #kind {
+            fn eq(&self, other: &Self) -> bool {
+                mem::discriminant(self) == mem::discriminant(other)
+            }
+        }
+
+        #cfg_features
+        impl Hash for #kind {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                mem::discriminant(self).hash(state);
+            }
+        }
+
+        #cfg_features
+        impl Debug for #kind {
+            fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(match self {
+                    #(#kind::#names => #strings,)*
+                })
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        impls.extend(expand_impl(defs, node));
+    }
+
+    file::write(
+        KIND_SRC,
+        quote! {
+            #![allow(clippy::expl_impl_clone_on_copy)]
+
+            use crate::*;
+            use std::fmt::{self, Debug};
+            use std::hash::{Hash, Hasher};
+            use std::mem;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
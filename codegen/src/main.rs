@@ -7,10 +7,15 @@
 // and making use of syn.json from Rust code.
 //
 // Finally this crate generates the Visit, VisitMut, and Fold traits in Syn
-// programmatically from the syntax tree description.
+// programmatically from the syntax tree description, along with the Pass trait
+// for fusing VisitMut passes, a fieldless kind enum for each syntax tree enum,
+// variant accessors for the enums and field accessors for the structs held by
+// the non-exhaustive enums, the impls for respanning and for stably hashing a
+// syntax tree, and the Serde impls.
 
 #![allow(clippy::needless_pass_by_value)]
 
+mod accessor;
 mod cfg;
 mod clone;
 mod debug;
@@ -21,10 +26,15 @@ mod full;
 mod gen;
 mod hash;
 mod json;
+mod kind;
 mod lookup;
 mod operand;
 mod parse;
+mod pass;
+mod respan;
+mod serde_impl;
 mod snapshot;
+mod stable_hash;
 mod version;
 mod visit;
 mod visit_mut;
@@ -32,14 +42,20 @@ mod visit_mut;
 fn main() -> anyhow::Result<()> {
     color_backtrace::install();
     let defs = parse::parse()?;
+    accessor::generate(&defs)?;
     clone::generate(&defs)?;
     debug::generate(&defs)?;
     eq::generate(&defs)?;
     hash::generate(&defs)?;
+    stable_hash::generate(&defs)?;
     json::generate(&defs)?;
+    kind::generate(&defs)?;
     fold::generate(&defs)?;
     visit::generate(&defs)?;
     visit_mut::generate(&defs)?;
+    pass::generate(&defs)?;
+    respan::generate(&defs)?;
+    serde_impl::generate(&defs)?;
     snapshot::generate(&defs)?;
     Ok(())
 }
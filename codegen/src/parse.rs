@@ -62,6 +62,7 @@ fn introspect_item(item: &AstItem, items: &ItemLookup, tokens: &TokenLookup) ->
             ident: item.ast.ident.to_string(),
             features,
             data: types::Data::Enum(introspect_enum(data, items, tokens)),
+            field_features: BTreeMap::new(),
             exhaustive: !data.variants.iter().any(|v| is_doc_hidden(&v.attrs)),
         },
         Data::Struct(ref data) => types::Node {
@@ -74,6 +75,7 @@ fn introspect_item(item: &AstItem, items: &ItemLookup, tokens: &TokenLookup) ->
                     types::Data::Private
                 }
             },
+            field_features: introspect_field_features(data),
             exhaustive: true,
         },
         Data::Union(..) => panic!("Union not supported"),
@@ -118,6 +120,19 @@ fn introspect_struct(item: &DataStruct, items: &ItemLookup, tokens: &TokenLookup
     }
 }
 
+fn introspect_field_features(item: &DataStruct) -> BTreeMap<String, types::Features> {
+    item.fields
+        .iter()
+        .filter_map(|field| {
+            let features = introspect_features(&field.attrs);
+            if features.any.is_empty() {
+                return None;
+            }
+            Some((field.ident.as_ref().unwrap().to_string(), features))
+        })
+        .collect()
+}
+
 fn introspect_type(item: &syn::Type, items: &ItemLookup, tokens: &TokenLookup) -> types::Type {
     match item {
         syn::Type::Path(TypePath {
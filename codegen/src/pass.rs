@@ -0,0 +1,97 @@
+use crate::{cfg, file, gen};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Definitions, Node};
+
+const PASS_SRC: &str = "../src/gen/pass.rs";
+
+fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, _defs: &Definitions) {
+    let under_name = gen::under_name(&s.ident);
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let enter_fn = format_ident!("enter_{}", under_name);
+    let exit_fn = format_ident!("exit_{}", under_name);
+    let visit_mut_fn = format_ident!("visit_{}_mut", under_name);
+    let features = cfg::features(&s.features);
+
+    // The features of the node have already been emitted in front of the
+    // first method, but not the second.
+    traits.extend(quote! {
+        fn #enter_fn(&mut self, i: &mut #ty) {}
+        #features
+        fn #exit_fn(&mut self, i: &mut #ty) {}
+    });
+
+    impls.extend(quote! {
+        fn #visit_mut_fn(&mut self, node: &mut #ty) {
+            for pass in &mut self.passes {
+                pass.#enter_fn(node);
+            }
+            visit_mut::#visit_mut_fn(self, node);
+            for pass in &mut self.passes {
+                pass.#exit_fn(node);
+            }
+        }
+    });
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let (traits, impls) = gen::traverse(defs, node);
+    file::write(
+        PASS_SRC,
+        quote! {
+            #![allow(unused_variables)]
+
+            use crate::visit_mut::{self, VisitMut};
+            use crate::*;
+            use proc_macro2::Span;
+
+            /// A pass of rewrites local to each node, which can be fused with
+            /// other passes into a single traversal by [`Passes`].
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This trait is available only if Syn is built with the `"visit-mut"` feature.*
+            pub trait Pass {
+                #traits
+            }
+
+            /// Passes fused into a single traversal of a syntax tree.
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This type is available only if Syn is built with the `"visit-mut"` feature.*
+            pub struct Passes<'a> {
+                passes: Vec<&'a mut dyn Pass>,
+            }
+
+            impl<'a> Passes<'a> {
+                /// Creates an empty set of passes.
+                pub fn new() -> Self {
+                    Passes { passes: Vec::new() }
+                }
+
+                /// Adds a pass, to run after the passes added before it.
+                pub fn with(mut self, pass: &'a mut dyn Pass) -> Self {
+                    self.passes.push(pass);
+                    self
+                }
+            }
+
+            impl<'a> Default for Passes<'a> {
+                fn default() -> Self {
+                    Passes::new()
+                }
+            }
+
+            impl<'a> VisitMut for Passes<'a> {
+                #impls
+            }
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use crate::{cfg, file, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+
+const RESPAN_SRC: &str = "../src/gen/respan.rs";
+
+// Fields of primitive types such as the `u32` of `Index` have no span.
+fn has_spans(ty: &Type) -> bool {
+    match ty {
+        Type::Std(_) => false,
+        Type::Syn(node) => node != "Reserved",
+        Type::Option(ty) | Type::Box(ty) | Type::Vec(ty) => has_spans(ty),
+        _ => true,
+    }
+}
+
+fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let arms = variants.iter().map(|(variant_name, fields)| {
+                let variant = Ident::new(variant_name, Span::call_site());
+                if fields.is_empty() {
+                    quote! {
+                        #ident::#variant => {}
+                    }
+                } else {
+                    let mut pats = Vec::new();
+                    let mut set_spans = Vec::new();
+                    for (i, ty) in fields.iter().enumerate() {
+                        if has_spans(ty) {
+                            let pat = format_ident!("v{}", i);
+                            set_spans.push(quote!(#pat.set_spans(span);));
+                            pats.push(quote!(#pat));
+                        } else {
+                            pats.push(quote!(_));
+                        }
+                    }
+                    let mut cfg = None;
+                    if node.ident == "Expr" {
+                        if let Type::Syn(ty) = &fields[0] {
+                            if !lookup::node(defs, ty).features.any.contains("derive") {
+                                cfg = Some(quote!(#[cfg(feature = "full")]));
+                            }
+                        }
+                    }
+                    quote! {
+                        #cfg
+                        #ident::#variant(#(#pats),*) => {
+                            #(#set_spans)*
+                        }
+                    }
+                }
+            });
+            let nonexhaustive = if node.exhaustive {
+                None
+            } else if node.ident == "Expr" {
+                Some(quote! {
+                    #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+                    _ => unreachable!(),
+                })
+            } else {
+                Some(quote! {
+                    #[cfg(syn_no_non_exhaustive)]
+                    _ => unreachable!(),
+                })
+            };
+            quote! {
+                match self {
+                    #(#arms)*
+                    #nonexhaustive
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .filter(|(_f, ty)| has_spans(ty))
+                .map(|(f, _ty)| {
+                    let ident = Ident::new(f, Span::call_site());
+                    let cfg = cfg::field_features(node, f);
+                    quote! {
+                        #cfg
+                        self.#ident.set_spans(span);
+                    }
+                });
+            quote!(#(#fields)*)
+        }
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    if node.data == Data::Private {
+        return TokenStream::new();
+    }
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+    let body = expand_impl_body(defs, node);
+
+    quote! {
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+        impl ToTokensSpanned for #ident {
+            fn set_spans(&mut self, span: Span) {
+                #body
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        impls.extend(expand_impl(defs, node));
+    }
+
+    file::write(
+        RESPAN_SRC,
+        quote! {
+            #![allow(unused_variables)]
+
+            use crate::span::ToTokensSpanned;
+            use crate::*;
+            use proc_macro2::Span;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,668 @@
+use crate::{cfg, file, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Features, Fields, Node, Type};
+
+const SERDE_SRC: &str = "../src/gen/serde.rs";
+
+// How a field of a struct is represented.
+enum Repr {
+    // Omitted, and filled in with a default value when deserializing.
+    Omitted(TokenStream),
+    // An optional token, represented as a bool.
+    Flag,
+    // An `Option` of some other type, which may be missing when deserializing.
+    Optional,
+    // The elements, followed by a bool field for whether there is trailing
+    // punctuation.
+    Punctuated,
+    Value,
+}
+
+fn repr(ty: &Type) -> Repr {
+    match ty {
+        Type::Token(_) | Type::Group(_) => Repr::Omitted(quote!(Default::default())),
+        Type::Ext(ty) if ty == "Span" => Repr::Omitted(quote!(Span::call_site())),
+        Type::Syn(ty) if ty == "Reserved" => Repr::Omitted(quote!(Default::default())),
+        Type::Option(inner) => match **inner {
+            Type::Token(_) => Repr::Flag,
+            _ => Repr::Optional,
+        },
+        Type::Punctuated(_) => Repr::Punctuated,
+        _ => Repr::Value,
+    }
+}
+
+fn rust_type(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Syn(ty) | Type::Std(ty) => {
+            let ident = Ident::new(ty, Span::call_site());
+            quote!(#ident)
+        }
+        Type::Ext(ty) => {
+            let ident = Ident::new(ty, Span::call_site());
+            quote!(proc_macro2::#ident)
+        }
+        Type::Token(ty) | Type::Group(ty) => {
+            let ident = Ident::new(ty, Span::call_site());
+            quote!(token::#ident)
+        }
+        Type::Punctuated(p) => {
+            let element = rust_type(&p.element);
+            let punct = Ident::new(&p.punct, Span::call_site());
+            quote!(Punctuated<#element, token::#punct>)
+        }
+        Type::Option(inner) => {
+            let inner = rust_type(inner);
+            quote!(Option<#inner>)
+        }
+        Type::Box(inner) => {
+            let inner = rust_type(inner);
+            quote!(Box<#inner>)
+        }
+        Type::Vec(inner) => {
+            let inner = rust_type(inner);
+            quote!(Vec<#inner>)
+        }
+        Type::Tuple(inner) => {
+            let inner = inner.iter().map(rust_type);
+            quote!((#(#inner),*))
+        }
+    }
+}
+
+fn expr_variant_cfg(defs: &Definitions, node: &Node, fields: &[Type]) -> Option<TokenStream> {
+    if node.ident == "Expr" {
+        if let Type::Syn(ty) = &fields[0] {
+            if !lookup::node(defs, ty).features.any.contains("derive") {
+                return Some(quote!(#[cfg(feature = "full")]));
+            }
+        }
+    }
+    None
+}
+
+// The fields of a struct in the order in which they are serialized, with the
+// cfg of the fields that are not always present. Those fields go last, so
+// that the index of every other field is the same with or without them.
+fn serialized_fields<'a>(
+    node: &'a Node,
+    fields: &'a Fields,
+) -> Vec<(&'a str, &'a Type, TokenStream)> {
+    let (always, gated): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|(f, _ty)| !node.field_features.contains_key(*f));
+    always
+        .into_iter()
+        .chain(gated)
+        .map(|(f, ty)| (f.as_str(), ty, cfg::field_features(node, f)))
+        .collect()
+}
+
+// The features of the fields that are not always present. As the list of
+// field names has to be declared once for each combination of features, all
+// such fields of a struct must be gated by the same features.
+fn gated_features(node: &Node) -> Option<&Features> {
+    let mut features = node.field_features.values();
+    let first = features.next()?;
+    assert!(features.all(|f| f == first), "{}", node.ident);
+    Some(first)
+}
+
+fn cfg_predicate(features: &Features) -> TokenStream {
+    let features = &features.any;
+    if features.len() == 1 {
+        quote!(#(feature = #features)*)
+    } else {
+        quote!(any(#(feature = #features),*))
+    }
+}
+
+fn is_token(ty: &Type) -> bool {
+    match ty {
+        Type::Token(_) => true,
+        _ => false,
+    }
+}
+
+fn expand_serialize(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let arms = variants.iter().enumerate().map(|(index, (variant_name, fields))| {
+                let variant = Ident::new(variant_name, Span::call_site());
+                let index = index as u32;
+                let cfg = if fields.is_empty() {
+                    None
+                } else {
+                    expr_variant_cfg(defs, node, fields)
+                };
+                if fields.is_empty() {
+                    quote! {
+                        #ident::#variant => {
+                            serializer.serialize_unit_variant(#type_name, #index, #variant_name)
+                        }
+                    }
+                } else if fields.len() == 1 && is_token(&fields[0]) {
+                    quote! {
+                        #ident::#variant(_) => {
+                            serializer.serialize_unit_variant(#type_name, #index, #variant_name)
+                        }
+                    }
+                } else if fields.len() == 1 {
+                    quote! {
+                        #cfg
+                        #ident::#variant(v0) => {
+                            serializer.serialize_newtype_variant(#type_name, #index, #variant_name, &Wrap(v0))
+                        }
+                    }
+                } else {
+                    let pats = (0..fields.len())
+                        .map(|i| format_ident!("v{}", i))
+                        .collect::<Vec<_>>();
+                    let len = fields.len();
+                    quote! {
+                        #cfg
+                        #ident::#variant(#(#pats),*) => {
+                            let mut state = serializer.serialize_tuple_variant(#type_name, #index, #variant_name, #len)?;
+                            #(state.serialize_field(&Wrap(#pats))?;)*
+                            state.end()
+                        }
+                    }
+                }
+                    });
+            let nonexhaustive = if node.exhaustive {
+                None
+            } else if node.ident == "Expr" {
+                Some(quote! {
+                    #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+                    _ => unreachable!(),
+                })
+            } else {
+                Some(quote! {
+                    #[cfg(syn_no_non_exhaustive)]
+                    _ => unreachable!(),
+                })
+            };
+            quote! {
+                match self {
+                    #(#arms)*
+                    #nonexhaustive
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let mut len = 0usize;
+            let mut gated_len = 0usize;
+            let mut serialize_fields = Vec::new();
+            for (f, ty, cfg) in serialized_fields(node, fields) {
+                let field = Ident::new(f, Span::call_site());
+                let count = if cfg.is_empty() {
+                    &mut len
+                } else {
+                    &mut gated_len
+                };
+                match repr(ty) {
+                    Repr::Omitted(_) => continue,
+                    Repr::Flag => serialize_fields.push(quote! {
+                        #cfg
+                        state.serialize_field(#f, &self.#field.is_some())?;
+                    }),
+                    Repr::Punctuated => {
+                        let trailing = format!("{}_trailing", f);
+                        serialize_fields.push(quote! {
+                            #cfg
+                            state.serialize_field(#f, &Wrap(&self.#field))?;
+                            #cfg
+                            state.serialize_field(#trailing, &self.#field.trailing_punct())?;
+                    });
+                        *count += 1;
+                    }
+                    Repr::Optional | Repr::Value => serialize_fields.push(quote! {
+                        #cfg
+                        state.serialize_field(#f, &Wrap(&self.#field))?;
+                    }),
+                }
+                *count += 1;
+            }
+            let state = if len == 0 && gated_len == 0 {
+                quote!(state)
+            } else {
+                quote!(mut state)
+            };
+            let len = match gated_features(node) {
+                Some(features) if gated_len > 0 => {
+                    let predicate = cfg_predicate(features);
+                    quote!(#len + if cfg!(#predicate) { #gated_len } else { 0 })
+                }
+                _ => quote!(#len),
+            };
+            quote! {
+                let #state = serializer.serialize_struct(#type_name, #len)?;
+                #(#serialize_fields)*
+                state.end()
+            }
+        }
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_deserialize(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+    let expecting = match &node.data {
+        Data::Enum(_) => format!("enum {}", type_name),
+        _ => format!("struct {}", type_name),
+    };
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let names = variants.keys();
+            let arms = variants.iter().enumerate().map(|(index, (variant_name, fields))| {
+                let variant = Ident::new(variant_name, Span::call_site());
+                let cfg = if fields.is_empty() {
+                    None
+                } else {
+                    expr_variant_cfg(defs, node, fields)
+                };
+                if fields.is_empty() {
+                    quote! {
+                        Some(#index) => {
+                            variant.unit_variant()?;
+                            Ok(#ident::#variant)
+                        }
+                    }
+                } else if fields.len() == 1 && is_token(&fields[0]) {
+                    quote! {
+                        Some(#index) => {
+                            variant.unit_variant()?;
+                            Ok(#ident::#variant(Default::default()))
+                        }
+                    }
+                } else if fields.len() == 1 {
+                    let ty = rust_type(&fields[0]);
+                    quote! {
+                        #cfg
+                        Some(#index) => {
+                            let v0 = variant.newtype_variant::<Unwrap<#ty>>()?.0;
+                            Ok(#ident::#variant(v0))
+                        }
+                    }
+                } else {
+                    let pats = (0..fields.len())
+                        .map(|i| format_ident!("v{}", i))
+                        .collect::<Vec<_>>();
+                    let types = fields.iter().map(rust_type);
+                    let len = fields.len();
+                    quote! {
+                        #cfg
+                        Some(#index) => {
+                            let (#(#pats),*) = variant.tuple_variant(#len, TupleVisitor::<(#(#types),*)>::new())?;
+                            Ok(#ident::#variant(#(#pats),*))
+                        }
+                    }
+                }
+                    });
+            quote! {
+                const VARIANTS: &[&str] = &[#(#names),*];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: EnumAccess<'de>,
+                    {
+                        let (key, variant) = data.variant::<Key>()?;
+                        match key.index(VARIANTS) {
+                            #(#arms)*
+                            _ => Err(key.unknown_variant(VARIANTS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_enum(#type_name, VARIANTS, Visitor)
+            }
+        }
+        Data::Struct(fields) => {
+            let mut names = Vec::new();
+            let mut slots = Vec::new();
+            let mut map_arms = Vec::new();
+            let mut from_map = Vec::new();
+            let mut from_seq = Vec::new();
+            let mut always_len = None;
+            for (f, ty, cfg) in serialized_fields(node, fields) {
+                let field = Ident::new(f, Span::call_site());
+                let index = names.len();
+                if !cfg.is_empty() && always_len.is_none() {
+                    always_len = Some(index);
+                }
+                match repr(ty) {
+                    Repr::Omitted(default) => {
+                        from_map.push(quote!(#cfg #field: #default,));
+                        from_seq.push(quote!(#cfg #field: #default,));
+                        continue;
+                    }
+                    Repr::Flag => {
+                        slots.push(quote!(#cfg let mut #field = None;));
+                        map_arms.push(quote! {
+                            #cfg
+                            Some(#index) => next_value::<_, bool>(&mut map, &mut #field, #f)?,
+                        });
+                        from_map.push(quote!(#cfg #field: flag(#field.unwrap_or(false)),));
+                        from_seq.push(quote! {
+                            #cfg
+                            #field: flag(next_element::<_, bool>(&mut seq, #index, &self)?),
+                        });
+                    }
+                    Repr::Optional => {
+                        let ty = rust_type(ty);
+                        slots.push(quote!(#cfg let mut #field = None;));
+                        map_arms.push(quote! {
+                            #cfg
+                            Some(#index) => next_value::<_, #ty>(&mut map, &mut #field, #f)?,
+                        });
+                        from_map.push(quote!(#cfg #field: #field.unwrap_or(None),));
+                        from_seq.push(quote! {
+                            #cfg
+                            #field: next_element(&mut seq, #index, &self)?,
+                        });
+                    }
+                    Repr::Punctuated => {
+                        let ty = rust_type(ty);
+                        let trailing = format!("{}_trailing", f);
+                        let trailing_slot = format_ident!("{}_trailing", f);
+                        let trailing_index = index + 1;
+                        slots.push(quote! {
+                            #cfg
+                            let mut #field = None;
+                            #cfg
+                            let mut #trailing_slot = None;
+                        });
+                        map_arms.push(quote! {
+                            #cfg
+                            Some(#index) => next_value::<_, #ty>(&mut map, &mut #field, #f)?,
+                            #cfg
+                            Some(#trailing_index) => {
+                                next_value::<_, bool>(&mut map, &mut #trailing_slot, #trailing)?
+                            }
+                        });
+                        from_map.push(quote! {
+                            #cfg
+                            #field: trailing(
+                                required::<_, A::Error>(#field, #f)?,
+                                #trailing_slot.unwrap_or(false),
+                            ),
+                        });
+                        from_seq.push(quote! {
+                            #cfg
+                            #field: trailing(
+                                next_element(&mut seq, #index, &self)?,
+                                next_element(&mut seq, #trailing_index, &self)?,
+                            ),
+                        });
+                        names.push(f.to_owned());
+                        names.push(trailing);
+                        continue;
+                    }
+                    Repr::Value => {
+                        let ty = rust_type(ty);
+                        slots.push(quote!(#cfg let mut #field = None;));
+                        map_arms.push(quote! {
+                            #cfg
+                            Some(#index) => next_value::<_, #ty>(&mut map, &mut #field, #f)?,
+                        });
+                        from_map.push(quote!(#cfg #field: required::<_, A::Error>(#field, #f)?,));
+                        from_seq.push(quote! {
+                            #cfg
+                            #field: next_element(&mut seq, #index, &self)?,
+                        });
+                    }
+                }
+                names.push(f.to_owned());
+            }
+            let always_len = always_len.unwrap_or(names.len());
+            let fields_const = match gated_features(node) {
+                Some(features) if always_len < names.len() => {
+                    let predicate = cfg_predicate(features);
+                    let always = &names[..always_len];
+                    quote! {
+                        #[cfg(#predicate)]
+                        const FIELDS: &[&str] = &[#(#names),*];
+                        #[cfg(not(#predicate))]
+                        const FIELDS: &[&str] = &[#(#always),*];
+                    }
+                }
+                _ => quote!(const FIELDS: &[&str] = &[#(#names),*];),
+            };
+            let allow_unused = cfg::allow_unused_if_gated(node);
+            let seq = if names.is_empty() {
+                quote!(_seq)
+            } else {
+                quote!(mut seq)
+            };
+            quote! {
+                #fields_const
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    #allow_unused
+                    fn visit_seq<A>(self, #seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        Ok(#ident {
+                            #(#from_seq)*
+                        })
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        #(#slots)*
+                        while let Some(field) = map.next_key::<Key>()? {
+                            match field.index(FIELDS) {
+                                #(#map_arms)*
+                                _ => {
+                                    map.next_value::<IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        Ok(#ident {
+                            #(#from_map)*
+                        })
+                    }
+                }
+
+                deserializer.deserialize_struct(#type_name, FIELDS, Visitor)
+            }
+        }
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    let manual_serde = node.data == Data::Private || node.ident == "Lifetime";
+    if manual_serde {
+        return TokenStream::new();
+    }
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+    let serialize = expand_serialize(defs, node);
+    let deserialize = expand_deserialize(defs, node);
+    let allow_unused = cfg::allow_unused_if_gated(node);
+
+    quote! {
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl Serialize for #ident {
+            #allow_unused
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                #serialize
+            }
+        }
+
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                #deserialize
+            }
+        }
+
+        #cfg_features
+        impl Ser for #ident {
+            fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.serialize(serializer)
+            }
+        }
+
+        #cfg_features
+        impl<'de> De<'de> for #ident {
+            fn de<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Self::deserialize(deserializer)
+            }
+        }
+    }
+}
+
+fn uses_token(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Token(ty) => ty == name,
+        Type::Punctuated(p) => p.punct == name || uses_token(&p.element, name),
+        Type::Option(ty) | Type::Box(ty) | Type::Vec(ty) => uses_token(ty, name),
+        Type::Tuple(types) => types.iter().any(|ty| uses_token(ty, name)),
+        Type::Syn(_) | Type::Std(_) | Type::Ext(_) | Type::Group(_) => false,
+    }
+}
+
+// The features of the types and fields in which a token appears, if these are
+// the only features under which the token is defined. This is the case for
+// the keywords of the clauses parsed by the "verus" feature.
+fn token_features(defs: &Definitions, name: &str) -> Features {
+    let mut features = Features::default();
+    for node in &defs.types {
+        match &node.data {
+            Data::Struct(fields) => {
+                for (f, ty) in fields {
+                    if uses_token(ty, name) {
+                        let field_features = node.field_features.get(f);
+                        let any = &field_features.unwrap_or(&node.features).any;
+                        features.any.extend(any.iter().cloned());
+                    }
+                }
+            }
+            Data::Enum(variants) => {
+                if variants.values().flatten().any(|ty| uses_token(ty, name)) {
+                    features.any.extend(node.features.any.iter().cloned());
+                }
+            }
+            Data::Private => {}
+        }
+    }
+    let always = ["derive", "full", "parse-only"];
+    if always.iter().any(|f| features.any.contains(*f)) {
+        features.any.clear();
+    }
+    features
+}
+
+fn expand_token_impl(name: &str, repr: &str, features: &Features) -> TokenStream {
+    let ident = Ident::new(name, Span::call_site());
+    let cfg_features = cfg::features(features);
+    quote! {
+        #cfg_features
+        impl Ser for token::#ident {
+            fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(#repr)
+            }
+        }
+
+        #cfg_features
+        impl<'de> De<'de> for token::#ident {
+            fn de<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                IgnoredAny::deserialize(deserializer)?;
+                Ok(Self::default())
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        impls.extend(expand_impl(defs, node));
+    }
+    for (name, repr) in &defs.tokens {
+        let features = token_features(defs, name);
+        impls.extend(expand_token_impl(name, repr, &features));
+    }
+    for (name, repr) in &[
+        ("Brace", "{}"),
+        ("Bracket", "[]"),
+        ("Paren", "()"),
+        ("Group", ""),
+    ] {
+        impls.extend(expand_token_impl(name, repr, &Features::default()));
+    }
+
+    file::write(
+        SERDE_SRC,
+        quote! {
+            #![allow(clippy::match_single_binding, clippy::too_many_lines)]
+
+            #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+            use crate::punctuated::Punctuated;
+            use crate::serde_helper::*;
+            use crate::*;
+            use ::serde::de::{self, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess};
+            use ::serde::ser::SerializeStruct;
+            #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+            use ::serde::ser::SerializeTupleVariant;
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+            use proc_macro2::Span;
+            use std::fmt;
+            use std::result::Result;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
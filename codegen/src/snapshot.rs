@@ -1,4 +1,4 @@
-use crate::{file, lookup};
+use crate::{cfg, file, lookup};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -206,6 +206,7 @@ fn expand_impl_body(defs: &Definitions, node: &Node, name: &str) -> TokenStream
         Data::Struct(fields) => {
             let fields = fields.iter().filter_map(|(f, ty)| {
                 let ident = Ident::new(f, Span::call_site());
+                let cfg = cfg::field_features(node, f);
                 if let Type::Option(ty) = ty {
                     let inner = quote!(_val);
                     let format = format_field(&inner, ty).map(|format| {
@@ -218,6 +219,7 @@ fn expand_impl_body(defs: &Definitions, node: &Node, name: &str) -> TokenStream
                     });
                     let ty = rust_type(ty);
                     Some(quote! {
+                        #cfg
                         if let Some(val) = &_val.#ident {
                             #[derive(RefCast)]
                             #[repr(transparent)]
@@ -245,7 +247,7 @@ fn expand_impl_body(defs: &Definitions, node: &Node, name: &str) -> TokenStream
                             }
                         };
                     }
-                    Some(call)
+                    Some(quote!(#cfg #call))
                 }
             });
             quote! {
@@ -275,8 +277,17 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
 
     let ident = Ident::new(&node.ident, Span::call_site());
     let body = expand_impl_body(defs, node, &node.ident);
+    // The tests are built with the "derive" and "full" features, so only
+    // types outside of them need to be cfg gated.
+    let features = &node.features.any;
+    let cfg_features = if features.contains("derive") || features.contains("full") {
+        TokenStream::new()
+    } else {
+        cfg::features(&node.features)
+    };
 
     quote! {
+        #cfg_features
         impl Debug for Lite<syn::#ident> {
             fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 let _val = &self.value;
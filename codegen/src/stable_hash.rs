@@ -0,0 +1,155 @@
+use crate::{cfg, file, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+
+const STABLE_HASH_SRC: &str = "../src/gen/stable_hash.rs";
+
+// The same fields as are left out of the Hash impls, so that trees which are
+// equal by their PartialEq impls hash the same.
+fn skip(field_type: &Type) -> bool {
+    match field_type {
+        Type::Syn(node) => node == "Reserved",
+        Type::Ext(ty) => ty == "Span",
+        Type::Token(_) | Type::Group(_) => true,
+        Type::Box(inner) => skip(inner),
+        Type::Tuple(inner) => inner.iter().all(skip),
+        _ => false,
+    }
+}
+
+fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let arms = variants
+                .iter()
+                .enumerate()
+                .map(|(i, (variant_name, fields))| {
+                    let i = i as u8;
+                    let variant = Ident::new(variant_name, Span::call_site());
+                    if fields.is_empty() {
+                        quote! {
+                            #ident::#variant => {
+                                hasher.write_u8(#i);
+                            }
+                        }
+                    } else {
+                        let mut pats = Vec::new();
+                        let mut hashes = Vec::new();
+                        for (i, field) in fields.iter().enumerate() {
+                            if skip(field) {
+                                pats.push(format_ident!("_"));
+                                continue;
+                            }
+                            let var = format_ident!("v{}", i);
+                            hashes.push(quote! {
+                                #var.stable_hash(hasher);
+                            });
+                            pats.push(var);
+                        }
+                        let mut cfg = None;
+                        if node.ident == "Expr" {
+                            if let Type::Syn(ty) = &fields[0] {
+                                if !lookup::node(defs, ty).features.any.contains("derive") {
+                                    cfg = Some(quote!(#[cfg(feature = "full")]));
+                                }
+                            }
+                        }
+                        quote! {
+                            #cfg
+                            #ident::#variant(#(#pats),*) => {
+                                hasher.write_u8(#i);
+                                #(#hashes)*
+                            }
+                        }
+                    }
+                });
+            let nonexhaustive = if node.exhaustive {
+                None
+            } else if node.ident == "Expr" {
+                Some(quote! {
+                    #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+                    _ => unreachable!(),
+                })
+            } else {
+                Some(quote! {
+                    #[cfg(syn_no_non_exhaustive)]
+                    _ => unreachable!(),
+                })
+            };
+            quote! {
+                match self {
+                    #(#arms)*
+                    #nonexhaustive
+                }
+            }
+        }
+        Data::Struct(fields) => fields
+            .iter()
+            .filter_map(|(f, ty)| {
+                if skip(ty) {
+                    return None;
+                }
+                let ident = Ident::new(f, Span::call_site());
+                let cfg = cfg::field_features(node, f);
+                Some(quote! {
+                    #cfg
+                    self.#ident.stable_hash(hasher);
+                })
+            })
+            .collect(),
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    if node.data == Data::Private {
+        return TokenStream::new();
+    }
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+
+    let body = expand_impl_body(defs, node);
+    let allow_unused = cfg::allow_unused_if_gated(node);
+    let hasher = if body.is_empty() {
+        quote!(_hasher)
+    } else {
+        quote!(hasher)
+    };
+
+    quote! {
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+        impl StableHash for #ident {
+            #allow_unused
+            fn stable_hash(&self, #hasher: &mut StableHasher) {
+                #body
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        impls.extend(expand_impl(defs, node));
+    }
+
+    file::write(
+        STABLE_HASH_SRC,
+        quote! {
+            use crate::hash::{StableHash, StableHasher};
+            use crate::*;
+            use std::hash::Hasher;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
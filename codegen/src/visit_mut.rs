@@ -1,5 +1,5 @@
 use crate::operand::{Borrowed, Operand, Owned};
-use crate::{file, full, gen};
+use crate::{cfg, file, full, gen};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -48,13 +48,14 @@ fn visit(
         Type::Punctuated(p) => {
             let operand = Borrowed(quote!(it));
             let val = visit(&p.element, features, defs, &operand)?;
+            let repr = &defs.tokens[&p.punct];
             let name = name.ref_mut_tokens();
             Some(quote! {
                 for el in Punctuated::pairs_mut(#name) {
                     let (it, p) = el.into_tuple();
                     #val;
                     if let Some(p) = p {
-                        tokens_helper(v, &mut p.spans);
+                        tokens_helper(v, #repr, &mut p.spans);
                     }
                 }
             })
@@ -91,13 +92,19 @@ fn visit(
                 quote!(spans)
             };
             Some(quote! {
-                tokens_helper(v, &mut #name.#spans);
+                tokens_helper(v, #repr, &mut #name.#spans);
             })
         }
-        Type::Group(_) => {
+        Type::Group(t) => {
             let name = name.tokens();
+            let repr = match t.as_str() {
+                "Brace" => "{}",
+                "Bracket" => "[]",
+                "Paren" => "()",
+                _ => "",
+            };
             Some(quote! {
-                tokens_helper(v, &mut #name.span);
+                tokens_helper(v, #repr, &mut #name.span);
             })
         }
         Type::Syn(t) => {
@@ -191,7 +198,9 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
                 let ref_toks = Owned(quote!(node.#id));
                 let visit_mut_field = visit(&ty, &s.features, defs, &ref_toks)
                     .unwrap_or_else(|| noop_visit(&ref_toks));
+                let cfg = cfg::field_features(s, field);
                 visit_mut_impl.extend(quote! {
+                    #cfg
                     #visit_mut_field;
                 });
             }
@@ -231,9 +240,9 @@ pub fn generate(defs: &Definitions) -> Result<()> {
         quote! {
             #![allow(unused_variables)]
 
-            #[cfg(any(feature = "full", feature = "derive"))]
+            #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
             use crate::gen::helper::visit_mut::*;
-            #[cfg(any(feature = "full", feature = "derive"))]
+            #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
             use crate::punctuated::Punctuated;
             use crate::*;
             use proc_macro2::Span;
@@ -254,9 +263,31 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             /// *This trait is available only if Syn is built with the `"visit-mut"` feature.*
             pub trait VisitMut {
                 #traits
+
+                /// Visits a keyword, punctuation or delimiter token, given as
+                /// the string it is written as, like `"fn"` or `"=>"`, and its
+                /// spans.
+                ///
+                /// Tokens are visited in the order of the syntax tree rather
+                /// than the order of the source. A delimiter is visited once
+                /// for the pair, before its contents, as `"{}"`, `"[]"`,
+                /// `"()"` or as `""` for an invisible group. The default implementation visits each
+                /// span with [`visit_span_mut`](VisitMut::visit_span_mut).
+                fn visit_token_mut(&mut self, token: &'static str, spans: &mut [Span]) {
+                    visit_token_mut(self, token, spans);
+                }
             }
 
             #impls
+
+            pub fn visit_token_mut<V>(v: &mut V, token: &'static str, spans: &mut [Span])
+            where
+                V: VisitMut + ?Sized,
+            {
+                for span in spans {
+                    v.visit_span_mut(span);
+                }
+            }
         },
     )?;
     Ok(())
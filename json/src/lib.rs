@@ -89,6 +89,14 @@ pub struct Node {
     )]
     pub data: Data,
 
+    /// Features behind which individual fields of a struct are cfg gated, in
+    /// addition to the features of the type itself.
+    ///
+    /// The keys in the map are field names. Fields not in the map are present
+    /// whenever the type is.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub field_features: BTreeMap<String, Features>,
+
     #[serde(skip_serializing_if = "is_true", default = "bool_true")]
     pub exhaustive: bool,
 }
@@ -183,7 +191,7 @@ pub struct Punctuated {
     pub punct: String,
 }
 
-/// Features behind which a syntax tree type is cfg gated.
+/// Features behind which a syntax tree type or field is cfg gated.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Features {
     /// Type is accessible if at least one of these features is enabled against
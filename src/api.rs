@@ -0,0 +1,577 @@
+//! Extraction of the public API of a crate.
+//!
+//! The entry point is [`public_items`], which walks the module tree of a
+//! parsed crate root and lists every item that is reachable from outside of
+//! the crate, under each path by which it can be named. This is the
+//! starting point for tools that generate documentation, or that compare two
+//! versions of a crate with the functions in [`semver`](crate::semver).
+//!
+//! The analysis is purely syntactic and has the following limitations:
+//!
+//! - Only modules with an inline body are looked into. The contents of a
+//!   module declared as `mod m;` live in another file, which the caller can
+//!   parse and splice into [`ItemMod::content`] beforehand.
+//! - Items generated by macros and items disabled by `#[cfg]` are not
+//!   recognized; every item is taken to exist as written.
+//! - Re-exports are followed only within the crate. A `pub use` of an
+//!   external crate's item is not listed.
+//!
+//! *This module is available only if Syn is built with the `"full"`
+//! feature.*
+
+use super::*;
+use std::collections::HashSet;
+
+/// An item that is part of the public API of a crate, as returned by
+/// [`public_items`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+pub struct PublicItem<'a> {
+    /// The path by which the item can be named from outside of the crate,
+    /// without the crate name: `[m, Thing]` for `crate::m::Thing`.
+    pub path: Vec<Ident>,
+    /// The visibility that makes the item public at this path. For an item
+    /// reached through a re-export this is the visibility of the `pub use`.
+    /// It is `None` for a `#[macro_export]` macro.
+    pub vis: Option<&'a Visibility>,
+    /// The definition of the item.
+    pub item: ApiItem<'a>,
+    /// Whether the path goes through a `pub use` re-export rather than
+    /// following the module structure of the definition.
+    pub reexport: bool,
+}
+
+/// The definition of a [`PublicItem`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+pub enum ApiItem<'a> {
+    /// An item in a module.
+    Item(&'a Item),
+    /// A public method or associated constant in an inherent impl block.
+    ImplItem(&'a ImplItem),
+    /// A function, static or type in an `extern` block.
+    ForeignItem(&'a ForeignItem),
+}
+
+impl<'a> Copy for ApiItem<'a> {}
+
+impl<'a> Clone for ApiItem<'a> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a> PublicItem<'a> {
+    /// Returns the signature of the item if it is a function or method.
+    pub fn signature(&self) -> Option<&'a Signature> {
+        match self.item {
+            ApiItem::Item(Item::Fn(item)) => Some(&item.sig),
+            ApiItem::ImplItem(ImplItem::Method(item)) => Some(&item.sig),
+            ApiItem::ForeignItem(ForeignItem::Fn(item)) => Some(&item.sig),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of the item joined by `::`, such as `m::Thing`.
+    pub fn path_string(&self) -> String {
+        let segments: Vec<String> = self.path.iter().map(Ident::to_string).collect();
+        segments.join("::")
+    }
+}
+
+/// Lists the public items of a crate whose root module is `file`.
+///
+/// An item is public if it is declared `pub` in a module that is itself
+/// reachable from the crate root, or if it is re-exported by a `pub use` in
+/// such a module. An item that can be named in several ways, for example
+/// both at its definition and through a re-export, is listed once per path.
+/// Public methods and associated constants of inherent impl blocks are listed
+/// under the paths of their self type, and `#[macro_export]` macros at the
+/// crate root.
+///
+/// Enum variants, struct fields and the items of traits are not listed
+/// separately, as they are public whenever their parent is.
+///
+/// See the [module documentation](self) for the limitations of the analysis.
+///
+/// *This function is available only if Syn is built with the `"full"`
+/// feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::parse_quote;
+///
+/// let file: syn::File = parse_quote! {
+///     mod imp {
+///         pub struct Parser;
+///
+///         impl Parser {
+///             pub fn new() -> Self { Parser }
+///             fn helper(&self) {}
+///         }
+///     }
+///
+///     pub mod error {
+///         pub struct Error;
+///         pub(crate) fn internal() {}
+///     }
+///
+///     pub use crate::imp::Parser;
+///     pub use error::Error as ParseError;
+/// };
+///
+/// let paths: Vec<String> = syn::api::public_items(&file)
+///     .iter()
+///     .map(|item| item.path_string())
+///     .collect();
+/// assert_eq!(
+///     paths,
+///     ["error", "error::Error", "Parser", "ParseError", "Parser::new"],
+/// );
+/// ```
+pub fn public_items(file: &File) -> Vec<PublicItem<'_>> {
+    let mut crate_modules = Vec::new();
+    collect_modules(Vec::new(), &file.items, &mut crate_modules);
+
+    let mut walker = Walker {
+        modules: crate_modules,
+        stack: Vec::new(),
+        seen: HashSet::new(),
+        real_paths: Vec::new(),
+        items: Vec::new(),
+    };
+    walker.visit_module(&[], Vec::new());
+    walker.add_impl_items();
+    walker.add_exported_macros();
+    walker.items
+}
+
+struct Module<'a> {
+    path: Vec<String>,
+    items: &'a [Item],
+}
+
+fn collect_modules<'a>(path: Vec<String>, items: &'a [Item], modules: &mut Vec<Module<'a>>) {
+    modules.push(Module {
+        path: path.clone(),
+        items,
+    });
+    for item in items {
+        if let Item::Mod(ItemMod {
+            ident,
+            content: Some((_brace, content)),
+            ..
+        }) = item
+        {
+            let mut path = path.clone();
+            path.push(ident.to_string());
+            collect_modules(path, content, modules);
+        }
+    }
+}
+
+// A name brought into a module by a `use` item.
+struct UseImport<'a> {
+    vis: &'a Visibility,
+    // Path to the imported item, as written.
+    prefix: Vec<&'a Ident>,
+    // Name of the import within the module, or `None` for a glob import.
+    name: Option<&'a Ident>,
+}
+
+fn use_imports<'a>(item: &'a ItemUse, imports: &mut Vec<UseImport<'a>>) {
+    if item.leading_colon.is_none() {
+        flatten_use_tree(&item.vis, &item.tree, Vec::new(), imports);
+    }
+}
+
+fn flatten_use_tree<'a>(
+    vis: &'a Visibility,
+    tree: &'a UseTree,
+    mut prefix: Vec<&'a Ident>,
+    imports: &mut Vec<UseImport<'a>>,
+) {
+    match tree {
+        UseTree::Path(tree) => {
+            prefix.push(&tree.ident);
+            flatten_use_tree(vis, &tree.tree, prefix, imports);
+        }
+        UseTree::Name(tree) => {
+            // `use a::b::{self}` imports the module `b`.
+            let name = match prefix.last() {
+                Some(last) if tree.ident == "self" => *last,
+                _ => {
+                    prefix.push(&tree.ident);
+                    &tree.ident
+                }
+            };
+            imports.push(UseImport {
+                vis,
+                prefix,
+                name: Some(name),
+            });
+        }
+        UseTree::Rename(tree) => {
+            if tree.ident != "self" {
+                prefix.push(&tree.ident);
+            }
+            imports.push(UseImport {
+                vis,
+                prefix,
+                name: Some(&tree.rename),
+            });
+        }
+        UseTree::Glob(_) => imports.push(UseImport {
+            vis,
+            prefix,
+            name: None,
+        }),
+        UseTree::Group(tree) => {
+            for tree in &tree.items {
+                flatten_use_tree(vis, tree, prefix.clone(), imports);
+            }
+        }
+    }
+}
+
+// The name and visibility under which an item is defined in its module.
+fn item_name(item: &Item) -> Option<(&Ident, &Visibility)> {
+    match item {
+        Item::Const(item) => Some((&item.ident, &item.vis)),
+        Item::Enum(item) => Some((&item.ident, &item.vis)),
+        Item::ExternCrate(item) => match &item.rename {
+            Some((_as_token, rename)) => Some((rename, &item.vis)),
+            None => Some((&item.ident, &item.vis)),
+        },
+        Item::Fn(item) => Some((&item.sig.ident, &item.vis)),
+        Item::Macro2(item) => Some((&item.ident, &item.vis)),
+        Item::Mod(item) => Some((&item.ident, &item.vis)),
+        Item::Static(item) => Some((&item.ident, &item.vis)),
+        Item::Struct(item) => Some((&item.ident, &item.vis)),
+        Item::Trait(item) => Some((&item.ident, &item.vis)),
+        Item::TraitAlias(item) => Some((&item.ident, &item.vis)),
+        Item::Type(item) => Some((&item.ident, &item.vis)),
+        Item::Union(item) => Some((&item.ident, &item.vis)),
+        _ => None,
+    }
+}
+
+fn foreign_item_name(item: &ForeignItem) -> Option<(&Ident, &Visibility)> {
+    match item {
+        ForeignItem::Fn(item) => Some((&item.sig.ident, &item.vis)),
+        ForeignItem::Static(item) => Some((&item.ident, &item.vis)),
+        ForeignItem::Type(item) => Some((&item.ident, &item.vis)),
+        _ => None,
+    }
+}
+
+struct Walker<'a> {
+    modules: Vec<Module<'a>>,
+    // Real paths of the modules being visited, to stop at cyclic re-exports.
+    stack: Vec<Vec<String>>,
+    // Every public path and real path of an item that has been listed.
+    seen: HashSet<(String, String)>,
+    // Real path of the definition of each of `items`.
+    real_paths: Vec<Vec<String>>,
+    items: Vec<PublicItem<'a>>,
+}
+
+impl<'a> Walker<'a> {
+    fn module(&self, path: &[String]) -> Option<&'a [Item]> {
+        self.modules
+            .iter()
+            .find(|module| module.path == path)
+            .map(|module| module.items)
+    }
+
+    // Lists the public contents of the module at `real` under the public
+    // path `public`.
+    fn visit_module(&mut self, real: &[String], public: Vec<Ident>) {
+        let items = match self.module(real) {
+            Some(items) => items,
+            None => return,
+        };
+        if self.stack.iter().any(|path| path == real) {
+            return;
+        }
+        self.stack.push(real.to_vec());
+
+        for item in items {
+            match item {
+                Item::Use(item) => {
+                    let mut imports = Vec::new();
+                    use_imports(item, &mut imports);
+                    for import in imports {
+                        if import.vis.is_public() {
+                            self.visit_import(real, &public, &import);
+                        }
+                    }
+                }
+                Item::ForeignMod(item) => {
+                    for foreign in &item.items {
+                        if let Some((ident, vis)) = foreign_item_name(foreign) {
+                            if vis.is_public() {
+                                let mut real_path = real.to_vec();
+                                real_path.push(ident.to_string());
+                                let mut path = public.clone();
+                                path.push(ident.clone());
+                                let item = ApiItem::ForeignItem(foreign);
+                                self.push(path, real_path, Some(vis), item, false);
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    if let Some((ident, vis)) = item_name(item) {
+                        if vis.is_public() {
+                            self.define(real, &public, ident, ident, Some(vis), item, false);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.stack.pop();
+    }
+
+    fn visit_import(&mut self, module: &[String], public: &[Ident], import: &UseImport<'a>) {
+        let target = match self.resolve(module, &import.prefix, 0) {
+            Some(target) => target,
+            None => return,
+        };
+        let name = match import.name {
+            Some(name) => name,
+            None => {
+                // A glob import of every public item of a module.
+                self.visit_module(&target, public.to_vec());
+                return;
+            }
+        };
+        let (parent, last) = target.split_at(target.len() - 1);
+        let items = match self.module(parent) {
+            Some(items) => items,
+            None => return,
+        };
+        for item in items {
+            if let Some((ident, _vis)) = item_name(item) {
+                if ident == &last[0] {
+                    self.define(parent, public, ident, name, Some(import.vis), item, true);
+                }
+            }
+        }
+    }
+
+    // Lists an item defined as `ident` in the module at `real` under the
+    // public path `public` followed by `name`.
+    fn define(
+        &mut self,
+        real: &[String],
+        public: &[Ident],
+        ident: &Ident,
+        name: &Ident,
+        vis: Option<&'a Visibility>,
+        item: &'a Item,
+        reexport: bool,
+    ) {
+        let mut real_path = real.to_vec();
+        real_path.push(ident.to_string());
+        let mut path = public.to_vec();
+        path.push(name.clone());
+        let inline_module = match item {
+            Item::Mod(ItemMod {
+                content: Some(_), ..
+            }) => true,
+            _ => false,
+        };
+        let api_item = ApiItem::Item(item);
+        let is_new = self.push(path.clone(), real_path.clone(), vis, api_item, reexport);
+        if is_new && inline_module {
+            self.visit_module(&real_path, path);
+        }
+    }
+
+    fn push(
+        &mut self,
+        path: Vec<Ident>,
+        real_path: Vec<String>,
+        vis: Option<&'a Visibility>,
+        item: ApiItem<'a>,
+        reexport: bool,
+    ) -> bool {
+        let path_key: Vec<String> = path.iter().map(Ident::to_string).collect();
+        let key = (path_key.join("::"), real_path.join("::"));
+        if !self.seen.insert(key) {
+            return false;
+        }
+        self.real_paths.push(real_path);
+        self.items.push(PublicItem {
+            path,
+            vis,
+            item,
+            reexport,
+        });
+        true
+    }
+
+    // Resolves a path written in the module at `module` to the real path of
+    // the item it names, following `use` imports.
+    fn resolve(&self, module: &[String], segments: &[&Ident], depth: usize) -> Option<Vec<String>> {
+        // Guard against cyclic imports.
+        if depth > 16 {
+            return None;
+        }
+        let (first, rest) = segments.split_first()?;
+        let (mut current, segments) = if *first == "crate" {
+            (Vec::new(), rest)
+        } else if *first == "self" || *first == "super" {
+            let mut current = module.to_vec();
+            let mut segments = segments;
+            while let Some((first, rest)) = segments.split_first() {
+                if *first == "super" {
+                    current.pop()?;
+                } else if *first != "self" {
+                    break;
+                }
+                segments = rest;
+            }
+            (current, segments)
+        } else if self.lookup(module, first, depth).is_some() {
+            (module.to_vec(), segments)
+        } else {
+            // A 2015 edition path, relative to the crate root.
+            (Vec::new(), segments)
+        };
+        if segments.is_empty() {
+            return Some(current);
+        }
+        for segment in segments {
+            current = self.lookup(&current, segment, depth)?;
+        }
+        Some(current)
+    }
+
+    // Looks up a name in the module at `module`, returning the real path of
+    // the item it refers to.
+    fn lookup(&self, module: &[String], name: &Ident, depth: usize) -> Option<Vec<String>> {
+        let items = self.module(module)?;
+        for item in items {
+            if let Some((ident, _vis)) = item_name(item) {
+                if ident == name {
+                    let mut path = module.to_vec();
+                    path.push(ident.to_string());
+                    return Some(path);
+                }
+            }
+            if let Item::ForeignMod(item) = item {
+                for foreign in &item.items {
+                    if let Some((ident, _vis)) = foreign_item_name(foreign) {
+                        if ident == name {
+                            let mut path = module.to_vec();
+                            path.push(ident.to_string());
+                            return Some(path);
+                        }
+                    }
+                }
+            }
+        }
+        let mut globs = Vec::new();
+        for item in items {
+            if let Item::Use(item) = item {
+                let mut imports = Vec::new();
+                use_imports(item, &mut imports);
+                for import in imports {
+                    match import.name {
+                        Some(imported) if imported == name => {
+                            return self.resolve(module, &import.prefix, depth + 1);
+                        }
+                        Some(_) => {}
+                        None => globs.push(import),
+                    }
+                }
+            }
+        }
+        // Names imported by a glob are shadowed by every other name.
+        for glob in globs {
+            if let Some(target) = self.resolve(module, &glob.prefix, depth + 1) {
+                if let Some(path) = self.lookup(&target, name, depth + 1) {
+                    return Some(path);
+                }
+            }
+        }
+        None
+    }
+
+    fn add_impl_items(&mut self) {
+        for i in 0..self.modules.len() {
+            let module = self.modules[i].path.clone();
+            for item in self.modules[i].items {
+                let item = match item {
+                    Item::Impl(item) if item.trait_.is_none() => item,
+                    _ => continue,
+                };
+                let path = match &*item.self_ty {
+                    Type::Path(TypePath { qself: None, path }) => path,
+                    _ => continue,
+                };
+                if path.leading_colon.is_some() {
+                    continue;
+                }
+                let segments: Vec<&Ident> =
+                    path.segments.iter().map(|segment| &segment.ident).collect();
+                let target = match self.resolve(&module, &segments, 0) {
+                    Some(target) => target,
+                    None => continue,
+                };
+                // List the associated items under the first public path of the
+                // self type.
+                let public = match self.real_paths.iter().position(|real| *real == target) {
+                    Some(index) => self.items[index].path.clone(),
+                    None => continue,
+                };
+                for impl_item in &item.items {
+                    let (ident, vis) = match impl_item {
+                        ImplItem::Const(item) => (&item.ident, &item.vis),
+                        ImplItem::Method(item) => (&item.sig.ident, &item.vis),
+                        _ => continue,
+                    };
+                    if vis.is_public() {
+                        let mut path = public.clone();
+                        path.push(ident.clone());
+                        let mut real_path = target.clone();
+                        real_path.push(ident.to_string());
+                        let api_item = ApiItem::ImplItem(impl_item);
+                        self.push(path, real_path, Some(vis), api_item, false);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_exported_macros(&mut self) {
+        for i in 0..self.modules.len() {
+            let module = self.modules[i].path.clone();
+            for item in self.modules[i].items {
+                if let Item::Macro(ItemMacro {
+                    attrs,
+                    ident: Some(ident),
+                    ..
+                }) = item
+                {
+                    if attrs.iter().any(is_macro_export) {
+                        let mut real_path = module.clone();
+                        real_path.push(ident.to_string());
+                        let path = vec![ident.clone()];
+                        self.push(path, real_path, None, ApiItem::Item(item), false);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_macro_export(attr: &Attribute) -> bool {
+    attr.path.leading_colon.is_none()
+        && attr.path.segments.len() == 1
+        && attr.path.segments[0].ident == "macro_export"
+}
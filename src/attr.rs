@@ -8,12 +8,20 @@ use std::slice;
 use crate::parse::{Parse, ParseBuffer, ParseStream, Parser, Result};
 #[cfg(feature = "parsing")]
 use crate::punctuated::Pair;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+use crate::verbatim;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+use proc_macro2::{Span, TokenTree};
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+use std::any::Any;
+#[cfg(feature = "parsing")]
+use std::fmt::{self, Debug};
 
 ast_struct! {
     /// An attribute like `#[repr(transparent)]`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// <br>
     ///
@@ -145,7 +153,7 @@ ast_struct! {
     /// };
     /// assert_eq!(doc, attr);
     /// ```
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Attribute {
         pub pound_token: Token![#],
         pub style: AttrStyle,
@@ -231,13 +239,46 @@ impl Attribute {
 
     /// Parses zero or more outer attributes from the stream.
     ///
+    /// Outer doc comments like `/// ...` are parsed as attributes too. An
+    /// inner attribute `#![...]` is an error, as one cannot appear where outer
+    /// attributes are expected.
+    ///
     /// *This function is available only if Syn is built with the `"parsing"`
     /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Attribute, Ident, Result, Token};
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // #[path = "s.rs"] mod s;
+    /// struct ModDecl {
+    ///     attrs: Vec<Attribute>,
+    ///     mod_token: Token![mod],
+    ///     name: Ident,
+    ///     semi_token: Token![;],
+    /// }
+    ///
+    /// impl Parse for ModDecl {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         Ok(ModDecl {
+    ///             attrs: input.call(Attribute::parse_outer)?,
+    ///             mod_token: input.parse()?,
+    ///             name: input.parse()?,
+    ///             semi_token: input.parse()?,
+    ///         })
+    ///     }
+    /// }
+    /// ```
     #[cfg(feature = "parsing")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     pub fn parse_outer(input: ParseStream) -> Result<Vec<Self>> {
         let mut attrs = Vec::new();
         while input.peek(Token![#]) {
+            if input.peek2(Token![!]) {
+                return Err(input.error("expected outer attribute, found inner attribute"));
+            }
             attrs.push(input.call(parsing::single_parse_outer)?);
         }
         Ok(attrs)
@@ -245,8 +286,43 @@ impl Attribute {
 
     /// Parses zero or more inner attributes from the stream.
     ///
+    /// Inner doc comments like `//! ...` are parsed as attributes too. Parsing
+    /// stops at the first token that does not start an inner attribute, which
+    /// leaves any outer attributes after them in the stream.
+    ///
     /// *This function is available only if Syn is built with the `"parsing"`
     /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{braced, token, Attribute, Item, Result};
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // { #![allow(dead_code)] fn f() {} }
+    /// struct Body {
+    ///     brace_token: token::Brace,
+    ///     attrs: Vec<Attribute>,
+    ///     items: Vec<Item>,
+    /// }
+    ///
+    /// impl Parse for Body {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let content;
+    ///         let brace_token = braced!(content in input);
+    ///         let attrs = content.call(Attribute::parse_inner)?;
+    ///         let mut items = Vec::new();
+    ///         while !content.is_empty() {
+    ///             items.push(content.parse()?);
+    ///         }
+    ///         Ok(Body {
+    ///             brace_token,
+    ///             attrs,
+    ///             items,
+    ///         })
+    ///     }
+    /// }
+    /// ```
     #[cfg(feature = "parsing")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     pub fn parse_inner(input: ParseStream) -> Result<Vec<Self>> {
@@ -312,8 +388,8 @@ ast_enum! {
     /// Distinguishes between attributes that decorate an item and attributes
     /// that are contained within an item.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// # Outer attributes
     ///
@@ -326,7 +402,7 @@ ast_enum! {
     /// - `#![feature(proc_macro)]`
     /// - `//! # Example`
     /// - `/*! Please file an issue */`
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum AttrStyle {
         Outer,
         Inner(Token![!]),
@@ -336,8 +412,8 @@ ast_enum! {
 ast_enum_of_structs! {
     /// Content of a compile-time structured attribute.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// ## Path
     ///
@@ -357,7 +433,7 @@ ast_enum_of_structs! {
     /// This type is a [syntax tree enum].
     ///
     /// [syntax tree enum]: Expr#syntax-tree-enums
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum Meta {
         Path(Path),
 
@@ -372,9 +448,9 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A structured list within an attribute, like `derive(Copy, Clone)`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct MetaList {
         pub path: Path,
         pub paren_token: token::Paren,
@@ -385,9 +461,9 @@ ast_struct! {
 ast_struct! {
     /// A name-value pair within an attribute, like `feature = "nightly"`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct MetaNameValue {
         pub path: Path,
         pub eq_token: Token![=],
@@ -412,9 +488,9 @@ impl Meta {
 ast_enum_of_structs! {
     /// Element of a compile-time attribute list.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum NestedMeta {
         /// A structured meta item, like the `Copy` in `#[derive(Copy)]` which
         /// would be a nested `Meta::Path`.
@@ -425,6 +501,94 @@ ast_enum_of_structs! {
     }
 }
 
+ast_enum_of_structs! {
+    /// A configuration predicate, like the `all(unix, feature = "serde")` in
+    /// `#[cfg(all(unix, feature = "serde"))]`.
+    ///
+    /// This is the argument of `#[cfg(...)]`, of `cfg!(...)` and the first
+    /// argument of `#[cfg_attr(...)]`. Parse it out of an attribute with
+    /// [`Attribute::parse_args`].
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    ///
+    /// # Syntax tree enum
+    ///
+    /// This type is a [syntax tree enum].
+    ///
+    /// [syntax tree enum]: Expr#syntax-tree-enums
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    pub enum CfgExpr {
+        /// A configuration option that is either set or not, like `unix`.
+        Key(Ident),
+
+        /// A configuration option with a value, like `feature = "serde"`.
+        KeyValue(CfgKeyValue),
+
+        /// `all(...)`: true if every predicate in the list is true, including
+        /// when the list is empty.
+        All(CfgAll),
+
+        /// `any(...)`: true if at least one predicate in the list is true.
+        Any(CfgAny),
+
+        /// `not(...)`: the negation of a predicate.
+        Not(CfgNot),
+    }
+}
+
+ast_struct! {
+    /// A configuration option with a value, like `feature = "serde"`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    pub struct CfgKeyValue {
+        pub key: Ident,
+        pub eq_token: Token![=],
+        pub value: LitStr,
+    }
+}
+
+ast_struct! {
+    /// A conjunction of configuration predicates: `all(unix, test)`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    pub struct CfgAll {
+        pub ident: Ident,
+        pub paren_token: token::Paren,
+        pub predicates: Punctuated<CfgExpr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// A disjunction of configuration predicates: `any(unix, windows)`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    pub struct CfgAny {
+        pub ident: Ident,
+        pub paren_token: token::Paren,
+        pub predicates: Punctuated<CfgExpr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// A negated configuration predicate: `not(test)`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    pub struct CfgNot {
+        pub ident: Ident,
+        pub paren_token: token::Paren,
+        pub predicate: Box<CfgExpr>,
+    }
+}
+
 /// Conventional argument type associated with an invocation of an attribute
 /// macro.
 ///
@@ -460,9 +624,888 @@ ast_enum_of_structs! {
 /// #   "".parse().unwrap()
 /// }
 /// ```
-#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only")))
+)]
 pub type AttributeArgs = Vec<NestedMeta>;
 
+/// A list of attributes along with a cache of the results of parsing them.
+///
+/// [`Attribute::parse_meta`] and [`Attribute::parse_args`] parse the
+/// attribute's tokens anew every time they are called. A macro that makes
+/// several passes over the same attributes, for example one pass per helper
+/// attribute that it supports, can construct a `ParsedAttributes` once and
+/// have every attribute parsed at most once. An attribute is parsed the first
+/// time its result is asked for, so attributes that no pass looks at, such as
+/// doc comments, are never parsed.
+///
+/// The cache is kept beside the attributes rather than in `Attribute` itself,
+/// whose fields are public and can be assigned without Syn noticing. Instead
+/// the attributes are borrowed for as long as the `ParsedAttributes` exists,
+/// so they cannot be modified in a way that would make the cached results go
+/// stale.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, DeriveInput, LitStr, Meta, ParsedAttributes};
+///
+/// let input: DeriveInput = parse_quote! {
+///     #[serde(rename_all = "camelCase")]
+///     #[serde(deny_unknown_fields)]
+///     #[doc = "..."]
+///     #[table("users")]
+///     struct Config;
+/// };
+///
+/// let mut attrs = ParsedAttributes::new(&input.attrs);
+///
+/// // First pass.
+/// let serde_attrs = attrs.find("serde").count();
+/// assert_eq!(serde_attrs, 2);
+///
+/// // Second pass reuses the same parsed metas.
+/// for (_attr, meta) in attrs.find("serde") {
+///     assert!(matches!(meta, Ok(Meta::List(_))));
+/// }
+///
+/// // Arguments are cached separately for each type they are parsed as.
+/// for (_attr, table) in attrs.find_args::<LitStr>("table") {
+///     assert_eq!(table.as_ref().unwrap().value(), "users");
+/// }
+/// ```
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub struct ParsedAttributes<'a> {
+    attrs: &'a [Attribute],
+    metas: Vec<Option<Result<Meta>>>,
+    // For each attribute, a `Result<T>` for every type `T` that its arguments
+    // have been parsed as.
+    args: Vec<Vec<Box<dyn Any>>>,
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl<'a> ParsedAttributes<'a> {
+    /// Prepares to parse the attributes in the list, without parsing any of
+    /// them yet.
+    pub fn new(attrs: &'a [Attribute]) -> Self {
+        ParsedAttributes {
+            attrs,
+            metas: attrs.iter().map(|_| None).collect(),
+            args: attrs.iter().map(|_| Vec::new()).collect(),
+        }
+    }
+
+    /// The attributes that are parsed.
+    pub fn attrs(&self) -> &'a [Attribute] {
+        self.attrs
+    }
+
+    /// The number of attributes.
+    pub fn len(&self) -> usize {
+        self.attrs.len()
+    }
+
+    /// Whether there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attrs.is_empty()
+    }
+
+    /// The attribute at the given index along with its parsed meta, or `None`
+    /// if the index is out of bounds.
+    pub fn get(&mut self, index: usize) -> Option<(&'a Attribute, &Result<Meta>)> {
+        let attr = self.attrs.get(index)?;
+        let meta = self.metas[index].get_or_insert_with(|| attr.parse_meta());
+        Some((attr, meta))
+    }
+
+    /// The attribute at the given index along with its arguments parsed as
+    /// `T`, as by [`Attribute::parse_args`], or `None` if the index is out of
+    /// bounds.
+    pub fn get_args<T: Parse + 'static>(
+        &mut self,
+        index: usize,
+    ) -> Option<(&'a Attribute, &Result<T>)> {
+        let attr = self.attrs.get(index)?;
+        Some((attr, cached_args(attr, &mut self.args[index])))
+    }
+
+    /// Iterates over every attribute along with its parsed meta.
+    pub fn iter(&mut self) -> ParsedAttributesIter<'a, '_> {
+        ParsedAttributesIter {
+            attrs: self.attrs.iter(),
+            metas: self.metas.iter_mut(),
+        }
+    }
+
+    /// Iterates over the attributes whose path is the single identifier
+    /// `name`, like `#[serde(...)]` for the name `"serde"`, along with their
+    /// parsed metas.
+    ///
+    /// Attributes with a different path are skipped without being parsed.
+    pub fn find<'p>(
+        &'p mut self,
+        name: &'p str,
+    ) -> impl Iterator<Item = (&'a Attribute, &'p Result<Meta>)> + 'p {
+        self.iter()
+            .inner_filter(move |attr| attr.path.is_ident(name))
+    }
+
+    /// Iterates over the attributes whose path is the single identifier
+    /// `name` along with their arguments parsed as `T`, as by
+    /// [`Attribute::parse_args`].
+    ///
+    /// Attributes with a different path are skipped without being parsed.
+    pub fn find_args<'p, T: Parse + 'static>(
+        &'p mut self,
+        name: &'p str,
+    ) -> impl Iterator<Item = (&'a Attribute, &'p Result<T>)> + 'p {
+        self.attrs
+            .iter()
+            .zip(self.args.iter_mut())
+            .filter(move |(attr, _args)| attr.path.is_ident(name))
+            .map(|(attr, args)| (attr, cached_args(attr, args)))
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+fn cached_args<'p, T: Parse + 'static>(
+    attr: &Attribute,
+    cache: &'p mut Vec<Box<dyn Any>>,
+) -> &'p Result<T> {
+    let index = match cache.iter().position(|args| args.is::<Result<T>>()) {
+        Some(index) => index,
+        None => {
+            cache.push(Box::new(attr.parse_args::<T>()));
+            cache.len() - 1
+        }
+    };
+    cache[index].downcast_ref().unwrap()
+}
+
+/// Iterator over the attributes of a [`ParsedAttributes`] with their parsed
+/// metas.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub struct ParsedAttributesIter<'a, 'p> {
+    attrs: slice::Iter<'a, Attribute>,
+    metas: slice::IterMut<'p, Option<Result<Meta>>>,
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl<'a, 'p> ParsedAttributesIter<'a, 'p> {
+    // Skips the attributes rejected by `predicate` without parsing them.
+    fn inner_filter<F>(
+        self,
+        mut predicate: F,
+    ) -> impl Iterator<Item = (&'a Attribute, &'p Result<Meta>)>
+    where
+        F: FnMut(&Attribute) -> bool,
+    {
+        self.attrs
+            .zip(self.metas)
+            .filter(move |(attr, _meta)| predicate(attr))
+            .map(|(attr, meta)| (attr, &*meta.get_or_insert_with(|| attr.parse_meta())))
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl<'a, 'p> Iterator for ParsedAttributesIter<'a, 'p> {
+    type Item = (&'a Attribute, &'p Result<Meta>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let attr = self.attrs.next()?;
+        let meta = self.metas.next()?;
+        Some((attr, meta.get_or_insert_with(|| attr.parse_meta())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.attrs.size_hint()
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl<'a, 'p> ExactSizeIterator for ParsedAttributesIter<'a, 'p> {}
+
+/// The traits named in the `#[derive(...)]` attributes of an item.
+///
+/// Every `derive` attribute in the list contributes its traits in source
+/// order, including ones wrapped in `#[cfg_attr(predicate, derive(...))]`, in
+/// which case the traits remember the predicates that guard them.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, DeriveInput, DeriveList};
+///
+/// let input: DeriveInput = parse_quote! {
+///     #[derive(Debug, Clone)]
+///     #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+///     struct Point {
+///         x: i32,
+///         y: i32,
+///     }
+/// };
+///
+/// let derives = DeriveList::from_attrs(&input.attrs).unwrap();
+/// assert!(derives.contains("Clone"));
+/// assert!(derives.contains("Serialize"));
+/// assert!(!derives.contains("Default"));
+///
+/// let serialize = derives.find("Serialize").unwrap();
+/// assert_eq!(serialize.cfg.len(), 1);
+/// ```
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub struct DeriveList {
+    pub traits: Vec<DerivedTrait>,
+}
+
+/// A single trait in a [`DeriveList`].
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub struct DerivedTrait {
+    /// The path of the trait, like `Debug` or `serde::Serialize`.
+    pub path: Path,
+    /// The predicates of the `cfg_attr` attributes that the `derive` is
+    /// nested in, outermost first. Empty for an unconditional derive.
+    pub cfg: Vec<Meta>,
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl DeriveList {
+    /// Collects the derived traits from a list of attributes.
+    ///
+    /// Attributes other than `derive` and `cfg_attr` are ignored, as are the
+    /// attributes inside a `cfg_attr` other than `derive` and `cfg_attr`.
+    /// Errors from all malformed attributes are combined.
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut list = DeriveList { traits: Vec::new() };
+        let mut errors: Option<Error> = None;
+        for attr in attrs {
+            let result = if attr.path.is_ident("derive") {
+                attr.parse_args_with(|input: ParseStream| {
+                    parse_derive_body(input, &[], &mut list.traits)
+                })
+            } else if attr.path.is_ident("cfg_attr") {
+                attr.parse_args_with(|input: ParseStream| {
+                    parse_cfg_attr_body(input, &[], &mut list.traits)
+                })
+            } else {
+                continue;
+            };
+            if let Err(err) = result {
+                match &mut errors {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(list),
+        }
+    }
+
+    /// Looks up a derived trait by the last segment of its path, so that
+    /// `"Serialize"` finds both `Serialize` and `serde::Serialize`.
+    pub fn find(&self, name: &str) -> Option<&DerivedTrait> {
+        self.traits
+            .iter()
+            .find(|derived| match derived.path.segments.last() {
+                Some(last) => last.ident == name,
+                None => false,
+            })
+    }
+
+    /// Whether a trait with the given name is derived, conditionally or not.
+    /// See [`find`](DeriveList::find) for how names are matched.
+    pub fn contains(&self, name: &str) -> bool {
+        self.find(name).is_some()
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+fn parse_derive_body(
+    input: ParseStream,
+    cfg: &[TokenStream],
+    traits: &mut Vec<DerivedTrait>,
+) -> Result<()> {
+    let paths = Punctuated::<Path, Token![,]>::parse_terminated_with(input, Path::parse_mod_style)?;
+    for path in paths {
+        // Meta is only Clone with the "clone-impls" feature, so every trait
+        // parses its own copy of the predicates.
+        let cfg = cfg
+            .iter()
+            .map(|predicate| crate::parse2(predicate.clone()))
+            .collect::<Result<_>>()?;
+        traits.push(DerivedTrait { path, cfg });
+    }
+    Ok(())
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+fn parse_cfg_attr_body(
+    input: ParseStream,
+    outer: &[TokenStream],
+    traits: &mut Vec<DerivedTrait>,
+) -> Result<()> {
+    let begin = input.fork();
+    input.parse::<Meta>()?;
+    let mut cfg = outer.to_vec();
+    cfg.push(verbatim::between(begin, input));
+    input.parse::<Token![,]>()?;
+    while !input.is_empty() {
+        let path = input.call(Path::parse_mod_style)?;
+        if path.is_ident("derive") || path.is_ident("cfg_attr") {
+            let content;
+            parenthesized!(content in input);
+            if path.is_ident("derive") {
+                parse_derive_body(&content, &cfg, traits)?;
+            } else {
+                parse_cfg_attr_body(&content, &cfg, traits)?;
+            }
+        } else {
+            // Skip the rest of some other attribute.
+            input.step(|cursor| {
+                let mut rest = *cursor;
+                while let Some((tt, next)) = rest.token_tree() {
+                    match &tt {
+                        TokenTree::Punct(punct) if punct.as_char() == ',' => break,
+                        _ => rest = next,
+                    }
+                }
+                Ok(((), rest))
+            })?;
+        }
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+    Ok(())
+}
+
+/// The layout hints of the `#[repr(...)]` attributes of an item.
+///
+/// Each hint records the span of the place where it was written. Hints from
+/// multiple `repr` attributes are merged, as they are by the compiler.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, DeriveInput, ReprAttr, ReprInt};
+///
+/// let input: DeriveInput = parse_quote! {
+///     #[repr(C, u8)]
+///     #[repr(align(8))]
+///     enum Tag {
+///         A,
+///         B,
+///     }
+/// };
+///
+/// let repr = ReprAttr::from_attrs(&input.attrs).unwrap();
+/// assert!(repr.is_c());
+/// assert_eq!(repr.int.map(|(int, _span)| int), Some(ReprInt::U8));
+/// assert_eq!(repr.align.map(|(align, _span)| align), Some(8));
+/// assert!(!repr.is_packed());
+/// ```
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub struct ReprAttr {
+    /// `repr(C)`
+    pub c: Option<Span>,
+    /// `repr(Rust)`
+    pub rust: Option<Span>,
+    /// `repr(transparent)`
+    pub transparent: Option<Span>,
+    /// `repr(simd)`
+    pub simd: Option<Span>,
+    /// A primitive integer representation like `repr(u8)`.
+    pub int: Option<(ReprInt, Span)>,
+    /// `repr(align(N))`
+    pub align: Option<(u64, Span)>,
+    /// `repr(packed)` or `repr(packed(N))`. A plain `packed` is the same as
+    /// `packed(1)`.
+    pub packed: Option<(u64, Span)>,
+}
+
+/// A primitive integer representation, as in `#[repr(u8)]`.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature and the `"parsing"` feature.*
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub enum ReprInt {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl ReprAttr {
+    /// Collects the hints of all `repr` attributes in a list of attributes.
+    /// Other attributes are ignored.
+    ///
+    /// Unrecognized hints, hints given more than once, alignments that are
+    /// not a power of two and `transparent` combined with any other hint are
+    /// reported as errors. Errors from all `repr` attributes are combined.
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut repr = ReprAttr {
+            c: None,
+            rust: None,
+            transparent: None,
+            simd: None,
+            int: None,
+            align: None,
+            packed: None,
+        };
+        let mut errors: Option<Error> = None;
+        let mut error = |err: Error| match &mut errors {
+            Some(errors) => errors.combine(err),
+            None => errors = Some(err),
+        };
+        for attr in attrs {
+            if !attr.path.is_ident("repr") {
+                continue;
+            }
+            let hints = match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            {
+                Ok(hints) => hints,
+                Err(err) => {
+                    error(err);
+                    continue;
+                }
+            };
+            for hint in hints {
+                if let Err(err) = repr.add(hint) {
+                    error(err);
+                }
+            }
+        }
+        if let Some(span) = repr.transparent {
+            let others = repr.c.is_some()
+                || repr.rust.is_some()
+                || repr.simd.is_some()
+                || repr.int.is_some()
+                || repr.align.is_some()
+                || repr.packed.is_some();
+            if others {
+                error(Error::new(
+                    span,
+                    "transparent representation cannot have other repr hints",
+                ));
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(repr),
+        }
+    }
+
+    /// Whether the item has `repr(C)`.
+    pub fn is_c(&self) -> bool {
+        self.c.is_some()
+    }
+
+    /// Whether the item has `repr(transparent)`.
+    pub fn is_transparent(&self) -> bool {
+        self.transparent.is_some()
+    }
+
+    /// Whether the item has `repr(packed)` or `repr(packed(N))`.
+    pub fn is_packed(&self) -> bool {
+        self.packed.is_some()
+    }
+
+    fn add(&mut self, hint: Meta) -> Result<()> {
+        let (name, span) = match hint.path().get_ident() {
+            Some(ident) => (ident.to_string(), ident.span()),
+            None => return Err(unrecognized(hint.path().segments[0].ident.span())),
+        };
+        match &hint {
+            Meta::Path(_) => {
+                let slot = match name.as_str() {
+                    "C" => &mut self.c,
+                    "Rust" => &mut self.rust,
+                    "transparent" => &mut self.transparent,
+                    "simd" => &mut self.simd,
+                    "packed" => return set(&mut self.packed, (1, span)),
+                    _ => match ReprInt::from_name(&name) {
+                        Some(int) => return set(&mut self.int, (int, span)),
+                        None => return Err(unrecognized(span)),
+                    },
+                };
+                set(slot, span)
+            }
+            Meta::List(list) => {
+                let slot = match name.as_str() {
+                    "align" => &mut self.align,
+                    "packed" => &mut self.packed,
+                    _ => return Err(unrecognized(span)),
+                };
+                let n = match list.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Int(n))) if list.nested.len() == 1 => {
+                        n.base10_parse::<u64>()?
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            span,
+                            format!("`{}` takes exactly one integer argument", name),
+                        ));
+                    }
+                };
+                if !n.is_power_of_two() || n > 1 << 29 {
+                    return Err(Error::new(
+                        span,
+                        format!("invalid `repr({})`: not a power of two up to 2^29", name),
+                    ));
+                }
+                set(slot, (n, span))
+            }
+            Meta::NameValue(_) => Err(unrecognized(span)),
+        }
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+fn set<T>(slot: &mut Option<T>, value: T) -> Result<()>
+where
+    T: ReprSpan,
+{
+    if slot.is_some() {
+        return Err(Error::new(value.span(), "conflicting representation hints"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+fn unrecognized(span: Span) -> Error {
+    Error::new(span, "unrecognized representation hint")
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+trait ReprSpan {
+    fn span(&self) -> Span;
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl ReprSpan for Span {
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl<T> ReprSpan for (T, Span) {
+    fn span(&self) -> Span {
+        self.1
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl ReprInt {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i8" => Some(ReprInt::I8),
+            "i16" => Some(ReprInt::I16),
+            "i32" => Some(ReprInt::I32),
+            "i64" => Some(ReprInt::I64),
+            "i128" => Some(ReprInt::I128),
+            "isize" => Some(ReprInt::Isize),
+            "u8" => Some(ReprInt::U8),
+            "u16" => Some(ReprInt::U16),
+            "u32" => Some(ReprInt::U32),
+            "u64" => Some(ReprInt::U64),
+            "u128" => Some(ReprInt::U128),
+            "usize" => Some(ReprInt::Usize),
+            _ => None,
+        }
+    }
+
+    /// The name of the integer type, like `"u8"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReprInt::I8 => "i8",
+            ReprInt::I16 => "i16",
+            ReprInt::I32 => "i32",
+            ReprInt::I64 => "i64",
+            ReprInt::I128 => "i128",
+            ReprInt::Isize => "isize",
+            ReprInt::U8 => "u8",
+            ReprInt::U16 => "u16",
+            ReprInt::U32 => "u32",
+            ReprInt::U64 => "u64",
+            ReprInt::U128 => "u128",
+            ReprInt::Usize => "usize",
+        }
+    }
+
+    /// Whether the integer type is signed.
+    pub fn is_signed(&self) -> bool {
+        self.as_str().starts_with('i')
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl Copy for ReprInt {}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl Clone for ReprInt {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl PartialEq for ReprInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl Eq for ReprInt {}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+impl Debug for ReprInt {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.as_str();
+        let mut variant = name[..1].to_uppercase();
+        variant.push_str(&name[1..]);
+        formatter.write_str(&variant)
+    }
+}
+
+/// A lint level attribute like `#[allow(dead_code)]` or
+/// `#[deny(clippy::all, reason = "...")]`.
+///
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"parsing"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, ItemFn, LintAttr, LintLevel};
+///
+/// let item: ItemFn = parse_quote! {
+///     #[inline]
+///     #[allow(clippy::too_many_arguments, reason = "mirrors the C API")]
+///     #[deny(unsafe_code)]
+///     fn f() {}
+/// };
+///
+/// let lints = LintAttr::from_attrs(&item.attrs).unwrap();
+/// assert_eq!(lints.len(), 2);
+/// assert_eq!(lints[0].level, LintLevel::Allow);
+/// assert_eq!(lints[0].reason.as_ref().unwrap().value(), "mirrors the C API");
+/// assert!(lints[1].lints[0].is_ident("unsafe_code"));
+/// ```
+#[cfg(feature = "parsing")]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "parsing"
+    )))
+)]
+pub struct LintAttr {
+    pub level: LintLevel,
+    /// The lints, like `dead_code` or `clippy::all`, in source order.
+    pub lints: Vec<Path>,
+    /// The `reason = "..."` at the end of the list, if any.
+    pub reason: Option<LitStr>,
+}
+
+/// The level set by a [`LintAttr`].
+///
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"parsing"` feature.*
+#[cfg(feature = "parsing")]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "parsing"
+    )))
+)]
+pub enum LintLevel {
+    /// `#[allow(...)]`
+    Allow,
+    /// `#[expect(...)]`
+    Expect,
+    /// `#[warn(...)]`
+    Warn,
+    /// `#[deny(...)]`
+    Deny,
+    /// `#[forbid(...)]`
+    Forbid,
+}
+
+#[cfg(feature = "parsing")]
+impl LintAttr {
+    /// Parses a lint level attribute.
+    ///
+    /// Returns `None` if the attribute is not one of `allow`, `expect`,
+    /// `warn`, `deny` or `forbid`.
+    pub fn from_attr(attr: &Attribute) -> Option<Result<Self>> {
+        let level = LintLevel::from_name(&attr.path.get_ident()?.to_string())?;
+        Some(attr.parse_args_with(|input: ParseStream| {
+            let mut lints = Vec::new();
+            let mut reason = None;
+            while !input.is_empty() {
+                if reason.is_some() {
+                    return Err(input.error("reason in lint attribute must come last"));
+                }
+                if input.peek(Ident) && input.peek2(Token![=]) {
+                    let key: Ident = input.parse()?;
+                    if key != "reason" {
+                        return Err(Error::new(key.span(), "expected `reason`"));
+                    }
+                    input.parse::<Token![=]>()?;
+                    reason = Some(input.parse()?);
+                } else {
+                    lints.push(input.call(Path::parse_mod_style)?);
+                }
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+            }
+            Ok(LintAttr {
+                level,
+                lints,
+                reason,
+            })
+        }))
+    }
+
+    /// Parses all lint level attributes in a list of attributes, in source
+    /// order. Other attributes are ignored. Errors from all malformed lint
+    /// attributes are combined.
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        let mut lint_attrs = Vec::new();
+        let mut errors: Option<Error> = None;
+        for attr in attrs {
+            match LintAttr::from_attr(attr) {
+                Some(Ok(lint_attr)) => lint_attrs.push(lint_attr),
+                Some(Err(err)) => match &mut errors {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                },
+                None => {}
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(lint_attrs),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl LintLevel {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "expect" => Some(LintLevel::Expect),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            "forbid" => Some(LintLevel::Forbid),
+            _ => None,
+        }
+    }
+
+    /// The name of the attribute that sets this level, like `"allow"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Expect => "expect",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+            LintLevel::Forbid => "forbid",
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Copy for LintLevel {}
+
+#[cfg(feature = "parsing")]
+impl Clone for LintLevel {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl PartialEq for LintLevel {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Eq for LintLevel {}
+
+#[cfg(feature = "parsing")]
+impl Debug for LintLevel {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            LintLevel::Allow => "Allow",
+            LintLevel::Expect => "Expect",
+            LintLevel::Warn => "Warn",
+            LintLevel::Deny => "Deny",
+            LintLevel::Forbid => "Forbid",
+        })
+    }
+}
+
 pub trait FilterAttrs<'a> {
     type Ret: Iterator<Item = &'a Attribute>;
 
@@ -593,6 +1636,64 @@ pub mod parsing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for CfgExpr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let ident = input.call(Ident::parse_any)?;
+            if input.peek(Token![=]) {
+                return Ok(CfgExpr::KeyValue(CfgKeyValue {
+                    key: ident,
+                    eq_token: input.parse()?,
+                    value: input.parse()?,
+                }));
+            }
+            if !input.peek(token::Paren) {
+                return Ok(CfgExpr::Key(ident));
+            }
+            let content;
+            let paren_token = parenthesized!(content in input);
+            if ident == "all" {
+                Ok(CfgExpr::All(CfgAll {
+                    ident,
+                    paren_token,
+                    predicates: content.parse_terminated(CfgExpr::parse)?,
+                }))
+            } else if ident == "any" {
+                Ok(CfgExpr::Any(CfgAny {
+                    ident,
+                    paren_token,
+                    predicates: content.parse_terminated(CfgExpr::parse)?,
+                }))
+            } else if ident == "not" {
+                let predicate: CfgExpr = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("expected 1 cfg-pattern"));
+                }
+                Ok(CfgExpr::Not(CfgNot {
+                    ident,
+                    paren_token,
+                    predicate: Box::new(predicate),
+                }))
+            } else {
+                Err(Error::new(
+                    ident.span(),
+                    format!("invalid predicate `{}`", ident),
+                ))
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for CfgKeyValue {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(CfgKeyValue {
+                key: input.call(Ident::parse_any)?,
+                eq_token: input.parse()?,
+                value: input.parse()?,
+            })
+        }
+    }
+
     pub fn parse_meta_after_path(path: Path, input: ParseStream) -> Result<Meta> {
         if input.peek(token::Paren) {
             parse_meta_list_after_path(path, input).map(Meta::List)
@@ -659,4 +1760,42 @@ mod printing {
             self.lit.to_tokens(tokens);
         }
     }
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for CfgKeyValue {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.key.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.value.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for CfgAll {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.ident.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                self.predicates.to_tokens(tokens);
+            });
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for CfgAny {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.ident.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                self.predicates.to_tokens(tokens);
+            });
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for CfgNot {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.ident.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                self.predicate.to_tokens(tokens);
+            });
+        }
+    }
 }
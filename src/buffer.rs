@@ -7,28 +7,27 @@
 // Syn, and caution should be used when editing it. The public-facing interface
 // is 100% safe but the implementation is fragile internally.
 
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
 use crate::proc_macro as pm;
 use crate::Lifetime;
 use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::cmp::Ordering;
 use std::marker::PhantomData;
-use std::ptr;
-use std::slice;
+use std::mem;
 
 /// Internal type which is used instead of `TokenTree` to represent a token tree
 /// within a `TokenBuffer`.
 enum Entry {
     // Mimicking types from proc-macro.
-    Group(Group, TokenBuffer),
+    // Group entries contain the offset to the matching End entry.
+    Group(Group, usize),
     Ident(Ident),
     Punct(Punct),
     Literal(Literal),
-    // End entries contain a raw pointer to the entry from the containing
-    // token tree, or null if this is the outermost level.
-    End(*const Entry),
+    // End entries contain the offset (negative) to the start of the buffer,
+    // and the offset (negative) to the matching Group entry, which is zero at
+    // the outermost level.
+    End(isize, isize),
 }
 
 /// A buffer that can be efficiently traversed multiple times, unlike
@@ -37,76 +36,40 @@ enum Entry {
 ///
 /// *This type is available only if Syn is built with the `"parsing"` feature.*
 pub struct TokenBuffer {
-    // NOTE: Do not implement clone on this - there are raw pointers inside
-    // these entries which will be messed up. Moving the `TokenBuffer` itself is
-    // safe as the data pointed to won't be moved.
-    ptr: *const Entry,
-    len: usize,
-}
-
-impl Drop for TokenBuffer {
-    fn drop(&mut self) {
-        unsafe {
-            let slice = slice::from_raw_parts_mut(self.ptr as *mut Entry, self.len);
-            let _ = Box::from_raw(slice);
-        }
-    }
+    // NOTE: Do not implement clone on this - while the current design could be
+    // cloned, other designs which could be desirable may not be cloneable.
+    //
+    // The entries are not modified while a cursor into the buffer exists, so
+    // a Vec is as stably addressed as a boxed slice, and keeps its capacity
+    // when the buffer is refilled by `reset`.
+    entries: Vec<Entry>,
 }
 
 impl TokenBuffer {
-    // NOTE: Do not mutate the Vec returned from this function once it returns;
-    // the address of its backing memory must remain stable.
-    fn inner_new(stream: TokenStream, up: *const Entry) -> TokenBuffer {
-        // Build up the entries list, recording the locations of any Groups
-        // in the list to be processed later.
-        let mut entries = Vec::new();
-        let mut groups = Vec::new();
+    // The tokens of a group are stored right after the group's own entry and
+    // are followed by an End entry, so the entries of the whole buffer are a
+    // depth-first traversal of the token trees.
+    fn recursive_new(entries: &mut Vec<Entry>, stream: TokenStream) {
         for tt in stream {
             match tt {
-                TokenTree::Ident(sym) => {
-                    entries.push(Entry::Ident(sym));
-                }
-                TokenTree::Punct(op) => {
-                    entries.push(Entry::Punct(op));
-                }
-                TokenTree::Literal(l) => {
-                    entries.push(Entry::Literal(l));
-                }
-                TokenTree::Group(g) => {
-                    // Record the index of the interesting entry, and store an
-                    // `End(null)` there temporarily.
-                    groups.push((entries.len(), g));
-                    entries.push(Entry::End(ptr::null()));
+                TokenTree::Ident(ident) => entries.push(Entry::Ident(ident)),
+                TokenTree::Punct(punct) => entries.push(Entry::Punct(punct)),
+                TokenTree::Literal(literal) => entries.push(Entry::Literal(literal)),
+                TokenTree::Group(group) => {
+                    let group_start_index = entries.len();
+                    // Replaced by the Group entry once its length is known.
+                    entries.push(Entry::End(0, 0));
+                    Self::recursive_new(entries, group.stream());
+                    let group_end_index = entries.len();
+                    let group_offset = group_end_index - group_start_index;
+                    entries.push(Entry::End(
+                        -(group_end_index as isize),
+                        -(group_offset as isize),
+                    ));
+                    entries[group_start_index] = Entry::Group(group, group_offset);
                 }
             }
         }
-        // Add an `End` entry to the end with a reference to the enclosing token
-        // stream which was passed in.
-        entries.push(Entry::End(up));
-
-        // NOTE: This is done to ensure that we don't accidentally modify the
-        // length of the backing buffer. The backing buffer must remain at a
-        // constant address after this point, as we are going to store a raw
-        // pointer into it.
-        let entries = entries.into_boxed_slice();
-        let len = entries.len();
-        // Convert boxed slice into a pointer to the first element early, to
-        // avoid invalidating pointers into this slice when we move the Box.
-        // See https://github.com/rust-lang/unsafe-code-guidelines/issues/326
-        let entries = Box::into_raw(entries) as *mut Entry;
-        for (idx, group) in groups {
-            // We know that this index refers to one of the temporary
-            // `End(null)` entries, and we know that the last entry is
-            // `End(up)`, so the next index is also valid.
-            let group_up = unsafe { entries.add(idx + 1) };
-
-            // The end entry stored at the end of this Entry::Group should
-            // point to the Entry which follows the Group in the list.
-            let inner = Self::inner_new(group.stream(), group_up);
-            unsafe { *entries.add(idx) = Entry::Group(group, inner) };
-        }
-
-        TokenBuffer { ptr: entries, len }
     }
 
     /// Creates a `TokenBuffer` containing all the tokens from the input
@@ -114,10 +77,7 @@ impl TokenBuffer {
     ///
     /// *This method is available only if Syn is built with both the `"parsing"` and
     /// `"proc-macro"` features.*
-    #[cfg(all(
-        not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-        feature = "proc-macro"
-    ))]
+    #[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
     pub fn new(stream: pm::TokenStream) -> Self {
         Self::new2(stream.into())
     }
@@ -125,13 +85,28 @@ impl TokenBuffer {
     /// Creates a `TokenBuffer` containing all the tokens from the input
     /// `proc_macro2::TokenStream`.
     pub fn new2(stream: TokenStream) -> Self {
-        Self::inner_new(stream, ptr::null())
+        let mut buffer = TokenBuffer {
+            entries: Vec::new(),
+        };
+        buffer.reset(stream);
+        buffer.entries.shrink_to_fit();
+        buffer
+    }
+
+    // Replaces the contents of the buffer with the tokens of `stream`, reusing
+    // the allocation of the previous contents.
+    pub(crate) fn reset(&mut self, stream: TokenStream) {
+        self.entries.clear();
+        Self::recursive_new(&mut self.entries, stream);
+        self.entries
+            .push(Entry::End(-(self.entries.len() as isize), 0));
     }
 
     /// Creates a cursor referencing the first token in the buffer and able to
     /// traverse until the end of the buffer.
     pub fn begin(&self) -> Cursor {
-        unsafe { Cursor::create(self.ptr, self.ptr.add(self.len - 1)) }
+        let ptr = self.entries[..].as_ptr();
+        unsafe { Cursor::create(ptr, ptr.add(self.entries.len() - 1)) }
     }
 }
 
@@ -147,6 +122,12 @@ impl TokenBuffer {
 /// Two cursors are equal if they have the same location in the same input
 /// stream, and have the same scope.
 ///
+/// Cursors into the same `TokenBuffer` are ordered by their [position] in it,
+/// so a cursor that has advanced compares greater than where it started. The
+/// order of cursors into different buffers is unspecified, but consistent.
+///
+/// [position]: Cursor::position
+///
 /// *This type is available only if Syn is built with the `"parsing"` feature.*
 pub struct Cursor<'a> {
     // The current entry which the `Cursor` is pointing at.
@@ -171,7 +152,7 @@ impl<'a> Cursor<'a> {
         // object in global storage.
         struct UnsafeSyncEntry(Entry);
         unsafe impl Sync for UnsafeSyncEntry {}
-        static EMPTY_ENTRY: UnsafeSyncEntry = UnsafeSyncEntry(Entry::End(0 as *const Entry));
+        static EMPTY_ENTRY: UnsafeSyncEntry = UnsafeSyncEntry(Entry::End(0, 0));
 
         Cursor {
             ptr: &EMPTY_ENTRY.0,
@@ -188,11 +169,11 @@ impl<'a> Cursor<'a> {
         // past it, unless `ptr == scope`, which means that we're at the edge of
         // our cursor's scope. We should only have `ptr != scope` at the exit
         // from None-delimited groups entered with `ignore_none`.
-        while let Entry::End(exit) = *ptr {
+        while let Entry::End(..) = *ptr {
             if ptr == scope {
                 break;
             }
-            ptr = exit;
+            ptr = ptr.add(1);
         }
 
         Cursor {
@@ -210,7 +191,10 @@ impl<'a> Cursor<'a> {
     /// Bump the cursor to point at the next token after the current one. This
     /// is undefined behavior if the cursor is currently looking at an
     /// `Entry::End`.
-    unsafe fn bump(self) -> Cursor<'a> {
+    ///
+    /// If the cursor is looking at an `Entry::Group`, the bumped cursor will
+    /// point at the first token within the group.
+    unsafe fn bump_ignore_group(self) -> Cursor<'a> {
         Cursor::create(self.ptr.offset(1), self.scope)
     }
 
@@ -220,13 +204,13 @@ impl<'a> Cursor<'a> {
     ///
     /// WARNING: This mutates its argument.
     fn ignore_none(&mut self) {
-        while let Entry::Group(group, buf) = self.entry() {
+        while let Entry::Group(group, _) = self.entry() {
             if group.delimiter() == Delimiter::None {
                 // NOTE: We call `Cursor::create` here to make sure that
                 // situations where we should immediately exit the span after
                 // entering it are handled correctly.
                 unsafe {
-                    *self = Cursor::create(buf.ptr, self.scope);
+                    *self = self.bump_ignore_group();
                 }
             } else {
                 break;
@@ -241,6 +225,56 @@ impl<'a> Cursor<'a> {
         self.ptr == self.scope
     }
 
+    /// Returns the position of the cursor within its `TokenBuffer`.
+    ///
+    /// Positions are numbered from 0 in the order that the tokens appear in
+    /// the buffer. Each token takes up one position. A group takes up one
+    /// position for its opening delimiter, followed by the positions of its
+    /// contents and one for its closing delimiter. The end of the buffer has
+    /// the last position.
+    ///
+    /// A cursor keeps its position for as long as the buffer lives, so
+    /// positions can be used as keys of a memo table.
+    pub fn position(self) -> usize {
+        (self.ptr as usize - self.buffer_start() as usize) / mem::size_of::<Entry>()
+    }
+
+    /// Returns how many positions `other` is ahead of this cursor.
+    ///
+    /// Returns `None` if `other` is behind this cursor, or if the cursors are
+    /// not into the same `TokenBuffer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::Result;
+    /// use syn::parse::ParseStream;
+    ///
+    /// fn parse_all(input: ParseStream, mut parse_one: impl FnMut(ParseStream) -> Result<()>) -> Result<()> {
+    ///     while !input.is_empty() {
+    ///         let before = input.cursor();
+    ///         parse_one(input)?;
+    ///         // A parser that succeeds without consuming input would loop
+    ///         // forever.
+    ///         assert_ne!(before.distance(input.cursor()), Some(0));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn distance(self, other: Cursor) -> Option<usize> {
+        if self.buffer_start() != other.buffer_start() || other.ptr < self.ptr {
+            return None;
+        }
+        Some(other.position() - self.position())
+    }
+
+    fn buffer_start(self) -> *const Entry {
+        match unsafe { &*self.scope } {
+            Entry::End(to_start, _) => unsafe { self.scope.offset(*to_start) },
+            _ => unreachable!(),
+        }
+    }
+
     /// If the cursor is pointing at a `Group` with the given delimiter, returns
     /// a cursor into that group and one pointing to the next `TokenTree`.
     pub fn group(mut self, delim: Delimiter) -> Option<(Cursor<'a>, Span, Cursor<'a>)> {
@@ -251,9 +285,12 @@ impl<'a> Cursor<'a> {
             self.ignore_none();
         }
 
-        if let Entry::Group(group, buf) = self.entry() {
+        if let Entry::Group(group, end_offset) = self.entry() {
             if group.delimiter() == delim {
-                return Some((buf.begin(), group.span(), unsafe { self.bump() }));
+                let end_of_group = unsafe { self.ptr.add(*end_offset) };
+                let inside_of_group = unsafe { Cursor::create(self.ptr.add(1), end_of_group) };
+                let after_group = unsafe { Cursor::create(end_of_group, self.scope) };
+                return Some((inside_of_group, group.span(), after_group));
             }
         }
 
@@ -265,7 +302,7 @@ impl<'a> Cursor<'a> {
     pub fn ident(mut self) -> Option<(Ident, Cursor<'a>)> {
         self.ignore_none();
         match self.entry() {
-            Entry::Ident(ident) => Some((ident.clone(), unsafe { self.bump() })),
+            Entry::Ident(ident) => Some((ident.clone(), unsafe { self.bump_ignore_group() })),
             _ => None,
         }
     }
@@ -275,7 +312,9 @@ impl<'a> Cursor<'a> {
     pub fn punct(mut self) -> Option<(Punct, Cursor<'a>)> {
         self.ignore_none();
         match self.entry() {
-            Entry::Punct(op) if op.as_char() != '\'' => Some((op.clone(), unsafe { self.bump() })),
+            Entry::Punct(op) if op.as_char() != '\'' => {
+                Some((op.clone(), unsafe { self.bump_ignore_group() }))
+            }
             _ => None,
         }
     }
@@ -285,7 +324,7 @@ impl<'a> Cursor<'a> {
     pub fn literal(mut self) -> Option<(Literal, Cursor<'a>)> {
         self.ignore_none();
         match self.entry() {
-            Entry::Literal(lit) => Some((lit.clone(), unsafe { self.bump() })),
+            Entry::Literal(lit) => Some((lit.clone(), unsafe { self.bump_ignore_group() })),
             _ => None,
         }
     }
@@ -296,7 +335,7 @@ impl<'a> Cursor<'a> {
         self.ignore_none();
         match self.entry() {
             Entry::Punct(op) if op.as_char() == '\'' && op.spacing() == Spacing::Joint => {
-                let next = unsafe { self.bump() };
+                let next = unsafe { self.bump_ignore_group() };
                 match next.ident() {
                     Some((ident, rest)) => {
                         let lifetime = Lifetime {
@@ -332,15 +371,16 @@ impl<'a> Cursor<'a> {
     /// This method does not treat `None`-delimited groups as transparent, and
     /// will return a `Group(None, ..)` if the cursor is looking at one.
     pub fn token_tree(self) -> Option<(TokenTree, Cursor<'a>)> {
-        let tree = match self.entry() {
-            Entry::Group(group, _) => group.clone().into(),
-            Entry::Literal(lit) => lit.clone().into(),
-            Entry::Ident(ident) => ident.clone().into(),
-            Entry::Punct(op) => op.clone().into(),
+        let (tree, len) = match self.entry() {
+            Entry::Group(group, end_offset) => (group.clone().into(), *end_offset),
+            Entry::Literal(lit) => (lit.clone().into(), 1),
+            Entry::Ident(ident) => (ident.clone().into(), 1),
+            Entry::Punct(op) => (op.clone().into(), 1),
             Entry::End(..) => return None,
         };
 
-        Some((tree, unsafe { self.bump() }))
+        let rest = unsafe { Cursor::create(self.ptr.add(len), self.scope) };
+        Some((tree, rest))
     }
 
     /// Returns the `Span` of the current token, or `Span::call_site()` if this
@@ -365,13 +405,16 @@ impl<'a> Cursor<'a> {
 
             // Treat lifetimes as a single tt for the purposes of 'skip'.
             Entry::Punct(op) if op.as_char() == '\'' && op.spacing() == Spacing::Joint => {
-                let next = unsafe { self.bump() };
+                let next = unsafe { self.bump_ignore_group() };
                 match next.entry() {
-                    Entry::Ident(_) => Some(unsafe { next.bump() }),
+                    Entry::Ident(_) => Some(unsafe { next.bump_ignore_group() }),
                     _ => Some(next),
                 }
             }
-            _ => Some(unsafe { self.bump() }),
+            Entry::Group(_, end_offset) => {
+                Some(unsafe { Cursor::create(self.ptr.add(*end_offset), self.scope) })
+            }
+            _ => Some(unsafe { self.bump_ignore_group() }),
         }
     }
 }
@@ -394,6 +437,20 @@ impl<'a> PartialEq for Cursor<'a> {
     }
 }
 
+impl<'a> PartialOrd for Cursor<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Cursor<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Entries of one buffer are laid out in order, so comparing addresses
+        // compares positions. Scope only breaks ties, to agree with `Eq`.
+        (self.ptr as usize, self.scope as usize).cmp(&(other.ptr as usize, other.scope as usize))
+    }
+}
+
 pub(crate) fn same_scope(a: Cursor, b: Cursor) -> bool {
     a.scope == b.scope
 }
@@ -411,3 +468,27 @@ pub(crate) fn close_span_of_group(cursor: Cursor) -> Span {
         _ => cursor.span(),
     }
 }
+
+// The span of the last token before the end of the cursor's scope, if any.
+pub(crate) fn last_span(mut cursor: Cursor) -> Option<Span> {
+    let mut last = None;
+    while let Some((tt, next)) = cursor.token_tree() {
+        last = Some(tt.span());
+        cursor = next;
+    }
+    last
+}
+
+// Whether the cursor's scope ends at the closing delimiter of a group, as
+// opposed to the end of the whole buffer.
+pub(crate) fn scope_is_group(cursor: Cursor) -> bool {
+    match unsafe { &*cursor.scope } {
+        Entry::End(_, group_offset) => *group_offset != 0,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "lexer")]
+pub(crate) fn entry_id(cursor: Cursor) -> usize {
+    cursor.ptr as usize
+}
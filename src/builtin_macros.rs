@@ -0,0 +1,1499 @@
+//! Parsers for the bodies of well-known standard library macros.
+//!
+//! A [`Macro`] invocation holds its body as an opaque [`TokenStream`]. For a
+//! handful of ubiquitous standard library macros the body has a fixed
+//! structure made of expressions and patterns, and the types in this module
+//! parse it into that structure:
+//!
+//! - [`FormatArgs`] for `format_args!` and the macros that forward to it, like
+//!   `format!`, `print!`, `println!`, `eprint!`, `eprintln!` and `panic!`;
+//! - [`VecMacro`] for `vec!`;
+//! - [`Matches`] for `matches!`;
+//! - [`Assert`] for `assert!` and `debug_assert!`.
+//!
+//! Macros in item or statement position declare items with a structure of
+//! their own:
+//!
+//! - [`ThreadLocal`] for `thread_local!`;
+//! - [`LazyStatic`] for `lazy_static!` of the `lazy_static` crate, which is
+//!   common enough to be treated like part of the standard library;
+//! - [`MacroRules`] for the rules of `macro_rules!`.
+//!
+//! Each of them implements [`Parse`] and so can be used with
+//! [`Macro::parse_body`]. [`MacroKind::of`] tells which of the macros an
+//! invocation is from its name, and [`BuiltinMacro::from_macro`] picks the
+//! right parser based on it.
+//!
+//! The format string of `format_args!` is itself a small language. See
+//! [`FormatString`] for splitting it into text and placeholders and matching
+//! the placeholders up with the arguments.
+//!
+//! The macro is recognized by the last segment of its path only, so a
+//! user-defined macro that shadows one of these names is parsed the same way.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"parsing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::builtin_macros::BuiltinMacro;
+//! use syn::{parse_quote, Macro};
+//!
+//! let mac: Macro = parse_quote!(println!("{} + {} = {sum}", a, b, sum = a + b));
+//!
+//! match BuiltinMacro::from_macro(&mac) {
+//!     Some(Ok(BuiltinMacro::FormatArgs(args))) => {
+//!         assert_eq!(args.args.len(), 3);
+//!         assert!(args.args[2].name.is_some());
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! [`TokenStream`]: proc_macro2::TokenStream
+
+use super::*;
+use crate::ext::IdentExt;
+use crate::parse::{Parse, ParseStream};
+use crate::punctuated::Punctuated;
+use proc_macro2::{Literal, Span, TokenStream};
+use std::fmt::{self, Debug};
+use std::mem;
+use std::ops::Range;
+
+/// The arguments of `format_args!` and the formatting macros built on it.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatArgs {
+    /// The format string, usually a string literal but possibly an arbitrary
+    /// expression such as a macro call producing one.
+    pub format: Expr,
+    /// The comma after the format string.
+    pub comma: Option<Token![,]>,
+    /// The arguments after the format string.
+    pub args: Punctuated<FormatArg, Token![,]>,
+}
+
+/// A single argument of `format_args!`: `x` or `width = 10`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatArg {
+    /// The name of a named argument along with its `=` token.
+    pub name: Option<(Ident, Token![=])>,
+    pub expr: Expr,
+}
+
+/// The body of `vec!`: either a list of elements `vec![a, b, c]` or a
+/// repetition `vec![elem; len]`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum VecMacro {
+    /// `vec![a, b, c]`, including the empty `vec![]`.
+    Elems(Punctuated<Expr, Token![,]>),
+    /// `vec![elem; len]`.
+    Repeat {
+        elem: Box<Expr>,
+        semi_token: Token![;],
+        len: Box<Expr>,
+    },
+}
+
+/// The body of `matches!`: `expr, pat` with an optional `if guard`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct Matches {
+    pub expr: Expr,
+    pub comma: Token![,],
+    pub pat: Pat,
+    pub guard: Option<(Token![if], Box<Expr>)>,
+    pub trailing_comma: Option<Token![,]>,
+}
+
+/// The body of `assert!`: a condition optionally followed by a panic message
+/// in the form accepted by `format_args!`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct Assert {
+    pub cond: Expr,
+    pub comma: Option<Token![,]>,
+    /// The custom panic message, if any.
+    pub message: Option<FormatArgs>,
+}
+
+/// The body of `thread_local!`: any number of thread-local statics.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct ThreadLocal {
+    pub statics: Vec<ThreadLocalStatic>,
+}
+
+/// A single static of `thread_local!`: `static NAME: Type = init;`, where
+/// the initializer may also be a block `const { init }`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct ThreadLocalStatic {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub static_token: Token![static],
+    pub ident: Ident,
+    pub colon_token: Token![:],
+    pub ty: Box<Type>,
+    pub eq_token: Token![=],
+    /// The `const` of a `const { init }` initializer, whose block is `expr`.
+    pub const_token: Option<Token![const]>,
+    pub expr: Box<Expr>,
+    /// The semicolon, which the last static may go without.
+    pub semi_token: Option<Token![;]>,
+}
+
+/// The body of `lazy_static!`: any number of lazily initialized statics.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct LazyStatic {
+    pub statics: Vec<LazyStaticItem>,
+}
+
+/// A single static of `lazy_static!`: `static ref NAME: Type = init;`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct LazyStaticItem {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub static_token: Token![static],
+    pub ref_token: Token![ref],
+    pub ident: Ident,
+    pub colon_token: Token![:],
+    pub ty: Box<Type>,
+    pub eq_token: Token![=],
+    pub expr: Box<Expr>,
+    pub semi_token: Token![;],
+}
+
+/// The body of `macro_rules!`: the rules of a declarative macro.
+///
+/// The name of the macro is not part of the body, but the `ident` of the
+/// [`ItemMacro`] in which the body appears.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct MacroRules {
+    pub rules: Vec<MacroRule>,
+}
+
+/// A single rule of `macro_rules!`: `(matcher) => { transcriber };`.
+///
+/// Neither the matcher nor the transcriber is parsed any further, as both
+/// may contain arbitrary tokens around the `$` fragments.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct MacroRule {
+    pub matcher: (MacroDelimiter, TokenStream),
+    pub fat_arrow_token: Token![=>],
+    pub transcriber: (MacroDelimiter, TokenStream),
+    /// The semicolon, which the last rule may go without.
+    pub semi_token: Option<Token![;]>,
+}
+
+/// Which one of the macros supported by this module an invocation is.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum MacroKind {
+    /// `format_args!` or one of the macros that forward to it.
+    FormatArgs,
+    Vec,
+    Matches,
+    /// `assert!` or `debug_assert!`.
+    Assert,
+    ThreadLocal,
+    LazyStatic,
+    MacroRules,
+}
+
+impl MacroKind {
+    /// The kind of macro that the last segment of the path of `mac` names, or
+    /// `None` if it is none of the supported macros.
+    pub fn of(mac: &Macro) -> Option<Self> {
+        let name = mac.path.segments.last()?.ident.to_string();
+        let kind = match name.as_str() {
+            "format_args" | "format" | "print" | "println" | "eprint" | "eprintln" | "panic"
+            | "unreachable" | "todo" | "unimplemented" => MacroKind::FormatArgs,
+            "vec" => MacroKind::Vec,
+            "matches" => MacroKind::Matches,
+            "assert" | "debug_assert" => MacroKind::Assert,
+            "thread_local" => MacroKind::ThreadLocal,
+            "lazy_static" => MacroKind::LazyStatic,
+            "macro_rules" => MacroKind::MacroRules,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    /// Whether the macro expands to items, and so is invoked in item or
+    /// statement position rather than as an expression.
+    pub fn is_item(self) -> bool {
+        match self {
+            MacroKind::ThreadLocal | MacroKind::LazyStatic | MacroKind::MacroRules => true,
+            MacroKind::FormatArgs | MacroKind::Vec | MacroKind::Matches | MacroKind::Assert => {
+                false
+            }
+        }
+    }
+}
+
+impl Copy for MacroKind {}
+
+impl Clone for MacroKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for MacroKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+
+impl Eq for MacroKind {}
+
+impl Debug for MacroKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            MacroKind::FormatArgs => "FormatArgs",
+            MacroKind::Vec => "Vec",
+            MacroKind::Matches => "Matches",
+            MacroKind::Assert => "Assert",
+            MacroKind::ThreadLocal => "ThreadLocal",
+            MacroKind::LazyStatic => "LazyStatic",
+            MacroKind::MacroRules => "MacroRules",
+        })
+    }
+}
+
+/// The parsed body of one of the macros supported by this module.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum BuiltinMacro {
+    FormatArgs(FormatArgs),
+    Vec(VecMacro),
+    Matches(Matches),
+    Assert(Assert),
+    ThreadLocal(ThreadLocal),
+    LazyStatic(LazyStatic),
+    MacroRules(MacroRules),
+}
+
+impl BuiltinMacro {
+    /// Parses the body of `mac` if the last segment of its path names one of
+    /// the supported macros.
+    ///
+    /// Returns `None` for any other macro, and `Some(Err(..))` if the macro is
+    /// recognized but its body does not have the expected structure.
+    pub fn from_macro(mac: &Macro) -> Option<Result<Self>> {
+        let body = match MacroKind::of(mac)? {
+            MacroKind::FormatArgs => {
+                if mac.tokens.is_empty() {
+                    // `panic!()` and friends take no arguments at all.
+                    return None;
+                }
+                mac.parse_body().map(BuiltinMacro::FormatArgs)
+            }
+            MacroKind::Vec => mac.parse_body().map(BuiltinMacro::Vec),
+            MacroKind::Matches => mac.parse_body().map(BuiltinMacro::Matches),
+            MacroKind::Assert => mac.parse_body().map(BuiltinMacro::Assert),
+            MacroKind::ThreadLocal => mac.parse_body().map(BuiltinMacro::ThreadLocal),
+            MacroKind::LazyStatic => mac.parse_body().map(BuiltinMacro::LazyStatic),
+            MacroKind::MacroRules => mac.parse_body().map(BuiltinMacro::MacroRules),
+        };
+        Some(body)
+    }
+}
+
+impl Parse for FormatArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let format: Expr = input.parse()?;
+        if input.is_empty() {
+            return Ok(FormatArgs {
+                format,
+                comma: None,
+                args: Punctuated::new(),
+            });
+        }
+        let comma: Token![,] = input.parse()?;
+        let args = Punctuated::parse_terminated(input)?;
+        Ok(FormatArgs {
+            format,
+            comma: Some(comma),
+            args,
+        })
+    }
+}
+
+impl Parse for FormatArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let ident = input.call(Ident::parse_any)?;
+            let eq_token: Token![=] = input.parse()?;
+            Some((ident, eq_token))
+        } else {
+            None
+        };
+        let expr: Expr = input.parse()?;
+        Ok(FormatArg { name, expr })
+    }
+}
+
+impl Parse for VecMacro {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.is_empty() {
+            return Ok(VecMacro::Elems(Punctuated::new()));
+        }
+        let first: Expr = input.parse()?;
+        if input.peek(Token![;]) {
+            let semi_token: Token![;] = input.parse()?;
+            let len: Expr = input.parse()?;
+            return Ok(VecMacro::Repeat {
+                elem: Box::new(first),
+                semi_token,
+                len: Box::new(len),
+            });
+        }
+        let mut elems = Punctuated::new();
+        elems.push_value(first);
+        while !input.is_empty() {
+            let punct: Token![,] = input.parse()?;
+            elems.push_punct(punct);
+            if input.is_empty() {
+                break;
+            }
+            let elem: Expr = input.parse()?;
+            elems.push_value(elem);
+        }
+        Ok(VecMacro::Elems(elems))
+    }
+}
+
+impl Parse for Matches {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let expr: Expr = input.parse()?;
+        let comma: Token![,] = input.parse()?;
+        let pat = pat::parsing::multi_pat_with_leading_vert(input)?;
+        let guard = if input.peek(Token![if]) {
+            let if_token: Token![if] = input.parse()?;
+            let guard: Expr = input.parse()?;
+            Some((if_token, Box::new(guard)))
+        } else {
+            None
+        };
+        let trailing_comma: Option<Token![,]> = input.parse()?;
+        Ok(Matches {
+            expr,
+            comma,
+            pat,
+            guard,
+            trailing_comma,
+        })
+    }
+}
+
+impl Parse for Assert {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let cond: Expr = input.parse()?;
+        let comma: Option<Token![,]> = input.parse()?;
+        let message = if comma.is_some() && !input.is_empty() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Assert {
+            cond,
+            comma,
+            message,
+        })
+    }
+}
+
+impl Parse for ThreadLocal {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut statics = Vec::new();
+        while !input.is_empty() {
+            let item: ThreadLocalStatic = input.parse()?;
+            let last = item.semi_token.is_none();
+            statics.push(item);
+            if last {
+                break;
+            }
+        }
+        Ok(ThreadLocal { statics })
+    }
+}
+
+impl Parse for ThreadLocalStatic {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis: Visibility = input.parse()?;
+        let static_token: Token![static] = input.parse()?;
+        let ident: Ident = input.parse()?;
+        let colon_token: Token![:] = input.parse()?;
+        let ty: Type = input.parse()?;
+        let eq_token: Token![=] = input.parse()?;
+        let (const_token, expr) = if input.peek(Token![const]) && input.peek2(token::Brace) {
+            let const_token: Token![const] = input.parse()?;
+            let block: ExprBlock = input.parse()?;
+            (Some(const_token), Expr::Block(block))
+        } else {
+            (None, input.parse()?)
+        };
+        let semi_token: Option<Token![;]> = input.parse()?;
+        Ok(ThreadLocalStatic {
+            attrs,
+            vis,
+            static_token,
+            ident,
+            colon_token,
+            ty: Box::new(ty),
+            eq_token,
+            const_token,
+            expr: Box::new(expr),
+            semi_token,
+        })
+    }
+}
+
+impl Parse for LazyStatic {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut statics = Vec::new();
+        while !input.is_empty() {
+            statics.push(input.parse()?);
+        }
+        Ok(LazyStatic { statics })
+    }
+}
+
+impl Parse for LazyStaticItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(LazyStaticItem {
+            attrs: input.call(Attribute::parse_outer)?,
+            vis: input.parse()?,
+            static_token: input.parse()?,
+            ref_token: input.parse()?,
+            ident: input.parse()?,
+            colon_token: input.parse()?,
+            ty: input.parse()?,
+            eq_token: input.parse()?,
+            expr: input.parse()?,
+            semi_token: input.parse()?,
+        })
+    }
+}
+
+impl Parse for MacroRules {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut rules = Vec::new();
+        while !input.is_empty() {
+            let rule: MacroRule = input.parse()?;
+            let last = rule.semi_token.is_none();
+            rules.push(rule);
+            if last {
+                break;
+            }
+        }
+        Ok(MacroRules { rules })
+    }
+}
+
+impl Parse for MacroRule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MacroRule {
+            matcher: mac::parse_delimiter(input)?,
+            fat_arrow_token: input.parse()?,
+            transcriber: mac::parse_delimiter(input)?,
+            semi_token: input.parse()?,
+        })
+    }
+}
+
+/// A `format!`-style format string split into literal text and placeholders.
+///
+/// Every piece records the byte range it occupies in the *value* of the
+/// string literal, that is after escape sequences have been processed.
+/// [`FormatString::span`] turns such a range into a span pointing into the
+/// literal in the source code, so that diagnostics about a single placeholder
+/// can be underlined precisely.
+///
+/// Pointing inside of a literal requires [`Literal::subspan`], which only
+/// works on a nightly compiler. Elsewhere the span of the whole literal is
+/// used instead.
+///
+/// [`Literal::subspan`]: proc_macro2::Literal::subspan
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::builtin_macros::{FormatArgRef, FormatPiece, FormatString};
+/// use syn::LitStr;
+///
+/// let lit: LitStr = syn::parse_str(r#""{name}: {:>8.2}""#).unwrap();
+/// let format = FormatString::parse(&lit).unwrap();
+///
+/// let args: Vec<&FormatArgRef> = format.placeholders().map(|p| &p.arg).collect();
+/// assert_eq!(args, [&FormatArgRef::Name("name".to_owned()), &FormatArgRef::Next]);
+///
+/// match &format.pieces[1] {
+///     FormatPiece::Text(text) => assert_eq!(text.text, ": "),
+///     FormatPiece::Placeholder(_) => unreachable!(),
+/// }
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatString {
+    /// The text and placeholders of the format string in order.
+    pub pieces: Vec<FormatPiece>,
+    token: Literal,
+    offsets: Option<Vec<usize>>,
+}
+
+/// A piece of a [`FormatString`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatPiece {
+    Text(FormatText),
+    Placeholder(Placeholder),
+}
+
+/// Literal text between placeholders, with `{{` and `}}` unescaped.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatText {
+    pub text: String,
+    pub range: Range<usize>,
+}
+
+/// A placeholder like `{}`, `{0}` or `{name:>width$}`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct Placeholder {
+    /// The argument being formatted.
+    pub arg: FormatArgRef,
+    /// The range of the argument reference; empty for [`FormatArgRef::Next`].
+    pub arg_range: Range<usize>,
+    pub spec: FormatSpec,
+    /// The range of the whole placeholder including the braces.
+    pub range: Range<usize>,
+}
+
+/// The format spec of a placeholder, the part after the `:`.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatSpec {
+    pub fill: Option<char>,
+    pub align: Option<FormatAlign>,
+    pub sign: Option<FormatSign>,
+    /// The `#` flag.
+    pub alternate: bool,
+    /// The `0` flag.
+    pub zero_pad: bool,
+    pub width: Option<FormatCount>,
+    pub precision: Option<FormatCount>,
+    /// The formatting trait: empty for `Display`, otherwise `?`, `x?`, `x`,
+    /// `e` and so on.
+    pub ty: String,
+    /// The range of the spec, not including the `:`. Empty if the placeholder
+    /// has no spec.
+    pub range: Range<usize>,
+}
+
+/// A reference to an argument from within a format string.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatArgRef {
+    /// The next positional argument: `{}`, or the `*` in `{:.*}`.
+    Next,
+    /// An explicit positional argument: `{0}` or `{:1$}`.
+    Index(usize),
+    /// A named argument or a captured variable: `{name}` or `{:width$}`.
+    Name(String),
+}
+
+/// The width or precision of a placeholder.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatCount {
+    /// A literal count like the `8` in `{:8}`.
+    Literal(usize),
+    /// A count taken from an argument like `{:1$}`, `{:width$}` or `{:.*}`,
+    /// along with the range of the reference.
+    Arg(FormatArgRef, Range<usize>),
+}
+
+/// Alignment of a placeholder.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatAlign {
+    /// `<`
+    Left,
+    /// `^`
+    Center,
+    /// `>`
+    Right,
+}
+
+/// Sign flag of a placeholder.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatSign {
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+}
+
+/// An argument reference resolved by [`FormatString::map_arguments`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub struct FormatArgUse {
+    /// The range of the reference in the format string.
+    pub range: Range<usize>,
+    pub target: FormatArgTarget,
+}
+
+/// What an argument reference in a format string refers to.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"parsing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub enum FormatArgTarget {
+    /// The argument at this index in [`FormatArgs::args`].
+    Index(usize),
+    /// A variable from the surrounding scope captured by name, like `x` in
+    /// `format!("{x}")` when there is no argument named `x`. The identifier
+    /// is spanned to the reference.
+    Captured(Ident),
+}
+
+impl Clone for FormatArgRef {
+    fn clone(&self) -> Self {
+        match self {
+            FormatArgRef::Next => FormatArgRef::Next,
+            FormatArgRef::Index(index) => FormatArgRef::Index(*index),
+            FormatArgRef::Name(name) => FormatArgRef::Name(name.clone()),
+        }
+    }
+}
+
+impl PartialEq for FormatArgRef {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FormatArgRef::Next, FormatArgRef::Next) => true,
+            (FormatArgRef::Index(a), FormatArgRef::Index(b)) => a == b,
+            (FormatArgRef::Name(a), FormatArgRef::Name(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FormatArgRef {}
+
+impl Debug for FormatArgRef {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatArgRef::Next => formatter.write_str("Next"),
+            FormatArgRef::Index(index) => formatter.debug_tuple("Index").field(index).finish(),
+            FormatArgRef::Name(name) => formatter.debug_tuple("Name").field(name).finish(),
+        }
+    }
+}
+
+impl Clone for FormatCount {
+    fn clone(&self) -> Self {
+        match self {
+            FormatCount::Literal(n) => FormatCount::Literal(*n),
+            FormatCount::Arg(arg, range) => FormatCount::Arg(arg.clone(), range.clone()),
+        }
+    }
+}
+
+impl PartialEq for FormatCount {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FormatCount::Literal(a), FormatCount::Literal(b)) => a == b,
+            (FormatCount::Arg(a, a_range), FormatCount::Arg(b, b_range)) => {
+                a == b && a_range == b_range
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FormatCount {}
+
+impl Debug for FormatCount {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatCount::Literal(n) => formatter.debug_tuple("Literal").field(n).finish(),
+            FormatCount::Arg(arg, range) => formatter
+                .debug_tuple("Arg")
+                .field(arg)
+                .field(range)
+                .finish(),
+        }
+    }
+}
+
+impl Copy for FormatAlign {}
+
+impl Clone for FormatAlign {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for FormatAlign {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FormatAlign::Left, FormatAlign::Left)
+            | (FormatAlign::Center, FormatAlign::Center)
+            | (FormatAlign::Right, FormatAlign::Right) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FormatAlign {}
+
+impl Debug for FormatAlign {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            FormatAlign::Left => "Left",
+            FormatAlign::Center => "Center",
+            FormatAlign::Right => "Right",
+        })
+    }
+}
+
+impl Copy for FormatSign {}
+
+impl Clone for FormatSign {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for FormatSign {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FormatSign::Plus, FormatSign::Plus) | (FormatSign::Minus, FormatSign::Minus) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FormatSign {}
+
+impl Debug for FormatSign {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            FormatSign::Plus => "Plus",
+            FormatSign::Minus => "Minus",
+        })
+    }
+}
+
+impl FormatString {
+    /// Parses the value of a string literal as a format string.
+    pub fn parse(lit: &LitStr) -> Result<Self> {
+        let value = lit.value();
+        let token = lit.token();
+        let offsets =
+            source_offsets(&token.to_string()).filter(|offsets| offsets.len() == value.len() + 1);
+        let mut format = FormatString {
+            pieces: Vec::new(),
+            token,
+            offsets,
+        };
+        let mut parser = FormatParser { s: &value, pos: 0 };
+        match parser.pieces() {
+            Ok(pieces) => {
+                format.pieces = pieces;
+                Ok(format)
+            }
+            Err((range, message)) => Err(Error::new(format.span(range), message)),
+        }
+    }
+
+    /// Iterates over the placeholders of the format string.
+    pub fn placeholders(&self) -> impl Iterator<Item = &Placeholder> {
+        self.pieces.iter().filter_map(|piece| match piece {
+            FormatPiece::Placeholder(placeholder) => Some(placeholder),
+            FormatPiece::Text(_) => None,
+        })
+    }
+
+    /// The span of a range of the format string.
+    ///
+    /// This points at the corresponding part of the literal if the compiler
+    /// supports it, and at the whole literal otherwise.
+    pub fn span(&self, range: Range<usize>) -> Span {
+        if let Some(offsets) = &self.offsets {
+            if range.start <= range.end && range.end < offsets.len() {
+                let start = offsets[range.start];
+                let end = offsets[range.end];
+                if let Some(span) = self.token.subspan(start..end) {
+                    return span;
+                }
+            }
+        }
+        self.token.span()
+    }
+
+    /// Resolves every argument reference in the format string against the
+    /// given arguments, following the rules of `format_args!`.
+    ///
+    /// References are returned in the order in which `format_args!` consumes
+    /// them: for each placeholder its width, then its precision, then the
+    /// value itself. An error is returned for a positional reference past the
+    /// end of the arguments and for an argument that is never referenced.
+    pub fn map_arguments(&self, args: &FormatArgs) -> Result<Vec<FormatArgUse>> {
+        let mut mapper = ArgMapper {
+            format: self,
+            args,
+            used: vec![false; args.args.len()],
+            next: 0,
+            uses: Vec::new(),
+            errors: None,
+        };
+        for placeholder in self.placeholders() {
+            for count in &[&placeholder.spec.width, &placeholder.spec.precision] {
+                if let Some(FormatCount::Arg(arg, range)) = count {
+                    mapper.resolve(arg, range);
+                }
+            }
+            mapper.resolve(&placeholder.arg, &placeholder.arg_range);
+        }
+        for (i, arg) in args.args.iter().enumerate() {
+            if !mapper.used[i] {
+                let message = if arg.name.is_some() {
+                    "named argument never used"
+                } else {
+                    "argument never used"
+                };
+                mapper.error(arg_error(arg, message));
+            }
+        }
+        match mapper.errors {
+            Some(errors) => Err(errors),
+            None => Ok(mapper.uses),
+        }
+    }
+}
+
+impl FormatArgs {
+    /// Parses the format string if it is a string literal.
+    pub fn format_string(&self) -> Result<FormatString> {
+        match &self.format {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => FormatString::parse(lit),
+            other => Err(arg_error(other, "format argument must be a string literal")),
+        }
+    }
+
+    /// Parses the format string and resolves its argument references against
+    /// the arguments. See [`FormatString::map_arguments`].
+    pub fn map_arguments(&self) -> Result<(FormatString, Vec<FormatArgUse>)> {
+        let format = self.format_string()?;
+        let uses = format.map_arguments(self)?;
+        Ok((format, uses))
+    }
+}
+
+#[cfg(feature = "printing")]
+fn arg_error<T: quote::ToTokens>(tokens: T, message: &str) -> Error {
+    Error::new_spanned(tokens, message)
+}
+
+#[cfg(not(feature = "printing"))]
+fn arg_error<T>(_tokens: T, message: &str) -> Error {
+    Error::new(Span::call_site(), message)
+}
+
+struct ArgMapper<'a> {
+    format: &'a FormatString,
+    args: &'a FormatArgs,
+    used: Vec<bool>,
+    next: usize,
+    uses: Vec<FormatArgUse>,
+    errors: Option<Error>,
+}
+
+impl<'a> ArgMapper<'a> {
+    fn resolve(&mut self, arg: &FormatArgRef, range: &Range<usize>) {
+        let index = match arg {
+            FormatArgRef::Next => {
+                self.next += 1;
+                self.next - 1
+            }
+            FormatArgRef::Index(index) => *index,
+            FormatArgRef::Name(name) => {
+                let position = self.args.args.iter().position(|arg| match &arg.name {
+                    Some((ident, _eq_token)) => ident == name,
+                    None => false,
+                });
+                match position {
+                    Some(index) => index,
+                    None => {
+                        let ident = Ident::new(name, self.format.span(range.clone()));
+                        self.uses.push(FormatArgUse {
+                            range: range.clone(),
+                            target: FormatArgTarget::Captured(ident),
+                        });
+                        return;
+                    }
+                }
+            }
+        };
+        let len = self.args.args.len();
+        if index < len {
+            self.used[index] = true;
+            self.uses.push(FormatArgUse {
+                range: range.clone(),
+                target: FormatArgTarget::Index(index),
+            });
+        } else {
+            let there_are = match len {
+                0 => "no arguments were given".to_owned(),
+                1 => "there is 1 argument".to_owned(),
+                _ => format!("there are {} arguments", len),
+            };
+            let message = format!(
+                "invalid reference to positional argument {} ({})",
+                index, there_are,
+            );
+            let span = if range.start == range.end {
+                self.format.span(self.enclosing_placeholder(range.start))
+            } else {
+                self.format.span(range.clone())
+            };
+            self.error(Error::new(span, message));
+        }
+    }
+
+    fn enclosing_placeholder(&self, pos: usize) -> Range<usize> {
+        self.format
+            .placeholders()
+            .map(|placeholder| placeholder.range.clone())
+            .find(|range| range.start <= pos && pos <= range.end)
+            .unwrap_or(pos..pos)
+    }
+
+    fn error(&mut self, error: Error) {
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+}
+
+struct FormatParser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+type FormatError = (Range<usize>, String);
+
+impl<'a> FormatParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn peek2(&self) -> Option<char> {
+        let mut chars = self.s[self.pos..].chars();
+        chars.next();
+        chars.next()
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += ch.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn pieces(&mut self) -> std::result::Result<Vec<FormatPiece>, FormatError> {
+        let mut pieces = Vec::new();
+        loop {
+            let start = self.pos;
+            let mut text = String::new();
+            while let Some(ch) = self.peek() {
+                if (ch == '{' || ch == '}') && self.peek2() == Some(ch) {
+                    text.push(ch);
+                    self.pos += 2;
+                } else if ch == '{' {
+                    break;
+                } else if ch == '}' {
+                    return Err((
+                        self.pos..self.pos + 1,
+                        "invalid format string: unmatched `}` found".to_owned(),
+                    ));
+                } else {
+                    text.push(ch);
+                    self.pos += ch.len_utf8();
+                }
+            }
+            if self.pos > start {
+                pieces.push(FormatPiece::Text(FormatText {
+                    text,
+                    range: start..self.pos,
+                }));
+            }
+            if self.pos == self.s.len() {
+                return Ok(pieces);
+            }
+            pieces.push(FormatPiece::Placeholder(self.placeholder()?));
+        }
+    }
+
+    fn placeholder(&mut self) -> std::result::Result<Placeholder, FormatError> {
+        let open = self.pos;
+        self.pos += 1;
+        let arg_start = self.pos;
+        let arg = if let Some(index) = self.integer()? {
+            FormatArgRef::Index(index)
+        } else if let Some(name) = self.identifier() {
+            FormatArgRef::Name(name)
+        } else {
+            FormatArgRef::Next
+        };
+        let arg_range = arg_start..self.pos;
+        let spec = if self.eat(':') {
+            self.spec()?
+        } else {
+            FormatSpec {
+                fill: None,
+                align: None,
+                sign: None,
+                alternate: false,
+                zero_pad: false,
+                width: None,
+                precision: None,
+                ty: String::new(),
+                range: self.pos..self.pos,
+            }
+        };
+        if !self.eat('}') {
+            let message = match self.peek() {
+                Some(ch) => format!("invalid format string: expected `}}`, found `{}`", ch),
+                None => "invalid format string: expected `}` but string was terminated".to_owned(),
+            };
+            return Err((open..self.pos, message));
+        }
+        Ok(Placeholder {
+            arg,
+            arg_range,
+            spec,
+            range: open..self.pos,
+        })
+    }
+
+    fn spec(&mut self) -> std::result::Result<FormatSpec, FormatError> {
+        let start = self.pos;
+
+        let mut fill = None;
+        let mut align = self.peek2().and_then(align_of);
+        if align.is_some() {
+            let ch = self.peek().unwrap();
+            fill = Some(ch);
+            self.pos += ch.len_utf8() + 1;
+        } else {
+            align = self.peek().and_then(align_of);
+            if align.is_some() {
+                self.pos += 1;
+            }
+        }
+
+        let sign = if self.eat('+') {
+            Some(FormatSign::Plus)
+        } else if self.eat('-') {
+            Some(FormatSign::Minus)
+        } else {
+            None
+        };
+
+        let alternate = self.eat('#');
+
+        // In `{:0$}` the zero is a width argument rather than a flag.
+        let zero_pad = self.peek() == Some('0') && self.peek2() != Some('$') && self.eat('0');
+
+        let width = self.count()?;
+
+        let precision = if self.eat('.') {
+            let star = self.pos;
+            if self.eat('*') {
+                Some(FormatCount::Arg(FormatArgRef::Next, star..self.pos))
+            } else {
+                match self.count()? {
+                    Some(count) => Some(count),
+                    None => {
+                        return Err((
+                            star - 1..self.pos,
+                            "invalid format string: expected precision after `.`".to_owned(),
+                        ));
+                    }
+                }
+            }
+        } else {
+            None
+        };
+
+        let mut ty = if self.eat('?') {
+            "?".to_owned()
+        } else {
+            self.identifier().unwrap_or_default()
+        };
+        if !ty.is_empty() && ty != "?" && self.eat('?') {
+            ty.push('?');
+        }
+
+        Ok(FormatSpec {
+            fill,
+            align,
+            sign,
+            alternate,
+            zero_pad,
+            width,
+            precision,
+            ty,
+            range: start..self.pos,
+        })
+    }
+
+    fn count(&mut self) -> std::result::Result<Option<FormatCount>, FormatError> {
+        let start = self.pos;
+        if let Some(n) = self.integer()? {
+            if self.eat('$') {
+                let range = start..self.pos - 1;
+                return Ok(Some(FormatCount::Arg(FormatArgRef::Index(n), range)));
+            }
+            return Ok(Some(FormatCount::Literal(n)));
+        }
+        if let Some(name) = self.identifier() {
+            if self.eat('$') {
+                let range = start..self.pos - 1;
+                return Ok(Some(FormatCount::Arg(FormatArgRef::Name(name), range)));
+            }
+            // Not a count after all but the formatting trait, as in `{:x}`.
+            self.pos = start;
+        }
+        Ok(None)
+    }
+
+    fn integer(&mut self) -> std::result::Result<Option<usize>, FormatError> {
+        let start = self.pos;
+        while let Some('0'..='9') = self.peek() {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Ok(None);
+        }
+        match self.s[start..self.pos].parse() {
+            Ok(n) => Ok(Some(n)),
+            Err(_) => Err((
+                start..self.pos,
+                "invalid format string: integer is too large".to_owned(),
+            )),
+        }
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        let start = self.pos;
+        match self.peek() {
+            Some(ch) if ch == '_' || ch.is_alphabetic() => self.pos += ch.len_utf8(),
+            _ => return None,
+        }
+        while let Some(ch) = self.peek() {
+            if ch == '_' || ch.is_alphanumeric() {
+                self.pos += ch.len_utf8();
+            } else {
+                break;
+            }
+        }
+        Some(self.s[start..self.pos].to_owned())
+    }
+}
+
+fn align_of(ch: char) -> Option<FormatAlign> {
+    match ch {
+        '<' => Some(FormatAlign::Left),
+        '^' => Some(FormatAlign::Center),
+        '>' => Some(FormatAlign::Right),
+        _ => None,
+    }
+}
+
+// Maps each byte offset in the value of a string literal, plus the offset one
+// past the end, to the byte offset in the source representation of the
+// literal where the character containing it begins.
+fn source_offsets(repr: &str) -> Option<Vec<usize>> {
+    let bytes = repr.as_bytes();
+    let end = repr.rfind('"')?;
+    if repr.starts_with('r') {
+        let start = repr[1..].find('"')? + 2;
+        return Some((start..=end).collect());
+    }
+    if !repr.starts_with('"') {
+        return None;
+    }
+    let mut offsets = Vec::new();
+    let mut i = 1;
+    while i < end {
+        match bytes[i] {
+            b'\\' => match *bytes.get(i + 1)? {
+                b'x' => {
+                    offsets.push(i);
+                    i += 4;
+                }
+                b'u' => {
+                    let close = i + repr[i..].find('}')?;
+                    let digits = repr[i + 3..close].replace('_', "");
+                    let ch = std::char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?;
+                    for _ in 0..ch.len_utf8() {
+                        offsets.push(i);
+                    }
+                    i = close + 1;
+                }
+                b'\n' | b'\r' => {
+                    i += 1;
+                    while i < end && bytes[i].is_ascii_whitespace() {
+                        i += 1;
+                    }
+                }
+                _ => {
+                    offsets.push(i);
+                    i += 2;
+                }
+            },
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                offsets.push(i);
+                i += 2;
+            }
+            _ => {
+                let len = repr[i..].chars().next()?.len_utf8();
+                for k in 0..len {
+                    offsets.push(i + k);
+                }
+                i += len;
+            }
+        }
+    }
+    offsets.push(end);
+    Some(offsets)
+}
+
+#[cfg(feature = "printing")]
+mod printing {
+    use super::*;
+    use proc_macro2::TokenStream;
+    use quote::{ToTokens, TokenStreamExt};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for FormatArgs {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.format.to_tokens(tokens);
+            self.comma.to_tokens(tokens);
+            self.args.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for FormatArg {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            if let Some((name, eq_token)) = &self.name {
+                name.to_tokens(tokens);
+                eq_token.to_tokens(tokens);
+            }
+            self.expr.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for VecMacro {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                VecMacro::Elems(elems) => elems.to_tokens(tokens),
+                VecMacro::Repeat {
+                    elem,
+                    semi_token,
+                    len,
+                } => {
+                    elem.to_tokens(tokens);
+                    semi_token.to_tokens(tokens);
+                    len.to_tokens(tokens);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Matches {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.expr.to_tokens(tokens);
+            self.comma.to_tokens(tokens);
+            self.pat.to_tokens(tokens);
+            if let Some((if_token, guard)) = &self.guard {
+                if_token.to_tokens(tokens);
+                guard.to_tokens(tokens);
+            }
+            self.trailing_comma.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Assert {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.cond.to_tokens(tokens);
+            self.comma.to_tokens(tokens);
+            self.message.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ThreadLocal {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(&self.statics);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ThreadLocalStatic {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(&self.attrs);
+            self.vis.to_tokens(tokens);
+            self.static_token.to_tokens(tokens);
+            self.ident.to_tokens(tokens);
+            self.colon_token.to_tokens(tokens);
+            self.ty.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.const_token.to_tokens(tokens);
+            self.expr.to_tokens(tokens);
+            self.semi_token.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for LazyStatic {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(&self.statics);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for LazyStaticItem {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(&self.attrs);
+            self.vis.to_tokens(tokens);
+            self.static_token.to_tokens(tokens);
+            self.ref_token.to_tokens(tokens);
+            self.ident.to_tokens(tokens);
+            self.colon_token.to_tokens(tokens);
+            self.ty.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.expr.to_tokens(tokens);
+            self.semi_token.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRules {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(&self.rules);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRule {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            delimited(&self.matcher, tokens);
+            self.fat_arrow_token.to_tokens(tokens);
+            delimited(&self.transcriber, tokens);
+            self.semi_token.to_tokens(tokens);
+        }
+    }
+
+    fn delimited(group: &(MacroDelimiter, TokenStream), tokens: &mut TokenStream) {
+        let (delimiter, inner) = group;
+        match delimiter {
+            MacroDelimiter::Paren(paren) => {
+                paren.surround(tokens, |tokens| inner.to_tokens(tokens))
+            }
+            MacroDelimiter::Brace(brace) => {
+                brace.surround(tokens, |tokens| inner.to_tokens(tokens))
+            }
+            MacroDelimiter::Bracket(bracket) => {
+                bracket.surround(tokens, |tokens| inner.to_tokens(tokens));
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for BuiltinMacro {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                BuiltinMacro::FormatArgs(args) => args.to_tokens(tokens),
+                BuiltinMacro::Vec(vec) => vec.to_tokens(tokens),
+                BuiltinMacro::Matches(matches) => matches.to_tokens(tokens),
+                BuiltinMacro::Assert(assert) => assert.to_tokens(tokens),
+                BuiltinMacro::ThreadLocal(thread_local) => thread_local.to_tokens(tokens),
+                BuiltinMacro::LazyStatic(lazy_static) => lazy_static.to_tokens(tokens),
+                BuiltinMacro::MacroRules(rules) => rules.to_tokens(tokens),
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Parsers as values that can be named and chained.
+//!
+//! Syn's own parsers are functions of the form `fn(ParseStream) ->
+//! Result<T>`, which are called on a stream with [`ParseBuffer::call`] and
+//! combined by writing ordinary code in between the calls. Code that builds
+//! its grammar out of parser combinators instead needs parsers that are
+//! values. [`Combinator`] is implemented for every such function and closure,
+//! and gives them methods for naming them with [`named`], for transforming
+//! their output with [`and_then`], and for trying another parser in case one
+//! fails with [`or_else`]. The resulting parsers are themselves combinators
+//! and [`Parser`]s, so they can be run on a stream with
+//! [`parse_stream`](Combinator::parse_stream) or on tokens with
+//! [`Parser::parse2`] and [`Parser::parse_str`]. References to them are
+//! parsers as well, for running one parser more than once.
+//!
+//! [`named`]: Combinator::named
+//! [`and_then`]: Combinator::and_then
+//! [`or_else`]: Combinator::or_else
+//!
+//! *This module is available only if Syn is built with the `"parsing"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::parse::combinator::Combinator;
+//! use syn::parse::{ParseStream, Parser};
+//! use syn::{Error, Ident, LitInt, Result};
+//!
+//! fn level(input: ParseStream) -> Result<u8> {
+//!     let lit: LitInt = input.parse()?;
+//!     lit.base10_parse()
+//! }
+//!
+//! fn named_level(input: ParseStream) -> Result<u8> {
+//!     let ident: Ident = input.parse()?;
+//!     match ident.to_string().as_str() {
+//!         "low" => Ok(0),
+//!         "high" => Ok(9),
+//!         _ => Err(Error::new(ident.span(), "unknown level")),
+//!     }
+//! }
+//!
+//! let parser = &level
+//!     .or_else(named_level)
+//!     .and_then(|level| {
+//!         if level < 10 {
+//!             Ok(level)
+//!         } else {
+//!             Err(Error::new(proc_macro2::Span::call_site(), "level too high"))
+//!         }
+//!     })
+//!     .named("a level");
+//!
+//! assert_eq!(parser.parse_str("high").unwrap(), 9);
+//! assert_eq!(parser.parse_str("3").unwrap(), 3);
+//! assert_eq!(parser.parse_str("").unwrap_err().to_string(), "expected a level");
+//! ```
+
+use super::discouraged::Speculative;
+use super::*;
+
+/// A parser that is a value, which can be named and chained.
+///
+/// This trait is implemented for every function and closure of the form
+/// `Fn(ParseStream) -> Result<T>`, and for the parsers built out of them by
+/// its methods. See the [module documentation](self) for an example.
+pub trait Combinator: Sized {
+    type Output;
+
+    /// Runs the parser on a parse stream.
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output>;
+
+    /// Names the parser, for errors of the form "expected `name`".
+    ///
+    /// If the parser fails, the error starts with the message "expected
+    /// {name}" at the position where the parser started, followed by the
+    /// messages of the parser's own error.
+    fn named(self, name: &'static str) -> Named<Self> {
+        Named { parser: self, name }
+    }
+
+    /// Transforms the output of the parser with a function that may fail.
+    fn and_then<F, U>(self, function: F) -> AndThen<Self, F>
+    where
+        F: Fn(Self::Output) -> Result<U>,
+    {
+        AndThen {
+            parser: self,
+            function,
+        }
+    }
+
+    /// Tries another parser at the same position if this one fails.
+    ///
+    /// This parser is run on a fork of the input, so that it consumes nothing
+    /// if it fails. If the other parser fails too, the error has the messages
+    /// of both errors.
+    fn or_else<P>(self, other: P) -> OrElse<Self, P>
+    where
+        P: Combinator<Output = Self::Output>,
+    {
+        OrElse {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+impl<F, T> Combinator for F
+where
+    F: Fn(ParseStream) -> Result<T>,
+{
+    type Output = T;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<T> {
+        let _recursion = enter_recursion(input)?;
+        self(input)
+    }
+}
+
+/// A parser with a name, returned by [`Combinator::named`].
+pub struct Named<P> {
+    parser: P,
+    name: &'static str,
+}
+
+impl<P: Combinator> Combinator for Named<P> {
+    type Output = P::Output;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<P::Output> {
+        let span = input.span();
+        self.parser.parse_stream(input).map_err(|err| {
+            let mut named = Error::new(span, format!("expected {}", self.name));
+            named.combine(err);
+            named
+        })
+    }
+}
+
+/// A parser whose output is transformed, returned by [`Combinator::and_then`].
+pub struct AndThen<P, F> {
+    parser: P,
+    function: F,
+}
+
+impl<P, F, U> Combinator for AndThen<P, F>
+where
+    P: Combinator,
+    F: Fn(P::Output) -> Result<U>,
+{
+    type Output = U;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<U> {
+        let output = self.parser.parse_stream(input)?;
+        (self.function)(output)
+    }
+}
+
+/// A choice between two parsers, returned by [`Combinator::or_else`].
+pub struct OrElse<P, Q> {
+    first: P,
+    second: Q,
+}
+
+impl<P, Q> Combinator for OrElse<P, Q>
+where
+    P: Combinator,
+    Q: Combinator<Output = P::Output>,
+{
+    type Output = P::Output;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<P::Output> {
+        let ahead = input.fork();
+        let first_err = match self.first.parse_stream(&ahead) {
+            Ok(output) => {
+                input.advance_to(&ahead);
+                return Ok(output);
+            }
+            Err(err) => err,
+        };
+        self.second.parse_stream(input).map_err(|err| {
+            let mut combined = first_err;
+            combined.combine(err);
+            combined
+        })
+    }
+}
+
+macro_rules! impl_parser {
+    ($($ty:ident<$($param:ident),*>)*) => {
+        $(
+            impl<$($param),*> Parser for $ty<$($param),*>
+            where
+                $ty<$($param),*>: Combinator,
+            {
+                type Output = <Self as Combinator>::Output;
+
+                fn parse2(self, tokens: TokenStream) -> Result<Self::Output> {
+                    (|input: ParseStream| self.parse_stream(input)).parse2(tokens)
+                }
+            }
+
+            impl<'r, $($param),*> Parser for &'r $ty<$($param),*>
+            where
+                $ty<$($param),*>: Combinator,
+            {
+                type Output = <$ty<$($param),*> as Combinator>::Output;
+
+                fn parse2(self, tokens: TokenStream) -> Result<Self::Output> {
+                    (|input: ParseStream| self.parse_stream(input)).parse2(tokens)
+                }
+            }
+        )*
+    };
+}
+
+impl_parser!(Named<P> AndThen<P, F> OrElse<P, Q>);
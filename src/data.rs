@@ -1,5 +1,6 @@
 use super::*;
 use crate::punctuated::Punctuated;
+use proc_macro2::Span;
 
 ast_struct! {
     /// An enum variant.
@@ -112,6 +113,36 @@ impl Fields {
             Fields::Unnamed(f) => f.unnamed.is_empty(),
         }
     }
+
+    /// Returns a pattern that destructures a struct or enum variant with
+    /// these fields, binding each field to a variable `__binding_0`,
+    /// `__binding_1`, and so on.
+    ///
+    /// The `path` is the path of the struct or variant, such as `Self` or
+    /// `Self::Variant`. See [`FieldsPattern`] for an example.
+    pub fn pattern(&self, path: Path) -> FieldsPattern<'_> {
+        let bindings = (0..self.len())
+            .map(|i| Ident::new(&format!("__binding_{}", i), Span::call_site()))
+            .collect();
+        FieldsPattern {
+            path,
+            fields: self,
+            bindings,
+        }
+    }
+}
+
+impl Variant {
+    /// Returns a pattern that matches this variant as `Self::Variant` and
+    /// binds each of its fields, for use in the body of an impl for the
+    /// enum.
+    ///
+    /// See [`Fields::pattern`].
+    pub fn pattern(&self) -> FieldsPattern<'_> {
+        let mut path = Path::from(Ident::new("Self", Span::call_site()));
+        path.segments.push(PathSegment::from(self.ident.clone()));
+        self.fields.pattern(path)
+    }
 }
 
 impl IntoIterator for Fields {
@@ -145,6 +176,81 @@ impl<'a> IntoIterator for &'a mut Fields {
     }
 }
 
+/// A pattern destructuring a struct or enum variant into one variable per
+/// field, as returned by [`Fields::pattern`] and [`Variant::pattern`].
+///
+/// Printing the pattern produces `Path { a: __binding_0, b: __binding_1 }`
+/// for named fields, `Path(__binding_0, __binding_1)` for unnamed fields and
+/// just `Path` for a unit struct or variant.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// # use proc_macro2::TokenStream;
+/// # use quote::quote;
+/// # use syn::{Data, DeriveInput};
+/// #
+/// // Generate the body of `fn count(&self) -> usize` returning the number of
+/// // fields of the enum variant that `self` is.
+/// fn count_fields(input: &DeriveInput) -> TokenStream {
+///     let data = match &input.data {
+///         Data::Enum(data) => data,
+///         _ => unimplemented!(),
+///     };
+///     let arms = data.variant_patterns().into_iter().map(|pat| {
+///         let count = pat.bindings.len();
+///         let bindings = &pat.bindings;
+///         quote! {
+///             #pat => {
+///                 #(let _ = #bindings;)*
+///                 #count
+///             }
+///         }
+///     });
+///     quote! {
+///         match self {
+///             #(#arms)*
+///         }
+///     }
+/// }
+/// #
+/// # let input: DeriveInput = syn::parse_quote! {
+/// #     enum E { A { x: u8 }, B(u8, u8), C }
+/// # };
+/// # let expected = quote! {
+/// #     match self {
+/// #         Self::A { x: __binding_0 } => { let _ = __binding_0; 1usize }
+/// #         Self::B(__binding_0, __binding_1) => {
+/// #             let _ = __binding_0;
+/// #             let _ = __binding_1;
+/// #             2usize
+/// #         }
+/// #         Self::C => { 0usize }
+/// #     }
+/// # };
+/// # assert_eq!(count_fields(&input).to_string(), expected.to_string());
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub struct FieldsPattern<'a> {
+    /// Path of the struct or enum variant being matched.
+    pub path: Path,
+    /// Fields of the struct or enum variant.
+    pub fields: &'a Fields,
+    /// The variable bound to each field, in the order of the fields.
+    pub bindings: Vec<Ident>,
+}
+
+impl<'a> FieldsPattern<'a> {
+    /// Get an iterator over each field together with the variable it is
+    /// bound to.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Field, &Ident)> {
+        self.fields.iter().zip(&self.bindings)
+    }
+}
+
 ast_struct! {
     /// A field of a struct or enum variant.
     ///
@@ -236,6 +342,113 @@ ast_struct! {
     }
 }
 
+impl Visibility {
+    /// Whether this is an unrestricted `pub` visibility.
+    pub fn is_public(&self) -> bool {
+        match self {
+            Visibility::Public(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether no visibility was written.
+    ///
+    /// This is true only for `Visibility::Inherited`, not for an explicit
+    /// `pub(self)` even though the two have the same meaning.
+    pub fn is_inherited(&self) -> bool {
+        match self {
+            Visibility::Inherited => true,
+            _ => false,
+        }
+    }
+
+    /// Whether an item with this visibility is visible everywhere that an
+    /// item with the `other` visibility is visible.
+    ///
+    /// Both visibilities are assumed to belong to items in the same module.
+    /// The comparison is purely syntactic: `pub(crate)`, `crate` and
+    /// `pub(in crate)` are equivalent, as are `pub(self)` and no visibility,
+    /// and `pub(in crate::a)` is at least as visible as `pub(in
+    /// crate::a::b)`. Where the answer would depend on the location of the
+    /// current module within the crate, such as comparing `pub(super)`
+    /// against `pub(in crate::a)`, this returns false.
+    ///
+    /// ```
+    /// # use syn::Visibility;
+    /// #
+    /// let vis = |s| syn::parse_str::<Visibility>(s).unwrap();
+    ///
+    /// assert!(vis("pub").is_at_least_as_visible_as(&vis("pub(crate)")));
+    /// assert!(vis("pub(crate)").is_at_least_as_visible_as(&vis("pub(super)")));
+    /// assert!(vis("pub(super)").is_at_least_as_visible_as(&vis("")));
+    /// assert!(!vis("").is_at_least_as_visible_as(&vis("pub(super)")));
+    /// assert!(!vis("pub(super)").is_at_least_as_visible_as(&vis("pub(in crate::a)")));
+    /// ```
+    pub fn is_at_least_as_visible_as(&self, other: &Visibility) -> bool {
+        match (VisScope::of(self), VisScope::of(other)) {
+            (VisScope::Public, _) => true,
+            (_, VisScope::Public) | (VisScope::Unknown, _) | (_, VisScope::Unknown) => false,
+            (VisScope::Absolute(this), VisScope::Absolute(other)) => {
+                this.len() <= other.len() && this.iter().zip(&other).all(|(a, b)| a == b)
+            }
+            (VisScope::Absolute(this), VisScope::Relative(_)) => this.is_empty(),
+            (VisScope::Relative(_), VisScope::Absolute(_)) => false,
+            (VisScope::Relative(this), VisScope::Relative(other)) => this >= other,
+        }
+    }
+}
+
+// The set of modules in which an item is visible, as far as can be determined
+// from the syntax alone.
+enum VisScope<'a> {
+    // Visible everywhere.
+    Public,
+    // Visible within the module at this path from the crate root.
+    Absolute(Vec<&'a Ident>),
+    // Visible within the module this many `super` steps up from the current
+    // module.
+    Relative(usize),
+    // Not a valid visibility restriction.
+    Unknown,
+}
+
+impl<'a> VisScope<'a> {
+    fn of(vis: &'a Visibility) -> Self {
+        let restricted = match vis {
+            Visibility::Public(_) => return VisScope::Public,
+            Visibility::Crate(_) => return VisScope::Absolute(Vec::new()),
+            Visibility::Inherited => return VisScope::Relative(0),
+            Visibility::Restricted(restricted) => restricted,
+        };
+
+        let mut segments = restricted
+            .path
+            .segments
+            .iter()
+            .map(|segment| &segment.ident);
+        match restricted.path.segments.first() {
+            Some(first) if first.ident == "self" || first.ident == "super" => {
+                let mut supers = 0;
+                for ident in segments {
+                    if ident == "super" {
+                        supers += 1;
+                    } else if ident != "self" {
+                        // Not an ancestor of the current module.
+                        return VisScope::Unknown;
+                    }
+                }
+                VisScope::Relative(supers)
+            }
+            Some(first) if first.ident == "crate" => {
+                segments.next();
+                VisScope::Absolute(segments.collect())
+            }
+            // A 2015 edition path, which is relative to the crate root.
+            _ => VisScope::Absolute(segments.collect()),
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -379,6 +592,9 @@ pub mod parsing {
                 } else if content.peek(Token![in]) {
                     let in_token: Token![in] = content.parse()?;
                     let path = content.call(Path::parse_mod_style)?;
+                    if !content.is_empty() {
+                        return Err(content.error("expected `)` after visibility path"));
+                    }
 
                     input.advance_to(&ahead);
                     return Ok(Visibility::Restricted(VisRestricted {
@@ -464,6 +680,34 @@ mod printing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl<'a> ToTokens for FieldsPattern<'a> {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            match self.fields {
+                Fields::Named(fields) => fields.brace_token.surround(tokens, |tokens| {
+                    for (i, (field, binding)) in self.iter().enumerate() {
+                        if i > 0 {
+                            <Token![,]>::default().to_tokens(tokens);
+                        }
+                        field.ident.to_tokens(tokens);
+                        <Token![:]>::default().to_tokens(tokens);
+                        binding.to_tokens(tokens);
+                    }
+                }),
+                Fields::Unnamed(fields) => fields.paren_token.surround(tokens, |tokens| {
+                    for (i, binding) in self.bindings.iter().enumerate() {
+                        if i > 0 {
+                            <Token![,]>::default().to_tokens(tokens);
+                        }
+                        binding.to_tokens(tokens);
+                    }
+                }),
+                Fields::Unit => {}
+            }
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for VisPublic {
         fn to_tokens(&self, tokens: &mut TokenStream) {
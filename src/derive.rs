@@ -87,6 +87,18 @@ ast_struct! {
     }
 }
 
+impl DataEnum {
+    /// Returns a pattern for each variant of the enum, in order, that matches
+    /// the variant as `Self::Variant` and binds each of its fields.
+    ///
+    /// These can be used as the arms of an exhaustive `match self { ... }`
+    /// in an impl generated for the enum. See [`FieldsPattern`] for an
+    /// example.
+    pub fn variant_patterns(&self) -> Vec<FieldsPattern<'_>> {
+        self.variants.iter().map(Variant::pattern).collect()
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -163,27 +163,31 @@ impl<'a> Speculative for ParseBuffer<'a> {
             panic!("Fork was not derived from the advancing parse stream");
         }
 
-        let (self_unexp, self_sp) = inner_unexpected(self);
-        let (fork_unexp, fork_sp) = inner_unexpected(fork);
-        if !Rc::ptr_eq(&self_unexp, &fork_unexp) {
-            match (fork_sp, self_sp) {
-                // Unexpected set on the fork, but not on `self`, copy it over.
-                (Some(span), None) => {
-                    self_unexp.set(Unexpected::Some(span));
-                }
-                // Unexpected unset. Use chain to propagate errors from fork.
-                (None, None) => {
-                    fork_unexp.set(Unexpected::Chain(self_unexp));
+        // A fork without a slot has not parsed any groups, so there are no
+        // leftover tokens of its own to propagate.
+        if let Some(fork_slot) = fork.slot.get() {
+            let unexpected = self.unexpected;
+            let (self_unexp, self_sp) = unexpected.resolve(get_unexpected(self));
+            let (fork_unexp, fork_sp) = unexpected.resolve(fork_slot);
+            if self_unexp != fork_unexp {
+                match (fork_sp, self_sp) {
+                    // Unexpected set on the fork, but not on `self`, copy it over.
+                    (Some(leftover), None) => {
+                        unexpected.set(self_unexp, Unexpected::Some(leftover));
+                    }
+                    // Unexpected unset. Use chain to propagate errors from fork.
+                    (None, None) => {
+                        unexpected.set(fork_unexp, Unexpected::Chain(self_unexp));
 
-                    // Ensure toplevel 'unexpected' tokens from the fork don't
-                    // bubble up the chain by replacing the root `unexpected`
-                    // pointer, only 'unexpected' tokens from existing group
-                    // parsers should bubble.
-                    fork.unexpected
-                        .set(Some(Rc::new(Cell::new(Unexpected::None))));
+                        // Ensure toplevel 'unexpected' tokens from the fork
+                        // don't bubble up the chain by giving the fork a new
+                        // slot, only 'unexpected' tokens from existing group
+                        // parsers should bubble.
+                        fork.slot.set(None);
+                    }
+                    // Unexpected has been set on `self`. No changes needed.
+                    (_, Some(_)) => {}
                 }
-                // Unexpected has been set on `self`. No changes needed.
-                (_, Some(_)) => {}
             }
         }
 
@@ -0,0 +1,546 @@
+//! Analysis of lifetime elision in function signatures.
+//!
+//! The entry point is [`Signature::lifetime_elision`], which reports every
+//! lifetime position in the inputs and output of a signature and where the
+//! elided output lifetimes would get their lifetime from according to the
+//! [lifetime elision rules]:
+//!
+//! - Each elided lifetime in the inputs becomes a distinct lifetime parameter.
+//! - If there is exactly one lifetime used in the inputs, elided or not, that
+//!   lifetime is assigned to all elided output lifetimes.
+//! - If the receiver is a reference to `Self`, such as `&self`, `&mut self` or
+//!   `self: &Self`, the lifetime of that reference is assigned to all elided
+//!   output lifetimes.
+//!
+//! [lifetime elision rules]: https://doc.rust-lang.org/reference/lifetime-elision.html
+//!
+//! The analysis is purely syntactic. Lifetime parameters hidden in paths,
+//! such as the one in `std::cell::Ref<T>`, cannot be seen without knowing the
+//! definition of the type and are not reported. Types produced by macros are
+//! not looked into. Lifetimes inside function pointer types and `Fn(...)`
+//! sugar have their own elision scope and are not part of the enclosing
+//! signature.
+//!
+//! The module also computes [default trait object lifetime bounds], which
+//! determine the lifetime of a `dyn Trait` without an explicit lifetime bound.
+//! See [`TypeTraitObject::default_lifetime`].
+//!
+//! [default trait object lifetime bounds]: https://doc.rust-lang.org/reference/lifetime-elision.html#default-trait-object-lifetimes
+//!
+//! *This module is available only if Syn is built with the `"full"` feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::elision::OutputSource;
+//! use syn::Signature;
+//!
+//! let sig: Signature = syn::parse_str("fn get(&self, key: &str) -> &Value").unwrap();
+//! let elision = sig.lifetime_elision();
+//!
+//! // The `&self` receiver and the `&str` argument.
+//! assert_eq!(elision.inputs.len(), 2);
+//! assert!(elision.inputs.iter().all(|input| input.is_elided()));
+//!
+//! // The output borrows from `self`.
+//! assert_eq!(elision.output_source, OutputSource::Receiver(0));
+//! ```
+
+use super::*;
+use crate::punctuated::Punctuated;
+use proc_macro2::Span;
+use std::fmt::{self, Debug};
+
+/// The result of [`Signature::lifetime_elision`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub struct LifetimeElision<'a> {
+    /// Lifetime positions in the receiver and arguments, in source order.
+    pub inputs: Vec<LifetimePosition<'a>>,
+    /// Lifetime positions in the return type, in source order.
+    pub output: Vec<LifetimePosition<'a>>,
+    /// Where elided lifetimes in the return type get their lifetime from.
+    pub output_source: OutputSource,
+}
+
+/// A place in a signature where a lifetime is written, or where one is
+/// implied by a reference type without a lifetime.
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub struct LifetimePosition<'a> {
+    /// The lifetime as written, or `None` for a reference without a lifetime
+    /// like `&T`.
+    pub lifetime: Option<&'a Lifetime>,
+    /// The span of the lifetime, or of the `&` token if no lifetime is
+    /// written.
+    pub span: Span,
+    /// Whether this position is the reference of a `self` receiver.
+    pub is_receiver: bool,
+}
+
+/// Where elided lifetimes in the return type of a signature get their
+/// lifetime from.
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub enum OutputSource {
+    /// The lifetime of the reference in the `self` receiver, which is the
+    /// input at this index.
+    Receiver(usize),
+    /// The only lifetime used in the inputs, which first appears at the input
+    /// at this index.
+    SoleInput(usize),
+    /// The inputs contain no lifetimes, so elided output lifetimes are an
+    /// error unless they are `'static`.
+    NoInputs,
+    /// The inputs contain more than one lifetime and there is no reference
+    /// receiver, so elided output lifetimes are an error.
+    Ambiguous,
+}
+
+impl<'a> LifetimeElision<'a> {
+    /// Iterates over the positions in the inputs that have no lifetime or use
+    /// the placeholder lifetime `'_`. Each of these stands for a distinct
+    /// lifetime parameter of the function.
+    pub fn elided_inputs(&self) -> impl Iterator<Item = &LifetimePosition<'a>> {
+        self.inputs.iter().filter(|input| input.is_elided())
+    }
+
+    /// Iterates over the positions in the return type that have no lifetime or
+    /// use the placeholder lifetime `'_`.
+    pub fn elided_outputs(&self) -> impl Iterator<Item = &LifetimePosition<'a>> {
+        self.output.iter().filter(|output| output.is_elided())
+    }
+
+    /// The input position whose lifetime is assigned to the elided output
+    /// lifetimes, if there is exactly one.
+    pub fn output_lifetime(&self) -> Option<&LifetimePosition<'a>> {
+        match self.output_source {
+            OutputSource::Receiver(i) | OutputSource::SoleInput(i) => self.inputs.get(i),
+            OutputSource::NoInputs | OutputSource::Ambiguous => None,
+        }
+    }
+
+    /// Whether the signature is accepted by the elision rules, meaning either
+    /// the return type has no elided lifetimes or there is an unambiguous
+    /// lifetime for them to refer to.
+    pub fn is_valid(&self) -> bool {
+        self.elided_outputs().next().is_none() || self.output_lifetime().is_some()
+    }
+}
+
+impl<'a> LifetimePosition<'a> {
+    /// Whether no lifetime is written at this position, or the placeholder
+    /// lifetime `'_` is.
+    pub fn is_elided(&self) -> bool {
+        match self.lifetime {
+            None => true,
+            Some(lifetime) => lifetime.ident == "_",
+        }
+    }
+}
+
+impl Signature {
+    /// Reports which lifetimes in this signature are elided and how the
+    /// elided lifetimes of the return type would be resolved.
+    ///
+    /// See the [`elision`][crate::elision] module for details.
+    ///
+    /// *This method is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn lifetime_elision(&self) -> LifetimeElision<'_> {
+        let mut inputs = Vec::new();
+        let mut receiver = None;
+
+        for arg in &self.inputs {
+            match arg {
+                FnArg::Receiver(arg) => {
+                    if let Some((and_token, lifetime)) = &arg.reference {
+                        receiver = Some(inputs.len());
+                        inputs.push(LifetimePosition {
+                            lifetime: lifetime.as_ref(),
+                            span: match lifetime {
+                                Some(lifetime) => lifetime.apostrophe,
+                                None => and_token.span,
+                            },
+                            is_receiver: true,
+                        });
+                    }
+                }
+                FnArg::Typed(arg) => {
+                    let first = inputs.len();
+                    Collect::new(&mut inputs).visit_type(&arg.ty);
+                    let refs_to_self: Vec<usize> = (first..inputs.len())
+                        .filter(|&i| inputs[i].is_receiver)
+                        .collect();
+                    let this = if is_self(&arg.pat) && refs_to_self.len() == 1 {
+                        Some(refs_to_self[0])
+                    } else {
+                        None
+                    };
+                    for (i, input) in inputs.iter_mut().enumerate().skip(first) {
+                        input.is_receiver = this == Some(i);
+                    }
+                    if this.is_some() {
+                        receiver = this;
+                    }
+                }
+            }
+        }
+
+        let mut output = Vec::new();
+        if let ReturnType::Type(_, ty) = &self.output {
+            Collect::new(&mut output).visit_type(ty);
+            for position in &mut output {
+                position.is_receiver = false;
+            }
+        }
+
+        let output_source = match receiver {
+            Some(i) => OutputSource::Receiver(i),
+            None => sole_input(&inputs),
+        };
+
+        LifetimeElision {
+            inputs,
+            output,
+            output_source,
+        }
+    }
+}
+
+/// The position in which a trait object type appears, which determines its
+/// default lifetime bound.
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub enum ObjectContext<'a> {
+    /// The referent of a reference: `&'a dyn Trait`. The lifetime is `None`
+    /// if the reference has no lifetime, as in `&dyn Trait`.
+    Reference(Option<&'a Lifetime>),
+    /// A type argument of a generic type, as in `Box<dyn Trait>` or `Ref<'a,
+    /// dyn Trait>`, along with the lifetime bounds declared on the
+    /// corresponding type parameter. For `struct Ref<'a, T: ?Sized + 'a>`
+    /// that is `'a`; for standard library containers like `Box`, `Rc` and
+    /// `Vec` there are none.
+    TypeArgument(&'a [Lifetime]),
+    /// Inside of a function body, where the default is inferred.
+    Expression,
+    /// Any other position, such as the type of a `static` or the target of a
+    /// type alias.
+    Other,
+}
+
+/// The lifetime bound of a trait object type, as computed by
+/// [`TypeTraitObject::default_lifetime`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub enum ObjectLifetime<'a> {
+    /// The bound is written explicitly: `dyn Trait + 'a`.
+    Explicit(&'a Lifetime),
+    /// The bound defaults to this lifetime, from the enclosing reference, the
+    /// bound on the enclosing type parameter, or the trait's definition.
+    Default(&'a Lifetime),
+    /// The bound defaults to the elided lifetime of the enclosing reference,
+    /// as in `&dyn Trait` which means `&'x (dyn Trait + 'x)`.
+    Reference,
+    /// The bound defaults to `'static`.
+    Static,
+    /// The bound is inferred by the compiler from the surrounding expression.
+    Inferred,
+    /// There is no default and the bound must be written explicitly, because
+    /// the enclosing type parameter or the trait has more than one lifetime
+    /// bound.
+    Ambiguous,
+}
+
+impl TypeTraitObject {
+    /// The lifetime bound written on this trait object, if any.
+    pub fn explicit_lifetime(&self) -> Option<&Lifetime> {
+        self.bounds.iter().find_map(|bound| match bound {
+            TypeParamBound::Lifetime(lifetime) => Some(lifetime),
+            TypeParamBound::Trait(_) => None,
+        })
+    }
+
+    /// Computes the lifetime bound of this trait object according to the
+    /// rules for default trait object lifetimes.
+    ///
+    /// The `trait_bounds` are the lifetime bounds declared on the definition
+    /// of the trait, such as `'a` in `trait Trait<'a>: 'a`, which apply when
+    /// the enclosing type does not determine a default. They are usually
+    /// empty.
+    ///
+    /// *This method is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// ```
+    /// use syn::elision::{ObjectContext, ObjectLifetime};
+    /// use syn::{Type, TypeReference};
+    ///
+    /// let ty: TypeReference = syn::parse_str("&'a dyn Trait").unwrap();
+    /// let object = match &*ty.elem {
+    ///     Type::TraitObject(object) => object,
+    ///     _ => unreachable!(),
+    /// };
+    /// let context = ObjectContext::Reference(ty.lifetime.as_ref());
+    /// match object.default_lifetime(context, &[]) {
+    ///     ObjectLifetime::Default(lifetime) => assert_eq!(lifetime.ident, "a"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn default_lifetime<'a>(
+        &'a self,
+        context: ObjectContext<'a>,
+        trait_bounds: &'a [Lifetime],
+    ) -> ObjectLifetime<'a> {
+        if let Some(lifetime) = self.explicit_lifetime() {
+            return ObjectLifetime::Explicit(lifetime);
+        }
+
+        match context {
+            ObjectContext::Reference(Some(lifetime)) => return ObjectLifetime::Default(lifetime),
+            ObjectContext::Reference(None) => return ObjectLifetime::Reference,
+            ObjectContext::TypeArgument(bounds) => match unique_lifetime(bounds) {
+                Unique::None => {}
+                Unique::One(lifetime) => return ObjectLifetime::from_bound(lifetime),
+                Unique::Many => return ObjectLifetime::Ambiguous,
+            },
+            ObjectContext::Expression | ObjectContext::Other => {}
+        }
+
+        if trait_bounds.iter().any(|bound| bound.ident == "static") {
+            return ObjectLifetime::Static;
+        }
+        match unique_lifetime(trait_bounds) {
+            Unique::One(lifetime) => ObjectLifetime::from_bound(lifetime),
+            Unique::Many => ObjectLifetime::Ambiguous,
+            Unique::None => match context {
+                ObjectContext::Expression => ObjectLifetime::Inferred,
+                _ => ObjectLifetime::Static,
+            },
+        }
+    }
+}
+
+impl<'a> ObjectLifetime<'a> {
+    fn from_bound(lifetime: &'a Lifetime) -> Self {
+        if lifetime.ident == "static" {
+            ObjectLifetime::Static
+        } else {
+            ObjectLifetime::Default(lifetime)
+        }
+    }
+}
+
+enum Unique<'a> {
+    None,
+    One(&'a Lifetime),
+    Many,
+}
+
+fn unique_lifetime(lifetimes: &[Lifetime]) -> Unique<'_> {
+    let first = match lifetimes.first() {
+        Some(first) => first,
+        None => return Unique::None,
+    };
+    if lifetimes.iter().all(|lifetime| lifetime == first) {
+        Unique::One(first)
+    } else {
+        Unique::Many
+    }
+}
+
+fn sole_input(inputs: &[LifetimePosition]) -> OutputSource {
+    let first = match inputs.first() {
+        Some(first) => first,
+        None => return OutputSource::NoInputs,
+    };
+    if first.is_elided() {
+        if inputs.len() == 1 {
+            OutputSource::SoleInput(0)
+        } else {
+            OutputSource::Ambiguous
+        }
+    } else if inputs.iter().all(|input| input.lifetime == first.lifetime) {
+        OutputSource::SoleInput(0)
+    } else {
+        OutputSource::Ambiguous
+    }
+}
+
+fn is_self(pat: &Pat) -> bool {
+    match pat {
+        Pat::Ident(pat) => pat.ident == "self",
+        _ => false,
+    }
+}
+
+fn is_self_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.leading_colon.is_none()
+                && ty.path.segments.len() == 1
+                && ty.path.segments[0].ident == "Self"
+                && ty.path.segments[0].arguments.is_empty()
+        }
+        Type::Paren(ty) => is_self_type(&ty.elem),
+        Type::Group(ty) => is_self_type(&ty.elem),
+        _ => false,
+    }
+}
+
+// Collects lifetime positions in source order. References directly to `Self`
+// are marked with `is_receiver` so that the caller can pick out the receiver
+// reference of a `self: &Self` argument; the caller clears the flag otherwise.
+struct Collect<'a, 'p> {
+    positions: &'p mut Vec<LifetimePosition<'a>>,
+    // Lifetimes introduced by `for<...>` binders enclosing the current type.
+    bound: Vec<&'a Ident>,
+}
+
+impl<'a, 'p> Collect<'a, 'p> {
+    fn new(positions: &'p mut Vec<LifetimePosition<'a>>) -> Self {
+        Collect {
+            positions,
+            bound: Vec::new(),
+        }
+    }
+
+    fn lifetime(&mut self, lifetime: &'a Lifetime) {
+        if self.bound.contains(&&lifetime.ident) {
+            return;
+        }
+        self.positions.push(LifetimePosition {
+            lifetime: Some(lifetime),
+            span: lifetime.apostrophe,
+            is_receiver: false,
+        });
+    }
+
+    fn visit_type(&mut self, ty: &'a Type) {
+        match ty {
+            Type::Array(ty) => self.visit_type(&ty.elem),
+            Type::Group(ty) => self.visit_type(&ty.elem),
+            Type::ImplTrait(ty) => self.visit_bounds(&ty.bounds),
+            Type::Paren(ty) => self.visit_type(&ty.elem),
+            Type::Path(ty) => {
+                if let Some(qself) = &ty.qself {
+                    self.visit_type(&qself.ty);
+                }
+                self.visit_path(&ty.path);
+            }
+            Type::Ptr(ty) => self.visit_type(&ty.elem),
+            Type::Reference(ty) => {
+                match &ty.lifetime {
+                    Some(lifetime) if self.bound.contains(&&lifetime.ident) => {}
+                    lifetime => self.positions.push(LifetimePosition {
+                        lifetime: lifetime.as_ref(),
+                        span: match lifetime {
+                            Some(lifetime) => lifetime.apostrophe,
+                            None => ty.and_token.span,
+                        },
+                        is_receiver: is_self_type(&ty.elem),
+                    }),
+                }
+                self.visit_type(&ty.elem);
+            }
+            Type::Slice(ty) => self.visit_type(&ty.elem),
+            Type::TraitObject(ty) => self.visit_bounds(&ty.bounds),
+            Type::Tuple(ty) => {
+                for elem in &ty.elems {
+                    self.visit_type(elem);
+                }
+            }
+            // Function pointers have their own elision scope.
+            Type::BareFn(_) => {}
+            _ => {}
+        }
+    }
+
+    fn visit_bounds(&mut self, bounds: &'a Punctuated<TypeParamBound, Token![+]>) {
+        for bound in bounds {
+            match bound {
+                TypeParamBound::Trait(bound) => {
+                    let outer = self.bound.len();
+                    if let Some(lifetimes) = &bound.lifetimes {
+                        for def in &lifetimes.lifetimes {
+                            self.bound.push(&def.lifetime.ident);
+                        }
+                    }
+                    self.visit_path(&bound.path);
+                    self.bound.truncate(outer);
+                }
+                TypeParamBound::Lifetime(lifetime) => self.lifetime(lifetime),
+            }
+        }
+    }
+
+    fn visit_path(&mut self, path: &'a Path) {
+        for segment in &path.segments {
+            match &segment.arguments {
+                PathArguments::None => {}
+                PathArguments::AngleBracketed(arguments) => {
+                    for arg in &arguments.args {
+                        match arg {
+                            GenericArgument::Lifetime(lifetime) => self.lifetime(lifetime),
+                            GenericArgument::Type(ty) => self.visit_type(ty),
+                            GenericArgument::Binding(binding) => self.visit_type(&binding.ty),
+                            GenericArgument::Constraint(constraint) => {
+                                self.visit_bounds(&constraint.bounds);
+                            }
+                            GenericArgument::Const(_) => {}
+                        }
+                    }
+                }
+                // `Fn(&T) -> &U` sugar has its own elision scope.
+                PathArguments::Parenthesized(_) => {}
+            }
+        }
+    }
+}
+
+impl<'a> Copy for LifetimePosition<'a> {}
+
+impl<'a> Clone for LifetimePosition<'a> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for OutputSource {}
+
+impl Clone for OutputSource {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for OutputSource {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (OutputSource::Receiver(a), OutputSource::Receiver(b))
+            | (OutputSource::SoleInput(a), OutputSource::SoleInput(b)) => a == b,
+            (OutputSource::NoInputs, OutputSource::NoInputs)
+            | (OutputSource::Ambiguous, OutputSource::Ambiguous) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for OutputSource {}
+
+impl Debug for OutputSource {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutputSource::Receiver(i) => formatter.debug_tuple("Receiver").field(i).finish(),
+            OutputSource::SoleInput(i) => formatter.debug_tuple("SoleInput").field(i).finish(),
+            OutputSource::NoInputs => formatter.write_str("NoInputs"),
+            OutputSource::Ambiguous => formatter.write_str("Ambiguous"),
+        }
+    }
+}
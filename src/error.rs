@@ -6,8 +6,14 @@ use proc_macro2::{
 };
 #[cfg(feature = "printing")]
 use quote::ToTokens;
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
+#[cfg(feature = "serde")]
+use std::cmp;
 use std::fmt::{self, Debug, Display};
 use std::iter::FromIterator;
+#[cfg(feature = "serde")]
+use std::ops::Range;
 use std::slice;
 use std::vec;
 
@@ -95,6 +101,9 @@ struct ErrorMessage {
     start_span: ThreadBound<Span>,
     end_span: ThreadBound<Span>,
     message: String,
+    // Whether the message reports a parse that was cancelled by a
+    // `ParserConfig`.
+    cancelled: bool,
 }
 
 #[cfg(test)]
@@ -139,6 +148,7 @@ impl Error {
                 start_span: ThreadBound::new(span),
                 end_span: ThreadBound::new(span),
                 message: message.to_string(),
+                cancelled: false,
             }],
         }
     }
@@ -166,6 +176,7 @@ impl Error {
                 start_span: ThreadBound::new(start),
                 end_span: ThreadBound::new(end),
                 message: message.to_string(),
+                cancelled: false,
             }],
         }
     }
@@ -242,6 +253,13 @@ impl Error {
     pub fn combine(&mut self, another: Error) {
         self.messages.extend(another.messages);
     }
+
+    /// Returns whether this error reports that parsing was abandoned because
+    /// of the cancellation token or deadline of a
+    /// [`ParserConfig`](crate::parse::ParserConfig).
+    pub fn is_cancelled(&self) -> bool {
+        self.messages.iter().any(|message| message.cancelled)
+    }
 }
 
 impl ErrorMessage {
@@ -286,13 +304,29 @@ pub fn new_at<T: Display>(scope: Span, cursor: Cursor, message: T) -> Error {
     }
 }
 
-#[cfg(all(feature = "parsing", any(feature = "full", feature = "derive")))]
+#[cfg(feature = "parsing")]
+pub fn new_cancelled(span: Span) -> Error {
+    Error {
+        messages: vec![ErrorMessage {
+            start_span: ThreadBound::new(span),
+            end_span: ThreadBound::new(span),
+            message: "parsing cancelled".to_owned(),
+            cancelled: true,
+        }],
+    }
+}
+
+#[cfg(all(
+    feature = "parsing",
+    any(feature = "full", feature = "derive", feature = "parse-only")
+))]
 pub fn new2<T: Display>(start: Span, end: Span, message: T) -> Error {
     Error {
         messages: vec![ErrorMessage {
             start_span: ThreadBound::new(start),
             end_span: ThreadBound::new(end),
             message: message.to_string(),
+            cancelled: false,
         }],
     }
 }
@@ -345,6 +379,7 @@ impl Clone for ErrorMessage {
             start_span: ThreadBound::new(start),
             end_span: ThreadBound::new(end),
             message: self.message.clone(),
+            cancelled: self.cancelled,
         }
     }
 }
@@ -414,3 +449,133 @@ impl Extend<Error> for Error {
         }
     }
 }
+
+/// Serializes the error as a diagnostic for tools that forward it on, such
+/// as a build server reporting the errors of a Syn-based code generator.
+///
+/// The first message of the error, which is also what `Display` shows, is
+/// the main one. Messages added to it with [`Error::combine`] follow as its
+/// notes. Each message has the byte range of its span in the source, or
+/// `null` if the location of the span is not known:
+///
+/// ```json
+/// {
+///   "message": "expected identifier",
+///   "span": { "start": 12, "end": 15 },
+///   "notes": [
+///     { "message": "duplicate field", "span": null }
+///   ]
+/// }
+/// ```
+///
+/// The location of a span is only known inside of a procedural macro, or
+/// when `proc-macro2` is built with its `"span-locations"` feature, and is
+/// left out for an error that is serialized on another thread than the one
+/// it was created on. The byte offsets are positions in the compiler's
+/// source map, or in that of `proc-macro2` for code parsed outside of a
+/// procedural macro, which are relative to the start of the first file
+/// rather than of the file that the span is in.
+///
+/// # Example
+///
+/// ```
+/// use proc_macro2::Span;
+/// use syn::Error;
+///
+/// let mut error = Error::new(Span::call_site(), "unknown attribute");
+/// error.combine(Error::new(Span::call_site(), "expected `skip` or `rename`"));
+///
+/// let json = serde_json::to_value(&error).unwrap();
+/// assert_eq!(json["message"], "unknown attribute");
+/// assert_eq!(json["notes"][0]["message"], "expected `skip` or `rename`");
+/// ```
+///
+/// *This impl is available only if Syn is built with the `"serde"`
+/// feature.*
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (first, notes) = self.messages.split_first().unwrap();
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("message", &first.message)?;
+        state.serialize_field("span", &first.byte_range().map(ByteRange))?;
+        state.serialize_field("notes", &Notes(notes))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ErrorMessage {
+    fn byte_range(&self) -> Option<Range<usize>> {
+        let start = span_byte_range(self.start_span.get()?)?;
+        let end = span_byte_range(self.end_span.get()?)?;
+        Some(start.start..cmp::max(start.end, end.end))
+    }
+}
+
+// The range in the `Debug` representation of a span, which looks like
+// `bytes(12..15)`, or `#0 bytes(12..15)` for a span of the compiler, if the
+// span knows its location.
+#[cfg(feature = "serde")]
+fn span_byte_range(span: &Span) -> Option<Range<usize>> {
+    let repr = format!("{:?}", span);
+    let range = &repr[repr.find("bytes(")? + "bytes(".len()..];
+    let range = &range[..range.find(')')?];
+    let dots = range.find("..")?;
+    let start = range[..dots].parse().ok()?;
+    let end = range[dots + "..".len()..].parse().ok()?;
+    Some(start..end)
+}
+
+#[cfg(feature = "serde")]
+struct ByteRange(Range<usize>);
+
+#[cfg(feature = "serde")]
+impl Serialize for ByteRange {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ByteRange", 2)?;
+        state.serialize_field("start", &self.0.start)?;
+        state.serialize_field("end", &self.0.end)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct Notes<'a>(&'a [ErrorMessage]);
+
+#[cfg(feature = "serde")]
+impl<'a> Serialize for Notes<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for note in self.0 {
+            seq.serialize_element(&Note(note))?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct Note<'a>(&'a ErrorMessage);
+
+#[cfg(feature = "serde")]
+impl<'a> Serialize for Note<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Note", 2)?;
+        state.serialize_field("message", &self.0.message)?;
+        state.serialize_field("span", &self.0.byte_range().map(ByteRange))?;
+        state.end()
+    }
+}
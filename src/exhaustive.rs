@@ -0,0 +1,264 @@
+//! Syntactic check of which variants of an enum a list of patterns covers.
+//!
+//! Macros that compile a table of patterns written by the user into a
+//! `match`, such as the rules of a state machine or the cases of a DSL, get
+//! a non-exhaustive or unreachable pattern reported by rustc deep inside of
+//! the generated code, if at all. The check in this module looks at the
+//! patterns against the definition of the enum before any code is generated,
+//! so that the macro can report a missing variant at the enum, or an
+//! unreachable pattern at the pattern.
+//!
+//! The check is purely syntactic, with no name resolution or type inference.
+//! A variant is recognized by a path ending in `Enum::Variant` or
+//! `Self::Variant`, or by a lone identifier that is the name of a variant, as
+//! after `use Enum::*`; any other lone identifier is taken to be a binding
+//! that matches everything. A pattern of a variant matches all of it if the
+//! patterns of its fields are bindings, wildcards, or tuples or references
+//! of those.
+//!
+//! The check only reports what is certain from the syntax. A pattern that
+//! cannot be analyzed, such as a macro invocation or a path to something
+//! other than a variant of the enum, could match any variant, so no variant
+//! is reported missing if there is one. Variants that are matched only for
+//! some values of their fields are reported as partially covered, rather
+//! than as missing, since whether the patterns add up to all of the values
+//! needs the types of the fields.
+//!
+//! *This module is available only if Syn is built with the `"full"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::{parse_quote, ItemEnum, Pat};
+//!
+//! let item: ItemEnum = parse_quote! {
+//!     enum State {
+//!         Idle,
+//!         Running { pid: u32 },
+//!         Failed(i32),
+//!         Done,
+//!     }
+//! };
+//! let pats: Vec<Pat> = vec![
+//!     parse_quote!(State::Idle),
+//!     parse_quote!(State::Running { .. }),
+//!     parse_quote!(State::Failed(0)),
+//!     parse_quote!(State::Running { pid: 1 }),
+//! ];
+//!
+//! let coverage = syn::exhaustive::check(&item, &pats);
+//! let missing: Vec<String> = coverage.missing.iter().map(|v| v.ident.to_string()).collect();
+//! let partial: Vec<String> = coverage.partial.iter().map(|v| v.ident.to_string()).collect();
+//! assert_eq!(missing, ["Done"]);
+//! assert_eq!(partial, ["Failed"]);
+//! assert_eq!(coverage.unreachable, [3]);
+//! ```
+
+use super::*;
+
+/// The variants of an enum that a list of patterns covers, returned by
+/// [`check`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+pub struct Coverage<'a> {
+    /// The variants that no pattern matches, in the order of the enum.
+    pub missing: Vec<&'a Variant>,
+    /// The variants that some pattern matches, but only for some values of
+    /// its fields, and that no pattern matches completely.
+    pub partial: Vec<&'a Variant>,
+    /// The indices of the patterns that match nothing that an earlier
+    /// pattern does not already match.
+    pub unreachable: Vec<usize>,
+}
+
+/// Checks which variants of an enum a list of patterns, tried in order,
+/// covers.
+///
+/// See the [module documentation](self) for how the patterns are looked at.
+/// The patterns of `match` arms that have a guard match only some of their
+/// values, and should be left out.
+///
+/// *This function is available only if Syn is built with the `"full"`
+/// feature.*
+pub fn check<'a>(item: &'a ItemEnum, pats: &[Pat]) -> Coverage<'a> {
+    let variants: Vec<&Variant> = item.variants.iter().collect();
+    let checker = Checker {
+        item,
+        variants: &variants,
+    };
+    let mut full = vec![false; variants.len()];
+    let mut touched = vec![false; variants.len()];
+    let mut all = false;
+    let mut unknown = false;
+    let mut unreachable = Vec::new();
+
+    for (i, pat) in pats.iter().enumerate() {
+        let cover = checker.cover(pat);
+        let adds_nothing = if cover.all {
+            full.iter().all(|full| *full)
+        } else {
+            !cover.unknown && cover.variants.iter().all(|(v, _)| full[*v])
+        };
+        if all || adds_nothing {
+            unreachable.push(i);
+        }
+        all |= cover.all;
+        unknown |= cover.unknown;
+        for (v, is_full) in cover.variants {
+            touched[v] = true;
+            full[v] |= is_full;
+        }
+    }
+
+    let mut missing = Vec::new();
+    let mut partial = Vec::new();
+    if !all {
+        for (v, variant) in variants.iter().enumerate() {
+            if !touched[v] && !unknown {
+                missing.push(*variant);
+            } else if touched[v] && !full[v] {
+                partial.push(*variant);
+            }
+        }
+    }
+    Coverage {
+        missing,
+        partial,
+        unreachable,
+    }
+}
+
+// What a pattern matches.
+struct Cover {
+    // Everything.
+    all: bool,
+    // The index of each variant that it matches, and whether it matches all
+    // values of it.
+    variants: Vec<(usize, bool)>,
+    // Possibly anything, for a pattern that cannot be analyzed.
+    unknown: bool,
+}
+
+impl Cover {
+    fn all() -> Self {
+        Cover {
+            all: true,
+            variants: Vec::new(),
+            unknown: false,
+        }
+    }
+
+    fn variant(index: usize, full: bool) -> Self {
+        Cover {
+            all: false,
+            variants: vec![(index, full)],
+            unknown: false,
+        }
+    }
+
+    fn unknown() -> Self {
+        Cover {
+            all: false,
+            variants: Vec::new(),
+            unknown: true,
+        }
+    }
+}
+
+struct Checker<'a> {
+    item: &'a ItemEnum,
+    variants: &'a [&'a Variant],
+}
+
+impl<'a> Checker<'a> {
+    fn cover(&self, pat: &Pat) -> Cover {
+        match pat {
+            Pat::Wild(_) => Cover::all(),
+            Pat::Ident(pat) => match &pat.subpat {
+                Some((_at, subpat)) => self.cover(subpat),
+                None => match self.variant_named(&pat.ident) {
+                    Some(v) => Cover::variant(v, true),
+                    None => Cover::all(),
+                },
+            },
+            Pat::Path(pat) if pat.qself.is_none() => match self.resolve(&pat.path) {
+                Some(v) => Cover::variant(v, true),
+                None => Cover::unknown(),
+            },
+            Pat::TupleStruct(pat) => match self.resolve(&pat.path) {
+                Some(v) => {
+                    let full = pat.pat.elems.iter().all(|elem| self.irrefutable(elem));
+                    Cover::variant(v, full)
+                }
+                None => Cover::unknown(),
+            },
+            Pat::Struct(pat) => match self.resolve(&pat.path) {
+                Some(v) => {
+                    let full = pat.fields.iter().all(|field| self.irrefutable(&field.pat));
+                    Cover::variant(v, full)
+                }
+                None => Cover::unknown(),
+            },
+            Pat::Or(pat) => {
+                let mut cover = Cover {
+                    all: false,
+                    variants: Vec::new(),
+                    unknown: false,
+                };
+                for case in &pat.cases {
+                    let case = self.cover(case);
+                    cover.all |= case.all;
+                    cover.unknown |= case.unknown;
+                    cover.variants.extend(case.variants);
+                }
+                cover
+            }
+            Pat::Box(pat) => self.cover(&pat.pat),
+            Pat::Reference(pat) => self.cover(&pat.pat),
+            Pat::Type(pat) => self.cover(&pat.pat),
+            _ => Cover::unknown(),
+        }
+    }
+
+    // Whether a pattern matches every value of a field.
+    fn irrefutable(&self, pat: &Pat) -> bool {
+        match pat {
+            Pat::Wild(_) | Pat::Rest(_) => true,
+            Pat::Ident(pat) => match &pat.subpat {
+                Some((_at, subpat)) => self.irrefutable(subpat),
+                None => self.variant_named(&pat.ident).is_none(),
+            },
+            Pat::Tuple(pat) => pat.elems.iter().all(|elem| self.irrefutable(elem)),
+            Pat::Or(pat) => pat.cases.iter().any(|case| self.irrefutable(case)),
+            Pat::Box(pat) => self.irrefutable(&pat.pat),
+            Pat::Reference(pat) => self.irrefutable(&pat.pat),
+            Pat::Type(pat) => self.irrefutable(&pat.pat),
+            _ => false,
+        }
+    }
+
+    fn variant_named(&self, ident: &Ident) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.ident == *ident)
+    }
+
+    // The variant that a path of the form `Enum::Variant` or `Self::Variant`
+    // refers to, or `Variant` alone.
+    fn resolve(&self, path: &Path) -> Option<usize> {
+        let segments: Vec<&PathSegment> = path.segments.iter().collect();
+        let (last, prefix) = segments.split_last()?;
+        if !last.arguments.is_none() {
+            return None;
+        }
+        if let Some(parent) = prefix.last() {
+            if parent.ident != self.item.ident && parent.ident != "Self" {
+                return None;
+            }
+        } else if path.leading_colon.is_some() {
+            return None;
+        }
+        self.variant_named(&last.ident)
+    }
+}
@@ -12,12 +12,9 @@ pub extern crate quote;
 
 pub use proc_macro2::{Span, TokenStream as TokenStream2};
 
-pub use crate::span::IntoSpans;
+pub use crate::spans::IntoSpans;
 
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
 pub use proc_macro::TokenStream;
 
 #[cfg(feature = "printing")]
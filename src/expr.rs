@@ -14,8 +14,9 @@ use std::mem;
 ast_enum_of_structs! {
     /// A Rust expression.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature, but most of the variants are not available unless "full" is enabled.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"` or
+    /// `"parse-only"` feature, but most of the variants are not available unless
+    /// "full" is enabled.*
     ///
     /// # Syntax tree enums
     ///
@@ -86,7 +87,7 @@ ast_enum_of_structs! {
     /// A sign that you may not be choosing the right variable names is if you
     /// see names getting repeated in your code, like accessing
     /// `receiver.receiver` or `pat.pat` or `cond.cond`.
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     #[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
     pub enum Expr {
         /// A slice literal expression: `[a, b, c, d]`.
@@ -316,9 +317,9 @@ ast_struct! {
 ast_struct! {
     /// A binary operation: `a + b`, `a * b`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprBinary {
         pub attrs: Vec<Attribute>,
         pub left: Box<Expr>,
@@ -368,9 +369,9 @@ ast_struct! {
 ast_struct! {
     /// A function call expression: `invoke(a, b)`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprCall {
         pub attrs: Vec<Attribute>,
         pub func: Box<Expr>,
@@ -382,9 +383,9 @@ ast_struct! {
 ast_struct! {
     /// A cast expression: `foo as f64`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprCast {
         pub attrs: Vec<Attribute>,
         pub expr: Box<Expr>,
@@ -428,7 +429,7 @@ ast_struct! {
     /// field (`obj.0`).
     ///
     /// *This type is available only if Syn is built with the `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprField {
         pub attrs: Vec<Attribute>,
         pub base: Box<Expr>,
@@ -449,6 +450,7 @@ ast_struct! {
         pub pat: Pat,
         pub in_token: Token![in],
         pub expr: Box<Expr>,
+        pub spec: Option<Box<SpecClauses>>,
         pub body: Block,
     }
 }
@@ -490,9 +492,9 @@ ast_struct! {
 ast_struct! {
     /// A square bracketed indexing expression: `vector[2]`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprIndex {
         pub attrs: Vec<Attribute>,
         pub expr: Box<Expr>,
@@ -518,9 +520,9 @@ ast_struct! {
 ast_struct! {
     /// A literal in place of an expression: `1`, `"foo"`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprLit {
         pub attrs: Vec<Attribute>,
         pub lit: Lit,
@@ -536,6 +538,7 @@ ast_struct! {
         pub attrs: Vec<Attribute>,
         pub label: Option<Label>,
         pub loop_token: Token![loop],
+        pub spec: Option<Box<SpecClauses>>,
         pub body: Block,
     }
 }
@@ -585,7 +588,7 @@ ast_struct! {
     /// A parenthesized expression: `(a + b)`.
     ///
     /// *This type is available only if Syn is built with the `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprParen {
         pub attrs: Vec<Attribute>,
         pub paren_token: token::Paren,
@@ -599,9 +602,9 @@ ast_struct! {
     ///
     /// A plain identifier like `x` is a path of length 1.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprPath {
         pub attrs: Vec<Attribute>,
         pub qself: Option<QSelf>,
@@ -732,9 +735,9 @@ ast_struct! {
 ast_struct! {
     /// A unary operation: `!x`, `*x`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ExprUnary {
         pub attrs: Vec<Attribute>,
         pub op: UnOp,
@@ -764,6 +767,7 @@ ast_struct! {
         pub label: Option<Label>,
         pub while_token: Token![while],
         pub cond: Box<Expr>,
+        pub spec: Option<Box<SpecClauses>>,
         pub body: Block,
     }
 }
@@ -845,9 +849,9 @@ ast_enum! {
     /// A struct or tuple struct field accessed in a struct literal or field
     /// expression.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum Member {
         /// A named field like `self.x`.
         Named(Ident),
@@ -915,9 +919,9 @@ impl IdentFragment for Member {
 ast_struct! {
     /// The index of an unnamed tuple struct field.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Index {
         pub index: u32,
         pub span: Span,
@@ -1071,7 +1075,6 @@ ast_enum! {
     }
 }
 
-#[cfg(any(feature = "parsing", feature = "printing"))]
 #[cfg(feature = "full")]
 pub(crate) fn requires_terminator(expr: &Expr) -> bool {
     // see https://github.com/rust-lang/rust/blob/2679c38fc/src/librustc_ast/util/classify.rs#L7-L25
@@ -1089,10 +1092,330 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
     }
 }
 
+#[cfg(feature = "full")]
+impl Arm {
+    /// Creates a match arm `pat => body` with no attributes and no guard.
+    ///
+    /// The arm ends in a comma unless the body is a block-like expression
+    /// such as `{ ... }` or `if ...`, matching the usual formatting.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Arm, ExprMatch};
+    ///
+    /// let arms = vec![
+    ///     Arm::new(parse_quote!(Some(x)), parse_quote!(x * 2)).with_guard(parse_quote!(x > 0)),
+    ///     Arm::new(parse_quote!(_), parse_quote!({ 0 })),
+    /// ];
+    /// let expr = ExprMatch::new(parse_quote!(opt), arms);
+    ///
+    /// let expected = quote! {
+    ///     match opt {
+    ///         Some(x) if x > 0 => x * 2,
+    ///         _ => { 0 }
+    ///     }
+    /// };
+    /// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+    /// ```
+    pub fn new(pat: Pat, body: Expr) -> Self {
+        let comma = if requires_terminator(&body) {
+            Some(Token![,](Span::call_site()))
+        } else {
+            None
+        };
+        Arm {
+            attrs: Vec::new(),
+            pat,
+            guard: None,
+            fat_arrow_token: Token![=>](Span::call_site()),
+            body: Box::new(body),
+            comma,
+        }
+    }
+
+    /// Adds an `if` guard to the arm, replacing any existing guard.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn with_guard(mut self, guard: Expr) -> Self {
+        self.guard = Some((Token![if](Span::call_site()), Box::new(guard)));
+        self
+    }
+
+    /// Whether the arm needs a comma before a following arm, which is the case
+    /// unless its body is a block-like expression.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn requires_comma(&self) -> bool {
+        requires_terminator(&self.body)
+    }
+}
+
+#[cfg(feature = "full")]
+impl ExprMatch {
+    /// Creates a `match` expression with the given scrutinee and arms.
+    ///
+    /// Every arm other than the last one that [requires a
+    /// comma](Arm::requires_comma) is given one, so that the arms can be
+    /// produced independently of their position.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn new<I>(expr: Expr, arms: I) -> Self
+    where
+        I: IntoIterator<Item = Arm>,
+    {
+        let mut expr_match = ExprMatch {
+            attrs: Vec::new(),
+            match_token: Token![match](Span::call_site()),
+            expr: Box::new(expr),
+            brace_token: token::Brace(Span::call_site()),
+            arms: Vec::new(),
+        };
+        for arm in arms {
+            expr_match.push_arm(arm);
+        }
+        expr_match
+    }
+
+    /// Appends an arm, adding a comma to the arm that was previously last if
+    /// it needs one now that another arm follows it.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn push_arm(&mut self, arm: Arm) {
+        if let Some(last) = self.arms.last_mut() {
+            if last.comma.is_none() && last.requires_comma() {
+                last.comma = Some(Token![,](Span::call_site()));
+            }
+        }
+        self.arms.push(arm);
+    }
+}
+
+#[cfg(feature = "full")]
+impl ExprStruct {
+    /// Creates a struct literal `Path {}` with no attributes, no fields and no
+    /// base struct, to be filled in by [`with_field`](Self::with_field) and
+    /// [`with_rest`](Self::with_rest).
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn new(path: Path) -> Self {
+        ExprStruct {
+            attrs: Vec::new(),
+            path,
+            brace_token: token::Brace(Span::call_site()),
+            fields: Punctuated::new(),
+            dot2_token: None,
+            rest: None,
+        }
+    }
+
+    /// Appends a field-value pair to the struct literal.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, ExprStruct, FieldValue, Ident};
+    ///
+    /// // The fields of a builder that were set, in a derive for `Config`.
+    /// let set: Vec<Ident> = vec![parse_quote!(port), parse_quote!(verbose)];
+    ///
+    /// let mut expr = ExprStruct::new(parse_quote!(Config));
+    /// for ident in &set {
+    ///     let value = parse_quote!(self.#ident.unwrap());
+    ///     expr = expr.with_field(FieldValue::new(ident.clone().into(), value));
+    /// }
+    /// let expr = expr.with_rest(parse_quote!(Config::default()));
+    ///
+    /// let expected = quote! {
+    ///     Config {
+    ///         port: self.port.unwrap(),
+    ///         verbose: self.verbose.unwrap(),
+    ///         ..Config::default()
+    ///     }
+    /// };
+    /// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+    /// ```
+    pub fn with_field(mut self, field: FieldValue) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Sets the base struct of a functional update, as in
+    /// `Point { x: 1, ..base }`, replacing any existing base.
+    ///
+    /// A trailing comma is added after the fields if there are any, since
+    /// the base needs to be separated from them.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn with_rest(mut self, rest: Expr) -> Self {
+        if !self.fields.empty_or_trailing() {
+            self.fields.push_punct(Token![,](Span::call_site()));
+        }
+        self.dot2_token = Some(Token![..](Span::call_site()));
+        self.rest = Some(Box::new(rest));
+        self
+    }
+
+    /// Returns the fields of a struct or enum variant that the literal does
+    /// not set, in the order of their declaration.
+    ///
+    /// These are the fields that are taken from the base struct in a
+    /// functional update like `Point { x: 1, ..base }`, or that are missing
+    /// from the literal if it has no base.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ExprStruct, ItemStruct};
+    ///
+    /// let item: ItemStruct = parse_quote! {
+    ///     struct Point { x: i32, y: i32, z: i32 }
+    /// };
+    /// let expr: ExprStruct = parse_quote!(Point { y: 1, ..Point::ORIGIN });
+    ///
+    /// let omitted: Vec<_> = expr
+    ///     .omitted_fields(&item.fields)
+    ///     .into_iter()
+    ///     .map(|field| field.ident.as_ref().unwrap().to_string())
+    ///     .collect();
+    /// assert_eq!(omitted, ["x", "z"]);
+    /// ```
+    pub fn omitted_fields<'a>(&self, fields: &'a Fields) -> Vec<&'a Field> {
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(i, field)| {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(Index::from(*i)),
+                };
+                !self.fields.iter().any(|value| value.member == member)
+            })
+            .map(|(_i, field)| field)
+            .collect()
+    }
+}
+
+#[cfg(feature = "full")]
+impl FieldValue {
+    /// Creates a field-value pair `member: expr` with no attributes.
+    ///
+    /// The pair is printed in shorthand, as `x` rather than `x: x`, if the
+    /// value is a path that is the same as the name of the field.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn new(member: Member, expr: Expr) -> Self {
+        FieldValue {
+            attrs: Vec::new(),
+            member,
+            colon_token: None,
+            expr,
+        }
+    }
+
+    /// Whether the field is written in shorthand, like the `x` in
+    /// `Point { x, y: 0 }`.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn is_shorthand(&self) -> bool {
+        self.colon_token.is_none()
+    }
+
+    /// Whether the field could be written in shorthand, which is the case if
+    /// its value is a plain path of the same name as the field, like in
+    /// `Point { x: x }`.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn can_be_shorthand(&self) -> bool {
+        let member = match &self.member {
+            Member::Named(member) => member,
+            Member::Unnamed(_) => return false,
+        };
+        match &self.expr {
+            Expr::Path(expr) => {
+                expr.attrs.is_empty()
+                    && expr.qself.is_none()
+                    && expr.path.leading_colon.is_none()
+                    && expr.path.segments.len() == 1
+                    && expr.path.segments[0].arguments.is_empty()
+                    && expr.path.segments[0].ident == *member
+            }
+            _ => false,
+        }
+    }
+
+    /// Writes the field in shorthand, as in `Point { x }`, if its value
+    /// [allows](FieldValue::can_be_shorthand) it. Returns whether the field
+    /// is in shorthand now.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, ExprStruct};
+    ///
+    /// let mut expr: ExprStruct = parse_quote!(Point { x: x, y: y + 1 });
+    /// for field in &mut expr.fields {
+    ///     field.use_shorthand();
+    /// }
+    ///
+    /// let expected = quote!(Point { x, y: y + 1 });
+    /// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+    /// ```
+    pub fn use_shorthand(&mut self) -> bool {
+        if self.can_be_shorthand() {
+            self.colon_token = None;
+        }
+        self.is_shorthand()
+    }
+
+    /// Writes the field in full, as in `Point { x: x }`, if it is in
+    /// shorthand.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn expand_shorthand(&mut self) {
+        if self.colon_token.is_none() {
+            self.colon_token = Some(Token![:](member_span(&self.member)));
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+fn member_span(member: &Member) -> Span {
+    match member {
+        Member::Named(member) => member.span(),
+        Member::Unnamed(member) => member.span,
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{self, Parse, ParseStream, Result};
     use crate::path;
     #[cfg(feature = "full")]
     use proc_macro2::TokenTree;
@@ -1494,6 +1817,7 @@ pub(crate) mod parsing {
     // box <trailer>
     #[cfg(feature = "full")]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
+        let _recursion = parse::enter_recursion(input)?;
         let begin = input.fork();
         let attrs = input.call(expr_attrs)?;
         if input.peek(Token![&]) {
@@ -1531,6 +1855,7 @@ pub(crate) mod parsing {
 
     #[cfg(not(feature = "full"))]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
+        let _recursion = parse::enter_recursion(input)?;
         if input.peek(Token![*]) || input.peek(Token![!]) || input.peek(Token![-]) {
             Ok(Expr::Unary(ExprUnary {
                 attrs: Vec::new(),
@@ -2195,6 +2520,10 @@ pub(crate) mod parsing {
 
             let in_token: Token![in] = input.parse()?;
             let expr: Expr = input.call(Expr::parse_without_eager_brace)?;
+            #[cfg(feature = "verus")]
+            let spec = spec::parsing::loop_clauses(input)?;
+            #[cfg(not(feature = "verus"))]
+            let spec = None;
 
             let content;
             let brace_token = braced!(content in input);
@@ -2208,6 +2537,7 @@ pub(crate) mod parsing {
                 pat,
                 in_token,
                 expr: Box::new(expr),
+                spec,
                 body: Block { brace_token, stmts },
             })
         }
@@ -2220,6 +2550,10 @@ pub(crate) mod parsing {
             let mut attrs = input.call(Attribute::parse_outer)?;
             let label: Option<Label> = input.parse()?;
             let loop_token: Token![loop] = input.parse()?;
+            #[cfg(feature = "verus")]
+            let spec = spec::parsing::loop_clauses(input)?;
+            #[cfg(not(feature = "verus"))]
+            let spec = None;
 
             let content;
             let brace_token = braced!(content in input);
@@ -2230,6 +2564,7 @@ pub(crate) mod parsing {
                 attrs,
                 label,
                 loop_token,
+                spec,
                 body: Block { brace_token, stmts },
             })
         }
@@ -2532,6 +2867,10 @@ pub(crate) mod parsing {
             let label: Option<Label> = input.parse()?;
             let while_token: Token![while] = input.parse()?;
             let cond = Expr::parse_without_eager_brace(input)?;
+            #[cfg(feature = "verus")]
+            let spec = spec::parsing::loop_clauses(input)?;
+            #[cfg(not(feature = "verus"))]
+            let spec = None;
 
             let content;
             let brace_token = braced!(content in input);
@@ -2543,6 +2882,7 @@ pub(crate) mod parsing {
                 label,
                 while_token,
                 cond: Box::new(cond),
+                spec,
                 body: Block { brace_token, stmts },
             })
         }
@@ -2942,7 +3282,7 @@ pub(crate) mod printing {
     // If the given expression is a bare `ExprStruct`, wraps it in parenthesis
     // before appending it to `TokenStream`.
     #[cfg(feature = "full")]
-    fn wrap_bare_struct(tokens: &mut TokenStream, e: &Expr) {
+    pub(crate) fn wrap_bare_struct(tokens: &mut TokenStream, e: &Expr) {
         if let Expr::Struct(_) = *e {
             token::Paren::default().surround(tokens, |tokens| {
                 e.to_tokens(tokens);
@@ -3150,6 +3490,7 @@ pub(crate) mod printing {
             self.label.to_tokens(tokens);
             self.while_token.to_tokens(tokens);
             wrap_bare_struct(tokens, &self.cond);
+            self.spec.to_tokens(tokens);
             self.body.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 tokens.append_all(&self.body.stmts);
@@ -3167,6 +3508,7 @@ pub(crate) mod printing {
             self.pat.to_tokens(tokens);
             self.in_token.to_tokens(tokens);
             wrap_bare_struct(tokens, &self.expr);
+            self.spec.to_tokens(tokens);
             self.body.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 tokens.append_all(&self.body.stmts);
@@ -3181,6 +3523,7 @@ pub(crate) mod printing {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.label.to_tokens(tokens);
             self.loop_token.to_tokens(tokens);
+            self.spec.to_tokens(tokens);
             self.body.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 tokens.append_all(&self.body.stmts);
@@ -3517,6 +3860,10 @@ pub(crate) mod printing {
             if let Some(colon_token) = &self.colon_token {
                 colon_token.to_tokens(tokens);
                 self.expr.to_tokens(tokens);
+            } else if !self.can_be_shorthand() {
+                // The value of a shorthand field was replaced after parsing.
+                Token![:](member_span(&self.member)).to_tokens(tokens);
+                self.expr.to_tokens(tokens);
             }
         }
     }
@@ -7,7 +7,8 @@ use crate::parse::Peek;
 use crate::parse::{ParseStream, Result};
 use crate::sealed::lookahead;
 use crate::token::CustomToken;
-use proc_macro2::Ident;
+use crate::Error;
+use proc_macro2::{Ident, Span};
 
 /// Additional methods for `Ident` not provided by proc-macro2 or libproc_macro.
 ///
@@ -48,6 +49,45 @@ pub trait IdentExt: Sized + private::Sealed {
     /// ```
     fn parse_any(input: ParseStream) -> Result<Self>;
 
+    /// Creates an identifier, returning an error instead of panicking like
+    /// `Ident::new` if the string is not a valid identifier.
+    ///
+    /// A string of the form `r#name` creates a raw identifier. The error is
+    /// located at `span`, which makes it suitable for reporting names from an
+    /// external schema or configuration file that cannot be used in Rust.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use syn::Ident;
+    /// use syn::ext::IdentExt;
+    ///
+    /// let span = Span::call_site();
+    /// assert_eq!(Ident::try_new("field_1", span).unwrap(), "field_1");
+    /// assert_eq!(Ident::try_new("r#type", span).unwrap(), "r#type");
+    ///
+    /// let err = Ident::try_new("1st-field", span).unwrap_err();
+    /// assert_eq!(err.to_string(), "`1st-field` is not a valid identifier");
+    /// ```
+    fn try_new(string: &str, span: Span) -> Result<Self>;
+
+    /// Creates the closest valid identifier to the given string.
+    ///
+    ///   - new_sanitized(`field-name`) = `field_name`
+    ///   - new_sanitized(`2d`) = `_2d`
+    ///   - new_sanitized(`type`) = `r#type`
+    ///   - new_sanitized(`self`) = `self_`
+    ///   - new_sanitized(``) = `_`
+    ///
+    /// Characters that cannot occur in an identifier are replaced with `_`,
+    /// and a `_` is prepended if the first character cannot start one.
+    /// Keywords become raw identifiers, or get a trailing underscore for the
+    /// keywords that cannot be raw identifiers (`self`, `Self`, `super`,
+    /// `crate`), as in [`to_snake_case`][IdentExt::to_snake_case]. Valid
+    /// identifiers are returned unchanged.
+    fn new_sanitized(string: &str, span: Span) -> Self;
+
     /// Peeks any identifier including keywords. Usage:
     /// `input.peek(Ident::peek_any)`
     ///
@@ -84,6 +124,58 @@ pub trait IdentExt: Sized + private::Sealed {
     /// }
     /// ```
     fn unraw(&self) -> Ident;
+
+    /// Converts an identifier to `snake_case`, keeping its span.
+    ///
+    ///   - to_snake_case(`MyType`) = `my_type`
+    ///   - to_snake_case(`HTTPServer`) = `http_server`
+    ///   - to_snake_case(`Http2Server`) = `http2_server`
+    ///   - to_snake_case(`r#Loop`) = `r#loop`
+    ///
+    /// Word boundaries are underscores, a lowercase letter or digit followed
+    /// by an uppercase letter, and the last uppercase letter of an acronym
+    /// that is followed by a lowercase letter. Digits belong to the word they
+    /// follow. Leading underscores are preserved.
+    ///
+    /// If the converted name is a keyword, it is returned as a raw identifier,
+    /// or with a trailing underscore for the keywords that cannot be raw
+    /// identifiers (`self`, `Self`, `super`, `crate`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use syn::Ident;
+    /// use syn::ext::IdentExt;
+    ///
+    /// // Name of the module generated for a derive input like `struct HttpClient`.
+    /// fn module_name(type_name: &Ident) -> Ident {
+    ///     let snake = type_name.to_snake_case();
+    ///     Ident::new(&format!("__{}_impl", snake), type_name.span())
+    /// }
+    /// ```
+    fn to_snake_case(&self) -> Ident;
+
+    /// Converts an identifier to `UpperCamelCase`, which is the case used for
+    /// type names, keeping its span.
+    ///
+    ///   - to_camel_case(`my_type`) = `MyType`
+    ///   - to_camel_case(`http2_server`) = `Http2Server`
+    ///   - to_camel_case(`SCREAMING_CASE`) = `ScreamingCase`
+    ///
+    /// Word boundaries and keywords are handled as in
+    /// [`to_snake_case`][IdentExt::to_snake_case].
+    fn to_camel_case(&self) -> Ident;
+
+    /// Converts an identifier to `SCREAMING_SNAKE_CASE`, which is the case
+    /// used for constants and statics, keeping its span.
+    ///
+    ///   - to_screaming_snake_case(`MyType`) = `MY_TYPE`
+    ///   - to_screaming_snake_case(`max_value`) = `MAX_VALUE`
+    ///
+    /// Word boundaries and keywords are handled as in
+    /// [`to_snake_case`][IdentExt::to_snake_case].
+    fn to_screaming_snake_case(&self) -> Ident;
 }
 
 impl IdentExt for Ident {
@@ -94,6 +186,57 @@ impl IdentExt for Ident {
         })
     }
 
+    fn try_new(string: &str, span: Span) -> Result<Self> {
+        let (name, raw) = if string.starts_with("r#") {
+            (&string[2..], true)
+        } else {
+            (string, false)
+        };
+        if name.is_empty() || !crate::ident::xid_ok(name) {
+            return Err(Error::new(
+                span,
+                format!("`{}` is not a valid identifier", string),
+            ));
+        }
+        if raw {
+            match name {
+                "_" | "self" | "Self" | "super" | "crate" => Err(Error::new(
+                    span,
+                    format!("`{}` cannot be a raw identifier", name),
+                )),
+                _ => Ok(Ident::new_raw(name, span)),
+            }
+        } else {
+            Ok(Ident::new(name, span))
+        }
+    }
+
+    fn new_sanitized(string: &str, span: Span) -> Self {
+        let name = if string.starts_with("r#") {
+            &string[2..]
+        } else {
+            string
+        };
+        let mut sanitized = String::new();
+        for (i, ch) in name.chars().enumerate() {
+            if i == 0 && ch != '_' && !unicode_ident::is_xid_start(ch) {
+                sanitized.push('_');
+                if !unicode_ident::is_xid_continue(ch) {
+                    continue;
+                }
+            }
+            if unicode_ident::is_xid_continue(ch) {
+                sanitized.push(ch);
+            } else {
+                sanitized.push('_');
+            }
+        }
+        if sanitized.is_empty() {
+            sanitized.push('_');
+        }
+        keyword_safe(sanitized, span)
+    }
+
     fn unraw(&self) -> Ident {
         let string = self.to_string();
         if string.starts_with("r#") {
@@ -102,6 +245,96 @@ impl IdentExt for Ident {
             self.clone()
         }
     }
+
+    fn to_snake_case(&self) -> Ident {
+        convert_case(self, Case::Snake)
+    }
+
+    fn to_camel_case(&self) -> Ident {
+        convert_case(self, Case::Camel)
+    }
+
+    fn to_screaming_snake_case(&self) -> Ident {
+        convert_case(self, Case::ScreamingSnake)
+    }
+}
+
+enum Case {
+    Snake,
+    Camel,
+    ScreamingSnake,
+}
+
+fn convert_case(ident: &Ident, case: Case) -> Ident {
+    let string = ident.unraw().to_string();
+    let rest = string.trim_start_matches('_');
+    let words = split_words(rest);
+    if words.is_empty() {
+        return ident.clone();
+    }
+
+    let mut converted = string[..string.len() - rest.len()].to_owned();
+    for (i, word) in words.iter().enumerate() {
+        match case {
+            Case::Snake | Case::ScreamingSnake if i > 0 => converted.push('_'),
+            _ => {}
+        }
+        match case {
+            Case::Snake => converted.extend(word.chars().flat_map(char::to_lowercase)),
+            Case::ScreamingSnake => converted.extend(word.chars().flat_map(char::to_uppercase)),
+            Case::Camel => {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    converted.extend(first.to_uppercase());
+                }
+                converted.extend(chars.flat_map(char::to_lowercase));
+            }
+        }
+    }
+
+    keyword_safe(converted, ident.span())
+}
+
+// Turns a valid identifier that might be a keyword into one that can be used
+// as a name.
+fn keyword_safe(mut converted: String, span: Span) -> Ident {
+    match converted.as_str() {
+        "_" => Ident::new(&converted, span),
+        "self" | "Self" | "super" | "crate" => {
+            converted.push('_');
+            Ident::new(&converted, span)
+        }
+        // Keywords of the 2018 edition onward, which Syn accepts as identifiers
+        // for compatibility with the 2015 edition.
+        "async" | "await" | "dyn" | "try" => Ident::new_raw(&converted, span),
+        _ if crate::ident::accept_as_ident(&Ident::new(&converted, span)) => {
+            Ident::new(&converted, span)
+        }
+        _ => Ident::new_raw(&converted, span),
+    }
+}
+
+fn split_words(string: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for segment in string.split('_').filter(|segment| !segment.is_empty()) {
+        let chars: Vec<(usize, char)> = segment.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let prev = chars[i - 1].1;
+            let (offset, ch) = chars[i];
+            let next_is_lower = chars.get(i + 1).map_or(false, |next| next.1.is_lowercase());
+            let boundary = ch.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || prev.is_uppercase() && next_is_lower);
+            if boundary {
+                words.push(&segment[start..offset]);
+                start = offset;
+            }
+        }
+        words.push(&segment[start..]);
+    }
+    words
 }
 
 impl Peek for private::PeekFn {
@@ -0,0 +1,440 @@
+//! Syntactic checks of whether types can cross a foreign function interface.
+//!
+//! Macros that generate bindings to or from C need to reject the types that
+//! have no C equivalent before the generated code reaches rustc, whose
+//! `improper_ctypes` lint can only warn about them and points into the
+//! generated code rather than at the user's type. The checks in this module
+//! report an error spanned to each part of a type or function signature that
+//! is not FFI-safe:
+//!
+//! - references, which carry a lifetime that the foreign code cannot
+//!   uphold, and should be raw pointers instead,
+//! - trait objects, `impl Trait`, slices, `str` and tuples, and raw pointers
+//!   to the unsized ones among them,
+//! - generic type parameters of the signature,
+//! - function pointers with the Rust ABI,
+//! - `char`, 128-bit integers, and arrays passed by value,
+//! - every other named type that is not known to have a C-compatible layout.
+//!
+//! The primitive integer and floating point types, `bool`, the `c_*` types of
+//! `std::os::raw`, `NonNull<T>`, and `Option` of a non-Rust function pointer
+//! or of `NonNull<T>` are known to be FFI-safe. Any other type is known to be
+//! FFI-safe only if it is added to the [`FfiTypes`] that the check is run
+//! with, usually from the items of the crate that are `#[repr(C)]`,
+//! `#[repr(transparent)]` or `#[repr]` of an integer type.
+//!
+//! The checks are purely syntactic. Types are recognized by the last
+//! segment of their path, so a type alias or a type that shadows one of the
+//! names above is not seen through, and the fields of the added items are
+//! not looked into.
+//!
+//! *This module is available only if Syn is built with the `"printing"`
+//! feature and the `"derive"` or `"full"` feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::ffi::FfiTypes;
+//! use syn::{parse_quote, ItemStruct, Signature};
+//!
+//! let item: ItemStruct = parse_quote! {
+//!     #[repr(C)]
+//!     struct Point {
+//!         x: f64,
+//!         y: f64,
+//!     }
+//! };
+//! let mut types = FfiTypes::new();
+//! types.add_item(&item.into());
+//!
+//! let sig: Signature = parse_quote! {
+//!     extern "C" fn distance(a: *const Point, b: &Point, tag: String) -> f64
+//! };
+//! let err = types.check_signature(&sig).unwrap_err();
+//! let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+//! assert_eq!(
+//!     messages,
+//!     [
+//!         "references are not FFI-safe, use a raw pointer instead",
+//!         "type `String` is not known to be FFI-safe, it needs `#[repr(C)]` or `#[repr(transparent)]`",
+//!     ],
+//! );
+//! ```
+
+use super::*;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use std::collections::HashSet;
+
+/// The types other than the built-in ones that are known to be FFI-safe.
+///
+/// *This type is available only if Syn is built with the `"printing"`
+/// feature and the `"derive"` or `"full"` feature.*
+pub struct FfiTypes {
+    names: HashSet<String>,
+}
+
+impl FfiTypes {
+    /// Creates a set that knows only the built-in FFI-safe types.
+    pub fn new() -> Self {
+        FfiTypes {
+            names: HashSet::new(),
+        }
+    }
+
+    /// Adds a type to the set by name.
+    pub fn add(&mut self, ident: &Ident) {
+        self.names.insert(ident.to_string());
+    }
+
+    /// Adds the type of a struct, enum or union if its attributes give it a
+    /// C-compatible layout, and returns whether it was added.
+    ///
+    /// *This method is available only if Syn is built with the `"derive"` and
+    /// `"printing"` features.*
+    #[cfg(feature = "derive")]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "derive", feature = "printing"))))]
+    pub fn add_item(&mut self, input: &DeriveInput) -> bool {
+        self.add_if_repr(&input.attrs, &input.ident)
+    }
+
+    /// Adds the types of the structs, enums and unions among the items whose
+    /// attributes give them a C-compatible layout, including those in inline
+    /// modules.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"printing"` features.*
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "printing"))))]
+    pub fn add_items(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Struct(item) => {
+                    self.add_if_repr(&item.attrs, &item.ident);
+                }
+                Item::Enum(item) => {
+                    self.add_if_repr(&item.attrs, &item.ident);
+                }
+                Item::Union(item) => {
+                    self.add_if_repr(&item.attrs, &item.ident);
+                }
+                Item::Mod(item) => {
+                    if let Some((_brace, items)) = &item.content {
+                        self.add_items(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn add_if_repr(&mut self, attrs: &[Attribute], ident: &Ident) -> bool {
+        let has_repr = attrs
+            .iter()
+            .any(|attr| is_ident(&attr.path, "repr") && has_c_layout(attr.tokens.clone()));
+        if has_repr {
+            self.add(ident);
+        }
+        has_repr
+    }
+
+    /// Checks a type that is passed by value to or from a foreign function.
+    ///
+    /// Returns an error with a message spanned to each part of the type that
+    /// is not FFI-safe.
+    pub fn check_type(&self, ty: &Type) -> Result<()> {
+        let mut checker = Checker {
+            types: self,
+            generics: HashSet::new(),
+            errors: None,
+        };
+        checker.check(ty, true);
+        checker.finish()
+    }
+
+    /// Checks the parameters and return type of a function that is called
+    /// from or calls into foreign code.
+    ///
+    /// Besides the types, this rejects a `self` parameter, generic type and
+    /// const parameters, `async`, and an explicit `extern "Rust"`. A
+    /// signature without an ABI is accepted, since the ABI is often added by
+    /// the macro that checks the signature.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"printing"` features.*
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "printing"))))]
+    pub fn check_signature(&self, sig: &Signature) -> Result<()> {
+        let mut checker = Checker {
+            types: self,
+            generics: HashSet::new(),
+            errors: None,
+        };
+        if let Some(abi) = &sig.abi {
+            if is_rust_abi(abi) {
+                checker.error(abi, "the Rust ABI is not FFI-safe");
+            }
+        }
+        if let Some(asyncness) = &sig.asyncness {
+            checker.error(asyncness, "async functions cannot be called through FFI");
+        }
+        for param in sig.generics.params.iter() {
+            match param {
+                GenericParam::Type(param) => {
+                    checker.generics.insert(param.ident.to_string());
+                    checker.error(
+                        &param.ident,
+                        "generic functions cannot be called through FFI",
+                    );
+                }
+                GenericParam::Const(param) => {
+                    checker.error(
+                        &param.ident,
+                        "generic functions cannot be called through FFI",
+                    );
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+        for input in &sig.inputs {
+            match input {
+                FnArg::Receiver(receiver) => {
+                    checker.error(receiver, "`self` is not FFI-safe");
+                }
+                FnArg::Typed(arg) => checker.check(&arg.ty, true),
+            }
+        }
+        checker.check_return(&sig.output);
+        checker.finish()
+    }
+}
+
+impl Default for FfiTypes {
+    fn default() -> Self {
+        FfiTypes::new()
+    }
+}
+
+// Whether the arguments of a `#[repr(...)]` give a type a C-compatible
+// layout.
+fn has_c_layout(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Group(group) => has_c_layout(group.stream()),
+        TokenTree::Ident(ident) => {
+            let name = ident.to_string();
+            name == "C" || name == "transparent" || is_integer(&name)
+        }
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+fn is_integer(name: &str) -> bool {
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => true,
+        _ => false,
+    }
+}
+
+fn is_rust_abi(abi: &Abi) -> bool {
+    abi.is_rust()
+        || match abi.name_value() {
+            Some(name) => name.starts_with("rust-"),
+            None => false,
+        }
+}
+
+struct Checker<'a> {
+    types: &'a FfiTypes,
+    generics: HashSet<String>,
+    errors: Option<Error>,
+}
+
+impl<'a> Checker<'a> {
+    fn error<T: ToTokens>(&mut self, node: &T, message: &str) {
+        let error = Error::new_spanned(node, message);
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+
+    fn check_return(&mut self, output: &ReturnType) {
+        if let ReturnType::Type(_arrow, ty) = output {
+            match &**ty {
+                Type::Never(_) => {}
+                Type::Tuple(tuple) if tuple.elems.is_empty() => {}
+                ty => self.check(ty, true),
+            }
+        }
+    }
+
+    // Checks a type, which is passed by value unless it is the type argument
+    // of a known generic type.
+    fn check(&mut self, ty: &Type, by_value: bool) {
+        match ty {
+            Type::Array(ty) => {
+                if by_value {
+                    let message =
+                        "arrays cannot be passed by value through FFI, use a pointer instead";
+                    self.error(ty, message);
+                } else {
+                    self.check(&ty.elem, false);
+                }
+            }
+            Type::BareFn(ty) => {
+                match &ty.abi {
+                    Some(abi) if !is_rust_abi(abi) => {}
+                    _ => self.error(
+                        ty,
+                        "function pointers need a non-Rust ABI, like `extern \"C\" fn`",
+                    ),
+                }
+                for input in &ty.inputs {
+                    self.check(&input.ty, true);
+                }
+                self.check_return(&ty.output);
+            }
+            Type::Group(ty) => self.check(&ty.elem, by_value),
+            Type::ImplTrait(ty) => self.error(ty, "`impl Trait` is not FFI-safe"),
+            Type::Never(ty) => self.error(ty, "`!` is only FFI-safe as a return type"),
+            Type::Paren(ty) => self.check(&ty.elem, by_value),
+            Type::Path(ty) => self.check_path(ty),
+            Type::Ptr(ty) => self.check_pointee(&ty.elem),
+            Type::Reference(ty) => {
+                self.error(ty, "references are not FFI-safe, use a raw pointer instead");
+            }
+            Type::Slice(ty) => self.error(
+                ty,
+                "slices are not FFI-safe, use a pointer and a length instead",
+            ),
+            Type::TraitObject(ty) => self.error(ty, "trait objects are not FFI-safe"),
+            Type::Tuple(ty) => {
+                if ty.elems.is_empty() {
+                    self.error(ty, "`()` is only FFI-safe as a return type");
+                } else {
+                    self.error(
+                        ty,
+                        "tuples are not FFI-safe, use a `#[repr(C)]` struct instead",
+                    );
+                }
+            }
+            _ => self.error(ty, "type cannot be checked for FFI safety"),
+        }
+    }
+
+    // Pointers to sized types are FFI-safe whatever the type, but pointers
+    // to unsized types are twice as wide.
+    fn check_pointee(&mut self, ty: &Type) {
+        let unsized_type = match ty {
+            Type::Group(ty) => return self.check_pointee(&ty.elem),
+            Type::Paren(ty) => return self.check_pointee(&ty.elem),
+            Type::ImplTrait(_) | Type::Slice(_) | Type::TraitObject(_) => true,
+            Type::Path(ty) => ty.qself.is_none() && is_ident(&ty.path, "str"),
+            _ => false,
+        };
+        if unsized_type {
+            self.error(ty, "pointers to unsized types are not FFI-safe");
+        }
+    }
+
+    fn check_path(&mut self, ty: &TypePath) {
+        if ty.qself.is_some() {
+            self.error(ty, "associated types cannot be checked for FFI safety");
+            return;
+        }
+        let segment = match ty.path.segments.last() {
+            Some(segment) => segment,
+            None => return,
+        };
+        let name = segment.ident.to_string();
+        let args: Vec<&Type> = match &segment.arguments {
+            PathArguments::None => Vec::new(),
+            PathArguments::AngleBracketed(arguments) => arguments
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+            PathArguments::Parenthesized(_) => {
+                self.error(ty, "type cannot be checked for FFI safety");
+                return;
+            }
+        };
+
+        if ty.path.segments.len() == 1 && self.generics.contains(&name) {
+            self.error(
+                ty,
+                &format!("generic type parameter `{}` is not FFI-safe", name),
+            );
+            return;
+        }
+        match name.as_str() {
+            "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize"
+            | "f32" | "f64" | "bool" | "c_char" | "c_schar" | "c_uchar" | "c_short"
+            | "c_ushort" | "c_int" | "c_uint" | "c_long" | "c_ulong" | "c_longlong"
+            | "c_ulonglong" | "c_float" | "c_double" | "c_void" => {}
+            "u128" | "i128" => self.error(ty, "128-bit integers do not have a stable ABI"),
+            "char" => self.error(ty, "`char` is not FFI-safe, use `u32` instead"),
+            "str" => self.error(
+                ty,
+                "`str` is not FFI-safe, use a pointer and a length instead",
+            ),
+            "NonNull" if args.len() == 1 => self.check_pointee(args[0]),
+            "Option" if args.len() == 1 => match strip_parens(args[0]) {
+                Type::BareFn(_) => self.check(args[0], false),
+                Type::Path(arg) if is_non_null(arg) => self.check(args[0], false),
+                _ => self.error(
+                    ty,
+                    "`Option` is only FFI-safe around a function pointer or `NonNull`",
+                ),
+            },
+            _ => {
+                if self.types.names.contains(&name) {
+                    for arg in args {
+                        self.check(arg, false);
+                    }
+                } else {
+                    let message = format!(
+                        "type `{}` is not known to be FFI-safe, it needs `#[repr(C)]` or `#[repr(transparent)]`",
+                        name,
+                    );
+                    self.error(ty, &message);
+                }
+            }
+        }
+    }
+}
+
+// Like `Path::is_ident`, which needs the "parsing" feature.
+fn is_ident(path: &Path, name: &str) -> bool {
+    path.leading_colon.is_none()
+        && path.segments.len() == 1
+        && path.segments[0].arguments.is_none()
+        && path.segments[0].ident == name
+}
+
+fn strip_parens(mut ty: &Type) -> &Type {
+    loop {
+        ty = match ty {
+            Type::Group(group) => &group.elem,
+            Type::Paren(paren) => &paren.elem,
+            _ => return ty,
+        };
+    }
+}
+
+fn is_non_null(ty: &TypePath) -> bool {
+    match ty.path.segments.last() {
+        Some(segment) => ty.qself.is_none() && segment.ident == "NonNull",
+        None => false,
+    }
+}
@@ -0,0 +1,8532 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(clippy::match_like_matches_macro)]
+use crate::*;
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl AbiName {
+    ///Returns whether this is `AbiName::Rust`.
+    pub fn is_rust(&self) -> bool {
+        match self {
+            AbiName::Rust(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Rust`, or `None` if this is a different variant.
+    pub fn as_rust(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Rust(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::C`.
+    pub fn is_c(&self) -> bool {
+        match self {
+            AbiName::C(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::C`, or `None` if this is a different variant.
+    pub fn as_c(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::C(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::CUnwind`.
+    pub fn is_cunwind(&self) -> bool {
+        match self {
+            AbiName::CUnwind(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::CUnwind`, or `None` if this is a different variant.
+    pub fn as_cunwind(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::CUnwind(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::System`.
+    pub fn is_system(&self) -> bool {
+        match self {
+            AbiName::System(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::System`, or `None` if this is a different variant.
+    pub fn as_system(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::System(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::SystemUnwind`.
+    pub fn is_system_unwind(&self) -> bool {
+        match self {
+            AbiName::SystemUnwind(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::SystemUnwind`, or `None` if this is a different variant.
+    pub fn as_system_unwind(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::SystemUnwind(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Cdecl`.
+    pub fn is_cdecl(&self) -> bool {
+        match self {
+            AbiName::Cdecl(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Cdecl`, or `None` if this is a different variant.
+    pub fn as_cdecl(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Cdecl(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Stdcall`.
+    pub fn is_stdcall(&self) -> bool {
+        match self {
+            AbiName::Stdcall(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Stdcall`, or `None` if this is a different variant.
+    pub fn as_stdcall(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Stdcall(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Fastcall`.
+    pub fn is_fastcall(&self) -> bool {
+        match self {
+            AbiName::Fastcall(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Fastcall`, or `None` if this is a different variant.
+    pub fn as_fastcall(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Fastcall(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Vectorcall`.
+    pub fn is_vectorcall(&self) -> bool {
+        match self {
+            AbiName::Vectorcall(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Vectorcall`, or `None` if this is a different variant.
+    pub fn as_vectorcall(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Vectorcall(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Thiscall`.
+    pub fn is_thiscall(&self) -> bool {
+        match self {
+            AbiName::Thiscall(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Thiscall`, or `None` if this is a different variant.
+    pub fn as_thiscall(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Thiscall(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Aapcs`.
+    pub fn is_aapcs(&self) -> bool {
+        match self {
+            AbiName::Aapcs(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Aapcs`, or `None` if this is a different variant.
+    pub fn as_aapcs(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Aapcs(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Win64`.
+    pub fn is_win64(&self) -> bool {
+        match self {
+            AbiName::Win64(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Win64`, or `None` if this is a different variant.
+    pub fn as_win64(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Win64(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Sysv64`.
+    pub fn is_sysv64(&self) -> bool {
+        match self {
+            AbiName::Sysv64(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Sysv64`, or `None` if this is a different variant.
+    pub fn as_sysv64(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Sysv64(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Efiapi`.
+    pub fn is_efiapi(&self) -> bool {
+        match self {
+            AbiName::Efiapi(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Efiapi`, or `None` if this is a different variant.
+    pub fn as_efiapi(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Efiapi(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `AbiName::Other`.
+    pub fn is_other(&self) -> bool {
+        match self {
+            AbiName::Other(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AbiName::Other`, or `None` if this is a different variant.
+    pub fn as_other(&self) -> Option<&LitStr> {
+        match self {
+            AbiName::Other(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl AttrStyle {
+    ///Returns whether this is `AttrStyle::Outer`.
+    pub fn is_outer(&self) -> bool {
+        match self {
+            AttrStyle::Outer => true,
+            _ => false,
+        }
+    }
+    ///Returns whether this is `AttrStyle::Inner`.
+    pub fn is_inner(&self) -> bool {
+        match self {
+            AttrStyle::Inner(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `AttrStyle::Inner`, or `None` if this is a different variant.
+    pub fn as_inner(&self) -> Option<&token::Bang> {
+        match self {
+            AttrStyle::Inner(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl BinOp {
+    ///Returns whether this is `BinOp::Add`.
+    pub fn is_add(&self) -> bool {
+        match self {
+            BinOp::Add(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Add`, or `None` if this is a different variant.
+    pub fn as_add(&self) -> Option<&token::Add> {
+        match self {
+            BinOp::Add(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Sub`.
+    pub fn is_sub(&self) -> bool {
+        match self {
+            BinOp::Sub(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Sub`, or `None` if this is a different variant.
+    pub fn as_sub(&self) -> Option<&token::Sub> {
+        match self {
+            BinOp::Sub(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Mul`.
+    pub fn is_mul(&self) -> bool {
+        match self {
+            BinOp::Mul(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Mul`, or `None` if this is a different variant.
+    pub fn as_mul(&self) -> Option<&token::Star> {
+        match self {
+            BinOp::Mul(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Div`.
+    pub fn is_div(&self) -> bool {
+        match self {
+            BinOp::Div(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Div`, or `None` if this is a different variant.
+    pub fn as_div(&self) -> Option<&token::Div> {
+        match self {
+            BinOp::Div(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Rem`.
+    pub fn is_rem(&self) -> bool {
+        match self {
+            BinOp::Rem(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Rem`, or `None` if this is a different variant.
+    pub fn as_rem(&self) -> Option<&token::Rem> {
+        match self {
+            BinOp::Rem(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::And`.
+    pub fn is_and(&self) -> bool {
+        match self {
+            BinOp::And(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::And`, or `None` if this is a different variant.
+    pub fn as_and(&self) -> Option<&token::AndAnd> {
+        match self {
+            BinOp::And(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Or`.
+    pub fn is_or(&self) -> bool {
+        match self {
+            BinOp::Or(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Or`, or `None` if this is a different variant.
+    pub fn as_or(&self) -> Option<&token::OrOr> {
+        match self {
+            BinOp::Or(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitXor`.
+    pub fn is_bit_xor(&self) -> bool {
+        match self {
+            BinOp::BitXor(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitXor`, or `None` if this is a different variant.
+    pub fn as_bit_xor(&self) -> Option<&token::Caret> {
+        match self {
+            BinOp::BitXor(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitAnd`.
+    pub fn is_bit_and(&self) -> bool {
+        match self {
+            BinOp::BitAnd(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitAnd`, or `None` if this is a different variant.
+    pub fn as_bit_and(&self) -> Option<&token::And> {
+        match self {
+            BinOp::BitAnd(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitOr`.
+    pub fn is_bit_or(&self) -> bool {
+        match self {
+            BinOp::BitOr(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitOr`, or `None` if this is a different variant.
+    pub fn as_bit_or(&self) -> Option<&token::Or> {
+        match self {
+            BinOp::BitOr(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Shl`.
+    pub fn is_shl(&self) -> bool {
+        match self {
+            BinOp::Shl(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Shl`, or `None` if this is a different variant.
+    pub fn as_shl(&self) -> Option<&token::Shl> {
+        match self {
+            BinOp::Shl(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Shr`.
+    pub fn is_shr(&self) -> bool {
+        match self {
+            BinOp::Shr(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Shr`, or `None` if this is a different variant.
+    pub fn as_shr(&self) -> Option<&token::Shr> {
+        match self {
+            BinOp::Shr(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Eq`.
+    pub fn is_eq(&self) -> bool {
+        match self {
+            BinOp::Eq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Eq`, or `None` if this is a different variant.
+    pub fn as_eq(&self) -> Option<&token::EqEq> {
+        match self {
+            BinOp::Eq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Lt`.
+    pub fn is_lt(&self) -> bool {
+        match self {
+            BinOp::Lt(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Lt`, or `None` if this is a different variant.
+    pub fn as_lt(&self) -> Option<&token::Lt> {
+        match self {
+            BinOp::Lt(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Le`.
+    pub fn is_le(&self) -> bool {
+        match self {
+            BinOp::Le(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Le`, or `None` if this is a different variant.
+    pub fn as_le(&self) -> Option<&token::Le> {
+        match self {
+            BinOp::Le(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Ne`.
+    pub fn is_ne(&self) -> bool {
+        match self {
+            BinOp::Ne(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Ne`, or `None` if this is a different variant.
+    pub fn as_ne(&self) -> Option<&token::Ne> {
+        match self {
+            BinOp::Ne(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Ge`.
+    pub fn is_ge(&self) -> bool {
+        match self {
+            BinOp::Ge(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Ge`, or `None` if this is a different variant.
+    pub fn as_ge(&self) -> Option<&token::Ge> {
+        match self {
+            BinOp::Ge(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::Gt`.
+    pub fn is_gt(&self) -> bool {
+        match self {
+            BinOp::Gt(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::Gt`, or `None` if this is a different variant.
+    pub fn as_gt(&self) -> Option<&token::Gt> {
+        match self {
+            BinOp::Gt(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::AddEq`.
+    pub fn is_add_eq(&self) -> bool {
+        match self {
+            BinOp::AddEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::AddEq`, or `None` if this is a different variant.
+    pub fn as_add_eq(&self) -> Option<&token::AddEq> {
+        match self {
+            BinOp::AddEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::SubEq`.
+    pub fn is_sub_eq(&self) -> bool {
+        match self {
+            BinOp::SubEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::SubEq`, or `None` if this is a different variant.
+    pub fn as_sub_eq(&self) -> Option<&token::SubEq> {
+        match self {
+            BinOp::SubEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::MulEq`.
+    pub fn is_mul_eq(&self) -> bool {
+        match self {
+            BinOp::MulEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::MulEq`, or `None` if this is a different variant.
+    pub fn as_mul_eq(&self) -> Option<&token::MulEq> {
+        match self {
+            BinOp::MulEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::DivEq`.
+    pub fn is_div_eq(&self) -> bool {
+        match self {
+            BinOp::DivEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::DivEq`, or `None` if this is a different variant.
+    pub fn as_div_eq(&self) -> Option<&token::DivEq> {
+        match self {
+            BinOp::DivEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::RemEq`.
+    pub fn is_rem_eq(&self) -> bool {
+        match self {
+            BinOp::RemEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::RemEq`, or `None` if this is a different variant.
+    pub fn as_rem_eq(&self) -> Option<&token::RemEq> {
+        match self {
+            BinOp::RemEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitXorEq`.
+    pub fn is_bit_xor_eq(&self) -> bool {
+        match self {
+            BinOp::BitXorEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitXorEq`, or `None` if this is a different variant.
+    pub fn as_bit_xor_eq(&self) -> Option<&token::CaretEq> {
+        match self {
+            BinOp::BitXorEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitAndEq`.
+    pub fn is_bit_and_eq(&self) -> bool {
+        match self {
+            BinOp::BitAndEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitAndEq`, or `None` if this is a different variant.
+    pub fn as_bit_and_eq(&self) -> Option<&token::AndEq> {
+        match self {
+            BinOp::BitAndEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::BitOrEq`.
+    pub fn is_bit_or_eq(&self) -> bool {
+        match self {
+            BinOp::BitOrEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::BitOrEq`, or `None` if this is a different variant.
+    pub fn as_bit_or_eq(&self) -> Option<&token::OrEq> {
+        match self {
+            BinOp::BitOrEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::ShlEq`.
+    pub fn is_shl_eq(&self) -> bool {
+        match self {
+            BinOp::ShlEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::ShlEq`, or `None` if this is a different variant.
+    pub fn as_shl_eq(&self) -> Option<&token::ShlEq> {
+        match self {
+            BinOp::ShlEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `BinOp::ShrEq`.
+    pub fn is_shr_eq(&self) -> bool {
+        match self {
+            BinOp::ShrEq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `BinOp::ShrEq`, or `None` if this is a different variant.
+    pub fn as_shr_eq(&self) -> Option<&token::ShrEq> {
+        match self {
+            BinOp::ShrEq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl CfgExpr {
+    ///Returns whether this is `CfgExpr::Key`.
+    pub fn is_key(&self) -> bool {
+        match self {
+            CfgExpr::Key(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `CfgExpr::Key`, or `None` if this is a different variant.
+    pub fn as_key(&self) -> Option<&proc_macro2::Ident> {
+        match self {
+            CfgExpr::Key(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `CfgExpr::KeyValue`.
+    pub fn is_key_value(&self) -> bool {
+        match self {
+            CfgExpr::KeyValue(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `CfgExpr::KeyValue`, or `None` if this is a different variant.
+    pub fn as_key_value(&self) -> Option<&CfgKeyValue> {
+        match self {
+            CfgExpr::KeyValue(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `CfgExpr::All`.
+    pub fn is_all(&self) -> bool {
+        match self {
+            CfgExpr::All(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `CfgExpr::All`, or `None` if this is a different variant.
+    pub fn as_all(&self) -> Option<&CfgAll> {
+        match self {
+            CfgExpr::All(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `CfgExpr::Any`.
+    pub fn is_any(&self) -> bool {
+        match self {
+            CfgExpr::Any(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `CfgExpr::Any`, or `None` if this is a different variant.
+    pub fn as_any(&self) -> Option<&CfgAny> {
+        match self {
+            CfgExpr::Any(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `CfgExpr::Not`.
+    pub fn is_not(&self) -> bool {
+        match self {
+            CfgExpr::Not(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `CfgExpr::Not`, or `None` if this is a different variant.
+    pub fn as_not(&self) -> Option<&CfgNot> {
+        match self {
+            CfgExpr::Not(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Data {
+    ///Returns whether this is `Data::Struct`.
+    pub fn is_struct(&self) -> bool {
+        match self {
+            Data::Struct(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Data::Struct`, or `None` if this is a different variant.
+    pub fn as_struct(&self) -> Option<&DataStruct> {
+        match self {
+            Data::Struct(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Data::Enum`.
+    pub fn is_enum(&self) -> bool {
+        match self {
+            Data::Enum(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Data::Enum`, or `None` if this is a different variant.
+    pub fn as_enum(&self) -> Option<&DataEnum> {
+        match self {
+            Data::Enum(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Data::Union`.
+    pub fn is_union(&self) -> bool {
+        match self {
+            Data::Union(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Data::Union`, or `None` if this is a different variant.
+    pub fn as_union(&self) -> Option<&DataUnion> {
+        match self {
+            Data::Union(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Expr {
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Array`.
+    pub fn is_array(&self) -> bool {
+        match self {
+            Expr::Array(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Array`, or `None` if this is a different variant.
+    pub fn as_array(&self) -> Option<&ExprArray> {
+        match self {
+            Expr::Array(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Assign`.
+    pub fn is_assign(&self) -> bool {
+        match self {
+            Expr::Assign(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Assign`, or `None` if this is a different variant.
+    pub fn as_assign(&self) -> Option<&ExprAssign> {
+        match self {
+            Expr::Assign(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::AssignOp`.
+    pub fn is_assign_op(&self) -> bool {
+        match self {
+            Expr::AssignOp(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::AssignOp`, or `None` if this is a different variant.
+    pub fn as_assign_op(&self) -> Option<&ExprAssignOp> {
+        match self {
+            Expr::AssignOp(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Async`.
+    pub fn is_async(&self) -> bool {
+        match self {
+            Expr::Async(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Async`, or `None` if this is a different variant.
+    pub fn as_async(&self) -> Option<&ExprAsync> {
+        match self {
+            Expr::Async(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Await`.
+    pub fn is_await(&self) -> bool {
+        match self {
+            Expr::Await(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Await`, or `None` if this is a different variant.
+    pub fn as_await(&self) -> Option<&ExprAwait> {
+        match self {
+            Expr::Await(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Binary`.
+    pub fn is_binary(&self) -> bool {
+        match self {
+            Expr::Binary(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Binary`, or `None` if this is a different variant.
+    pub fn as_binary(&self) -> Option<&ExprBinary> {
+        match self {
+            Expr::Binary(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Block`.
+    pub fn is_block(&self) -> bool {
+        match self {
+            Expr::Block(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Block`, or `None` if this is a different variant.
+    pub fn as_block(&self) -> Option<&ExprBlock> {
+        match self {
+            Expr::Block(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Box`.
+    pub fn is_box(&self) -> bool {
+        match self {
+            Expr::Box(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Box`, or `None` if this is a different variant.
+    pub fn as_box(&self) -> Option<&ExprBox> {
+        match self {
+            Expr::Box(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Break`.
+    pub fn is_break(&self) -> bool {
+        match self {
+            Expr::Break(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Break`, or `None` if this is a different variant.
+    pub fn as_break(&self) -> Option<&ExprBreak> {
+        match self {
+            Expr::Break(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Call`.
+    pub fn is_call(&self) -> bool {
+        match self {
+            Expr::Call(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Call`, or `None` if this is a different variant.
+    pub fn as_call(&self) -> Option<&ExprCall> {
+        match self {
+            Expr::Call(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Cast`.
+    pub fn is_cast(&self) -> bool {
+        match self {
+            Expr::Cast(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Cast`, or `None` if this is a different variant.
+    pub fn as_cast(&self) -> Option<&ExprCast> {
+        match self {
+            Expr::Cast(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Closure`.
+    pub fn is_closure(&self) -> bool {
+        match self {
+            Expr::Closure(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Closure`, or `None` if this is a different variant.
+    pub fn as_closure(&self) -> Option<&ExprClosure> {
+        match self {
+            Expr::Closure(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Continue`.
+    pub fn is_continue(&self) -> bool {
+        match self {
+            Expr::Continue(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Continue`, or `None` if this is a different variant.
+    pub fn as_continue(&self) -> Option<&ExprContinue> {
+        match self {
+            Expr::Continue(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Field`.
+    pub fn is_field(&self) -> bool {
+        match self {
+            Expr::Field(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Field`, or `None` if this is a different variant.
+    pub fn as_field(&self) -> Option<&ExprField> {
+        match self {
+            Expr::Field(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::ForLoop`.
+    pub fn is_for_loop(&self) -> bool {
+        match self {
+            Expr::ForLoop(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::ForLoop`, or `None` if this is a different variant.
+    pub fn as_for_loop(&self) -> Option<&ExprForLoop> {
+        match self {
+            Expr::ForLoop(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Group`.
+    pub fn is_group(&self) -> bool {
+        match self {
+            Expr::Group(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Group`, or `None` if this is a different variant.
+    pub fn as_group(&self) -> Option<&ExprGroup> {
+        match self {
+            Expr::Group(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::If`.
+    pub fn is_if(&self) -> bool {
+        match self {
+            Expr::If(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::If`, or `None` if this is a different variant.
+    pub fn as_if(&self) -> Option<&ExprIf> {
+        match self {
+            Expr::If(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Index`.
+    pub fn is_index(&self) -> bool {
+        match self {
+            Expr::Index(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Index`, or `None` if this is a different variant.
+    pub fn as_index(&self) -> Option<&ExprIndex> {
+        match self {
+            Expr::Index(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Let`.
+    pub fn is_let(&self) -> bool {
+        match self {
+            Expr::Let(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Let`, or `None` if this is a different variant.
+    pub fn as_let(&self) -> Option<&ExprLet> {
+        match self {
+            Expr::Let(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Lit`.
+    pub fn is_lit(&self) -> bool {
+        match self {
+            Expr::Lit(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Lit`, or `None` if this is a different variant.
+    pub fn as_lit(&self) -> Option<&ExprLit> {
+        match self {
+            Expr::Lit(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Loop`.
+    pub fn is_loop(&self) -> bool {
+        match self {
+            Expr::Loop(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Loop`, or `None` if this is a different variant.
+    pub fn as_loop(&self) -> Option<&ExprLoop> {
+        match self {
+            Expr::Loop(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            Expr::Macro(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&ExprMacro> {
+        match self {
+            Expr::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Match`.
+    pub fn is_match(&self) -> bool {
+        match self {
+            Expr::Match(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Match`, or `None` if this is a different variant.
+    pub fn as_match(&self) -> Option<&ExprMatch> {
+        match self {
+            Expr::Match(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::MethodCall`.
+    pub fn is_method_call(&self) -> bool {
+        match self {
+            Expr::MethodCall(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::MethodCall`, or `None` if this is a different variant.
+    pub fn as_method_call(&self) -> Option<&ExprMethodCall> {
+        match self {
+            Expr::MethodCall(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Paren`.
+    pub fn is_paren(&self) -> bool {
+        match self {
+            Expr::Paren(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Paren`, or `None` if this is a different variant.
+    pub fn as_paren(&self) -> Option<&ExprParen> {
+        match self {
+            Expr::Paren(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Path`.
+    pub fn is_path(&self) -> bool {
+        match self {
+            Expr::Path(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Path`, or `None` if this is a different variant.
+    pub fn as_path(&self) -> Option<&ExprPath> {
+        match self {
+            Expr::Path(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Range`.
+    pub fn is_range(&self) -> bool {
+        match self {
+            Expr::Range(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Range`, or `None` if this is a different variant.
+    pub fn as_range(&self) -> Option<&ExprRange> {
+        match self {
+            Expr::Range(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Reference`.
+    pub fn is_reference(&self) -> bool {
+        match self {
+            Expr::Reference(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Reference`, or `None` if this is a different variant.
+    pub fn as_reference(&self) -> Option<&ExprReference> {
+        match self {
+            Expr::Reference(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Repeat`.
+    pub fn is_repeat(&self) -> bool {
+        match self {
+            Expr::Repeat(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Repeat`, or `None` if this is a different variant.
+    pub fn as_repeat(&self) -> Option<&ExprRepeat> {
+        match self {
+            Expr::Repeat(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Return`.
+    pub fn is_return(&self) -> bool {
+        match self {
+            Expr::Return(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Return`, or `None` if this is a different variant.
+    pub fn as_return(&self) -> Option<&ExprReturn> {
+        match self {
+            Expr::Return(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Struct`.
+    pub fn is_struct(&self) -> bool {
+        match self {
+            Expr::Struct(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Struct`, or `None` if this is a different variant.
+    pub fn as_struct(&self) -> Option<&ExprStruct> {
+        match self {
+            Expr::Struct(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Try`.
+    pub fn is_try(&self) -> bool {
+        match self {
+            Expr::Try(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Try`, or `None` if this is a different variant.
+    pub fn as_try(&self) -> Option<&ExprTry> {
+        match self {
+            Expr::Try(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::TryBlock`.
+    pub fn is_try_block(&self) -> bool {
+        match self {
+            Expr::TryBlock(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::TryBlock`, or `None` if this is a different variant.
+    pub fn as_try_block(&self) -> Option<&ExprTryBlock> {
+        match self {
+            Expr::TryBlock(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Tuple`.
+    pub fn is_tuple(&self) -> bool {
+        match self {
+            Expr::Tuple(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Tuple`, or `None` if this is a different variant.
+    pub fn as_tuple(&self) -> Option<&ExprTuple> {
+        match self {
+            Expr::Tuple(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            Expr::Type(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&ExprType> {
+        match self {
+            Expr::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Unary`.
+    pub fn is_unary(&self) -> bool {
+        match self {
+            Expr::Unary(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Unary`, or `None` if this is a different variant.
+    pub fn as_unary(&self) -> Option<&ExprUnary> {
+        match self {
+            Expr::Unary(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Unsafe`.
+    pub fn is_unsafe(&self) -> bool {
+        match self {
+            Expr::Unsafe(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Unsafe`, or `None` if this is a different variant.
+    pub fn as_unsafe(&self) -> Option<&ExprUnsafe> {
+        match self {
+            Expr::Unsafe(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Expr::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            Expr::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Expr::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            Expr::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::While`.
+    pub fn is_while(&self) -> bool {
+        match self {
+            Expr::While(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::While`, or `None` if this is a different variant.
+    pub fn as_while(&self) -> Option<&ExprWhile> {
+        match self {
+            Expr::While(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns whether this is `Expr::Yield`.
+    pub fn is_yield(&self) -> bool {
+        match self {
+            Expr::Yield(..) => true,
+            _ => false,
+        }
+    }
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    ///Returns the field of `Expr::Yield`, or `None` if this is a different variant.
+    pub fn as_yield(&self) -> Option<&ExprYield> {
+        match self {
+            Expr::Yield(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ExprArray {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `elems` field.
+    pub fn elems(&self) -> &punctuated::Punctuated<Expr, token::Comma> {
+        &self.elems
+    }
+    ///Returns a mutable reference to the `elems` field.
+    pub fn elems_mut(&mut self) -> &mut punctuated::Punctuated<Expr, token::Comma> {
+        &mut self.elems
+    }
+    ///Replaces the `elems` field.
+    pub fn set_elems(&mut self, value: punctuated::Punctuated<Expr, token::Comma>) {
+        self.elems = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprAssign {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `left` field.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+    ///Returns a mutable reference to the `left` field.
+    pub fn left_mut(&mut self) -> &mut Expr {
+        &mut self.left
+    }
+    ///Replaces the `left` field.
+    pub fn set_left(&mut self, value: Expr) {
+        *self.left = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `right` field.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
+    ///Returns a mutable reference to the `right` field.
+    pub fn right_mut(&mut self) -> &mut Expr {
+        &mut self.right
+    }
+    ///Replaces the `right` field.
+    pub fn set_right(&mut self, value: Expr) {
+        *self.right = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprAssignOp {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `left` field.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+    ///Returns a mutable reference to the `left` field.
+    pub fn left_mut(&mut self) -> &mut Expr {
+        &mut self.left
+    }
+    ///Replaces the `left` field.
+    pub fn set_left(&mut self, value: Expr) {
+        *self.left = value;
+    }
+    ///Returns the `op` field.
+    pub fn op(&self) -> &BinOp {
+        &self.op
+    }
+    ///Returns a mutable reference to the `op` field.
+    pub fn op_mut(&mut self) -> &mut BinOp {
+        &mut self.op
+    }
+    ///Replaces the `op` field.
+    pub fn set_op(&mut self, value: BinOp) {
+        self.op = value;
+    }
+    ///Returns the `right` field.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
+    ///Returns a mutable reference to the `right` field.
+    pub fn right_mut(&mut self) -> &mut Expr {
+        &mut self.right
+    }
+    ///Replaces the `right` field.
+    pub fn set_right(&mut self, value: Expr) {
+        *self.right = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprAsync {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `async_token` field.
+    pub fn async_token(&self) -> &token::Async {
+        &self.async_token
+    }
+    ///Returns a mutable reference to the `async_token` field.
+    pub fn async_token_mut(&mut self) -> &mut token::Async {
+        &mut self.async_token
+    }
+    ///Replaces the `async_token` field.
+    pub fn set_async_token(&mut self, value: token::Async) {
+        self.async_token = value;
+    }
+    ///Returns the `capture` field.
+    pub fn capture(&self) -> &Option<token::Move> {
+        &self.capture
+    }
+    ///Returns a mutable reference to the `capture` field.
+    pub fn capture_mut(&mut self) -> &mut Option<token::Move> {
+        &mut self.capture
+    }
+    ///Replaces the `capture` field.
+    pub fn set_capture(&mut self, value: Option<token::Move>) {
+        self.capture = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprAwait {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `base` field.
+    pub fn base(&self) -> &Expr {
+        &self.base
+    }
+    ///Returns a mutable reference to the `base` field.
+    pub fn base_mut(&mut self) -> &mut Expr {
+        &mut self.base
+    }
+    ///Replaces the `base` field.
+    pub fn set_base(&mut self, value: Expr) {
+        *self.base = value;
+    }
+    ///Returns the `dot_token` field.
+    pub fn dot_token(&self) -> &token::Dot {
+        &self.dot_token
+    }
+    ///Returns a mutable reference to the `dot_token` field.
+    pub fn dot_token_mut(&mut self) -> &mut token::Dot {
+        &mut self.dot_token
+    }
+    ///Replaces the `dot_token` field.
+    pub fn set_dot_token(&mut self, value: token::Dot) {
+        self.dot_token = value;
+    }
+    ///Returns the `await_token` field.
+    pub fn await_token(&self) -> &token::Await {
+        &self.await_token
+    }
+    ///Returns a mutable reference to the `await_token` field.
+    pub fn await_token_mut(&mut self) -> &mut token::Await {
+        &mut self.await_token
+    }
+    ///Replaces the `await_token` field.
+    pub fn set_await_token(&mut self, value: token::Await) {
+        self.await_token = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprBinary {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `left` field.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+    ///Returns a mutable reference to the `left` field.
+    pub fn left_mut(&mut self) -> &mut Expr {
+        &mut self.left
+    }
+    ///Replaces the `left` field.
+    pub fn set_left(&mut self, value: Expr) {
+        *self.left = value;
+    }
+    ///Returns the `op` field.
+    pub fn op(&self) -> &BinOp {
+        &self.op
+    }
+    ///Returns a mutable reference to the `op` field.
+    pub fn op_mut(&mut self) -> &mut BinOp {
+        &mut self.op
+    }
+    ///Replaces the `op` field.
+    pub fn set_op(&mut self, value: BinOp) {
+        self.op = value;
+    }
+    ///Returns the `right` field.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
+    ///Returns a mutable reference to the `right` field.
+    pub fn right_mut(&mut self) -> &mut Expr {
+        &mut self.right
+    }
+    ///Replaces the `right` field.
+    pub fn set_right(&mut self, value: Expr) {
+        *self.right = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprBlock {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Label> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Label> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Label>) {
+        self.label = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprBox {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `box_token` field.
+    pub fn box_token(&self) -> &token::Box {
+        &self.box_token
+    }
+    ///Returns a mutable reference to the `box_token` field.
+    pub fn box_token_mut(&mut self) -> &mut token::Box {
+        &mut self.box_token
+    }
+    ///Replaces the `box_token` field.
+    pub fn set_box_token(&mut self, value: token::Box) {
+        self.box_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprBreak {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `break_token` field.
+    pub fn break_token(&self) -> &token::Break {
+        &self.break_token
+    }
+    ///Returns a mutable reference to the `break_token` field.
+    pub fn break_token_mut(&mut self) -> &mut token::Break {
+        &mut self.break_token
+    }
+    ///Replaces the `break_token` field.
+    pub fn set_break_token(&mut self, value: token::Break) {
+        self.break_token = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Lifetime> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Lifetime> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Lifetime>) {
+        self.label = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Option<Box<Expr>> {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Option<Box<Expr>>) {
+        self.expr = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprCall {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `func` field.
+    pub fn func(&self) -> &Expr {
+        &self.func
+    }
+    ///Returns a mutable reference to the `func` field.
+    pub fn func_mut(&mut self) -> &mut Expr {
+        &mut self.func
+    }
+    ///Replaces the `func` field.
+    pub fn set_func(&mut self, value: Expr) {
+        *self.func = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `args` field.
+    pub fn args(&self) -> &punctuated::Punctuated<Expr, token::Comma> {
+        &self.args
+    }
+    ///Returns a mutable reference to the `args` field.
+    pub fn args_mut(&mut self) -> &mut punctuated::Punctuated<Expr, token::Comma> {
+        &mut self.args
+    }
+    ///Replaces the `args` field.
+    pub fn set_args(&mut self, value: punctuated::Punctuated<Expr, token::Comma>) {
+        self.args = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprCast {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `as_token` field.
+    pub fn as_token(&self) -> &token::As {
+        &self.as_token
+    }
+    ///Returns a mutable reference to the `as_token` field.
+    pub fn as_token_mut(&mut self) -> &mut token::As {
+        &mut self.as_token
+    }
+    ///Replaces the `as_token` field.
+    pub fn set_as_token(&mut self, value: token::As) {
+        self.as_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprClosure {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `movability` field.
+    pub fn movability(&self) -> &Option<token::Static> {
+        &self.movability
+    }
+    ///Returns a mutable reference to the `movability` field.
+    pub fn movability_mut(&mut self) -> &mut Option<token::Static> {
+        &mut self.movability
+    }
+    ///Replaces the `movability` field.
+    pub fn set_movability(&mut self, value: Option<token::Static>) {
+        self.movability = value;
+    }
+    ///Returns the `asyncness` field.
+    pub fn asyncness(&self) -> &Option<token::Async> {
+        &self.asyncness
+    }
+    ///Returns a mutable reference to the `asyncness` field.
+    pub fn asyncness_mut(&mut self) -> &mut Option<token::Async> {
+        &mut self.asyncness
+    }
+    ///Replaces the `asyncness` field.
+    pub fn set_asyncness(&mut self, value: Option<token::Async>) {
+        self.asyncness = value;
+    }
+    ///Returns the `capture` field.
+    pub fn capture(&self) -> &Option<token::Move> {
+        &self.capture
+    }
+    ///Returns a mutable reference to the `capture` field.
+    pub fn capture_mut(&mut self) -> &mut Option<token::Move> {
+        &mut self.capture
+    }
+    ///Replaces the `capture` field.
+    pub fn set_capture(&mut self, value: Option<token::Move>) {
+        self.capture = value;
+    }
+    ///Returns the `or1_token` field.
+    pub fn or1_token(&self) -> &token::Or {
+        &self.or1_token
+    }
+    ///Returns a mutable reference to the `or1_token` field.
+    pub fn or1_token_mut(&mut self) -> &mut token::Or {
+        &mut self.or1_token
+    }
+    ///Replaces the `or1_token` field.
+    pub fn set_or1_token(&mut self, value: token::Or) {
+        self.or1_token = value;
+    }
+    ///Returns the `inputs` field.
+    pub fn inputs(&self) -> &punctuated::Punctuated<Pat, token::Comma> {
+        &self.inputs
+    }
+    ///Returns a mutable reference to the `inputs` field.
+    pub fn inputs_mut(&mut self) -> &mut punctuated::Punctuated<Pat, token::Comma> {
+        &mut self.inputs
+    }
+    ///Replaces the `inputs` field.
+    pub fn set_inputs(&mut self, value: punctuated::Punctuated<Pat, token::Comma>) {
+        self.inputs = value;
+    }
+    ///Returns the `or2_token` field.
+    pub fn or2_token(&self) -> &token::Or {
+        &self.or2_token
+    }
+    ///Returns a mutable reference to the `or2_token` field.
+    pub fn or2_token_mut(&mut self) -> &mut token::Or {
+        &mut self.or2_token
+    }
+    ///Replaces the `or2_token` field.
+    pub fn set_or2_token(&mut self, value: token::Or) {
+        self.or2_token = value;
+    }
+    ///Returns the `output` field.
+    pub fn output(&self) -> &ReturnType {
+        &self.output
+    }
+    ///Returns a mutable reference to the `output` field.
+    pub fn output_mut(&mut self) -> &mut ReturnType {
+        &mut self.output
+    }
+    ///Replaces the `output` field.
+    pub fn set_output(&mut self, value: ReturnType) {
+        self.output = value;
+    }
+    ///Returns the `body` field.
+    pub fn body(&self) -> &Expr {
+        &self.body
+    }
+    ///Returns a mutable reference to the `body` field.
+    pub fn body_mut(&mut self) -> &mut Expr {
+        &mut self.body
+    }
+    ///Replaces the `body` field.
+    pub fn set_body(&mut self, value: Expr) {
+        *self.body = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprContinue {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `continue_token` field.
+    pub fn continue_token(&self) -> &token::Continue {
+        &self.continue_token
+    }
+    ///Returns a mutable reference to the `continue_token` field.
+    pub fn continue_token_mut(&mut self) -> &mut token::Continue {
+        &mut self.continue_token
+    }
+    ///Replaces the `continue_token` field.
+    pub fn set_continue_token(&mut self, value: token::Continue) {
+        self.continue_token = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Lifetime> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Lifetime> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Lifetime>) {
+        self.label = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprField {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `base` field.
+    pub fn base(&self) -> &Expr {
+        &self.base
+    }
+    ///Returns a mutable reference to the `base` field.
+    pub fn base_mut(&mut self) -> &mut Expr {
+        &mut self.base
+    }
+    ///Replaces the `base` field.
+    pub fn set_base(&mut self, value: Expr) {
+        *self.base = value;
+    }
+    ///Returns the `dot_token` field.
+    pub fn dot_token(&self) -> &token::Dot {
+        &self.dot_token
+    }
+    ///Returns a mutable reference to the `dot_token` field.
+    pub fn dot_token_mut(&mut self) -> &mut token::Dot {
+        &mut self.dot_token
+    }
+    ///Replaces the `dot_token` field.
+    pub fn set_dot_token(&mut self, value: token::Dot) {
+        self.dot_token = value;
+    }
+    ///Returns the `member` field.
+    pub fn member(&self) -> &Member {
+        &self.member
+    }
+    ///Returns a mutable reference to the `member` field.
+    pub fn member_mut(&mut self) -> &mut Member {
+        &mut self.member
+    }
+    ///Replaces the `member` field.
+    pub fn set_member(&mut self, value: Member) {
+        self.member = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprForLoop {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Label> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Label> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Label>) {
+        self.label = value;
+    }
+    ///Returns the `for_token` field.
+    pub fn for_token(&self) -> &token::For {
+        &self.for_token
+    }
+    ///Returns a mutable reference to the `for_token` field.
+    pub fn for_token_mut(&mut self) -> &mut token::For {
+        &mut self.for_token
+    }
+    ///Replaces the `for_token` field.
+    pub fn set_for_token(&mut self, value: token::For) {
+        self.for_token = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &Pat {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut Pat {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: Pat) {
+        self.pat = value;
+    }
+    ///Returns the `in_token` field.
+    pub fn in_token(&self) -> &token::In {
+        &self.in_token
+    }
+    ///Returns a mutable reference to the `in_token` field.
+    pub fn in_token_mut(&mut self) -> &mut token::In {
+        &mut self.in_token
+    }
+    ///Replaces the `in_token` field.
+    pub fn set_in_token(&mut self, value: token::In) {
+        self.in_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `spec` field.
+    pub fn spec(&self) -> &Option<Box<SpecClauses>> {
+        &self.spec
+    }
+    ///Returns a mutable reference to the `spec` field.
+    pub fn spec_mut(&mut self) -> &mut Option<Box<SpecClauses>> {
+        &mut self.spec
+    }
+    ///Replaces the `spec` field.
+    pub fn set_spec(&mut self, value: Option<Box<SpecClauses>>) {
+        self.spec = value;
+    }
+    ///Returns the `body` field.
+    pub fn body(&self) -> &Block {
+        &self.body
+    }
+    ///Returns a mutable reference to the `body` field.
+    pub fn body_mut(&mut self) -> &mut Block {
+        &mut self.body
+    }
+    ///Replaces the `body` field.
+    pub fn set_body(&mut self, value: Block) {
+        self.body = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprGroup {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `group_token` field.
+    pub fn group_token(&self) -> &token::Group {
+        &self.group_token
+    }
+    ///Returns a mutable reference to the `group_token` field.
+    pub fn group_token_mut(&mut self) -> &mut token::Group {
+        &mut self.group_token
+    }
+    ///Replaces the `group_token` field.
+    pub fn set_group_token(&mut self, value: token::Group) {
+        self.group_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprIf {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `if_token` field.
+    pub fn if_token(&self) -> &token::If {
+        &self.if_token
+    }
+    ///Returns a mutable reference to the `if_token` field.
+    pub fn if_token_mut(&mut self) -> &mut token::If {
+        &mut self.if_token
+    }
+    ///Replaces the `if_token` field.
+    pub fn set_if_token(&mut self, value: token::If) {
+        self.if_token = value;
+    }
+    ///Returns the `cond` field.
+    pub fn cond(&self) -> &Expr {
+        &self.cond
+    }
+    ///Returns a mutable reference to the `cond` field.
+    pub fn cond_mut(&mut self) -> &mut Expr {
+        &mut self.cond
+    }
+    ///Replaces the `cond` field.
+    pub fn set_cond(&mut self, value: Expr) {
+        *self.cond = value;
+    }
+    ///Returns the `then_branch` field.
+    pub fn then_branch(&self) -> &Block {
+        &self.then_branch
+    }
+    ///Returns a mutable reference to the `then_branch` field.
+    pub fn then_branch_mut(&mut self) -> &mut Block {
+        &mut self.then_branch
+    }
+    ///Replaces the `then_branch` field.
+    pub fn set_then_branch(&mut self, value: Block) {
+        self.then_branch = value;
+    }
+    ///Returns the `else_branch` field.
+    pub fn else_branch(&self) -> &Option<(token::Else, Box<Expr>)> {
+        &self.else_branch
+    }
+    ///Returns a mutable reference to the `else_branch` field.
+    pub fn else_branch_mut(&mut self) -> &mut Option<(token::Else, Box<Expr>)> {
+        &mut self.else_branch
+    }
+    ///Replaces the `else_branch` field.
+    pub fn set_else_branch(&mut self, value: Option<(token::Else, Box<Expr>)>) {
+        self.else_branch = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprIndex {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `index` field.
+    pub fn index(&self) -> &Expr {
+        &self.index
+    }
+    ///Returns a mutable reference to the `index` field.
+    pub fn index_mut(&mut self) -> &mut Expr {
+        &mut self.index
+    }
+    ///Replaces the `index` field.
+    pub fn set_index(&mut self, value: Expr) {
+        *self.index = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprLet {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `let_token` field.
+    pub fn let_token(&self) -> &token::Let {
+        &self.let_token
+    }
+    ///Returns a mutable reference to the `let_token` field.
+    pub fn let_token_mut(&mut self) -> &mut token::Let {
+        &mut self.let_token
+    }
+    ///Replaces the `let_token` field.
+    pub fn set_let_token(&mut self, value: token::Let) {
+        self.let_token = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &Pat {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut Pat {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: Pat) {
+        self.pat = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprLit {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `lit` field.
+    pub fn lit(&self) -> &Lit {
+        &self.lit
+    }
+    ///Returns a mutable reference to the `lit` field.
+    pub fn lit_mut(&mut self) -> &mut Lit {
+        &mut self.lit
+    }
+    ///Replaces the `lit` field.
+    pub fn set_lit(&mut self, value: Lit) {
+        self.lit = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprLoop {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Label> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Label> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Label>) {
+        self.label = value;
+    }
+    ///Returns the `loop_token` field.
+    pub fn loop_token(&self) -> &token::Loop {
+        &self.loop_token
+    }
+    ///Returns a mutable reference to the `loop_token` field.
+    pub fn loop_token_mut(&mut self) -> &mut token::Loop {
+        &mut self.loop_token
+    }
+    ///Replaces the `loop_token` field.
+    pub fn set_loop_token(&mut self, value: token::Loop) {
+        self.loop_token = value;
+    }
+    ///Returns the `spec` field.
+    pub fn spec(&self) -> &Option<Box<SpecClauses>> {
+        &self.spec
+    }
+    ///Returns a mutable reference to the `spec` field.
+    pub fn spec_mut(&mut self) -> &mut Option<Box<SpecClauses>> {
+        &mut self.spec
+    }
+    ///Replaces the `spec` field.
+    pub fn set_spec(&mut self, value: Option<Box<SpecClauses>>) {
+        self.spec = value;
+    }
+    ///Returns the `body` field.
+    pub fn body(&self) -> &Block {
+        &self.body
+    }
+    ///Returns a mutable reference to the `body` field.
+    pub fn body_mut(&mut self) -> &mut Block {
+        &mut self.body
+    }
+    ///Replaces the `body` field.
+    pub fn set_body(&mut self, value: Block) {
+        self.body = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprMatch {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `match_token` field.
+    pub fn match_token(&self) -> &token::Match {
+        &self.match_token
+    }
+    ///Returns a mutable reference to the `match_token` field.
+    pub fn match_token_mut(&mut self) -> &mut token::Match {
+        &mut self.match_token
+    }
+    ///Replaces the `match_token` field.
+    pub fn set_match_token(&mut self, value: token::Match) {
+        self.match_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `arms` field.
+    pub fn arms(&self) -> &Vec<Arm> {
+        &self.arms
+    }
+    ///Returns a mutable reference to the `arms` field.
+    pub fn arms_mut(&mut self) -> &mut Vec<Arm> {
+        &mut self.arms
+    }
+    ///Replaces the `arms` field.
+    pub fn set_arms(&mut self, value: Vec<Arm>) {
+        self.arms = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprMethodCall {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `receiver` field.
+    pub fn receiver(&self) -> &Expr {
+        &self.receiver
+    }
+    ///Returns a mutable reference to the `receiver` field.
+    pub fn receiver_mut(&mut self) -> &mut Expr {
+        &mut self.receiver
+    }
+    ///Replaces the `receiver` field.
+    pub fn set_receiver(&mut self, value: Expr) {
+        *self.receiver = value;
+    }
+    ///Returns the `dot_token` field.
+    pub fn dot_token(&self) -> &token::Dot {
+        &self.dot_token
+    }
+    ///Returns a mutable reference to the `dot_token` field.
+    pub fn dot_token_mut(&mut self) -> &mut token::Dot {
+        &mut self.dot_token
+    }
+    ///Replaces the `dot_token` field.
+    pub fn set_dot_token(&mut self, value: token::Dot) {
+        self.dot_token = value;
+    }
+    ///Returns the `method` field.
+    pub fn method(&self) -> &proc_macro2::Ident {
+        &self.method
+    }
+    ///Returns a mutable reference to the `method` field.
+    pub fn method_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.method
+    }
+    ///Replaces the `method` field.
+    pub fn set_method(&mut self, value: proc_macro2::Ident) {
+        self.method = value;
+    }
+    ///Returns the `turbofish` field.
+    pub fn turbofish(&self) -> &Option<MethodTurbofish> {
+        &self.turbofish
+    }
+    ///Returns a mutable reference to the `turbofish` field.
+    pub fn turbofish_mut(&mut self) -> &mut Option<MethodTurbofish> {
+        &mut self.turbofish
+    }
+    ///Replaces the `turbofish` field.
+    pub fn set_turbofish(&mut self, value: Option<MethodTurbofish>) {
+        self.turbofish = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `args` field.
+    pub fn args(&self) -> &punctuated::Punctuated<Expr, token::Comma> {
+        &self.args
+    }
+    ///Returns a mutable reference to the `args` field.
+    pub fn args_mut(&mut self) -> &mut punctuated::Punctuated<Expr, token::Comma> {
+        &mut self.args
+    }
+    ///Replaces the `args` field.
+    pub fn set_args(&mut self, value: punctuated::Punctuated<Expr, token::Comma>) {
+        self.args = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprParen {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprPath {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `qself` field.
+    pub fn qself(&self) -> &Option<QSelf> {
+        &self.qself
+    }
+    ///Returns a mutable reference to the `qself` field.
+    pub fn qself_mut(&mut self) -> &mut Option<QSelf> {
+        &mut self.qself
+    }
+    ///Replaces the `qself` field.
+    pub fn set_qself(&mut self, value: Option<QSelf>) {
+        self.qself = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprRange {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `from` field.
+    pub fn from(&self) -> &Option<Box<Expr>> {
+        &self.from
+    }
+    ///Returns a mutable reference to the `from` field.
+    pub fn from_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.from
+    }
+    ///Replaces the `from` field.
+    pub fn set_from(&mut self, value: Option<Box<Expr>>) {
+        self.from = value;
+    }
+    ///Returns the `limits` field.
+    pub fn limits(&self) -> &RangeLimits {
+        &self.limits
+    }
+    ///Returns a mutable reference to the `limits` field.
+    pub fn limits_mut(&mut self) -> &mut RangeLimits {
+        &mut self.limits
+    }
+    ///Replaces the `limits` field.
+    pub fn set_limits(&mut self, value: RangeLimits) {
+        self.limits = value;
+    }
+    ///Returns the `to` field.
+    pub fn to(&self) -> &Option<Box<Expr>> {
+        &self.to
+    }
+    ///Returns a mutable reference to the `to` field.
+    pub fn to_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.to
+    }
+    ///Replaces the `to` field.
+    pub fn set_to(&mut self, value: Option<Box<Expr>>) {
+        self.to = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprReference {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `and_token` field.
+    pub fn and_token(&self) -> &token::And {
+        &self.and_token
+    }
+    ///Returns a mutable reference to the `and_token` field.
+    pub fn and_token_mut(&mut self) -> &mut token::And {
+        &mut self.and_token
+    }
+    ///Replaces the `and_token` field.
+    pub fn set_and_token(&mut self, value: token::And) {
+        self.and_token = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprRepeat {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+    ///Returns the `len` field.
+    pub fn len(&self) -> &Expr {
+        &self.len
+    }
+    ///Returns a mutable reference to the `len` field.
+    pub fn len_mut(&mut self) -> &mut Expr {
+        &mut self.len
+    }
+    ///Replaces the `len` field.
+    pub fn set_len(&mut self, value: Expr) {
+        *self.len = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprReturn {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `return_token` field.
+    pub fn return_token(&self) -> &token::Return {
+        &self.return_token
+    }
+    ///Returns a mutable reference to the `return_token` field.
+    pub fn return_token_mut(&mut self) -> &mut token::Return {
+        &mut self.return_token
+    }
+    ///Replaces the `return_token` field.
+    pub fn set_return_token(&mut self, value: token::Return) {
+        self.return_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Option<Box<Expr>> {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Option<Box<Expr>>) {
+        self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprStruct {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `fields` field.
+    pub fn fields(&self) -> &punctuated::Punctuated<FieldValue, token::Comma> {
+        &self.fields
+    }
+    ///Returns a mutable reference to the `fields` field.
+    pub fn fields_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<FieldValue, token::Comma> {
+        &mut self.fields
+    }
+    ///Replaces the `fields` field.
+    pub fn set_fields(
+        &mut self,
+        value: punctuated::Punctuated<FieldValue, token::Comma>,
+    ) {
+        self.fields = value;
+    }
+    ///Returns the `dot2_token` field.
+    pub fn dot2_token(&self) -> &Option<token::Dot2> {
+        &self.dot2_token
+    }
+    ///Returns a mutable reference to the `dot2_token` field.
+    pub fn dot2_token_mut(&mut self) -> &mut Option<token::Dot2> {
+        &mut self.dot2_token
+    }
+    ///Replaces the `dot2_token` field.
+    pub fn set_dot2_token(&mut self, value: Option<token::Dot2>) {
+        self.dot2_token = value;
+    }
+    ///Returns the `rest` field.
+    pub fn rest(&self) -> &Option<Box<Expr>> {
+        &self.rest
+    }
+    ///Returns a mutable reference to the `rest` field.
+    pub fn rest_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.rest
+    }
+    ///Replaces the `rest` field.
+    pub fn set_rest(&mut self, value: Option<Box<Expr>>) {
+        self.rest = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprTry {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `question_token` field.
+    pub fn question_token(&self) -> &token::Question {
+        &self.question_token
+    }
+    ///Returns a mutable reference to the `question_token` field.
+    pub fn question_token_mut(&mut self) -> &mut token::Question {
+        &mut self.question_token
+    }
+    ///Replaces the `question_token` field.
+    pub fn set_question_token(&mut self, value: token::Question) {
+        self.question_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprTryBlock {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `try_token` field.
+    pub fn try_token(&self) -> &token::Try {
+        &self.try_token
+    }
+    ///Returns a mutable reference to the `try_token` field.
+    pub fn try_token_mut(&mut self) -> &mut token::Try {
+        &mut self.try_token
+    }
+    ///Replaces the `try_token` field.
+    pub fn set_try_token(&mut self, value: token::Try) {
+        self.try_token = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprTuple {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `elems` field.
+    pub fn elems(&self) -> &punctuated::Punctuated<Expr, token::Comma> {
+        &self.elems
+    }
+    ///Returns a mutable reference to the `elems` field.
+    pub fn elems_mut(&mut self) -> &mut punctuated::Punctuated<Expr, token::Comma> {
+        &mut self.elems
+    }
+    ///Replaces the `elems` field.
+    pub fn set_elems(&mut self, value: punctuated::Punctuated<Expr, token::Comma>) {
+        self.elems = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ExprUnary {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `op` field.
+    pub fn op(&self) -> &UnOp {
+        &self.op
+    }
+    ///Returns a mutable reference to the `op` field.
+    pub fn op_mut(&mut self) -> &mut UnOp {
+        &mut self.op
+    }
+    ///Replaces the `op` field.
+    pub fn set_op(&mut self, value: UnOp) {
+        self.op = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprUnsafe {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `unsafe_token` field.
+    pub fn unsafe_token(&self) -> &token::Unsafe {
+        &self.unsafe_token
+    }
+    ///Returns a mutable reference to the `unsafe_token` field.
+    pub fn unsafe_token_mut(&mut self) -> &mut token::Unsafe {
+        &mut self.unsafe_token
+    }
+    ///Replaces the `unsafe_token` field.
+    pub fn set_unsafe_token(&mut self, value: token::Unsafe) {
+        self.unsafe_token = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprWhile {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `label` field.
+    pub fn label(&self) -> &Option<Label> {
+        &self.label
+    }
+    ///Returns a mutable reference to the `label` field.
+    pub fn label_mut(&mut self) -> &mut Option<Label> {
+        &mut self.label
+    }
+    ///Replaces the `label` field.
+    pub fn set_label(&mut self, value: Option<Label>) {
+        self.label = value;
+    }
+    ///Returns the `while_token` field.
+    pub fn while_token(&self) -> &token::While {
+        &self.while_token
+    }
+    ///Returns a mutable reference to the `while_token` field.
+    pub fn while_token_mut(&mut self) -> &mut token::While {
+        &mut self.while_token
+    }
+    ///Replaces the `while_token` field.
+    pub fn set_while_token(&mut self, value: token::While) {
+        self.while_token = value;
+    }
+    ///Returns the `cond` field.
+    pub fn cond(&self) -> &Expr {
+        &self.cond
+    }
+    ///Returns a mutable reference to the `cond` field.
+    pub fn cond_mut(&mut self) -> &mut Expr {
+        &mut self.cond
+    }
+    ///Replaces the `cond` field.
+    pub fn set_cond(&mut self, value: Expr) {
+        *self.cond = value;
+    }
+    ///Returns the `spec` field.
+    pub fn spec(&self) -> &Option<Box<SpecClauses>> {
+        &self.spec
+    }
+    ///Returns a mutable reference to the `spec` field.
+    pub fn spec_mut(&mut self) -> &mut Option<Box<SpecClauses>> {
+        &mut self.spec
+    }
+    ///Replaces the `spec` field.
+    pub fn set_spec(&mut self, value: Option<Box<SpecClauses>>) {
+        self.spec = value;
+    }
+    ///Returns the `body` field.
+    pub fn body(&self) -> &Block {
+        &self.body
+    }
+    ///Returns a mutable reference to the `body` field.
+    pub fn body_mut(&mut self) -> &mut Block {
+        &mut self.body
+    }
+    ///Replaces the `body` field.
+    pub fn set_body(&mut self, value: Block) {
+        self.body = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ExprYield {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `yield_token` field.
+    pub fn yield_token(&self) -> &token::Yield {
+        &self.yield_token
+    }
+    ///Returns a mutable reference to the `yield_token` field.
+    pub fn yield_token_mut(&mut self) -> &mut token::Yield {
+        &mut self.yield_token
+    }
+    ///Replaces the `yield_token` field.
+    pub fn set_yield_token(&mut self, value: token::Yield) {
+        self.yield_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Option<Box<Expr>> {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Option<Box<Expr>> {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Option<Box<Expr>>) {
+        self.expr = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Fields {
+    ///Returns whether this is `Fields::Named`.
+    pub fn is_named(&self) -> bool {
+        match self {
+            Fields::Named(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Fields::Named`, or `None` if this is a different variant.
+    pub fn as_named(&self) -> Option<&FieldsNamed> {
+        match self {
+            Fields::Named(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Fields::Unnamed`.
+    pub fn is_unnamed(&self) -> bool {
+        match self {
+            Fields::Unnamed(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Fields::Unnamed`, or `None` if this is a different variant.
+    pub fn as_unnamed(&self) -> Option<&FieldsUnnamed> {
+        match self {
+            Fields::Unnamed(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Fields::Unit`.
+    pub fn is_unit(&self) -> bool {
+        match self {
+            Fields::Unit => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl FnArg {
+    ///Returns whether this is `FnArg::Receiver`.
+    pub fn is_receiver(&self) -> bool {
+        match self {
+            FnArg::Receiver(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `FnArg::Receiver`, or `None` if this is a different variant.
+    pub fn as_receiver(&self) -> Option<&Receiver> {
+        match self {
+            FnArg::Receiver(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `FnArg::Typed`.
+    pub fn is_typed(&self) -> bool {
+        match self {
+            FnArg::Typed(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `FnArg::Typed`, or `None` if this is a different variant.
+    pub fn as_typed(&self) -> Option<&PatType> {
+        match self {
+            FnArg::Typed(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ForeignItem {
+    ///Returns whether this is `ForeignItem::Fn`.
+    pub fn is_fn(&self) -> bool {
+        match self {
+            ForeignItem::Fn(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ForeignItem::Fn`, or `None` if this is a different variant.
+    pub fn as_fn(&self) -> Option<&ForeignItemFn> {
+        match self {
+            ForeignItem::Fn(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ForeignItem::Static`.
+    pub fn is_static(&self) -> bool {
+        match self {
+            ForeignItem::Static(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ForeignItem::Static`, or `None` if this is a different variant.
+    pub fn as_static(&self) -> Option<&ForeignItemStatic> {
+        match self {
+            ForeignItem::Static(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ForeignItem::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            ForeignItem::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ForeignItem::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&ForeignItemType> {
+        match self {
+            ForeignItem::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ForeignItem::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            ForeignItem::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ForeignItem::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&ForeignItemMacro> {
+        match self {
+            ForeignItem::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ForeignItem::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            ForeignItem::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ForeignItem::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            ForeignItem::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ForeignItemFn {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `sig` field.
+    pub fn sig(&self) -> &Signature {
+        &self.sig
+    }
+    ///Returns a mutable reference to the `sig` field.
+    pub fn sig_mut(&mut self) -> &mut Signature {
+        &mut self.sig
+    }
+    ///Replaces the `sig` field.
+    pub fn set_sig(&mut self, value: Signature) {
+        self.sig = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ForeignItemMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ForeignItemStatic {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `static_token` field.
+    pub fn static_token(&self) -> &token::Static {
+        &self.static_token
+    }
+    ///Returns a mutable reference to the `static_token` field.
+    pub fn static_token_mut(&mut self) -> &mut token::Static {
+        &mut self.static_token
+    }
+    ///Replaces the `static_token` field.
+    pub fn set_static_token(&mut self, value: token::Static) {
+        self.static_token = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ForeignItemType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `type_token` field.
+    pub fn type_token(&self) -> &token::Type {
+        &self.type_token
+    }
+    ///Returns a mutable reference to the `type_token` field.
+    pub fn type_token_mut(&mut self) -> &mut token::Type {
+        &mut self.type_token
+    }
+    ///Replaces the `type_token` field.
+    pub fn set_type_token(&mut self, value: token::Type) {
+        self.type_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl GenericArgument {
+    ///Returns whether this is `GenericArgument::Lifetime`.
+    pub fn is_lifetime(&self) -> bool {
+        match self {
+            GenericArgument::Lifetime(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericArgument::Lifetime`, or `None` if this is a different variant.
+    pub fn as_lifetime(&self) -> Option<&Lifetime> {
+        match self {
+            GenericArgument::Lifetime(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericArgument::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            GenericArgument::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericArgument::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&Type> {
+        match self {
+            GenericArgument::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericArgument::Binding`.
+    pub fn is_binding(&self) -> bool {
+        match self {
+            GenericArgument::Binding(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericArgument::Binding`, or `None` if this is a different variant.
+    pub fn as_binding(&self) -> Option<&Binding> {
+        match self {
+            GenericArgument::Binding(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericArgument::Constraint`.
+    pub fn is_constraint(&self) -> bool {
+        match self {
+            GenericArgument::Constraint(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericArgument::Constraint`, or `None` if this is a different variant.
+    pub fn as_constraint(&self) -> Option<&Constraint> {
+        match self {
+            GenericArgument::Constraint(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericArgument::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            GenericArgument::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericArgument::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&Expr> {
+        match self {
+            GenericArgument::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl GenericMethodArgument {
+    ///Returns whether this is `GenericMethodArgument::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            GenericMethodArgument::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericMethodArgument::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&Type> {
+        match self {
+            GenericMethodArgument::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericMethodArgument::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            GenericMethodArgument::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericMethodArgument::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&Expr> {
+        match self {
+            GenericMethodArgument::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl GenericParam {
+    ///Returns whether this is `GenericParam::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            GenericParam::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericParam::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&TypeParam> {
+        match self {
+            GenericParam::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericParam::Lifetime`.
+    pub fn is_lifetime(&self) -> bool {
+        match self {
+            GenericParam::Lifetime(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericParam::Lifetime`, or `None` if this is a different variant.
+    pub fn as_lifetime(&self) -> Option<&LifetimeDef> {
+        match self {
+            GenericParam::Lifetime(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `GenericParam::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            GenericParam::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `GenericParam::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&ConstParam> {
+        match self {
+            GenericParam::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ImplItem {
+    ///Returns whether this is `ImplItem::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            ImplItem::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ImplItem::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&ImplItemConst> {
+        match self {
+            ImplItem::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ImplItem::Method`.
+    pub fn is_method(&self) -> bool {
+        match self {
+            ImplItem::Method(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ImplItem::Method`, or `None` if this is a different variant.
+    pub fn as_method(&self) -> Option<&ImplItemMethod> {
+        match self {
+            ImplItem::Method(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ImplItem::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            ImplItem::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ImplItem::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&ImplItemType> {
+        match self {
+            ImplItem::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ImplItem::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            ImplItem::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ImplItem::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&ImplItemMacro> {
+        match self {
+            ImplItem::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `ImplItem::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            ImplItem::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `ImplItem::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            ImplItem::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ImplItemConst {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `defaultness` field.
+    pub fn defaultness(&self) -> &Option<token::Default> {
+        &self.defaultness
+    }
+    ///Returns a mutable reference to the `defaultness` field.
+    pub fn defaultness_mut(&mut self) -> &mut Option<token::Default> {
+        &mut self.defaultness
+    }
+    ///Replaces the `defaultness` field.
+    pub fn set_defaultness(&mut self, value: Option<token::Default>) {
+        self.defaultness = value;
+    }
+    ///Returns the `const_token` field.
+    pub fn const_token(&self) -> &token::Const {
+        &self.const_token
+    }
+    ///Returns a mutable reference to the `const_token` field.
+    pub fn const_token_mut(&mut self) -> &mut token::Const {
+        &mut self.const_token
+    }
+    ///Replaces the `const_token` field.
+    pub fn set_const_token(&mut self, value: token::Const) {
+        self.const_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        self.ty = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        self.expr = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ImplItemMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ImplItemMethod {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `defaultness` field.
+    pub fn defaultness(&self) -> &Option<token::Default> {
+        &self.defaultness
+    }
+    ///Returns a mutable reference to the `defaultness` field.
+    pub fn defaultness_mut(&mut self) -> &mut Option<token::Default> {
+        &mut self.defaultness
+    }
+    ///Replaces the `defaultness` field.
+    pub fn set_defaultness(&mut self, value: Option<token::Default>) {
+        self.defaultness = value;
+    }
+    ///Returns the `sig` field.
+    pub fn sig(&self) -> &Signature {
+        &self.sig
+    }
+    ///Returns a mutable reference to the `sig` field.
+    pub fn sig_mut(&mut self) -> &mut Signature {
+        &mut self.sig
+    }
+    ///Replaces the `sig` field.
+    pub fn set_sig(&mut self, value: Signature) {
+        self.sig = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ImplItemType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `defaultness` field.
+    pub fn defaultness(&self) -> &Option<token::Default> {
+        &self.defaultness
+    }
+    ///Returns a mutable reference to the `defaultness` field.
+    pub fn defaultness_mut(&mut self) -> &mut Option<token::Default> {
+        &mut self.defaultness
+    }
+    ///Replaces the `defaultness` field.
+    pub fn set_defaultness(&mut self, value: Option<token::Default>) {
+        self.defaultness = value;
+    }
+    ///Returns the `type_token` field.
+    pub fn type_token(&self) -> &token::Type {
+        &self.type_token
+    }
+    ///Returns a mutable reference to the `type_token` field.
+    pub fn type_token_mut(&mut self) -> &mut token::Type {
+        &mut self.type_token
+    }
+    ///Replaces the `type_token` field.
+    pub fn set_type_token(&mut self, value: token::Type) {
+        self.type_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        self.ty = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl Item {
+    ///Returns whether this is `Item::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            Item::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&ItemConst> {
+        match self {
+            Item::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Enum`.
+    pub fn is_enum(&self) -> bool {
+        match self {
+            Item::Enum(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Enum`, or `None` if this is a different variant.
+    pub fn as_enum(&self) -> Option<&ItemEnum> {
+        match self {
+            Item::Enum(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::ExternCrate`.
+    pub fn is_extern_crate(&self) -> bool {
+        match self {
+            Item::ExternCrate(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::ExternCrate`, or `None` if this is a different variant.
+    pub fn as_extern_crate(&self) -> Option<&ItemExternCrate> {
+        match self {
+            Item::ExternCrate(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Fn`.
+    pub fn is_fn(&self) -> bool {
+        match self {
+            Item::Fn(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Fn`, or `None` if this is a different variant.
+    pub fn as_fn(&self) -> Option<&ItemFn> {
+        match self {
+            Item::Fn(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::ForeignMod`.
+    pub fn is_foreign_mod(&self) -> bool {
+        match self {
+            Item::ForeignMod(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::ForeignMod`, or `None` if this is a different variant.
+    pub fn as_foreign_mod(&self) -> Option<&ItemForeignMod> {
+        match self {
+            Item::ForeignMod(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Impl`.
+    pub fn is_impl(&self) -> bool {
+        match self {
+            Item::Impl(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Impl`, or `None` if this is a different variant.
+    pub fn as_impl(&self) -> Option<&ItemImpl> {
+        match self {
+            Item::Impl(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            Item::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&ItemMacro> {
+        match self {
+            Item::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Macro2`.
+    pub fn is_macro2(&self) -> bool {
+        match self {
+            Item::Macro2(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Macro2`, or `None` if this is a different variant.
+    pub fn as_macro2(&self) -> Option<&ItemMacro2> {
+        match self {
+            Item::Macro2(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Mod`.
+    pub fn is_mod(&self) -> bool {
+        match self {
+            Item::Mod(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Mod`, or `None` if this is a different variant.
+    pub fn as_mod(&self) -> Option<&ItemMod> {
+        match self {
+            Item::Mod(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Static`.
+    pub fn is_static(&self) -> bool {
+        match self {
+            Item::Static(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Static`, or `None` if this is a different variant.
+    pub fn as_static(&self) -> Option<&ItemStatic> {
+        match self {
+            Item::Static(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Struct`.
+    pub fn is_struct(&self) -> bool {
+        match self {
+            Item::Struct(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Struct`, or `None` if this is a different variant.
+    pub fn as_struct(&self) -> Option<&ItemStruct> {
+        match self {
+            Item::Struct(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Trait`.
+    pub fn is_trait(&self) -> bool {
+        match self {
+            Item::Trait(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Trait`, or `None` if this is a different variant.
+    pub fn as_trait(&self) -> Option<&ItemTrait> {
+        match self {
+            Item::Trait(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::TraitAlias`.
+    pub fn is_trait_alias(&self) -> bool {
+        match self {
+            Item::TraitAlias(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::TraitAlias`, or `None` if this is a different variant.
+    pub fn as_trait_alias(&self) -> Option<&ItemTraitAlias> {
+        match self {
+            Item::TraitAlias(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            Item::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&ItemType> {
+        match self {
+            Item::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Union`.
+    pub fn is_union(&self) -> bool {
+        match self {
+            Item::Union(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Union`, or `None` if this is a different variant.
+    pub fn as_union(&self) -> Option<&ItemUnion> {
+        match self {
+            Item::Union(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Use`.
+    pub fn is_use(&self) -> bool {
+        match self {
+            Item::Use(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Use`, or `None` if this is a different variant.
+    pub fn as_use(&self) -> Option<&ItemUse> {
+        match self {
+            Item::Use(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Item::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            Item::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Item::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            Item::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl ItemConst {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `const_token` field.
+    pub fn const_token(&self) -> &token::Const {
+        &self.const_token
+    }
+    ///Returns a mutable reference to the `const_token` field.
+    pub fn const_token_mut(&mut self) -> &mut token::Const {
+        &mut self.const_token
+    }
+    ///Replaces the `const_token` field.
+    pub fn set_const_token(&mut self, value: token::Const) {
+        self.const_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemEnum {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `enum_token` field.
+    pub fn enum_token(&self) -> &token::Enum {
+        &self.enum_token
+    }
+    ///Returns a mutable reference to the `enum_token` field.
+    pub fn enum_token_mut(&mut self) -> &mut token::Enum {
+        &mut self.enum_token
+    }
+    ///Replaces the `enum_token` field.
+    pub fn set_enum_token(&mut self, value: token::Enum) {
+        self.enum_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `variants` field.
+    pub fn variants(&self) -> &punctuated::Punctuated<Variant, token::Comma> {
+        &self.variants
+    }
+    ///Returns a mutable reference to the `variants` field.
+    pub fn variants_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<Variant, token::Comma> {
+        &mut self.variants
+    }
+    ///Replaces the `variants` field.
+    pub fn set_variants(
+        &mut self,
+        value: punctuated::Punctuated<Variant, token::Comma>,
+    ) {
+        self.variants = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemExternCrate {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `extern_token` field.
+    pub fn extern_token(&self) -> &token::Extern {
+        &self.extern_token
+    }
+    ///Returns a mutable reference to the `extern_token` field.
+    pub fn extern_token_mut(&mut self) -> &mut token::Extern {
+        &mut self.extern_token
+    }
+    ///Replaces the `extern_token` field.
+    pub fn set_extern_token(&mut self, value: token::Extern) {
+        self.extern_token = value;
+    }
+    ///Returns the `crate_token` field.
+    pub fn crate_token(&self) -> &token::Crate {
+        &self.crate_token
+    }
+    ///Returns a mutable reference to the `crate_token` field.
+    pub fn crate_token_mut(&mut self) -> &mut token::Crate {
+        &mut self.crate_token
+    }
+    ///Replaces the `crate_token` field.
+    pub fn set_crate_token(&mut self, value: token::Crate) {
+        self.crate_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `rename` field.
+    pub fn rename(&self) -> &Option<(token::As, proc_macro2::Ident)> {
+        &self.rename
+    }
+    ///Returns a mutable reference to the `rename` field.
+    pub fn rename_mut(&mut self) -> &mut Option<(token::As, proc_macro2::Ident)> {
+        &mut self.rename
+    }
+    ///Replaces the `rename` field.
+    pub fn set_rename(&mut self, value: Option<(token::As, proc_macro2::Ident)>) {
+        self.rename = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemFn {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `sig` field.
+    pub fn sig(&self) -> &Signature {
+        &self.sig
+    }
+    ///Returns a mutable reference to the `sig` field.
+    pub fn sig_mut(&mut self) -> &mut Signature {
+        &mut self.sig
+    }
+    ///Replaces the `sig` field.
+    pub fn set_sig(&mut self, value: Signature) {
+        self.sig = value;
+    }
+    ///Returns the `block` field.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+    ///Returns a mutable reference to the `block` field.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+    ///Replaces the `block` field.
+    pub fn set_block(&mut self, value: Block) {
+        *self.block = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemForeignMod {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `abi` field.
+    pub fn abi(&self) -> &Abi {
+        &self.abi
+    }
+    ///Returns a mutable reference to the `abi` field.
+    pub fn abi_mut(&mut self) -> &mut Abi {
+        &mut self.abi
+    }
+    ///Replaces the `abi` field.
+    pub fn set_abi(&mut self, value: Abi) {
+        self.abi = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `items` field.
+    pub fn items(&self) -> &Vec<ForeignItem> {
+        &self.items
+    }
+    ///Returns a mutable reference to the `items` field.
+    pub fn items_mut(&mut self) -> &mut Vec<ForeignItem> {
+        &mut self.items
+    }
+    ///Replaces the `items` field.
+    pub fn set_items(&mut self, value: Vec<ForeignItem>) {
+        self.items = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemImpl {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `defaultness` field.
+    pub fn defaultness(&self) -> &Option<token::Default> {
+        &self.defaultness
+    }
+    ///Returns a mutable reference to the `defaultness` field.
+    pub fn defaultness_mut(&mut self) -> &mut Option<token::Default> {
+        &mut self.defaultness
+    }
+    ///Replaces the `defaultness` field.
+    pub fn set_defaultness(&mut self, value: Option<token::Default>) {
+        self.defaultness = value;
+    }
+    ///Returns the `unsafety` field.
+    pub fn unsafety(&self) -> &Option<token::Unsafe> {
+        &self.unsafety
+    }
+    ///Returns a mutable reference to the `unsafety` field.
+    pub fn unsafety_mut(&mut self) -> &mut Option<token::Unsafe> {
+        &mut self.unsafety
+    }
+    ///Replaces the `unsafety` field.
+    pub fn set_unsafety(&mut self, value: Option<token::Unsafe>) {
+        self.unsafety = value;
+    }
+    ///Returns the `impl_token` field.
+    pub fn impl_token(&self) -> &token::Impl {
+        &self.impl_token
+    }
+    ///Returns a mutable reference to the `impl_token` field.
+    pub fn impl_token_mut(&mut self) -> &mut token::Impl {
+        &mut self.impl_token
+    }
+    ///Replaces the `impl_token` field.
+    pub fn set_impl_token(&mut self, value: token::Impl) {
+        self.impl_token = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `trait_` field.
+    pub fn trait_(&self) -> &Option<(Option<token::Bang>, Path, token::For)> {
+        &self.trait_
+    }
+    ///Returns a mutable reference to the `trait_` field.
+    pub fn trait_mut(&mut self) -> &mut Option<(Option<token::Bang>, Path, token::For)> {
+        &mut self.trait_
+    }
+    ///Replaces the `trait_` field.
+    pub fn set_trait(&mut self, value: Option<(Option<token::Bang>, Path, token::For)>) {
+        self.trait_ = value;
+    }
+    ///Returns the `self_ty` field.
+    pub fn self_ty(&self) -> &Type {
+        &self.self_ty
+    }
+    ///Returns a mutable reference to the `self_ty` field.
+    pub fn self_ty_mut(&mut self) -> &mut Type {
+        &mut self.self_ty
+    }
+    ///Replaces the `self_ty` field.
+    pub fn set_self_ty(&mut self, value: Type) {
+        *self.self_ty = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `items` field.
+    pub fn items(&self) -> &Vec<ImplItem> {
+        &self.items
+    }
+    ///Returns a mutable reference to the `items` field.
+    pub fn items_mut(&mut self) -> &mut Vec<ImplItem> {
+        &mut self.items
+    }
+    ///Replaces the `items` field.
+    pub fn set_items(&mut self, value: Vec<ImplItem>) {
+        self.items = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &Option<proc_macro2::Ident> {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut Option<proc_macro2::Ident> {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: Option<proc_macro2::Ident>) {
+        self.ident = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemMacro2 {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `macro_token` field.
+    pub fn macro_token(&self) -> &token::Macro {
+        &self.macro_token
+    }
+    ///Returns a mutable reference to the `macro_token` field.
+    pub fn macro_token_mut(&mut self) -> &mut token::Macro {
+        &mut self.macro_token
+    }
+    ///Replaces the `macro_token` field.
+    pub fn set_macro_token(&mut self, value: token::Macro) {
+        self.macro_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `rules` field.
+    pub fn rules(&self) -> &proc_macro2::TokenStream {
+        &self.rules
+    }
+    ///Returns a mutable reference to the `rules` field.
+    pub fn rules_mut(&mut self) -> &mut proc_macro2::TokenStream {
+        &mut self.rules
+    }
+    ///Replaces the `rules` field.
+    pub fn set_rules(&mut self, value: proc_macro2::TokenStream) {
+        self.rules = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemMod {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `mod_token` field.
+    pub fn mod_token(&self) -> &token::Mod {
+        &self.mod_token
+    }
+    ///Returns a mutable reference to the `mod_token` field.
+    pub fn mod_token_mut(&mut self) -> &mut token::Mod {
+        &mut self.mod_token
+    }
+    ///Replaces the `mod_token` field.
+    pub fn set_mod_token(&mut self, value: token::Mod) {
+        self.mod_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `content` field.
+    pub fn content(&self) -> &Option<(token::Brace, Vec<Item>)> {
+        &self.content
+    }
+    ///Returns a mutable reference to the `content` field.
+    pub fn content_mut(&mut self) -> &mut Option<(token::Brace, Vec<Item>)> {
+        &mut self.content
+    }
+    ///Replaces the `content` field.
+    pub fn set_content(&mut self, value: Option<(token::Brace, Vec<Item>)>) {
+        self.content = value;
+    }
+    ///Returns the `semi` field.
+    pub fn semi(&self) -> &Option<token::Semi> {
+        &self.semi
+    }
+    ///Returns a mutable reference to the `semi` field.
+    pub fn semi_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi
+    }
+    ///Replaces the `semi` field.
+    pub fn set_semi(&mut self, value: Option<token::Semi>) {
+        self.semi = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemStatic {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `static_token` field.
+    pub fn static_token(&self) -> &token::Static {
+        &self.static_token
+    }
+    ///Returns a mutable reference to the `static_token` field.
+    pub fn static_token_mut(&mut self) -> &mut token::Static {
+        &mut self.static_token
+    }
+    ///Replaces the `static_token` field.
+    pub fn set_static_token(&mut self, value: token::Static) {
+        self.static_token = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemStruct {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `struct_token` field.
+    pub fn struct_token(&self) -> &token::Struct {
+        &self.struct_token
+    }
+    ///Returns a mutable reference to the `struct_token` field.
+    pub fn struct_token_mut(&mut self) -> &mut token::Struct {
+        &mut self.struct_token
+    }
+    ///Replaces the `struct_token` field.
+    pub fn set_struct_token(&mut self, value: token::Struct) {
+        self.struct_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `fields` field.
+    pub fn fields(&self) -> &Fields {
+        &self.fields
+    }
+    ///Returns a mutable reference to the `fields` field.
+    pub fn fields_mut(&mut self) -> &mut Fields {
+        &mut self.fields
+    }
+    ///Replaces the `fields` field.
+    pub fn set_fields(&mut self, value: Fields) {
+        self.fields = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemTrait {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `unsafety` field.
+    pub fn unsafety(&self) -> &Option<token::Unsafe> {
+        &self.unsafety
+    }
+    ///Returns a mutable reference to the `unsafety` field.
+    pub fn unsafety_mut(&mut self) -> &mut Option<token::Unsafe> {
+        &mut self.unsafety
+    }
+    ///Replaces the `unsafety` field.
+    pub fn set_unsafety(&mut self, value: Option<token::Unsafe>) {
+        self.unsafety = value;
+    }
+    ///Returns the `auto_token` field.
+    pub fn auto_token(&self) -> &Option<token::Auto> {
+        &self.auto_token
+    }
+    ///Returns a mutable reference to the `auto_token` field.
+    pub fn auto_token_mut(&mut self) -> &mut Option<token::Auto> {
+        &mut self.auto_token
+    }
+    ///Replaces the `auto_token` field.
+    pub fn set_auto_token(&mut self, value: Option<token::Auto>) {
+        self.auto_token = value;
+    }
+    ///Returns the `trait_token` field.
+    pub fn trait_token(&self) -> &token::Trait {
+        &self.trait_token
+    }
+    ///Returns a mutable reference to the `trait_token` field.
+    pub fn trait_token_mut(&mut self) -> &mut token::Trait {
+        &mut self.trait_token
+    }
+    ///Replaces the `trait_token` field.
+    pub fn set_trait_token(&mut self, value: token::Trait) {
+        self.trait_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &Option<token::Colon> {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut Option<token::Colon> {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: Option<token::Colon>) {
+        self.colon_token = value;
+    }
+    ///Returns the `supertraits` field.
+    pub fn supertraits(&self) -> &punctuated::Punctuated<TypeParamBound, token::Add> {
+        &self.supertraits
+    }
+    ///Returns a mutable reference to the `supertraits` field.
+    pub fn supertraits_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<TypeParamBound, token::Add> {
+        &mut self.supertraits
+    }
+    ///Replaces the `supertraits` field.
+    pub fn set_supertraits(
+        &mut self,
+        value: punctuated::Punctuated<TypeParamBound, token::Add>,
+    ) {
+        self.supertraits = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `items` field.
+    pub fn items(&self) -> &Vec<TraitItem> {
+        &self.items
+    }
+    ///Returns a mutable reference to the `items` field.
+    pub fn items_mut(&mut self) -> &mut Vec<TraitItem> {
+        &mut self.items
+    }
+    ///Replaces the `items` field.
+    pub fn set_items(&mut self, value: Vec<TraitItem>) {
+        self.items = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemTraitAlias {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `trait_token` field.
+    pub fn trait_token(&self) -> &token::Trait {
+        &self.trait_token
+    }
+    ///Returns a mutable reference to the `trait_token` field.
+    pub fn trait_token_mut(&mut self) -> &mut token::Trait {
+        &mut self.trait_token
+    }
+    ///Replaces the `trait_token` field.
+    pub fn set_trait_token(&mut self, value: token::Trait) {
+        self.trait_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `bounds` field.
+    pub fn bounds(&self) -> &punctuated::Punctuated<TypeParamBound, token::Add> {
+        &self.bounds
+    }
+    ///Returns a mutable reference to the `bounds` field.
+    pub fn bounds_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<TypeParamBound, token::Add> {
+        &mut self.bounds
+    }
+    ///Replaces the `bounds` field.
+    pub fn set_bounds(
+        &mut self,
+        value: punctuated::Punctuated<TypeParamBound, token::Add>,
+    ) {
+        self.bounds = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `type_token` field.
+    pub fn type_token(&self) -> &token::Type {
+        &self.type_token
+    }
+    ///Returns a mutable reference to the `type_token` field.
+    pub fn type_token_mut(&mut self) -> &mut token::Type {
+        &mut self.type_token
+    }
+    ///Replaces the `type_token` field.
+    pub fn set_type_token(&mut self, value: token::Type) {
+        self.type_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `eq_token` field.
+    pub fn eq_token(&self) -> &token::Eq {
+        &self.eq_token
+    }
+    ///Returns a mutable reference to the `eq_token` field.
+    pub fn eq_token_mut(&mut self) -> &mut token::Eq {
+        &mut self.eq_token
+    }
+    ///Replaces the `eq_token` field.
+    pub fn set_eq_token(&mut self, value: token::Eq) {
+        self.eq_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemUnion {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `union_token` field.
+    pub fn union_token(&self) -> &token::Union {
+        &self.union_token
+    }
+    ///Returns a mutable reference to the `union_token` field.
+    pub fn union_token_mut(&mut self) -> &mut token::Union {
+        &mut self.union_token
+    }
+    ///Replaces the `union_token` field.
+    pub fn set_union_token(&mut self, value: token::Union) {
+        self.union_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `fields` field.
+    pub fn fields(&self) -> &FieldsNamed {
+        &self.fields
+    }
+    ///Returns a mutable reference to the `fields` field.
+    pub fn fields_mut(&mut self) -> &mut FieldsNamed {
+        &mut self.fields
+    }
+    ///Replaces the `fields` field.
+    pub fn set_fields(&mut self, value: FieldsNamed) {
+        self.fields = value;
+    }
+}
+#[cfg(feature = "full")]
+impl ItemUse {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `vis` field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+    ///Returns a mutable reference to the `vis` field.
+    pub fn vis_mut(&mut self) -> &mut Visibility {
+        &mut self.vis
+    }
+    ///Replaces the `vis` field.
+    pub fn set_vis(&mut self, value: Visibility) {
+        self.vis = value;
+    }
+    ///Returns the `use_token` field.
+    pub fn use_token(&self) -> &token::Use {
+        &self.use_token
+    }
+    ///Returns a mutable reference to the `use_token` field.
+    pub fn use_token_mut(&mut self) -> &mut token::Use {
+        &mut self.use_token
+    }
+    ///Replaces the `use_token` field.
+    pub fn set_use_token(&mut self, value: token::Use) {
+        self.use_token = value;
+    }
+    ///Returns the `leading_colon` field.
+    pub fn leading_colon(&self) -> &Option<token::Colon2> {
+        &self.leading_colon
+    }
+    ///Returns a mutable reference to the `leading_colon` field.
+    pub fn leading_colon_mut(&mut self) -> &mut Option<token::Colon2> {
+        &mut self.leading_colon
+    }
+    ///Replaces the `leading_colon` field.
+    pub fn set_leading_colon(&mut self, value: Option<token::Colon2>) {
+        self.leading_colon = value;
+    }
+    ///Returns the `tree` field.
+    pub fn tree(&self) -> &UseTree {
+        &self.tree
+    }
+    ///Returns a mutable reference to the `tree` field.
+    pub fn tree_mut(&mut self) -> &mut UseTree {
+        &mut self.tree
+    }
+    ///Replaces the `tree` field.
+    pub fn set_tree(&mut self, value: UseTree) {
+        self.tree = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+impl Lit {
+    ///Returns whether this is `Lit::Str`.
+    pub fn is_str(&self) -> bool {
+        match self {
+            Lit::Str(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Str`, or `None` if this is a different variant.
+    pub fn as_str(&self) -> Option<&LitStr> {
+        match self {
+            Lit::Str(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::ByteStr`.
+    pub fn is_byte_str(&self) -> bool {
+        match self {
+            Lit::ByteStr(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::ByteStr`, or `None` if this is a different variant.
+    pub fn as_byte_str(&self) -> Option<&LitByteStr> {
+        match self {
+            Lit::ByteStr(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Byte`.
+    pub fn is_byte(&self) -> bool {
+        match self {
+            Lit::Byte(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Byte`, or `None` if this is a different variant.
+    pub fn as_byte(&self) -> Option<&LitByte> {
+        match self {
+            Lit::Byte(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Char`.
+    pub fn is_char(&self) -> bool {
+        match self {
+            Lit::Char(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Char`, or `None` if this is a different variant.
+    pub fn as_char(&self) -> Option<&LitChar> {
+        match self {
+            Lit::Char(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Int`.
+    pub fn is_int(&self) -> bool {
+        match self {
+            Lit::Int(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Int`, or `None` if this is a different variant.
+    pub fn as_int(&self) -> Option<&LitInt> {
+        match self {
+            Lit::Int(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Float`.
+    pub fn is_float(&self) -> bool {
+        match self {
+            Lit::Float(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Float`, or `None` if this is a different variant.
+    pub fn as_float(&self) -> Option<&LitFloat> {
+        match self {
+            Lit::Float(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Bool`.
+    pub fn is_bool(&self) -> bool {
+        match self {
+            Lit::Bool(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Bool`, or `None` if this is a different variant.
+    pub fn as_bool(&self) -> Option<&LitBool> {
+        match self {
+            Lit::Bool(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Lit::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            Lit::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Lit::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::Literal> {
+        match self {
+            Lit::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Meta {
+    ///Returns whether this is `Meta::Path`.
+    pub fn is_path(&self) -> bool {
+        match self {
+            Meta::Path(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Meta::Path`, or `None` if this is a different variant.
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            Meta::Path(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Meta::List`.
+    pub fn is_list(&self) -> bool {
+        match self {
+            Meta::List(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Meta::List`, or `None` if this is a different variant.
+    pub fn as_list(&self) -> Option<&MetaList> {
+        match self {
+            Meta::List(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Meta::NameValue`.
+    pub fn is_name_value(&self) -> bool {
+        match self {
+            Meta::NameValue(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Meta::NameValue`, or `None` if this is a different variant.
+    pub fn as_name_value(&self) -> Option<&MetaNameValue> {
+        match self {
+            Meta::NameValue(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl NestedMeta {
+    ///Returns whether this is `NestedMeta::Meta`.
+    pub fn is_meta(&self) -> bool {
+        match self {
+            NestedMeta::Meta(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `NestedMeta::Meta`, or `None` if this is a different variant.
+    pub fn as_meta(&self) -> Option<&Meta> {
+        match self {
+            NestedMeta::Meta(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `NestedMeta::Lit`.
+    pub fn is_lit(&self) -> bool {
+        match self {
+            NestedMeta::Lit(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `NestedMeta::Lit`, or `None` if this is a different variant.
+    pub fn as_lit(&self) -> Option<&Lit> {
+        match self {
+            NestedMeta::Lit(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Pat {
+    ///Returns whether this is `Pat::Box`.
+    pub fn is_box(&self) -> bool {
+        match self {
+            Pat::Box(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Box`, or `None` if this is a different variant.
+    pub fn as_box(&self) -> Option<&PatBox> {
+        match self {
+            Pat::Box(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Ident`.
+    pub fn is_ident(&self) -> bool {
+        match self {
+            Pat::Ident(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Ident`, or `None` if this is a different variant.
+    pub fn as_ident(&self) -> Option<&PatIdent> {
+        match self {
+            Pat::Ident(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Lit`.
+    pub fn is_lit(&self) -> bool {
+        match self {
+            Pat::Lit(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Lit`, or `None` if this is a different variant.
+    pub fn as_lit(&self) -> Option<&PatLit> {
+        match self {
+            Pat::Lit(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            Pat::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&PatMacro> {
+        match self {
+            Pat::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Or`.
+    pub fn is_or(&self) -> bool {
+        match self {
+            Pat::Or(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Or`, or `None` if this is a different variant.
+    pub fn as_or(&self) -> Option<&PatOr> {
+        match self {
+            Pat::Or(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Path`.
+    pub fn is_path(&self) -> bool {
+        match self {
+            Pat::Path(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Path`, or `None` if this is a different variant.
+    pub fn as_path(&self) -> Option<&PatPath> {
+        match self {
+            Pat::Path(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Range`.
+    pub fn is_range(&self) -> bool {
+        match self {
+            Pat::Range(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Range`, or `None` if this is a different variant.
+    pub fn as_range(&self) -> Option<&PatRange> {
+        match self {
+            Pat::Range(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Reference`.
+    pub fn is_reference(&self) -> bool {
+        match self {
+            Pat::Reference(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Reference`, or `None` if this is a different variant.
+    pub fn as_reference(&self) -> Option<&PatReference> {
+        match self {
+            Pat::Reference(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Rest`.
+    pub fn is_rest(&self) -> bool {
+        match self {
+            Pat::Rest(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Rest`, or `None` if this is a different variant.
+    pub fn as_rest(&self) -> Option<&PatRest> {
+        match self {
+            Pat::Rest(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Slice`.
+    pub fn is_slice(&self) -> bool {
+        match self {
+            Pat::Slice(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Slice`, or `None` if this is a different variant.
+    pub fn as_slice(&self) -> Option<&PatSlice> {
+        match self {
+            Pat::Slice(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Struct`.
+    pub fn is_struct(&self) -> bool {
+        match self {
+            Pat::Struct(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Struct`, or `None` if this is a different variant.
+    pub fn as_struct(&self) -> Option<&PatStruct> {
+        match self {
+            Pat::Struct(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Tuple`.
+    pub fn is_tuple(&self) -> bool {
+        match self {
+            Pat::Tuple(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Tuple`, or `None` if this is a different variant.
+    pub fn as_tuple(&self) -> Option<&PatTuple> {
+        match self {
+            Pat::Tuple(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::TupleStruct`.
+    pub fn is_tuple_struct(&self) -> bool {
+        match self {
+            Pat::TupleStruct(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::TupleStruct`, or `None` if this is a different variant.
+    pub fn as_tuple_struct(&self) -> Option<&PatTupleStruct> {
+        match self {
+            Pat::TupleStruct(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            Pat::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&PatType> {
+        match self {
+            Pat::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            Pat::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            Pat::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Pat::Wild`.
+    pub fn is_wild(&self) -> bool {
+        match self {
+            Pat::Wild(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Pat::Wild`, or `None` if this is a different variant.
+    pub fn as_wild(&self) -> Option<&PatWild> {
+        match self {
+            Pat::Wild(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl PatBox {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `box_token` field.
+    pub fn box_token(&self) -> &token::Box {
+        &self.box_token
+    }
+    ///Returns a mutable reference to the `box_token` field.
+    pub fn box_token_mut(&mut self) -> &mut token::Box {
+        &mut self.box_token
+    }
+    ///Replaces the `box_token` field.
+    pub fn set_box_token(&mut self, value: token::Box) {
+        self.box_token = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &Pat {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut Pat {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: Pat) {
+        *self.pat = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatIdent {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `by_ref` field.
+    pub fn by_ref(&self) -> &Option<token::Ref> {
+        &self.by_ref
+    }
+    ///Returns a mutable reference to the `by_ref` field.
+    pub fn by_ref_mut(&mut self) -> &mut Option<token::Ref> {
+        &mut self.by_ref
+    }
+    ///Replaces the `by_ref` field.
+    pub fn set_by_ref(&mut self, value: Option<token::Ref>) {
+        self.by_ref = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `subpat` field.
+    pub fn subpat(&self) -> &Option<(token::At, Box<Pat>)> {
+        &self.subpat
+    }
+    ///Returns a mutable reference to the `subpat` field.
+    pub fn subpat_mut(&mut self) -> &mut Option<(token::At, Box<Pat>)> {
+        &mut self.subpat
+    }
+    ///Replaces the `subpat` field.
+    pub fn set_subpat(&mut self, value: Option<(token::At, Box<Pat>)>) {
+        self.subpat = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatLit {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `expr` field.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+    ///Returns a mutable reference to the `expr` field.
+    pub fn expr_mut(&mut self) -> &mut Expr {
+        &mut self.expr
+    }
+    ///Replaces the `expr` field.
+    pub fn set_expr(&mut self, value: Expr) {
+        *self.expr = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatOr {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `leading_vert` field.
+    pub fn leading_vert(&self) -> &Option<token::Or> {
+        &self.leading_vert
+    }
+    ///Returns a mutable reference to the `leading_vert` field.
+    pub fn leading_vert_mut(&mut self) -> &mut Option<token::Or> {
+        &mut self.leading_vert
+    }
+    ///Replaces the `leading_vert` field.
+    pub fn set_leading_vert(&mut self, value: Option<token::Or>) {
+        self.leading_vert = value;
+    }
+    ///Returns the `cases` field.
+    pub fn cases(&self) -> &punctuated::Punctuated<Pat, token::Or> {
+        &self.cases
+    }
+    ///Returns a mutable reference to the `cases` field.
+    pub fn cases_mut(&mut self) -> &mut punctuated::Punctuated<Pat, token::Or> {
+        &mut self.cases
+    }
+    ///Replaces the `cases` field.
+    pub fn set_cases(&mut self, value: punctuated::Punctuated<Pat, token::Or>) {
+        self.cases = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatPath {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `qself` field.
+    pub fn qself(&self) -> &Option<QSelf> {
+        &self.qself
+    }
+    ///Returns a mutable reference to the `qself` field.
+    pub fn qself_mut(&mut self) -> &mut Option<QSelf> {
+        &mut self.qself
+    }
+    ///Replaces the `qself` field.
+    pub fn set_qself(&mut self, value: Option<QSelf>) {
+        self.qself = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatRange {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `lo` field.
+    pub fn lo(&self) -> &Expr {
+        &self.lo
+    }
+    ///Returns a mutable reference to the `lo` field.
+    pub fn lo_mut(&mut self) -> &mut Expr {
+        &mut self.lo
+    }
+    ///Replaces the `lo` field.
+    pub fn set_lo(&mut self, value: Expr) {
+        *self.lo = value;
+    }
+    ///Returns the `limits` field.
+    pub fn limits(&self) -> &RangeLimits {
+        &self.limits
+    }
+    ///Returns a mutable reference to the `limits` field.
+    pub fn limits_mut(&mut self) -> &mut RangeLimits {
+        &mut self.limits
+    }
+    ///Replaces the `limits` field.
+    pub fn set_limits(&mut self, value: RangeLimits) {
+        self.limits = value;
+    }
+    ///Returns the `hi` field.
+    pub fn hi(&self) -> &Expr {
+        &self.hi
+    }
+    ///Returns a mutable reference to the `hi` field.
+    pub fn hi_mut(&mut self) -> &mut Expr {
+        &mut self.hi
+    }
+    ///Replaces the `hi` field.
+    pub fn set_hi(&mut self, value: Expr) {
+        *self.hi = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatReference {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `and_token` field.
+    pub fn and_token(&self) -> &token::And {
+        &self.and_token
+    }
+    ///Returns a mutable reference to the `and_token` field.
+    pub fn and_token_mut(&mut self) -> &mut token::And {
+        &mut self.and_token
+    }
+    ///Replaces the `and_token` field.
+    pub fn set_and_token(&mut self, value: token::And) {
+        self.and_token = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &Pat {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut Pat {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: Pat) {
+        *self.pat = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatRest {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `dot2_token` field.
+    pub fn dot2_token(&self) -> &token::Dot2 {
+        &self.dot2_token
+    }
+    ///Returns a mutable reference to the `dot2_token` field.
+    pub fn dot2_token_mut(&mut self) -> &mut token::Dot2 {
+        &mut self.dot2_token
+    }
+    ///Replaces the `dot2_token` field.
+    pub fn set_dot2_token(&mut self, value: token::Dot2) {
+        self.dot2_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatSlice {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `elems` field.
+    pub fn elems(&self) -> &punctuated::Punctuated<Pat, token::Comma> {
+        &self.elems
+    }
+    ///Returns a mutable reference to the `elems` field.
+    pub fn elems_mut(&mut self) -> &mut punctuated::Punctuated<Pat, token::Comma> {
+        &mut self.elems
+    }
+    ///Replaces the `elems` field.
+    pub fn set_elems(&mut self, value: punctuated::Punctuated<Pat, token::Comma>) {
+        self.elems = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatStruct {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+    ///Returns the `brace_token` field.
+    pub fn brace_token(&self) -> &token::Brace {
+        &self.brace_token
+    }
+    ///Returns a mutable reference to the `brace_token` field.
+    pub fn brace_token_mut(&mut self) -> &mut token::Brace {
+        &mut self.brace_token
+    }
+    ///Replaces the `brace_token` field.
+    pub fn set_brace_token(&mut self, value: token::Brace) {
+        self.brace_token = value;
+    }
+    ///Returns the `fields` field.
+    pub fn fields(&self) -> &punctuated::Punctuated<FieldPat, token::Comma> {
+        &self.fields
+    }
+    ///Returns a mutable reference to the `fields` field.
+    pub fn fields_mut(&mut self) -> &mut punctuated::Punctuated<FieldPat, token::Comma> {
+        &mut self.fields
+    }
+    ///Replaces the `fields` field.
+    pub fn set_fields(&mut self, value: punctuated::Punctuated<FieldPat, token::Comma>) {
+        self.fields = value;
+    }
+    ///Returns the `dot2_token` field.
+    pub fn dot2_token(&self) -> &Option<token::Dot2> {
+        &self.dot2_token
+    }
+    ///Returns a mutable reference to the `dot2_token` field.
+    pub fn dot2_token_mut(&mut self) -> &mut Option<token::Dot2> {
+        &mut self.dot2_token
+    }
+    ///Replaces the `dot2_token` field.
+    pub fn set_dot2_token(&mut self, value: Option<token::Dot2>) {
+        self.dot2_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatTuple {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `elems` field.
+    pub fn elems(&self) -> &punctuated::Punctuated<Pat, token::Comma> {
+        &self.elems
+    }
+    ///Returns a mutable reference to the `elems` field.
+    pub fn elems_mut(&mut self) -> &mut punctuated::Punctuated<Pat, token::Comma> {
+        &mut self.elems
+    }
+    ///Replaces the `elems` field.
+    pub fn set_elems(&mut self, value: punctuated::Punctuated<Pat, token::Comma>) {
+        self.elems = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatTupleStruct {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &PatTuple {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut PatTuple {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: PatTuple) {
+        self.pat = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `pat` field.
+    pub fn pat(&self) -> &Pat {
+        &self.pat
+    }
+    ///Returns a mutable reference to the `pat` field.
+    pub fn pat_mut(&mut self) -> &mut Pat {
+        &mut self.pat
+    }
+    ///Replaces the `pat` field.
+    pub fn set_pat(&mut self, value: Pat) {
+        *self.pat = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        *self.ty = value;
+    }
+}
+#[cfg(feature = "full")]
+impl PatWild {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `underscore_token` field.
+    pub fn underscore_token(&self) -> &token::Underscore {
+        &self.underscore_token
+    }
+    ///Returns a mutable reference to the `underscore_token` field.
+    pub fn underscore_token_mut(&mut self) -> &mut token::Underscore {
+        &mut self.underscore_token
+    }
+    ///Replaces the `underscore_token` field.
+    pub fn set_underscore_token(&mut self, value: token::Underscore) {
+        self.underscore_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl RangeLimits {
+    ///Returns whether this is `RangeLimits::HalfOpen`.
+    pub fn is_half_open(&self) -> bool {
+        match self {
+            RangeLimits::HalfOpen(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `RangeLimits::HalfOpen`, or `None` if this is a different variant.
+    pub fn as_half_open(&self) -> Option<&token::Dot2> {
+        match self {
+            RangeLimits::HalfOpen(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `RangeLimits::Closed`.
+    pub fn is_closed(&self) -> bool {
+        match self {
+            RangeLimits::Closed(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `RangeLimits::Closed`, or `None` if this is a different variant.
+    pub fn as_closed(&self) -> Option<&token::DotDotEq> {
+        match self {
+            RangeLimits::Closed(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ReturnType {
+    ///Returns whether this is `ReturnType::Default`.
+    pub fn is_default(&self) -> bool {
+        match self {
+            ReturnType::Default => true,
+            _ => false,
+        }
+    }
+    ///Returns whether this is `ReturnType::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            ReturnType::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the fields of `ReturnType::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<(&token::RArrow, &Type)> {
+        match self {
+            ReturnType::Type(v0, v1) => Some((v0, &**v1)),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Stmt {
+    ///Returns whether this is `Stmt::Local`.
+    pub fn is_local(&self) -> bool {
+        match self {
+            Stmt::Local(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Stmt::Local`, or `None` if this is a different variant.
+    pub fn as_local(&self) -> Option<&Local> {
+        match self {
+            Stmt::Local(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Stmt::Item`.
+    pub fn is_item(&self) -> bool {
+        match self {
+            Stmt::Item(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Stmt::Item`, or `None` if this is a different variant.
+    pub fn as_item(&self) -> Option<&Item> {
+        match self {
+            Stmt::Item(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Stmt::Expr`.
+    pub fn is_expr(&self) -> bool {
+        match self {
+            Stmt::Expr(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Stmt::Expr`, or `None` if this is a different variant.
+    pub fn as_expr(&self) -> Option<&Expr> {
+        match self {
+            Stmt::Expr(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Stmt::Semi`.
+    pub fn is_semi(&self) -> bool {
+        match self {
+            Stmt::Semi(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the fields of `Stmt::Semi`, or `None` if this is a different variant.
+    pub fn as_semi(&self) -> Option<(&Expr, &token::Semi)> {
+        match self {
+            Stmt::Semi(v0, v1) => Some((v0, v1)),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TraitBoundModifier {
+    ///Returns whether this is `TraitBoundModifier::None`.
+    pub fn is_none(&self) -> bool {
+        match self {
+            TraitBoundModifier::None => true,
+            _ => false,
+        }
+    }
+    ///Returns whether this is `TraitBoundModifier::Maybe`.
+    pub fn is_maybe(&self) -> bool {
+        match self {
+            TraitBoundModifier::Maybe(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitBoundModifier::Maybe`, or `None` if this is a different variant.
+    pub fn as_maybe(&self) -> Option<&token::Question> {
+        match self {
+            TraitBoundModifier::Maybe(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl TraitItem {
+    ///Returns whether this is `TraitItem::Const`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            TraitItem::Const(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitItem::Const`, or `None` if this is a different variant.
+    pub fn as_const(&self) -> Option<&TraitItemConst> {
+        match self {
+            TraitItem::Const(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `TraitItem::Method`.
+    pub fn is_method(&self) -> bool {
+        match self {
+            TraitItem::Method(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitItem::Method`, or `None` if this is a different variant.
+    pub fn as_method(&self) -> Option<&TraitItemMethod> {
+        match self {
+            TraitItem::Method(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `TraitItem::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            TraitItem::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitItem::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&TraitItemType> {
+        match self {
+            TraitItem::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `TraitItem::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            TraitItem::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitItem::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&TraitItemMacro> {
+        match self {
+            TraitItem::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `TraitItem::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            TraitItem::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TraitItem::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            TraitItem::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl TraitItemConst {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `const_token` field.
+    pub fn const_token(&self) -> &token::Const {
+        &self.const_token
+    }
+    ///Returns a mutable reference to the `const_token` field.
+    pub fn const_token_mut(&mut self) -> &mut token::Const {
+        &mut self.const_token
+    }
+    ///Replaces the `const_token` field.
+    pub fn set_const_token(&mut self, value: token::Const) {
+        self.const_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &token::Colon {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut token::Colon {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: token::Colon) {
+        self.colon_token = value;
+    }
+    ///Returns the `ty` field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+    ///Returns a mutable reference to the `ty` field.
+    pub fn ty_mut(&mut self) -> &mut Type {
+        &mut self.ty
+    }
+    ///Replaces the `ty` field.
+    pub fn set_ty(&mut self, value: Type) {
+        self.ty = value;
+    }
+    ///Returns the `default` field.
+    pub fn default(&self) -> &Option<(token::Eq, Expr)> {
+        &self.default
+    }
+    ///Returns a mutable reference to the `default` field.
+    pub fn default_mut(&mut self) -> &mut Option<(token::Eq, Expr)> {
+        &mut self.default
+    }
+    ///Replaces the `default` field.
+    pub fn set_default(&mut self, value: Option<(token::Eq, Expr)>) {
+        self.default = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl TraitItemMacro {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl TraitItemMethod {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `sig` field.
+    pub fn sig(&self) -> &Signature {
+        &self.sig
+    }
+    ///Returns a mutable reference to the `sig` field.
+    pub fn sig_mut(&mut self) -> &mut Signature {
+        &mut self.sig
+    }
+    ///Replaces the `sig` field.
+    pub fn set_sig(&mut self, value: Signature) {
+        self.sig = value;
+    }
+    ///Returns the `default` field.
+    pub fn default(&self) -> &Option<Block> {
+        &self.default
+    }
+    ///Returns a mutable reference to the `default` field.
+    pub fn default_mut(&mut self) -> &mut Option<Block> {
+        &mut self.default
+    }
+    ///Replaces the `default` field.
+    pub fn set_default(&mut self, value: Option<Block>) {
+        self.default = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &Option<token::Semi> {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut Option<token::Semi> {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: Option<token::Semi>) {
+        self.semi_token = value;
+    }
+}
+#[cfg(feature = "full")]
+impl TraitItemType {
+    ///Returns the `attrs` field.
+    pub fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+    ///Returns a mutable reference to the `attrs` field.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attrs
+    }
+    ///Replaces the `attrs` field.
+    pub fn set_attrs(&mut self, value: Vec<Attribute>) {
+        self.attrs = value;
+    }
+    ///Returns the `type_token` field.
+    pub fn type_token(&self) -> &token::Type {
+        &self.type_token
+    }
+    ///Returns a mutable reference to the `type_token` field.
+    pub fn type_token_mut(&mut self) -> &mut token::Type {
+        &mut self.type_token
+    }
+    ///Replaces the `type_token` field.
+    pub fn set_type_token(&mut self, value: token::Type) {
+        self.type_token = value;
+    }
+    ///Returns the `ident` field.
+    pub fn ident(&self) -> &proc_macro2::Ident {
+        &self.ident
+    }
+    ///Returns a mutable reference to the `ident` field.
+    pub fn ident_mut(&mut self) -> &mut proc_macro2::Ident {
+        &mut self.ident
+    }
+    ///Replaces the `ident` field.
+    pub fn set_ident(&mut self, value: proc_macro2::Ident) {
+        self.ident = value;
+    }
+    ///Returns the `generics` field.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+    ///Returns a mutable reference to the `generics` field.
+    pub fn generics_mut(&mut self) -> &mut Generics {
+        &mut self.generics
+    }
+    ///Replaces the `generics` field.
+    pub fn set_generics(&mut self, value: Generics) {
+        self.generics = value;
+    }
+    ///Returns the `colon_token` field.
+    pub fn colon_token(&self) -> &Option<token::Colon> {
+        &self.colon_token
+    }
+    ///Returns a mutable reference to the `colon_token` field.
+    pub fn colon_token_mut(&mut self) -> &mut Option<token::Colon> {
+        &mut self.colon_token
+    }
+    ///Replaces the `colon_token` field.
+    pub fn set_colon_token(&mut self, value: Option<token::Colon>) {
+        self.colon_token = value;
+    }
+    ///Returns the `bounds` field.
+    pub fn bounds(&self) -> &punctuated::Punctuated<TypeParamBound, token::Add> {
+        &self.bounds
+    }
+    ///Returns a mutable reference to the `bounds` field.
+    pub fn bounds_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<TypeParamBound, token::Add> {
+        &mut self.bounds
+    }
+    ///Replaces the `bounds` field.
+    pub fn set_bounds(
+        &mut self,
+        value: punctuated::Punctuated<TypeParamBound, token::Add>,
+    ) {
+        self.bounds = value;
+    }
+    ///Returns the `default` field.
+    pub fn default(&self) -> &Option<(token::Eq, Type)> {
+        &self.default
+    }
+    ///Returns a mutable reference to the `default` field.
+    pub fn default_mut(&mut self) -> &mut Option<(token::Eq, Type)> {
+        &mut self.default
+    }
+    ///Replaces the `default` field.
+    pub fn set_default(&mut self, value: Option<(token::Eq, Type)>) {
+        self.default = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Type {
+    ///Returns whether this is `Type::Array`.
+    pub fn is_array(&self) -> bool {
+        match self {
+            Type::Array(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Array`, or `None` if this is a different variant.
+    pub fn as_array(&self) -> Option<&TypeArray> {
+        match self {
+            Type::Array(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::BareFn`.
+    pub fn is_bare_fn(&self) -> bool {
+        match self {
+            Type::BareFn(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::BareFn`, or `None` if this is a different variant.
+    pub fn as_bare_fn(&self) -> Option<&TypeBareFn> {
+        match self {
+            Type::BareFn(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Group`.
+    pub fn is_group(&self) -> bool {
+        match self {
+            Type::Group(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Group`, or `None` if this is a different variant.
+    pub fn as_group(&self) -> Option<&TypeGroup> {
+        match self {
+            Type::Group(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::ImplTrait`.
+    pub fn is_impl_trait(&self) -> bool {
+        match self {
+            Type::ImplTrait(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::ImplTrait`, or `None` if this is a different variant.
+    pub fn as_impl_trait(&self) -> Option<&TypeImplTrait> {
+        match self {
+            Type::ImplTrait(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Infer`.
+    pub fn is_infer(&self) -> bool {
+        match self {
+            Type::Infer(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Infer`, or `None` if this is a different variant.
+    pub fn as_infer(&self) -> Option<&TypeInfer> {
+        match self {
+            Type::Infer(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Macro`.
+    pub fn is_macro(&self) -> bool {
+        match self {
+            Type::Macro(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Macro`, or `None` if this is a different variant.
+    pub fn as_macro(&self) -> Option<&TypeMacro> {
+        match self {
+            Type::Macro(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Never`.
+    pub fn is_never(&self) -> bool {
+        match self {
+            Type::Never(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Never`, or `None` if this is a different variant.
+    pub fn as_never(&self) -> Option<&TypeNever> {
+        match self {
+            Type::Never(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Paren`.
+    pub fn is_paren(&self) -> bool {
+        match self {
+            Type::Paren(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Paren`, or `None` if this is a different variant.
+    pub fn as_paren(&self) -> Option<&TypeParen> {
+        match self {
+            Type::Paren(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Path`.
+    pub fn is_path(&self) -> bool {
+        match self {
+            Type::Path(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Path`, or `None` if this is a different variant.
+    pub fn as_path(&self) -> Option<&TypePath> {
+        match self {
+            Type::Path(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Ptr`.
+    pub fn is_ptr(&self) -> bool {
+        match self {
+            Type::Ptr(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Ptr`, or `None` if this is a different variant.
+    pub fn as_ptr(&self) -> Option<&TypePtr> {
+        match self {
+            Type::Ptr(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Reference`.
+    pub fn is_reference(&self) -> bool {
+        match self {
+            Type::Reference(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Reference`, or `None` if this is a different variant.
+    pub fn as_reference(&self) -> Option<&TypeReference> {
+        match self {
+            Type::Reference(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Slice`.
+    pub fn is_slice(&self) -> bool {
+        match self {
+            Type::Slice(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Slice`, or `None` if this is a different variant.
+    pub fn as_slice(&self) -> Option<&TypeSlice> {
+        match self {
+            Type::Slice(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::TraitObject`.
+    pub fn is_trait_object(&self) -> bool {
+        match self {
+            Type::TraitObject(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::TraitObject`, or `None` if this is a different variant.
+    pub fn as_trait_object(&self) -> Option<&TypeTraitObject> {
+        match self {
+            Type::TraitObject(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Tuple`.
+    pub fn is_tuple(&self) -> bool {
+        match self {
+            Type::Tuple(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Tuple`, or `None` if this is a different variant.
+    pub fn as_tuple(&self) -> Option<&TypeTuple> {
+        match self {
+            Type::Tuple(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Type::Verbatim`.
+    pub fn is_verbatim(&self) -> bool {
+        match self {
+            Type::Verbatim(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Type::Verbatim`, or `None` if this is a different variant.
+    pub fn as_verbatim(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            Type::Verbatim(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeArray {
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+    ///Returns the `semi_token` field.
+    pub fn semi_token(&self) -> &token::Semi {
+        &self.semi_token
+    }
+    ///Returns a mutable reference to the `semi_token` field.
+    pub fn semi_token_mut(&mut self) -> &mut token::Semi {
+        &mut self.semi_token
+    }
+    ///Replaces the `semi_token` field.
+    pub fn set_semi_token(&mut self, value: token::Semi) {
+        self.semi_token = value;
+    }
+    ///Returns the `len` field.
+    pub fn len(&self) -> &Expr {
+        &self.len
+    }
+    ///Returns a mutable reference to the `len` field.
+    pub fn len_mut(&mut self) -> &mut Expr {
+        &mut self.len
+    }
+    ///Replaces the `len` field.
+    pub fn set_len(&mut self, value: Expr) {
+        self.len = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeBareFn {
+    ///Returns the `lifetimes` field.
+    pub fn lifetimes(&self) -> &Option<BoundLifetimes> {
+        &self.lifetimes
+    }
+    ///Returns a mutable reference to the `lifetimes` field.
+    pub fn lifetimes_mut(&mut self) -> &mut Option<BoundLifetimes> {
+        &mut self.lifetimes
+    }
+    ///Replaces the `lifetimes` field.
+    pub fn set_lifetimes(&mut self, value: Option<BoundLifetimes>) {
+        self.lifetimes = value;
+    }
+    ///Returns the `unsafety` field.
+    pub fn unsafety(&self) -> &Option<token::Unsafe> {
+        &self.unsafety
+    }
+    ///Returns a mutable reference to the `unsafety` field.
+    pub fn unsafety_mut(&mut self) -> &mut Option<token::Unsafe> {
+        &mut self.unsafety
+    }
+    ///Replaces the `unsafety` field.
+    pub fn set_unsafety(&mut self, value: Option<token::Unsafe>) {
+        self.unsafety = value;
+    }
+    ///Returns the `abi` field.
+    pub fn abi(&self) -> &Option<Abi> {
+        &self.abi
+    }
+    ///Returns a mutable reference to the `abi` field.
+    pub fn abi_mut(&mut self) -> &mut Option<Abi> {
+        &mut self.abi
+    }
+    ///Replaces the `abi` field.
+    pub fn set_abi(&mut self, value: Option<Abi>) {
+        self.abi = value;
+    }
+    ///Returns the `fn_token` field.
+    pub fn fn_token(&self) -> &token::Fn {
+        &self.fn_token
+    }
+    ///Returns a mutable reference to the `fn_token` field.
+    pub fn fn_token_mut(&mut self) -> &mut token::Fn {
+        &mut self.fn_token
+    }
+    ///Replaces the `fn_token` field.
+    pub fn set_fn_token(&mut self, value: token::Fn) {
+        self.fn_token = value;
+    }
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `inputs` field.
+    pub fn inputs(&self) -> &punctuated::Punctuated<BareFnArg, token::Comma> {
+        &self.inputs
+    }
+    ///Returns a mutable reference to the `inputs` field.
+    pub fn inputs_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<BareFnArg, token::Comma> {
+        &mut self.inputs
+    }
+    ///Replaces the `inputs` field.
+    pub fn set_inputs(
+        &mut self,
+        value: punctuated::Punctuated<BareFnArg, token::Comma>,
+    ) {
+        self.inputs = value;
+    }
+    ///Returns the `variadic` field.
+    pub fn variadic(&self) -> &Option<Variadic> {
+        &self.variadic
+    }
+    ///Returns a mutable reference to the `variadic` field.
+    pub fn variadic_mut(&mut self) -> &mut Option<Variadic> {
+        &mut self.variadic
+    }
+    ///Replaces the `variadic` field.
+    pub fn set_variadic(&mut self, value: Option<Variadic>) {
+        self.variadic = value;
+    }
+    ///Returns the `output` field.
+    pub fn output(&self) -> &ReturnType {
+        &self.output
+    }
+    ///Returns a mutable reference to the `output` field.
+    pub fn output_mut(&mut self) -> &mut ReturnType {
+        &mut self.output
+    }
+    ///Replaces the `output` field.
+    pub fn set_output(&mut self, value: ReturnType) {
+        self.output = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeGroup {
+    ///Returns the `group_token` field.
+    pub fn group_token(&self) -> &token::Group {
+        &self.group_token
+    }
+    ///Returns a mutable reference to the `group_token` field.
+    pub fn group_token_mut(&mut self) -> &mut token::Group {
+        &mut self.group_token
+    }
+    ///Replaces the `group_token` field.
+    pub fn set_group_token(&mut self, value: token::Group) {
+        self.group_token = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeImplTrait {
+    ///Returns the `impl_token` field.
+    pub fn impl_token(&self) -> &token::Impl {
+        &self.impl_token
+    }
+    ///Returns a mutable reference to the `impl_token` field.
+    pub fn impl_token_mut(&mut self) -> &mut token::Impl {
+        &mut self.impl_token
+    }
+    ///Replaces the `impl_token` field.
+    pub fn set_impl_token(&mut self, value: token::Impl) {
+        self.impl_token = value;
+    }
+    ///Returns the `bounds` field.
+    pub fn bounds(&self) -> &punctuated::Punctuated<TypeParamBound, token::Add> {
+        &self.bounds
+    }
+    ///Returns a mutable reference to the `bounds` field.
+    pub fn bounds_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<TypeParamBound, token::Add> {
+        &mut self.bounds
+    }
+    ///Replaces the `bounds` field.
+    pub fn set_bounds(
+        &mut self,
+        value: punctuated::Punctuated<TypeParamBound, token::Add>,
+    ) {
+        self.bounds = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeInfer {
+    ///Returns the `underscore_token` field.
+    pub fn underscore_token(&self) -> &token::Underscore {
+        &self.underscore_token
+    }
+    ///Returns a mutable reference to the `underscore_token` field.
+    pub fn underscore_token_mut(&mut self) -> &mut token::Underscore {
+        &mut self.underscore_token
+    }
+    ///Replaces the `underscore_token` field.
+    pub fn set_underscore_token(&mut self, value: token::Underscore) {
+        self.underscore_token = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeMacro {
+    ///Returns the `mac` field.
+    pub fn mac(&self) -> &Macro {
+        &self.mac
+    }
+    ///Returns a mutable reference to the `mac` field.
+    pub fn mac_mut(&mut self) -> &mut Macro {
+        &mut self.mac
+    }
+    ///Replaces the `mac` field.
+    pub fn set_mac(&mut self, value: Macro) {
+        self.mac = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeNever {
+    ///Returns the `bang_token` field.
+    pub fn bang_token(&self) -> &token::Bang {
+        &self.bang_token
+    }
+    ///Returns a mutable reference to the `bang_token` field.
+    pub fn bang_token_mut(&mut self) -> &mut token::Bang {
+        &mut self.bang_token
+    }
+    ///Replaces the `bang_token` field.
+    pub fn set_bang_token(&mut self, value: token::Bang) {
+        self.bang_token = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeParamBound {
+    ///Returns whether this is `TypeParamBound::Trait`.
+    pub fn is_trait(&self) -> bool {
+        match self {
+            TypeParamBound::Trait(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TypeParamBound::Trait`, or `None` if this is a different variant.
+    pub fn as_trait(&self) -> Option<&TraitBound> {
+        match self {
+            TypeParamBound::Trait(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `TypeParamBound::Lifetime`.
+    pub fn is_lifetime(&self) -> bool {
+        match self {
+            TypeParamBound::Lifetime(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `TypeParamBound::Lifetime`, or `None` if this is a different variant.
+    pub fn as_lifetime(&self) -> Option<&Lifetime> {
+        match self {
+            TypeParamBound::Lifetime(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeParen {
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypePath {
+    ///Returns the `qself` field.
+    pub fn qself(&self) -> &Option<QSelf> {
+        &self.qself
+    }
+    ///Returns a mutable reference to the `qself` field.
+    pub fn qself_mut(&mut self) -> &mut Option<QSelf> {
+        &mut self.qself
+    }
+    ///Replaces the `qself` field.
+    pub fn set_qself(&mut self, value: Option<QSelf>) {
+        self.qself = value;
+    }
+    ///Returns the `path` field.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    ///Returns a mutable reference to the `path` field.
+    pub fn path_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+    ///Replaces the `path` field.
+    pub fn set_path(&mut self, value: Path) {
+        self.path = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypePtr {
+    ///Returns the `star_token` field.
+    pub fn star_token(&self) -> &token::Star {
+        &self.star_token
+    }
+    ///Returns a mutable reference to the `star_token` field.
+    pub fn star_token_mut(&mut self) -> &mut token::Star {
+        &mut self.star_token
+    }
+    ///Replaces the `star_token` field.
+    pub fn set_star_token(&mut self, value: token::Star) {
+        self.star_token = value;
+    }
+    ///Returns the `const_token` field.
+    pub fn const_token(&self) -> &Option<token::Const> {
+        &self.const_token
+    }
+    ///Returns a mutable reference to the `const_token` field.
+    pub fn const_token_mut(&mut self) -> &mut Option<token::Const> {
+        &mut self.const_token
+    }
+    ///Replaces the `const_token` field.
+    pub fn set_const_token(&mut self, value: Option<token::Const>) {
+        self.const_token = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeReference {
+    ///Returns the `and_token` field.
+    pub fn and_token(&self) -> &token::And {
+        &self.and_token
+    }
+    ///Returns a mutable reference to the `and_token` field.
+    pub fn and_token_mut(&mut self) -> &mut token::And {
+        &mut self.and_token
+    }
+    ///Replaces the `and_token` field.
+    pub fn set_and_token(&mut self, value: token::And) {
+        self.and_token = value;
+    }
+    ///Returns the `lifetime` field.
+    pub fn lifetime(&self) -> &Option<Lifetime> {
+        &self.lifetime
+    }
+    ///Returns a mutable reference to the `lifetime` field.
+    pub fn lifetime_mut(&mut self) -> &mut Option<Lifetime> {
+        &mut self.lifetime
+    }
+    ///Replaces the `lifetime` field.
+    pub fn set_lifetime(&mut self, value: Option<Lifetime>) {
+        self.lifetime = value;
+    }
+    ///Returns the `mutability` field.
+    pub fn mutability(&self) -> &Option<token::Mut> {
+        &self.mutability
+    }
+    ///Returns a mutable reference to the `mutability` field.
+    pub fn mutability_mut(&mut self) -> &mut Option<token::Mut> {
+        &mut self.mutability
+    }
+    ///Replaces the `mutability` field.
+    pub fn set_mutability(&mut self, value: Option<token::Mut>) {
+        self.mutability = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeSlice {
+    ///Returns the `bracket_token` field.
+    pub fn bracket_token(&self) -> &token::Bracket {
+        &self.bracket_token
+    }
+    ///Returns a mutable reference to the `bracket_token` field.
+    pub fn bracket_token_mut(&mut self) -> &mut token::Bracket {
+        &mut self.bracket_token
+    }
+    ///Replaces the `bracket_token` field.
+    pub fn set_bracket_token(&mut self, value: token::Bracket) {
+        self.bracket_token = value;
+    }
+    ///Returns the `elem` field.
+    pub fn elem(&self) -> &Type {
+        &self.elem
+    }
+    ///Returns a mutable reference to the `elem` field.
+    pub fn elem_mut(&mut self) -> &mut Type {
+        &mut self.elem
+    }
+    ///Replaces the `elem` field.
+    pub fn set_elem(&mut self, value: Type) {
+        *self.elem = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeTraitObject {
+    ///Returns the `dyn_token` field.
+    pub fn dyn_token(&self) -> &Option<token::Dyn> {
+        &self.dyn_token
+    }
+    ///Returns a mutable reference to the `dyn_token` field.
+    pub fn dyn_token_mut(&mut self) -> &mut Option<token::Dyn> {
+        &mut self.dyn_token
+    }
+    ///Replaces the `dyn_token` field.
+    pub fn set_dyn_token(&mut self, value: Option<token::Dyn>) {
+        self.dyn_token = value;
+    }
+    ///Returns the `bounds` field.
+    pub fn bounds(&self) -> &punctuated::Punctuated<TypeParamBound, token::Add> {
+        &self.bounds
+    }
+    ///Returns a mutable reference to the `bounds` field.
+    pub fn bounds_mut(
+        &mut self,
+    ) -> &mut punctuated::Punctuated<TypeParamBound, token::Add> {
+        &mut self.bounds
+    }
+    ///Replaces the `bounds` field.
+    pub fn set_bounds(
+        &mut self,
+        value: punctuated::Punctuated<TypeParamBound, token::Add>,
+    ) {
+        self.bounds = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeTuple {
+    ///Returns the `paren_token` field.
+    pub fn paren_token(&self) -> &token::Paren {
+        &self.paren_token
+    }
+    ///Returns a mutable reference to the `paren_token` field.
+    pub fn paren_token_mut(&mut self) -> &mut token::Paren {
+        &mut self.paren_token
+    }
+    ///Replaces the `paren_token` field.
+    pub fn set_paren_token(&mut self, value: token::Paren) {
+        self.paren_token = value;
+    }
+    ///Returns the `elems` field.
+    pub fn elems(&self) -> &punctuated::Punctuated<Type, token::Comma> {
+        &self.elems
+    }
+    ///Returns a mutable reference to the `elems` field.
+    pub fn elems_mut(&mut self) -> &mut punctuated::Punctuated<Type, token::Comma> {
+        &mut self.elems
+    }
+    ///Replaces the `elems` field.
+    pub fn set_elems(&mut self, value: punctuated::Punctuated<Type, token::Comma>) {
+        self.elems = value;
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl UnOp {
+    ///Returns whether this is `UnOp::Deref`.
+    pub fn is_deref(&self) -> bool {
+        match self {
+            UnOp::Deref(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UnOp::Deref`, or `None` if this is a different variant.
+    pub fn as_deref(&self) -> Option<&token::Star> {
+        match self {
+            UnOp::Deref(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UnOp::Not`.
+    pub fn is_not(&self) -> bool {
+        match self {
+            UnOp::Not(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UnOp::Not`, or `None` if this is a different variant.
+    pub fn as_not(&self) -> Option<&token::Bang> {
+        match self {
+            UnOp::Not(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UnOp::Neg`.
+    pub fn is_neg(&self) -> bool {
+        match self {
+            UnOp::Neg(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UnOp::Neg`, or `None` if this is a different variant.
+    pub fn as_neg(&self) -> Option<&token::Sub> {
+        match self {
+            UnOp::Neg(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl UseTree {
+    ///Returns whether this is `UseTree::Path`.
+    pub fn is_path(&self) -> bool {
+        match self {
+            UseTree::Path(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UseTree::Path`, or `None` if this is a different variant.
+    pub fn as_path(&self) -> Option<&UsePath> {
+        match self {
+            UseTree::Path(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UseTree::Name`.
+    pub fn is_name(&self) -> bool {
+        match self {
+            UseTree::Name(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UseTree::Name`, or `None` if this is a different variant.
+    pub fn as_name(&self) -> Option<&UseName> {
+        match self {
+            UseTree::Name(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UseTree::Rename`.
+    pub fn is_rename(&self) -> bool {
+        match self {
+            UseTree::Rename(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UseTree::Rename`, or `None` if this is a different variant.
+    pub fn as_rename(&self) -> Option<&UseRename> {
+        match self {
+            UseTree::Rename(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UseTree::Glob`.
+    pub fn is_glob(&self) -> bool {
+        match self {
+            UseTree::Glob(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UseTree::Glob`, or `None` if this is a different variant.
+    pub fn as_glob(&self) -> Option<&UseGlob> {
+        match self {
+            UseTree::Glob(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `UseTree::Group`.
+    pub fn is_group(&self) -> bool {
+        match self {
+            UseTree::Group(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `UseTree::Group`, or `None` if this is a different variant.
+    pub fn as_group(&self) -> Option<&UseGroup> {
+        match self {
+            UseTree::Group(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Visibility {
+    ///Returns the field of `Visibility::Public`, or `None` if this is a different variant.
+    pub fn as_public(&self) -> Option<&VisPublic> {
+        match self {
+            Visibility::Public(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Visibility::Crate`.
+    pub fn is_crate(&self) -> bool {
+        match self {
+            Visibility::Crate(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Visibility::Crate`, or `None` if this is a different variant.
+    pub fn as_crate(&self) -> Option<&VisCrate> {
+        match self {
+            Visibility::Crate(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `Visibility::Restricted`.
+    pub fn is_restricted(&self) -> bool {
+        match self {
+            Visibility::Restricted(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `Visibility::Restricted`, or `None` if this is a different variant.
+    pub fn as_restricted(&self) -> Option<&VisRestricted> {
+        match self {
+            Visibility::Restricted(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl WherePredicate {
+    ///Returns whether this is `WherePredicate::Type`.
+    pub fn is_type(&self) -> bool {
+        match self {
+            WherePredicate::Type(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `WherePredicate::Type`, or `None` if this is a different variant.
+    pub fn as_type(&self) -> Option<&PredicateType> {
+        match self {
+            WherePredicate::Type(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `WherePredicate::Lifetime`.
+    pub fn is_lifetime(&self) -> bool {
+        match self {
+            WherePredicate::Lifetime(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `WherePredicate::Lifetime`, or `None` if this is a different variant.
+    pub fn as_lifetime(&self) -> Option<&PredicateLifetime> {
+        match self {
+            WherePredicate::Lifetime(v0) => Some(v0),
+            _ => None,
+        }
+    }
+    ///Returns whether this is `WherePredicate::Eq`.
+    pub fn is_eq(&self) -> bool {
+        match self {
+            WherePredicate::Eq(..) => true,
+            _ => false,
+        }
+    }
+    ///Returns the field of `WherePredicate::Eq`, or `None` if this is a different variant.
+    pub fn as_eq(&self) -> Option<&PredicateEq> {
+        match self {
+            WherePredicate::Eq(v0) => Some(v0),
+            _ => None,
+        }
+    }
+}
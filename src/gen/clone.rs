@@ -3,7 +3,7 @@
 
 #![allow(clippy::clone_on_copy, clippy::expl_impl_clone_on_copy)]
 use crate::*;
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Abi {
     fn clone(&self) -> Self {
@@ -13,7 +13,32 @@ impl Clone for Abi {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AbiName {
+    fn clone(&self) -> Self {
+        match self {
+            AbiName::Rust(v0) => AbiName::Rust(v0.clone()),
+            AbiName::C(v0) => AbiName::C(v0.clone()),
+            AbiName::CUnwind(v0) => AbiName::CUnwind(v0.clone()),
+            AbiName::System(v0) => AbiName::System(v0.clone()),
+            AbiName::SystemUnwind(v0) => AbiName::SystemUnwind(v0.clone()),
+            AbiName::Cdecl(v0) => AbiName::Cdecl(v0.clone()),
+            AbiName::Stdcall(v0) => AbiName::Stdcall(v0.clone()),
+            AbiName::Fastcall(v0) => AbiName::Fastcall(v0.clone()),
+            AbiName::Vectorcall(v0) => AbiName::Vectorcall(v0.clone()),
+            AbiName::Thiscall(v0) => AbiName::Thiscall(v0.clone()),
+            AbiName::Aapcs(v0) => AbiName::Aapcs(v0.clone()),
+            AbiName::Win64(v0) => AbiName::Win64(v0.clone()),
+            AbiName::Sysv64(v0) => AbiName::Sysv64(v0.clone()),
+            AbiName::Efiapi(v0) => AbiName::Efiapi(v0.clone()),
+            AbiName::Other(v0) => AbiName::Other(v0.clone()),
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for AngleBracketedGenericArguments {
     fn clone(&self) -> Self {
@@ -39,17 +64,17 @@ impl Clone for Arm {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Copy for AttrStyle {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for AttrStyle {
     fn clone(&self) -> Self {
         *self
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Attribute {
     fn clone(&self) -> Self {
@@ -62,7 +87,7 @@ impl Clone for Attribute {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for BareFnArg {
     fn clone(&self) -> Self {
@@ -73,17 +98,17 @@ impl Clone for BareFnArg {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Copy for BinOp {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for BinOp {
     fn clone(&self) -> Self {
         *self
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Binding {
     fn clone(&self) -> Self {
@@ -104,7 +129,7 @@ impl Clone for Block {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for BoundLifetimes {
     fn clone(&self) -> Self {
@@ -116,7 +141,64 @@ impl Clone for BoundLifetimes {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for CfgAll {
+    fn clone(&self) -> Self {
+        CfgAll {
+            ident: self.ident.clone(),
+            paren_token: self.paren_token.clone(),
+            predicates: self.predicates.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for CfgAny {
+    fn clone(&self) -> Self {
+        CfgAny {
+            ident: self.ident.clone(),
+            paren_token: self.paren_token.clone(),
+            predicates: self.predicates.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for CfgExpr {
+    fn clone(&self) -> Self {
+        match self {
+            CfgExpr::Key(v0) => CfgExpr::Key(v0.clone()),
+            CfgExpr::KeyValue(v0) => CfgExpr::KeyValue(v0.clone()),
+            CfgExpr::All(v0) => CfgExpr::All(v0.clone()),
+            CfgExpr::Any(v0) => CfgExpr::Any(v0.clone()),
+            CfgExpr::Not(v0) => CfgExpr::Not(v0.clone()),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for CfgKeyValue {
+    fn clone(&self) -> Self {
+        CfgKeyValue {
+            key: self.key.clone(),
+            eq_token: self.eq_token.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for CfgNot {
+    fn clone(&self) -> Self {
+        CfgNot {
+            ident: self.ident.clone(),
+            paren_token: self.paren_token.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ConstParam {
     fn clone(&self) -> Self {
@@ -131,7 +213,7 @@ impl Clone for ConstParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Constraint {
     fn clone(&self) -> Self {
@@ -185,6 +267,16 @@ impl Clone for DataUnion {
         }
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for Decreases {
+    fn clone(&self) -> Self {
+        Decreases {
+            decreases_token: self.decreases_token.clone(),
+            exprs: self.exprs.clone(),
+        }
+    }
+}
 #[cfg(feature = "derive")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for DeriveInput {
@@ -198,7 +290,17 @@ impl Clone for DeriveInput {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for Ensures {
+    fn clone(&self) -> Self {
+        Ensures {
+            ensures_token: self.ensures_token.clone(),
+            exprs: self.exprs.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Expr {
     fn clone(&self) -> Self {
@@ -337,7 +439,7 @@ impl Clone for ExprAwait {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprBinary {
     fn clone(&self) -> Self {
@@ -383,7 +485,7 @@ impl Clone for ExprBreak {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprCall {
     fn clone(&self) -> Self {
@@ -395,7 +497,7 @@ impl Clone for ExprCall {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprCast {
     fn clone(&self) -> Self {
@@ -435,7 +537,7 @@ impl Clone for ExprContinue {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprField {
     fn clone(&self) -> Self {
@@ -458,6 +560,7 @@ impl Clone for ExprForLoop {
             pat: self.pat.clone(),
             in_token: self.in_token.clone(),
             expr: self.expr.clone(),
+            spec: self.spec.clone(),
             body: self.body.clone(),
         }
     }
@@ -486,7 +589,7 @@ impl Clone for ExprIf {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprIndex {
     fn clone(&self) -> Self {
@@ -511,7 +614,7 @@ impl Clone for ExprLet {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprLit {
     fn clone(&self) -> Self {
@@ -529,6 +632,7 @@ impl Clone for ExprLoop {
             attrs: self.attrs.clone(),
             label: self.label.clone(),
             loop_token: self.loop_token.clone(),
+            spec: self.spec.clone(),
             body: self.body.clone(),
         }
     }
@@ -571,7 +675,7 @@ impl Clone for ExprMethodCall {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprParen {
     fn clone(&self) -> Self {
@@ -582,7 +686,7 @@ impl Clone for ExprParen {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprPath {
     fn clone(&self) -> Self {
@@ -701,7 +805,7 @@ impl Clone for ExprType {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprUnary {
     fn clone(&self) -> Self {
@@ -732,6 +836,7 @@ impl Clone for ExprWhile {
             label: self.label.clone(),
             while_token: self.while_token.clone(),
             cond: self.cond.clone(),
+            spec: self.spec.clone(),
             body: self.body.clone(),
         }
     }
@@ -838,6 +943,18 @@ impl Clone for FnArg {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for FnQualifiers {
+    fn clone(&self) -> Self {
+        FnQualifiers {
+            constness: self.constness.clone(),
+            asyncness: self.asyncness.clone(),
+            unsafety: self.unsafety.clone(),
+            abi: self.abi.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ForeignItem {
     fn clone(&self) -> Self {
         match self {
@@ -903,7 +1020,7 @@ impl Clone for ForeignItemType {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for GenericArgument {
     fn clone(&self) -> Self {
@@ -926,7 +1043,7 @@ impl Clone for GenericMethodArgument {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for GenericParam {
     fn clone(&self) -> Self {
@@ -937,7 +1054,7 @@ impl Clone for GenericParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Generics {
     fn clone(&self) -> Self {
@@ -1023,7 +1140,7 @@ impl Clone for ImplItemType {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Index {
     fn clone(&self) -> Self {
@@ -1033,6 +1150,16 @@ impl Clone for Index {
         }
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for Invariant {
+    fn clone(&self) -> Self {
+        Invariant {
+            invariant_token: self.invariant_token.clone(),
+            exprs: self.exprs.clone(),
+        }
+    }
+}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Item {
@@ -1309,7 +1436,7 @@ impl Clone for Label {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for LifetimeDef {
     fn clone(&self) -> Self {
@@ -1358,7 +1485,7 @@ impl Clone for Local {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Macro {
     fn clone(&self) -> Self {
@@ -1370,7 +1497,7 @@ impl Clone for Macro {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for MacroDelimiter {
     fn clone(&self) -> Self {
@@ -1381,7 +1508,7 @@ impl Clone for MacroDelimiter {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Member {
     fn clone(&self) -> Self {
@@ -1391,7 +1518,7 @@ impl Clone for Member {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Meta {
     fn clone(&self) -> Self {
@@ -1402,7 +1529,7 @@ impl Clone for Meta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for MetaList {
     fn clone(&self) -> Self {
@@ -1413,7 +1540,7 @@ impl Clone for MetaList {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for MetaNameValue {
     fn clone(&self) -> Self {
@@ -1436,7 +1563,7 @@ impl Clone for MethodTurbofish {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for NestedMeta {
     fn clone(&self) -> Self {
@@ -1446,7 +1573,7 @@ impl Clone for NestedMeta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ParenthesizedGenericArguments {
     fn clone(&self) -> Self {
@@ -1651,7 +1778,7 @@ impl Clone for PatWild {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Path {
     fn clone(&self) -> Self {
@@ -1661,7 +1788,7 @@ impl Clone for Path {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PathArguments {
     fn clone(&self) -> Self {
@@ -1674,7 +1801,7 @@ impl Clone for PathArguments {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PathSegment {
     fn clone(&self) -> Self {
@@ -1684,7 +1811,7 @@ impl Clone for PathSegment {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PredicateEq {
     fn clone(&self) -> Self {
@@ -1695,7 +1822,7 @@ impl Clone for PredicateEq {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PredicateLifetime {
     fn clone(&self) -> Self {
@@ -1706,7 +1833,7 @@ impl Clone for PredicateLifetime {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PredicateType {
     fn clone(&self) -> Self {
@@ -1718,7 +1845,7 @@ impl Clone for PredicateType {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for QSelf {
     fn clone(&self) -> Self {
@@ -1753,7 +1880,17 @@ impl Clone for Receiver {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for Requires {
+    fn clone(&self) -> Self {
+        Requires {
+            requires_token: self.requires_token.clone(),
+            exprs: self.exprs.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ReturnType {
     fn clone(&self) -> Self {
@@ -1779,6 +1916,23 @@ impl Clone for Signature {
             inputs: self.inputs.clone(),
             variadic: self.variadic.clone(),
             output: self.output.clone(),
+            spec: self.spec.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for SpecClauses {
+    fn clone(&self) -> Self {
+        SpecClauses {
+            #[cfg(feature = "verus")]
+            requires: self.requires.clone(),
+            #[cfg(feature = "verus")]
+            ensures: self.ensures.clone(),
+            #[cfg(feature = "verus")]
+            invariant: self.invariant.clone(),
+            #[cfg(feature = "verus")]
+            decreases: self.decreases.clone(),
         }
     }
 }
@@ -1794,7 +1948,7 @@ impl Clone for Stmt {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TraitBound {
     fn clone(&self) -> Self {
@@ -1806,10 +1960,10 @@ impl Clone for TraitBound {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Copy for TraitBoundModifier {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TraitBoundModifier {
     fn clone(&self) -> Self {
@@ -1885,7 +2039,7 @@ impl Clone for TraitItemType {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Type {
     fn clone(&self) -> Self {
@@ -1910,7 +2064,7 @@ impl Clone for Type {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeArray {
     fn clone(&self) -> Self {
@@ -1922,7 +2076,7 @@ impl Clone for TypeArray {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeBareFn {
     fn clone(&self) -> Self {
@@ -1938,7 +2092,7 @@ impl Clone for TypeBareFn {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeGroup {
     fn clone(&self) -> Self {
@@ -1948,7 +2102,7 @@ impl Clone for TypeGroup {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeImplTrait {
     fn clone(&self) -> Self {
@@ -1958,7 +2112,7 @@ impl Clone for TypeImplTrait {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeInfer {
     fn clone(&self) -> Self {
@@ -1967,14 +2121,14 @@ impl Clone for TypeInfer {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeMacro {
     fn clone(&self) -> Self {
         TypeMacro { mac: self.mac.clone() }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeNever {
     fn clone(&self) -> Self {
@@ -1983,7 +2137,7 @@ impl Clone for TypeNever {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeParam {
     fn clone(&self) -> Self {
@@ -1997,7 +2151,7 @@ impl Clone for TypeParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeParamBound {
     fn clone(&self) -> Self {
@@ -2007,7 +2161,7 @@ impl Clone for TypeParamBound {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeParen {
     fn clone(&self) -> Self {
@@ -2017,7 +2171,7 @@ impl Clone for TypeParen {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypePath {
     fn clone(&self) -> Self {
@@ -2027,7 +2181,7 @@ impl Clone for TypePath {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypePtr {
     fn clone(&self) -> Self {
@@ -2039,7 +2193,7 @@ impl Clone for TypePtr {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeReference {
     fn clone(&self) -> Self {
@@ -2051,7 +2205,7 @@ impl Clone for TypeReference {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeSlice {
     fn clone(&self) -> Self {
@@ -2061,7 +2215,7 @@ impl Clone for TypeSlice {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeTraitObject {
     fn clone(&self) -> Self {
@@ -2071,7 +2225,7 @@ impl Clone for TypeTraitObject {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for TypeTuple {
     fn clone(&self) -> Self {
@@ -2081,10 +2235,10 @@ impl Clone for TypeTuple {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Copy for UnOp {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for UnOp {
     fn clone(&self) -> Self {
@@ -2154,7 +2308,7 @@ impl Clone for UseTree {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Variadic {
     fn clone(&self) -> Self {
@@ -2218,7 +2372,7 @@ impl Clone for Visibility {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for WhereClause {
     fn clone(&self) -> Self {
@@ -2228,7 +2382,7 @@ impl Clone for WhereClause {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for WherePredicate {
     fn clone(&self) -> Self {
@@ -3,7 +3,7 @@
 
 use crate::*;
 use std::fmt::{self, Debug};
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Abi {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -13,7 +13,92 @@ impl Debug for Abi {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AbiName {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbiName::Rust(v0) => {
+                let mut formatter = formatter.debug_tuple("Rust");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::C(v0) => {
+                let mut formatter = formatter.debug_tuple("C");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::CUnwind(v0) => {
+                let mut formatter = formatter.debug_tuple("CUnwind");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::System(v0) => {
+                let mut formatter = formatter.debug_tuple("System");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::SystemUnwind(v0) => {
+                let mut formatter = formatter.debug_tuple("SystemUnwind");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Cdecl(v0) => {
+                let mut formatter = formatter.debug_tuple("Cdecl");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Stdcall(v0) => {
+                let mut formatter = formatter.debug_tuple("Stdcall");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Fastcall(v0) => {
+                let mut formatter = formatter.debug_tuple("Fastcall");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Vectorcall(v0) => {
+                let mut formatter = formatter.debug_tuple("Vectorcall");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Thiscall(v0) => {
+                let mut formatter = formatter.debug_tuple("Thiscall");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Aapcs(v0) => {
+                let mut formatter = formatter.debug_tuple("Aapcs");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Win64(v0) => {
+                let mut formatter = formatter.debug_tuple("Win64");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Sysv64(v0) => {
+                let mut formatter = formatter.debug_tuple("Sysv64");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Efiapi(v0) => {
+                let mut formatter = formatter.debug_tuple("Efiapi");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AbiName::Other(v0) => {
+                let mut formatter = formatter.debug_tuple("Other");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for AngleBracketedGenericArguments {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -39,7 +124,7 @@ impl Debug for Arm {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for AttrStyle {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -53,7 +138,7 @@ impl Debug for AttrStyle {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Attribute {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -66,7 +151,7 @@ impl Debug for Attribute {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for BareFnArg {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -77,7 +162,7 @@ impl Debug for BareFnArg {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for BinOp {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -225,7 +310,7 @@ impl Debug for BinOp {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Binding {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -246,7 +331,7 @@ impl Debug for Block {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for BoundLifetimes {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -258,7 +343,84 @@ impl Debug for BoundLifetimes {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for CfgAll {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("CfgAll");
+        formatter.field("ident", &self.ident);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("predicates", &self.predicates);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for CfgAny {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("CfgAny");
+        formatter.field("ident", &self.ident);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("predicates", &self.predicates);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for CfgExpr {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CfgExpr::Key(v0) => {
+                let mut formatter = formatter.debug_tuple("Key");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            CfgExpr::KeyValue(v0) => {
+                let mut formatter = formatter.debug_tuple("KeyValue");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            CfgExpr::All(v0) => {
+                let mut formatter = formatter.debug_tuple("All");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            CfgExpr::Any(v0) => {
+                let mut formatter = formatter.debug_tuple("Any");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            CfgExpr::Not(v0) => {
+                let mut formatter = formatter.debug_tuple("Not");
+                formatter.field(v0);
+                formatter.finish()
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for CfgKeyValue {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("CfgKeyValue");
+        formatter.field("key", &self.key);
+        formatter.field("eq_token", &self.eq_token);
+        formatter.field("value", &self.value);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for CfgNot {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("CfgNot");
+        formatter.field("ident", &self.ident);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("predicate", &self.predicate);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ConstParam {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -273,7 +435,7 @@ impl Debug for ConstParam {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Constraint {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -339,6 +501,16 @@ impl Debug for DataUnion {
         formatter.finish()
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for Decreases {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("Decreases");
+        formatter.field("decreases_token", &self.decreases_token);
+        formatter.field("exprs", &self.exprs);
+        formatter.finish()
+    }
+}
 #[cfg(feature = "derive")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for DeriveInput {
@@ -352,7 +524,17 @@ impl Debug for DeriveInput {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for Ensures {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("Ensures");
+        formatter.field("ensures_token", &self.ensures_token);
+        formatter.field("exprs", &self.exprs);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Expr {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -651,7 +833,7 @@ impl Debug for ExprAwait {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprBinary {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -697,7 +879,7 @@ impl Debug for ExprBreak {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprCall {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -709,7 +891,7 @@ impl Debug for ExprCall {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprCast {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -749,7 +931,7 @@ impl Debug for ExprContinue {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprField {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -772,6 +954,7 @@ impl Debug for ExprForLoop {
         formatter.field("pat", &self.pat);
         formatter.field("in_token", &self.in_token);
         formatter.field("expr", &self.expr);
+        formatter.field("spec", &self.spec);
         formatter.field("body", &self.body);
         formatter.finish()
     }
@@ -800,7 +983,7 @@ impl Debug for ExprIf {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprIndex {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -825,7 +1008,7 @@ impl Debug for ExprLet {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprLit {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -843,6 +1026,7 @@ impl Debug for ExprLoop {
         formatter.field("attrs", &self.attrs);
         formatter.field("label", &self.label);
         formatter.field("loop_token", &self.loop_token);
+        formatter.field("spec", &self.spec);
         formatter.field("body", &self.body);
         formatter.finish()
     }
@@ -885,7 +1069,7 @@ impl Debug for ExprMethodCall {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprParen {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -896,7 +1080,7 @@ impl Debug for ExprParen {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprPath {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1015,7 +1199,7 @@ impl Debug for ExprType {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprUnary {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1046,6 +1230,7 @@ impl Debug for ExprWhile {
         formatter.field("label", &self.label);
         formatter.field("while_token", &self.while_token);
         formatter.field("cond", &self.cond);
+        formatter.field("spec", &self.spec);
         formatter.field("body", &self.body);
         formatter.finish()
     }
@@ -1168,6 +1353,18 @@ impl Debug for FnArg {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for FnQualifiers {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("FnQualifiers");
+        formatter.field("constness", &self.constness);
+        formatter.field("asyncness", &self.asyncness);
+        formatter.field("unsafety", &self.unsafety);
+        formatter.field("abi", &self.abi);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ForeignItem {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1253,7 +1450,7 @@ impl Debug for ForeignItemType {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for GenericArgument {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1304,7 +1501,7 @@ impl Debug for GenericMethodArgument {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for GenericParam {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1327,7 +1524,7 @@ impl Debug for GenericParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Generics {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1433,7 +1630,7 @@ impl Debug for ImplItemType {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Index {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1443,6 +1640,16 @@ impl Debug for Index {
         formatter.finish()
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for Invariant {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("Invariant");
+        formatter.field("invariant_token", &self.invariant_token);
+        formatter.field("exprs", &self.exprs);
+        formatter.finish()
+    }
+}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Item {
@@ -1796,7 +2003,7 @@ impl Debug for Lifetime {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for LifetimeDef {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1868,7 +2075,7 @@ impl Debug for Local {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Macro {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1880,7 +2087,7 @@ impl Debug for Macro {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for MacroDelimiter {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1903,7 +2110,7 @@ impl Debug for MacroDelimiter {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Member {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1921,7 +2128,7 @@ impl Debug for Member {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Meta {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1944,7 +2151,7 @@ impl Debug for Meta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for MetaList {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1955,7 +2162,7 @@ impl Debug for MetaList {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for MetaNameValue {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1978,7 +2185,7 @@ impl Debug for MethodTurbofish {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for NestedMeta {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -1996,7 +2203,7 @@ impl Debug for NestedMeta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ParenthesizedGenericArguments {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2265,7 +2472,7 @@ impl Debug for PatWild {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Path {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2275,7 +2482,7 @@ impl Debug for Path {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PathArguments {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2294,7 +2501,7 @@ impl Debug for PathArguments {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PathSegment {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2304,7 +2511,7 @@ impl Debug for PathSegment {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PredicateEq {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2315,7 +2522,7 @@ impl Debug for PredicateEq {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PredicateLifetime {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2326,7 +2533,7 @@ impl Debug for PredicateLifetime {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PredicateType {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2338,7 +2545,7 @@ impl Debug for PredicateType {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for QSelf {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2381,7 +2588,17 @@ impl Debug for Receiver {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for Requires {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("Requires");
+        formatter.field("requires_token", &self.requires_token);
+        formatter.field("exprs", &self.exprs);
+        formatter.finish()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ReturnType {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2412,6 +2629,19 @@ impl Debug for Signature {
         formatter.field("inputs", &self.inputs);
         formatter.field("variadic", &self.variadic);
         formatter.field("output", &self.output);
+        formatter.field("spec", &self.spec);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for SpecClauses {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("SpecClauses");
+        #[cfg(feature = "verus")] formatter.field("requires", &self.requires);
+        #[cfg(feature = "verus")] formatter.field("ensures", &self.ensures);
+        #[cfg(feature = "verus")] formatter.field("invariant", &self.invariant);
+        #[cfg(feature = "verus")] formatter.field("decreases", &self.decreases);
         formatter.finish()
     }
 }
@@ -2444,7 +2674,7 @@ impl Debug for Stmt {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TraitBound {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2456,7 +2686,7 @@ impl Debug for TraitBound {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TraitBoundModifier {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2559,7 +2789,7 @@ impl Debug for TraitItemType {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Type {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2644,7 +2874,7 @@ impl Debug for Type {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeArray {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2656,7 +2886,7 @@ impl Debug for TypeArray {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeBareFn {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2672,7 +2902,7 @@ impl Debug for TypeBareFn {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeGroup {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2682,7 +2912,7 @@ impl Debug for TypeGroup {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeImplTrait {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2692,7 +2922,7 @@ impl Debug for TypeImplTrait {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeInfer {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2701,7 +2931,7 @@ impl Debug for TypeInfer {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeMacro {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2710,7 +2940,7 @@ impl Debug for TypeMacro {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeNever {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2719,7 +2949,7 @@ impl Debug for TypeNever {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeParam {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2733,7 +2963,7 @@ impl Debug for TypeParam {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeParamBound {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2751,7 +2981,7 @@ impl Debug for TypeParamBound {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeParen {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2761,7 +2991,7 @@ impl Debug for TypeParen {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypePath {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2771,7 +3001,7 @@ impl Debug for TypePath {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypePtr {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2783,7 +3013,7 @@ impl Debug for TypePtr {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeReference {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2795,7 +3025,7 @@ impl Debug for TypeReference {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeSlice {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2805,7 +3035,7 @@ impl Debug for TypeSlice {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeTraitObject {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2815,7 +3045,7 @@ impl Debug for TypeTraitObject {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for TypeTuple {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2825,7 +3055,7 @@ impl Debug for TypeTuple {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for UnOp {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -2931,7 +3161,7 @@ impl Debug for UseTree {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Variadic {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -3007,7 +3237,7 @@ impl Debug for Visibility {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for WhereClause {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -3017,7 +3247,7 @@ impl Debug for WhereClause {
         formatter.finish()
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for WherePredicate {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
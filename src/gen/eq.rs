@@ -1,23 +1,52 @@
 // This file is @generated by syn-internal-codegen.
 // It is not intended for manual editing.
 
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 use crate::tt::TokenStreamHelper;
 use crate::*;
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Abi {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Abi {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AbiName {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AbiName {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AbiName::Rust(self0), AbiName::Rust(other0)) => self0 == other0,
+            (AbiName::C(self0), AbiName::C(other0)) => self0 == other0,
+            (AbiName::CUnwind(self0), AbiName::CUnwind(other0)) => self0 == other0,
+            (AbiName::System(self0), AbiName::System(other0)) => self0 == other0,
+            (AbiName::SystemUnwind(self0), AbiName::SystemUnwind(other0)) => {
+                self0 == other0
+            }
+            (AbiName::Cdecl(self0), AbiName::Cdecl(other0)) => self0 == other0,
+            (AbiName::Stdcall(self0), AbiName::Stdcall(other0)) => self0 == other0,
+            (AbiName::Fastcall(self0), AbiName::Fastcall(other0)) => self0 == other0,
+            (AbiName::Vectorcall(self0), AbiName::Vectorcall(other0)) => self0 == other0,
+            (AbiName::Thiscall(self0), AbiName::Thiscall(other0)) => self0 == other0,
+            (AbiName::Aapcs(self0), AbiName::Aapcs(other0)) => self0 == other0,
+            (AbiName::Win64(self0), AbiName::Win64(other0)) => self0 == other0,
+            (AbiName::Sysv64(self0), AbiName::Sysv64(other0)) => self0 == other0,
+            (AbiName::Efiapi(self0), AbiName::Efiapi(other0)) => self0 == other0,
+            (AbiName::Other(self0), AbiName::Other(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for AngleBracketedGenericArguments {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for AngleBracketedGenericArguments {
     fn eq(&self, other: &Self) -> bool {
@@ -35,10 +64,10 @@ impl PartialEq for Arm {
             && self.body == other.body && self.comma == other.comma
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for AttrStyle {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for AttrStyle {
     fn eq(&self, other: &Self) -> bool {
@@ -49,10 +78,10 @@ impl PartialEq for AttrStyle {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Attribute {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Attribute {
     fn eq(&self, other: &Self) -> bool {
@@ -60,20 +89,20 @@ impl PartialEq for Attribute {
             && TokenStreamHelper(&self.tokens) == TokenStreamHelper(&other.tokens)
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for BareFnArg {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for BareFnArg {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs && self.name == other.name && self.ty == other.ty
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for BinOp {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for BinOp {
     fn eq(&self, other: &Self) -> bool {
@@ -110,10 +139,10 @@ impl PartialEq for BinOp {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Binding {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Binding {
     fn eq(&self, other: &Self) -> bool {
@@ -130,20 +159,77 @@ impl PartialEq for Block {
         self.stmts == other.stmts
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for BoundLifetimes {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for BoundLifetimes {
     fn eq(&self, other: &Self) -> bool {
         self.lifetimes == other.lifetimes
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for CfgAll {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for CfgAll {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.predicates == other.predicates
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for CfgAny {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for CfgAny {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.predicates == other.predicates
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for CfgExpr {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for CfgExpr {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CfgExpr::Key(self0), CfgExpr::Key(other0)) => self0 == other0,
+            (CfgExpr::KeyValue(self0), CfgExpr::KeyValue(other0)) => self0 == other0,
+            (CfgExpr::All(self0), CfgExpr::All(other0)) => self0 == other0,
+            (CfgExpr::Any(self0), CfgExpr::Any(other0)) => self0 == other0,
+            (CfgExpr::Not(self0), CfgExpr::Not(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for CfgKeyValue {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for CfgKeyValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for CfgNot {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for CfgNot {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.predicate == other.predicate
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ConstParam {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ConstParam {
     fn eq(&self, other: &Self) -> bool {
@@ -151,10 +237,10 @@ impl PartialEq for ConstParam {
             && self.eq_token == other.eq_token && self.default == other.default
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Constraint {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Constraint {
     fn eq(&self, other: &Self) -> bool {
@@ -206,6 +292,16 @@ impl PartialEq for DataUnion {
         self.fields == other.fields
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for Decreases {}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for Decreases {
+    fn eq(&self, other: &Self) -> bool {
+        self.exprs == other.exprs
+    }
+}
 #[cfg(feature = "derive")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for DeriveInput {}
@@ -217,10 +313,20 @@ impl PartialEq for DeriveInput {
             && self.generics == other.generics && self.data == other.data
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for Ensures {}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for Ensures {
+    fn eq(&self, other: &Self) -> bool {
+        self.exprs == other.exprs
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Expr {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Expr {
     fn eq(&self, other: &Self) -> bool {
@@ -353,10 +459,10 @@ impl PartialEq for ExprAwait {
         self.attrs == other.attrs && self.base == other.base
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprBinary {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprBinary {
     fn eq(&self, other: &Self) -> bool {
@@ -395,20 +501,20 @@ impl PartialEq for ExprBreak {
         self.attrs == other.attrs && self.label == other.label && self.expr == other.expr
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprCall {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprCall {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs && self.func == other.func && self.args == other.args
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprCast {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprCast {
     fn eq(&self, other: &Self) -> bool {
@@ -438,10 +544,10 @@ impl PartialEq for ExprContinue {
         self.attrs == other.attrs && self.label == other.label
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprField {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprField {
     fn eq(&self, other: &Self) -> bool {
@@ -457,7 +563,8 @@ impl Eq for ExprForLoop {}
 impl PartialEq for ExprForLoop {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs && self.label == other.label && self.pat == other.pat
-            && self.expr == other.expr && self.body == other.body
+            && self.expr == other.expr && self.spec == other.spec
+            && self.body == other.body
     }
 }
 #[cfg(feature = "full")]
@@ -482,10 +589,10 @@ impl PartialEq for ExprIf {
             && self.else_branch == other.else_branch
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprIndex {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprIndex {
     fn eq(&self, other: &Self) -> bool {
@@ -502,10 +609,10 @@ impl PartialEq for ExprLet {
         self.attrs == other.attrs && self.pat == other.pat && self.expr == other.expr
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprLit {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprLit {
     fn eq(&self, other: &Self) -> bool {
@@ -519,7 +626,8 @@ impl Eq for ExprLoop {}
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprLoop {
     fn eq(&self, other: &Self) -> bool {
-        self.attrs == other.attrs && self.label == other.label && self.body == other.body
+        self.attrs == other.attrs && self.label == other.label && self.spec == other.spec
+            && self.body == other.body
     }
 }
 #[cfg(feature = "full")]
@@ -554,20 +662,20 @@ impl PartialEq for ExprMethodCall {
             && self.args == other.args
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprParen {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprParen {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs && self.expr == other.expr
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprPath {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprPath {
     fn eq(&self, other: &Self) -> bool {
@@ -668,10 +776,10 @@ impl PartialEq for ExprType {
         self.attrs == other.attrs && self.expr == other.expr && self.ty == other.ty
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprUnary {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ExprUnary {
     fn eq(&self, other: &Self) -> bool {
@@ -696,7 +804,7 @@ impl Eq for ExprWhile {}
 impl PartialEq for ExprWhile {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs && self.label == other.label && self.cond == other.cond
-            && self.body == other.body
+            && self.spec == other.spec && self.body == other.body
     }
 }
 #[cfg(feature = "full")]
@@ -804,6 +912,17 @@ impl PartialEq for FnArg {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for FnQualifiers {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for FnQualifiers {
+    fn eq(&self, other: &Self) -> bool {
+        self.constness == other.constness && self.asyncness == other.asyncness
+            && self.unsafety == other.unsafety && self.abi == other.abi
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ForeignItem {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -864,10 +983,10 @@ impl PartialEq for ForeignItemType {
         self.attrs == other.attrs && self.vis == other.vis && self.ident == other.ident
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for GenericArgument {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for GenericArgument {
     fn eq(&self, other: &Self) -> bool {
@@ -910,10 +1029,10 @@ impl PartialEq for GenericMethodArgument {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for GenericParam {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for GenericParam {
     fn eq(&self, other: &Self) -> bool {
@@ -927,10 +1046,10 @@ impl PartialEq for GenericParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Generics {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Generics {
     fn eq(&self, other: &Self) -> bool {
@@ -1004,6 +1123,16 @@ impl PartialEq for ImplItemType {
             && self.generics == other.generics && self.ty == other.ty
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for Invariant {}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for Invariant {
+    fn eq(&self, other: &Self) -> bool {
+        self.exprs == other.exprs
+    }
+}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Item {}
@@ -1227,10 +1356,10 @@ impl PartialEq for Label {
         self.name == other.name
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for LifetimeDef {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for LifetimeDef {
     fn eq(&self, other: &Self) -> bool {
@@ -1288,10 +1417,10 @@ impl PartialEq for Local {
         self.attrs == other.attrs && self.pat == other.pat && self.init == other.init
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Macro {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Macro {
     fn eq(&self, other: &Self) -> bool {
@@ -1299,10 +1428,10 @@ impl PartialEq for Macro {
             && TokenStreamHelper(&self.tokens) == TokenStreamHelper(&other.tokens)
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for MacroDelimiter {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for MacroDelimiter {
     fn eq(&self, other: &Self) -> bool {
@@ -1314,10 +1443,10 @@ impl PartialEq for MacroDelimiter {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Meta {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Meta {
     fn eq(&self, other: &Self) -> bool {
@@ -1329,20 +1458,20 @@ impl PartialEq for Meta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for MetaList {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for MetaList {
     fn eq(&self, other: &Self) -> bool {
         self.path == other.path && self.nested == other.nested
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for MetaNameValue {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for MetaNameValue {
     fn eq(&self, other: &Self) -> bool {
@@ -1359,10 +1488,10 @@ impl PartialEq for MethodTurbofish {
         self.args == other.args
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for NestedMeta {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for NestedMeta {
     fn eq(&self, other: &Self) -> bool {
@@ -1373,10 +1502,10 @@ impl PartialEq for NestedMeta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ParenthesizedGenericArguments {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ParenthesizedGenericArguments {
     fn eq(&self, other: &Self) -> bool {
@@ -1569,20 +1698,20 @@ impl PartialEq for PatWild {
         self.attrs == other.attrs
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Path {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Path {
     fn eq(&self, other: &Self) -> bool {
         self.leading_colon == other.leading_colon && self.segments == other.segments
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PathArguments {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for PathArguments {
     fn eq(&self, other: &Self) -> bool {
@@ -1600,40 +1729,40 @@ impl PartialEq for PathArguments {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PathSegment {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for PathSegment {
     fn eq(&self, other: &Self) -> bool {
         self.ident == other.ident && self.arguments == other.arguments
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PredicateEq {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for PredicateEq {
     fn eq(&self, other: &Self) -> bool {
         self.lhs_ty == other.lhs_ty && self.rhs_ty == other.rhs_ty
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PredicateLifetime {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for PredicateLifetime {
     fn eq(&self, other: &Self) -> bool {
         self.lifetime == other.lifetime && self.bounds == other.bounds
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PredicateType {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for PredicateType {
     fn eq(&self, other: &Self) -> bool {
@@ -1641,10 +1770,10 @@ impl PartialEq for PredicateType {
             && self.bounds == other.bounds
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for QSelf {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for QSelf {
     fn eq(&self, other: &Self) -> bool {
@@ -1677,10 +1806,20 @@ impl PartialEq for Receiver {
             && self.mutability == other.mutability
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for Requires {}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for Requires {
+    fn eq(&self, other: &Self) -> bool {
+        self.exprs == other.exprs
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ReturnType {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ReturnType {
     fn eq(&self, other: &Self) -> bool {
@@ -1702,7 +1841,34 @@ impl PartialEq for Signature {
             && self.unsafety == other.unsafety && self.abi == other.abi
             && self.ident == other.ident && self.generics == other.generics
             && self.inputs == other.inputs && self.variadic == other.variadic
-            && self.output == other.output
+            && self.output == other.output && self.spec == other.spec
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for SpecClauses {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for SpecClauses {
+    #[cfg_attr(not(any(feature = "verus")), allow(unused_variables, unused_mut))]
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "verus")]
+        if self.requires != other.requires {
+            return false;
+        }
+        #[cfg(feature = "verus")]
+        if self.ensures != other.ensures {
+            return false;
+        }
+        #[cfg(feature = "verus")]
+        if self.invariant != other.invariant {
+            return false;
+        }
+        #[cfg(feature = "verus")]
+        if self.decreases != other.decreases {
+            return false;
+        }
+        true
     }
 }
 #[cfg(feature = "full")]
@@ -1721,10 +1887,10 @@ impl PartialEq for Stmt {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TraitBound {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TraitBound {
     fn eq(&self, other: &Self) -> bool {
@@ -1732,10 +1898,10 @@ impl PartialEq for TraitBound {
             && self.lifetimes == other.lifetimes && self.path == other.path
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TraitBoundModifier {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TraitBoundModifier {
     fn eq(&self, other: &Self) -> bool {
@@ -1810,10 +1976,10 @@ impl PartialEq for TraitItemType {
             && self.bounds == other.bounds && self.default == other.default
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Type {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Type {
     fn eq(&self, other: &Self) -> bool {
@@ -1839,20 +2005,20 @@ impl PartialEq for Type {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeArray {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeArray {
     fn eq(&self, other: &Self) -> bool {
         self.elem == other.elem && self.len == other.len
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeBareFn {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeBareFn {
     fn eq(&self, other: &Self) -> bool {
@@ -1861,60 +2027,60 @@ impl PartialEq for TypeBareFn {
             && self.variadic == other.variadic && self.output == other.output
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeGroup {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeGroup {
     fn eq(&self, other: &Self) -> bool {
         self.elem == other.elem
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeImplTrait {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeImplTrait {
     fn eq(&self, other: &Self) -> bool {
         self.bounds == other.bounds
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeInfer {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeInfer {
     fn eq(&self, _other: &Self) -> bool {
         true
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeMacro {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeMacro {
     fn eq(&self, other: &Self) -> bool {
         self.mac == other.mac
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeNever {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeNever {
     fn eq(&self, _other: &Self) -> bool {
         true
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeParam {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeParam {
     fn eq(&self, other: &Self) -> bool {
@@ -1923,10 +2089,10 @@ impl PartialEq for TypeParam {
             && self.eq_token == other.eq_token && self.default == other.default
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeParamBound {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeParamBound {
     fn eq(&self, other: &Self) -> bool {
@@ -1941,30 +2107,30 @@ impl PartialEq for TypeParamBound {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeParen {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeParen {
     fn eq(&self, other: &Self) -> bool {
         self.elem == other.elem
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypePath {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypePath {
     fn eq(&self, other: &Self) -> bool {
         self.qself == other.qself && self.path == other.path
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypePtr {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypePtr {
     fn eq(&self, other: &Self) -> bool {
@@ -1972,10 +2138,10 @@ impl PartialEq for TypePtr {
             && self.elem == other.elem
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeReference {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeReference {
     fn eq(&self, other: &Self) -> bool {
@@ -1983,40 +2149,40 @@ impl PartialEq for TypeReference {
             && self.elem == other.elem
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeSlice {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeSlice {
     fn eq(&self, other: &Self) -> bool {
         self.elem == other.elem
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeTraitObject {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeTraitObject {
     fn eq(&self, other: &Self) -> bool {
         self.dyn_token == other.dyn_token && self.bounds == other.bounds
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for TypeTuple {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for TypeTuple {
     fn eq(&self, other: &Self) -> bool {
         self.elems == other.elems
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for UnOp {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for UnOp {
     fn eq(&self, other: &Self) -> bool {
@@ -2095,10 +2261,10 @@ impl PartialEq for UseTree {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Variadic {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for Variadic {
     fn eq(&self, other: &Self) -> bool {
@@ -2164,20 +2330,20 @@ impl PartialEq for Visibility {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for WhereClause {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for WhereClause {
     fn eq(&self, other: &Self) -> bool {
         self.predicates == other.predicates
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for WherePredicate {}
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for WherePredicate {
     fn eq(&self, other: &Self) -> bool {
@@ -3,9 +3,9 @@
 
 #![allow(unreachable_code, unused_variables)]
 #![allow(clippy::match_wildcard_for_single_variants)]
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::gen::helper::fold::*;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::token::{Brace, Bracket, Group, Paren};
 use crate::*;
 use proc_macro2::Span;
@@ -15,7 +15,7 @@ macro_rules! full {
         $e
     };
 }
-#[cfg(all(feature = "derive", not(feature = "full")))]
+#[cfg(all(any(feature = "derive", feature = "parse-only"), not(feature = "full")))]
 macro_rules! full {
     ($e:expr) => {
         unreachable!()
@@ -29,11 +29,15 @@ macro_rules! full {
 ///
 /// *This trait is available only if Syn is built with the `"fold"` feature.*
 pub trait Fold {
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_abi(&mut self, i: Abi) -> Abi {
         fold_abi(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_abi_name(&mut self, i: AbiName) -> AbiName {
+        fold_abi_name(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_angle_bracketed_generic_arguments(
         &mut self,
         i: AngleBracketedGenericArguments,
@@ -44,23 +48,23 @@ pub trait Fold {
     fn fold_arm(&mut self, i: Arm) -> Arm {
         fold_arm(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_attr_style(&mut self, i: AttrStyle) -> AttrStyle {
         fold_attr_style(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_attribute(&mut self, i: Attribute) -> Attribute {
         fold_attribute(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_bare_fn_arg(&mut self, i: BareFnArg) -> BareFnArg {
         fold_bare_fn_arg(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_bin_op(&mut self, i: BinOp) -> BinOp {
         fold_bin_op(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_binding(&mut self, i: Binding) -> Binding {
         fold_binding(self, i)
     }
@@ -68,15 +72,35 @@ pub trait Fold {
     fn fold_block(&mut self, i: Block) -> Block {
         fold_block(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_bound_lifetimes(&mut self, i: BoundLifetimes) -> BoundLifetimes {
         fold_bound_lifetimes(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_cfg_all(&mut self, i: CfgAll) -> CfgAll {
+        fold_cfg_all(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_cfg_any(&mut self, i: CfgAny) -> CfgAny {
+        fold_cfg_any(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_cfg_expr(&mut self, i: CfgExpr) -> CfgExpr {
+        fold_cfg_expr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_cfg_key_value(&mut self, i: CfgKeyValue) -> CfgKeyValue {
+        fold_cfg_key_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn fold_cfg_not(&mut self, i: CfgNot) -> CfgNot {
+        fold_cfg_not(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_const_param(&mut self, i: ConstParam) -> ConstParam {
         fold_const_param(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_constraint(&mut self, i: Constraint) -> Constraint {
         fold_constraint(self, i)
     }
@@ -96,11 +120,19 @@ pub trait Fold {
     fn fold_data_union(&mut self, i: DataUnion) -> DataUnion {
         fold_data_union(self, i)
     }
+    #[cfg(feature = "verus")]
+    fn fold_decreases(&mut self, i: Decreases) -> Decreases {
+        fold_decreases(self, i)
+    }
     #[cfg(feature = "derive")]
     fn fold_derive_input(&mut self, i: DeriveInput) -> DeriveInput {
         fold_derive_input(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn fold_ensures(&mut self, i: Ensures) -> Ensures {
+        fold_ensures(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr(&mut self, i: Expr) -> Expr {
         fold_expr(self, i)
     }
@@ -124,7 +156,7 @@ pub trait Fold {
     fn fold_expr_await(&mut self, i: ExprAwait) -> ExprAwait {
         fold_expr_await(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_binary(&mut self, i: ExprBinary) -> ExprBinary {
         fold_expr_binary(self, i)
     }
@@ -140,11 +172,11 @@ pub trait Fold {
     fn fold_expr_break(&mut self, i: ExprBreak) -> ExprBreak {
         fold_expr_break(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_call(&mut self, i: ExprCall) -> ExprCall {
         fold_expr_call(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_cast(&mut self, i: ExprCast) -> ExprCast {
         fold_expr_cast(self, i)
     }
@@ -156,7 +188,7 @@ pub trait Fold {
     fn fold_expr_continue(&mut self, i: ExprContinue) -> ExprContinue {
         fold_expr_continue(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_field(&mut self, i: ExprField) -> ExprField {
         fold_expr_field(self, i)
     }
@@ -172,7 +204,7 @@ pub trait Fold {
     fn fold_expr_if(&mut self, i: ExprIf) -> ExprIf {
         fold_expr_if(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_index(&mut self, i: ExprIndex) -> ExprIndex {
         fold_expr_index(self, i)
     }
@@ -180,7 +212,7 @@ pub trait Fold {
     fn fold_expr_let(&mut self, i: ExprLet) -> ExprLet {
         fold_expr_let(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_lit(&mut self, i: ExprLit) -> ExprLit {
         fold_expr_lit(self, i)
     }
@@ -200,11 +232,11 @@ pub trait Fold {
     fn fold_expr_method_call(&mut self, i: ExprMethodCall) -> ExprMethodCall {
         fold_expr_method_call(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_paren(&mut self, i: ExprParen) -> ExprParen {
         fold_expr_paren(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_path(&mut self, i: ExprPath) -> ExprPath {
         fold_expr_path(self, i)
     }
@@ -244,7 +276,7 @@ pub trait Fold {
     fn fold_expr_type(&mut self, i: ExprType) -> ExprType {
         fold_expr_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_expr_unary(&mut self, i: ExprUnary) -> ExprUnary {
         fold_expr_unary(self, i)
     }
@@ -293,6 +325,10 @@ pub trait Fold {
         fold_fn_arg(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_fn_qualifiers(&mut self, i: FnQualifiers) -> FnQualifiers {
+        fold_fn_qualifiers(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_foreign_item(&mut self, i: ForeignItem) -> ForeignItem {
         fold_foreign_item(self, i)
     }
@@ -312,7 +348,7 @@ pub trait Fold {
     fn fold_foreign_item_type(&mut self, i: ForeignItemType) -> ForeignItemType {
         fold_foreign_item_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_generic_argument(&mut self, i: GenericArgument) -> GenericArgument {
         fold_generic_argument(self, i)
     }
@@ -323,11 +359,11 @@ pub trait Fold {
     ) -> GenericMethodArgument {
         fold_generic_method_argument(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_generic_param(&mut self, i: GenericParam) -> GenericParam {
         fold_generic_param(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_generics(&mut self, i: Generics) -> Generics {
         fold_generics(self, i)
     }
@@ -354,10 +390,14 @@ pub trait Fold {
     fn fold_impl_item_type(&mut self, i: ImplItemType) -> ImplItemType {
         fold_impl_item_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_index(&mut self, i: Index) -> Index {
         fold_index(self, i)
     }
+    #[cfg(feature = "verus")]
+    fn fold_invariant(&mut self, i: Invariant) -> Invariant {
+        fold_invariant(self, i)
+    }
     #[cfg(feature = "full")]
     fn fold_item(&mut self, i: Item) -> Item {
         fold_item(self, i)
@@ -433,7 +473,7 @@ pub trait Fold {
     fn fold_lifetime(&mut self, i: Lifetime) -> Lifetime {
         fold_lifetime(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_lifetime_def(&mut self, i: LifetimeDef) -> LifetimeDef {
         fold_lifetime_def(self, i)
     }
@@ -465,27 +505,27 @@ pub trait Fold {
     fn fold_local(&mut self, i: Local) -> Local {
         fold_local(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_macro(&mut self, i: Macro) -> Macro {
         fold_macro(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_macro_delimiter(&mut self, i: MacroDelimiter) -> MacroDelimiter {
         fold_macro_delimiter(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_member(&mut self, i: Member) -> Member {
         fold_member(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_meta(&mut self, i: Meta) -> Meta {
         fold_meta(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_meta_list(&mut self, i: MetaList) -> MetaList {
         fold_meta_list(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_meta_name_value(&mut self, i: MetaNameValue) -> MetaNameValue {
         fold_meta_name_value(self, i)
     }
@@ -493,11 +533,11 @@ pub trait Fold {
     fn fold_method_turbofish(&mut self, i: MethodTurbofish) -> MethodTurbofish {
         fold_method_turbofish(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_nested_meta(&mut self, i: NestedMeta) -> NestedMeta {
         fold_nested_meta(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_parenthesized_generic_arguments(
         &mut self,
         i: ParenthesizedGenericArguments,
@@ -568,31 +608,31 @@ pub trait Fold {
     fn fold_pat_wild(&mut self, i: PatWild) -> PatWild {
         fold_pat_wild(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_path(&mut self, i: Path) -> Path {
         fold_path(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_path_arguments(&mut self, i: PathArguments) -> PathArguments {
         fold_path_arguments(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_path_segment(&mut self, i: PathSegment) -> PathSegment {
         fold_path_segment(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_predicate_eq(&mut self, i: PredicateEq) -> PredicateEq {
         fold_predicate_eq(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_predicate_lifetime(&mut self, i: PredicateLifetime) -> PredicateLifetime {
         fold_predicate_lifetime(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_predicate_type(&mut self, i: PredicateType) -> PredicateType {
         fold_predicate_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_qself(&mut self, i: QSelf) -> QSelf {
         fold_qself(self, i)
     }
@@ -604,7 +644,11 @@ pub trait Fold {
     fn fold_receiver(&mut self, i: Receiver) -> Receiver {
         fold_receiver(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn fold_requires(&mut self, i: Requires) -> Requires {
+        fold_requires(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_return_type(&mut self, i: ReturnType) -> ReturnType {
         fold_return_type(self, i)
     }
@@ -616,14 +660,18 @@ pub trait Fold {
         fold_span(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_spec_clauses(&mut self, i: SpecClauses) -> SpecClauses {
+        fold_spec_clauses(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_stmt(&mut self, i: Stmt) -> Stmt {
         fold_stmt(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_trait_bound(&mut self, i: TraitBound) -> TraitBound {
         fold_trait_bound(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_trait_bound_modifier(
         &mut self,
         i: TraitBoundModifier,
@@ -650,75 +698,75 @@ pub trait Fold {
     fn fold_trait_item_type(&mut self, i: TraitItemType) -> TraitItemType {
         fold_trait_item_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type(&mut self, i: Type) -> Type {
         fold_type(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_array(&mut self, i: TypeArray) -> TypeArray {
         fold_type_array(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_bare_fn(&mut self, i: TypeBareFn) -> TypeBareFn {
         fold_type_bare_fn(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_group(&mut self, i: TypeGroup) -> TypeGroup {
         fold_type_group(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_impl_trait(&mut self, i: TypeImplTrait) -> TypeImplTrait {
         fold_type_impl_trait(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_infer(&mut self, i: TypeInfer) -> TypeInfer {
         fold_type_infer(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_macro(&mut self, i: TypeMacro) -> TypeMacro {
         fold_type_macro(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_never(&mut self, i: TypeNever) -> TypeNever {
         fold_type_never(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_param(&mut self, i: TypeParam) -> TypeParam {
         fold_type_param(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_param_bound(&mut self, i: TypeParamBound) -> TypeParamBound {
         fold_type_param_bound(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_paren(&mut self, i: TypeParen) -> TypeParen {
         fold_type_paren(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_path(&mut self, i: TypePath) -> TypePath {
         fold_type_path(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_ptr(&mut self, i: TypePtr) -> TypePtr {
         fold_type_ptr(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_reference(&mut self, i: TypeReference) -> TypeReference {
         fold_type_reference(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_slice(&mut self, i: TypeSlice) -> TypeSlice {
         fold_type_slice(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_trait_object(&mut self, i: TypeTraitObject) -> TypeTraitObject {
         fold_type_trait_object(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_type_tuple(&mut self, i: TypeTuple) -> TypeTuple {
         fold_type_tuple(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_un_op(&mut self, i: UnOp) -> UnOp {
         fold_un_op(self, i)
     }
@@ -746,7 +794,7 @@ pub trait Fold {
     fn fold_use_tree(&mut self, i: UseTree) -> UseTree {
         fold_use_tree(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_variadic(&mut self, i: Variadic) -> Variadic {
         fold_variadic(self, i)
     }
@@ -770,16 +818,16 @@ pub trait Fold {
     fn fold_visibility(&mut self, i: Visibility) -> Visibility {
         fold_visibility(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_where_clause(&mut self, i: WhereClause) -> WhereClause {
         fold_where_clause(self, i)
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn fold_where_predicate(&mut self, i: WherePredicate) -> WherePredicate {
         fold_where_predicate(self, i)
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_abi<F>(f: &mut F, node: Abi) -> Abi
 where
     F: Fold + ?Sized,
@@ -789,7 +837,36 @@ where
         name: (node.name).map(|it| f.fold_lit_str(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_abi_name<F>(f: &mut F, node: AbiName) -> AbiName
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AbiName::Rust(_binding_0) => AbiName::Rust(f.fold_lit_str(_binding_0)),
+        AbiName::C(_binding_0) => AbiName::C(f.fold_lit_str(_binding_0)),
+        AbiName::CUnwind(_binding_0) => AbiName::CUnwind(f.fold_lit_str(_binding_0)),
+        AbiName::System(_binding_0) => AbiName::System(f.fold_lit_str(_binding_0)),
+        AbiName::SystemUnwind(_binding_0) => {
+            AbiName::SystemUnwind(f.fold_lit_str(_binding_0))
+        }
+        AbiName::Cdecl(_binding_0) => AbiName::Cdecl(f.fold_lit_str(_binding_0)),
+        AbiName::Stdcall(_binding_0) => AbiName::Stdcall(f.fold_lit_str(_binding_0)),
+        AbiName::Fastcall(_binding_0) => AbiName::Fastcall(f.fold_lit_str(_binding_0)),
+        AbiName::Vectorcall(_binding_0) => {
+            AbiName::Vectorcall(f.fold_lit_str(_binding_0))
+        }
+        AbiName::Thiscall(_binding_0) => AbiName::Thiscall(f.fold_lit_str(_binding_0)),
+        AbiName::Aapcs(_binding_0) => AbiName::Aapcs(f.fold_lit_str(_binding_0)),
+        AbiName::Win64(_binding_0) => AbiName::Win64(f.fold_lit_str(_binding_0)),
+        AbiName::Sysv64(_binding_0) => AbiName::Sysv64(f.fold_lit_str(_binding_0)),
+        AbiName::Efiapi(_binding_0) => AbiName::Efiapi(f.fold_lit_str(_binding_0)),
+        AbiName::Other(_binding_0) => AbiName::Other(f.fold_lit_str(_binding_0)),
+        #[cfg(syn_no_non_exhaustive)]
+        _ => unreachable!(),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_angle_bracketed_generic_arguments<F>(
     f: &mut F,
     node: AngleBracketedGenericArguments,
@@ -823,7 +900,7 @@ where
         comma: (node.comma).map(|it| Token![,](tokens_helper(f, &it.spans))),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_attr_style<F>(f: &mut F, node: AttrStyle) -> AttrStyle
 where
     F: Fold + ?Sized,
@@ -835,7 +912,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_attribute<F>(f: &mut F, node: Attribute) -> Attribute
 where
     F: Fold + ?Sized,
@@ -848,7 +925,7 @@ where
         tokens: node.tokens,
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_bare_fn_arg<F>(f: &mut F, node: BareFnArg) -> BareFnArg
 where
     F: Fold + ?Sized,
@@ -863,7 +940,7 @@ where
         ty: f.fold_type(node.ty),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_bin_op<F>(f: &mut F, node: BinOp) -> BinOp
 where
     F: Fold + ?Sized,
@@ -955,7 +1032,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_binding<F>(f: &mut F, node: Binding) -> Binding
 where
     F: Fold + ?Sized,
@@ -976,7 +1053,7 @@ where
         stmts: FoldHelper::lift(node.stmts, |it| f.fold_stmt(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_bound_lifetimes<F>(f: &mut F, node: BoundLifetimes) -> BoundLifetimes
 where
     F: Fold + ?Sized,
@@ -988,7 +1065,66 @@ where
         gt_token: Token![>](tokens_helper(f, &node.gt_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_cfg_all<F>(f: &mut F, node: CfgAll) -> CfgAll
+where
+    F: Fold + ?Sized,
+{
+    CfgAll {
+        ident: f.fold_ident(node.ident),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        predicates: FoldHelper::lift(node.predicates, |it| f.fold_cfg_expr(it)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_cfg_any<F>(f: &mut F, node: CfgAny) -> CfgAny
+where
+    F: Fold + ?Sized,
+{
+    CfgAny {
+        ident: f.fold_ident(node.ident),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        predicates: FoldHelper::lift(node.predicates, |it| f.fold_cfg_expr(it)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_cfg_expr<F>(f: &mut F, node: CfgExpr) -> CfgExpr
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        CfgExpr::Key(_binding_0) => CfgExpr::Key(f.fold_ident(_binding_0)),
+        CfgExpr::KeyValue(_binding_0) => {
+            CfgExpr::KeyValue(f.fold_cfg_key_value(_binding_0))
+        }
+        CfgExpr::All(_binding_0) => CfgExpr::All(f.fold_cfg_all(_binding_0)),
+        CfgExpr::Any(_binding_0) => CfgExpr::Any(f.fold_cfg_any(_binding_0)),
+        CfgExpr::Not(_binding_0) => CfgExpr::Not(f.fold_cfg_not(_binding_0)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_cfg_key_value<F>(f: &mut F, node: CfgKeyValue) -> CfgKeyValue
+where
+    F: Fold + ?Sized,
+{
+    CfgKeyValue {
+        key: f.fold_ident(node.key),
+        eq_token: Token![=](tokens_helper(f, &node.eq_token.spans)),
+        value: f.fold_lit_str(node.value),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn fold_cfg_not<F>(f: &mut F, node: CfgNot) -> CfgNot
+where
+    F: Fold + ?Sized,
+{
+    CfgNot {
+        ident: f.fold_ident(node.ident),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        predicate: Box::new(f.fold_cfg_expr(*node.predicate)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_const_param<F>(f: &mut F, node: ConstParam) -> ConstParam
 where
     F: Fold + ?Sized,
@@ -1003,7 +1139,7 @@ where
         default: (node.default).map(|it| f.fold_expr(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_constraint<F>(f: &mut F, node: Constraint) -> Constraint
 where
     F: Fold + ?Sized,
@@ -1057,6 +1193,16 @@ where
         fields: f.fold_fields_named(node.fields),
     }
 }
+#[cfg(feature = "verus")]
+pub fn fold_decreases<F>(f: &mut F, node: Decreases) -> Decreases
+where
+    F: Fold + ?Sized,
+{
+    Decreases {
+        decreases_token: Token![decreases](tokens_helper(f, &node.decreases_token.span)),
+        exprs: FoldHelper::lift(node.exprs, |it| f.fold_expr(it)),
+    }
+}
 #[cfg(feature = "derive")]
 pub fn fold_derive_input<F>(f: &mut F, node: DeriveInput) -> DeriveInput
 where
@@ -1070,7 +1216,17 @@ where
         data: f.fold_data(node.data),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn fold_ensures<F>(f: &mut F, node: Ensures) -> Ensures
+where
+    F: Fold + ?Sized,
+{
+    Ensures {
+        ensures_token: Token![ensures](tokens_helper(f, &node.ensures_token.span)),
+        exprs: FoldHelper::lift(node.exprs, |it| f.fold_expr(it)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr<F>(f: &mut F, node: Expr) -> Expr
 where
     F: Fold + ?Sized,
@@ -1193,7 +1349,7 @@ where
         await_token: crate::token::Await(tokens_helper(f, &node.await_token.span)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_binary<F>(f: &mut F, node: ExprBinary) -> ExprBinary
 where
     F: Fold + ?Sized,
@@ -1239,7 +1395,7 @@ where
         expr: (node.expr).map(|it| Box::new(f.fold_expr(*it))),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_call<F>(f: &mut F, node: ExprCall) -> ExprCall
 where
     F: Fold + ?Sized,
@@ -1251,7 +1407,7 @@ where
         args: FoldHelper::lift(node.args, |it| f.fold_expr(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_cast<F>(f: &mut F, node: ExprCast) -> ExprCast
 where
     F: Fold + ?Sized,
@@ -1292,7 +1448,7 @@ where
         label: (node.label).map(|it| f.fold_lifetime(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_field<F>(f: &mut F, node: ExprField) -> ExprField
 where
     F: Fold + ?Sized,
@@ -1316,6 +1472,7 @@ where
         pat: f.fold_pat(node.pat),
         in_token: Token![in](tokens_helper(f, &node.in_token.span)),
         expr: Box::new(f.fold_expr(*node.expr)),
+        spec: (node.spec).map(|it| Box::new(f.fold_spec_clauses(*it))),
         body: f.fold_block(node.body),
     }
 }
@@ -1347,7 +1504,7 @@ where
             )),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_index<F>(f: &mut F, node: ExprIndex) -> ExprIndex
 where
     F: Fold + ?Sized,
@@ -1372,7 +1529,7 @@ where
         expr: Box::new(f.fold_expr(*node.expr)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_lit<F>(f: &mut F, node: ExprLit) -> ExprLit
 where
     F: Fold + ?Sized,
@@ -1391,6 +1548,7 @@ where
         attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
         label: (node.label).map(|it| f.fold_label(it)),
         loop_token: Token![loop](tokens_helper(f, &node.loop_token.span)),
+        spec: (node.spec).map(|it| Box::new(f.fold_spec_clauses(*it))),
         body: f.fold_block(node.body),
     }
 }
@@ -1432,7 +1590,7 @@ where
         args: FoldHelper::lift(node.args, |it| f.fold_expr(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_paren<F>(f: &mut F, node: ExprParen) -> ExprParen
 where
     F: Fold + ?Sized,
@@ -1443,7 +1601,7 @@ where
         expr: Box::new(f.fold_expr(*node.expr)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_path<F>(f: &mut F, node: ExprPath) -> ExprPath
 where
     F: Fold + ?Sized,
@@ -1562,7 +1720,7 @@ where
         ty: Box::new(f.fold_type(*node.ty)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_expr_unary<F>(f: &mut F, node: ExprUnary) -> ExprUnary
 where
     F: Fold + ?Sized,
@@ -1594,6 +1752,7 @@ where
         label: (node.label).map(|it| f.fold_label(it)),
         while_token: Token![while](tokens_helper(f, &node.while_token.span)),
         cond: Box::new(f.fold_expr(*node.cond)),
+        spec: (node.spec).map(|it| Box::new(f.fold_spec_clauses(*it))),
         body: f.fold_block(node.body),
     }
 }
@@ -1698,6 +1857,18 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_fn_qualifiers<F>(f: &mut F, node: FnQualifiers) -> FnQualifiers
+where
+    F: Fold + ?Sized,
+{
+    FnQualifiers {
+        constness: (node.constness).map(|it| Token![const](tokens_helper(f, &it.span))),
+        asyncness: (node.asyncness).map(|it| Token![async](tokens_helper(f, &it.span))),
+        unsafety: (node.unsafety).map(|it| Token![unsafe](tokens_helper(f, &it.span))),
+        abi: (node.abi).map(|it| f.fold_abi(it)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_foreign_item<F>(f: &mut F, node: ForeignItem) -> ForeignItem
 where
     F: Fold + ?Sized,
@@ -1775,7 +1946,7 @@ where
         semi_token: Token![;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_generic_argument<F>(f: &mut F, node: GenericArgument) -> GenericArgument
 where
     F: Fold + ?Sized,
@@ -1815,7 +1986,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_generic_param<F>(f: &mut F, node: GenericParam) -> GenericParam
 where
     F: Fold + ?Sized,
@@ -1832,7 +2003,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_generics<F>(f: &mut F, node: Generics) -> Generics
 where
     F: Fold + ?Sized,
@@ -1936,7 +2107,7 @@ where
         semi_token: Token![;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_index<F>(f: &mut F, node: Index) -> Index
 where
     F: Fold + ?Sized,
@@ -1946,6 +2117,16 @@ where
         span: f.fold_span(node.span),
     }
 }
+#[cfg(feature = "verus")]
+pub fn fold_invariant<F>(f: &mut F, node: Invariant) -> Invariant
+where
+    F: Fold + ?Sized,
+{
+    Invariant {
+        invariant_token: Token![invariant](tokens_helper(f, &node.invariant_token.span)),
+        exprs: FoldHelper::lift(node.exprs, |it| f.fold_expr(it)),
+    }
+}
 #[cfg(feature = "full")]
 pub fn fold_item<F>(f: &mut F, node: Item) -> Item
 where
@@ -2255,7 +2436,7 @@ where
         ident: f.fold_ident(node.ident),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_lifetime_def<F>(f: &mut F, node: LifetimeDef) -> LifetimeDef
 where
     F: Fold + ?Sized,
@@ -2362,7 +2543,7 @@ where
         semi_token: Token![;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_macro<F>(f: &mut F, node: Macro) -> Macro
 where
     F: Fold + ?Sized,
@@ -2374,7 +2555,7 @@ where
         tokens: node.tokens,
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_macro_delimiter<F>(f: &mut F, node: MacroDelimiter) -> MacroDelimiter
 where
     F: Fold + ?Sized,
@@ -2391,7 +2572,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_member<F>(f: &mut F, node: Member) -> Member
 where
     F: Fold + ?Sized,
@@ -2401,7 +2582,7 @@ where
         Member::Unnamed(_binding_0) => Member::Unnamed(f.fold_index(_binding_0)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_meta<F>(f: &mut F, node: Meta) -> Meta
 where
     F: Fold + ?Sized,
@@ -2414,7 +2595,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_meta_list<F>(f: &mut F, node: MetaList) -> MetaList
 where
     F: Fold + ?Sized,
@@ -2425,7 +2606,7 @@ where
         nested: FoldHelper::lift(node.nested, |it| f.fold_nested_meta(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_meta_name_value<F>(f: &mut F, node: MetaNameValue) -> MetaNameValue
 where
     F: Fold + ?Sized,
@@ -2448,7 +2629,7 @@ where
         gt_token: Token![>](tokens_helper(f, &node.gt_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_nested_meta<F>(f: &mut F, node: NestedMeta) -> NestedMeta
 where
     F: Fold + ?Sized,
@@ -2458,7 +2639,7 @@ where
         NestedMeta::Lit(_binding_0) => NestedMeta::Lit(f.fold_lit(_binding_0)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_parenthesized_generic_arguments<F>(
     f: &mut F,
     node: ParenthesizedGenericArguments,
@@ -2673,7 +2854,7 @@ where
         underscore_token: Token![_](tokens_helper(f, &node.underscore_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_path<F>(f: &mut F, node: Path) -> Path
 where
     F: Fold + ?Sized,
@@ -2684,7 +2865,7 @@ where
         segments: FoldHelper::lift(node.segments, |it| f.fold_path_segment(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_path_arguments<F>(f: &mut F, node: PathArguments) -> PathArguments
 where
     F: Fold + ?Sized,
@@ -2703,7 +2884,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_path_segment<F>(f: &mut F, node: PathSegment) -> PathSegment
 where
     F: Fold + ?Sized,
@@ -2713,7 +2894,7 @@ where
         arguments: f.fold_path_arguments(node.arguments),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_predicate_eq<F>(f: &mut F, node: PredicateEq) -> PredicateEq
 where
     F: Fold + ?Sized,
@@ -2724,7 +2905,7 @@ where
         rhs_ty: f.fold_type(node.rhs_ty),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_predicate_lifetime<F>(
     f: &mut F,
     node: PredicateLifetime,
@@ -2738,7 +2919,7 @@ where
         bounds: FoldHelper::lift(node.bounds, |it| f.fold_lifetime(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_predicate_type<F>(f: &mut F, node: PredicateType) -> PredicateType
 where
     F: Fold + ?Sized,
@@ -2750,7 +2931,7 @@ where
         bounds: FoldHelper::lift(node.bounds, |it| f.fold_type_param_bound(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_qself<F>(f: &mut F, node: QSelf) -> QSelf
 where
     F: Fold + ?Sized,
@@ -2793,7 +2974,17 @@ where
         self_token: Token![self](tokens_helper(f, &node.self_token.span)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn fold_requires<F>(f: &mut F, node: Requires) -> Requires
+where
+    F: Fold + ?Sized,
+{
+    Requires {
+        requires_token: Token![requires](tokens_helper(f, &node.requires_token.span)),
+        exprs: FoldHelper::lift(node.exprs, |it| f.fold_expr(it)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_return_type<F>(f: &mut F, node: ReturnType) -> ReturnType
 where
     F: Fold + ?Sized,
@@ -2825,6 +3016,7 @@ where
         inputs: FoldHelper::lift(node.inputs, |it| f.fold_fn_arg(it)),
         variadic: (node.variadic).map(|it| f.fold_variadic(it)),
         output: f.fold_return_type(node.output),
+        spec: (node.spec).map(|it| Box::new(f.fold_spec_clauses(*it))),
     }
 }
 pub fn fold_span<F>(f: &mut F, node: Span) -> Span
@@ -2834,6 +3026,22 @@ where
     node
 }
 #[cfg(feature = "full")]
+pub fn fold_spec_clauses<F>(f: &mut F, node: SpecClauses) -> SpecClauses
+where
+    F: Fold + ?Sized,
+{
+    SpecClauses {
+        #[cfg(feature = "verus")]
+        requires: (node.requires).map(|it| f.fold_requires(it)),
+        #[cfg(feature = "verus")]
+        ensures: (node.ensures).map(|it| f.fold_ensures(it)),
+        #[cfg(feature = "verus")]
+        invariant: (node.invariant).map(|it| f.fold_invariant(it)),
+        #[cfg(feature = "verus")]
+        decreases: (node.decreases).map(|it| f.fold_decreases(it)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_stmt<F>(f: &mut F, node: Stmt) -> Stmt
 where
     F: Fold + ?Sized,
@@ -2850,7 +3058,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_trait_bound<F>(f: &mut F, node: TraitBound) -> TraitBound
 where
     F: Fold + ?Sized,
@@ -2862,7 +3070,7 @@ where
         path: f.fold_path(node.path),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_trait_bound_modifier<F>(
     f: &mut F,
     node: TraitBoundModifier,
@@ -2956,7 +3164,7 @@ where
         semi_token: Token![;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type<F>(f: &mut F, node: Type) -> Type
 where
     F: Fold + ?Sized,
@@ -2985,7 +3193,7 @@ where
         _ => unreachable!(),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_array<F>(f: &mut F, node: TypeArray) -> TypeArray
 where
     F: Fold + ?Sized,
@@ -2997,7 +3205,7 @@ where
         len: f.fold_expr(node.len),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_bare_fn<F>(f: &mut F, node: TypeBareFn) -> TypeBareFn
 where
     F: Fold + ?Sized,
@@ -3013,7 +3221,7 @@ where
         output: f.fold_return_type(node.output),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_group<F>(f: &mut F, node: TypeGroup) -> TypeGroup
 where
     F: Fold + ?Sized,
@@ -3023,7 +3231,7 @@ where
         elem: Box::new(f.fold_type(*node.elem)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_impl_trait<F>(f: &mut F, node: TypeImplTrait) -> TypeImplTrait
 where
     F: Fold + ?Sized,
@@ -3033,7 +3241,7 @@ where
         bounds: FoldHelper::lift(node.bounds, |it| f.fold_type_param_bound(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_infer<F>(f: &mut F, node: TypeInfer) -> TypeInfer
 where
     F: Fold + ?Sized,
@@ -3042,7 +3250,7 @@ where
         underscore_token: Token![_](tokens_helper(f, &node.underscore_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_macro<F>(f: &mut F, node: TypeMacro) -> TypeMacro
 where
     F: Fold + ?Sized,
@@ -3051,7 +3259,7 @@ where
         mac: f.fold_macro(node.mac),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_never<F>(f: &mut F, node: TypeNever) -> TypeNever
 where
     F: Fold + ?Sized,
@@ -3060,7 +3268,7 @@ where
         bang_token: Token![!](tokens_helper(f, &node.bang_token.spans)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_param<F>(f: &mut F, node: TypeParam) -> TypeParam
 where
     F: Fold + ?Sized,
@@ -3074,7 +3282,7 @@ where
         default: (node.default).map(|it| f.fold_type(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_param_bound<F>(f: &mut F, node: TypeParamBound) -> TypeParamBound
 where
     F: Fold + ?Sized,
@@ -3088,7 +3296,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_paren<F>(f: &mut F, node: TypeParen) -> TypeParen
 where
     F: Fold + ?Sized,
@@ -3098,7 +3306,7 @@ where
         elem: Box::new(f.fold_type(*node.elem)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_path<F>(f: &mut F, node: TypePath) -> TypePath
 where
     F: Fold + ?Sized,
@@ -3108,7 +3316,7 @@ where
         path: f.fold_path(node.path),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_ptr<F>(f: &mut F, node: TypePtr) -> TypePtr
 where
     F: Fold + ?Sized,
@@ -3121,7 +3329,7 @@ where
         elem: Box::new(f.fold_type(*node.elem)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_reference<F>(f: &mut F, node: TypeReference) -> TypeReference
 where
     F: Fold + ?Sized,
@@ -3133,7 +3341,7 @@ where
         elem: Box::new(f.fold_type(*node.elem)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_slice<F>(f: &mut F, node: TypeSlice) -> TypeSlice
 where
     F: Fold + ?Sized,
@@ -3143,7 +3351,7 @@ where
         elem: Box::new(f.fold_type(*node.elem)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_trait_object<F>(f: &mut F, node: TypeTraitObject) -> TypeTraitObject
 where
     F: Fold + ?Sized,
@@ -3153,7 +3361,7 @@ where
         bounds: FoldHelper::lift(node.bounds, |it| f.fold_type_param_bound(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_type_tuple<F>(f: &mut F, node: TypeTuple) -> TypeTuple
 where
     F: Fold + ?Sized,
@@ -3163,7 +3371,7 @@ where
         elems: FoldHelper::lift(node.elems, |it| f.fold_type(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_un_op<F>(f: &mut F, node: UnOp) -> UnOp
 where
     F: Fold + ?Sized,
@@ -3243,7 +3451,7 @@ where
         UseTree::Group(_binding_0) => UseTree::Group(f.fold_use_group(_binding_0)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_variadic<F>(f: &mut F, node: Variadic) -> Variadic
 where
     F: Fold + ?Sized,
@@ -3312,7 +3520,7 @@ where
         Visibility::Inherited => Visibility::Inherited,
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_where_clause<F>(f: &mut F, node: WhereClause) -> WhereClause
 where
     F: Fold + ?Sized,
@@ -3322,7 +3530,7 @@ where
         predicates: FoldHelper::lift(node.predicates, |it| f.fold_where_predicate(it)),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn fold_where_predicate<F>(f: &mut F, node: WherePredicate) -> WherePredicate
 where
     F: Fold + ?Sized,
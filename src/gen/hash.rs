@@ -1,11 +1,11 @@
 // This file is @generated by syn-internal-codegen.
 // It is not intended for manual editing.
 
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 use crate::tt::TokenStreamHelper;
 use crate::*;
 use std::hash::{Hash, Hasher};
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Abi {
     fn hash<H>(&self, state: &mut H)
@@ -15,7 +15,80 @@ impl Hash for Abi {
         self.name.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AbiName {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AbiName::Rust(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            AbiName::C(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+            AbiName::CUnwind(v0) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+            }
+            AbiName::System(v0) => {
+                state.write_u8(3u8);
+                v0.hash(state);
+            }
+            AbiName::SystemUnwind(v0) => {
+                state.write_u8(4u8);
+                v0.hash(state);
+            }
+            AbiName::Cdecl(v0) => {
+                state.write_u8(5u8);
+                v0.hash(state);
+            }
+            AbiName::Stdcall(v0) => {
+                state.write_u8(6u8);
+                v0.hash(state);
+            }
+            AbiName::Fastcall(v0) => {
+                state.write_u8(7u8);
+                v0.hash(state);
+            }
+            AbiName::Vectorcall(v0) => {
+                state.write_u8(8u8);
+                v0.hash(state);
+            }
+            AbiName::Thiscall(v0) => {
+                state.write_u8(9u8);
+                v0.hash(state);
+            }
+            AbiName::Aapcs(v0) => {
+                state.write_u8(10u8);
+                v0.hash(state);
+            }
+            AbiName::Win64(v0) => {
+                state.write_u8(11u8);
+                v0.hash(state);
+            }
+            AbiName::Sysv64(v0) => {
+                state.write_u8(12u8);
+                v0.hash(state);
+            }
+            AbiName::Efiapi(v0) => {
+                state.write_u8(13u8);
+                v0.hash(state);
+            }
+            AbiName::Other(v0) => {
+                state.write_u8(14u8);
+                v0.hash(state);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for AngleBracketedGenericArguments {
     fn hash<H>(&self, state: &mut H)
@@ -40,7 +113,7 @@ impl Hash for Arm {
         self.comma.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for AttrStyle {
     fn hash<H>(&self, state: &mut H)
@@ -57,7 +130,7 @@ impl Hash for AttrStyle {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Attribute {
     fn hash<H>(&self, state: &mut H)
@@ -69,7 +142,7 @@ impl Hash for Attribute {
         TokenStreamHelper(&self.tokens).hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for BareFnArg {
     fn hash<H>(&self, state: &mut H)
@@ -81,7 +154,7 @@ impl Hash for BareFnArg {
         self.ty.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for BinOp {
     fn hash<H>(&self, state: &mut H)
@@ -176,7 +249,7 @@ impl Hash for BinOp {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Binding {
     fn hash<H>(&self, state: &mut H)
@@ -197,7 +270,7 @@ impl Hash for Block {
         self.stmts.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for BoundLifetimes {
     fn hash<H>(&self, state: &mut H)
@@ -207,7 +280,82 @@ impl Hash for BoundLifetimes {
         self.lifetimes.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for CfgAll {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.ident.hash(state);
+        self.predicates.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for CfgAny {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.ident.hash(state);
+        self.predicates.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for CfgExpr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            CfgExpr::Key(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            CfgExpr::KeyValue(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+            CfgExpr::All(v0) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+            }
+            CfgExpr::Any(v0) => {
+                state.write_u8(3u8);
+                v0.hash(state);
+            }
+            CfgExpr::Not(v0) => {
+                state.write_u8(4u8);
+                v0.hash(state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for CfgKeyValue {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.key.hash(state);
+        self.value.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for CfgNot {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.ident.hash(state);
+        self.predicate.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ConstParam {
     fn hash<H>(&self, state: &mut H)
@@ -221,7 +369,7 @@ impl Hash for ConstParam {
         self.default.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Constraint {
     fn hash<H>(&self, state: &mut H)
@@ -286,6 +434,16 @@ impl Hash for DataUnion {
         self.fields.hash(state);
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for Decreases {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.exprs.hash(state);
+    }
+}
 #[cfg(feature = "derive")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for DeriveInput {
@@ -300,7 +458,17 @@ impl Hash for DeriveInput {
         self.data.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for Ensures {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.exprs.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Expr {
     fn hash<H>(&self, state: &mut H)
@@ -562,7 +730,7 @@ impl Hash for ExprAwait {
         self.base.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprBinary {
     fn hash<H>(&self, state: &mut H)
@@ -610,7 +778,7 @@ impl Hash for ExprBreak {
         self.expr.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprCall {
     fn hash<H>(&self, state: &mut H)
@@ -622,7 +790,7 @@ impl Hash for ExprCall {
         self.args.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprCast {
     fn hash<H>(&self, state: &mut H)
@@ -661,7 +829,7 @@ impl Hash for ExprContinue {
         self.label.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprField {
     fn hash<H>(&self, state: &mut H)
@@ -684,6 +852,7 @@ impl Hash for ExprForLoop {
         self.label.hash(state);
         self.pat.hash(state);
         self.expr.hash(state);
+        self.spec.hash(state);
         self.body.hash(state);
     }
 }
@@ -711,7 +880,7 @@ impl Hash for ExprIf {
         self.else_branch.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprIndex {
     fn hash<H>(&self, state: &mut H)
@@ -735,7 +904,7 @@ impl Hash for ExprLet {
         self.expr.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprLit {
     fn hash<H>(&self, state: &mut H)
@@ -755,6 +924,7 @@ impl Hash for ExprLoop {
     {
         self.attrs.hash(state);
         self.label.hash(state);
+        self.spec.hash(state);
         self.body.hash(state);
     }
 }
@@ -795,7 +965,7 @@ impl Hash for ExprMethodCall {
         self.args.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprParen {
     fn hash<H>(&self, state: &mut H)
@@ -806,7 +976,7 @@ impl Hash for ExprParen {
         self.expr.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprPath {
     fn hash<H>(&self, state: &mut H)
@@ -925,7 +1095,7 @@ impl Hash for ExprType {
         self.ty.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprUnary {
     fn hash<H>(&self, state: &mut H)
@@ -958,6 +1128,7 @@ impl Hash for ExprWhile {
         self.attrs.hash(state);
         self.label.hash(state);
         self.cond.hash(state);
+        self.spec.hash(state);
         self.body.hash(state);
     }
 }
@@ -1087,6 +1258,19 @@ impl Hash for FnArg {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for FnQualifiers {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.constness.hash(state);
+        self.asyncness.hash(state);
+        self.unsafety.hash(state);
+        self.abi.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ForeignItem {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1168,7 +1352,7 @@ impl Hash for ForeignItemType {
         self.ident.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for GenericArgument {
     fn hash<H>(&self, state: &mut H)
@@ -1218,7 +1402,7 @@ impl Hash for GenericMethodArgument {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for GenericParam {
     fn hash<H>(&self, state: &mut H)
@@ -1241,7 +1425,7 @@ impl Hash for GenericParam {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Generics {
     fn hash<H>(&self, state: &mut H)
@@ -1343,6 +1527,16 @@ impl Hash for ImplItemType {
         self.ty.hash(state);
     }
 }
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for Invariant {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.exprs.hash(state);
+    }
+}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Item {
@@ -1659,7 +1853,7 @@ impl Hash for Label {
         self.name.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for LifetimeDef {
     fn hash<H>(&self, state: &mut H)
@@ -1735,7 +1929,7 @@ impl Hash for Local {
         self.init.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Macro {
     fn hash<H>(&self, state: &mut H)
@@ -1747,7 +1941,7 @@ impl Hash for Macro {
         TokenStreamHelper(&self.tokens).hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for MacroDelimiter {
     fn hash<H>(&self, state: &mut H)
@@ -1767,7 +1961,7 @@ impl Hash for MacroDelimiter {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Meta {
     fn hash<H>(&self, state: &mut H)
@@ -1790,7 +1984,7 @@ impl Hash for Meta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for MetaList {
     fn hash<H>(&self, state: &mut H)
@@ -1801,7 +1995,7 @@ impl Hash for MetaList {
         self.nested.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for MetaNameValue {
     fn hash<H>(&self, state: &mut H)
@@ -1822,7 +2016,7 @@ impl Hash for MethodTurbofish {
         self.args.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for NestedMeta {
     fn hash<H>(&self, state: &mut H)
@@ -1841,7 +2035,7 @@ impl Hash for NestedMeta {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ParenthesizedGenericArguments {
     fn hash<H>(&self, state: &mut H)
@@ -2104,7 +2298,7 @@ impl Hash for PatWild {
         self.attrs.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Path {
     fn hash<H>(&self, state: &mut H)
@@ -2115,7 +2309,7 @@ impl Hash for Path {
         self.segments.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PathArguments {
     fn hash<H>(&self, state: &mut H)
@@ -2137,7 +2331,7 @@ impl Hash for PathArguments {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PathSegment {
     fn hash<H>(&self, state: &mut H)
@@ -2148,7 +2342,7 @@ impl Hash for PathSegment {
         self.arguments.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PredicateEq {
     fn hash<H>(&self, state: &mut H)
@@ -2159,7 +2353,7 @@ impl Hash for PredicateEq {
         self.rhs_ty.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PredicateLifetime {
     fn hash<H>(&self, state: &mut H)
@@ -2170,7 +2364,7 @@ impl Hash for PredicateLifetime {
         self.bounds.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PredicateType {
     fn hash<H>(&self, state: &mut H)
@@ -2182,7 +2376,7 @@ impl Hash for PredicateType {
         self.bounds.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for QSelf {
     fn hash<H>(&self, state: &mut H)
@@ -2223,7 +2417,17 @@ impl Hash for Receiver {
         self.mutability.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for Requires {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.exprs.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ReturnType {
     fn hash<H>(&self, state: &mut H)
@@ -2257,6 +2461,21 @@ impl Hash for Signature {
         self.inputs.hash(state);
         self.variadic.hash(state);
         self.output.hash(state);
+        self.spec.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for SpecClauses {
+    #[cfg_attr(not(any(feature = "verus")), allow(unused_variables, unused_mut))]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        #[cfg(feature = "verus")] self.requires.hash(state);
+        #[cfg(feature = "verus")] self.ensures.hash(state);
+        #[cfg(feature = "verus")] self.invariant.hash(state);
+        #[cfg(feature = "verus")] self.decreases.hash(state);
     }
 }
 #[cfg(feature = "full")]
@@ -2286,7 +2505,7 @@ impl Hash for Stmt {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TraitBound {
     fn hash<H>(&self, state: &mut H)
@@ -2299,7 +2518,7 @@ impl Hash for TraitBound {
         self.path.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TraitBoundModifier {
     fn hash<H>(&self, state: &mut H)
@@ -2402,7 +2621,7 @@ impl Hash for TraitItemType {
         self.default.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Type {
     fn hash<H>(&self, state: &mut H)
@@ -2475,7 +2694,7 @@ impl Hash for Type {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeArray {
     fn hash<H>(&self, state: &mut H)
@@ -2486,7 +2705,7 @@ impl Hash for TypeArray {
         self.len.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeBareFn {
     fn hash<H>(&self, state: &mut H)
@@ -2501,7 +2720,7 @@ impl Hash for TypeBareFn {
         self.output.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeGroup {
     fn hash<H>(&self, state: &mut H)
@@ -2511,7 +2730,7 @@ impl Hash for TypeGroup {
         self.elem.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeImplTrait {
     fn hash<H>(&self, state: &mut H)
@@ -2521,7 +2740,7 @@ impl Hash for TypeImplTrait {
         self.bounds.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeInfer {
     fn hash<H>(&self, _state: &mut H)
@@ -2529,7 +2748,7 @@ impl Hash for TypeInfer {
         H: Hasher,
     {}
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeMacro {
     fn hash<H>(&self, state: &mut H)
@@ -2539,7 +2758,7 @@ impl Hash for TypeMacro {
         self.mac.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeNever {
     fn hash<H>(&self, _state: &mut H)
@@ -2547,7 +2766,7 @@ impl Hash for TypeNever {
         H: Hasher,
     {}
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeParam {
     fn hash<H>(&self, state: &mut H)
@@ -2562,7 +2781,7 @@ impl Hash for TypeParam {
         self.default.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeParamBound {
     fn hash<H>(&self, state: &mut H)
@@ -2581,7 +2800,7 @@ impl Hash for TypeParamBound {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeParen {
     fn hash<H>(&self, state: &mut H)
@@ -2591,7 +2810,7 @@ impl Hash for TypeParen {
         self.elem.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypePath {
     fn hash<H>(&self, state: &mut H)
@@ -2602,7 +2821,7 @@ impl Hash for TypePath {
         self.path.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypePtr {
     fn hash<H>(&self, state: &mut H)
@@ -2614,7 +2833,7 @@ impl Hash for TypePtr {
         self.elem.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeReference {
     fn hash<H>(&self, state: &mut H)
@@ -2626,7 +2845,7 @@ impl Hash for TypeReference {
         self.elem.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeSlice {
     fn hash<H>(&self, state: &mut H)
@@ -2636,7 +2855,7 @@ impl Hash for TypeSlice {
         self.elem.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeTraitObject {
     fn hash<H>(&self, state: &mut H)
@@ -2647,7 +2866,7 @@ impl Hash for TypeTraitObject {
         self.bounds.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for TypeTuple {
     fn hash<H>(&self, state: &mut H)
@@ -2657,7 +2876,7 @@ impl Hash for TypeTuple {
         self.elems.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for UnOp {
     fn hash<H>(&self, state: &mut H)
@@ -2758,7 +2977,7 @@ impl Hash for UseTree {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Variadic {
     fn hash<H>(&self, state: &mut H)
@@ -2834,7 +3053,7 @@ impl Hash for Visibility {
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for WhereClause {
     fn hash<H>(&self, state: &mut H)
@@ -2844,7 +3063,7 @@ impl Hash for WhereClause {
         self.predicates.hash(state);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for WherePredicate {
     fn hash<H>(&self, state: &mut H)
@@ -0,0 +1,2204 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(clippy::expl_impl_clone_on_copy)]
+use crate::*;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::mem;
+///Fieldless counterpart of [`AbiName`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum AbiNameKind {
+    Rust,
+    C,
+    CUnwind,
+    System,
+    SystemUnwind,
+    Cdecl,
+    Stdcall,
+    Fastcall,
+    Vectorcall,
+    Thiscall,
+    Aapcs,
+    Win64,
+    Sysv64,
+    Efiapi,
+    Other,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl AbiName {
+    ///Returns the variant of this `AbiName` as a fieldless [`AbiNameKind`].
+    pub fn kind(&self) -> AbiNameKind {
+        match self {
+            AbiName::Rust(..) => AbiNameKind::Rust,
+            AbiName::C(..) => AbiNameKind::C,
+            AbiName::CUnwind(..) => AbiNameKind::CUnwind,
+            AbiName::System(..) => AbiNameKind::System,
+            AbiName::SystemUnwind(..) => AbiNameKind::SystemUnwind,
+            AbiName::Cdecl(..) => AbiNameKind::Cdecl,
+            AbiName::Stdcall(..) => AbiNameKind::Stdcall,
+            AbiName::Fastcall(..) => AbiNameKind::Fastcall,
+            AbiName::Vectorcall(..) => AbiNameKind::Vectorcall,
+            AbiName::Thiscall(..) => AbiNameKind::Thiscall,
+            AbiName::Aapcs(..) => AbiNameKind::Aapcs,
+            AbiName::Win64(..) => AbiNameKind::Win64,
+            AbiName::Sysv64(..) => AbiNameKind::Sysv64,
+            AbiName::Efiapi(..) => AbiNameKind::Efiapi,
+            AbiName::Other(..) => AbiNameKind::Other,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for AbiNameKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for AbiNameKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for AbiNameKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for AbiNameKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for AbiNameKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for AbiNameKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    AbiNameKind::Rust => "Rust",
+                    AbiNameKind::C => "C",
+                    AbiNameKind::CUnwind => "CUnwind",
+                    AbiNameKind::System => "System",
+                    AbiNameKind::SystemUnwind => "SystemUnwind",
+                    AbiNameKind::Cdecl => "Cdecl",
+                    AbiNameKind::Stdcall => "Stdcall",
+                    AbiNameKind::Fastcall => "Fastcall",
+                    AbiNameKind::Vectorcall => "Vectorcall",
+                    AbiNameKind::Thiscall => "Thiscall",
+                    AbiNameKind::Aapcs => "Aapcs",
+                    AbiNameKind::Win64 => "Win64",
+                    AbiNameKind::Sysv64 => "Sysv64",
+                    AbiNameKind::Efiapi => "Efiapi",
+                    AbiNameKind::Other => "Other",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`AttrStyle`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum AttrStyleKind {
+    Outer,
+    Inner,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl AttrStyle {
+    ///Returns the variant of this `AttrStyle` as a fieldless [`AttrStyleKind`].
+    pub fn kind(&self) -> AttrStyleKind {
+        match self {
+            AttrStyle::Outer => AttrStyleKind::Outer,
+            AttrStyle::Inner(..) => AttrStyleKind::Inner,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for AttrStyleKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for AttrStyleKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for AttrStyleKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for AttrStyleKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for AttrStyleKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for AttrStyleKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    AttrStyleKind::Outer => "Outer",
+                    AttrStyleKind::Inner => "Inner",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`BinOp`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum BinOpKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    And,
+    Or,
+    BitXor,
+    BitAnd,
+    BitOr,
+    Shl,
+    Shr,
+    Eq,
+    Lt,
+    Le,
+    Ne,
+    Ge,
+    Gt,
+    AddEq,
+    SubEq,
+    MulEq,
+    DivEq,
+    RemEq,
+    BitXorEq,
+    BitAndEq,
+    BitOrEq,
+    ShlEq,
+    ShrEq,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl BinOp {
+    ///Returns the variant of this `BinOp` as a fieldless [`BinOpKind`].
+    pub fn kind(&self) -> BinOpKind {
+        match self {
+            BinOp::Add(..) => BinOpKind::Add,
+            BinOp::Sub(..) => BinOpKind::Sub,
+            BinOp::Mul(..) => BinOpKind::Mul,
+            BinOp::Div(..) => BinOpKind::Div,
+            BinOp::Rem(..) => BinOpKind::Rem,
+            BinOp::And(..) => BinOpKind::And,
+            BinOp::Or(..) => BinOpKind::Or,
+            BinOp::BitXor(..) => BinOpKind::BitXor,
+            BinOp::BitAnd(..) => BinOpKind::BitAnd,
+            BinOp::BitOr(..) => BinOpKind::BitOr,
+            BinOp::Shl(..) => BinOpKind::Shl,
+            BinOp::Shr(..) => BinOpKind::Shr,
+            BinOp::Eq(..) => BinOpKind::Eq,
+            BinOp::Lt(..) => BinOpKind::Lt,
+            BinOp::Le(..) => BinOpKind::Le,
+            BinOp::Ne(..) => BinOpKind::Ne,
+            BinOp::Ge(..) => BinOpKind::Ge,
+            BinOp::Gt(..) => BinOpKind::Gt,
+            BinOp::AddEq(..) => BinOpKind::AddEq,
+            BinOp::SubEq(..) => BinOpKind::SubEq,
+            BinOp::MulEq(..) => BinOpKind::MulEq,
+            BinOp::DivEq(..) => BinOpKind::DivEq,
+            BinOp::RemEq(..) => BinOpKind::RemEq,
+            BinOp::BitXorEq(..) => BinOpKind::BitXorEq,
+            BinOp::BitAndEq(..) => BinOpKind::BitAndEq,
+            BinOp::BitOrEq(..) => BinOpKind::BitOrEq,
+            BinOp::ShlEq(..) => BinOpKind::ShlEq,
+            BinOp::ShrEq(..) => BinOpKind::ShrEq,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for BinOpKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for BinOpKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for BinOpKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for BinOpKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for BinOpKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for BinOpKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    BinOpKind::Add => "Add",
+                    BinOpKind::Sub => "Sub",
+                    BinOpKind::Mul => "Mul",
+                    BinOpKind::Div => "Div",
+                    BinOpKind::Rem => "Rem",
+                    BinOpKind::And => "And",
+                    BinOpKind::Or => "Or",
+                    BinOpKind::BitXor => "BitXor",
+                    BinOpKind::BitAnd => "BitAnd",
+                    BinOpKind::BitOr => "BitOr",
+                    BinOpKind::Shl => "Shl",
+                    BinOpKind::Shr => "Shr",
+                    BinOpKind::Eq => "Eq",
+                    BinOpKind::Lt => "Lt",
+                    BinOpKind::Le => "Le",
+                    BinOpKind::Ne => "Ne",
+                    BinOpKind::Ge => "Ge",
+                    BinOpKind::Gt => "Gt",
+                    BinOpKind::AddEq => "AddEq",
+                    BinOpKind::SubEq => "SubEq",
+                    BinOpKind::MulEq => "MulEq",
+                    BinOpKind::DivEq => "DivEq",
+                    BinOpKind::RemEq => "RemEq",
+                    BinOpKind::BitXorEq => "BitXorEq",
+                    BinOpKind::BitAndEq => "BitAndEq",
+                    BinOpKind::BitOrEq => "BitOrEq",
+                    BinOpKind::ShlEq => "ShlEq",
+                    BinOpKind::ShrEq => "ShrEq",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`CfgExpr`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum CfgExprKind {
+    Key,
+    KeyValue,
+    All,
+    Any,
+    Not,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl CfgExpr {
+    ///Returns the variant of this `CfgExpr` as a fieldless [`CfgExprKind`].
+    pub fn kind(&self) -> CfgExprKind {
+        match self {
+            CfgExpr::Key(..) => CfgExprKind::Key,
+            CfgExpr::KeyValue(..) => CfgExprKind::KeyValue,
+            CfgExpr::All(..) => CfgExprKind::All,
+            CfgExpr::Any(..) => CfgExprKind::Any,
+            CfgExpr::Not(..) => CfgExprKind::Not,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for CfgExprKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for CfgExprKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for CfgExprKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for CfgExprKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for CfgExprKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for CfgExprKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    CfgExprKind::Key => "Key",
+                    CfgExprKind::KeyValue => "KeyValue",
+                    CfgExprKind::All => "All",
+                    CfgExprKind::Any => "Any",
+                    CfgExprKind::Not => "Not",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Data`], naming one of its variants.
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "derive"))))]
+pub enum DataKind {
+    Struct,
+    Enum,
+    Union,
+}
+#[cfg(feature = "derive")]
+impl Data {
+    ///Returns the variant of this `Data` as a fieldless [`DataKind`].
+    pub fn kind(&self) -> DataKind {
+        match self {
+            Data::Struct(..) => DataKind::Struct,
+            Data::Enum(..) => DataKind::Enum,
+            Data::Union(..) => DataKind::Union,
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Copy for DataKind {}
+#[cfg(feature = "derive")]
+impl Clone for DataKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "derive")]
+impl Eq for DataKind {}
+#[cfg(feature = "derive")]
+impl PartialEq for DataKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "derive")]
+impl Hash for DataKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "derive")]
+impl Debug for DataKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    DataKind::Struct => "Struct",
+                    DataKind::Enum => "Enum",
+                    DataKind::Union => "Union",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Expr`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum ExprKind {
+    Array,
+    Assign,
+    AssignOp,
+    Async,
+    Await,
+    Binary,
+    Block,
+    Box,
+    Break,
+    Call,
+    Cast,
+    Closure,
+    Continue,
+    Field,
+    ForLoop,
+    Group,
+    If,
+    Index,
+    Let,
+    Lit,
+    Loop,
+    Macro,
+    Match,
+    MethodCall,
+    Paren,
+    Path,
+    Range,
+    Reference,
+    Repeat,
+    Return,
+    Struct,
+    Try,
+    TryBlock,
+    Tuple,
+    Type,
+    Unary,
+    Unsafe,
+    Verbatim,
+    While,
+    Yield,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Expr {
+    ///Returns the variant of this `Expr` as a fieldless [`ExprKind`].
+    pub fn kind(&self) -> ExprKind {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(..) => ExprKind::Array,
+            #[cfg(feature = "full")]
+            Expr::Assign(..) => ExprKind::Assign,
+            #[cfg(feature = "full")]
+            Expr::AssignOp(..) => ExprKind::AssignOp,
+            #[cfg(feature = "full")]
+            Expr::Async(..) => ExprKind::Async,
+            #[cfg(feature = "full")]
+            Expr::Await(..) => ExprKind::Await,
+            Expr::Binary(..) => ExprKind::Binary,
+            #[cfg(feature = "full")]
+            Expr::Block(..) => ExprKind::Block,
+            #[cfg(feature = "full")]
+            Expr::Box(..) => ExprKind::Box,
+            #[cfg(feature = "full")]
+            Expr::Break(..) => ExprKind::Break,
+            Expr::Call(..) => ExprKind::Call,
+            Expr::Cast(..) => ExprKind::Cast,
+            #[cfg(feature = "full")]
+            Expr::Closure(..) => ExprKind::Closure,
+            #[cfg(feature = "full")]
+            Expr::Continue(..) => ExprKind::Continue,
+            Expr::Field(..) => ExprKind::Field,
+            #[cfg(feature = "full")]
+            Expr::ForLoop(..) => ExprKind::ForLoop,
+            #[cfg(feature = "full")]
+            Expr::Group(..) => ExprKind::Group,
+            #[cfg(feature = "full")]
+            Expr::If(..) => ExprKind::If,
+            Expr::Index(..) => ExprKind::Index,
+            #[cfg(feature = "full")]
+            Expr::Let(..) => ExprKind::Let,
+            Expr::Lit(..) => ExprKind::Lit,
+            #[cfg(feature = "full")]
+            Expr::Loop(..) => ExprKind::Loop,
+            #[cfg(feature = "full")]
+            Expr::Macro(..) => ExprKind::Macro,
+            #[cfg(feature = "full")]
+            Expr::Match(..) => ExprKind::Match,
+            #[cfg(feature = "full")]
+            Expr::MethodCall(..) => ExprKind::MethodCall,
+            Expr::Paren(..) => ExprKind::Paren,
+            Expr::Path(..) => ExprKind::Path,
+            #[cfg(feature = "full")]
+            Expr::Range(..) => ExprKind::Range,
+            #[cfg(feature = "full")]
+            Expr::Reference(..) => ExprKind::Reference,
+            #[cfg(feature = "full")]
+            Expr::Repeat(..) => ExprKind::Repeat,
+            #[cfg(feature = "full")]
+            Expr::Return(..) => ExprKind::Return,
+            #[cfg(feature = "full")]
+            Expr::Struct(..) => ExprKind::Struct,
+            #[cfg(feature = "full")]
+            Expr::Try(..) => ExprKind::Try,
+            #[cfg(feature = "full")]
+            Expr::TryBlock(..) => ExprKind::TryBlock,
+            #[cfg(feature = "full")]
+            Expr::Tuple(..) => ExprKind::Tuple,
+            #[cfg(feature = "full")]
+            Expr::Type(..) => ExprKind::Type,
+            Expr::Unary(..) => ExprKind::Unary,
+            #[cfg(feature = "full")]
+            Expr::Unsafe(..) => ExprKind::Unsafe,
+            Expr::Verbatim(..) => ExprKind::Verbatim,
+            #[cfg(feature = "full")]
+            Expr::While(..) => ExprKind::While,
+            #[cfg(feature = "full")]
+            Expr::Yield(..) => ExprKind::Yield,
+            #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for ExprKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for ExprKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for ExprKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for ExprKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for ExprKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for ExprKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    ExprKind::Array => "Array",
+                    ExprKind::Assign => "Assign",
+                    ExprKind::AssignOp => "AssignOp",
+                    ExprKind::Async => "Async",
+                    ExprKind::Await => "Await",
+                    ExprKind::Binary => "Binary",
+                    ExprKind::Block => "Block",
+                    ExprKind::Box => "Box",
+                    ExprKind::Break => "Break",
+                    ExprKind::Call => "Call",
+                    ExprKind::Cast => "Cast",
+                    ExprKind::Closure => "Closure",
+                    ExprKind::Continue => "Continue",
+                    ExprKind::Field => "Field",
+                    ExprKind::ForLoop => "ForLoop",
+                    ExprKind::Group => "Group",
+                    ExprKind::If => "If",
+                    ExprKind::Index => "Index",
+                    ExprKind::Let => "Let",
+                    ExprKind::Lit => "Lit",
+                    ExprKind::Loop => "Loop",
+                    ExprKind::Macro => "Macro",
+                    ExprKind::Match => "Match",
+                    ExprKind::MethodCall => "MethodCall",
+                    ExprKind::Paren => "Paren",
+                    ExprKind::Path => "Path",
+                    ExprKind::Range => "Range",
+                    ExprKind::Reference => "Reference",
+                    ExprKind::Repeat => "Repeat",
+                    ExprKind::Return => "Return",
+                    ExprKind::Struct => "Struct",
+                    ExprKind::Try => "Try",
+                    ExprKind::TryBlock => "TryBlock",
+                    ExprKind::Tuple => "Tuple",
+                    ExprKind::Type => "Type",
+                    ExprKind::Unary => "Unary",
+                    ExprKind::Unsafe => "Unsafe",
+                    ExprKind::Verbatim => "Verbatim",
+                    ExprKind::While => "While",
+                    ExprKind::Yield => "Yield",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Fields`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "derive", feature = "full"))))]
+pub enum FieldsKind {
+    Named,
+    Unnamed,
+    Unit,
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Fields {
+    ///Returns the variant of this `Fields` as a fieldless [`FieldsKind`].
+    pub fn kind(&self) -> FieldsKind {
+        match self {
+            Fields::Named(..) => FieldsKind::Named,
+            Fields::Unnamed(..) => FieldsKind::Unnamed,
+            Fields::Unit => FieldsKind::Unit,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Copy for FieldsKind {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Clone for FieldsKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Eq for FieldsKind {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl PartialEq for FieldsKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Hash for FieldsKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Debug for FieldsKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    FieldsKind::Named => "Named",
+                    FieldsKind::Unnamed => "Unnamed",
+                    FieldsKind::Unit => "Unit",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`FnArg`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+pub enum FnArgKind {
+    Receiver,
+    Typed,
+}
+#[cfg(feature = "full")]
+impl FnArg {
+    ///Returns the variant of this `FnArg` as a fieldless [`FnArgKind`].
+    pub fn kind(&self) -> FnArgKind {
+        match self {
+            FnArg::Receiver(..) => FnArgKind::Receiver,
+            FnArg::Typed(..) => FnArgKind::Typed,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for FnArgKind {}
+#[cfg(feature = "full")]
+impl Clone for FnArgKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for FnArgKind {}
+#[cfg(feature = "full")]
+impl PartialEq for FnArgKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for FnArgKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for FnArgKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    FnArgKind::Receiver => "Receiver",
+                    FnArgKind::Typed => "Typed",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`ForeignItem`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum ForeignItemKind {
+    Fn,
+    Static,
+    Type,
+    Macro,
+    Verbatim,
+}
+#[cfg(feature = "full")]
+impl ForeignItem {
+    ///Returns the variant of this `ForeignItem` as a fieldless [`ForeignItemKind`].
+    pub fn kind(&self) -> ForeignItemKind {
+        match self {
+            ForeignItem::Fn(..) => ForeignItemKind::Fn,
+            ForeignItem::Static(..) => ForeignItemKind::Static,
+            ForeignItem::Type(..) => ForeignItemKind::Type,
+            ForeignItem::Macro(..) => ForeignItemKind::Macro,
+            ForeignItem::Verbatim(..) => ForeignItemKind::Verbatim,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for ForeignItemKind {}
+#[cfg(feature = "full")]
+impl Clone for ForeignItemKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for ForeignItemKind {}
+#[cfg(feature = "full")]
+impl PartialEq for ForeignItemKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for ForeignItemKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for ForeignItemKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    ForeignItemKind::Fn => "Fn",
+                    ForeignItemKind::Static => "Static",
+                    ForeignItemKind::Type => "Type",
+                    ForeignItemKind::Macro => "Macro",
+                    ForeignItemKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`GenericArgument`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum GenericArgumentKind {
+    Lifetime,
+    Type,
+    Binding,
+    Constraint,
+    Const,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl GenericArgument {
+    ///Returns the variant of this `GenericArgument` as a fieldless [`GenericArgumentKind`].
+    pub fn kind(&self) -> GenericArgumentKind {
+        match self {
+            GenericArgument::Lifetime(..) => GenericArgumentKind::Lifetime,
+            GenericArgument::Type(..) => GenericArgumentKind::Type,
+            GenericArgument::Binding(..) => GenericArgumentKind::Binding,
+            GenericArgument::Constraint(..) => GenericArgumentKind::Constraint,
+            GenericArgument::Const(..) => GenericArgumentKind::Const,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for GenericArgumentKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for GenericArgumentKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for GenericArgumentKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for GenericArgumentKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for GenericArgumentKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for GenericArgumentKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    GenericArgumentKind::Lifetime => "Lifetime",
+                    GenericArgumentKind::Type => "Type",
+                    GenericArgumentKind::Binding => "Binding",
+                    GenericArgumentKind::Constraint => "Constraint",
+                    GenericArgumentKind::Const => "Const",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`GenericMethodArgument`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+pub enum GenericMethodArgumentKind {
+    Type,
+    Const,
+}
+#[cfg(feature = "full")]
+impl GenericMethodArgument {
+    ///Returns the variant of this `GenericMethodArgument` as a fieldless [`GenericMethodArgumentKind`].
+    pub fn kind(&self) -> GenericMethodArgumentKind {
+        match self {
+            GenericMethodArgument::Type(..) => GenericMethodArgumentKind::Type,
+            GenericMethodArgument::Const(..) => GenericMethodArgumentKind::Const,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for GenericMethodArgumentKind {}
+#[cfg(feature = "full")]
+impl Clone for GenericMethodArgumentKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for GenericMethodArgumentKind {}
+#[cfg(feature = "full")]
+impl PartialEq for GenericMethodArgumentKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for GenericMethodArgumentKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for GenericMethodArgumentKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    GenericMethodArgumentKind::Type => "Type",
+                    GenericMethodArgumentKind::Const => "Const",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`GenericParam`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum GenericParamKind {
+    Type,
+    Lifetime,
+    Const,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl GenericParam {
+    ///Returns the variant of this `GenericParam` as a fieldless [`GenericParamKind`].
+    pub fn kind(&self) -> GenericParamKind {
+        match self {
+            GenericParam::Type(..) => GenericParamKind::Type,
+            GenericParam::Lifetime(..) => GenericParamKind::Lifetime,
+            GenericParam::Const(..) => GenericParamKind::Const,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for GenericParamKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for GenericParamKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for GenericParamKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for GenericParamKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for GenericParamKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for GenericParamKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    GenericParamKind::Type => "Type",
+                    GenericParamKind::Lifetime => "Lifetime",
+                    GenericParamKind::Const => "Const",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`ImplItem`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum ImplItemKind {
+    Const,
+    Method,
+    Type,
+    Macro,
+    Verbatim,
+}
+#[cfg(feature = "full")]
+impl ImplItem {
+    ///Returns the variant of this `ImplItem` as a fieldless [`ImplItemKind`].
+    pub fn kind(&self) -> ImplItemKind {
+        match self {
+            ImplItem::Const(..) => ImplItemKind::Const,
+            ImplItem::Method(..) => ImplItemKind::Method,
+            ImplItem::Type(..) => ImplItemKind::Type,
+            ImplItem::Macro(..) => ImplItemKind::Macro,
+            ImplItem::Verbatim(..) => ImplItemKind::Verbatim,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for ImplItemKind {}
+#[cfg(feature = "full")]
+impl Clone for ImplItemKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for ImplItemKind {}
+#[cfg(feature = "full")]
+impl PartialEq for ImplItemKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for ImplItemKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for ImplItemKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    ImplItemKind::Const => "Const",
+                    ImplItemKind::Method => "Method",
+                    ImplItemKind::Type => "Type",
+                    ImplItemKind::Macro => "Macro",
+                    ImplItemKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Item`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum ItemKind {
+    Const,
+    Enum,
+    ExternCrate,
+    Fn,
+    ForeignMod,
+    Impl,
+    Macro,
+    Macro2,
+    Mod,
+    Static,
+    Struct,
+    Trait,
+    TraitAlias,
+    Type,
+    Union,
+    Use,
+    Verbatim,
+}
+#[cfg(feature = "full")]
+impl Item {
+    ///Returns the variant of this `Item` as a fieldless [`ItemKind`].
+    pub fn kind(&self) -> ItemKind {
+        match self {
+            Item::Const(..) => ItemKind::Const,
+            Item::Enum(..) => ItemKind::Enum,
+            Item::ExternCrate(..) => ItemKind::ExternCrate,
+            Item::Fn(..) => ItemKind::Fn,
+            Item::ForeignMod(..) => ItemKind::ForeignMod,
+            Item::Impl(..) => ItemKind::Impl,
+            Item::Macro(..) => ItemKind::Macro,
+            Item::Macro2(..) => ItemKind::Macro2,
+            Item::Mod(..) => ItemKind::Mod,
+            Item::Static(..) => ItemKind::Static,
+            Item::Struct(..) => ItemKind::Struct,
+            Item::Trait(..) => ItemKind::Trait,
+            Item::TraitAlias(..) => ItemKind::TraitAlias,
+            Item::Type(..) => ItemKind::Type,
+            Item::Union(..) => ItemKind::Union,
+            Item::Use(..) => ItemKind::Use,
+            Item::Verbatim(..) => ItemKind::Verbatim,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for ItemKind {}
+#[cfg(feature = "full")]
+impl Clone for ItemKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for ItemKind {}
+#[cfg(feature = "full")]
+impl PartialEq for ItemKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for ItemKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for ItemKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    ItemKind::Const => "Const",
+                    ItemKind::Enum => "Enum",
+                    ItemKind::ExternCrate => "ExternCrate",
+                    ItemKind::Fn => "Fn",
+                    ItemKind::ForeignMod => "ForeignMod",
+                    ItemKind::Impl => "Impl",
+                    ItemKind::Macro => "Macro",
+                    ItemKind::Macro2 => "Macro2",
+                    ItemKind::Mod => "Mod",
+                    ItemKind::Static => "Static",
+                    ItemKind::Struct => "Struct",
+                    ItemKind::Trait => "Trait",
+                    ItemKind::TraitAlias => "TraitAlias",
+                    ItemKind::Type => "Type",
+                    ItemKind::Union => "Union",
+                    ItemKind::Use => "Use",
+                    ItemKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Lit`], naming one of its variants.
+pub enum LitKind {
+    Str,
+    ByteStr,
+    Byte,
+    Char,
+    Int,
+    Float,
+    Bool,
+    Verbatim,
+}
+impl Lit {
+    ///Returns the variant of this `Lit` as a fieldless [`LitKind`].
+    pub fn kind(&self) -> LitKind {
+        match self {
+            Lit::Str(..) => LitKind::Str,
+            Lit::ByteStr(..) => LitKind::ByteStr,
+            Lit::Byte(..) => LitKind::Byte,
+            Lit::Char(..) => LitKind::Char,
+            Lit::Int(..) => LitKind::Int,
+            Lit::Float(..) => LitKind::Float,
+            Lit::Bool(..) => LitKind::Bool,
+            Lit::Verbatim(..) => LitKind::Verbatim,
+        }
+    }
+}
+impl Copy for LitKind {}
+impl Clone for LitKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl Eq for LitKind {}
+impl PartialEq for LitKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+impl Hash for LitKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+impl Debug for LitKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    LitKind::Str => "Str",
+                    LitKind::ByteStr => "ByteStr",
+                    LitKind::Byte => "Byte",
+                    LitKind::Char => "Char",
+                    LitKind::Int => "Int",
+                    LitKind::Float => "Float",
+                    LitKind::Bool => "Bool",
+                    LitKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`MacroDelimiter`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum MacroDelimiterKind {
+    Paren,
+    Brace,
+    Bracket,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl MacroDelimiter {
+    ///Returns the variant of this `MacroDelimiter` as a fieldless [`MacroDelimiterKind`].
+    pub fn kind(&self) -> MacroDelimiterKind {
+        match self {
+            MacroDelimiter::Paren(..) => MacroDelimiterKind::Paren,
+            MacroDelimiter::Brace(..) => MacroDelimiterKind::Brace,
+            MacroDelimiter::Bracket(..) => MacroDelimiterKind::Bracket,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for MacroDelimiterKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for MacroDelimiterKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for MacroDelimiterKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for MacroDelimiterKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for MacroDelimiterKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for MacroDelimiterKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    MacroDelimiterKind::Paren => "Paren",
+                    MacroDelimiterKind::Brace => "Brace",
+                    MacroDelimiterKind::Bracket => "Bracket",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Member`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum MemberKind {
+    Named,
+    Unnamed,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Member {
+    ///Returns the variant of this `Member` as a fieldless [`MemberKind`].
+    pub fn kind(&self) -> MemberKind {
+        match self {
+            Member::Named(..) => MemberKind::Named,
+            Member::Unnamed(..) => MemberKind::Unnamed,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for MemberKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for MemberKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for MemberKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for MemberKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for MemberKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for MemberKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    MemberKind::Named => "Named",
+                    MemberKind::Unnamed => "Unnamed",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Meta`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum MetaKind {
+    Path,
+    List,
+    NameValue,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Meta {
+    ///Returns the variant of this `Meta` as a fieldless [`MetaKind`].
+    pub fn kind(&self) -> MetaKind {
+        match self {
+            Meta::Path(..) => MetaKind::Path,
+            Meta::List(..) => MetaKind::List,
+            Meta::NameValue(..) => MetaKind::NameValue,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for MetaKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for MetaKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for MetaKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for MetaKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for MetaKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for MetaKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    MetaKind::Path => "Path",
+                    MetaKind::List => "List",
+                    MetaKind::NameValue => "NameValue",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`NestedMeta`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum NestedMetaKind {
+    Meta,
+    Lit,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl NestedMeta {
+    ///Returns the variant of this `NestedMeta` as a fieldless [`NestedMetaKind`].
+    pub fn kind(&self) -> NestedMetaKind {
+        match self {
+            NestedMeta::Meta(..) => NestedMetaKind::Meta,
+            NestedMeta::Lit(..) => NestedMetaKind::Lit,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for NestedMetaKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for NestedMetaKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for NestedMetaKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for NestedMetaKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for NestedMetaKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for NestedMetaKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    NestedMetaKind::Meta => "Meta",
+                    NestedMetaKind::Lit => "Lit",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Pat`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum PatKind {
+    Box,
+    Ident,
+    Lit,
+    Macro,
+    Or,
+    Path,
+    Range,
+    Reference,
+    Rest,
+    Slice,
+    Struct,
+    Tuple,
+    TupleStruct,
+    Type,
+    Verbatim,
+    Wild,
+}
+#[cfg(feature = "full")]
+impl Pat {
+    ///Returns the variant of this `Pat` as a fieldless [`PatKind`].
+    pub fn kind(&self) -> PatKind {
+        match self {
+            Pat::Box(..) => PatKind::Box,
+            Pat::Ident(..) => PatKind::Ident,
+            Pat::Lit(..) => PatKind::Lit,
+            Pat::Macro(..) => PatKind::Macro,
+            Pat::Or(..) => PatKind::Or,
+            Pat::Path(..) => PatKind::Path,
+            Pat::Range(..) => PatKind::Range,
+            Pat::Reference(..) => PatKind::Reference,
+            Pat::Rest(..) => PatKind::Rest,
+            Pat::Slice(..) => PatKind::Slice,
+            Pat::Struct(..) => PatKind::Struct,
+            Pat::Tuple(..) => PatKind::Tuple,
+            Pat::TupleStruct(..) => PatKind::TupleStruct,
+            Pat::Type(..) => PatKind::Type,
+            Pat::Verbatim(..) => PatKind::Verbatim,
+            Pat::Wild(..) => PatKind::Wild,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for PatKind {}
+#[cfg(feature = "full")]
+impl Clone for PatKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for PatKind {}
+#[cfg(feature = "full")]
+impl PartialEq for PatKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for PatKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for PatKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    PatKind::Box => "Box",
+                    PatKind::Ident => "Ident",
+                    PatKind::Lit => "Lit",
+                    PatKind::Macro => "Macro",
+                    PatKind::Or => "Or",
+                    PatKind::Path => "Path",
+                    PatKind::Range => "Range",
+                    PatKind::Reference => "Reference",
+                    PatKind::Rest => "Rest",
+                    PatKind::Slice => "Slice",
+                    PatKind::Struct => "Struct",
+                    PatKind::Tuple => "Tuple",
+                    PatKind::TupleStruct => "TupleStruct",
+                    PatKind::Type => "Type",
+                    PatKind::Verbatim => "Verbatim",
+                    PatKind::Wild => "Wild",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`PathArguments`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum PathArgumentsKind {
+    None,
+    AngleBracketed,
+    Parenthesized,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PathArguments {
+    ///Returns the variant of this `PathArguments` as a fieldless [`PathArgumentsKind`].
+    pub fn kind(&self) -> PathArgumentsKind {
+        match self {
+            PathArguments::None => PathArgumentsKind::None,
+            PathArguments::AngleBracketed(..) => PathArgumentsKind::AngleBracketed,
+            PathArguments::Parenthesized(..) => PathArgumentsKind::Parenthesized,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for PathArgumentsKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for PathArgumentsKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for PathArgumentsKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for PathArgumentsKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for PathArgumentsKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for PathArgumentsKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    PathArgumentsKind::None => "None",
+                    PathArgumentsKind::AngleBracketed => "AngleBracketed",
+                    PathArgumentsKind::Parenthesized => "Parenthesized",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`RangeLimits`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+pub enum RangeLimitsKind {
+    HalfOpen,
+    Closed,
+}
+#[cfg(feature = "full")]
+impl RangeLimits {
+    ///Returns the variant of this `RangeLimits` as a fieldless [`RangeLimitsKind`].
+    pub fn kind(&self) -> RangeLimitsKind {
+        match self {
+            RangeLimits::HalfOpen(..) => RangeLimitsKind::HalfOpen,
+            RangeLimits::Closed(..) => RangeLimitsKind::Closed,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for RangeLimitsKind {}
+#[cfg(feature = "full")]
+impl Clone for RangeLimitsKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for RangeLimitsKind {}
+#[cfg(feature = "full")]
+impl PartialEq for RangeLimitsKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for RangeLimitsKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for RangeLimitsKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    RangeLimitsKind::HalfOpen => "HalfOpen",
+                    RangeLimitsKind::Closed => "Closed",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`ReturnType`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum ReturnTypeKind {
+    Default,
+    Type,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl ReturnType {
+    ///Returns the variant of this `ReturnType` as a fieldless [`ReturnTypeKind`].
+    pub fn kind(&self) -> ReturnTypeKind {
+        match self {
+            ReturnType::Default => ReturnTypeKind::Default,
+            ReturnType::Type(..) => ReturnTypeKind::Type,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for ReturnTypeKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for ReturnTypeKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for ReturnTypeKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for ReturnTypeKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for ReturnTypeKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for ReturnTypeKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    ReturnTypeKind::Default => "Default",
+                    ReturnTypeKind::Type => "Type",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Stmt`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+pub enum StmtKind {
+    Local,
+    Item,
+    Expr,
+    Semi,
+}
+#[cfg(feature = "full")]
+impl Stmt {
+    ///Returns the variant of this `Stmt` as a fieldless [`StmtKind`].
+    pub fn kind(&self) -> StmtKind {
+        match self {
+            Stmt::Local(..) => StmtKind::Local,
+            Stmt::Item(..) => StmtKind::Item,
+            Stmt::Expr(..) => StmtKind::Expr,
+            Stmt::Semi(..) => StmtKind::Semi,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for StmtKind {}
+#[cfg(feature = "full")]
+impl Clone for StmtKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for StmtKind {}
+#[cfg(feature = "full")]
+impl PartialEq for StmtKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for StmtKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for StmtKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    StmtKind::Local => "Local",
+                    StmtKind::Item => "Item",
+                    StmtKind::Expr => "Expr",
+                    StmtKind::Semi => "Semi",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`TraitBoundModifier`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum TraitBoundModifierKind {
+    None,
+    Maybe,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TraitBoundModifier {
+    ///Returns the variant of this `TraitBoundModifier` as a fieldless [`TraitBoundModifierKind`].
+    pub fn kind(&self) -> TraitBoundModifierKind {
+        match self {
+            TraitBoundModifier::None => TraitBoundModifierKind::None,
+            TraitBoundModifier::Maybe(..) => TraitBoundModifierKind::Maybe,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for TraitBoundModifierKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for TraitBoundModifierKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for TraitBoundModifierKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for TraitBoundModifierKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for TraitBoundModifierKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for TraitBoundModifierKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    TraitBoundModifierKind::None => "None",
+                    TraitBoundModifierKind::Maybe => "Maybe",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`TraitItem`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum TraitItemKind {
+    Const,
+    Method,
+    Type,
+    Macro,
+    Verbatim,
+}
+#[cfg(feature = "full")]
+impl TraitItem {
+    ///Returns the variant of this `TraitItem` as a fieldless [`TraitItemKind`].
+    pub fn kind(&self) -> TraitItemKind {
+        match self {
+            TraitItem::Const(..) => TraitItemKind::Const,
+            TraitItem::Method(..) => TraitItemKind::Method,
+            TraitItem::Type(..) => TraitItemKind::Type,
+            TraitItem::Macro(..) => TraitItemKind::Macro,
+            TraitItem::Verbatim(..) => TraitItemKind::Verbatim,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for TraitItemKind {}
+#[cfg(feature = "full")]
+impl Clone for TraitItemKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for TraitItemKind {}
+#[cfg(feature = "full")]
+impl PartialEq for TraitItemKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for TraitItemKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for TraitItemKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    TraitItemKind::Const => "Const",
+                    TraitItemKind::Method => "Method",
+                    TraitItemKind::Type => "Type",
+                    TraitItemKind::Macro => "Macro",
+                    TraitItemKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Type`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+#[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+pub enum TypeKind {
+    Array,
+    BareFn,
+    Group,
+    ImplTrait,
+    Infer,
+    Macro,
+    Never,
+    Paren,
+    Path,
+    Ptr,
+    Reference,
+    Slice,
+    TraitObject,
+    Tuple,
+    Verbatim,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Type {
+    ///Returns the variant of this `Type` as a fieldless [`TypeKind`].
+    pub fn kind(&self) -> TypeKind {
+        match self {
+            Type::Array(..) => TypeKind::Array,
+            Type::BareFn(..) => TypeKind::BareFn,
+            Type::Group(..) => TypeKind::Group,
+            Type::ImplTrait(..) => TypeKind::ImplTrait,
+            Type::Infer(..) => TypeKind::Infer,
+            Type::Macro(..) => TypeKind::Macro,
+            Type::Never(..) => TypeKind::Never,
+            Type::Paren(..) => TypeKind::Paren,
+            Type::Path(..) => TypeKind::Path,
+            Type::Ptr(..) => TypeKind::Ptr,
+            Type::Reference(..) => TypeKind::Reference,
+            Type::Slice(..) => TypeKind::Slice,
+            Type::TraitObject(..) => TypeKind::TraitObject,
+            Type::Tuple(..) => TypeKind::Tuple,
+            Type::Verbatim(..) => TypeKind::Verbatim,
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for TypeKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for TypeKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for TypeKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for TypeKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for TypeKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for TypeKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    TypeKind::Array => "Array",
+                    TypeKind::BareFn => "BareFn",
+                    TypeKind::Group => "Group",
+                    TypeKind::ImplTrait => "ImplTrait",
+                    TypeKind::Infer => "Infer",
+                    TypeKind::Macro => "Macro",
+                    TypeKind::Never => "Never",
+                    TypeKind::Paren => "Paren",
+                    TypeKind::Path => "Path",
+                    TypeKind::Ptr => "Ptr",
+                    TypeKind::Reference => "Reference",
+                    TypeKind::Slice => "Slice",
+                    TypeKind::TraitObject => "TraitObject",
+                    TypeKind::Tuple => "Tuple",
+                    TypeKind::Verbatim => "Verbatim",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`TypeParamBound`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum TypeParamBoundKind {
+    Trait,
+    Lifetime,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl TypeParamBound {
+    ///Returns the variant of this `TypeParamBound` as a fieldless [`TypeParamBoundKind`].
+    pub fn kind(&self) -> TypeParamBoundKind {
+        match self {
+            TypeParamBound::Trait(..) => TypeParamBoundKind::Trait,
+            TypeParamBound::Lifetime(..) => TypeParamBoundKind::Lifetime,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for TypeParamBoundKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for TypeParamBoundKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for TypeParamBoundKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for TypeParamBoundKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for TypeParamBoundKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for TypeParamBoundKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    TypeParamBoundKind::Trait => "Trait",
+                    TypeParamBoundKind::Lifetime => "Lifetime",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`UnOp`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum UnOpKind {
+    Deref,
+    Not,
+    Neg,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl UnOp {
+    ///Returns the variant of this `UnOp` as a fieldless [`UnOpKind`].
+    pub fn kind(&self) -> UnOpKind {
+        match self {
+            UnOp::Deref(..) => UnOpKind::Deref,
+            UnOp::Not(..) => UnOpKind::Not,
+            UnOp::Neg(..) => UnOpKind::Neg,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for UnOpKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for UnOpKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for UnOpKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for UnOpKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for UnOpKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for UnOpKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    UnOpKind::Deref => "Deref",
+                    UnOpKind::Not => "Not",
+                    UnOpKind::Neg => "Neg",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`UseTree`], naming one of its variants.
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full"))))]
+pub enum UseTreeKind {
+    Path,
+    Name,
+    Rename,
+    Glob,
+    Group,
+}
+#[cfg(feature = "full")]
+impl UseTree {
+    ///Returns the variant of this `UseTree` as a fieldless [`UseTreeKind`].
+    pub fn kind(&self) -> UseTreeKind {
+        match self {
+            UseTree::Path(..) => UseTreeKind::Path,
+            UseTree::Name(..) => UseTreeKind::Name,
+            UseTree::Rename(..) => UseTreeKind::Rename,
+            UseTree::Glob(..) => UseTreeKind::Glob,
+            UseTree::Group(..) => UseTreeKind::Group,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Copy for UseTreeKind {}
+#[cfg(feature = "full")]
+impl Clone for UseTreeKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+impl Eq for UseTreeKind {}
+#[cfg(feature = "full")]
+impl PartialEq for UseTreeKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(feature = "full")]
+impl Hash for UseTreeKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Debug for UseTreeKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    UseTreeKind::Path => "Path",
+                    UseTreeKind::Name => "Name",
+                    UseTreeKind::Rename => "Rename",
+                    UseTreeKind::Glob => "Glob",
+                    UseTreeKind::Group => "Group",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`Visibility`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "derive", feature = "full"))))]
+pub enum VisibilityKind {
+    Public,
+    Crate,
+    Restricted,
+    Inherited,
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Visibility {
+    ///Returns the variant of this `Visibility` as a fieldless [`VisibilityKind`].
+    pub fn kind(&self) -> VisibilityKind {
+        match self {
+            Visibility::Public(..) => VisibilityKind::Public,
+            Visibility::Crate(..) => VisibilityKind::Crate,
+            Visibility::Restricted(..) => VisibilityKind::Restricted,
+            Visibility::Inherited => VisibilityKind::Inherited,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Copy for VisibilityKind {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Clone for VisibilityKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Eq for VisibilityKind {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl PartialEq for VisibilityKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Hash for VisibilityKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Debug for VisibilityKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    VisibilityKind::Public => "Public",
+                    VisibilityKind::Crate => "Crate",
+                    VisibilityKind::Restricted => "Restricted",
+                    VisibilityKind::Inherited => "Inherited",
+                },
+            )
+    }
+}
+///Fieldless counterpart of [`WherePredicate`], naming one of its variants.
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "derive", feature = "full", feature = "parse-only")))
+)]
+pub enum WherePredicateKind {
+    Type,
+    Lifetime,
+    Eq,
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl WherePredicate {
+    ///Returns the variant of this `WherePredicate` as a fieldless [`WherePredicateKind`].
+    pub fn kind(&self) -> WherePredicateKind {
+        match self {
+            WherePredicate::Type(..) => WherePredicateKind::Type,
+            WherePredicate::Lifetime(..) => WherePredicateKind::Lifetime,
+            WherePredicate::Eq(..) => WherePredicateKind::Eq,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Copy for WherePredicateKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Clone for WherePredicateKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Eq for WherePredicateKind {}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl PartialEq for WherePredicateKind {
+    fn eq(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Hash for WherePredicateKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Debug for WherePredicateKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str(
+                match self {
+                    WherePredicateKind::Type => "Type",
+                    WherePredicateKind::Lifetime => "Lifetime",
+                    WherePredicateKind::Eq => "Eq",
+                },
+            )
+    }
+}
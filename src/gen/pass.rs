@@ -0,0 +1,2816 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+use crate::visit_mut::{self, VisitMut};
+use crate::*;
+use proc_macro2::Span;
+/// A pass of rewrites local to each node, which can be fused with
+/// other passes into a single traversal by [`Passes`].
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available only if Syn is built with the `"visit-mut"` feature.*
+pub trait Pass {
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_abi(&mut self, i: &mut Abi) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_abi(&mut self, i: &mut Abi) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_abi_name(&mut self, i: &mut AbiName) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_abi_name(&mut self, i: &mut AbiName) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &mut AngleBracketedGenericArguments,
+    ) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &mut AngleBracketedGenericArguments,
+    ) {}
+    #[cfg(feature = "full")]
+    fn enter_arm(&mut self, i: &mut Arm) {}
+    #[cfg(feature = "full")]
+    fn exit_arm(&mut self, i: &mut Arm) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_attr_style(&mut self, i: &mut AttrStyle) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_attr_style(&mut self, i: &mut AttrStyle) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_attribute(&mut self, i: &mut Attribute) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_attribute(&mut self, i: &mut Attribute) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_bare_fn_arg(&mut self, i: &mut BareFnArg) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_bare_fn_arg(&mut self, i: &mut BareFnArg) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_bin_op(&mut self, i: &mut BinOp) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_bin_op(&mut self, i: &mut BinOp) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_binding(&mut self, i: &mut Binding) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_binding(&mut self, i: &mut Binding) {}
+    #[cfg(feature = "full")]
+    fn enter_block(&mut self, i: &mut Block) {}
+    #[cfg(feature = "full")]
+    fn exit_block(&mut self, i: &mut Block) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_bound_lifetimes(&mut self, i: &mut BoundLifetimes) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_bound_lifetimes(&mut self, i: &mut BoundLifetimes) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_cfg_all(&mut self, i: &mut CfgAll) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_cfg_all(&mut self, i: &mut CfgAll) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_cfg_any(&mut self, i: &mut CfgAny) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_cfg_any(&mut self, i: &mut CfgAny) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_cfg_expr(&mut self, i: &mut CfgExpr) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_cfg_expr(&mut self, i: &mut CfgExpr) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_cfg_key_value(&mut self, i: &mut CfgKeyValue) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_cfg_key_value(&mut self, i: &mut CfgKeyValue) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_cfg_not(&mut self, i: &mut CfgNot) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_cfg_not(&mut self, i: &mut CfgNot) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_const_param(&mut self, i: &mut ConstParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_const_param(&mut self, i: &mut ConstParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_constraint(&mut self, i: &mut Constraint) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_constraint(&mut self, i: &mut Constraint) {}
+    #[cfg(feature = "derive")]
+    fn enter_data(&mut self, i: &mut Data) {}
+    #[cfg(feature = "derive")]
+    fn exit_data(&mut self, i: &mut Data) {}
+    #[cfg(feature = "derive")]
+    fn enter_data_enum(&mut self, i: &mut DataEnum) {}
+    #[cfg(feature = "derive")]
+    fn exit_data_enum(&mut self, i: &mut DataEnum) {}
+    #[cfg(feature = "derive")]
+    fn enter_data_struct(&mut self, i: &mut DataStruct) {}
+    #[cfg(feature = "derive")]
+    fn exit_data_struct(&mut self, i: &mut DataStruct) {}
+    #[cfg(feature = "derive")]
+    fn enter_data_union(&mut self, i: &mut DataUnion) {}
+    #[cfg(feature = "derive")]
+    fn exit_data_union(&mut self, i: &mut DataUnion) {}
+    #[cfg(feature = "verus")]
+    fn enter_decreases(&mut self, i: &mut Decreases) {}
+    #[cfg(feature = "verus")]
+    fn exit_decreases(&mut self, i: &mut Decreases) {}
+    #[cfg(feature = "derive")]
+    fn enter_derive_input(&mut self, i: &mut DeriveInput) {}
+    #[cfg(feature = "derive")]
+    fn exit_derive_input(&mut self, i: &mut DeriveInput) {}
+    #[cfg(feature = "verus")]
+    fn enter_ensures(&mut self, i: &mut Ensures) {}
+    #[cfg(feature = "verus")]
+    fn exit_ensures(&mut self, i: &mut Ensures) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr(&mut self, i: &mut Expr) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr(&mut self, i: &mut Expr) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_array(&mut self, i: &mut ExprArray) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_array(&mut self, i: &mut ExprArray) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_assign(&mut self, i: &mut ExprAssign) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_assign(&mut self, i: &mut ExprAssign) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_assign_op(&mut self, i: &mut ExprAssignOp) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_assign_op(&mut self, i: &mut ExprAssignOp) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_async(&mut self, i: &mut ExprAsync) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_async(&mut self, i: &mut ExprAsync) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_await(&mut self, i: &mut ExprAwait) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_await(&mut self, i: &mut ExprAwait) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_binary(&mut self, i: &mut ExprBinary) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_binary(&mut self, i: &mut ExprBinary) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_block(&mut self, i: &mut ExprBlock) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_block(&mut self, i: &mut ExprBlock) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_box(&mut self, i: &mut ExprBox) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_box(&mut self, i: &mut ExprBox) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_break(&mut self, i: &mut ExprBreak) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_break(&mut self, i: &mut ExprBreak) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_call(&mut self, i: &mut ExprCall) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_call(&mut self, i: &mut ExprCall) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_cast(&mut self, i: &mut ExprCast) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_cast(&mut self, i: &mut ExprCast) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_closure(&mut self, i: &mut ExprClosure) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_closure(&mut self, i: &mut ExprClosure) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_continue(&mut self, i: &mut ExprContinue) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_continue(&mut self, i: &mut ExprContinue) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_field(&mut self, i: &mut ExprField) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_field(&mut self, i: &mut ExprField) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_for_loop(&mut self, i: &mut ExprForLoop) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_for_loop(&mut self, i: &mut ExprForLoop) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_group(&mut self, i: &mut ExprGroup) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_group(&mut self, i: &mut ExprGroup) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_if(&mut self, i: &mut ExprIf) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_if(&mut self, i: &mut ExprIf) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_index(&mut self, i: &mut ExprIndex) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_index(&mut self, i: &mut ExprIndex) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_let(&mut self, i: &mut ExprLet) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_let(&mut self, i: &mut ExprLet) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_lit(&mut self, i: &mut ExprLit) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_lit(&mut self, i: &mut ExprLit) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_loop(&mut self, i: &mut ExprLoop) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_loop(&mut self, i: &mut ExprLoop) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_macro(&mut self, i: &mut ExprMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_macro(&mut self, i: &mut ExprMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_match(&mut self, i: &mut ExprMatch) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_match(&mut self, i: &mut ExprMatch) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_method_call(&mut self, i: &mut ExprMethodCall) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_method_call(&mut self, i: &mut ExprMethodCall) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_paren(&mut self, i: &mut ExprParen) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_paren(&mut self, i: &mut ExprParen) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_path(&mut self, i: &mut ExprPath) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_path(&mut self, i: &mut ExprPath) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_range(&mut self, i: &mut ExprRange) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_range(&mut self, i: &mut ExprRange) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_reference(&mut self, i: &mut ExprReference) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_reference(&mut self, i: &mut ExprReference) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_repeat(&mut self, i: &mut ExprRepeat) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_repeat(&mut self, i: &mut ExprRepeat) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_return(&mut self, i: &mut ExprReturn) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_return(&mut self, i: &mut ExprReturn) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_struct(&mut self, i: &mut ExprStruct) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_struct(&mut self, i: &mut ExprStruct) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_try(&mut self, i: &mut ExprTry) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_try(&mut self, i: &mut ExprTry) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_try_block(&mut self, i: &mut ExprTryBlock) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_try_block(&mut self, i: &mut ExprTryBlock) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_tuple(&mut self, i: &mut ExprTuple) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_tuple(&mut self, i: &mut ExprTuple) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_type(&mut self, i: &mut ExprType) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_type(&mut self, i: &mut ExprType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_expr_unary(&mut self, i: &mut ExprUnary) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_expr_unary(&mut self, i: &mut ExprUnary) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_unsafe(&mut self, i: &mut ExprUnsafe) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_unsafe(&mut self, i: &mut ExprUnsafe) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_while(&mut self, i: &mut ExprWhile) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_while(&mut self, i: &mut ExprWhile) {}
+    #[cfg(feature = "full")]
+    fn enter_expr_yield(&mut self, i: &mut ExprYield) {}
+    #[cfg(feature = "full")]
+    fn exit_expr_yield(&mut self, i: &mut ExprYield) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_field(&mut self, i: &mut Field) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_field(&mut self, i: &mut Field) {}
+    #[cfg(feature = "full")]
+    fn enter_field_pat(&mut self, i: &mut FieldPat) {}
+    #[cfg(feature = "full")]
+    fn exit_field_pat(&mut self, i: &mut FieldPat) {}
+    #[cfg(feature = "full")]
+    fn enter_field_value(&mut self, i: &mut FieldValue) {}
+    #[cfg(feature = "full")]
+    fn exit_field_value(&mut self, i: &mut FieldValue) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_fields(&mut self, i: &mut Fields) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_fields(&mut self, i: &mut Fields) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_fields_named(&mut self, i: &mut FieldsNamed) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_fields_named(&mut self, i: &mut FieldsNamed) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_fields_unnamed(&mut self, i: &mut FieldsUnnamed) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_fields_unnamed(&mut self, i: &mut FieldsUnnamed) {}
+    #[cfg(feature = "full")]
+    fn enter_file(&mut self, i: &mut File) {}
+    #[cfg(feature = "full")]
+    fn exit_file(&mut self, i: &mut File) {}
+    #[cfg(feature = "full")]
+    fn enter_fn_arg(&mut self, i: &mut FnArg) {}
+    #[cfg(feature = "full")]
+    fn exit_fn_arg(&mut self, i: &mut FnArg) {}
+    #[cfg(feature = "full")]
+    fn enter_fn_qualifiers(&mut self, i: &mut FnQualifiers) {}
+    #[cfg(feature = "full")]
+    fn exit_fn_qualifiers(&mut self, i: &mut FnQualifiers) {}
+    #[cfg(feature = "full")]
+    fn enter_foreign_item(&mut self, i: &mut ForeignItem) {}
+    #[cfg(feature = "full")]
+    fn exit_foreign_item(&mut self, i: &mut ForeignItem) {}
+    #[cfg(feature = "full")]
+    fn enter_foreign_item_fn(&mut self, i: &mut ForeignItemFn) {}
+    #[cfg(feature = "full")]
+    fn exit_foreign_item_fn(&mut self, i: &mut ForeignItemFn) {}
+    #[cfg(feature = "full")]
+    fn enter_foreign_item_macro(&mut self, i: &mut ForeignItemMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_foreign_item_macro(&mut self, i: &mut ForeignItemMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_foreign_item_static(&mut self, i: &mut ForeignItemStatic) {}
+    #[cfg(feature = "full")]
+    fn exit_foreign_item_static(&mut self, i: &mut ForeignItemStatic) {}
+    #[cfg(feature = "full")]
+    fn enter_foreign_item_type(&mut self, i: &mut ForeignItemType) {}
+    #[cfg(feature = "full")]
+    fn exit_foreign_item_type(&mut self, i: &mut ForeignItemType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_generic_argument(&mut self, i: &mut GenericArgument) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_generic_argument(&mut self, i: &mut GenericArgument) {}
+    #[cfg(feature = "full")]
+    fn enter_generic_method_argument(&mut self, i: &mut GenericMethodArgument) {}
+    #[cfg(feature = "full")]
+    fn exit_generic_method_argument(&mut self, i: &mut GenericMethodArgument) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_generic_param(&mut self, i: &mut GenericParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_generic_param(&mut self, i: &mut GenericParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_generics(&mut self, i: &mut Generics) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_generics(&mut self, i: &mut Generics) {}
+    fn enter_ident(&mut self, i: &mut Ident) {}
+    fn exit_ident(&mut self, i: &mut Ident) {}
+    #[cfg(feature = "full")]
+    fn enter_impl_item(&mut self, i: &mut ImplItem) {}
+    #[cfg(feature = "full")]
+    fn exit_impl_item(&mut self, i: &mut ImplItem) {}
+    #[cfg(feature = "full")]
+    fn enter_impl_item_const(&mut self, i: &mut ImplItemConst) {}
+    #[cfg(feature = "full")]
+    fn exit_impl_item_const(&mut self, i: &mut ImplItemConst) {}
+    #[cfg(feature = "full")]
+    fn enter_impl_item_macro(&mut self, i: &mut ImplItemMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_impl_item_macro(&mut self, i: &mut ImplItemMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_impl_item_method(&mut self, i: &mut ImplItemMethod) {}
+    #[cfg(feature = "full")]
+    fn exit_impl_item_method(&mut self, i: &mut ImplItemMethod) {}
+    #[cfg(feature = "full")]
+    fn enter_impl_item_type(&mut self, i: &mut ImplItemType) {}
+    #[cfg(feature = "full")]
+    fn exit_impl_item_type(&mut self, i: &mut ImplItemType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_index(&mut self, i: &mut Index) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_index(&mut self, i: &mut Index) {}
+    #[cfg(feature = "verus")]
+    fn enter_invariant(&mut self, i: &mut Invariant) {}
+    #[cfg(feature = "verus")]
+    fn exit_invariant(&mut self, i: &mut Invariant) {}
+    #[cfg(feature = "full")]
+    fn enter_item(&mut self, i: &mut Item) {}
+    #[cfg(feature = "full")]
+    fn exit_item(&mut self, i: &mut Item) {}
+    #[cfg(feature = "full")]
+    fn enter_item_const(&mut self, i: &mut ItemConst) {}
+    #[cfg(feature = "full")]
+    fn exit_item_const(&mut self, i: &mut ItemConst) {}
+    #[cfg(feature = "full")]
+    fn enter_item_enum(&mut self, i: &mut ItemEnum) {}
+    #[cfg(feature = "full")]
+    fn exit_item_enum(&mut self, i: &mut ItemEnum) {}
+    #[cfg(feature = "full")]
+    fn enter_item_extern_crate(&mut self, i: &mut ItemExternCrate) {}
+    #[cfg(feature = "full")]
+    fn exit_item_extern_crate(&mut self, i: &mut ItemExternCrate) {}
+    #[cfg(feature = "full")]
+    fn enter_item_fn(&mut self, i: &mut ItemFn) {}
+    #[cfg(feature = "full")]
+    fn exit_item_fn(&mut self, i: &mut ItemFn) {}
+    #[cfg(feature = "full")]
+    fn enter_item_foreign_mod(&mut self, i: &mut ItemForeignMod) {}
+    #[cfg(feature = "full")]
+    fn exit_item_foreign_mod(&mut self, i: &mut ItemForeignMod) {}
+    #[cfg(feature = "full")]
+    fn enter_item_impl(&mut self, i: &mut ItemImpl) {}
+    #[cfg(feature = "full")]
+    fn exit_item_impl(&mut self, i: &mut ItemImpl) {}
+    #[cfg(feature = "full")]
+    fn enter_item_macro(&mut self, i: &mut ItemMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_item_macro(&mut self, i: &mut ItemMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_item_macro2(&mut self, i: &mut ItemMacro2) {}
+    #[cfg(feature = "full")]
+    fn exit_item_macro2(&mut self, i: &mut ItemMacro2) {}
+    #[cfg(feature = "full")]
+    fn enter_item_mod(&mut self, i: &mut ItemMod) {}
+    #[cfg(feature = "full")]
+    fn exit_item_mod(&mut self, i: &mut ItemMod) {}
+    #[cfg(feature = "full")]
+    fn enter_item_static(&mut self, i: &mut ItemStatic) {}
+    #[cfg(feature = "full")]
+    fn exit_item_static(&mut self, i: &mut ItemStatic) {}
+    #[cfg(feature = "full")]
+    fn enter_item_struct(&mut self, i: &mut ItemStruct) {}
+    #[cfg(feature = "full")]
+    fn exit_item_struct(&mut self, i: &mut ItemStruct) {}
+    #[cfg(feature = "full")]
+    fn enter_item_trait(&mut self, i: &mut ItemTrait) {}
+    #[cfg(feature = "full")]
+    fn exit_item_trait(&mut self, i: &mut ItemTrait) {}
+    #[cfg(feature = "full")]
+    fn enter_item_trait_alias(&mut self, i: &mut ItemTraitAlias) {}
+    #[cfg(feature = "full")]
+    fn exit_item_trait_alias(&mut self, i: &mut ItemTraitAlias) {}
+    #[cfg(feature = "full")]
+    fn enter_item_type(&mut self, i: &mut ItemType) {}
+    #[cfg(feature = "full")]
+    fn exit_item_type(&mut self, i: &mut ItemType) {}
+    #[cfg(feature = "full")]
+    fn enter_item_union(&mut self, i: &mut ItemUnion) {}
+    #[cfg(feature = "full")]
+    fn exit_item_union(&mut self, i: &mut ItemUnion) {}
+    #[cfg(feature = "full")]
+    fn enter_item_use(&mut self, i: &mut ItemUse) {}
+    #[cfg(feature = "full")]
+    fn exit_item_use(&mut self, i: &mut ItemUse) {}
+    #[cfg(feature = "full")]
+    fn enter_label(&mut self, i: &mut Label) {}
+    #[cfg(feature = "full")]
+    fn exit_label(&mut self, i: &mut Label) {}
+    fn enter_lifetime(&mut self, i: &mut Lifetime) {}
+    fn exit_lifetime(&mut self, i: &mut Lifetime) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_lifetime_def(&mut self, i: &mut LifetimeDef) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_lifetime_def(&mut self, i: &mut LifetimeDef) {}
+    fn enter_lit(&mut self, i: &mut Lit) {}
+    fn exit_lit(&mut self, i: &mut Lit) {}
+    fn enter_lit_bool(&mut self, i: &mut LitBool) {}
+    fn exit_lit_bool(&mut self, i: &mut LitBool) {}
+    fn enter_lit_byte(&mut self, i: &mut LitByte) {}
+    fn exit_lit_byte(&mut self, i: &mut LitByte) {}
+    fn enter_lit_byte_str(&mut self, i: &mut LitByteStr) {}
+    fn exit_lit_byte_str(&mut self, i: &mut LitByteStr) {}
+    fn enter_lit_char(&mut self, i: &mut LitChar) {}
+    fn exit_lit_char(&mut self, i: &mut LitChar) {}
+    fn enter_lit_float(&mut self, i: &mut LitFloat) {}
+    fn exit_lit_float(&mut self, i: &mut LitFloat) {}
+    fn enter_lit_int(&mut self, i: &mut LitInt) {}
+    fn exit_lit_int(&mut self, i: &mut LitInt) {}
+    fn enter_lit_str(&mut self, i: &mut LitStr) {}
+    fn exit_lit_str(&mut self, i: &mut LitStr) {}
+    #[cfg(feature = "full")]
+    fn enter_local(&mut self, i: &mut Local) {}
+    #[cfg(feature = "full")]
+    fn exit_local(&mut self, i: &mut Local) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_macro(&mut self, i: &mut Macro) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_macro(&mut self, i: &mut Macro) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_macro_delimiter(&mut self, i: &mut MacroDelimiter) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_macro_delimiter(&mut self, i: &mut MacroDelimiter) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_member(&mut self, i: &mut Member) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_member(&mut self, i: &mut Member) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_meta(&mut self, i: &mut Meta) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_meta(&mut self, i: &mut Meta) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_meta_list(&mut self, i: &mut MetaList) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_meta_list(&mut self, i: &mut MetaList) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_meta_name_value(&mut self, i: &mut MetaNameValue) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_meta_name_value(&mut self, i: &mut MetaNameValue) {}
+    #[cfg(feature = "full")]
+    fn enter_method_turbofish(&mut self, i: &mut MethodTurbofish) {}
+    #[cfg(feature = "full")]
+    fn exit_method_turbofish(&mut self, i: &mut MethodTurbofish) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_nested_meta(&mut self, i: &mut NestedMeta) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_nested_meta(&mut self, i: &mut NestedMeta) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_parenthesized_generic_arguments(
+        &mut self,
+        i: &mut ParenthesizedGenericArguments,
+    ) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_parenthesized_generic_arguments(
+        &mut self,
+        i: &mut ParenthesizedGenericArguments,
+    ) {}
+    #[cfg(feature = "full")]
+    fn enter_pat(&mut self, i: &mut Pat) {}
+    #[cfg(feature = "full")]
+    fn exit_pat(&mut self, i: &mut Pat) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_box(&mut self, i: &mut PatBox) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_box(&mut self, i: &mut PatBox) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_ident(&mut self, i: &mut PatIdent) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_ident(&mut self, i: &mut PatIdent) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_lit(&mut self, i: &mut PatLit) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_lit(&mut self, i: &mut PatLit) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_macro(&mut self, i: &mut PatMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_macro(&mut self, i: &mut PatMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_or(&mut self, i: &mut PatOr) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_or(&mut self, i: &mut PatOr) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_path(&mut self, i: &mut PatPath) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_path(&mut self, i: &mut PatPath) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_range(&mut self, i: &mut PatRange) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_range(&mut self, i: &mut PatRange) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_reference(&mut self, i: &mut PatReference) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_reference(&mut self, i: &mut PatReference) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_rest(&mut self, i: &mut PatRest) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_rest(&mut self, i: &mut PatRest) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_slice(&mut self, i: &mut PatSlice) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_slice(&mut self, i: &mut PatSlice) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_struct(&mut self, i: &mut PatStruct) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_struct(&mut self, i: &mut PatStruct) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_tuple(&mut self, i: &mut PatTuple) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_tuple(&mut self, i: &mut PatTuple) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_tuple_struct(&mut self, i: &mut PatTupleStruct) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_tuple_struct(&mut self, i: &mut PatTupleStruct) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_type(&mut self, i: &mut PatType) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_type(&mut self, i: &mut PatType) {}
+    #[cfg(feature = "full")]
+    fn enter_pat_wild(&mut self, i: &mut PatWild) {}
+    #[cfg(feature = "full")]
+    fn exit_pat_wild(&mut self, i: &mut PatWild) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_path(&mut self, i: &mut Path) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_path(&mut self, i: &mut Path) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_path_arguments(&mut self, i: &mut PathArguments) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_path_arguments(&mut self, i: &mut PathArguments) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_path_segment(&mut self, i: &mut PathSegment) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_path_segment(&mut self, i: &mut PathSegment) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_predicate_eq(&mut self, i: &mut PredicateEq) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_predicate_eq(&mut self, i: &mut PredicateEq) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_predicate_lifetime(&mut self, i: &mut PredicateLifetime) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_predicate_lifetime(&mut self, i: &mut PredicateLifetime) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_predicate_type(&mut self, i: &mut PredicateType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_predicate_type(&mut self, i: &mut PredicateType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_qself(&mut self, i: &mut QSelf) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_qself(&mut self, i: &mut QSelf) {}
+    #[cfg(feature = "full")]
+    fn enter_range_limits(&mut self, i: &mut RangeLimits) {}
+    #[cfg(feature = "full")]
+    fn exit_range_limits(&mut self, i: &mut RangeLimits) {}
+    #[cfg(feature = "full")]
+    fn enter_receiver(&mut self, i: &mut Receiver) {}
+    #[cfg(feature = "full")]
+    fn exit_receiver(&mut self, i: &mut Receiver) {}
+    #[cfg(feature = "verus")]
+    fn enter_requires(&mut self, i: &mut Requires) {}
+    #[cfg(feature = "verus")]
+    fn exit_requires(&mut self, i: &mut Requires) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_return_type(&mut self, i: &mut ReturnType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_return_type(&mut self, i: &mut ReturnType) {}
+    #[cfg(feature = "full")]
+    fn enter_signature(&mut self, i: &mut Signature) {}
+    #[cfg(feature = "full")]
+    fn exit_signature(&mut self, i: &mut Signature) {}
+    fn enter_span(&mut self, i: &mut Span) {}
+    fn exit_span(&mut self, i: &mut Span) {}
+    #[cfg(feature = "full")]
+    fn enter_spec_clauses(&mut self, i: &mut SpecClauses) {}
+    #[cfg(feature = "full")]
+    fn exit_spec_clauses(&mut self, i: &mut SpecClauses) {}
+    #[cfg(feature = "full")]
+    fn enter_stmt(&mut self, i: &mut Stmt) {}
+    #[cfg(feature = "full")]
+    fn exit_stmt(&mut self, i: &mut Stmt) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_trait_bound(&mut self, i: &mut TraitBound) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_trait_bound(&mut self, i: &mut TraitBound) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_trait_bound_modifier(&mut self, i: &mut TraitBoundModifier) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_trait_bound_modifier(&mut self, i: &mut TraitBoundModifier) {}
+    #[cfg(feature = "full")]
+    fn enter_trait_item(&mut self, i: &mut TraitItem) {}
+    #[cfg(feature = "full")]
+    fn exit_trait_item(&mut self, i: &mut TraitItem) {}
+    #[cfg(feature = "full")]
+    fn enter_trait_item_const(&mut self, i: &mut TraitItemConst) {}
+    #[cfg(feature = "full")]
+    fn exit_trait_item_const(&mut self, i: &mut TraitItemConst) {}
+    #[cfg(feature = "full")]
+    fn enter_trait_item_macro(&mut self, i: &mut TraitItemMacro) {}
+    #[cfg(feature = "full")]
+    fn exit_trait_item_macro(&mut self, i: &mut TraitItemMacro) {}
+    #[cfg(feature = "full")]
+    fn enter_trait_item_method(&mut self, i: &mut TraitItemMethod) {}
+    #[cfg(feature = "full")]
+    fn exit_trait_item_method(&mut self, i: &mut TraitItemMethod) {}
+    #[cfg(feature = "full")]
+    fn enter_trait_item_type(&mut self, i: &mut TraitItemType) {}
+    #[cfg(feature = "full")]
+    fn exit_trait_item_type(&mut self, i: &mut TraitItemType) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type(&mut self, i: &mut Type) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type(&mut self, i: &mut Type) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_array(&mut self, i: &mut TypeArray) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_array(&mut self, i: &mut TypeArray) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_bare_fn(&mut self, i: &mut TypeBareFn) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_bare_fn(&mut self, i: &mut TypeBareFn) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_group(&mut self, i: &mut TypeGroup) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_group(&mut self, i: &mut TypeGroup) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_impl_trait(&mut self, i: &mut TypeImplTrait) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_impl_trait(&mut self, i: &mut TypeImplTrait) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_infer(&mut self, i: &mut TypeInfer) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_infer(&mut self, i: &mut TypeInfer) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_macro(&mut self, i: &mut TypeMacro) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_macro(&mut self, i: &mut TypeMacro) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_never(&mut self, i: &mut TypeNever) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_never(&mut self, i: &mut TypeNever) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_param(&mut self, i: &mut TypeParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_param(&mut self, i: &mut TypeParam) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_param_bound(&mut self, i: &mut TypeParamBound) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_param_bound(&mut self, i: &mut TypeParamBound) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_paren(&mut self, i: &mut TypeParen) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_paren(&mut self, i: &mut TypeParen) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_path(&mut self, i: &mut TypePath) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_path(&mut self, i: &mut TypePath) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_ptr(&mut self, i: &mut TypePtr) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_ptr(&mut self, i: &mut TypePtr) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_reference(&mut self, i: &mut TypeReference) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_reference(&mut self, i: &mut TypeReference) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_slice(&mut self, i: &mut TypeSlice) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_slice(&mut self, i: &mut TypeSlice) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_trait_object(&mut self, i: &mut TypeTraitObject) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_trait_object(&mut self, i: &mut TypeTraitObject) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_type_tuple(&mut self, i: &mut TypeTuple) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_type_tuple(&mut self, i: &mut TypeTuple) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_un_op(&mut self, i: &mut UnOp) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_un_op(&mut self, i: &mut UnOp) {}
+    #[cfg(feature = "full")]
+    fn enter_use_glob(&mut self, i: &mut UseGlob) {}
+    #[cfg(feature = "full")]
+    fn exit_use_glob(&mut self, i: &mut UseGlob) {}
+    #[cfg(feature = "full")]
+    fn enter_use_group(&mut self, i: &mut UseGroup) {}
+    #[cfg(feature = "full")]
+    fn exit_use_group(&mut self, i: &mut UseGroup) {}
+    #[cfg(feature = "full")]
+    fn enter_use_name(&mut self, i: &mut UseName) {}
+    #[cfg(feature = "full")]
+    fn exit_use_name(&mut self, i: &mut UseName) {}
+    #[cfg(feature = "full")]
+    fn enter_use_path(&mut self, i: &mut UsePath) {}
+    #[cfg(feature = "full")]
+    fn exit_use_path(&mut self, i: &mut UsePath) {}
+    #[cfg(feature = "full")]
+    fn enter_use_rename(&mut self, i: &mut UseRename) {}
+    #[cfg(feature = "full")]
+    fn exit_use_rename(&mut self, i: &mut UseRename) {}
+    #[cfg(feature = "full")]
+    fn enter_use_tree(&mut self, i: &mut UseTree) {}
+    #[cfg(feature = "full")]
+    fn exit_use_tree(&mut self, i: &mut UseTree) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_variadic(&mut self, i: &mut Variadic) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_variadic(&mut self, i: &mut Variadic) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_variant(&mut self, i: &mut Variant) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_variant(&mut self, i: &mut Variant) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_vis_crate(&mut self, i: &mut VisCrate) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_vis_crate(&mut self, i: &mut VisCrate) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_vis_public(&mut self, i: &mut VisPublic) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_vis_public(&mut self, i: &mut VisPublic) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_vis_restricted(&mut self, i: &mut VisRestricted) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_vis_restricted(&mut self, i: &mut VisRestricted) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn enter_visibility(&mut self, i: &mut Visibility) {}
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn exit_visibility(&mut self, i: &mut Visibility) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_where_clause(&mut self, i: &mut WhereClause) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_where_clause(&mut self, i: &mut WhereClause) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn enter_where_predicate(&mut self, i: &mut WherePredicate) {}
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn exit_where_predicate(&mut self, i: &mut WherePredicate) {}
+}
+/// Passes fused into a single traversal of a syntax tree.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This type is available only if Syn is built with the `"visit-mut"` feature.*
+pub struct Passes<'a> {
+    passes: Vec<&'a mut dyn Pass>,
+}
+impl<'a> Passes<'a> {
+    /// Creates an empty set of passes.
+    pub fn new() -> Self {
+        Passes { passes: Vec::new() }
+    }
+    /// Adds a pass, to run after the passes added before it.
+    pub fn with(mut self, pass: &'a mut dyn Pass) -> Self {
+        self.passes.push(pass);
+        self
+    }
+}
+impl<'a> Default for Passes<'a> {
+    fn default() -> Self {
+        Passes::new()
+    }
+}
+impl<'a> VisitMut for Passes<'a> {
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_abi_mut(&mut self, node: &mut Abi) {
+        for pass in &mut self.passes {
+            pass.enter_abi(node);
+        }
+        visit_mut::visit_abi_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_abi(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_abi_name_mut(&mut self, node: &mut AbiName) {
+        for pass in &mut self.passes {
+            pass.enter_abi_name(node);
+        }
+        visit_mut::visit_abi_name_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_abi_name(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_angle_bracketed_generic_arguments_mut(
+        &mut self,
+        node: &mut AngleBracketedGenericArguments,
+    ) {
+        for pass in &mut self.passes {
+            pass.enter_angle_bracketed_generic_arguments(node);
+        }
+        visit_mut::visit_angle_bracketed_generic_arguments_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_angle_bracketed_generic_arguments(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm_mut(&mut self, node: &mut Arm) {
+        for pass in &mut self.passes {
+            pass.enter_arm(node);
+        }
+        visit_mut::visit_arm_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_arm(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_attr_style_mut(&mut self, node: &mut AttrStyle) {
+        for pass in &mut self.passes {
+            pass.enter_attr_style(node);
+        }
+        visit_mut::visit_attr_style_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_attr_style(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_attribute_mut(&mut self, node: &mut Attribute) {
+        for pass in &mut self.passes {
+            pass.enter_attribute(node);
+        }
+        visit_mut::visit_attribute_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_attribute(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_bare_fn_arg_mut(&mut self, node: &mut BareFnArg) {
+        for pass in &mut self.passes {
+            pass.enter_bare_fn_arg(node);
+        }
+        visit_mut::visit_bare_fn_arg_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_bare_fn_arg(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_bin_op_mut(&mut self, node: &mut BinOp) {
+        for pass in &mut self.passes {
+            pass.enter_bin_op(node);
+        }
+        visit_mut::visit_bin_op_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_bin_op(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_binding_mut(&mut self, node: &mut Binding) {
+        for pass in &mut self.passes {
+            pass.enter_binding(node);
+        }
+        visit_mut::visit_binding_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_binding(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_block_mut(&mut self, node: &mut Block) {
+        for pass in &mut self.passes {
+            pass.enter_block(node);
+        }
+        visit_mut::visit_block_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_block(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_bound_lifetimes_mut(&mut self, node: &mut BoundLifetimes) {
+        for pass in &mut self.passes {
+            pass.enter_bound_lifetimes(node);
+        }
+        visit_mut::visit_bound_lifetimes_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_bound_lifetimes(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_all_mut(&mut self, node: &mut CfgAll) {
+        for pass in &mut self.passes {
+            pass.enter_cfg_all(node);
+        }
+        visit_mut::visit_cfg_all_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_cfg_all(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_any_mut(&mut self, node: &mut CfgAny) {
+        for pass in &mut self.passes {
+            pass.enter_cfg_any(node);
+        }
+        visit_mut::visit_cfg_any_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_cfg_any(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_expr_mut(&mut self, node: &mut CfgExpr) {
+        for pass in &mut self.passes {
+            pass.enter_cfg_expr(node);
+        }
+        visit_mut::visit_cfg_expr_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_cfg_expr(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_key_value_mut(&mut self, node: &mut CfgKeyValue) {
+        for pass in &mut self.passes {
+            pass.enter_cfg_key_value(node);
+        }
+        visit_mut::visit_cfg_key_value_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_cfg_key_value(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_not_mut(&mut self, node: &mut CfgNot) {
+        for pass in &mut self.passes {
+            pass.enter_cfg_not(node);
+        }
+        visit_mut::visit_cfg_not_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_cfg_not(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_const_param_mut(&mut self, node: &mut ConstParam) {
+        for pass in &mut self.passes {
+            pass.enter_const_param(node);
+        }
+        visit_mut::visit_const_param_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_const_param(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_constraint_mut(&mut self, node: &mut Constraint) {
+        for pass in &mut self.passes {
+            pass.enter_constraint(node);
+        }
+        visit_mut::visit_constraint_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_constraint(node);
+        }
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_mut(&mut self, node: &mut Data) {
+        for pass in &mut self.passes {
+            pass.enter_data(node);
+        }
+        visit_mut::visit_data_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_data(node);
+        }
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum_mut(&mut self, node: &mut DataEnum) {
+        for pass in &mut self.passes {
+            pass.enter_data_enum(node);
+        }
+        visit_mut::visit_data_enum_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_data_enum(node);
+        }
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct_mut(&mut self, node: &mut DataStruct) {
+        for pass in &mut self.passes {
+            pass.enter_data_struct(node);
+        }
+        visit_mut::visit_data_struct_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_data_struct(node);
+        }
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union_mut(&mut self, node: &mut DataUnion) {
+        for pass in &mut self.passes {
+            pass.enter_data_union(node);
+        }
+        visit_mut::visit_data_union_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_data_union(node);
+        }
+    }
+    #[cfg(feature = "verus")]
+    fn visit_decreases_mut(&mut self, node: &mut Decreases) {
+        for pass in &mut self.passes {
+            pass.enter_decreases(node);
+        }
+        visit_mut::visit_decreases_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_decreases(node);
+        }
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input_mut(&mut self, node: &mut DeriveInput) {
+        for pass in &mut self.passes {
+            pass.enter_derive_input(node);
+        }
+        visit_mut::visit_derive_input_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_derive_input(node);
+        }
+    }
+    #[cfg(feature = "verus")]
+    fn visit_ensures_mut(&mut self, node: &mut Ensures) {
+        for pass in &mut self.passes {
+            pass.enter_ensures(node);
+        }
+        visit_mut::visit_ensures_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_ensures(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        for pass in &mut self.passes {
+            pass.enter_expr(node);
+        }
+        visit_mut::visit_expr_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array_mut(&mut self, node: &mut ExprArray) {
+        for pass in &mut self.passes {
+            pass.enter_expr_array(node);
+        }
+        visit_mut::visit_expr_array_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_array(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_mut(&mut self, node: &mut ExprAssign) {
+        for pass in &mut self.passes {
+            pass.enter_expr_assign(node);
+        }
+        visit_mut::visit_expr_assign_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_assign(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op_mut(&mut self, node: &mut ExprAssignOp) {
+        for pass in &mut self.passes {
+            pass.enter_expr_assign_op(node);
+        }
+        visit_mut::visit_expr_assign_op_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_assign_op(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async_mut(&mut self, node: &mut ExprAsync) {
+        for pass in &mut self.passes {
+            pass.enter_expr_async(node);
+        }
+        visit_mut::visit_expr_async_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_async(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await_mut(&mut self, node: &mut ExprAwait) {
+        for pass in &mut self.passes {
+            pass.enter_expr_await(node);
+        }
+        visit_mut::visit_expr_await_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_await(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_binary_mut(&mut self, node: &mut ExprBinary) {
+        for pass in &mut self.passes {
+            pass.enter_expr_binary(node);
+        }
+        visit_mut::visit_expr_binary_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_binary(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block_mut(&mut self, node: &mut ExprBlock) {
+        for pass in &mut self.passes {
+            pass.enter_expr_block(node);
+        }
+        visit_mut::visit_expr_block_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_block(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box_mut(&mut self, node: &mut ExprBox) {
+        for pass in &mut self.passes {
+            pass.enter_expr_box(node);
+        }
+        visit_mut::visit_expr_box_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_box(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break_mut(&mut self, node: &mut ExprBreak) {
+        for pass in &mut self.passes {
+            pass.enter_expr_break(node);
+        }
+        visit_mut::visit_expr_break_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_break(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_call_mut(&mut self, node: &mut ExprCall) {
+        for pass in &mut self.passes {
+            pass.enter_expr_call(node);
+        }
+        visit_mut::visit_expr_call_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_call(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_cast_mut(&mut self, node: &mut ExprCast) {
+        for pass in &mut self.passes {
+            pass.enter_expr_cast(node);
+        }
+        visit_mut::visit_expr_cast_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_cast(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure_mut(&mut self, node: &mut ExprClosure) {
+        for pass in &mut self.passes {
+            pass.enter_expr_closure(node);
+        }
+        visit_mut::visit_expr_closure_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_closure(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue_mut(&mut self, node: &mut ExprContinue) {
+        for pass in &mut self.passes {
+            pass.enter_expr_continue(node);
+        }
+        visit_mut::visit_expr_continue_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_continue(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_field_mut(&mut self, node: &mut ExprField) {
+        for pass in &mut self.passes {
+            pass.enter_expr_field(node);
+        }
+        visit_mut::visit_expr_field_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_field(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop_mut(&mut self, node: &mut ExprForLoop) {
+        for pass in &mut self.passes {
+            pass.enter_expr_for_loop(node);
+        }
+        visit_mut::visit_expr_for_loop_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_for_loop(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group_mut(&mut self, node: &mut ExprGroup) {
+        for pass in &mut self.passes {
+            pass.enter_expr_group(node);
+        }
+        visit_mut::visit_expr_group_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_group(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if_mut(&mut self, node: &mut ExprIf) {
+        for pass in &mut self.passes {
+            pass.enter_expr_if(node);
+        }
+        visit_mut::visit_expr_if_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_if(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_index_mut(&mut self, node: &mut ExprIndex) {
+        for pass in &mut self.passes {
+            pass.enter_expr_index(node);
+        }
+        visit_mut::visit_expr_index_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_index(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let_mut(&mut self, node: &mut ExprLet) {
+        for pass in &mut self.passes {
+            pass.enter_expr_let(node);
+        }
+        visit_mut::visit_expr_let_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_let(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_lit_mut(&mut self, node: &mut ExprLit) {
+        for pass in &mut self.passes {
+            pass.enter_expr_lit(node);
+        }
+        visit_mut::visit_expr_lit_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_lit(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop_mut(&mut self, node: &mut ExprLoop) {
+        for pass in &mut self.passes {
+            pass.enter_expr_loop(node);
+        }
+        visit_mut::visit_expr_loop_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_loop(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro_mut(&mut self, node: &mut ExprMacro) {
+        for pass in &mut self.passes {
+            pass.enter_expr_macro(node);
+        }
+        visit_mut::visit_expr_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match_mut(&mut self, node: &mut ExprMatch) {
+        for pass in &mut self.passes {
+            pass.enter_expr_match(node);
+        }
+        visit_mut::visit_expr_match_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_match(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call_mut(&mut self, node: &mut ExprMethodCall) {
+        for pass in &mut self.passes {
+            pass.enter_expr_method_call(node);
+        }
+        visit_mut::visit_expr_method_call_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_method_call(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_paren_mut(&mut self, node: &mut ExprParen) {
+        for pass in &mut self.passes {
+            pass.enter_expr_paren(node);
+        }
+        visit_mut::visit_expr_paren_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_paren(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_path_mut(&mut self, node: &mut ExprPath) {
+        for pass in &mut self.passes {
+            pass.enter_expr_path(node);
+        }
+        visit_mut::visit_expr_path_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_path(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range_mut(&mut self, node: &mut ExprRange) {
+        for pass in &mut self.passes {
+            pass.enter_expr_range(node);
+        }
+        visit_mut::visit_expr_range_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_range(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference_mut(&mut self, node: &mut ExprReference) {
+        for pass in &mut self.passes {
+            pass.enter_expr_reference(node);
+        }
+        visit_mut::visit_expr_reference_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_reference(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat_mut(&mut self, node: &mut ExprRepeat) {
+        for pass in &mut self.passes {
+            pass.enter_expr_repeat(node);
+        }
+        visit_mut::visit_expr_repeat_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_repeat(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return_mut(&mut self, node: &mut ExprReturn) {
+        for pass in &mut self.passes {
+            pass.enter_expr_return(node);
+        }
+        visit_mut::visit_expr_return_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_return(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct_mut(&mut self, node: &mut ExprStruct) {
+        for pass in &mut self.passes {
+            pass.enter_expr_struct(node);
+        }
+        visit_mut::visit_expr_struct_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_struct(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_mut(&mut self, node: &mut ExprTry) {
+        for pass in &mut self.passes {
+            pass.enter_expr_try(node);
+        }
+        visit_mut::visit_expr_try_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_try(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block_mut(&mut self, node: &mut ExprTryBlock) {
+        for pass in &mut self.passes {
+            pass.enter_expr_try_block(node);
+        }
+        visit_mut::visit_expr_try_block_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_try_block(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple_mut(&mut self, node: &mut ExprTuple) {
+        for pass in &mut self.passes {
+            pass.enter_expr_tuple(node);
+        }
+        visit_mut::visit_expr_tuple_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_tuple(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type_mut(&mut self, node: &mut ExprType) {
+        for pass in &mut self.passes {
+            pass.enter_expr_type(node);
+        }
+        visit_mut::visit_expr_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_expr_unary_mut(&mut self, node: &mut ExprUnary) {
+        for pass in &mut self.passes {
+            pass.enter_expr_unary(node);
+        }
+        visit_mut::visit_expr_unary_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_unary(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe_mut(&mut self, node: &mut ExprUnsafe) {
+        for pass in &mut self.passes {
+            pass.enter_expr_unsafe(node);
+        }
+        visit_mut::visit_expr_unsafe_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_unsafe(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while_mut(&mut self, node: &mut ExprWhile) {
+        for pass in &mut self.passes {
+            pass.enter_expr_while(node);
+        }
+        visit_mut::visit_expr_while_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_while(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield_mut(&mut self, node: &mut ExprYield) {
+        for pass in &mut self.passes {
+            pass.enter_expr_yield(node);
+        }
+        visit_mut::visit_expr_yield_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_expr_yield(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field_mut(&mut self, node: &mut Field) {
+        for pass in &mut self.passes {
+            pass.enter_field(node);
+        }
+        visit_mut::visit_field_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_field(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat_mut(&mut self, node: &mut FieldPat) {
+        for pass in &mut self.passes {
+            pass.enter_field_pat(node);
+        }
+        visit_mut::visit_field_pat_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_field_pat(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value_mut(&mut self, node: &mut FieldValue) {
+        for pass in &mut self.passes {
+            pass.enter_field_value(node);
+        }
+        visit_mut::visit_field_value_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_field_value(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_mut(&mut self, node: &mut Fields) {
+        for pass in &mut self.passes {
+            pass.enter_fields(node);
+        }
+        visit_mut::visit_fields_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_fields(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named_mut(&mut self, node: &mut FieldsNamed) {
+        for pass in &mut self.passes {
+            pass.enter_fields_named(node);
+        }
+        visit_mut::visit_fields_named_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_fields_named(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed_mut(&mut self, node: &mut FieldsUnnamed) {
+        for pass in &mut self.passes {
+            pass.enter_fields_unnamed(node);
+        }
+        visit_mut::visit_fields_unnamed_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_fields_unnamed(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_file_mut(&mut self, node: &mut File) {
+        for pass in &mut self.passes {
+            pass.enter_file(node);
+        }
+        visit_mut::visit_file_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_file(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg_mut(&mut self, node: &mut FnArg) {
+        for pass in &mut self.passes {
+            pass.enter_fn_arg(node);
+        }
+        visit_mut::visit_fn_arg_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_fn_arg(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_qualifiers_mut(&mut self, node: &mut FnQualifiers) {
+        for pass in &mut self.passes {
+            pass.enter_fn_qualifiers(node);
+        }
+        visit_mut::visit_fn_qualifiers_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_fn_qualifiers(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_mut(&mut self, node: &mut ForeignItem) {
+        for pass in &mut self.passes {
+            pass.enter_foreign_item(node);
+        }
+        visit_mut::visit_foreign_item_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_foreign_item(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn_mut(&mut self, node: &mut ForeignItemFn) {
+        for pass in &mut self.passes {
+            pass.enter_foreign_item_fn(node);
+        }
+        visit_mut::visit_foreign_item_fn_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_foreign_item_fn(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro_mut(&mut self, node: &mut ForeignItemMacro) {
+        for pass in &mut self.passes {
+            pass.enter_foreign_item_macro(node);
+        }
+        visit_mut::visit_foreign_item_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_foreign_item_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static_mut(&mut self, node: &mut ForeignItemStatic) {
+        for pass in &mut self.passes {
+            pass.enter_foreign_item_static(node);
+        }
+        visit_mut::visit_foreign_item_static_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_foreign_item_static(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type_mut(&mut self, node: &mut ForeignItemType) {
+        for pass in &mut self.passes {
+            pass.enter_foreign_item_type(node);
+        }
+        visit_mut::visit_foreign_item_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_foreign_item_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_generic_argument_mut(&mut self, node: &mut GenericArgument) {
+        for pass in &mut self.passes {
+            pass.enter_generic_argument(node);
+        }
+        visit_mut::visit_generic_argument_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_generic_argument(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument_mut(&mut self, node: &mut GenericMethodArgument) {
+        for pass in &mut self.passes {
+            pass.enter_generic_method_argument(node);
+        }
+        visit_mut::visit_generic_method_argument_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_generic_method_argument(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_generic_param_mut(&mut self, node: &mut GenericParam) {
+        for pass in &mut self.passes {
+            pass.enter_generic_param(node);
+        }
+        visit_mut::visit_generic_param_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_generic_param(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_generics_mut(&mut self, node: &mut Generics) {
+        for pass in &mut self.passes {
+            pass.enter_generics(node);
+        }
+        visit_mut::visit_generics_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_generics(node);
+        }
+    }
+    fn visit_ident_mut(&mut self, node: &mut Ident) {
+        for pass in &mut self.passes {
+            pass.enter_ident(node);
+        }
+        visit_mut::visit_ident_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_ident(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_mut(&mut self, node: &mut ImplItem) {
+        for pass in &mut self.passes {
+            pass.enter_impl_item(node);
+        }
+        visit_mut::visit_impl_item_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_impl_item(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const_mut(&mut self, node: &mut ImplItemConst) {
+        for pass in &mut self.passes {
+            pass.enter_impl_item_const(node);
+        }
+        visit_mut::visit_impl_item_const_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_impl_item_const(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro_mut(&mut self, node: &mut ImplItemMacro) {
+        for pass in &mut self.passes {
+            pass.enter_impl_item_macro(node);
+        }
+        visit_mut::visit_impl_item_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_impl_item_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method_mut(&mut self, node: &mut ImplItemMethod) {
+        for pass in &mut self.passes {
+            pass.enter_impl_item_method(node);
+        }
+        visit_mut::visit_impl_item_method_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_impl_item_method(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type_mut(&mut self, node: &mut ImplItemType) {
+        for pass in &mut self.passes {
+            pass.enter_impl_item_type(node);
+        }
+        visit_mut::visit_impl_item_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_impl_item_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_index_mut(&mut self, node: &mut Index) {
+        for pass in &mut self.passes {
+            pass.enter_index(node);
+        }
+        visit_mut::visit_index_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_index(node);
+        }
+    }
+    #[cfg(feature = "verus")]
+    fn visit_invariant_mut(&mut self, node: &mut Invariant) {
+        for pass in &mut self.passes {
+            pass.enter_invariant(node);
+        }
+        visit_mut::visit_invariant_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_invariant(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mut(&mut self, node: &mut Item) {
+        for pass in &mut self.passes {
+            pass.enter_item(node);
+        }
+        visit_mut::visit_item_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const_mut(&mut self, node: &mut ItemConst) {
+        for pass in &mut self.passes {
+            pass.enter_item_const(node);
+        }
+        visit_mut::visit_item_const_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_const(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum_mut(&mut self, node: &mut ItemEnum) {
+        for pass in &mut self.passes {
+            pass.enter_item_enum(node);
+        }
+        visit_mut::visit_item_enum_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_enum(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate_mut(&mut self, node: &mut ItemExternCrate) {
+        for pass in &mut self.passes {
+            pass.enter_item_extern_crate(node);
+        }
+        visit_mut::visit_item_extern_crate_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_extern_crate(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn_mut(&mut self, node: &mut ItemFn) {
+        for pass in &mut self.passes {
+            pass.enter_item_fn(node);
+        }
+        visit_mut::visit_item_fn_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_fn(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod_mut(&mut self, node: &mut ItemForeignMod) {
+        for pass in &mut self.passes {
+            pass.enter_item_foreign_mod(node);
+        }
+        visit_mut::visit_item_foreign_mod_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_foreign_mod(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        for pass in &mut self.passes {
+            pass.enter_item_impl(node);
+        }
+        visit_mut::visit_item_impl_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_impl(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro_mut(&mut self, node: &mut ItemMacro) {
+        for pass in &mut self.passes {
+            pass.enter_item_macro(node);
+        }
+        visit_mut::visit_item_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2_mut(&mut self, node: &mut ItemMacro2) {
+        for pass in &mut self.passes {
+            pass.enter_item_macro2(node);
+        }
+        visit_mut::visit_item_macro2_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_macro2(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        for pass in &mut self.passes {
+            pass.enter_item_mod(node);
+        }
+        visit_mut::visit_item_mod_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_mod(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static_mut(&mut self, node: &mut ItemStatic) {
+        for pass in &mut self.passes {
+            pass.enter_item_static(node);
+        }
+        visit_mut::visit_item_static_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_static(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct_mut(&mut self, node: &mut ItemStruct) {
+        for pass in &mut self.passes {
+            pass.enter_item_struct(node);
+        }
+        visit_mut::visit_item_struct_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_struct(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_mut(&mut self, node: &mut ItemTrait) {
+        for pass in &mut self.passes {
+            pass.enter_item_trait(node);
+        }
+        visit_mut::visit_item_trait_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_trait(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias_mut(&mut self, node: &mut ItemTraitAlias) {
+        for pass in &mut self.passes {
+            pass.enter_item_trait_alias(node);
+        }
+        visit_mut::visit_item_trait_alias_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_trait_alias(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type_mut(&mut self, node: &mut ItemType) {
+        for pass in &mut self.passes {
+            pass.enter_item_type(node);
+        }
+        visit_mut::visit_item_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_type(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union_mut(&mut self, node: &mut ItemUnion) {
+        for pass in &mut self.passes {
+            pass.enter_item_union(node);
+        }
+        visit_mut::visit_item_union_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_union(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use_mut(&mut self, node: &mut ItemUse) {
+        for pass in &mut self.passes {
+            pass.enter_item_use(node);
+        }
+        visit_mut::visit_item_use_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_item_use(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_label_mut(&mut self, node: &mut Label) {
+        for pass in &mut self.passes {
+            pass.enter_label(node);
+        }
+        visit_mut::visit_label_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_label(node);
+        }
+    }
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) {
+        for pass in &mut self.passes {
+            pass.enter_lifetime(node);
+        }
+        visit_mut::visit_lifetime_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lifetime(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_lifetime_def_mut(&mut self, node: &mut LifetimeDef) {
+        for pass in &mut self.passes {
+            pass.enter_lifetime_def(node);
+        }
+        visit_mut::visit_lifetime_def_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lifetime_def(node);
+        }
+    }
+    fn visit_lit_mut(&mut self, node: &mut Lit) {
+        for pass in &mut self.passes {
+            pass.enter_lit(node);
+        }
+        visit_mut::visit_lit_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit(node);
+        }
+    }
+    fn visit_lit_bool_mut(&mut self, node: &mut LitBool) {
+        for pass in &mut self.passes {
+            pass.enter_lit_bool(node);
+        }
+        visit_mut::visit_lit_bool_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_bool(node);
+        }
+    }
+    fn visit_lit_byte_mut(&mut self, node: &mut LitByte) {
+        for pass in &mut self.passes {
+            pass.enter_lit_byte(node);
+        }
+        visit_mut::visit_lit_byte_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_byte(node);
+        }
+    }
+    fn visit_lit_byte_str_mut(&mut self, node: &mut LitByteStr) {
+        for pass in &mut self.passes {
+            pass.enter_lit_byte_str(node);
+        }
+        visit_mut::visit_lit_byte_str_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_byte_str(node);
+        }
+    }
+    fn visit_lit_char_mut(&mut self, node: &mut LitChar) {
+        for pass in &mut self.passes {
+            pass.enter_lit_char(node);
+        }
+        visit_mut::visit_lit_char_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_char(node);
+        }
+    }
+    fn visit_lit_float_mut(&mut self, node: &mut LitFloat) {
+        for pass in &mut self.passes {
+            pass.enter_lit_float(node);
+        }
+        visit_mut::visit_lit_float_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_float(node);
+        }
+    }
+    fn visit_lit_int_mut(&mut self, node: &mut LitInt) {
+        for pass in &mut self.passes {
+            pass.enter_lit_int(node);
+        }
+        visit_mut::visit_lit_int_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_int(node);
+        }
+    }
+    fn visit_lit_str_mut(&mut self, node: &mut LitStr) {
+        for pass in &mut self.passes {
+            pass.enter_lit_str(node);
+        }
+        visit_mut::visit_lit_str_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_lit_str(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_local_mut(&mut self, node: &mut Local) {
+        for pass in &mut self.passes {
+            pass.enter_local(node);
+        }
+        visit_mut::visit_local_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_local(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_macro_mut(&mut self, node: &mut Macro) {
+        for pass in &mut self.passes {
+            pass.enter_macro(node);
+        }
+        visit_mut::visit_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_macro(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_macro_delimiter_mut(&mut self, node: &mut MacroDelimiter) {
+        for pass in &mut self.passes {
+            pass.enter_macro_delimiter(node);
+        }
+        visit_mut::visit_macro_delimiter_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_macro_delimiter(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_member_mut(&mut self, node: &mut Member) {
+        for pass in &mut self.passes {
+            pass.enter_member(node);
+        }
+        visit_mut::visit_member_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_member(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_meta_mut(&mut self, node: &mut Meta) {
+        for pass in &mut self.passes {
+            pass.enter_meta(node);
+        }
+        visit_mut::visit_meta_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_meta(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_meta_list_mut(&mut self, node: &mut MetaList) {
+        for pass in &mut self.passes {
+            pass.enter_meta_list(node);
+        }
+        visit_mut::visit_meta_list_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_meta_list(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_meta_name_value_mut(&mut self, node: &mut MetaNameValue) {
+        for pass in &mut self.passes {
+            pass.enter_meta_name_value(node);
+        }
+        visit_mut::visit_meta_name_value_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_meta_name_value(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish_mut(&mut self, node: &mut MethodTurbofish) {
+        for pass in &mut self.passes {
+            pass.enter_method_turbofish(node);
+        }
+        visit_mut::visit_method_turbofish_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_method_turbofish(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_nested_meta_mut(&mut self, node: &mut NestedMeta) {
+        for pass in &mut self.passes {
+            pass.enter_nested_meta(node);
+        }
+        visit_mut::visit_nested_meta_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_nested_meta(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        node: &mut ParenthesizedGenericArguments,
+    ) {
+        for pass in &mut self.passes {
+            pass.enter_parenthesized_generic_arguments(node);
+        }
+        visit_mut::visit_parenthesized_generic_arguments_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_parenthesized_generic_arguments(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_mut(&mut self, node: &mut Pat) {
+        for pass in &mut self.passes {
+            pass.enter_pat(node);
+        }
+        visit_mut::visit_pat_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box_mut(&mut self, node: &mut PatBox) {
+        for pass in &mut self.passes {
+            pass.enter_pat_box(node);
+        }
+        visit_mut::visit_pat_box_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_box(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident_mut(&mut self, node: &mut PatIdent) {
+        for pass in &mut self.passes {
+            pass.enter_pat_ident(node);
+        }
+        visit_mut::visit_pat_ident_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_ident(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit_mut(&mut self, node: &mut PatLit) {
+        for pass in &mut self.passes {
+            pass.enter_pat_lit(node);
+        }
+        visit_mut::visit_pat_lit_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_lit(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro_mut(&mut self, node: &mut PatMacro) {
+        for pass in &mut self.passes {
+            pass.enter_pat_macro(node);
+        }
+        visit_mut::visit_pat_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or_mut(&mut self, node: &mut PatOr) {
+        for pass in &mut self.passes {
+            pass.enter_pat_or(node);
+        }
+        visit_mut::visit_pat_or_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_or(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path_mut(&mut self, node: &mut PatPath) {
+        for pass in &mut self.passes {
+            pass.enter_pat_path(node);
+        }
+        visit_mut::visit_pat_path_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_path(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range_mut(&mut self, node: &mut PatRange) {
+        for pass in &mut self.passes {
+            pass.enter_pat_range(node);
+        }
+        visit_mut::visit_pat_range_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_range(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference_mut(&mut self, node: &mut PatReference) {
+        for pass in &mut self.passes {
+            pass.enter_pat_reference(node);
+        }
+        visit_mut::visit_pat_reference_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_reference(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest_mut(&mut self, node: &mut PatRest) {
+        for pass in &mut self.passes {
+            pass.enter_pat_rest(node);
+        }
+        visit_mut::visit_pat_rest_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_rest(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice_mut(&mut self, node: &mut PatSlice) {
+        for pass in &mut self.passes {
+            pass.enter_pat_slice(node);
+        }
+        visit_mut::visit_pat_slice_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_slice(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct_mut(&mut self, node: &mut PatStruct) {
+        for pass in &mut self.passes {
+            pass.enter_pat_struct(node);
+        }
+        visit_mut::visit_pat_struct_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_struct(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_mut(&mut self, node: &mut PatTuple) {
+        for pass in &mut self.passes {
+            pass.enter_pat_tuple(node);
+        }
+        visit_mut::visit_pat_tuple_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_tuple(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct_mut(&mut self, node: &mut PatTupleStruct) {
+        for pass in &mut self.passes {
+            pass.enter_pat_tuple_struct(node);
+        }
+        visit_mut::visit_pat_tuple_struct_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_tuple_struct(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type_mut(&mut self, node: &mut PatType) {
+        for pass in &mut self.passes {
+            pass.enter_pat_type(node);
+        }
+        visit_mut::visit_pat_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_type(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild_mut(&mut self, node: &mut PatWild) {
+        for pass in &mut self.passes {
+            pass.enter_pat_wild(node);
+        }
+        visit_mut::visit_pat_wild_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_pat_wild(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        for pass in &mut self.passes {
+            pass.enter_path(node);
+        }
+        visit_mut::visit_path_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_path(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_path_arguments_mut(&mut self, node: &mut PathArguments) {
+        for pass in &mut self.passes {
+            pass.enter_path_arguments(node);
+        }
+        visit_mut::visit_path_arguments_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_path_arguments(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_path_segment_mut(&mut self, node: &mut PathSegment) {
+        for pass in &mut self.passes {
+            pass.enter_path_segment(node);
+        }
+        visit_mut::visit_path_segment_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_path_segment(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_predicate_eq_mut(&mut self, node: &mut PredicateEq) {
+        for pass in &mut self.passes {
+            pass.enter_predicate_eq(node);
+        }
+        visit_mut::visit_predicate_eq_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_predicate_eq(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_predicate_lifetime_mut(&mut self, node: &mut PredicateLifetime) {
+        for pass in &mut self.passes {
+            pass.enter_predicate_lifetime(node);
+        }
+        visit_mut::visit_predicate_lifetime_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_predicate_lifetime(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_predicate_type_mut(&mut self, node: &mut PredicateType) {
+        for pass in &mut self.passes {
+            pass.enter_predicate_type(node);
+        }
+        visit_mut::visit_predicate_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_predicate_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_qself_mut(&mut self, node: &mut QSelf) {
+        for pass in &mut self.passes {
+            pass.enter_qself(node);
+        }
+        visit_mut::visit_qself_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_qself(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits_mut(&mut self, node: &mut RangeLimits) {
+        for pass in &mut self.passes {
+            pass.enter_range_limits(node);
+        }
+        visit_mut::visit_range_limits_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_range_limits(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver_mut(&mut self, node: &mut Receiver) {
+        for pass in &mut self.passes {
+            pass.enter_receiver(node);
+        }
+        visit_mut::visit_receiver_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_receiver(node);
+        }
+    }
+    #[cfg(feature = "verus")]
+    fn visit_requires_mut(&mut self, node: &mut Requires) {
+        for pass in &mut self.passes {
+            pass.enter_requires(node);
+        }
+        visit_mut::visit_requires_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_requires(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_return_type_mut(&mut self, node: &mut ReturnType) {
+        for pass in &mut self.passes {
+            pass.enter_return_type(node);
+        }
+        visit_mut::visit_return_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_return_type(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature_mut(&mut self, node: &mut Signature) {
+        for pass in &mut self.passes {
+            pass.enter_signature(node);
+        }
+        visit_mut::visit_signature_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_signature(node);
+        }
+    }
+    fn visit_span_mut(&mut self, node: &mut Span) {
+        for pass in &mut self.passes {
+            pass.enter_span(node);
+        }
+        visit_mut::visit_span_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_span(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_spec_clauses_mut(&mut self, node: &mut SpecClauses) {
+        for pass in &mut self.passes {
+            pass.enter_spec_clauses(node);
+        }
+        visit_mut::visit_spec_clauses_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_spec_clauses(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt_mut(&mut self, node: &mut Stmt) {
+        for pass in &mut self.passes {
+            pass.enter_stmt(node);
+        }
+        visit_mut::visit_stmt_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_stmt(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_trait_bound_mut(&mut self, node: &mut TraitBound) {
+        for pass in &mut self.passes {
+            pass.enter_trait_bound(node);
+        }
+        visit_mut::visit_trait_bound_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_bound(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_trait_bound_modifier_mut(&mut self, node: &mut TraitBoundModifier) {
+        for pass in &mut self.passes {
+            pass.enter_trait_bound_modifier(node);
+        }
+        visit_mut::visit_trait_bound_modifier_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_bound_modifier(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_mut(&mut self, node: &mut TraitItem) {
+        for pass in &mut self.passes {
+            pass.enter_trait_item(node);
+        }
+        visit_mut::visit_trait_item_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_item(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const_mut(&mut self, node: &mut TraitItemConst) {
+        for pass in &mut self.passes {
+            pass.enter_trait_item_const(node);
+        }
+        visit_mut::visit_trait_item_const_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_item_const(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro_mut(&mut self, node: &mut TraitItemMacro) {
+        for pass in &mut self.passes {
+            pass.enter_trait_item_macro(node);
+        }
+        visit_mut::visit_trait_item_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_item_macro(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method_mut(&mut self, node: &mut TraitItemMethod) {
+        for pass in &mut self.passes {
+            pass.enter_trait_item_method(node);
+        }
+        visit_mut::visit_trait_item_method_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_item_method(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type_mut(&mut self, node: &mut TraitItemType) {
+        for pass in &mut self.passes {
+            pass.enter_trait_item_type(node);
+        }
+        visit_mut::visit_trait_item_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_trait_item_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        for pass in &mut self.passes {
+            pass.enter_type(node);
+        }
+        visit_mut::visit_type_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_array_mut(&mut self, node: &mut TypeArray) {
+        for pass in &mut self.passes {
+            pass.enter_type_array(node);
+        }
+        visit_mut::visit_type_array_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_array(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_bare_fn_mut(&mut self, node: &mut TypeBareFn) {
+        for pass in &mut self.passes {
+            pass.enter_type_bare_fn(node);
+        }
+        visit_mut::visit_type_bare_fn_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_bare_fn(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_group_mut(&mut self, node: &mut TypeGroup) {
+        for pass in &mut self.passes {
+            pass.enter_type_group(node);
+        }
+        visit_mut::visit_type_group_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_group(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_impl_trait_mut(&mut self, node: &mut TypeImplTrait) {
+        for pass in &mut self.passes {
+            pass.enter_type_impl_trait(node);
+        }
+        visit_mut::visit_type_impl_trait_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_impl_trait(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_infer_mut(&mut self, node: &mut TypeInfer) {
+        for pass in &mut self.passes {
+            pass.enter_type_infer(node);
+        }
+        visit_mut::visit_type_infer_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_infer(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_macro_mut(&mut self, node: &mut TypeMacro) {
+        for pass in &mut self.passes {
+            pass.enter_type_macro(node);
+        }
+        visit_mut::visit_type_macro_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_macro(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_never_mut(&mut self, node: &mut TypeNever) {
+        for pass in &mut self.passes {
+            pass.enter_type_never(node);
+        }
+        visit_mut::visit_type_never_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_never(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_param_mut(&mut self, node: &mut TypeParam) {
+        for pass in &mut self.passes {
+            pass.enter_type_param(node);
+        }
+        visit_mut::visit_type_param_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_param(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_param_bound_mut(&mut self, node: &mut TypeParamBound) {
+        for pass in &mut self.passes {
+            pass.enter_type_param_bound(node);
+        }
+        visit_mut::visit_type_param_bound_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_param_bound(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_paren_mut(&mut self, node: &mut TypeParen) {
+        for pass in &mut self.passes {
+            pass.enter_type_paren(node);
+        }
+        visit_mut::visit_type_paren_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_paren(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_path_mut(&mut self, node: &mut TypePath) {
+        for pass in &mut self.passes {
+            pass.enter_type_path(node);
+        }
+        visit_mut::visit_type_path_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_path(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_ptr_mut(&mut self, node: &mut TypePtr) {
+        for pass in &mut self.passes {
+            pass.enter_type_ptr(node);
+        }
+        visit_mut::visit_type_ptr_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_ptr(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) {
+        for pass in &mut self.passes {
+            pass.enter_type_reference(node);
+        }
+        visit_mut::visit_type_reference_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_reference(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_slice_mut(&mut self, node: &mut TypeSlice) {
+        for pass in &mut self.passes {
+            pass.enter_type_slice(node);
+        }
+        visit_mut::visit_type_slice_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_slice(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_trait_object_mut(&mut self, node: &mut TypeTraitObject) {
+        for pass in &mut self.passes {
+            pass.enter_type_trait_object(node);
+        }
+        visit_mut::visit_type_trait_object_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_trait_object(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_type_tuple_mut(&mut self, node: &mut TypeTuple) {
+        for pass in &mut self.passes {
+            pass.enter_type_tuple(node);
+        }
+        visit_mut::visit_type_tuple_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_type_tuple(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_un_op_mut(&mut self, node: &mut UnOp) {
+        for pass in &mut self.passes {
+            pass.enter_un_op(node);
+        }
+        visit_mut::visit_un_op_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_un_op(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob_mut(&mut self, node: &mut UseGlob) {
+        for pass in &mut self.passes {
+            pass.enter_use_glob(node);
+        }
+        visit_mut::visit_use_glob_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_glob(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group_mut(&mut self, node: &mut UseGroup) {
+        for pass in &mut self.passes {
+            pass.enter_use_group(node);
+        }
+        visit_mut::visit_use_group_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_group(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name_mut(&mut self, node: &mut UseName) {
+        for pass in &mut self.passes {
+            pass.enter_use_name(node);
+        }
+        visit_mut::visit_use_name_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_name(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path_mut(&mut self, node: &mut UsePath) {
+        for pass in &mut self.passes {
+            pass.enter_use_path(node);
+        }
+        visit_mut::visit_use_path_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_path(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename_mut(&mut self, node: &mut UseRename) {
+        for pass in &mut self.passes {
+            pass.enter_use_rename(node);
+        }
+        visit_mut::visit_use_rename_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_rename(node);
+        }
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree_mut(&mut self, node: &mut UseTree) {
+        for pass in &mut self.passes {
+            pass.enter_use_tree(node);
+        }
+        visit_mut::visit_use_tree_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_use_tree(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_variadic_mut(&mut self, node: &mut Variadic) {
+        for pass in &mut self.passes {
+            pass.enter_variadic(node);
+        }
+        visit_mut::visit_variadic_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_variadic(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant_mut(&mut self, node: &mut Variant) {
+        for pass in &mut self.passes {
+            pass.enter_variant(node);
+        }
+        visit_mut::visit_variant_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_variant(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate_mut(&mut self, node: &mut VisCrate) {
+        for pass in &mut self.passes {
+            pass.enter_vis_crate(node);
+        }
+        visit_mut::visit_vis_crate_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_vis_crate(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public_mut(&mut self, node: &mut VisPublic) {
+        for pass in &mut self.passes {
+            pass.enter_vis_public(node);
+        }
+        visit_mut::visit_vis_public_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_vis_public(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted_mut(&mut self, node: &mut VisRestricted) {
+        for pass in &mut self.passes {
+            pass.enter_vis_restricted(node);
+        }
+        visit_mut::visit_vis_restricted_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_vis_restricted(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility_mut(&mut self, node: &mut Visibility) {
+        for pass in &mut self.passes {
+            pass.enter_visibility(node);
+        }
+        visit_mut::visit_visibility_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_visibility(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) {
+        for pass in &mut self.passes {
+            pass.enter_where_clause(node);
+        }
+        visit_mut::visit_where_clause_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_where_clause(node);
+        }
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_where_predicate_mut(&mut self, node: &mut WherePredicate) {
+        for pass in &mut self.passes {
+            pass.enter_where_predicate(node);
+        }
+        visit_mut::visit_where_predicate_mut(self, node);
+        for pass in &mut self.passes {
+            pass.exit_where_predicate(node);
+        }
+    }
+}
@@ -0,0 +1,2534 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+use crate::span::ToTokensSpanned;
+use crate::*;
+use proc_macro2::Span;
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Abi {
+    fn set_spans(&mut self, span: Span) {
+        self.extern_token.set_spans(span);
+        self.name.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for AbiName {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            AbiName::Rust(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::C(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::CUnwind(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::System(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::SystemUnwind(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Cdecl(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Stdcall(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Fastcall(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Vectorcall(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Thiscall(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Aapcs(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Win64(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Sysv64(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Efiapi(v0) => {
+                v0.set_spans(span);
+            }
+            AbiName::Other(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for AngleBracketedGenericArguments {
+    fn set_spans(&mut self, span: Span) {
+        self.colon2_token.set_spans(span);
+        self.lt_token.set_spans(span);
+        self.args.set_spans(span);
+        self.gt_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Arm {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.pat.set_spans(span);
+        self.guard.set_spans(span);
+        self.fat_arrow_token.set_spans(span);
+        self.body.set_spans(span);
+        self.comma.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for AttrStyle {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            AttrStyle::Outer => {}
+            AttrStyle::Inner(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Attribute {
+    fn set_spans(&mut self, span: Span) {
+        self.pound_token.set_spans(span);
+        self.style.set_spans(span);
+        self.bracket_token.set_spans(span);
+        self.path.set_spans(span);
+        self.tokens.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for BareFnArg {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.name.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for BinOp {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            BinOp::Add(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Sub(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Mul(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Div(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Rem(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::And(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Or(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitXor(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitAnd(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitOr(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Shl(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Shr(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Eq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Lt(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Le(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Ne(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Ge(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::Gt(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::AddEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::SubEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::MulEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::DivEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::RemEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitXorEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitAndEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::BitOrEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::ShlEq(v0) => {
+                v0.set_spans(span);
+            }
+            BinOp::ShrEq(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Binding {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Block {
+    fn set_spans(&mut self, span: Span) {
+        self.brace_token.set_spans(span);
+        self.stmts.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for BoundLifetimes {
+    fn set_spans(&mut self, span: Span) {
+        self.for_token.set_spans(span);
+        self.lt_token.set_spans(span);
+        self.lifetimes.set_spans(span);
+        self.gt_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for CfgAll {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.predicates.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for CfgAny {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.predicates.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for CfgExpr {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            CfgExpr::Key(v0) => {
+                v0.set_spans(span);
+            }
+            CfgExpr::KeyValue(v0) => {
+                v0.set_spans(span);
+            }
+            CfgExpr::All(v0) => {
+                v0.set_spans(span);
+            }
+            CfgExpr::Any(v0) => {
+                v0.set_spans(span);
+            }
+            CfgExpr::Not(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for CfgKeyValue {
+    fn set_spans(&mut self, span: Span) {
+        self.key.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.value.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for CfgNot {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.predicate.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ConstParam {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.const_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.default.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Constraint {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Data {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Data::Struct(v0) => {
+                v0.set_spans(span);
+            }
+            Data::Enum(v0) => {
+                v0.set_spans(span);
+            }
+            Data::Union(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for DataEnum {
+    fn set_spans(&mut self, span: Span) {
+        self.enum_token.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.variants.set_spans(span);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for DataStruct {
+    fn set_spans(&mut self, span: Span) {
+        self.struct_token.set_spans(span);
+        self.fields.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for DataUnion {
+    fn set_spans(&mut self, span: Span) {
+        self.union_token.set_spans(span);
+        self.fields.set_spans(span);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Decreases {
+    fn set_spans(&mut self, span: Span) {
+        self.decreases_token.set_spans(span);
+        self.exprs.set_spans(span);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for DeriveInput {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.data.set_spans(span);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Ensures {
+    fn set_spans(&mut self, span: Span) {
+        self.ensures_token.set_spans(span);
+        self.exprs.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Expr {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Assign(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::AssignOp(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Async(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Await(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Binary(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Block(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Box(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Break(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Call(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Cast(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Closure(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Continue(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Field(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::ForLoop(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Group(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::If(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Index(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Let(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Lit(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Loop(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Match(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::MethodCall(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Paren(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Path(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Range(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Reference(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Repeat(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Return(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Struct(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Try(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::TryBlock(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Tuple(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Type(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Unary(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Unsafe(v0) => {
+                v0.set_spans(span);
+            }
+            Expr::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::While(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(feature = "full")]
+            Expr::Yield(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprArray {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.bracket_token.set_spans(span);
+        self.elems.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprAssign {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.left.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.right.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprAssignOp {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.left.set_spans(span);
+        self.op.set_spans(span);
+        self.right.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprAsync {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.async_token.set_spans(span);
+        self.capture.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprAwait {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.base.set_spans(span);
+        self.dot_token.set_spans(span);
+        self.await_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprBinary {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.left.set_spans(span);
+        self.op.set_spans(span);
+        self.right.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprBlock {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.label.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprBox {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.box_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprBreak {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.break_token.set_spans(span);
+        self.label.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprCall {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.func.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.args.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprCast {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.expr.set_spans(span);
+        self.as_token.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprClosure {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.movability.set_spans(span);
+        self.asyncness.set_spans(span);
+        self.capture.set_spans(span);
+        self.or1_token.set_spans(span);
+        self.inputs.set_spans(span);
+        self.or2_token.set_spans(span);
+        self.output.set_spans(span);
+        self.body.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprContinue {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.continue_token.set_spans(span);
+        self.label.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprField {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.base.set_spans(span);
+        self.dot_token.set_spans(span);
+        self.member.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprForLoop {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.label.set_spans(span);
+        self.for_token.set_spans(span);
+        self.pat.set_spans(span);
+        self.in_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.spec.set_spans(span);
+        self.body.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprGroup {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.group_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprIf {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.if_token.set_spans(span);
+        self.cond.set_spans(span);
+        self.then_branch.set_spans(span);
+        self.else_branch.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprIndex {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.expr.set_spans(span);
+        self.bracket_token.set_spans(span);
+        self.index.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprLet {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.let_token.set_spans(span);
+        self.pat.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprLit {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.lit.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprLoop {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.label.set_spans(span);
+        self.loop_token.set_spans(span);
+        self.spec.set_spans(span);
+        self.body.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.mac.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprMatch {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.match_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.arms.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprMethodCall {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.receiver.set_spans(span);
+        self.dot_token.set_spans(span);
+        self.method.set_spans(span);
+        self.turbofish.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.args.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprParen {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprPath {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.qself.set_spans(span);
+        self.path.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprRange {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.from.set_spans(span);
+        self.limits.set_spans(span);
+        self.to.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprReference {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.and_token.set_spans(span);
+        self.mutability.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprRepeat {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.bracket_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.semi_token.set_spans(span);
+        self.len.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprReturn {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.return_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprStruct {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.path.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.fields.set_spans(span);
+        self.dot2_token.set_spans(span);
+        self.rest.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprTry {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.expr.set_spans(span);
+        self.question_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprTryBlock {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.try_token.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprTuple {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.elems.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.expr.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprUnary {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.op.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprUnsafe {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.unsafe_token.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprWhile {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.label.set_spans(span);
+        self.while_token.set_spans(span);
+        self.cond.set_spans(span);
+        self.spec.set_spans(span);
+        self.body.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ExprYield {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.yield_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Field {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FieldPat {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.member.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.pat.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FieldValue {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.member.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Fields {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Fields::Named(v0) => {
+                v0.set_spans(span);
+            }
+            Fields::Unnamed(v0) => {
+                v0.set_spans(span);
+            }
+            Fields::Unit => {}
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FieldsNamed {
+    fn set_spans(&mut self, span: Span) {
+        self.brace_token.set_spans(span);
+        self.named.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FieldsUnnamed {
+    fn set_spans(&mut self, span: Span) {
+        self.paren_token.set_spans(span);
+        self.unnamed.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for File {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.items.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FnArg {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            FnArg::Receiver(v0) => {
+                v0.set_spans(span);
+            }
+            FnArg::Typed(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for FnQualifiers {
+    fn set_spans(&mut self, span: Span) {
+        self.constness.set_spans(span);
+        self.asyncness.set_spans(span);
+        self.unsafety.set_spans(span);
+        self.abi.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ForeignItem {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            ForeignItem::Fn(v0) => {
+                v0.set_spans(span);
+            }
+            ForeignItem::Static(v0) => {
+                v0.set_spans(span);
+            }
+            ForeignItem::Type(v0) => {
+                v0.set_spans(span);
+            }
+            ForeignItem::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            ForeignItem::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ForeignItemFn {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.sig.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ForeignItemMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.mac.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ForeignItemStatic {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.static_token.set_spans(span);
+        self.mutability.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ForeignItemType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.type_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for GenericArgument {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            GenericArgument::Lifetime(v0) => {
+                v0.set_spans(span);
+            }
+            GenericArgument::Type(v0) => {
+                v0.set_spans(span);
+            }
+            GenericArgument::Binding(v0) => {
+                v0.set_spans(span);
+            }
+            GenericArgument::Constraint(v0) => {
+                v0.set_spans(span);
+            }
+            GenericArgument::Const(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for GenericMethodArgument {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            GenericMethodArgument::Type(v0) => {
+                v0.set_spans(span);
+            }
+            GenericMethodArgument::Const(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for GenericParam {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            GenericParam::Type(v0) => {
+                v0.set_spans(span);
+            }
+            GenericParam::Lifetime(v0) => {
+                v0.set_spans(span);
+            }
+            GenericParam::Const(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Generics {
+    fn set_spans(&mut self, span: Span) {
+        self.lt_token.set_spans(span);
+        self.params.set_spans(span);
+        self.gt_token.set_spans(span);
+        self.where_clause.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ImplItem {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            ImplItem::Const(v0) => {
+                v0.set_spans(span);
+            }
+            ImplItem::Method(v0) => {
+                v0.set_spans(span);
+            }
+            ImplItem::Type(v0) => {
+                v0.set_spans(span);
+            }
+            ImplItem::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            ImplItem::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ImplItemConst {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.defaultness.set_spans(span);
+        self.const_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ImplItemMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.mac.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ImplItemMethod {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.defaultness.set_spans(span);
+        self.sig.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ImplItemType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.defaultness.set_spans(span);
+        self.type_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Index {
+    fn set_spans(&mut self, span: Span) {
+        self.span.set_spans(span);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Invariant {
+    fn set_spans(&mut self, span: Span) {
+        self.invariant_token.set_spans(span);
+        self.exprs.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Item {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Item::Const(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Enum(v0) => {
+                v0.set_spans(span);
+            }
+            Item::ExternCrate(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Fn(v0) => {
+                v0.set_spans(span);
+            }
+            Item::ForeignMod(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Impl(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Macro2(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Mod(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Static(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Struct(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Trait(v0) => {
+                v0.set_spans(span);
+            }
+            Item::TraitAlias(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Type(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Union(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Use(v0) => {
+                v0.set_spans(span);
+            }
+            Item::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemConst {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.const_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemEnum {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.enum_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.variants.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemExternCrate {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.extern_token.set_spans(span);
+        self.crate_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.rename.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemFn {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.sig.set_spans(span);
+        self.block.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemForeignMod {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.abi.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.items.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemImpl {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.defaultness.set_spans(span);
+        self.unsafety.set_spans(span);
+        self.impl_token.set_spans(span);
+        self.generics.set_spans(span);
+        self.trait_.set_spans(span);
+        self.self_ty.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.items.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.ident.set_spans(span);
+        self.mac.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemMacro2 {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.macro_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.rules.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemMod {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.mod_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.content.set_spans(span);
+        self.semi.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemStatic {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.static_token.set_spans(span);
+        self.mutability.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.expr.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemStruct {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.struct_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.fields.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemTrait {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.unsafety.set_spans(span);
+        self.auto_token.set_spans(span);
+        self.trait_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.supertraits.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.items.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemTraitAlias {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.trait_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.bounds.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.type_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemUnion {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.union_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.fields.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ItemUse {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.vis.set_spans(span);
+        self.use_token.set_spans(span);
+        self.leading_colon.set_spans(span);
+        self.tree.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Label {
+    fn set_spans(&mut self, span: Span) {
+        self.name.set_spans(span);
+        self.colon_token.set_spans(span);
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Lifetime {
+    fn set_spans(&mut self, span: Span) {
+        self.apostrophe.set_spans(span);
+        self.ident.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for LifetimeDef {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.lifetime.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Lit {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Lit::Str(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::ByteStr(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Byte(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Char(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Int(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Float(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Bool(v0) => {
+                v0.set_spans(span);
+            }
+            Lit::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for LitBool {
+    fn set_spans(&mut self, span: Span) {
+        self.span.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Local {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.let_token.set_spans(span);
+        self.pat.set_spans(span);
+        self.init.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Macro {
+    fn set_spans(&mut self, span: Span) {
+        self.path.set_spans(span);
+        self.bang_token.set_spans(span);
+        self.delimiter.set_spans(span);
+        self.tokens.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for MacroDelimiter {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            MacroDelimiter::Paren(v0) => {
+                v0.set_spans(span);
+            }
+            MacroDelimiter::Brace(v0) => {
+                v0.set_spans(span);
+            }
+            MacroDelimiter::Bracket(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Member {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Member::Named(v0) => {
+                v0.set_spans(span);
+            }
+            Member::Unnamed(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Meta {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Meta::Path(v0) => {
+                v0.set_spans(span);
+            }
+            Meta::List(v0) => {
+                v0.set_spans(span);
+            }
+            Meta::NameValue(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for MetaList {
+    fn set_spans(&mut self, span: Span) {
+        self.path.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.nested.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for MetaNameValue {
+    fn set_spans(&mut self, span: Span) {
+        self.path.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.lit.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for MethodTurbofish {
+    fn set_spans(&mut self, span: Span) {
+        self.colon2_token.set_spans(span);
+        self.lt_token.set_spans(span);
+        self.args.set_spans(span);
+        self.gt_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for NestedMeta {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            NestedMeta::Meta(v0) => {
+                v0.set_spans(span);
+            }
+            NestedMeta::Lit(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ParenthesizedGenericArguments {
+    fn set_spans(&mut self, span: Span) {
+        self.paren_token.set_spans(span);
+        self.inputs.set_spans(span);
+        self.output.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Pat {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Pat::Box(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Ident(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Lit(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Or(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Path(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Range(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Reference(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Rest(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Slice(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Struct(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Tuple(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::TupleStruct(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Type(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            Pat::Wild(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatBox {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.box_token.set_spans(span);
+        self.pat.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatIdent {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.by_ref.set_spans(span);
+        self.mutability.set_spans(span);
+        self.ident.set_spans(span);
+        self.subpat.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatLit {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.expr.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.mac.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatOr {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.leading_vert.set_spans(span);
+        self.cases.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatPath {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.qself.set_spans(span);
+        self.path.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatRange {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.lo.set_spans(span);
+        self.limits.set_spans(span);
+        self.hi.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatReference {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.and_token.set_spans(span);
+        self.mutability.set_spans(span);
+        self.pat.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatRest {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.dot2_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatSlice {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.bracket_token.set_spans(span);
+        self.elems.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatStruct {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.path.set_spans(span);
+        self.brace_token.set_spans(span);
+        self.fields.set_spans(span);
+        self.dot2_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatTuple {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.elems.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatTupleStruct {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.path.set_spans(span);
+        self.pat.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.pat.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PatWild {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.underscore_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Path {
+    fn set_spans(&mut self, span: Span) {
+        self.leading_colon.set_spans(span);
+        self.segments.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PathArguments {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(v0) => {
+                v0.set_spans(span);
+            }
+            PathArguments::Parenthesized(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PathSegment {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.arguments.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PredicateEq {
+    fn set_spans(&mut self, span: Span) {
+        self.lhs_ty.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.rhs_ty.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PredicateLifetime {
+    fn set_spans(&mut self, span: Span) {
+        self.lifetime.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for PredicateType {
+    fn set_spans(&mut self, span: Span) {
+        self.lifetimes.set_spans(span);
+        self.bounded_ty.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for QSelf {
+    fn set_spans(&mut self, span: Span) {
+        self.lt_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.as_token.set_spans(span);
+        self.gt_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for RangeLimits {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            RangeLimits::HalfOpen(v0) => {
+                v0.set_spans(span);
+            }
+            RangeLimits::Closed(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Receiver {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.reference.set_spans(span);
+        self.mutability.set_spans(span);
+        self.self_token.set_spans(span);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Requires {
+    fn set_spans(&mut self, span: Span) {
+        self.requires_token.set_spans(span);
+        self.exprs.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for ReturnType {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            ReturnType::Default => {}
+            ReturnType::Type(v0, v1) => {
+                v0.set_spans(span);
+                v1.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Signature {
+    fn set_spans(&mut self, span: Span) {
+        self.constness.set_spans(span);
+        self.asyncness.set_spans(span);
+        self.unsafety.set_spans(span);
+        self.abi.set_spans(span);
+        self.fn_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.inputs.set_spans(span);
+        self.variadic.set_spans(span);
+        self.output.set_spans(span);
+        self.spec.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for SpecClauses {
+    fn set_spans(&mut self, span: Span) {
+        #[cfg(feature = "verus")] self.requires.set_spans(span);
+        #[cfg(feature = "verus")] self.ensures.set_spans(span);
+        #[cfg(feature = "verus")] self.invariant.set_spans(span);
+        #[cfg(feature = "verus")] self.decreases.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Stmt {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Stmt::Local(v0) => {
+                v0.set_spans(span);
+            }
+            Stmt::Item(v0) => {
+                v0.set_spans(span);
+            }
+            Stmt::Expr(v0) => {
+                v0.set_spans(span);
+            }
+            Stmt::Semi(v0, v1) => {
+                v0.set_spans(span);
+                v1.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitBound {
+    fn set_spans(&mut self, span: Span) {
+        self.paren_token.set_spans(span);
+        self.modifier.set_spans(span);
+        self.lifetimes.set_spans(span);
+        self.path.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitBoundModifier {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            TraitBoundModifier::None => {}
+            TraitBoundModifier::Maybe(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitItem {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            TraitItem::Const(v0) => {
+                v0.set_spans(span);
+            }
+            TraitItem::Method(v0) => {
+                v0.set_spans(span);
+            }
+            TraitItem::Type(v0) => {
+                v0.set_spans(span);
+            }
+            TraitItem::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            TraitItem::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitItemConst {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.const_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.ty.set_spans(span);
+        self.default.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitItemMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.mac.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitItemMethod {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.sig.set_spans(span);
+        self.default.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TraitItemType {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.type_token.set_spans(span);
+        self.ident.set_spans(span);
+        self.generics.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+        self.default.set_spans(span);
+        self.semi_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Type {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Type::Array(v0) => {
+                v0.set_spans(span);
+            }
+            Type::BareFn(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Group(v0) => {
+                v0.set_spans(span);
+            }
+            Type::ImplTrait(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Infer(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Macro(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Never(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Paren(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Path(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Ptr(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Reference(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Slice(v0) => {
+                v0.set_spans(span);
+            }
+            Type::TraitObject(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Tuple(v0) => {
+                v0.set_spans(span);
+            }
+            Type::Verbatim(v0) => {
+                v0.set_spans(span);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeArray {
+    fn set_spans(&mut self, span: Span) {
+        self.bracket_token.set_spans(span);
+        self.elem.set_spans(span);
+        self.semi_token.set_spans(span);
+        self.len.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeBareFn {
+    fn set_spans(&mut self, span: Span) {
+        self.lifetimes.set_spans(span);
+        self.unsafety.set_spans(span);
+        self.abi.set_spans(span);
+        self.fn_token.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.inputs.set_spans(span);
+        self.variadic.set_spans(span);
+        self.output.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeGroup {
+    fn set_spans(&mut self, span: Span) {
+        self.group_token.set_spans(span);
+        self.elem.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeImplTrait {
+    fn set_spans(&mut self, span: Span) {
+        self.impl_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeInfer {
+    fn set_spans(&mut self, span: Span) {
+        self.underscore_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeMacro {
+    fn set_spans(&mut self, span: Span) {
+        self.mac.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeNever {
+    fn set_spans(&mut self, span: Span) {
+        self.bang_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeParam {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.ident.set_spans(span);
+        self.colon_token.set_spans(span);
+        self.bounds.set_spans(span);
+        self.eq_token.set_spans(span);
+        self.default.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeParamBound {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            TypeParamBound::Trait(v0) => {
+                v0.set_spans(span);
+            }
+            TypeParamBound::Lifetime(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeParen {
+    fn set_spans(&mut self, span: Span) {
+        self.paren_token.set_spans(span);
+        self.elem.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypePath {
+    fn set_spans(&mut self, span: Span) {
+        self.qself.set_spans(span);
+        self.path.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypePtr {
+    fn set_spans(&mut self, span: Span) {
+        self.star_token.set_spans(span);
+        self.const_token.set_spans(span);
+        self.mutability.set_spans(span);
+        self.elem.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeReference {
+    fn set_spans(&mut self, span: Span) {
+        self.and_token.set_spans(span);
+        self.lifetime.set_spans(span);
+        self.mutability.set_spans(span);
+        self.elem.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeSlice {
+    fn set_spans(&mut self, span: Span) {
+        self.bracket_token.set_spans(span);
+        self.elem.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeTraitObject {
+    fn set_spans(&mut self, span: Span) {
+        self.dyn_token.set_spans(span);
+        self.bounds.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for TypeTuple {
+    fn set_spans(&mut self, span: Span) {
+        self.paren_token.set_spans(span);
+        self.elems.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UnOp {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            UnOp::Deref(v0) => {
+                v0.set_spans(span);
+            }
+            UnOp::Not(v0) => {
+                v0.set_spans(span);
+            }
+            UnOp::Neg(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UseGlob {
+    fn set_spans(&mut self, span: Span) {
+        self.star_token.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UseGroup {
+    fn set_spans(&mut self, span: Span) {
+        self.brace_token.set_spans(span);
+        self.items.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UseName {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UsePath {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.colon2_token.set_spans(span);
+        self.tree.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UseRename {
+    fn set_spans(&mut self, span: Span) {
+        self.ident.set_spans(span);
+        self.as_token.set_spans(span);
+        self.rename.set_spans(span);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for UseTree {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            UseTree::Path(v0) => {
+                v0.set_spans(span);
+            }
+            UseTree::Name(v0) => {
+                v0.set_spans(span);
+            }
+            UseTree::Rename(v0) => {
+                v0.set_spans(span);
+            }
+            UseTree::Glob(v0) => {
+                v0.set_spans(span);
+            }
+            UseTree::Group(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Variadic {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.dots.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Variant {
+    fn set_spans(&mut self, span: Span) {
+        self.attrs.set_spans(span);
+        self.ident.set_spans(span);
+        self.fields.set_spans(span);
+        self.discriminant.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for VisCrate {
+    fn set_spans(&mut self, span: Span) {
+        self.crate_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for VisPublic {
+    fn set_spans(&mut self, span: Span) {
+        self.pub_token.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for VisRestricted {
+    fn set_spans(&mut self, span: Span) {
+        self.pub_token.set_spans(span);
+        self.paren_token.set_spans(span);
+        self.in_token.set_spans(span);
+        self.path.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for Visibility {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            Visibility::Public(v0) => {
+                v0.set_spans(span);
+            }
+            Visibility::Crate(v0) => {
+                v0.set_spans(span);
+            }
+            Visibility::Restricted(v0) => {
+                v0.set_spans(span);
+            }
+            Visibility::Inherited => {}
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for WhereClause {
+    fn set_spans(&mut self, span: Span) {
+        self.where_token.set_spans(span);
+        self.predicates.set_spans(span);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+impl ToTokensSpanned for WherePredicate {
+    fn set_spans(&mut self, span: Span) {
+        match self {
+            WherePredicate::Type(v0) => {
+                v0.set_spans(span);
+            }
+            WherePredicate::Lifetime(v0) => {
+                v0.set_spans(span);
+            }
+            WherePredicate::Eq(v0) => {
+                v0.set_spans(span);
+            }
+        }
+    }
+}
@@ -0,0 +1,21463 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(clippy::match_single_binding, clippy::too_many_lines)]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+use crate::punctuated::Punctuated;
+use crate::serde_helper::*;
+use crate::*;
+use ::serde::de::{self, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess};
+use ::serde::ser::SerializeStruct;
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+use ::serde::ser::SerializeTupleVariant;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use proc_macro2::Span;
+use std::fmt;
+use std::result::Result;
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Abi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Abi", 1usize)?;
+        state.serialize_field("name", &Wrap(&self.name))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Abi;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Abi")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Option<LitStr>>(&mut map, &mut name, "name")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name: name.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Abi", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Abi {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Abi {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AbiName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AbiName::Rust(v0) => {
+                serializer.serialize_newtype_variant("AbiName", 0u32, "Rust", &Wrap(v0))
+            }
+            AbiName::C(v0) => {
+                serializer.serialize_newtype_variant("AbiName", 1u32, "C", &Wrap(v0))
+            }
+            AbiName::CUnwind(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 2u32, "CUnwind", &Wrap(v0))
+            }
+            AbiName::System(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 3u32, "System", &Wrap(v0))
+            }
+            AbiName::SystemUnwind(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "AbiName",
+                        4u32,
+                        "SystemUnwind",
+                        &Wrap(v0),
+                    )
+            }
+            AbiName::Cdecl(v0) => {
+                serializer.serialize_newtype_variant("AbiName", 5u32, "Cdecl", &Wrap(v0))
+            }
+            AbiName::Stdcall(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 6u32, "Stdcall", &Wrap(v0))
+            }
+            AbiName::Fastcall(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 7u32, "Fastcall", &Wrap(v0))
+            }
+            AbiName::Vectorcall(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 8u32, "Vectorcall", &Wrap(v0))
+            }
+            AbiName::Thiscall(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 9u32, "Thiscall", &Wrap(v0))
+            }
+            AbiName::Aapcs(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 10u32, "Aapcs", &Wrap(v0))
+            }
+            AbiName::Win64(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 11u32, "Win64", &Wrap(v0))
+            }
+            AbiName::Sysv64(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 12u32, "Sysv64", &Wrap(v0))
+            }
+            AbiName::Efiapi(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 13u32, "Efiapi", &Wrap(v0))
+            }
+            AbiName::Other(v0) => {
+                serializer
+                    .serialize_newtype_variant("AbiName", 14u32, "Other", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AbiName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Rust",
+            "C",
+            "CUnwind",
+            "System",
+            "SystemUnwind",
+            "Cdecl",
+            "Stdcall",
+            "Fastcall",
+            "Vectorcall",
+            "Thiscall",
+            "Aapcs",
+            "Win64",
+            "Sysv64",
+            "Efiapi",
+            "Other",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AbiName;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AbiName")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Rust(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::C(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::CUnwind(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::System(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::SystemUnwind(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Cdecl(v0))
+                    }
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Stdcall(v0))
+                    }
+                    Some(7usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Fastcall(v0))
+                    }
+                    Some(8usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Vectorcall(v0))
+                    }
+                    Some(9usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Thiscall(v0))
+                    }
+                    Some(10usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Aapcs(v0))
+                    }
+                    Some(11usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Win64(v0))
+                    }
+                    Some(12usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Sysv64(v0))
+                    }
+                    Some(13usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Efiapi(v0))
+                    }
+                    Some(14usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(AbiName::Other(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AbiName", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for AbiName {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for AbiName {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AngleBracketedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer
+            .serialize_struct("AngleBracketedGenericArguments", 3usize)?;
+        state.serialize_field("colon2_token", &self.colon2_token.is_some())?;
+        state.serialize_field("args", &Wrap(&self.args))?;
+        state.serialize_field("args_trailing", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AngleBracketedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["colon2_token", "args", "args_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AngleBracketedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AngleBracketedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token: flag(
+                        next_element::<_, bool>(&mut seq, 0usize, &self)?,
+                    ),
+                    lt_token: Default::default(),
+                    args: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut colon2_token = None;
+                let mut args = None;
+                let mut args_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon2_token, "colon2_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<GenericArgument, token::Comma>,
+                            >(&mut map, &mut args, "args")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut args_trailing, "args_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token: flag(colon2_token.unwrap_or(false)),
+                    lt_token: Default::default(),
+                    args: trailing(
+                        required::<_, A::Error>(args, "args")?,
+                        args_trailing.unwrap_or(false),
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer
+            .deserialize_struct("AngleBracketedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for AngleBracketedGenericArguments {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for AngleBracketedGenericArguments {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Arm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.serialize_field("guard", &Wrap(&self.guard))?;
+        state.serialize_field("body", &Wrap(&self.body))?;
+        state.serialize_field("comma", &self.comma.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Arm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "guard", "body", "comma"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Arm;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Arm")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Arm {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    pat: next_element(&mut seq, 1usize, &self)?,
+                    guard: next_element(&mut seq, 2usize, &self)?,
+                    fat_arrow_token: Default::default(),
+                    body: next_element(&mut seq, 3usize, &self)?,
+                    comma: flag(next_element::<_, bool>(&mut seq, 4usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut guard = None;
+                let mut body = None;
+                let mut comma = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, Pat>(&mut map, &mut pat, "pat")?,
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::If, Box<Expr>)>,
+                            >(&mut map, &mut guard, "guard")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut body, "body")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, bool>(&mut map, &mut comma, "comma")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Arm {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                    guard: guard.unwrap_or(None),
+                    fat_arrow_token: Default::default(),
+                    body: required::<_, A::Error>(body, "body")?,
+                    comma: flag(comma.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Arm", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Arm {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Arm {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AttrStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AttrStyle::Outer => {
+                serializer.serialize_unit_variant("AttrStyle", 0u32, "Outer")
+            }
+            AttrStyle::Inner(_) => {
+                serializer.serialize_unit_variant("AttrStyle", 1u32, "Inner")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AttrStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Outer", "Inner"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AttrStyle;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AttrStyle")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(AttrStyle::Outer)
+                    }
+                    Some(1usize) => {
+                        variant.unit_variant()?;
+                        Ok(AttrStyle::Inner(Default::default()))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AttrStyle", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for AttrStyle {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for AttrStyle {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Attribute", 3usize)?;
+        state.serialize_field("style", &Wrap(&self.style))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("tokens", &Wrap(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["style", "path", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Attribute;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Attribute")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style: next_element(&mut seq, 0usize, &self)?,
+                    bracket_token: Default::default(),
+                    path: next_element(&mut seq, 1usize, &self)?,
+                    tokens: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut style = None;
+                let mut path = None;
+                let mut tokens = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, AttrStyle>(&mut map, &mut style, "style")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::TokenStream,
+                            >(&mut map, &mut tokens, "tokens")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style: required::<_, A::Error>(style, "style")?,
+                    bracket_token: Default::default(),
+                    path: required::<_, A::Error>(path, "path")?,
+                    tokens: required::<_, A::Error>(tokens, "tokens")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Attribute", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Attribute {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Attribute {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BareFnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BareFnArg", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("name", &Wrap(&self.name))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BareFnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "name", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BareFnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BareFnArg")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(BareFnArg {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    name: next_element(&mut seq, 1usize, &self)?,
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut name = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<(proc_macro2::Ident, token::Colon)>,
+                            >(&mut map, &mut name, "name")?
+                        }
+                        Some(2usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BareFnArg {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    name: name.unwrap_or(None),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("BareFnArg", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for BareFnArg {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for BareFnArg {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BinOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BinOp::Add(_) => serializer.serialize_unit_variant("BinOp", 0u32, "Add"),
+            BinOp::Sub(_) => serializer.serialize_unit_variant("BinOp", 1u32, "Sub"),
+            BinOp::Mul(_) => serializer.serialize_unit_variant("BinOp", 2u32, "Mul"),
+            BinOp::Div(_) => serializer.serialize_unit_variant("BinOp", 3u32, "Div"),
+            BinOp::Rem(_) => serializer.serialize_unit_variant("BinOp", 4u32, "Rem"),
+            BinOp::And(_) => serializer.serialize_unit_variant("BinOp", 5u32, "And"),
+            BinOp::Or(_) => serializer.serialize_unit_variant("BinOp", 6u32, "Or"),
+            BinOp::BitXor(_) => {
+                serializer.serialize_unit_variant("BinOp", 7u32, "BitXor")
+            }
+            BinOp::BitAnd(_) => {
+                serializer.serialize_unit_variant("BinOp", 8u32, "BitAnd")
+            }
+            BinOp::BitOr(_) => serializer.serialize_unit_variant("BinOp", 9u32, "BitOr"),
+            BinOp::Shl(_) => serializer.serialize_unit_variant("BinOp", 10u32, "Shl"),
+            BinOp::Shr(_) => serializer.serialize_unit_variant("BinOp", 11u32, "Shr"),
+            BinOp::Eq(_) => serializer.serialize_unit_variant("BinOp", 12u32, "Eq"),
+            BinOp::Lt(_) => serializer.serialize_unit_variant("BinOp", 13u32, "Lt"),
+            BinOp::Le(_) => serializer.serialize_unit_variant("BinOp", 14u32, "Le"),
+            BinOp::Ne(_) => serializer.serialize_unit_variant("BinOp", 15u32, "Ne"),
+            BinOp::Ge(_) => serializer.serialize_unit_variant("BinOp", 16u32, "Ge"),
+            BinOp::Gt(_) => serializer.serialize_unit_variant("BinOp", 17u32, "Gt"),
+            BinOp::AddEq(_) => serializer.serialize_unit_variant("BinOp", 18u32, "AddEq"),
+            BinOp::SubEq(_) => serializer.serialize_unit_variant("BinOp", 19u32, "SubEq"),
+            BinOp::MulEq(_) => serializer.serialize_unit_variant("BinOp", 20u32, "MulEq"),
+            BinOp::DivEq(_) => serializer.serialize_unit_variant("BinOp", 21u32, "DivEq"),
+            BinOp::RemEq(_) => serializer.serialize_unit_variant("BinOp", 22u32, "RemEq"),
+            BinOp::BitXorEq(_) => {
+                serializer.serialize_unit_variant("BinOp", 23u32, "BitXorEq")
+            }
+            BinOp::BitAndEq(_) => {
+                serializer.serialize_unit_variant("BinOp", 24u32, "BitAndEq")
+            }
+            BinOp::BitOrEq(_) => {
+                serializer.serialize_unit_variant("BinOp", 25u32, "BitOrEq")
+            }
+            BinOp::ShlEq(_) => serializer.serialize_unit_variant("BinOp", 26u32, "ShlEq"),
+            BinOp::ShrEq(_) => serializer.serialize_unit_variant("BinOp", 27u32, "ShrEq"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BinOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Add",
+            "Sub",
+            "Mul",
+            "Div",
+            "Rem",
+            "And",
+            "Or",
+            "BitXor",
+            "BitAnd",
+            "BitOr",
+            "Shl",
+            "Shr",
+            "Eq",
+            "Lt",
+            "Le",
+            "Ne",
+            "Ge",
+            "Gt",
+            "AddEq",
+            "SubEq",
+            "MulEq",
+            "DivEq",
+            "RemEq",
+            "BitXorEq",
+            "BitAndEq",
+            "BitOrEq",
+            "ShlEq",
+            "ShrEq",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BinOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum BinOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Add(Default::default()))
+                    }
+                    Some(1usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Sub(Default::default()))
+                    }
+                    Some(2usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Mul(Default::default()))
+                    }
+                    Some(3usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Div(Default::default()))
+                    }
+                    Some(4usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Rem(Default::default()))
+                    }
+                    Some(5usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::And(Default::default()))
+                    }
+                    Some(6usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Or(Default::default()))
+                    }
+                    Some(7usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitXor(Default::default()))
+                    }
+                    Some(8usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitAnd(Default::default()))
+                    }
+                    Some(9usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitOr(Default::default()))
+                    }
+                    Some(10usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Shl(Default::default()))
+                    }
+                    Some(11usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Shr(Default::default()))
+                    }
+                    Some(12usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Eq(Default::default()))
+                    }
+                    Some(13usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Lt(Default::default()))
+                    }
+                    Some(14usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Le(Default::default()))
+                    }
+                    Some(15usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Ne(Default::default()))
+                    }
+                    Some(16usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Ge(Default::default()))
+                    }
+                    Some(17usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::Gt(Default::default()))
+                    }
+                    Some(18usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::AddEq(Default::default()))
+                    }
+                    Some(19usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::SubEq(Default::default()))
+                    }
+                    Some(20usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::MulEq(Default::default()))
+                    }
+                    Some(21usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::DivEq(Default::default()))
+                    }
+                    Some(22usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::RemEq(Default::default()))
+                    }
+                    Some(23usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitXorEq(Default::default()))
+                    }
+                    Some(24usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitAndEq(Default::default()))
+                    }
+                    Some(25usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::BitOrEq(Default::default()))
+                    }
+                    Some(26usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::ShlEq(Default::default()))
+                    }
+                    Some(27usize) => {
+                        variant.unit_variant()?;
+                        Ok(BinOp::ShrEq(Default::default()))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("BinOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for BinOp {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for BinOp {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Binding", 2usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Binding;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Binding")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Binding {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    eq_token: Default::default(),
+                    ty: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Binding {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    eq_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Binding", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Binding {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Binding {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Block {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Block", 1usize)?;
+        state.serialize_field("stmts", &Wrap(&self.stmts))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["stmts"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Block;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Block")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut stmts = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Vec<Stmt>>(&mut map, &mut stmts, "stmts")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts: required::<_, A::Error>(stmts, "stmts")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Block", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Block {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Block {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BoundLifetimes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BoundLifetimes", 2usize)?;
+        state.serialize_field("lifetimes", &Wrap(&self.lifetimes))?;
+        state.serialize_field("lifetimes_trailing", &self.lifetimes.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BoundLifetimes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes", "lifetimes_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BoundLifetimes;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BoundLifetimes")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                let mut lifetimes_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<LifetimeDef, token::Comma>,
+                            >(&mut map, &mut lifetimes, "lifetimes")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut lifetimes_trailing, "lifetimes_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes: trailing(
+                        required::<_, A::Error>(lifetimes, "lifetimes")?,
+                        lifetimes_trailing.unwrap_or(false),
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("BoundLifetimes", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for BoundLifetimes {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for BoundLifetimes {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for CfgAll {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("CfgAll", 3usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("predicates", &Wrap(&self.predicates))?;
+        state.serialize_field("predicates_trailing", &self.predicates.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for CfgAll {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "predicates", "predicates_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = CfgAll;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct CfgAll")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(CfgAll {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    predicates: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut predicates = None;
+                let mut predicates_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<CfgExpr, token::Comma>,
+                            >(&mut map, &mut predicates, "predicates")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut predicates_trailing, "predicates_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CfgAll {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    paren_token: Default::default(),
+                    predicates: trailing(
+                        required::<_, A::Error>(predicates, "predicates")?,
+                        predicates_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("CfgAll", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for CfgAll {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for CfgAll {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for CfgAny {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("CfgAny", 3usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("predicates", &Wrap(&self.predicates))?;
+        state.serialize_field("predicates_trailing", &self.predicates.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for CfgAny {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "predicates", "predicates_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = CfgAny;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct CfgAny")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(CfgAny {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    predicates: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut predicates = None;
+                let mut predicates_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<CfgExpr, token::Comma>,
+                            >(&mut map, &mut predicates, "predicates")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut predicates_trailing, "predicates_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CfgAny {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    paren_token: Default::default(),
+                    predicates: trailing(
+                        required::<_, A::Error>(predicates, "predicates")?,
+                        predicates_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("CfgAny", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for CfgAny {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for CfgAny {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for CfgExpr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            CfgExpr::Key(v0) => {
+                serializer.serialize_newtype_variant("CfgExpr", 0u32, "Key", &Wrap(v0))
+            }
+            CfgExpr::KeyValue(v0) => {
+                serializer
+                    .serialize_newtype_variant("CfgExpr", 1u32, "KeyValue", &Wrap(v0))
+            }
+            CfgExpr::All(v0) => {
+                serializer.serialize_newtype_variant("CfgExpr", 2u32, "All", &Wrap(v0))
+            }
+            CfgExpr::Any(v0) => {
+                serializer.serialize_newtype_variant("CfgExpr", 3u32, "Any", &Wrap(v0))
+            }
+            CfgExpr::Not(v0) => {
+                serializer.serialize_newtype_variant("CfgExpr", 4u32, "Not", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for CfgExpr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Key", "KeyValue", "All", "Any", "Not"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = CfgExpr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum CfgExpr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::Ident>>()?
+                            .0;
+                        Ok(CfgExpr::Key(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<CfgKeyValue>>()?.0;
+                        Ok(CfgExpr::KeyValue(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<CfgAll>>()?.0;
+                        Ok(CfgExpr::All(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<CfgAny>>()?.0;
+                        Ok(CfgExpr::Any(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<CfgNot>>()?.0;
+                        Ok(CfgExpr::Not(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("CfgExpr", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for CfgExpr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for CfgExpr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for CfgKeyValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("CfgKeyValue", 2usize)?;
+        state.serialize_field("key", &Wrap(&self.key))?;
+        state.serialize_field("value", &Wrap(&self.value))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for CfgKeyValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["key", "value"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = CfgKeyValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct CfgKeyValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(CfgKeyValue {
+                    key: next_element(&mut seq, 0usize, &self)?,
+                    eq_token: Default::default(),
+                    value: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut key = None;
+                let mut value = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut key, "key")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, LitStr>(&mut map, &mut value, "value")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CfgKeyValue {
+                    key: required::<_, A::Error>(key, "key")?,
+                    eq_token: Default::default(),
+                    value: required::<_, A::Error>(value, "value")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("CfgKeyValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for CfgKeyValue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for CfgKeyValue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for CfgNot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("CfgNot", 2usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("predicate", &Wrap(&self.predicate))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for CfgNot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "predicate"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = CfgNot;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct CfgNot")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(CfgNot {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    predicate: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut predicate = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Box<CfgExpr>,
+                            >(&mut map, &mut predicate, "predicate")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CfgNot {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    paren_token: Default::default(),
+                    predicate: required::<_, A::Error>(predicate, "predicate")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("CfgNot", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for CfgNot {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for CfgNot {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ConstParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ConstParam", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &Wrap(&self.default))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ConstParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "eq_token", "default"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ConstParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ConstParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ConstParam {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    const_token: Default::default(),
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                    eq_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    default: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut eq_token = None;
+                let mut default = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        Some(3usize) => {
+                            next_value::<_, bool>(&mut map, &mut eq_token, "eq_token")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Option<Expr>,
+                            >(&mut map, &mut default, "default")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConstParam {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    const_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    eq_token: flag(eq_token.unwrap_or(false)),
+                    default: default.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ConstParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ConstParam {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ConstParam {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Constraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Constraint", 3usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Constraint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "bounds", "bounds_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Constraint;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Constraint")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Constraint {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Constraint {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Constraint", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Constraint {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Constraint {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Data {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Data::Struct(v0) => {
+                serializer.serialize_newtype_variant("Data", 0u32, "Struct", &Wrap(v0))
+            }
+            Data::Enum(v0) => {
+                serializer.serialize_newtype_variant("Data", 1u32, "Enum", &Wrap(v0))
+            }
+            Data::Union(v0) => {
+                serializer.serialize_newtype_variant("Data", 2u32, "Union", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Struct", "Enum", "Union"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Data;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Data")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<DataStruct>>()?.0;
+                        Ok(Data::Struct(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<DataEnum>>()?.0;
+                        Ok(Data::Enum(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<DataUnion>>()?.0;
+                        Ok(Data::Union(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Data", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Ser for Data {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> De<'de> for Data {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataEnum", 2usize)?;
+        state.serialize_field("variants", &Wrap(&self.variants))?;
+        state.serialize_field("variants_trailing", &self.variants.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["variants", "variants_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut variants = None;
+                let mut variants_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Variant, token::Comma>,
+                            >(&mut map, &mut variants, "variants")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut variants_trailing, "variants_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants: trailing(
+                        required::<_, A::Error>(variants, "variants")?,
+                        variants_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Ser for DataEnum {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> De<'de> for DataEnum {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataStruct", 2usize)?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields: next_element(&mut seq, 0usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Fields>(&mut map, &mut fields, "fields")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields: required::<_, A::Error>(fields, "fields")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Ser for DataStruct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> De<'de> for DataStruct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataUnion", 1usize)?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                FieldsNamed,
+                            >(&mut map, &mut fields, "fields")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields: required::<_, A::Error>(fields, "fields")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Ser for DataUnion {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> De<'de> for DataUnion {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Decreases {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Decreases", 2usize)?;
+        state.serialize_field("exprs", &Wrap(&self.exprs))?;
+        state.serialize_field("exprs_trailing", &self.exprs.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Decreases {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["exprs", "exprs_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Decreases;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Decreases")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Decreases {
+                    decreases_token: Default::default(),
+                    exprs: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut exprs = None;
+                let mut exprs_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut exprs, "exprs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut exprs_trailing, "exprs_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Decreases {
+                    decreases_token: Default::default(),
+                    exprs: trailing(
+                        required::<_, A::Error>(exprs, "exprs")?,
+                        exprs_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Decreases", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for Decreases {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for Decreases {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DeriveInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DeriveInput", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("data", &Wrap(&self.data))?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DeriveInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "data"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DeriveInput;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DeriveInput")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(DeriveInput {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    data: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut data = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Data>(&mut map, &mut data, "data")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DeriveInput {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    data: required::<_, A::Error>(data, "data")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DeriveInput", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Ser for DeriveInput {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> De<'de> for DeriveInput {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Ensures {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Ensures", 2usize)?;
+        state.serialize_field("exprs", &Wrap(&self.exprs))?;
+        state.serialize_field("exprs_trailing", &self.exprs.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Ensures {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["exprs", "exprs_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Ensures;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Ensures")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Ensures {
+                    ensures_token: Default::default(),
+                    exprs: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut exprs = None;
+                let mut exprs_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut exprs, "exprs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut exprs_trailing, "exprs_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Ensures {
+                    ensures_token: Default::default(),
+                    exprs: trailing(
+                        required::<_, A::Error>(exprs, "exprs")?,
+                        exprs_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Ensures", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for Ensures {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for Ensures {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Expr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(v0) => {
+                serializer.serialize_newtype_variant("Expr", 0u32, "Array", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Assign(v0) => {
+                serializer.serialize_newtype_variant("Expr", 1u32, "Assign", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::AssignOp(v0) => {
+                serializer.serialize_newtype_variant("Expr", 2u32, "AssignOp", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Async(v0) => {
+                serializer.serialize_newtype_variant("Expr", 3u32, "Async", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Await(v0) => {
+                serializer.serialize_newtype_variant("Expr", 4u32, "Await", &Wrap(v0))
+            }
+            Expr::Binary(v0) => {
+                serializer.serialize_newtype_variant("Expr", 5u32, "Binary", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Block(v0) => {
+                serializer.serialize_newtype_variant("Expr", 6u32, "Block", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Box(v0) => {
+                serializer.serialize_newtype_variant("Expr", 7u32, "Box", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Break(v0) => {
+                serializer.serialize_newtype_variant("Expr", 8u32, "Break", &Wrap(v0))
+            }
+            Expr::Call(v0) => {
+                serializer.serialize_newtype_variant("Expr", 9u32, "Call", &Wrap(v0))
+            }
+            Expr::Cast(v0) => {
+                serializer.serialize_newtype_variant("Expr", 10u32, "Cast", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Closure(v0) => {
+                serializer.serialize_newtype_variant("Expr", 11u32, "Closure", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Continue(v0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 12u32, "Continue", &Wrap(v0))
+            }
+            Expr::Field(v0) => {
+                serializer.serialize_newtype_variant("Expr", 13u32, "Field", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::ForLoop(v0) => {
+                serializer.serialize_newtype_variant("Expr", 14u32, "ForLoop", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Group(v0) => {
+                serializer.serialize_newtype_variant("Expr", 15u32, "Group", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::If(v0) => {
+                serializer.serialize_newtype_variant("Expr", 16u32, "If", &Wrap(v0))
+            }
+            Expr::Index(v0) => {
+                serializer.serialize_newtype_variant("Expr", 17u32, "Index", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Let(v0) => {
+                serializer.serialize_newtype_variant("Expr", 18u32, "Let", &Wrap(v0))
+            }
+            Expr::Lit(v0) => {
+                serializer.serialize_newtype_variant("Expr", 19u32, "Lit", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Loop(v0) => {
+                serializer.serialize_newtype_variant("Expr", 20u32, "Loop", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Macro(v0) => {
+                serializer.serialize_newtype_variant("Expr", 21u32, "Macro", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Match(v0) => {
+                serializer.serialize_newtype_variant("Expr", 22u32, "Match", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::MethodCall(v0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 23u32, "MethodCall", &Wrap(v0))
+            }
+            Expr::Paren(v0) => {
+                serializer.serialize_newtype_variant("Expr", 24u32, "Paren", &Wrap(v0))
+            }
+            Expr::Path(v0) => {
+                serializer.serialize_newtype_variant("Expr", 25u32, "Path", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Range(v0) => {
+                serializer.serialize_newtype_variant("Expr", 26u32, "Range", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Reference(v0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 27u32, "Reference", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Repeat(v0) => {
+                serializer.serialize_newtype_variant("Expr", 28u32, "Repeat", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Return(v0) => {
+                serializer.serialize_newtype_variant("Expr", 29u32, "Return", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Struct(v0) => {
+                serializer.serialize_newtype_variant("Expr", 30u32, "Struct", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Try(v0) => {
+                serializer.serialize_newtype_variant("Expr", 31u32, "Try", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::TryBlock(v0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 32u32, "TryBlock", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Tuple(v0) => {
+                serializer.serialize_newtype_variant("Expr", 33u32, "Tuple", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Type(v0) => {
+                serializer.serialize_newtype_variant("Expr", 34u32, "Type", &Wrap(v0))
+            }
+            Expr::Unary(v0) => {
+                serializer.serialize_newtype_variant("Expr", 35u32, "Unary", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Unsafe(v0) => {
+                serializer.serialize_newtype_variant("Expr", 36u32, "Unsafe", &Wrap(v0))
+            }
+            Expr::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant("Expr", 37u32, "Verbatim", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::While(v0) => {
+                serializer.serialize_newtype_variant("Expr", 38u32, "While", &Wrap(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::Yield(v0) => {
+                serializer.serialize_newtype_variant("Expr", 39u32, "Yield", &Wrap(v0))
+            }
+            #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "Assign",
+            "AssignOp",
+            "Async",
+            "Await",
+            "Binary",
+            "Block",
+            "Box",
+            "Break",
+            "Call",
+            "Cast",
+            "Closure",
+            "Continue",
+            "Field",
+            "ForLoop",
+            "Group",
+            "If",
+            "Index",
+            "Let",
+            "Lit",
+            "Loop",
+            "Macro",
+            "Match",
+            "MethodCall",
+            "Paren",
+            "Path",
+            "Range",
+            "Reference",
+            "Repeat",
+            "Return",
+            "Struct",
+            "Try",
+            "TryBlock",
+            "Tuple",
+            "Type",
+            "Unary",
+            "Unsafe",
+            "Verbatim",
+            "While",
+            "Yield",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Expr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Expr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    #[cfg(feature = "full")]
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprArray>>()?.0;
+                        Ok(Expr::Array(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprAssign>>()?.0;
+                        Ok(Expr::Assign(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprAssignOp>>()?.0;
+                        Ok(Expr::AssignOp(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprAsync>>()?.0;
+                        Ok(Expr::Async(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprAwait>>()?.0;
+                        Ok(Expr::Await(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprBinary>>()?.0;
+                        Ok(Expr::Binary(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprBlock>>()?.0;
+                        Ok(Expr::Block(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(7usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprBox>>()?.0;
+                        Ok(Expr::Box(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(8usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprBreak>>()?.0;
+                        Ok(Expr::Break(v0))
+                    }
+                    Some(9usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprCall>>()?.0;
+                        Ok(Expr::Call(v0))
+                    }
+                    Some(10usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprCast>>()?.0;
+                        Ok(Expr::Cast(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(11usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprClosure>>()?.0;
+                        Ok(Expr::Closure(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(12usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprContinue>>()?.0;
+                        Ok(Expr::Continue(v0))
+                    }
+                    Some(13usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprField>>()?.0;
+                        Ok(Expr::Field(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(14usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprForLoop>>()?.0;
+                        Ok(Expr::ForLoop(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(15usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprGroup>>()?.0;
+                        Ok(Expr::Group(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(16usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprIf>>()?.0;
+                        Ok(Expr::If(v0))
+                    }
+                    Some(17usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprIndex>>()?.0;
+                        Ok(Expr::Index(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(18usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprLet>>()?.0;
+                        Ok(Expr::Let(v0))
+                    }
+                    Some(19usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprLit>>()?.0;
+                        Ok(Expr::Lit(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(20usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprLoop>>()?.0;
+                        Ok(Expr::Loop(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(21usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprMacro>>()?.0;
+                        Ok(Expr::Macro(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(22usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprMatch>>()?.0;
+                        Ok(Expr::Match(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(23usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprMethodCall>>()?.0;
+                        Ok(Expr::MethodCall(v0))
+                    }
+                    Some(24usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprParen>>()?.0;
+                        Ok(Expr::Paren(v0))
+                    }
+                    Some(25usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprPath>>()?.0;
+                        Ok(Expr::Path(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(26usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprRange>>()?.0;
+                        Ok(Expr::Range(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(27usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprReference>>()?.0;
+                        Ok(Expr::Reference(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(28usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprRepeat>>()?.0;
+                        Ok(Expr::Repeat(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(29usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprReturn>>()?.0;
+                        Ok(Expr::Return(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(30usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprStruct>>()?.0;
+                        Ok(Expr::Struct(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(31usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprTry>>()?.0;
+                        Ok(Expr::Try(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(32usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprTryBlock>>()?.0;
+                        Ok(Expr::TryBlock(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(33usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprTuple>>()?.0;
+                        Ok(Expr::Tuple(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(34usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprType>>()?.0;
+                        Ok(Expr::Type(v0))
+                    }
+                    Some(35usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprUnary>>()?.0;
+                        Ok(Expr::Unary(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(36usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprUnsafe>>()?.0;
+                        Ok(Expr::Unsafe(v0))
+                    }
+                    Some(37usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(Expr::Verbatim(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(38usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprWhile>>()?.0;
+                        Ok(Expr::While(v0))
+                    }
+                    #[cfg(feature = "full")]
+                    Some(39usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ExprYield>>()?.0;
+                        Ok(Expr::Yield(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Expr", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Expr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Expr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprArray", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("elems", &Wrap(&self.elems))?;
+        state.serialize_field("elems_trailing", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprArray {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    bracket_token: Default::default(),
+                    elems: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                let mut elems_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut elems, "elems")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut elems_trailing, "elems_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprArray {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    bracket_token: Default::default(),
+                    elems: trailing(
+                        required::<_, A::Error>(elems, "elems")?,
+                        elems_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprArray", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprArray {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprArray {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAssign {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssign", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("left", &Wrap(&self.left))?;
+        state.serialize_field("right", &Wrap(&self.right))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAssign {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssign;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssign")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprAssign {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    left: next_element(&mut seq, 1usize, &self)?,
+                    eq_token: Default::default(),
+                    right: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut right = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut left, "left")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut right, "right")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAssign {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    left: required::<_, A::Error>(left, "left")?,
+                    eq_token: Default::default(),
+                    right: required::<_, A::Error>(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssign", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprAssign {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprAssign {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAssignOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssignOp", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("left", &Wrap(&self.left))?;
+        state.serialize_field("op", &Wrap(&self.op))?;
+        state.serialize_field("right", &Wrap(&self.right))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAssignOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssignOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssignOp")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprAssignOp {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    left: next_element(&mut seq, 1usize, &self)?,
+                    op: next_element(&mut seq, 2usize, &self)?,
+                    right: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut op = None;
+                let mut right = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut left, "left")?
+                        }
+                        Some(2usize) => next_value::<_, BinOp>(&mut map, &mut op, "op")?,
+                        Some(3usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut right, "right")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAssignOp {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    left: required::<_, A::Error>(left, "left")?,
+                    op: required::<_, A::Error>(op, "op")?,
+                    right: required::<_, A::Error>(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssignOp", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprAssignOp {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprAssignOp {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAsync {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAsync", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAsync {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "capture", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAsync;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAsync")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprAsync {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    async_token: Default::default(),
+                    capture: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    block: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut capture = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, bool>(&mut map, &mut capture, "capture")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Block>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAsync {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    async_token: Default::default(),
+                    capture: flag(capture.unwrap_or(false)),
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAsync", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprAsync {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprAsync {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAwait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAwait", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("base", &Wrap(&self.base))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAwait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAwait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAwait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprAwait {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    base: next_element(&mut seq, 1usize, &self)?,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut base = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut base, "base")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAwait {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    base: required::<_, A::Error>(base, "base")?,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAwait", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprAwait {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprAwait {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBinary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBinary", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("left", &Wrap(&self.left))?;
+        state.serialize_field("op", &Wrap(&self.op))?;
+        state.serialize_field("right", &Wrap(&self.right))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBinary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBinary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBinary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprBinary {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    left: next_element(&mut seq, 1usize, &self)?,
+                    op: next_element(&mut seq, 2usize, &self)?,
+                    right: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut op = None;
+                let mut right = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut left, "left")?
+                        }
+                        Some(2usize) => next_value::<_, BinOp>(&mut map, &mut op, "op")?,
+                        Some(3usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut right, "right")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBinary {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    left: required::<_, A::Error>(left, "left")?,
+                    op: required::<_, A::Error>(op, "op")?,
+                    right: required::<_, A::Error>(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBinary", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprBinary {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprBinary {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBlock", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprBlock {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    label: next_element(&mut seq, 1usize, &self)?,
+                    block: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Label>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Block>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBlock {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    label: label.unwrap_or(None),
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprBlock {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprBlock {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBox", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprBox {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    box_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBox {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    box_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprBox {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprBox {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBreak", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBreak {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBreak;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBreak")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprBreak {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    break_token: Default::default(),
+                    label: next_element(&mut seq, 1usize, &self)?,
+                    expr: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Lifetime>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<Expr>>,
+                            >(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBreak {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    break_token: Default::default(),
+                    label: label.unwrap_or(None),
+                    expr: expr.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBreak", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprBreak {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprBreak {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCall", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("func", &Wrap(&self.func))?;
+        state.serialize_field("args", &Wrap(&self.args))?;
+        state.serialize_field("args_trailing", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "func", "args", "args_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprCall {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    func: next_element(&mut seq, 1usize, &self)?,
+                    paren_token: Default::default(),
+                    args: trailing(
+                        next_element(&mut seq, 2usize, &self)?,
+                        next_element(&mut seq, 3usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut func = None;
+                let mut args = None;
+                let mut args_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut func, "func")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut args, "args")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut args_trailing, "args_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprCall {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    func: required::<_, A::Error>(func, "func")?,
+                    paren_token: Default::default(),
+                    args: trailing(
+                        required::<_, A::Error>(args, "args")?,
+                        args_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCall", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprCall {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprCall {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprCast {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCast", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprCast {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCast;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCast")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprCast {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    as_token: Default::default(),
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprCast {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    as_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCast", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprCast {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprCast {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprClosure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprClosure", 8usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("movability", &self.movability.is_some())?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("inputs", &Wrap(&self.inputs))?;
+        state.serialize_field("inputs_trailing", &self.inputs.trailing_punct())?;
+        state.serialize_field("output", &Wrap(&self.output))?;
+        state.serialize_field("body", &Wrap(&self.body))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprClosure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "movability",
+            "asyncness",
+            "capture",
+            "inputs",
+            "inputs_trailing",
+            "output",
+            "body",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprClosure;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprClosure")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprClosure {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    movability: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    asyncness: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    capture: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    or1_token: Default::default(),
+                    inputs: trailing(
+                        next_element(&mut seq, 4usize, &self)?,
+                        next_element(&mut seq, 5usize, &self)?,
+                    ),
+                    or2_token: Default::default(),
+                    output: next_element(&mut seq, 6usize, &self)?,
+                    body: next_element(&mut seq, 7usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut movability = None;
+                let mut asyncness = None;
+                let mut capture = None;
+                let mut inputs = None;
+                let mut inputs_trailing = None;
+                let mut output = None;
+                let mut body = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut movability, "movability")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut asyncness, "asyncness")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, bool>(&mut map, &mut capture, "capture")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Pat, token::Comma>,
+                            >(&mut map, &mut inputs, "inputs")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut inputs_trailing, "inputs_trailing")?
+                        }
+                        Some(6usize) => {
+                            next_value::<_, ReturnType>(&mut map, &mut output, "output")?
+                        }
+                        Some(7usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut body, "body")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprClosure {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    movability: flag(movability.unwrap_or(false)),
+                    asyncness: flag(asyncness.unwrap_or(false)),
+                    capture: flag(capture.unwrap_or(false)),
+                    or1_token: Default::default(),
+                    inputs: trailing(
+                        required::<_, A::Error>(inputs, "inputs")?,
+                        inputs_trailing.unwrap_or(false),
+                    ),
+                    or2_token: Default::default(),
+                    output: required::<_, A::Error>(output, "output")?,
+                    body: required::<_, A::Error>(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprClosure", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprClosure {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprClosure {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprContinue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprContinue", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprContinue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprContinue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprContinue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprContinue {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    continue_token: Default::default(),
+                    label: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Lifetime>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprContinue {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    continue_token: Default::default(),
+                    label: label.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprContinue", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprContinue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprContinue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprField", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("base", &Wrap(&self.base))?;
+        state.serialize_field("member", &Wrap(&self.member))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base", "member"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprField;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprField")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprField {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    base: next_element(&mut seq, 1usize, &self)?,
+                    dot_token: Default::default(),
+                    member: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut base = None;
+                let mut member = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut base, "base")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Member>(&mut map, &mut member, "member")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprField {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    base: required::<_, A::Error>(base, "base")?,
+                    dot_token: Default::default(),
+                    member: required::<_, A::Error>(member, "member")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprField", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprField {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprField {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprForLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprForLoop", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("spec", &Wrap(&self.spec))?;
+        state.serialize_field("body", &Wrap(&self.body))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprForLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "pat", "expr", "spec", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprForLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprForLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprForLoop {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    label: next_element(&mut seq, 1usize, &self)?,
+                    for_token: Default::default(),
+                    pat: next_element(&mut seq, 2usize, &self)?,
+                    in_token: Default::default(),
+                    expr: next_element(&mut seq, 3usize, &self)?,
+                    spec: next_element(&mut seq, 4usize, &self)?,
+                    body: next_element(&mut seq, 5usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut pat = None;
+                let mut expr = None;
+                let mut spec = None;
+                let mut body = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Label>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        Some(2usize) => next_value::<_, Pat>(&mut map, &mut pat, "pat")?,
+                        Some(3usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<SpecClauses>>,
+                            >(&mut map, &mut spec, "spec")?
+                        }
+                        Some(5usize) => {
+                            next_value::<_, Block>(&mut map, &mut body, "body")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprForLoop {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    label: label.unwrap_or(None),
+                    for_token: Default::default(),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                    in_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    spec: spec.unwrap_or(None),
+                    body: required::<_, A::Error>(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprForLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprForLoop {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprForLoop {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprGroup", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprGroup {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    group_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprGroup {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    group_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprGroup {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprGroup {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprIf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIf", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("cond", &Wrap(&self.cond))?;
+        state.serialize_field("then_branch", &Wrap(&self.then_branch))?;
+        state.serialize_field("else_branch", &Wrap(&self.else_branch))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprIf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "cond", "then_branch", "else_branch"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprIf {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    if_token: Default::default(),
+                    cond: next_element(&mut seq, 1usize, &self)?,
+                    then_branch: next_element(&mut seq, 2usize, &self)?,
+                    else_branch: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut cond = None;
+                let mut then_branch = None;
+                let mut else_branch = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut cond, "cond")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Block,
+                            >(&mut map, &mut then_branch, "then_branch")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Else, Box<Expr>)>,
+                            >(&mut map, &mut else_branch, "else_branch")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprIf {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    if_token: Default::default(),
+                    cond: required::<_, A::Error>(cond, "cond")?,
+                    then_branch: required::<_, A::Error>(then_branch, "then_branch")?,
+                    else_branch: else_branch.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIf", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprIf {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprIf {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIndex", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("index", &Wrap(&self.index))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIndex;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIndex")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprIndex {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    bracket_token: Default::default(),
+                    index: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut index = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut index, "index")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprIndex {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    bracket_token: Default::default(),
+                    index: required::<_, A::Error>(index, "index")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIndex", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprIndex {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprIndex {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLet", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLet;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLet")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprLet {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    let_token: Default::default(),
+                    pat: next_element(&mut seq, 1usize, &self)?,
+                    eq_token: Default::default(),
+                    expr: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, Pat>(&mut map, &mut pat, "pat")?,
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLet {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    let_token: Default::default(),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                    eq_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLet", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprLet {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprLet {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLit", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("lit", &Wrap(&self.lit))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprLit {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    lit: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lit = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, Lit>(&mut map, &mut lit, "lit")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLit {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    lit: required::<_, A::Error>(lit, "lit")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLit", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprLit {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprLit {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLoop", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.serialize_field("spec", &Wrap(&self.spec))?;
+        state.serialize_field("body", &Wrap(&self.body))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "spec", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprLoop {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    label: next_element(&mut seq, 1usize, &self)?,
+                    loop_token: Default::default(),
+                    spec: next_element(&mut seq, 2usize, &self)?,
+                    body: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut spec = None;
+                let mut body = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Label>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<SpecClauses>>,
+                            >(&mut map, &mut spec, "spec")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Block>(&mut map, &mut body, "body")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLoop {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    label: label.unwrap_or(None),
+                    loop_token: Default::default(),
+                    spec: spec.unwrap_or(None),
+                    body: required::<_, A::Error>(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprLoop {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprLoop {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMacro", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    mac: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMatch", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("arms", &Wrap(&self.arms))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "arms"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMatch;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMatch")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprMatch {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    match_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    brace_token: Default::default(),
+                    arms: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut arms = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Vec<Arm>>(&mut map, &mut arms, "arms")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMatch {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    match_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    brace_token: Default::default(),
+                    arms: required::<_, A::Error>(arms, "arms")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMatch", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprMatch {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprMatch {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMethodCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMethodCall", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("receiver", &Wrap(&self.receiver))?;
+        state.serialize_field("method", &Wrap(&self.method))?;
+        state.serialize_field("turbofish", &Wrap(&self.turbofish))?;
+        state.serialize_field("args", &Wrap(&self.args))?;
+        state.serialize_field("args_trailing", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMethodCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "receiver",
+            "method",
+            "turbofish",
+            "args",
+            "args_trailing",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMethodCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMethodCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprMethodCall {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    receiver: next_element(&mut seq, 1usize, &self)?,
+                    dot_token: Default::default(),
+                    method: next_element(&mut seq, 2usize, &self)?,
+                    turbofish: next_element(&mut seq, 3usize, &self)?,
+                    paren_token: Default::default(),
+                    args: trailing(
+                        next_element(&mut seq, 4usize, &self)?,
+                        next_element(&mut seq, 5usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut receiver = None;
+                let mut method = None;
+                let mut turbofish = None;
+                let mut args = None;
+                let mut args_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Box<Expr>,
+                            >(&mut map, &mut receiver, "receiver")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut method, "method")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<MethodTurbofish>,
+                            >(&mut map, &mut turbofish, "turbofish")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut args, "args")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut args_trailing, "args_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMethodCall {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    receiver: required::<_, A::Error>(receiver, "receiver")?,
+                    dot_token: Default::default(),
+                    method: required::<_, A::Error>(method, "method")?,
+                    turbofish: turbofish.unwrap_or(None),
+                    paren_token: Default::default(),
+                    args: trailing(
+                        required::<_, A::Error>(args, "args")?,
+                        args_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMethodCall", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprMethodCall {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprMethodCall {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprParen", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprParen {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprParen {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    paren_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprParen {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprParen {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprPath", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("qself", &Wrap(&self.qself))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprPath {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    qself: next_element(&mut seq, 1usize, &self)?,
+                    path: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut qself = None;
+                let mut path = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<QSelf>,
+                            >(&mut map, &mut qself, "qself")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprPath {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    qself: qself.unwrap_or(None),
+                    path: required::<_, A::Error>(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprPath", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprPath {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprPath {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRange", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("from", &Wrap(&self.from))?;
+        state.serialize_field("limits", &Wrap(&self.limits))?;
+        state.serialize_field("to", &Wrap(&self.to))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "from", "limits", "to"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprRange {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    from: next_element(&mut seq, 1usize, &self)?,
+                    limits: next_element(&mut seq, 2usize, &self)?,
+                    to: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut from = None;
+                let mut limits = None;
+                let mut to = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<Expr>>,
+                            >(&mut map, &mut from, "from")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                RangeLimits,
+                            >(&mut map, &mut limits, "limits")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Option<Box<Expr>>>(&mut map, &mut to, "to")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprRange {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    from: from.unwrap_or(None),
+                    limits: required::<_, A::Error>(limits, "limits")?,
+                    to: to.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprRange {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprRange {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReference", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprReference {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    expr: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mutability = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprReference {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprReference {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprReference {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprRepeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRepeat", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("len", &Wrap(&self.len))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprRepeat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRepeat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRepeat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprRepeat {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    bracket_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    semi_token: Default::default(),
+                    len: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut len = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut len, "len")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprRepeat {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    bracket_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    semi_token: Default::default(),
+                    len: required::<_, A::Error>(len, "len")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRepeat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprRepeat {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprRepeat {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprReturn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReturn", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprReturn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReturn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReturn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprReturn {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    return_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<Expr>>,
+                            >(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprReturn {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    return_token: Default::default(),
+                    expr: expr.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReturn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprReturn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprReturn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprStruct", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.serialize_field("fields_trailing", &self.fields.trailing_punct())?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.serialize_field("rest", &Wrap(&self.rest))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "path",
+            "fields",
+            "fields_trailing",
+            "dot2_token",
+            "rest",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprStruct {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    path: next_element(&mut seq, 1usize, &self)?,
+                    brace_token: Default::default(),
+                    fields: trailing(
+                        next_element(&mut seq, 2usize, &self)?,
+                        next_element(&mut seq, 3usize, &self)?,
+                    ),
+                    dot2_token: flag(next_element::<_, bool>(&mut seq, 4usize, &self)?),
+                    rest: next_element(&mut seq, 5usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut fields = None;
+                let mut fields_trailing = None;
+                let mut dot2_token = None;
+                let mut rest = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<FieldValue, token::Comma>,
+                            >(&mut map, &mut fields, "fields")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut fields_trailing, "fields_trailing")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut dot2_token, "dot2_token")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<Expr>>,
+                            >(&mut map, &mut rest, "rest")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprStruct {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    path: required::<_, A::Error>(path, "path")?,
+                    brace_token: Default::default(),
+                    fields: trailing(
+                        required::<_, A::Error>(fields, "fields")?,
+                        fields_trailing.unwrap_or(false),
+                    ),
+                    dot2_token: flag(dot2_token.unwrap_or(false)),
+                    rest: rest.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprStruct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprStruct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTry", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTry;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTry")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprTry {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    question_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTry {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    question_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTry", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprTry {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprTry {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTryBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTryBlock", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTryBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTryBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTryBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprTryBlock {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    try_token: Default::default(),
+                    block: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Block>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTryBlock {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    try_token: Default::default(),
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTryBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprTryBlock {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprTryBlock {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTuple", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("elems", &Wrap(&self.elems))?;
+        state.serialize_field("elems_trailing", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprTuple {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                let mut elems_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut elems, "elems")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut elems_trailing, "elems_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTuple {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        required::<_, A::Error>(elems, "elems")?,
+                        elems_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprTuple {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprTuple {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprType", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprUnary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnary", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("op", &Wrap(&self.op))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprUnary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "op", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprUnary {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    op: next_element(&mut seq, 1usize, &self)?,
+                    expr: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut op = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, UnOp>(&mut map, &mut op, "op")?,
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprUnary {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    op: required::<_, A::Error>(op, "op")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnary", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ExprUnary {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ExprUnary {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprUnsafe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnsafe", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprUnsafe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnsafe;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnsafe")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprUnsafe {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    unsafe_token: Default::default(),
+                    block: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Block>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprUnsafe {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    unsafe_token: Default::default(),
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnsafe", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprUnsafe {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprUnsafe {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprWhile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprWhile", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("label", &Wrap(&self.label))?;
+        state.serialize_field("cond", &Wrap(&self.cond))?;
+        state.serialize_field("spec", &Wrap(&self.spec))?;
+        state.serialize_field("body", &Wrap(&self.body))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprWhile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "cond", "spec", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprWhile;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprWhile")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprWhile {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    label: next_element(&mut seq, 1usize, &self)?,
+                    while_token: Default::default(),
+                    cond: next_element(&mut seq, 2usize, &self)?,
+                    spec: next_element(&mut seq, 3usize, &self)?,
+                    body: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut cond = None;
+                let mut spec = None;
+                let mut body = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Label>,
+                            >(&mut map, &mut label, "label")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut cond, "cond")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<SpecClauses>>,
+                            >(&mut map, &mut spec, "spec")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Block>(&mut map, &mut body, "body")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprWhile {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    label: label.unwrap_or(None),
+                    while_token: Default::default(),
+                    cond: required::<_, A::Error>(cond, "cond")?,
+                    spec: spec.unwrap_or(None),
+                    body: required::<_, A::Error>(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprWhile", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprWhile {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprWhile {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprYield {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprYield", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprYield {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprYield;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprYield")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExprYield {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    yield_token: Default::default(),
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<Expr>>,
+                            >(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprYield {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    yield_token: Default::default(),
+                    expr: expr.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprYield", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ExprYield {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ExprYield {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Field", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "colon_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Field")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Field {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    ty: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut colon_token = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<proc_macro2::Ident>,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(4usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Field {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    ident: ident.unwrap_or(None),
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Field", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for Field {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for Field {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldPat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldPat", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("member", &Wrap(&self.member))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldPat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldPat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldPat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(FieldPat {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    member: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    pat: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut member = None;
+                let mut colon_token = None;
+                let mut pat = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Member>(&mut map, &mut member, "member")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Box<Pat>>(&mut map, &mut pat, "pat")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldPat {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    member: required::<_, A::Error>(member, "member")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldPat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for FieldPat {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for FieldPat {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldValue", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("member", &Wrap(&self.member))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(FieldValue {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    member: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    expr: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut member = None;
+                let mut colon_token = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Member>(&mut map, &mut member, "member")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Expr>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldValue {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    member: required::<_, A::Error>(member, "member")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldValue", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for FieldValue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for FieldValue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Fields::Named(v0) => {
+                serializer.serialize_newtype_variant("Fields", 0u32, "Named", &Wrap(v0))
+            }
+            Fields::Unnamed(v0) => {
+                serializer
+                    .serialize_newtype_variant("Fields", 1u32, "Unnamed", &Wrap(v0))
+            }
+            Fields::Unit => serializer.serialize_unit_variant("Fields", 2u32, "Unit"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed", "Unit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Fields;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Fields")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<FieldsNamed>>()?.0;
+                        Ok(Fields::Named(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<FieldsUnnamed>>()?.0;
+                        Ok(Fields::Unnamed(v0))
+                    }
+                    Some(2usize) => {
+                        variant.unit_variant()?;
+                        Ok(Fields::Unit)
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Fields", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for Fields {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for Fields {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldsNamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsNamed", 2usize)?;
+        state.serialize_field("named", &Wrap(&self.named))?;
+        state.serialize_field("named_trailing", &self.named.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldsNamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["named", "named_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsNamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsNamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut named = None;
+                let mut named_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Field, token::Comma>,
+                            >(&mut map, &mut named, "named")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut named_trailing, "named_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named: trailing(
+                        required::<_, A::Error>(named, "named")?,
+                        named_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsNamed", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for FieldsNamed {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for FieldsNamed {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldsUnnamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsUnnamed", 2usize)?;
+        state.serialize_field("unnamed", &Wrap(&self.unnamed))?;
+        state.serialize_field("unnamed_trailing", &self.unnamed.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldsUnnamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["unnamed", "unnamed_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsUnnamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsUnnamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut unnamed = None;
+                let mut unnamed_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Field, token::Comma>,
+                            >(&mut map, &mut unnamed, "unnamed")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut unnamed_trailing, "unnamed_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed: trailing(
+                        required::<_, A::Error>(unnamed, "unnamed")?,
+                        unnamed_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsUnnamed", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for FieldsUnnamed {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for FieldsUnnamed {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for File {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("File", 3usize)?;
+        state.serialize_field("shebang", &Wrap(&self.shebang))?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("items", &Wrap(&self.items))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for File {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["shebang", "attrs", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = File;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct File")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(File {
+                    shebang: next_element(&mut seq, 0usize, &self)?,
+                    attrs: next_element(&mut seq, 1usize, &self)?,
+                    items: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut shebang = None;
+                let mut attrs = None;
+                let mut items = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<String>,
+                            >(&mut map, &mut shebang, "shebang")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Vec<Item>>(&mut map, &mut items, "items")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(File {
+                    shebang: shebang.unwrap_or(None),
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    items: required::<_, A::Error>(items, "items")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("File", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for File {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for File {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            FnArg::Receiver(v0) => {
+                serializer
+                    .serialize_newtype_variant("FnArg", 0u32, "Receiver", &Wrap(v0))
+            }
+            FnArg::Typed(v0) => {
+                serializer.serialize_newtype_variant("FnArg", 1u32, "Typed", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Receiver", "Typed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum FnArg")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Receiver>>()?.0;
+                        Ok(FnArg::Receiver(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatType>>()?.0;
+                        Ok(FnArg::Typed(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("FnArg", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for FnArg {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for FnArg {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FnQualifiers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FnQualifiers", 4usize)?;
+        state.serialize_field("constness", &self.constness.is_some())?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &Wrap(&self.abi))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FnQualifiers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["constness", "asyncness", "unsafety", "abi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FnQualifiers;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FnQualifiers")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(FnQualifiers {
+                    constness: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    asyncness: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    unsafety: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    abi: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constness = None;
+                let mut asyncness = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut constness, "constness")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, bool>(&mut map, &mut asyncness, "asyncness")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut unsafety, "unsafety")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Option<Abi>>(&mut map, &mut abi, "abi")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FnQualifiers {
+                    constness: flag(constness.unwrap_or(false)),
+                    asyncness: flag(asyncness.unwrap_or(false)),
+                    unsafety: flag(unsafety.unwrap_or(false)),
+                    abi: abi.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FnQualifiers", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for FnQualifiers {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for FnQualifiers {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ForeignItem::Fn(v0) => {
+                serializer
+                    .serialize_newtype_variant("ForeignItem", 0u32, "Fn", &Wrap(v0))
+            }
+            ForeignItem::Static(v0) => {
+                serializer
+                    .serialize_newtype_variant("ForeignItem", 1u32, "Static", &Wrap(v0))
+            }
+            ForeignItem::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant("ForeignItem", 2u32, "Type", &Wrap(v0))
+            }
+            ForeignItem::Macro(v0) => {
+                serializer
+                    .serialize_newtype_variant("ForeignItem", 3u32, "Macro", &Wrap(v0))
+            }
+            ForeignItem::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "ForeignItem",
+                        4u32,
+                        "Verbatim",
+                        &Wrap(v0),
+                    )
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Fn", "Static", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ForeignItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ForeignItemFn>>()?.0;
+                        Ok(ForeignItem::Fn(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<ForeignItemStatic>>()?
+                            .0;
+                        Ok(ForeignItem::Static(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ForeignItemType>>()?.0;
+                        Ok(ForeignItem::Type(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<ForeignItemMacro>>()?
+                            .0;
+                        Ok(ForeignItem::Macro(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(ForeignItem::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ForeignItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ForeignItem {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ForeignItem {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemFn", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("sig", &Wrap(&self.sig))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ForeignItemFn {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    sig: next_element(&mut seq, 2usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut sig = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Signature>(&mut map, &mut sig, "sig")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemFn {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    sig: required::<_, A::Error>(sig, "sig")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ForeignItemFn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ForeignItemFn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemMacro", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ForeignItemMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    mac: next_element(&mut seq, 1usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ForeignItemMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ForeignItemMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemStatic", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ForeignItemStatic {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    static_token: Default::default(),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    ident: next_element(&mut seq, 3usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 4usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemStatic {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    static_token: Default::default(),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ForeignItemStatic {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ForeignItemStatic {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemType", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ForeignItemType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    type_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    type_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ForeignItemType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ForeignItemType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericArgument::Lifetime(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        0u32,
+                        "Lifetime",
+                        &Wrap(v0),
+                    )
+            }
+            GenericArgument::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        1u32,
+                        "Type",
+                        &Wrap(v0),
+                    )
+            }
+            GenericArgument::Binding(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        2u32,
+                        "Binding",
+                        &Wrap(v0),
+                    )
+            }
+            GenericArgument::Constraint(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        3u32,
+                        "Constraint",
+                        &Wrap(v0),
+                    )
+            }
+            GenericArgument::Const(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericArgument",
+                        4u32,
+                        "Const",
+                        &Wrap(v0),
+                    )
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Lifetime",
+            "Type",
+            "Binding",
+            "Constraint",
+            "Const",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Lifetime>>()?.0;
+                        Ok(GenericArgument::Lifetime(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Type>>()?.0;
+                        Ok(GenericArgument::Type(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Binding>>()?.0;
+                        Ok(GenericArgument::Binding(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Constraint>>()?.0;
+                        Ok(GenericArgument::Constraint(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Expr>>()?.0;
+                        Ok(GenericArgument::Const(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for GenericArgument {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for GenericArgument {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericMethodArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericMethodArgument::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericMethodArgument",
+                        0u32,
+                        "Type",
+                        &Wrap(v0),
+                    )
+            }
+            GenericMethodArgument::Const(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericMethodArgument",
+                        1u32,
+                        "Const",
+                        &Wrap(v0),
+                    )
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericMethodArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericMethodArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericMethodArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Type>>()?.0;
+                        Ok(GenericMethodArgument::Type(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Expr>>()?.0;
+                        Ok(GenericMethodArgument::Const(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericMethodArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for GenericMethodArgument {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for GenericMethodArgument {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericParam::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant("GenericParam", 0u32, "Type", &Wrap(v0))
+            }
+            GenericParam::Lifetime(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "GenericParam",
+                        1u32,
+                        "Lifetime",
+                        &Wrap(v0),
+                    )
+            }
+            GenericParam::Const(v0) => {
+                serializer
+                    .serialize_newtype_variant("GenericParam", 2u32, "Const", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericParam")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeParam>>()?.0;
+                        Ok(GenericParam::Type(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LifetimeDef>>()?.0;
+                        Ok(GenericParam::Lifetime(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ConstParam>>()?.0;
+                        Ok(GenericParam::Const(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericParam", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for GenericParam {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for GenericParam {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Generics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Generics", 5usize)?;
+        state.serialize_field("lt_token", &self.lt_token.is_some())?;
+        state.serialize_field("params", &Wrap(&self.params))?;
+        state.serialize_field("params_trailing", &self.params.trailing_punct())?;
+        state.serialize_field("gt_token", &self.gt_token.is_some())?;
+        state.serialize_field("where_clause", &Wrap(&self.where_clause))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Generics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lt_token",
+            "params",
+            "params_trailing",
+            "gt_token",
+            "where_clause",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Generics;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Generics")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Generics {
+                    lt_token: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    params: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                    gt_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    where_clause: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lt_token = None;
+                let mut params = None;
+                let mut params_trailing = None;
+                let mut gt_token = None;
+                let mut where_clause = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut lt_token, "lt_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<GenericParam, token::Comma>,
+                            >(&mut map, &mut params, "params")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut params_trailing, "params_trailing")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, bool>(&mut map, &mut gt_token, "gt_token")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Option<WhereClause>,
+                            >(&mut map, &mut where_clause, "where_clause")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Generics {
+                    lt_token: flag(lt_token.unwrap_or(false)),
+                    params: trailing(
+                        required::<_, A::Error>(params, "params")?,
+                        params_trailing.unwrap_or(false),
+                    ),
+                    gt_token: flag(gt_token.unwrap_or(false)),
+                    where_clause: where_clause.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Generics", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Generics {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Generics {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ImplItem::Const(v0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 0u32, "Const", &Wrap(v0))
+            }
+            ImplItem::Method(v0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 1u32, "Method", &Wrap(v0))
+            }
+            ImplItem::Type(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 2u32, "Type", &Wrap(v0))
+            }
+            ImplItem::Macro(v0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 3u32, "Macro", &Wrap(v0))
+            }
+            ImplItem::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant("ImplItem", 4u32, "Verbatim", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ImplItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ImplItemConst>>()?.0;
+                        Ok(ImplItem::Const(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ImplItemMethod>>()?.0;
+                        Ok(ImplItem::Method(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ImplItemType>>()?.0;
+                        Ok(ImplItem::Type(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ImplItemMacro>>()?.0;
+                        Ok(ImplItem::Macro(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(ImplItem::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ImplItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ImplItem {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ImplItem {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemConst", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ImplItemConst {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    defaultness: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    const_token: Default::default(),
+                    ident: next_element(&mut seq, 3usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 4usize, &self)?,
+                    eq_token: Default::default(),
+                    expr: next_element(&mut seq, 5usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut defaultness, "defaultness")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(4usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        Some(5usize) => {
+                            next_value::<_, Expr>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemConst {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    defaultness: flag(defaultness.unwrap_or(false)),
+                    const_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    eq_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ImplItemConst {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ImplItemConst {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMacro", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ImplItemMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    mac: next_element(&mut seq, 1usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ImplItemMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ImplItemMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMethod", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("sig", &Wrap(&self.sig))?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ImplItemMethod {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    defaultness: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    sig: next_element(&mut seq, 3usize, &self)?,
+                    block: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut sig = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut defaultness, "defaultness")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Signature>(&mut map, &mut sig, "sig")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Block>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemMethod {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    defaultness: flag(defaultness.unwrap_or(false)),
+                    sig: required::<_, A::Error>(sig, "sig")?,
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ImplItemMethod {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ImplItemMethod {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemType", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "defaultness",
+            "ident",
+            "generics",
+            "ty",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ImplItemType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    defaultness: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    type_token: Default::default(),
+                    ident: next_element(&mut seq, 3usize, &self)?,
+                    generics: next_element(&mut seq, 4usize, &self)?,
+                    eq_token: Default::default(),
+                    ty: next_element(&mut seq, 5usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut defaultness, "defaultness")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(5usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    defaultness: flag(defaultness.unwrap_or(false)),
+                    type_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    eq_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ImplItemType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ImplItemType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Index {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Index", 1usize)?;
+        state.serialize_field("index", &Wrap(&self.index))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Index {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Index;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Index")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Index {
+                    index: next_element(&mut seq, 0usize, &self)?,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut index = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, u32>(&mut map, &mut index, "index")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Index {
+                    index: required::<_, A::Error>(index, "index")?,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Index", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Index {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Index {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Invariant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Invariant", 2usize)?;
+        state.serialize_field("exprs", &Wrap(&self.exprs))?;
+        state.serialize_field("exprs_trailing", &self.exprs.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Invariant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["exprs", "exprs_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Invariant;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Invariant")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Invariant {
+                    invariant_token: Default::default(),
+                    exprs: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut exprs = None;
+                let mut exprs_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut exprs, "exprs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut exprs_trailing, "exprs_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Invariant {
+                    invariant_token: Default::default(),
+                    exprs: trailing(
+                        required::<_, A::Error>(exprs, "exprs")?,
+                        exprs_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Invariant", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for Invariant {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for Invariant {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Item::Const(v0) => {
+                serializer.serialize_newtype_variant("Item", 0u32, "Const", &Wrap(v0))
+            }
+            Item::Enum(v0) => {
+                serializer.serialize_newtype_variant("Item", 1u32, "Enum", &Wrap(v0))
+            }
+            Item::ExternCrate(v0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 2u32, "ExternCrate", &Wrap(v0))
+            }
+            Item::Fn(v0) => {
+                serializer.serialize_newtype_variant("Item", 3u32, "Fn", &Wrap(v0))
+            }
+            Item::ForeignMod(v0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 4u32, "ForeignMod", &Wrap(v0))
+            }
+            Item::Impl(v0) => {
+                serializer.serialize_newtype_variant("Item", 5u32, "Impl", &Wrap(v0))
+            }
+            Item::Macro(v0) => {
+                serializer.serialize_newtype_variant("Item", 6u32, "Macro", &Wrap(v0))
+            }
+            Item::Macro2(v0) => {
+                serializer.serialize_newtype_variant("Item", 7u32, "Macro2", &Wrap(v0))
+            }
+            Item::Mod(v0) => {
+                serializer.serialize_newtype_variant("Item", 8u32, "Mod", &Wrap(v0))
+            }
+            Item::Static(v0) => {
+                serializer.serialize_newtype_variant("Item", 9u32, "Static", &Wrap(v0))
+            }
+            Item::Struct(v0) => {
+                serializer.serialize_newtype_variant("Item", 10u32, "Struct", &Wrap(v0))
+            }
+            Item::Trait(v0) => {
+                serializer.serialize_newtype_variant("Item", 11u32, "Trait", &Wrap(v0))
+            }
+            Item::TraitAlias(v0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 12u32, "TraitAlias", &Wrap(v0))
+            }
+            Item::Type(v0) => {
+                serializer.serialize_newtype_variant("Item", 13u32, "Type", &Wrap(v0))
+            }
+            Item::Union(v0) => {
+                serializer.serialize_newtype_variant("Item", 14u32, "Union", &Wrap(v0))
+            }
+            Item::Use(v0) => {
+                serializer.serialize_newtype_variant("Item", 15u32, "Use", &Wrap(v0))
+            }
+            Item::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant("Item", 16u32, "Verbatim", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Const",
+            "Enum",
+            "ExternCrate",
+            "Fn",
+            "ForeignMod",
+            "Impl",
+            "Macro",
+            "Macro2",
+            "Mod",
+            "Static",
+            "Struct",
+            "Trait",
+            "TraitAlias",
+            "Type",
+            "Union",
+            "Use",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Item;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Item")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemConst>>()?.0;
+                        Ok(Item::Const(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemEnum>>()?.0;
+                        Ok(Item::Enum(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemExternCrate>>()?.0;
+                        Ok(Item::ExternCrate(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemFn>>()?.0;
+                        Ok(Item::Fn(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemForeignMod>>()?.0;
+                        Ok(Item::ForeignMod(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemImpl>>()?.0;
+                        Ok(Item::Impl(v0))
+                    }
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemMacro>>()?.0;
+                        Ok(Item::Macro(v0))
+                    }
+                    Some(7usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemMacro2>>()?.0;
+                        Ok(Item::Macro2(v0))
+                    }
+                    Some(8usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemMod>>()?.0;
+                        Ok(Item::Mod(v0))
+                    }
+                    Some(9usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemStatic>>()?.0;
+                        Ok(Item::Static(v0))
+                    }
+                    Some(10usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemStruct>>()?.0;
+                        Ok(Item::Struct(v0))
+                    }
+                    Some(11usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemTrait>>()?.0;
+                        Ok(Item::Trait(v0))
+                    }
+                    Some(12usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemTraitAlias>>()?.0;
+                        Ok(Item::TraitAlias(v0))
+                    }
+                    Some(13usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemType>>()?.0;
+                        Ok(Item::Type(v0))
+                    }
+                    Some(14usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemUnion>>()?.0;
+                        Ok(Item::Union(v0))
+                    }
+                    Some(15usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<ItemUse>>()?.0;
+                        Ok(Item::Use(v0))
+                    }
+                    Some(16usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(Item::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Item", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Item {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Item {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemConst", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemConst {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    const_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 3usize, &self)?,
+                    eq_token: Default::default(),
+                    expr: next_element(&mut seq, 4usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemConst {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    const_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    eq_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemConst {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemConst {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemEnum", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("variants", &Wrap(&self.variants))?;
+        state.serialize_field("variants_trailing", &self.variants.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "ident",
+            "generics",
+            "variants",
+            "variants_trailing",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemEnum {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    enum_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    brace_token: Default::default(),
+                    variants: trailing(
+                        next_element(&mut seq, 4usize, &self)?,
+                        next_element(&mut seq, 5usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut variants = None;
+                let mut variants_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Variant, token::Comma>,
+                            >(&mut map, &mut variants, "variants")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut variants_trailing, "variants_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemEnum {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    enum_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    brace_token: Default::default(),
+                    variants: trailing(
+                        required::<_, A::Error>(variants, "variants")?,
+                        variants_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemEnum {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemEnum {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemExternCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemExternCrate", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("rename", &Wrap(&self.rename))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemExternCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rename"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemExternCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemExternCrate")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemExternCrate {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    rename: next_element(&mut seq, 3usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut rename = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::As, proc_macro2::Ident)>,
+                            >(&mut map, &mut rename, "rename")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemExternCrate {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    rename: rename.unwrap_or(None),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemExternCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemExternCrate {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemExternCrate {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemFn", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("sig", &Wrap(&self.sig))?;
+        state.serialize_field("block", &Wrap(&self.block))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemFn {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    sig: next_element(&mut seq, 2usize, &self)?,
+                    block: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut sig = None;
+                let mut block = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Signature>(&mut map, &mut sig, "sig")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Box<Block>>(&mut map, &mut block, "block")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemFn {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    sig: required::<_, A::Error>(sig, "sig")?,
+                    block: required::<_, A::Error>(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemFn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemFn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemForeignMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemForeignMod", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("abi", &Wrap(&self.abi))?;
+        state.serialize_field("items", &Wrap(&self.items))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemForeignMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "abi", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemForeignMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemForeignMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemForeignMod {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    abi: next_element(&mut seq, 1usize, &self)?,
+                    brace_token: Default::default(),
+                    items: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut abi = None;
+                let mut items = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, Abi>(&mut map, &mut abi, "abi")?,
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Vec<ForeignItem>,
+                            >(&mut map, &mut items, "items")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemForeignMod {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    abi: required::<_, A::Error>(abi, "abi")?,
+                    brace_token: Default::default(),
+                    items: required::<_, A::Error>(items, "items")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemForeignMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemForeignMod {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemForeignMod {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemImpl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemImpl", 7usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("trait_", &Wrap(&self.trait_))?;
+        state.serialize_field("self_ty", &Wrap(&self.self_ty))?;
+        state.serialize_field("items", &Wrap(&self.items))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemImpl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "defaultness",
+            "unsafety",
+            "generics",
+            "trait_",
+            "self_ty",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemImpl;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemImpl")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemImpl {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    defaultness: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    unsafety: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    impl_token: Default::default(),
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    trait_: next_element(&mut seq, 4usize, &self)?,
+                    self_ty: next_element(&mut seq, 5usize, &self)?,
+                    brace_token: Default::default(),
+                    items: next_element(&mut seq, 6usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut defaultness = None;
+                let mut unsafety = None;
+                let mut generics = None;
+                let mut trait_ = None;
+                let mut self_ty = None;
+                let mut items = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut defaultness, "defaultness")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut unsafety, "unsafety")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Option<(Option<token::Bang>, Path, token::For)>,
+                            >(&mut map, &mut trait_, "trait_")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                Box<Type>,
+                            >(&mut map, &mut self_ty, "self_ty")?
+                        }
+                        Some(6usize) => {
+                            next_value::<
+                                _,
+                                Vec<ImplItem>,
+                            >(&mut map, &mut items, "items")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemImpl {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    defaultness: flag(defaultness.unwrap_or(false)),
+                    unsafety: flag(unsafety.unwrap_or(false)),
+                    impl_token: Default::default(),
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    trait_: trait_.unwrap_or(None),
+                    self_ty: required::<_, A::Error>(self_ty, "self_ty")?,
+                    brace_token: Default::default(),
+                    items: required::<_, A::Error>(items, "items")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemImpl", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemImpl {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemImpl {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    mac: next_element(&mut seq, 2usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<proc_macro2::Ident>,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    ident: ident.unwrap_or(None),
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMacro2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro2", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("rules", &Wrap(&self.rules))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMacro2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rules"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro2;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro2")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemMacro2 {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    macro_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    rules: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut rules = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::TokenStream,
+                            >(&mut map, &mut rules, "rules")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMacro2 {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    macro_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    rules: required::<_, A::Error>(rules, "rules")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro2", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemMacro2 {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemMacro2 {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMod", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("content", &Wrap(&self.content))?;
+        state.serialize_field("semi", &self.semi.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "content", "semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemMod {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    mod_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    content: next_element(&mut seq, 3usize, &self)?,
+                    semi: flag(next_element::<_, bool>(&mut seq, 4usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut content = None;
+                let mut semi = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Brace, Vec<Item>)>,
+                            >(&mut map, &mut content, "content")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, bool>(&mut map, &mut semi, "semi")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMod {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    mod_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    content: content.unwrap_or(None),
+                    semi: flag(semi.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemMod {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemMod {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStatic", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemStatic {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    static_token: Default::default(),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    ident: next_element(&mut seq, 3usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 4usize, &self)?,
+                    eq_token: Default::default(),
+                    expr: next_element(&mut seq, 5usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        Some(5usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemStatic {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    static_token: Default::default(),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    eq_token: Default::default(),
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemStatic {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemStatic {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStruct", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "ident",
+            "generics",
+            "fields",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemStruct {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    struct_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    fields: next_element(&mut seq, 4usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 5usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut fields = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Fields>(&mut map, &mut fields, "fields")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemStruct {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    struct_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    fields: required::<_, A::Error>(fields, "fields")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemStruct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemStruct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTrait", 10usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("auto_token", &self.auto_token.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("supertraits", &Wrap(&self.supertraits))?;
+        state
+            .serialize_field(
+                "supertraits_trailing",
+                &self.supertraits.trailing_punct(),
+            )?;
+        state.serialize_field("items", &Wrap(&self.items))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "unsafety",
+            "auto_token",
+            "ident",
+            "generics",
+            "colon_token",
+            "supertraits",
+            "supertraits_trailing",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemTrait {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    unsafety: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    auto_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    trait_token: Default::default(),
+                    ident: next_element(&mut seq, 4usize, &self)?,
+                    generics: next_element(&mut seq, 5usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 6usize, &self)?),
+                    supertraits: trailing(
+                        next_element(&mut seq, 7usize, &self)?,
+                        next_element(&mut seq, 8usize, &self)?,
+                    ),
+                    brace_token: Default::default(),
+                    items: next_element(&mut seq, 9usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut unsafety = None;
+                let mut auto_token = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut colon_token = None;
+                let mut supertraits = None;
+                let mut supertraits_trailing = None;
+                let mut items = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut unsafety, "unsafety")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut auto_token, "auto_token")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(6usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(7usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut supertraits, "supertraits")?
+                        }
+                        Some(8usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(
+                                &mut map,
+                                &mut supertraits_trailing,
+                                "supertraits_trailing",
+                            )?
+                        }
+                        Some(9usize) => {
+                            next_value::<
+                                _,
+                                Vec<TraitItem>,
+                            >(&mut map, &mut items, "items")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemTrait {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    unsafety: flag(unsafety.unwrap_or(false)),
+                    auto_token: flag(auto_token.unwrap_or(false)),
+                    trait_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    supertraits: trailing(
+                        required::<_, A::Error>(supertraits, "supertraits")?,
+                        supertraits_trailing.unwrap_or(false),
+                    ),
+                    brace_token: Default::default(),
+                    items: required::<_, A::Error>(items, "items")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemTrait {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemTrait {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemTraitAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTraitAlias", 6usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemTraitAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "ident",
+            "generics",
+            "bounds",
+            "bounds_trailing",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTraitAlias;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTraitAlias")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemTraitAlias {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    trait_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    eq_token: Default::default(),
+                    bounds: trailing(
+                        next_element(&mut seq, 4usize, &self)?,
+                        next_element(&mut seq, 5usize, &self)?,
+                    ),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemTraitAlias {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    trait_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    eq_token: Default::default(),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTraitAlias", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemTraitAlias {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemTraitAlias {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemType", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    type_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    eq_token: Default::default(),
+                    ty: next_element(&mut seq, 4usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    type_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    eq_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUnion", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemUnion {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    union_token: Default::default(),
+                    ident: next_element(&mut seq, 2usize, &self)?,
+                    generics: next_element(&mut seq, 3usize, &self)?,
+                    fields: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut fields = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                FieldsNamed,
+                            >(&mut map, &mut fields, "fields")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemUnion {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    union_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    fields: required::<_, A::Error>(fields, "fields")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemUnion {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemUnion {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemUse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUse", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("vis", &Wrap(&self.vis))?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("tree", &Wrap(&self.tree))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemUse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "leading_colon", "tree"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUse;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUse")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ItemUse {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    vis: next_element(&mut seq, 1usize, &self)?,
+                    use_token: Default::default(),
+                    leading_colon: flag(
+                        next_element::<_, bool>(&mut seq, 2usize, &self)?,
+                    ),
+                    tree: next_element(&mut seq, 3usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut leading_colon = None;
+                let mut tree = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Visibility>(&mut map, &mut vis, "vis")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut leading_colon, "leading_colon")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, UseTree>(&mut map, &mut tree, "tree")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemUse {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    vis: required::<_, A::Error>(vis, "vis")?,
+                    use_token: Default::default(),
+                    leading_colon: flag(leading_colon.unwrap_or(false)),
+                    tree: required::<_, A::Error>(tree, "tree")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUse", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for ItemUse {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for ItemUse {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Label", 1usize)?;
+        state.serialize_field("name", &Wrap(&self.name))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Label;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Label")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Label {
+                    name: next_element(&mut seq, 0usize, &self)?,
+                    colon_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Lifetime>(&mut map, &mut name, "name")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Label {
+                    name: required::<_, A::Error>(name, "name")?,
+                    colon_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Label", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Label {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Label {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for LifetimeDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LifetimeDef", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("lifetime", &Wrap(&self.lifetime))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LifetimeDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "lifetime",
+            "colon_token",
+            "bounds",
+            "bounds_trailing",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LifetimeDef;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LifetimeDef")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(LifetimeDef {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    lifetime: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    bounds: trailing(
+                        next_element(&mut seq, 3usize, &self)?,
+                        next_element(&mut seq, 4usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lifetime = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Lifetime,
+                            >(&mut map, &mut lifetime, "lifetime")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Lifetime, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LifetimeDef {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    lifetime: required::<_, A::Error>(lifetime, "lifetime")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LifetimeDef", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for LifetimeDef {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for LifetimeDef {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Lit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Lit::Str(v0) => {
+                serializer.serialize_newtype_variant("Lit", 0u32, "Str", &Wrap(v0))
+            }
+            Lit::ByteStr(v0) => {
+                serializer.serialize_newtype_variant("Lit", 1u32, "ByteStr", &Wrap(v0))
+            }
+            Lit::Byte(v0) => {
+                serializer.serialize_newtype_variant("Lit", 2u32, "Byte", &Wrap(v0))
+            }
+            Lit::Char(v0) => {
+                serializer.serialize_newtype_variant("Lit", 3u32, "Char", &Wrap(v0))
+            }
+            Lit::Int(v0) => {
+                serializer.serialize_newtype_variant("Lit", 4u32, "Int", &Wrap(v0))
+            }
+            Lit::Float(v0) => {
+                serializer.serialize_newtype_variant("Lit", 5u32, "Float", &Wrap(v0))
+            }
+            Lit::Bool(v0) => {
+                serializer.serialize_newtype_variant("Lit", 6u32, "Bool", &Wrap(v0))
+            }
+            Lit::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Lit", 7u32, "Verbatim", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Lit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Str",
+            "ByteStr",
+            "Byte",
+            "Char",
+            "Int",
+            "Float",
+            "Bool",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Lit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Lit")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitStr>>()?.0;
+                        Ok(Lit::Str(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitByteStr>>()?.0;
+                        Ok(Lit::ByteStr(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitByte>>()?.0;
+                        Ok(Lit::Byte(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitChar>>()?.0;
+                        Ok(Lit::Char(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitInt>>()?.0;
+                        Ok(Lit::Int(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitFloat>>()?.0;
+                        Ok(Lit::Float(v0))
+                    }
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<LitBool>>()?.0;
+                        Ok(Lit::Bool(v0))
+                    }
+                    Some(7usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::Literal>>()?
+                            .0;
+                        Ok(Lit::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Lit", VARIANTS, Visitor)
+    }
+}
+impl Ser for Lit {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+impl<'de> De<'de> for Lit {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for LitBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LitBool", 1usize)?;
+        state.serialize_field("value", &Wrap(&self.value))?;
+        state.end()
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LitBool {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["value"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LitBool;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LitBool")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(LitBool {
+                    value: next_element(&mut seq, 0usize, &self)?,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut value = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut value, "value")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LitBool {
+                    value: required::<_, A::Error>(value, "value")?,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LitBool", FIELDS, Visitor)
+    }
+}
+impl Ser for LitBool {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+impl<'de> De<'de> for LitBool {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Local {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Local", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.serialize_field("init", &Wrap(&self.init))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Local {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "init"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Local;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Local")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Local {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    let_token: Default::default(),
+                    pat: next_element(&mut seq, 1usize, &self)?,
+                    init: next_element(&mut seq, 2usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut init = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => next_value::<_, Pat>(&mut map, &mut pat, "pat")?,
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Eq, Box<Expr>)>,
+                            >(&mut map, &mut init, "init")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Local {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    let_token: Default::default(),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                    init: init.unwrap_or(None),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Local", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Local {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Local {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Macro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Macro", 3usize)?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("delimiter", &Wrap(&self.delimiter))?;
+        state.serialize_field("tokens", &Wrap(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Macro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "delimiter", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Macro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Macro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Macro {
+                    path: next_element(&mut seq, 0usize, &self)?,
+                    bang_token: Default::default(),
+                    delimiter: next_element(&mut seq, 1usize, &self)?,
+                    tokens: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut delimiter = None;
+                let mut tokens = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                MacroDelimiter,
+                            >(&mut map, &mut delimiter, "delimiter")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::TokenStream,
+                            >(&mut map, &mut tokens, "tokens")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Macro {
+                    path: required::<_, A::Error>(path, "path")?,
+                    bang_token: Default::default(),
+                    delimiter: required::<_, A::Error>(delimiter, "delimiter")?,
+                    tokens: required::<_, A::Error>(tokens, "tokens")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Macro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Macro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Macro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroDelimiter::Paren(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "MacroDelimiter",
+                        0u32,
+                        "Paren",
+                        &Wrap(v0),
+                    )
+            }
+            MacroDelimiter::Brace(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "MacroDelimiter",
+                        1u32,
+                        "Brace",
+                        &Wrap(v0),
+                    )
+            }
+            MacroDelimiter::Bracket(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "MacroDelimiter",
+                        2u32,
+                        "Bracket",
+                        &Wrap(v0),
+                    )
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroDelimiter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Paren", "Brace", "Bracket"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MacroDelimiter;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroDelimiter")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<token::Paren>>()?.0;
+                        Ok(MacroDelimiter::Paren(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<token::Brace>>()?.0;
+                        Ok(MacroDelimiter::Brace(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<token::Bracket>>()?.0;
+                        Ok(MacroDelimiter::Bracket(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroDelimiter", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for MacroDelimiter {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for MacroDelimiter {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Member {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Member::Named(v0) => {
+                serializer.serialize_newtype_variant("Member", 0u32, "Named", &Wrap(v0))
+            }
+            Member::Unnamed(v0) => {
+                serializer
+                    .serialize_newtype_variant("Member", 1u32, "Unnamed", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Member {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Member;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Member")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::Ident>>()?
+                            .0;
+                        Ok(Member::Named(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Index>>()?.0;
+                        Ok(Member::Unnamed(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Member", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Member {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Member {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Meta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Meta::Path(v0) => {
+                serializer.serialize_newtype_variant("Meta", 0u32, "Path", &Wrap(v0))
+            }
+            Meta::List(v0) => {
+                serializer.serialize_newtype_variant("Meta", 1u32, "List", &Wrap(v0))
+            }
+            Meta::NameValue(v0) => {
+                serializer
+                    .serialize_newtype_variant("Meta", 2u32, "NameValue", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Meta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Meta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Meta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Path>>()?.0;
+                        Ok(Meta::Path(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<MetaList>>()?.0;
+                        Ok(Meta::List(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<MetaNameValue>>()?.0;
+                        Ok(Meta::NameValue(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Meta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Meta {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Meta {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MetaList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaList", 3usize)?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("nested", &Wrap(&self.nested))?;
+        state.serialize_field("nested_trailing", &self.nested.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MetaList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "nested", "nested_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(MetaList {
+                    path: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    nested: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut nested = None;
+                let mut nested_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<NestedMeta, token::Comma>,
+                            >(&mut map, &mut nested, "nested")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut nested_trailing, "nested_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MetaList {
+                    path: required::<_, A::Error>(path, "path")?,
+                    paren_token: Default::default(),
+                    nested: trailing(
+                        required::<_, A::Error>(nested, "nested")?,
+                        nested_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaList", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for MetaList {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for MetaList {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MetaNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaNameValue", 2usize)?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("lit", &Wrap(&self.lit))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MetaNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(MetaNameValue {
+                    path: next_element(&mut seq, 0usize, &self)?,
+                    eq_token: Default::default(),
+                    lit: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut lit = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(1usize) => next_value::<_, Lit>(&mut map, &mut lit, "lit")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MetaNameValue {
+                    path: required::<_, A::Error>(path, "path")?,
+                    eq_token: Default::default(),
+                    lit: required::<_, A::Error>(lit, "lit")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaNameValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for MetaNameValue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for MetaNameValue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MethodTurbofish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MethodTurbofish", 2usize)?;
+        state.serialize_field("args", &Wrap(&self.args))?;
+        state.serialize_field("args_trailing", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MethodTurbofish {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["args", "args_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MethodTurbofish;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MethodTurbofish")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut args = None;
+                let mut args_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<GenericMethodArgument, token::Comma>,
+                            >(&mut map, &mut args, "args")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut args_trailing, "args_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args: trailing(
+                        required::<_, A::Error>(args, "args")?,
+                        args_trailing.unwrap_or(false),
+                    ),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MethodTurbofish", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for MethodTurbofish {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for MethodTurbofish {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for NestedMeta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            NestedMeta::Meta(v0) => {
+                serializer
+                    .serialize_newtype_variant("NestedMeta", 0u32, "Meta", &Wrap(v0))
+            }
+            NestedMeta::Lit(v0) => {
+                serializer
+                    .serialize_newtype_variant("NestedMeta", 1u32, "Lit", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for NestedMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Meta", "Lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = NestedMeta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum NestedMeta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Meta>>()?.0;
+                        Ok(NestedMeta::Meta(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Lit>>()?.0;
+                        Ok(NestedMeta::Lit(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("NestedMeta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for NestedMeta {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for NestedMeta {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ParenthesizedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer
+            .serialize_struct("ParenthesizedGenericArguments", 3usize)?;
+        state.serialize_field("inputs", &Wrap(&self.inputs))?;
+        state.serialize_field("inputs_trailing", &self.inputs.trailing_punct())?;
+        state.serialize_field("output", &Wrap(&self.output))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ParenthesizedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["inputs", "inputs_trailing", "output"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ParenthesizedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ParenthesizedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                    output: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut inputs = None;
+                let mut inputs_trailing = None;
+                let mut output = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Type, token::Comma>,
+                            >(&mut map, &mut inputs, "inputs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut inputs_trailing, "inputs_trailing")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, ReturnType>(&mut map, &mut output, "output")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        required::<_, A::Error>(inputs, "inputs")?,
+                        inputs_trailing.unwrap_or(false),
+                    ),
+                    output: required::<_, A::Error>(output, "output")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ParenthesizedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ParenthesizedGenericArguments {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ParenthesizedGenericArguments {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Pat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Pat::Box(v0) => {
+                serializer.serialize_newtype_variant("Pat", 0u32, "Box", &Wrap(v0))
+            }
+            Pat::Ident(v0) => {
+                serializer.serialize_newtype_variant("Pat", 1u32, "Ident", &Wrap(v0))
+            }
+            Pat::Lit(v0) => {
+                serializer.serialize_newtype_variant("Pat", 2u32, "Lit", &Wrap(v0))
+            }
+            Pat::Macro(v0) => {
+                serializer.serialize_newtype_variant("Pat", 3u32, "Macro", &Wrap(v0))
+            }
+            Pat::Or(v0) => {
+                serializer.serialize_newtype_variant("Pat", 4u32, "Or", &Wrap(v0))
+            }
+            Pat::Path(v0) => {
+                serializer.serialize_newtype_variant("Pat", 5u32, "Path", &Wrap(v0))
+            }
+            Pat::Range(v0) => {
+                serializer.serialize_newtype_variant("Pat", 6u32, "Range", &Wrap(v0))
+            }
+            Pat::Reference(v0) => {
+                serializer.serialize_newtype_variant("Pat", 7u32, "Reference", &Wrap(v0))
+            }
+            Pat::Rest(v0) => {
+                serializer.serialize_newtype_variant("Pat", 8u32, "Rest", &Wrap(v0))
+            }
+            Pat::Slice(v0) => {
+                serializer.serialize_newtype_variant("Pat", 9u32, "Slice", &Wrap(v0))
+            }
+            Pat::Struct(v0) => {
+                serializer.serialize_newtype_variant("Pat", 10u32, "Struct", &Wrap(v0))
+            }
+            Pat::Tuple(v0) => {
+                serializer.serialize_newtype_variant("Pat", 11u32, "Tuple", &Wrap(v0))
+            }
+            Pat::TupleStruct(v0) => {
+                serializer
+                    .serialize_newtype_variant("Pat", 12u32, "TupleStruct", &Wrap(v0))
+            }
+            Pat::Type(v0) => {
+                serializer.serialize_newtype_variant("Pat", 13u32, "Type", &Wrap(v0))
+            }
+            Pat::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Pat", 14u32, "Verbatim", &Wrap(v0))
+            }
+            Pat::Wild(v0) => {
+                serializer.serialize_newtype_variant("Pat", 15u32, "Wild", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Pat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Box",
+            "Ident",
+            "Lit",
+            "Macro",
+            "Or",
+            "Path",
+            "Range",
+            "Reference",
+            "Rest",
+            "Slice",
+            "Struct",
+            "Tuple",
+            "TupleStruct",
+            "Type",
+            "Verbatim",
+            "Wild",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Pat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Pat")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatBox>>()?.0;
+                        Ok(Pat::Box(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatIdent>>()?.0;
+                        Ok(Pat::Ident(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatLit>>()?.0;
+                        Ok(Pat::Lit(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatMacro>>()?.0;
+                        Ok(Pat::Macro(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatOr>>()?.0;
+                        Ok(Pat::Or(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatPath>>()?.0;
+                        Ok(Pat::Path(v0))
+                    }
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatRange>>()?.0;
+                        Ok(Pat::Range(v0))
+                    }
+                    Some(7usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatReference>>()?.0;
+                        Ok(Pat::Reference(v0))
+                    }
+                    Some(8usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatRest>>()?.0;
+                        Ok(Pat::Rest(v0))
+                    }
+                    Some(9usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatSlice>>()?.0;
+                        Ok(Pat::Slice(v0))
+                    }
+                    Some(10usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatStruct>>()?.0;
+                        Ok(Pat::Struct(v0))
+                    }
+                    Some(11usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatTuple>>()?.0;
+                        Ok(Pat::Tuple(v0))
+                    }
+                    Some(12usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatTupleStruct>>()?.0;
+                        Ok(Pat::TupleStruct(v0))
+                    }
+                    Some(13usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatType>>()?.0;
+                        Ok(Pat::Type(v0))
+                    }
+                    Some(14usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(Pat::Verbatim(v0))
+                    }
+                    Some(15usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PatWild>>()?.0;
+                        Ok(Pat::Wild(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Pat", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Pat {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Pat {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatBox", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatBox {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    box_token: Default::default(),
+                    pat: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Pat>>(&mut map, &mut pat, "pat")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatBox {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    box_token: Default::default(),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatBox {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatBox {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatIdent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatIdent", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("by_ref", &self.by_ref.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("subpat", &Wrap(&self.subpat))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatIdent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "by_ref", "mutability", "ident", "subpat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatIdent;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatIdent")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatIdent {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    by_ref: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    ident: next_element(&mut seq, 3usize, &self)?,
+                    subpat: next_element(&mut seq, 4usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut by_ref = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut subpat = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, bool>(&mut map, &mut by_ref, "by_ref")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::At, Box<Pat>)>,
+                            >(&mut map, &mut subpat, "subpat")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatIdent {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    by_ref: flag(by_ref.unwrap_or(false)),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    subpat: subpat.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatIdent", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatIdent {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatIdent {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatLit", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("expr", &Wrap(&self.expr))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatLit {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    expr: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut expr, "expr")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatLit {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    expr: required::<_, A::Error>(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatLit", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatLit {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatLit {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatMacro", 2usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    mac: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatOr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatOr", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("leading_vert", &self.leading_vert.is_some())?;
+        state.serialize_field("cases", &Wrap(&self.cases))?;
+        state.serialize_field("cases_trailing", &self.cases.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatOr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "leading_vert", "cases", "cases_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatOr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatOr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatOr {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    leading_vert: flag(
+                        next_element::<_, bool>(&mut seq, 1usize, &self)?,
+                    ),
+                    cases: trailing(
+                        next_element(&mut seq, 2usize, &self)?,
+                        next_element(&mut seq, 3usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut leading_vert = None;
+                let mut cases = None;
+                let mut cases_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut leading_vert, "leading_vert")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Pat, token::Or>,
+                            >(&mut map, &mut cases, "cases")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut cases_trailing, "cases_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatOr {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    leading_vert: flag(leading_vert.unwrap_or(false)),
+                    cases: trailing(
+                        required::<_, A::Error>(cases, "cases")?,
+                        cases_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatOr", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatOr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatOr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatPath", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("qself", &Wrap(&self.qself))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatPath {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    qself: next_element(&mut seq, 1usize, &self)?,
+                    path: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut qself = None;
+                let mut path = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<QSelf>,
+                            >(&mut map, &mut qself, "qself")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatPath {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    qself: qself.unwrap_or(None),
+                    path: required::<_, A::Error>(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatPath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatPath {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatPath {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRange", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("lo", &Wrap(&self.lo))?;
+        state.serialize_field("limits", &Wrap(&self.limits))?;
+        state.serialize_field("hi", &Wrap(&self.hi))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lo", "limits", "hi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatRange {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    lo: next_element(&mut seq, 1usize, &self)?,
+                    limits: next_element(&mut seq, 2usize, &self)?,
+                    hi: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lo = None;
+                let mut limits = None;
+                let mut hi = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut lo, "lo")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                RangeLimits,
+                            >(&mut map, &mut limits, "limits")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Box<Expr>>(&mut map, &mut hi, "hi")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatRange {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    lo: required::<_, A::Error>(lo, "lo")?,
+                    limits: required::<_, A::Error>(limits, "limits")?,
+                    hi: required::<_, A::Error>(hi, "hi")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatRange {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatRange {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatReference", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatReference {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    and_token: Default::default(),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    pat: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mutability = None;
+                let mut pat = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Pat>>(&mut map, &mut pat, "pat")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatReference {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    and_token: Default::default(),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatReference {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatReference {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatRest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRest", 1usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatRest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRest;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRest")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatRest {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    dot2_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatRest {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    dot2_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRest", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatRest {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatRest {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatSlice", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("elems", &Wrap(&self.elems))?;
+        state.serialize_field("elems_trailing", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatSlice {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    bracket_token: Default::default(),
+                    elems: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                let mut elems_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Pat, token::Comma>,
+                            >(&mut map, &mut elems, "elems")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut elems_trailing, "elems_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatSlice {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    bracket_token: Default::default(),
+                    elems: trailing(
+                        required::<_, A::Error>(elems, "elems")?,
+                        elems_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatSlice {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatSlice {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatStruct", 5usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.serialize_field("fields_trailing", &self.fields.trailing_punct())?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "path",
+            "fields",
+            "fields_trailing",
+            "dot2_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatStruct {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    path: next_element(&mut seq, 1usize, &self)?,
+                    brace_token: Default::default(),
+                    fields: trailing(
+                        next_element(&mut seq, 2usize, &self)?,
+                        next_element(&mut seq, 3usize, &self)?,
+                    ),
+                    dot2_token: flag(next_element::<_, bool>(&mut seq, 4usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut fields = None;
+                let mut fields_trailing = None;
+                let mut dot2_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<FieldPat, token::Comma>,
+                            >(&mut map, &mut fields, "fields")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut fields_trailing, "fields_trailing")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut dot2_token, "dot2_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatStruct {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    path: required::<_, A::Error>(path, "path")?,
+                    brace_token: Default::default(),
+                    fields: trailing(
+                        required::<_, A::Error>(fields, "fields")?,
+                        fields_trailing.unwrap_or(false),
+                    ),
+                    dot2_token: flag(dot2_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatStruct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatStruct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTuple", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("elems", &Wrap(&self.elems))?;
+        state.serialize_field("elems_trailing", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatTuple {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                let mut elems_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Pat, token::Comma>,
+                            >(&mut map, &mut elems, "elems")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut elems_trailing, "elems_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatTuple {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        required::<_, A::Error>(elems, "elems")?,
+                        elems_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatTuple {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatTuple {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatTupleStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTupleStruct", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatTupleStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTupleStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTupleStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatTupleStruct {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    path: next_element(&mut seq, 1usize, &self)?,
+                    pat: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut pat = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, PatTuple>(&mut map, &mut pat, "pat")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatTupleStruct {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    path: required::<_, A::Error>(path, "path")?,
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTupleStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatTupleStruct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatTupleStruct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatType", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("pat", &Wrap(&self.pat))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    pat: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Pat>>(&mut map, &mut pat, "pat")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    pat: required::<_, A::Error>(pat, "pat")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatWild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatWild", 1usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatWild {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatWild;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatWild")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PatWild {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatWild {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatWild", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for PatWild {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for PatWild {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Path", 3usize)?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("segments", &Wrap(&self.segments))?;
+        state.serialize_field("segments_trailing", &self.segments.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["leading_colon", "segments", "segments_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Path;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Path")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Path {
+                    leading_colon: flag(
+                        next_element::<_, bool>(&mut seq, 0usize, &self)?,
+                    ),
+                    segments: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut leading_colon = None;
+                let mut segments = None;
+                let mut segments_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut leading_colon, "leading_colon")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<PathSegment, token::Colon2>,
+                            >(&mut map, &mut segments, "segments")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut segments_trailing, "segments_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Path {
+                    leading_colon: flag(leading_colon.unwrap_or(false)),
+                    segments: trailing(
+                        required::<_, A::Error>(segments, "segments")?,
+                        segments_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Path", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Path {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Path {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PathArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PathArguments::None => {
+                serializer.serialize_unit_variant("PathArguments", 0u32, "None")
+            }
+            PathArguments::AngleBracketed(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "PathArguments",
+                        1u32,
+                        "AngleBracketed",
+                        &Wrap(v0),
+                    )
+            }
+            PathArguments::Parenthesized(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "PathArguments",
+                        2u32,
+                        "Parenthesized",
+                        &Wrap(v0),
+                    )
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PathArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "AngleBracketed", "Parenthesized"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum PathArguments")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(PathArguments::None)
+                    }
+                    Some(1usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<AngleBracketedGenericArguments>>()?
+                            .0;
+                        Ok(PathArguments::AngleBracketed(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<ParenthesizedGenericArguments>>()?
+                            .0;
+                        Ok(PathArguments::Parenthesized(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("PathArguments", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for PathArguments {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for PathArguments {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PathSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PathSegment", 2usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("arguments", &Wrap(&self.arguments))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PathSegment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "arguments"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathSegment;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PathSegment")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PathSegment {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    arguments: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut arguments = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                PathArguments,
+                            >(&mut map, &mut arguments, "arguments")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PathSegment {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    arguments: required::<_, A::Error>(arguments, "arguments")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PathSegment", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for PathSegment {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for PathSegment {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateEq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateEq", 2usize)?;
+        state.serialize_field("lhs_ty", &Wrap(&self.lhs_ty))?;
+        state.serialize_field("rhs_ty", &Wrap(&self.rhs_ty))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateEq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lhs_ty", "rhs_ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateEq;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateEq")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PredicateEq {
+                    lhs_ty: next_element(&mut seq, 0usize, &self)?,
+                    eq_token: Default::default(),
+                    rhs_ty: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lhs_ty = None;
+                let mut rhs_ty = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Type>(&mut map, &mut lhs_ty, "lhs_ty")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Type>(&mut map, &mut rhs_ty, "rhs_ty")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateEq {
+                    lhs_ty: required::<_, A::Error>(lhs_ty, "lhs_ty")?,
+                    eq_token: Default::default(),
+                    rhs_ty: required::<_, A::Error>(rhs_ty, "rhs_ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateEq", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for PredicateEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for PredicateEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateLifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateLifetime", 3usize)?;
+        state.serialize_field("lifetime", &Wrap(&self.lifetime))?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateLifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "bounds", "bounds_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateLifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateLifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PredicateLifetime {
+                    lifetime: next_element(&mut seq, 0usize, &self)?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetime = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Lifetime,
+                            >(&mut map, &mut lifetime, "lifetime")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Lifetime, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateLifetime {
+                    lifetime: required::<_, A::Error>(lifetime, "lifetime")?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateLifetime", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for PredicateLifetime {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for PredicateLifetime {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateType", 4usize)?;
+        state.serialize_field("lifetimes", &Wrap(&self.lifetimes))?;
+        state.serialize_field("bounded_ty", &Wrap(&self.bounded_ty))?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifetimes",
+            "bounded_ty",
+            "bounds",
+            "bounds_trailing",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(PredicateType {
+                    lifetimes: next_element(&mut seq, 0usize, &self)?,
+                    bounded_ty: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        next_element(&mut seq, 2usize, &self)?,
+                        next_element(&mut seq, 3usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                let mut bounded_ty = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<BoundLifetimes>,
+                            >(&mut map, &mut lifetimes, "lifetimes")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Type,
+                            >(&mut map, &mut bounded_ty, "bounded_ty")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateType {
+                    lifetimes: lifetimes.unwrap_or(None),
+                    bounded_ty: required::<_, A::Error>(bounded_ty, "bounded_ty")?,
+                    colon_token: Default::default(),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for PredicateType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for PredicateType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for QSelf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("QSelf", 3usize)?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("position", &Wrap(&self.position))?;
+        state.serialize_field("as_token", &self.as_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for QSelf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ty", "position", "as_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = QSelf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct QSelf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty: next_element(&mut seq, 0usize, &self)?,
+                    position: next_element(&mut seq, 1usize, &self)?,
+                    as_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ty = None;
+                let mut position = None;
+                let mut as_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut ty, "ty")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, usize>(&mut map, &mut position, "position")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut as_token, "as_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    position: required::<_, A::Error>(position, "position")?,
+                    as_token: flag(as_token.unwrap_or(false)),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("QSelf", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for QSelf {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for QSelf {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for RangeLimits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RangeLimits::HalfOpen(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 0u32, "HalfOpen")
+            }
+            RangeLimits::Closed(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 1u32, "Closed")
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for RangeLimits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["HalfOpen", "Closed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = RangeLimits;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum RangeLimits")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(RangeLimits::HalfOpen(Default::default()))
+                    }
+                    Some(1usize) => {
+                        variant.unit_variant()?;
+                        Ok(RangeLimits::Closed(Default::default()))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("RangeLimits", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for RangeLimits {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for RangeLimits {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Receiver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Receiver", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("reference", &Wrap(&self.reference))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Receiver {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "reference", "mutability"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Receiver;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Receiver")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Receiver {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    reference: next_element(&mut seq, 1usize, &self)?,
+                    mutability: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    self_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut reference = None;
+                let mut mutability = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::And, Option<Lifetime>)>,
+                            >(&mut map, &mut reference, "reference")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Receiver {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    reference: reference.unwrap_or(None),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    self_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Receiver", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Receiver {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Receiver {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Requires {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Requires", 2usize)?;
+        state.serialize_field("exprs", &Wrap(&self.exprs))?;
+        state.serialize_field("exprs_trailing", &self.exprs.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Requires {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["exprs", "exprs_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Requires;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Requires")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Requires {
+                    requires_token: Default::default(),
+                    exprs: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut exprs = None;
+                let mut exprs_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Expr, token::Comma>,
+                            >(&mut map, &mut exprs, "exprs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut exprs_trailing, "exprs_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Requires {
+                    requires_token: Default::default(),
+                    exprs: trailing(
+                        required::<_, A::Error>(exprs, "exprs")?,
+                        exprs_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Requires", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for Requires {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for Requires {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReturnType::Default => {
+                serializer.serialize_unit_variant("ReturnType", 0u32, "Default")
+            }
+            ReturnType::Type(v0, v1) => {
+                let mut state = serializer
+                    .serialize_tuple_variant("ReturnType", 1u32, "Type", 2usize)?;
+                state.serialize_field(&Wrap(v0))?;
+                state.serialize_field(&Wrap(v1))?;
+                state.end()
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Default", "Type"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ReturnType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ReturnType")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(ReturnType::Default)
+                    }
+                    Some(1usize) => {
+                        let (v0, v1) = variant
+                            .tuple_variant(
+                                2usize,
+                                TupleVisitor::<(token::RArrow, Box<Type>)>::new(),
+                            )?;
+                        Ok(ReturnType::Type(v0, v1))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ReturnType", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for ReturnType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for ReturnType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Signature", 11usize)?;
+        state.serialize_field("constness", &self.constness.is_some())?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &Wrap(&self.abi))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("inputs", &Wrap(&self.inputs))?;
+        state.serialize_field("inputs_trailing", &self.inputs.trailing_punct())?;
+        state.serialize_field("variadic", &Wrap(&self.variadic))?;
+        state.serialize_field("output", &Wrap(&self.output))?;
+        state.serialize_field("spec", &Wrap(&self.spec))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "constness",
+            "asyncness",
+            "unsafety",
+            "abi",
+            "ident",
+            "generics",
+            "inputs",
+            "inputs_trailing",
+            "variadic",
+            "output",
+            "spec",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Signature;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Signature")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Signature {
+                    constness: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    asyncness: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    unsafety: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    abi: next_element(&mut seq, 3usize, &self)?,
+                    fn_token: Default::default(),
+                    ident: next_element(&mut seq, 4usize, &self)?,
+                    generics: next_element(&mut seq, 5usize, &self)?,
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        next_element(&mut seq, 6usize, &self)?,
+                        next_element(&mut seq, 7usize, &self)?,
+                    ),
+                    variadic: next_element(&mut seq, 8usize, &self)?,
+                    output: next_element(&mut seq, 9usize, &self)?,
+                    spec: next_element(&mut seq, 10usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constness = None;
+                let mut asyncness = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut inputs = None;
+                let mut inputs_trailing = None;
+                let mut variadic = None;
+                let mut output = None;
+                let mut spec = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut constness, "constness")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, bool>(&mut map, &mut asyncness, "asyncness")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, bool>(&mut map, &mut unsafety, "unsafety")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Option<Abi>>(&mut map, &mut abi, "abi")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(6usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<FnArg, token::Comma>,
+                            >(&mut map, &mut inputs, "inputs")?
+                        }
+                        Some(7usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut inputs_trailing, "inputs_trailing")?
+                        }
+                        Some(8usize) => {
+                            next_value::<
+                                _,
+                                Option<Variadic>,
+                            >(&mut map, &mut variadic, "variadic")?
+                        }
+                        Some(9usize) => {
+                            next_value::<_, ReturnType>(&mut map, &mut output, "output")?
+                        }
+                        Some(10usize) => {
+                            next_value::<
+                                _,
+                                Option<Box<SpecClauses>>,
+                            >(&mut map, &mut spec, "spec")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Signature {
+                    constness: flag(constness.unwrap_or(false)),
+                    asyncness: flag(asyncness.unwrap_or(false)),
+                    unsafety: flag(unsafety.unwrap_or(false)),
+                    abi: abi.unwrap_or(None),
+                    fn_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        required::<_, A::Error>(inputs, "inputs")?,
+                        inputs_trailing.unwrap_or(false),
+                    ),
+                    variadic: variadic.unwrap_or(None),
+                    output: required::<_, A::Error>(output, "output")?,
+                    spec: spec.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Signature", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Signature {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Signature {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for SpecClauses {
+    #[cfg_attr(not(any(feature = "verus")), allow(unused_variables, unused_mut))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer
+            .serialize_struct(
+                "SpecClauses",
+                0usize + if cfg!(feature = "verus") { 4usize } else { 0 },
+            )?;
+        #[cfg(feature = "verus")]
+        state.serialize_field("requires", &Wrap(&self.requires))?;
+        #[cfg(feature = "verus")]
+        state.serialize_field("ensures", &Wrap(&self.ensures))?;
+        #[cfg(feature = "verus")]
+        state.serialize_field("invariant", &Wrap(&self.invariant))?;
+        #[cfg(feature = "verus")]
+        state.serialize_field("decreases", &Wrap(&self.decreases))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for SpecClauses {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[cfg(feature = "verus")]
+        const FIELDS: &[&str] = &["requires", "ensures", "invariant", "decreases"];
+        #[cfg(not(feature = "verus"))]
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = SpecClauses;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct SpecClauses")
+            }
+            #[cfg_attr(not(any(feature = "verus")), allow(unused_variables, unused_mut))]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(SpecClauses {
+                    #[cfg(feature = "verus")]
+                    requires: next_element(&mut seq, 0usize, &self)?,
+                    #[cfg(feature = "verus")]
+                    ensures: next_element(&mut seq, 1usize, &self)?,
+                    #[cfg(feature = "verus")]
+                    invariant: next_element(&mut seq, 2usize, &self)?,
+                    #[cfg(feature = "verus")]
+                    decreases: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                #[cfg(feature = "verus")]
+                let mut requires = None;
+                #[cfg(feature = "verus")]
+                let mut ensures = None;
+                #[cfg(feature = "verus")]
+                let mut invariant = None;
+                #[cfg(feature = "verus")]
+                let mut decreases = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        #[cfg(feature = "verus")]
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<Requires>,
+                            >(&mut map, &mut requires, "requires")?
+                        }
+                        #[cfg(feature = "verus")]
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Option<Ensures>,
+                            >(&mut map, &mut ensures, "ensures")?
+                        }
+                        #[cfg(feature = "verus")]
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<Invariant>,
+                            >(&mut map, &mut invariant, "invariant")?
+                        }
+                        #[cfg(feature = "verus")]
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<Decreases>,
+                            >(&mut map, &mut decreases, "decreases")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SpecClauses {
+                    #[cfg(feature = "verus")]
+                    requires: requires.unwrap_or(None),
+                    #[cfg(feature = "verus")]
+                    ensures: ensures.unwrap_or(None),
+                    #[cfg(feature = "verus")]
+                    invariant: invariant.unwrap_or(None),
+                    #[cfg(feature = "verus")]
+                    decreases: decreases.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("SpecClauses", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for SpecClauses {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for SpecClauses {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Stmt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Stmt::Local(v0) => {
+                serializer.serialize_newtype_variant("Stmt", 0u32, "Local", &Wrap(v0))
+            }
+            Stmt::Item(v0) => {
+                serializer.serialize_newtype_variant("Stmt", 1u32, "Item", &Wrap(v0))
+            }
+            Stmt::Expr(v0) => {
+                serializer.serialize_newtype_variant("Stmt", 2u32, "Expr", &Wrap(v0))
+            }
+            Stmt::Semi(v0, v1) => {
+                let mut state = serializer
+                    .serialize_tuple_variant("Stmt", 3u32, "Semi", 2usize)?;
+                state.serialize_field(&Wrap(v0))?;
+                state.serialize_field(&Wrap(v1))?;
+                state.end()
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Stmt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Local", "Item", "Expr", "Semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Stmt;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Stmt")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Local>>()?.0;
+                        Ok(Stmt::Local(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Item>>()?.0;
+                        Ok(Stmt::Item(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Expr>>()?.0;
+                        Ok(Stmt::Expr(v0))
+                    }
+                    Some(3usize) => {
+                        let (v0, v1) = variant
+                            .tuple_variant(
+                                2usize,
+                                TupleVisitor::<(Expr, token::Semi)>::new(),
+                            )?;
+                        Ok(Stmt::Semi(v0, v1))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Stmt", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for Stmt {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for Stmt {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitBound", 4usize)?;
+        state.serialize_field("paren_token", &Wrap(&self.paren_token))?;
+        state.serialize_field("modifier", &Wrap(&self.modifier))?;
+        state.serialize_field("lifetimes", &Wrap(&self.lifetimes))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "modifier", "lifetimes", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitBound")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TraitBound {
+                    paren_token: next_element(&mut seq, 0usize, &self)?,
+                    modifier: next_element(&mut seq, 1usize, &self)?,
+                    lifetimes: next_element(&mut seq, 2usize, &self)?,
+                    path: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token = None;
+                let mut modifier = None;
+                let mut lifetimes = None;
+                let mut path = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<token::Paren>,
+                            >(&mut map, &mut paren_token, "paren_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                TraitBoundModifier,
+                            >(&mut map, &mut modifier, "modifier")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<BoundLifetimes>,
+                            >(&mut map, &mut lifetimes, "lifetimes")?
+                        }
+                        Some(3usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitBound {
+                    paren_token: paren_token.unwrap_or(None),
+                    modifier: required::<_, A::Error>(modifier, "modifier")?,
+                    lifetimes: lifetimes.unwrap_or(None),
+                    path: required::<_, A::Error>(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitBound", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TraitBound {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TraitBound {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitBoundModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitBoundModifier::None => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 0u32, "None")
+            }
+            TraitBoundModifier::Maybe(_) => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 1u32, "Maybe")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitBoundModifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "Maybe"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBoundModifier;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitBoundModifier")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(TraitBoundModifier::None)
+                    }
+                    Some(1usize) => {
+                        variant.unit_variant()?;
+                        Ok(TraitBoundModifier::Maybe(Default::default()))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitBoundModifier", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TraitBoundModifier {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TraitBoundModifier {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitItem::Const(v0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 0u32, "Const", &Wrap(v0))
+            }
+            TraitItem::Method(v0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 1u32, "Method", &Wrap(v0))
+            }
+            TraitItem::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 2u32, "Type", &Wrap(v0))
+            }
+            TraitItem::Macro(v0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 3u32, "Macro", &Wrap(v0))
+            }
+            TraitItem::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant("TraitItem", 4u32, "Verbatim", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TraitItemConst>>()?.0;
+                        Ok(TraitItem::Const(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TraitItemMethod>>()?.0;
+                        Ok(TraitItem::Method(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TraitItemType>>()?.0;
+                        Ok(TraitItem::Type(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TraitItemMacro>>()?.0;
+                        Ok(TraitItem::Macro(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(TraitItem::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for TraitItem {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for TraitItem {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemConst", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("ty", &Wrap(&self.ty))?;
+        state.serialize_field("default", &Wrap(&self.default))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "default"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TraitItemConst {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    const_token: Default::default(),
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: Default::default(),
+                    ty: next_element(&mut seq, 2usize, &self)?,
+                    default: next_element(&mut seq, 3usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut default = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => next_value::<_, Type>(&mut map, &mut ty, "ty")?,
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Eq, Expr)>,
+                            >(&mut map, &mut default, "default")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemConst {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    const_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required::<_, A::Error>(ty, "ty")?,
+                    default: default.unwrap_or(None),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for TraitItemConst {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for TraitItemConst {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMacro", 3usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TraitItemMacro {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    mac: next_element(&mut seq, 1usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemMacro {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for TraitItemMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for TraitItemMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMethod", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("sig", &Wrap(&self.sig))?;
+        state.serialize_field("default", &Wrap(&self.default))?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "sig", "default", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TraitItemMethod {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    sig: next_element(&mut seq, 1usize, &self)?,
+                    default: next_element(&mut seq, 2usize, &self)?,
+                    semi_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut sig = None;
+                let mut default = None;
+                let mut semi_token = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Signature>(&mut map, &mut sig, "sig")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Option<Block>,
+                            >(&mut map, &mut default, "default")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut semi_token, "semi_token")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemMethod {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    sig: required::<_, A::Error>(sig, "sig")?,
+                    default: default.unwrap_or(None),
+                    semi_token: flag(semi_token.unwrap_or(false)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for TraitItemMethod {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for TraitItemMethod {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemType", 7usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("generics", &Wrap(&self.generics))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.serialize_field("default", &Wrap(&self.default))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "generics",
+            "colon_token",
+            "bounds",
+            "bounds_trailing",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TraitItemType {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    type_token: Default::default(),
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    generics: next_element(&mut seq, 2usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 3usize, &self)?),
+                    bounds: trailing(
+                        next_element(&mut seq, 4usize, &self)?,
+                        next_element(&mut seq, 5usize, &self)?,
+                    ),
+                    default: next_element(&mut seq, 6usize, &self)?,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                let mut default = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                Generics,
+                            >(&mut map, &mut generics, "generics")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        Some(6usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Eq, Type)>,
+                            >(&mut map, &mut default, "default")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemType {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    type_token: Default::default(),
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    generics: required::<_, A::Error>(generics, "generics")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                    default: default.unwrap_or(None),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for TraitItemType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for TraitItemType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Type::Array(v0) => {
+                serializer.serialize_newtype_variant("Type", 0u32, "Array", &Wrap(v0))
+            }
+            Type::BareFn(v0) => {
+                serializer.serialize_newtype_variant("Type", 1u32, "BareFn", &Wrap(v0))
+            }
+            Type::Group(v0) => {
+                serializer.serialize_newtype_variant("Type", 2u32, "Group", &Wrap(v0))
+            }
+            Type::ImplTrait(v0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 3u32, "ImplTrait", &Wrap(v0))
+            }
+            Type::Infer(v0) => {
+                serializer.serialize_newtype_variant("Type", 4u32, "Infer", &Wrap(v0))
+            }
+            Type::Macro(v0) => {
+                serializer.serialize_newtype_variant("Type", 5u32, "Macro", &Wrap(v0))
+            }
+            Type::Never(v0) => {
+                serializer.serialize_newtype_variant("Type", 6u32, "Never", &Wrap(v0))
+            }
+            Type::Paren(v0) => {
+                serializer.serialize_newtype_variant("Type", 7u32, "Paren", &Wrap(v0))
+            }
+            Type::Path(v0) => {
+                serializer.serialize_newtype_variant("Type", 8u32, "Path", &Wrap(v0))
+            }
+            Type::Ptr(v0) => {
+                serializer.serialize_newtype_variant("Type", 9u32, "Ptr", &Wrap(v0))
+            }
+            Type::Reference(v0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 10u32, "Reference", &Wrap(v0))
+            }
+            Type::Slice(v0) => {
+                serializer.serialize_newtype_variant("Type", 11u32, "Slice", &Wrap(v0))
+            }
+            Type::TraitObject(v0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 12u32, "TraitObject", &Wrap(v0))
+            }
+            Type::Tuple(v0) => {
+                serializer.serialize_newtype_variant("Type", 13u32, "Tuple", &Wrap(v0))
+            }
+            Type::Verbatim(v0) => {
+                serializer
+                    .serialize_newtype_variant("Type", 14u32, "Verbatim", &Wrap(v0))
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "BareFn",
+            "Group",
+            "ImplTrait",
+            "Infer",
+            "Macro",
+            "Never",
+            "Paren",
+            "Path",
+            "Ptr",
+            "Reference",
+            "Slice",
+            "TraitObject",
+            "Tuple",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Type;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Type")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeArray>>()?.0;
+                        Ok(Type::Array(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeBareFn>>()?.0;
+                        Ok(Type::BareFn(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeGroup>>()?.0;
+                        Ok(Type::Group(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeImplTrait>>()?.0;
+                        Ok(Type::ImplTrait(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeInfer>>()?.0;
+                        Ok(Type::Infer(v0))
+                    }
+                    Some(5usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeMacro>>()?.0;
+                        Ok(Type::Macro(v0))
+                    }
+                    Some(6usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeNever>>()?.0;
+                        Ok(Type::Never(v0))
+                    }
+                    Some(7usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeParen>>()?.0;
+                        Ok(Type::Paren(v0))
+                    }
+                    Some(8usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypePath>>()?.0;
+                        Ok(Type::Path(v0))
+                    }
+                    Some(9usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypePtr>>()?.0;
+                        Ok(Type::Ptr(v0))
+                    }
+                    Some(10usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeReference>>()?.0;
+                        Ok(Type::Reference(v0))
+                    }
+                    Some(11usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeSlice>>()?.0;
+                        Ok(Type::Slice(v0))
+                    }
+                    Some(12usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeTraitObject>>()?.0;
+                        Ok(Type::TraitObject(v0))
+                    }
+                    Some(13usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TypeTuple>>()?.0;
+                        Ok(Type::Tuple(v0))
+                    }
+                    Some(14usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<proc_macro2::TokenStream>>()?
+                            .0;
+                        Ok(Type::Verbatim(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Type", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Type {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Type {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeArray", 2usize)?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.serialize_field("len", &Wrap(&self.len))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem: next_element(&mut seq, 0usize, &self)?,
+                    semi_token: Default::default(),
+                    len: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                let mut len = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        Some(1usize) => next_value::<_, Expr>(&mut map, &mut len, "len")?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                    semi_token: Default::default(),
+                    len: required::<_, A::Error>(len, "len")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeArray", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeArray {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeArray {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeBareFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeBareFn", 7usize)?;
+        state.serialize_field("lifetimes", &Wrap(&self.lifetimes))?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &Wrap(&self.abi))?;
+        state.serialize_field("inputs", &Wrap(&self.inputs))?;
+        state.serialize_field("inputs_trailing", &self.inputs.trailing_punct())?;
+        state.serialize_field("variadic", &Wrap(&self.variadic))?;
+        state.serialize_field("output", &Wrap(&self.output))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeBareFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifetimes",
+            "unsafety",
+            "abi",
+            "inputs",
+            "inputs_trailing",
+            "variadic",
+            "output",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeBareFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeBareFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeBareFn {
+                    lifetimes: next_element(&mut seq, 0usize, &self)?,
+                    unsafety: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    abi: next_element(&mut seq, 2usize, &self)?,
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        next_element(&mut seq, 3usize, &self)?,
+                        next_element(&mut seq, 4usize, &self)?,
+                    ),
+                    variadic: next_element(&mut seq, 5usize, &self)?,
+                    output: next_element(&mut seq, 6usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                let mut inputs = None;
+                let mut inputs_trailing = None;
+                let mut variadic = None;
+                let mut output = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<BoundLifetimes>,
+                            >(&mut map, &mut lifetimes, "lifetimes")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, bool>(&mut map, &mut unsafety, "unsafety")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Option<Abi>>(&mut map, &mut abi, "abi")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<BareFnArg, token::Comma>,
+                            >(&mut map, &mut inputs, "inputs")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut inputs_trailing, "inputs_trailing")?
+                        }
+                        Some(5usize) => {
+                            next_value::<
+                                _,
+                                Option<Variadic>,
+                            >(&mut map, &mut variadic, "variadic")?
+                        }
+                        Some(6usize) => {
+                            next_value::<_, ReturnType>(&mut map, &mut output, "output")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeBareFn {
+                    lifetimes: lifetimes.unwrap_or(None),
+                    unsafety: flag(unsafety.unwrap_or(false)),
+                    abi: abi.unwrap_or(None),
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs: trailing(
+                        required::<_, A::Error>(inputs, "inputs")?,
+                        inputs_trailing.unwrap_or(false),
+                    ),
+                    variadic: variadic.unwrap_or(None),
+                    output: required::<_, A::Error>(output, "output")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeBareFn", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeBareFn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeBareFn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeGroup", 1usize)?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeGroup {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeGroup {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeImplTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeImplTrait", 2usize)?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeImplTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bounds", "bounds_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeImplTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeImplTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeImplTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeImplTrait {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeImplTrait {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeInfer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("TypeInfer", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeInfer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeInfer;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeInfer")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeInfer", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeInfer {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeInfer {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeMacro", 1usize)?;
+        state.serialize_field("mac", &Wrap(&self.mac))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeMacro {
+                    mac: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut mac = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Macro>(&mut map, &mut mac, "mac")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeMacro {
+                    mac: required::<_, A::Error>(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeMacro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeMacro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeNever {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("TypeNever", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeNever {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeNever;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeNever")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeNever", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeNever {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeNever {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParam", 7usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &Wrap(&self.default))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "colon_token",
+            "bounds",
+            "bounds_trailing",
+            "eq_token",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeParam {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    colon_token: flag(next_element::<_, bool>(&mut seq, 2usize, &self)?),
+                    bounds: trailing(
+                        next_element(&mut seq, 3usize, &self)?,
+                        next_element(&mut seq, 4usize, &self)?,
+                    ),
+                    eq_token: flag(next_element::<_, bool>(&mut seq, 5usize, &self)?),
+                    default: next_element(&mut seq, 6usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                let mut eq_token = None;
+                let mut default = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut colon_token, "colon_token")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(4usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        Some(5usize) => {
+                            next_value::<_, bool>(&mut map, &mut eq_token, "eq_token")?
+                        }
+                        Some(6usize) => {
+                            next_value::<
+                                _,
+                                Option<Type>,
+                            >(&mut map, &mut default, "default")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeParam {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon_token: flag(colon_token.unwrap_or(false)),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                    eq_token: flag(eq_token.unwrap_or(false)),
+                    default: default.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeParam {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeParam {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParamBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypeParamBound::Trait(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        0u32,
+                        "Trait",
+                        &Wrap(v0),
+                    )
+            }
+            TypeParamBound::Lifetime(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "TypeParamBound",
+                        1u32,
+                        "Lifetime",
+                        &Wrap(v0),
+                    )
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParamBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Trait", "Lifetime"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParamBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TypeParamBound")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<TraitBound>>()?.0;
+                        Ok(TypeParamBound::Trait(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<Lifetime>>()?.0;
+                        Ok(TypeParamBound::Lifetime(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TypeParamBound", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeParamBound {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeParamBound {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParen", 1usize)?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeParen {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeParen {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePath", 2usize)?;
+        state.serialize_field("qself", &Wrap(&self.qself))?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypePath {
+                    qself: next_element(&mut seq, 0usize, &self)?,
+                    path: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut qself = None;
+                let mut path = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<QSelf>,
+                            >(&mut map, &mut qself, "qself")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Path>(&mut map, &mut path, "path")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypePath {
+                    qself: qself.unwrap_or(None),
+                    path: required::<_, A::Error>(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePath", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypePath {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypePath {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypePtr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePtr", 3usize)?;
+        state.serialize_field("const_token", &self.const_token.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypePtr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["const_token", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePtr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePtr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    mutability: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    elem: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut const_token = None;
+                let mut mutability = None;
+                let mut elem = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut const_token, "const_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token: flag(const_token.unwrap_or(false)),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePtr", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypePtr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypePtr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeReference", 3usize)?;
+        state.serialize_field("lifetime", &Wrap(&self.lifetime))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime: next_element(&mut seq, 0usize, &self)?,
+                    mutability: flag(next_element::<_, bool>(&mut seq, 1usize, &self)?),
+                    elem: next_element(&mut seq, 2usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetime = None;
+                let mut mutability = None;
+                let mut elem = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Option<Lifetime>,
+                            >(&mut map, &mut lifetime, "lifetime")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut mutability, "mutability")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime: lifetime.unwrap_or(None),
+                    mutability: flag(mutability.unwrap_or(false)),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeReference", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeReference {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeReference {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeSlice", 1usize)?;
+        state.serialize_field("elem", &Wrap(&self.elem))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, Box<Type>>(&mut map, &mut elem, "elem")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem: required::<_, A::Error>(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeSlice {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeSlice {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeTraitObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTraitObject", 3usize)?;
+        state.serialize_field("dyn_token", &self.dyn_token.is_some())?;
+        state.serialize_field("bounds", &Wrap(&self.bounds))?;
+        state.serialize_field("bounds_trailing", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeTraitObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["dyn_token", "bounds", "bounds_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTraitObject;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTraitObject")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeTraitObject {
+                    dyn_token: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    bounds: trailing(
+                        next_element(&mut seq, 1usize, &self)?,
+                        next_element(&mut seq, 2usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut dyn_token = None;
+                let mut bounds = None;
+                let mut bounds_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut dyn_token, "dyn_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<TypeParamBound, token::Add>,
+                            >(&mut map, &mut bounds, "bounds")?
+                        }
+                        Some(2usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut bounds_trailing, "bounds_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeTraitObject {
+                    dyn_token: flag(dyn_token.unwrap_or(false)),
+                    bounds: trailing(
+                        required::<_, A::Error>(bounds, "bounds")?,
+                        bounds_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTraitObject", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeTraitObject {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeTraitObject {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTuple", 2usize)?;
+        state.serialize_field("elems", &Wrap(&self.elems))?;
+        state.serialize_field("elems_trailing", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elems", "elems_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elems = None;
+                let mut elems_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<Type, token::Comma>,
+                            >(&mut map, &mut elems, "elems")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut elems_trailing, "elems_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems: trailing(
+                        required::<_, A::Error>(elems, "elems")?,
+                        elems_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for TypeTuple {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for TypeTuple {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UnOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UnOp::Deref(_) => serializer.serialize_unit_variant("UnOp", 0u32, "Deref"),
+            UnOp::Not(_) => serializer.serialize_unit_variant("UnOp", 1u32, "Not"),
+            UnOp::Neg(_) => serializer.serialize_unit_variant("UnOp", 2u32, "Neg"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UnOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Deref", "Not", "Neg"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UnOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UnOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        variant.unit_variant()?;
+                        Ok(UnOp::Deref(Default::default()))
+                    }
+                    Some(1usize) => {
+                        variant.unit_variant()?;
+                        Ok(UnOp::Not(Default::default()))
+                    }
+                    Some(2usize) => {
+                        variant.unit_variant()?;
+                        Ok(UnOp::Neg(Default::default()))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UnOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for UnOp {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for UnOp {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseGlob {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("UseGlob", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGlob;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGlob")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGlob", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UseGlob {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UseGlob {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseGroup", 2usize)?;
+        state.serialize_field("items", &Wrap(&self.items))?;
+        state.serialize_field("items_trailing", &self.items.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["items", "items_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut items = None;
+                let mut items_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<UseTree, token::Comma>,
+                            >(&mut map, &mut items, "items")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut items_trailing, "items_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items: trailing(
+                        required::<_, A::Error>(items, "items")?,
+                        items_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UseGroup {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UseGroup {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseName", 1usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseName;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseName")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseName {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseName {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseName", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UseName {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UseName {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UsePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UsePath", 2usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("tree", &Wrap(&self.tree))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UsePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "tree"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UsePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UsePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UsePath {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    colon2_token: Default::default(),
+                    tree: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut tree = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<UseTree>>(&mut map, &mut tree, "tree")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UsePath {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    colon2_token: Default::default(),
+                    tree: required::<_, A::Error>(tree, "tree")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UsePath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UsePath {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UsePath {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseRename {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseRename", 2usize)?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("rename", &Wrap(&self.rename))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseRename {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "rename"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseRename;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseRename")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseRename {
+                    ident: next_element(&mut seq, 0usize, &self)?,
+                    as_token: Default::default(),
+                    rename: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut rename = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut rename, "rename")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseRename {
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    as_token: Default::default(),
+                    rename: required::<_, A::Error>(rename, "rename")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseRename", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UseRename {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UseRename {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseTree {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UseTree::Path(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 0u32, "Path", &Wrap(v0))
+            }
+            UseTree::Name(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 1u32, "Name", &Wrap(v0))
+            }
+            UseTree::Rename(v0) => {
+                serializer
+                    .serialize_newtype_variant("UseTree", 2u32, "Rename", &Wrap(v0))
+            }
+            UseTree::Glob(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 3u32, "Glob", &Wrap(v0))
+            }
+            UseTree::Group(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 4u32, "Group", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "Name", "Rename", "Glob", "Group"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseTree;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UseTree")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<UsePath>>()?.0;
+                        Ok(UseTree::Path(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<UseName>>()?.0;
+                        Ok(UseTree::Name(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<UseRename>>()?.0;
+                        Ok(UseTree::Rename(v0))
+                    }
+                    Some(3usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<UseGlob>>()?.0;
+                        Ok(UseTree::Glob(v0))
+                    }
+                    Some(4usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<UseGroup>>()?.0;
+                        Ok(UseTree::Group(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UseTree", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Ser for UseTree {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> De<'de> for UseTree {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Variadic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variadic", 1usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variadic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variadic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variadic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Variadic {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    dots: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Variadic {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    dots: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variadic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for Variadic {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for Variadic {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variant", 4usize)?;
+        state.serialize_field("attrs", &Wrap(&self.attrs))?;
+        state.serialize_field("ident", &Wrap(&self.ident))?;
+        state.serialize_field("fields", &Wrap(&self.fields))?;
+        state.serialize_field("discriminant", &Wrap(&self.discriminant))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "fields", "discriminant"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variant;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variant")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(Variant {
+                    attrs: next_element(&mut seq, 0usize, &self)?,
+                    ident: next_element(&mut seq, 1usize, &self)?,
+                    fields: next_element(&mut seq, 2usize, &self)?,
+                    discriminant: next_element(&mut seq, 3usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut fields = None;
+                let mut discriminant = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Vec<Attribute>,
+                            >(&mut map, &mut attrs, "attrs")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                proc_macro2::Ident,
+                            >(&mut map, &mut ident, "ident")?
+                        }
+                        Some(2usize) => {
+                            next_value::<_, Fields>(&mut map, &mut fields, "fields")?
+                        }
+                        Some(3usize) => {
+                            next_value::<
+                                _,
+                                Option<(token::Eq, Expr)>,
+                            >(&mut map, &mut discriminant, "discriminant")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Variant {
+                    attrs: required::<_, A::Error>(attrs, "attrs")?,
+                    ident: required::<_, A::Error>(ident, "ident")?,
+                    fields: required::<_, A::Error>(fields, "fields")?,
+                    discriminant: discriminant.unwrap_or(None),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variant", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for Variant {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for Variant {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("VisCrate", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisCrate")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for VisCrate {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for VisCrate {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisPublic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("VisPublic", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisPublic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisPublic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisPublic")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisPublic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for VisPublic {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for VisPublic {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisRestricted {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisRestricted", 2usize)?;
+        state.serialize_field("in_token", &self.in_token.is_some())?;
+        state.serialize_field("path", &Wrap(&self.path))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisRestricted {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["in_token", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisRestricted;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisRestricted")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token: flag(next_element::<_, bool>(&mut seq, 0usize, &self)?),
+                    path: next_element(&mut seq, 1usize, &self)?,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut in_token = None;
+                let mut path = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<_, bool>(&mut map, &mut in_token, "in_token")?
+                        }
+                        Some(1usize) => {
+                            next_value::<_, Box<Path>>(&mut map, &mut path, "path")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token: flag(in_token.unwrap_or(false)),
+                    path: required::<_, A::Error>(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisRestricted", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for VisRestricted {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for VisRestricted {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Visibility::Public(v0) => {
+                serializer
+                    .serialize_newtype_variant("Visibility", 0u32, "Public", &Wrap(v0))
+            }
+            Visibility::Crate(v0) => {
+                serializer
+                    .serialize_newtype_variant("Visibility", 1u32, "Crate", &Wrap(v0))
+            }
+            Visibility::Restricted(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "Visibility",
+                        2u32,
+                        "Restricted",
+                        &Wrap(v0),
+                    )
+            }
+            Visibility::Inherited => {
+                serializer.serialize_unit_variant("Visibility", 3u32, "Inherited")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Public", "Crate", "Restricted", "Inherited"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Visibility;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Visibility")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<VisPublic>>()?.0;
+                        Ok(Visibility::Public(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<VisCrate>>()?.0;
+                        Ok(Visibility::Crate(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<VisRestricted>>()?.0;
+                        Ok(Visibility::Restricted(v0))
+                    }
+                    Some(3usize) => {
+                        variant.unit_variant()?;
+                        Ok(Visibility::Inherited)
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Visibility", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Ser for Visibility {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> De<'de> for Visibility {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for WhereClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("WhereClause", 2usize)?;
+        state.serialize_field("predicates", &Wrap(&self.predicates))?;
+        state.serialize_field("predicates_trailing", &self.predicates.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for WhereClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["predicates", "predicates_trailing"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WhereClause;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WhereClause")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates: trailing(
+                        next_element(&mut seq, 0usize, &self)?,
+                        next_element(&mut seq, 1usize, &self)?,
+                    ),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut predicates = None;
+                let mut predicates_trailing = None;
+                while let Some(field) = map.next_key::<Key>()? {
+                    match field.index(FIELDS) {
+                        Some(0usize) => {
+                            next_value::<
+                                _,
+                                Punctuated<WherePredicate, token::Comma>,
+                            >(&mut map, &mut predicates, "predicates")?
+                        }
+                        Some(1usize) => {
+                            next_value::<
+                                _,
+                                bool,
+                            >(&mut map, &mut predicates_trailing, "predicates_trailing")?
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates: trailing(
+                        required::<_, A::Error>(predicates, "predicates")?,
+                        predicates_trailing.unwrap_or(false),
+                    ),
+                })
+            }
+        }
+        deserializer.deserialize_struct("WhereClause", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for WhereClause {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for WhereClause {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for WherePredicate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            WherePredicate::Type(v0) => {
+                serializer
+                    .serialize_newtype_variant("WherePredicate", 0u32, "Type", &Wrap(v0))
+            }
+            WherePredicate::Lifetime(v0) => {
+                serializer
+                    .serialize_newtype_variant(
+                        "WherePredicate",
+                        1u32,
+                        "Lifetime",
+                        &Wrap(v0),
+                    )
+            }
+            WherePredicate::Eq(v0) => {
+                serializer
+                    .serialize_newtype_variant("WherePredicate", 2u32, "Eq", &Wrap(v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for WherePredicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Eq"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WherePredicate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum WherePredicate")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (key, variant) = data.variant::<Key>()?;
+                match key.index(VARIANTS) {
+                    Some(0usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PredicateType>>()?.0;
+                        Ok(WherePredicate::Type(v0))
+                    }
+                    Some(1usize) => {
+                        let v0 = variant
+                            .newtype_variant::<Unwrap<PredicateLifetime>>()?
+                            .0;
+                        Ok(WherePredicate::Lifetime(v0))
+                    }
+                    Some(2usize) => {
+                        let v0 = variant.newtype_variant::<Unwrap<PredicateEq>>()?.0;
+                        Ok(WherePredicate::Eq(v0))
+                    }
+                    _ => Err(key.unknown_variant(VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("WherePredicate", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl Ser for WherePredicate {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(serializer)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+impl<'de> De<'de> for WherePredicate {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+impl Ser for token::Abstract {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("abstract")
+    }
+}
+impl<'de> De<'de> for token::Abstract {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Add {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("+")
+    }
+}
+impl<'de> De<'de> for token::Add {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::AddEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("+=")
+    }
+}
+impl<'de> De<'de> for token::AddEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::And {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("&")
+    }
+}
+impl<'de> De<'de> for token::And {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::AndAnd {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("&&")
+    }
+}
+impl<'de> De<'de> for token::AndAnd {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::AndEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("&=")
+    }
+}
+impl<'de> De<'de> for token::AndEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::As {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("as")
+    }
+}
+impl<'de> De<'de> for token::As {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Async {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("async")
+    }
+}
+impl<'de> De<'de> for token::Async {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::At {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("@")
+    }
+}
+impl<'de> De<'de> for token::At {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Auto {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("auto")
+    }
+}
+impl<'de> De<'de> for token::Auto {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Await {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("await")
+    }
+}
+impl<'de> De<'de> for token::Await {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Bang {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("!")
+    }
+}
+impl<'de> De<'de> for token::Bang {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Become {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("become")
+    }
+}
+impl<'de> De<'de> for token::Become {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Box {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("box")
+    }
+}
+impl<'de> De<'de> for token::Box {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Break {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("break")
+    }
+}
+impl<'de> De<'de> for token::Break {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Caret {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("^")
+    }
+}
+impl<'de> De<'de> for token::Caret {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::CaretEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("^=")
+    }
+}
+impl<'de> De<'de> for token::CaretEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Colon {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(":")
+    }
+}
+impl<'de> De<'de> for token::Colon {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Colon2 {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("::")
+    }
+}
+impl<'de> De<'de> for token::Colon2 {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Comma {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(",")
+    }
+}
+impl<'de> De<'de> for token::Comma {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Const {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("const")
+    }
+}
+impl<'de> De<'de> for token::Const {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Continue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("continue")
+    }
+}
+impl<'de> De<'de> for token::Continue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Crate {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("crate")
+    }
+}
+impl<'de> De<'de> for token::Crate {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for token::Decreases {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("decreases")
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for token::Decreases {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Default {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("default")
+    }
+}
+impl<'de> De<'de> for token::Default {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Div {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("/")
+    }
+}
+impl<'de> De<'de> for token::Div {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::DivEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("/=")
+    }
+}
+impl<'de> De<'de> for token::DivEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Do {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("do")
+    }
+}
+impl<'de> De<'de> for token::Do {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Dollar {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("$")
+    }
+}
+impl<'de> De<'de> for token::Dollar {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Dot {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(".")
+    }
+}
+impl<'de> De<'de> for token::Dot {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Dot2 {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("..")
+    }
+}
+impl<'de> De<'de> for token::Dot2 {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Dot3 {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("...")
+    }
+}
+impl<'de> De<'de> for token::Dot3 {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::DotDotEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("..=")
+    }
+}
+impl<'de> De<'de> for token::DotDotEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Dyn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("dyn")
+    }
+}
+impl<'de> De<'de> for token::Dyn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Else {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("else")
+    }
+}
+impl<'de> De<'de> for token::Else {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for token::Ensures {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("ensures")
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for token::Ensures {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Enum {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("enum")
+    }
+}
+impl<'de> De<'de> for token::Enum {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Eq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("=")
+    }
+}
+impl<'de> De<'de> for token::Eq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::EqEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("==")
+    }
+}
+impl<'de> De<'de> for token::EqEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Extern {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("extern")
+    }
+}
+impl<'de> De<'de> for token::Extern {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::FatArrow {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("=>")
+    }
+}
+impl<'de> De<'de> for token::FatArrow {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Final {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("final")
+    }
+}
+impl<'de> De<'de> for token::Final {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Fn {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("fn")
+    }
+}
+impl<'de> De<'de> for token::Fn {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::For {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("for")
+    }
+}
+impl<'de> De<'de> for token::For {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Ge {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(">=")
+    }
+}
+impl<'de> De<'de> for token::Ge {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Gt {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(">")
+    }
+}
+impl<'de> De<'de> for token::Gt {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::If {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("if")
+    }
+}
+impl<'de> De<'de> for token::If {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Impl {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("impl")
+    }
+}
+impl<'de> De<'de> for token::Impl {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::In {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("in")
+    }
+}
+impl<'de> De<'de> for token::In {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for token::Invariant {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("invariant")
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for token::Invariant {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::LArrow {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("<-")
+    }
+}
+impl<'de> De<'de> for token::LArrow {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Le {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("<=")
+    }
+}
+impl<'de> De<'de> for token::Le {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Let {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("let")
+    }
+}
+impl<'de> De<'de> for token::Let {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Loop {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("loop")
+    }
+}
+impl<'de> De<'de> for token::Loop {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Lt {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("<")
+    }
+}
+impl<'de> De<'de> for token::Lt {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Macro {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("macro")
+    }
+}
+impl<'de> De<'de> for token::Macro {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Match {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("match")
+    }
+}
+impl<'de> De<'de> for token::Match {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Mod {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("mod")
+    }
+}
+impl<'de> De<'de> for token::Mod {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Move {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("move")
+    }
+}
+impl<'de> De<'de> for token::Move {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::MulEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("*=")
+    }
+}
+impl<'de> De<'de> for token::MulEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Mut {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("mut")
+    }
+}
+impl<'de> De<'de> for token::Mut {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Ne {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("!=")
+    }
+}
+impl<'de> De<'de> for token::Ne {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Or {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("|")
+    }
+}
+impl<'de> De<'de> for token::Or {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::OrEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("|=")
+    }
+}
+impl<'de> De<'de> for token::OrEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::OrOr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("||")
+    }
+}
+impl<'de> De<'de> for token::OrOr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Override {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("override")
+    }
+}
+impl<'de> De<'de> for token::Override {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Pound {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("#")
+    }
+}
+impl<'de> De<'de> for token::Pound {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Priv {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("priv")
+    }
+}
+impl<'de> De<'de> for token::Priv {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Pub {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("pub")
+    }
+}
+impl<'de> De<'de> for token::Pub {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Question {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("?")
+    }
+}
+impl<'de> De<'de> for token::Question {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::RArrow {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("->")
+    }
+}
+impl<'de> De<'de> for token::RArrow {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Ref {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("ref")
+    }
+}
+impl<'de> De<'de> for token::Ref {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Rem {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("%")
+    }
+}
+impl<'de> De<'de> for token::Rem {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::RemEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("%=")
+    }
+}
+impl<'de> De<'de> for token::RemEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+#[cfg(feature = "verus")]
+impl Ser for token::Requires {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("requires")
+    }
+}
+#[cfg(feature = "verus")]
+impl<'de> De<'de> for token::Requires {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Return {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("return")
+    }
+}
+impl<'de> De<'de> for token::Return {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::SelfType {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("Self")
+    }
+}
+impl<'de> De<'de> for token::SelfType {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::SelfValue {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("self")
+    }
+}
+impl<'de> De<'de> for token::SelfValue {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Semi {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(";")
+    }
+}
+impl<'de> De<'de> for token::Semi {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Shl {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("<<")
+    }
+}
+impl<'de> De<'de> for token::Shl {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::ShlEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("<<=")
+    }
+}
+impl<'de> De<'de> for token::ShlEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Shr {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(">>")
+    }
+}
+impl<'de> De<'de> for token::Shr {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::ShrEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(">>=")
+    }
+}
+impl<'de> De<'de> for token::ShrEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Star {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("*")
+    }
+}
+impl<'de> De<'de> for token::Star {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Static {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("static")
+    }
+}
+impl<'de> De<'de> for token::Static {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Struct {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("struct")
+    }
+}
+impl<'de> De<'de> for token::Struct {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Sub {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("-")
+    }
+}
+impl<'de> De<'de> for token::Sub {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::SubEq {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("-=")
+    }
+}
+impl<'de> De<'de> for token::SubEq {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Super {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("super")
+    }
+}
+impl<'de> De<'de> for token::Super {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Tilde {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("~")
+    }
+}
+impl<'de> De<'de> for token::Tilde {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Trait {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("trait")
+    }
+}
+impl<'de> De<'de> for token::Trait {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Try {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("try")
+    }
+}
+impl<'de> De<'de> for token::Try {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Type {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("type")
+    }
+}
+impl<'de> De<'de> for token::Type {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Typeof {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("typeof")
+    }
+}
+impl<'de> De<'de> for token::Typeof {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Underscore {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("_")
+    }
+}
+impl<'de> De<'de> for token::Underscore {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Union {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("union")
+    }
+}
+impl<'de> De<'de> for token::Union {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Unsafe {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("unsafe")
+    }
+}
+impl<'de> De<'de> for token::Unsafe {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Unsized {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("unsized")
+    }
+}
+impl<'de> De<'de> for token::Unsized {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Use {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("use")
+    }
+}
+impl<'de> De<'de> for token::Use {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Virtual {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("virtual")
+    }
+}
+impl<'de> De<'de> for token::Virtual {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Where {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("where")
+    }
+}
+impl<'de> De<'de> for token::Where {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::While {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("while")
+    }
+}
+impl<'de> De<'de> for token::While {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Yield {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("yield")
+    }
+}
+impl<'de> De<'de> for token::Yield {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Brace {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("{}")
+    }
+}
+impl<'de> De<'de> for token::Brace {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Bracket {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("[]")
+    }
+}
+impl<'de> De<'de> for token::Bracket {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Paren {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("()")
+    }
+}
+impl<'de> De<'de> for token::Paren {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+impl Ser for token::Group {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("")
+    }
+}
+impl<'de> De<'de> for token::Group {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
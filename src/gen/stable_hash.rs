@@ -0,0 +1,2552 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::hash::{StableHash, StableHasher};
+use crate::*;
+use std::hash::Hasher;
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Abi {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.name.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for AbiName {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            AbiName::Rust(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::C(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::CUnwind(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::System(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::SystemUnwind(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Cdecl(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Stdcall(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Fastcall(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Vectorcall(v0) => {
+                hasher.write_u8(8u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Thiscall(v0) => {
+                hasher.write_u8(9u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Aapcs(v0) => {
+                hasher.write_u8(10u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Win64(v0) => {
+                hasher.write_u8(11u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Sysv64(v0) => {
+                hasher.write_u8(12u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Efiapi(v0) => {
+                hasher.write_u8(13u8);
+                v0.stable_hash(hasher);
+            }
+            AbiName::Other(v0) => {
+                hasher.write_u8(14u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for AngleBracketedGenericArguments {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.colon2_token.stable_hash(hasher);
+        self.args.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Arm {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+        self.guard.stable_hash(hasher);
+        self.body.stable_hash(hasher);
+        self.comma.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for AttrStyle {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            AttrStyle::Outer => {
+                hasher.write_u8(0u8);
+            }
+            AttrStyle::Inner(_) => {
+                hasher.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Attribute {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.style.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+        self.tokens.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for BareFnArg {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.name.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for BinOp {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            BinOp::Add(_) => {
+                hasher.write_u8(0u8);
+            }
+            BinOp::Sub(_) => {
+                hasher.write_u8(1u8);
+            }
+            BinOp::Mul(_) => {
+                hasher.write_u8(2u8);
+            }
+            BinOp::Div(_) => {
+                hasher.write_u8(3u8);
+            }
+            BinOp::Rem(_) => {
+                hasher.write_u8(4u8);
+            }
+            BinOp::And(_) => {
+                hasher.write_u8(5u8);
+            }
+            BinOp::Or(_) => {
+                hasher.write_u8(6u8);
+            }
+            BinOp::BitXor(_) => {
+                hasher.write_u8(7u8);
+            }
+            BinOp::BitAnd(_) => {
+                hasher.write_u8(8u8);
+            }
+            BinOp::BitOr(_) => {
+                hasher.write_u8(9u8);
+            }
+            BinOp::Shl(_) => {
+                hasher.write_u8(10u8);
+            }
+            BinOp::Shr(_) => {
+                hasher.write_u8(11u8);
+            }
+            BinOp::Eq(_) => {
+                hasher.write_u8(12u8);
+            }
+            BinOp::Lt(_) => {
+                hasher.write_u8(13u8);
+            }
+            BinOp::Le(_) => {
+                hasher.write_u8(14u8);
+            }
+            BinOp::Ne(_) => {
+                hasher.write_u8(15u8);
+            }
+            BinOp::Ge(_) => {
+                hasher.write_u8(16u8);
+            }
+            BinOp::Gt(_) => {
+                hasher.write_u8(17u8);
+            }
+            BinOp::AddEq(_) => {
+                hasher.write_u8(18u8);
+            }
+            BinOp::SubEq(_) => {
+                hasher.write_u8(19u8);
+            }
+            BinOp::MulEq(_) => {
+                hasher.write_u8(20u8);
+            }
+            BinOp::DivEq(_) => {
+                hasher.write_u8(21u8);
+            }
+            BinOp::RemEq(_) => {
+                hasher.write_u8(22u8);
+            }
+            BinOp::BitXorEq(_) => {
+                hasher.write_u8(23u8);
+            }
+            BinOp::BitAndEq(_) => {
+                hasher.write_u8(24u8);
+            }
+            BinOp::BitOrEq(_) => {
+                hasher.write_u8(25u8);
+            }
+            BinOp::ShlEq(_) => {
+                hasher.write_u8(26u8);
+            }
+            BinOp::ShrEq(_) => {
+                hasher.write_u8(27u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Binding {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Block {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.stmts.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for BoundLifetimes {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lifetimes.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for CfgAll {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.predicates.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for CfgAny {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.predicates.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for CfgExpr {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            CfgExpr::Key(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            CfgExpr::KeyValue(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            CfgExpr::All(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            CfgExpr::Any(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            CfgExpr::Not(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for CfgKeyValue {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.key.stable_hash(hasher);
+        self.value.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for CfgNot {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.predicate.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ConstParam {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+        self.eq_token.stable_hash(hasher);
+        self.default.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Constraint {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Data {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Data::Struct(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Data::Enum(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Data::Union(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for DataEnum {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.variants.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for DataStruct {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.fields.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for DataUnion {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.fields.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Decreases {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.exprs.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for DeriveInput {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.data.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Ensures {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.exprs.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Expr {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Assign(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::AssignOp(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Async(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Await(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Binary(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Block(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Box(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Break(v0) => {
+                hasher.write_u8(8u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Call(v0) => {
+                hasher.write_u8(9u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Cast(v0) => {
+                hasher.write_u8(10u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Closure(v0) => {
+                hasher.write_u8(11u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Continue(v0) => {
+                hasher.write_u8(12u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Field(v0) => {
+                hasher.write_u8(13u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::ForLoop(v0) => {
+                hasher.write_u8(14u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Group(v0) => {
+                hasher.write_u8(15u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::If(v0) => {
+                hasher.write_u8(16u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Index(v0) => {
+                hasher.write_u8(17u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Let(v0) => {
+                hasher.write_u8(18u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Lit(v0) => {
+                hasher.write_u8(19u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Loop(v0) => {
+                hasher.write_u8(20u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Macro(v0) => {
+                hasher.write_u8(21u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Match(v0) => {
+                hasher.write_u8(22u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::MethodCall(v0) => {
+                hasher.write_u8(23u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Paren(v0) => {
+                hasher.write_u8(24u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Path(v0) => {
+                hasher.write_u8(25u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Range(v0) => {
+                hasher.write_u8(26u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Reference(v0) => {
+                hasher.write_u8(27u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Repeat(v0) => {
+                hasher.write_u8(28u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Return(v0) => {
+                hasher.write_u8(29u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Struct(v0) => {
+                hasher.write_u8(30u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Try(v0) => {
+                hasher.write_u8(31u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::TryBlock(v0) => {
+                hasher.write_u8(32u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Tuple(v0) => {
+                hasher.write_u8(33u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Type(v0) => {
+                hasher.write_u8(34u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Unary(v0) => {
+                hasher.write_u8(35u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Unsafe(v0) => {
+                hasher.write_u8(36u8);
+                v0.stable_hash(hasher);
+            }
+            Expr::Verbatim(v0) => {
+                hasher.write_u8(37u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::While(v0) => {
+                hasher.write_u8(38u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(feature = "full")]
+            Expr::Yield(v0) => {
+                hasher.write_u8(39u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(any(syn_no_non_exhaustive, not(feature = "full")))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprArray {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.elems.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprAssign {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.left.stable_hash(hasher);
+        self.right.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprAssignOp {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.left.stable_hash(hasher);
+        self.op.stable_hash(hasher);
+        self.right.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprAsync {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.capture.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprAwait {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.base.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprBinary {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.left.stable_hash(hasher);
+        self.op.stable_hash(hasher);
+        self.right.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprBlock {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprBox {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprBreak {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprCall {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.func.stable_hash(hasher);
+        self.args.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprCast {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprClosure {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.movability.stable_hash(hasher);
+        self.asyncness.stable_hash(hasher);
+        self.capture.stable_hash(hasher);
+        self.inputs.stable_hash(hasher);
+        self.output.stable_hash(hasher);
+        self.body.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprContinue {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprField {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.base.stable_hash(hasher);
+        self.member.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprForLoop {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.spec.stable_hash(hasher);
+        self.body.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprGroup {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprIf {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.cond.stable_hash(hasher);
+        self.then_branch.stable_hash(hasher);
+        self.else_branch.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprIndex {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.index.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprLet {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprLit {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.lit.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprLoop {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+        self.spec.stable_hash(hasher);
+        self.body.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprMatch {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.arms.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprMethodCall {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.receiver.stable_hash(hasher);
+        self.method.stable_hash(hasher);
+        self.turbofish.stable_hash(hasher);
+        self.args.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprParen {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprPath {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.qself.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprRange {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.from.stable_hash(hasher);
+        self.limits.stable_hash(hasher);
+        self.to.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprReference {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprRepeat {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.len.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprReturn {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprStruct {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+        self.fields.stable_hash(hasher);
+        self.dot2_token.stable_hash(hasher);
+        self.rest.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprTry {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprTryBlock {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprTuple {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.elems.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprUnary {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.op.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprUnsafe {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprWhile {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.label.stable_hash(hasher);
+        self.cond.stable_hash(hasher);
+        self.spec.stable_hash(hasher);
+        self.body.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ExprYield {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Field {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FieldPat {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.member.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FieldValue {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.member.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Fields {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Fields::Named(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Fields::Unnamed(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Fields::Unit => {
+                hasher.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FieldsNamed {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.named.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FieldsUnnamed {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.unnamed.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for File {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.shebang.stable_hash(hasher);
+        self.attrs.stable_hash(hasher);
+        self.items.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FnArg {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            FnArg::Receiver(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            FnArg::Typed(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for FnQualifiers {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.constness.stable_hash(hasher);
+        self.asyncness.stable_hash(hasher);
+        self.unsafety.stable_hash(hasher);
+        self.abi.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ForeignItem {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            ForeignItem::Fn(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            ForeignItem::Static(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            ForeignItem::Type(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            ForeignItem::Macro(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            ForeignItem::Verbatim(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ForeignItemFn {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.sig.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ForeignItemMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ForeignItemStatic {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ForeignItemType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for GenericArgument {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            GenericArgument::Lifetime(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            GenericArgument::Type(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            GenericArgument::Binding(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            GenericArgument::Constraint(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            GenericArgument::Const(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for GenericMethodArgument {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            GenericMethodArgument::Type(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            GenericMethodArgument::Const(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for GenericParam {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            GenericParam::Type(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            GenericParam::Lifetime(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            GenericParam::Const(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Generics {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lt_token.stable_hash(hasher);
+        self.params.stable_hash(hasher);
+        self.gt_token.stable_hash(hasher);
+        self.where_clause.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ImplItem {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            ImplItem::Const(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            ImplItem::Method(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            ImplItem::Type(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            ImplItem::Macro(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            ImplItem::Verbatim(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ImplItemConst {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.defaultness.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ImplItemMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ImplItemMethod {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.defaultness.stable_hash(hasher);
+        self.sig.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ImplItemType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.defaultness.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Index {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.index.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Invariant {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.exprs.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Item {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Item::Const(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Enum(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Item::ExternCrate(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Fn(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            Item::ForeignMod(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Impl(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Macro(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Macro2(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Mod(v0) => {
+                hasher.write_u8(8u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Static(v0) => {
+                hasher.write_u8(9u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Struct(v0) => {
+                hasher.write_u8(10u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Trait(v0) => {
+                hasher.write_u8(11u8);
+                v0.stable_hash(hasher);
+            }
+            Item::TraitAlias(v0) => {
+                hasher.write_u8(12u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Type(v0) => {
+                hasher.write_u8(13u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Union(v0) => {
+                hasher.write_u8(14u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Use(v0) => {
+                hasher.write_u8(15u8);
+                v0.stable_hash(hasher);
+            }
+            Item::Verbatim(v0) => {
+                hasher.write_u8(16u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemConst {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemEnum {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.variants.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemExternCrate {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.rename.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemFn {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.sig.stable_hash(hasher);
+        self.block.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemForeignMod {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.abi.stable_hash(hasher);
+        self.items.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemImpl {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.defaultness.stable_hash(hasher);
+        self.unsafety.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.trait_.stable_hash(hasher);
+        self.self_ty.stable_hash(hasher);
+        self.items.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemMacro2 {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.rules.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemMod {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.content.stable_hash(hasher);
+        self.semi.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemStatic {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemStruct {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.fields.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemTrait {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.unsafety.stable_hash(hasher);
+        self.auto_token.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.supertraits.stable_hash(hasher);
+        self.items.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemTraitAlias {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemUnion {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.fields.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ItemUse {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.vis.stable_hash(hasher);
+        self.leading_colon.stable_hash(hasher);
+        self.tree.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Label {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.name.stable_hash(hasher);
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Lifetime {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for LifetimeDef {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.lifetime.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Lit {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Lit::Str(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::ByteStr(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Byte(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Char(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Int(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Float(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Bool(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            Lit::Verbatim(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for LitBool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.value.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Local {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+        self.init.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Macro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.path.stable_hash(hasher);
+        self.delimiter.stable_hash(hasher);
+        self.tokens.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for MacroDelimiter {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            MacroDelimiter::Paren(_) => {
+                hasher.write_u8(0u8);
+            }
+            MacroDelimiter::Brace(_) => {
+                hasher.write_u8(1u8);
+            }
+            MacroDelimiter::Bracket(_) => {
+                hasher.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Member {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Member::Named(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Member::Unnamed(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Meta {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Meta::Path(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Meta::List(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Meta::NameValue(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for MetaList {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.path.stable_hash(hasher);
+        self.nested.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for MetaNameValue {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.path.stable_hash(hasher);
+        self.lit.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for MethodTurbofish {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.args.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for NestedMeta {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            NestedMeta::Meta(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            NestedMeta::Lit(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ParenthesizedGenericArguments {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.inputs.stable_hash(hasher);
+        self.output.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Pat {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Pat::Box(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Ident(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Lit(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Macro(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Or(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Path(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Range(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Reference(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Rest(v0) => {
+                hasher.write_u8(8u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Slice(v0) => {
+                hasher.write_u8(9u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Struct(v0) => {
+                hasher.write_u8(10u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Tuple(v0) => {
+                hasher.write_u8(11u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::TupleStruct(v0) => {
+                hasher.write_u8(12u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Type(v0) => {
+                hasher.write_u8(13u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Verbatim(v0) => {
+                hasher.write_u8(14u8);
+                v0.stable_hash(hasher);
+            }
+            Pat::Wild(v0) => {
+                hasher.write_u8(15u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatBox {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatIdent {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.by_ref.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.subpat.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatLit {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.expr.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatOr {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.leading_vert.stable_hash(hasher);
+        self.cases.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatPath {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.qself.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatRange {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.lo.stable_hash(hasher);
+        self.limits.stable_hash(hasher);
+        self.hi.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatReference {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatRest {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatSlice {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.elems.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatStruct {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+        self.fields.stable_hash(hasher);
+        self.dot2_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatTuple {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.elems.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatTupleStruct {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.pat.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PatWild {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Path {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.leading_colon.stable_hash(hasher);
+        self.segments.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PathArguments {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            PathArguments::None => {
+                hasher.write_u8(0u8);
+            }
+            PathArguments::AngleBracketed(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            PathArguments::Parenthesized(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PathSegment {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.arguments.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PredicateEq {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lhs_ty.stable_hash(hasher);
+        self.rhs_ty.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PredicateLifetime {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lifetime.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for PredicateType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lifetimes.stable_hash(hasher);
+        self.bounded_ty.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for QSelf {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ty.stable_hash(hasher);
+        self.position.stable_hash(hasher);
+        self.as_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for RangeLimits {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            RangeLimits::HalfOpen(_) => {
+                hasher.write_u8(0u8);
+            }
+            RangeLimits::Closed(_) => {
+                hasher.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Receiver {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.reference.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "verus")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Requires {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.exprs.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for ReturnType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            ReturnType::Default => {
+                hasher.write_u8(0u8);
+            }
+            ReturnType::Type(_, v1) => {
+                hasher.write_u8(1u8);
+                v1.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Signature {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.constness.stable_hash(hasher);
+        self.asyncness.stable_hash(hasher);
+        self.unsafety.stable_hash(hasher);
+        self.abi.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.inputs.stable_hash(hasher);
+        self.variadic.stable_hash(hasher);
+        self.output.stable_hash(hasher);
+        self.spec.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for SpecClauses {
+    #[cfg_attr(not(any(feature = "verus")), allow(unused_variables, unused_mut))]
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        #[cfg(feature = "verus")] self.requires.stable_hash(hasher);
+        #[cfg(feature = "verus")] self.ensures.stable_hash(hasher);
+        #[cfg(feature = "verus")] self.invariant.stable_hash(hasher);
+        #[cfg(feature = "verus")] self.decreases.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Stmt {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Stmt::Local(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Stmt::Item(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Stmt::Expr(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Stmt::Semi(v0, _) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitBound {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.paren_token.stable_hash(hasher);
+        self.modifier.stable_hash(hasher);
+        self.lifetimes.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitBoundModifier {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            TraitBoundModifier::None => {
+                hasher.write_u8(0u8);
+            }
+            TraitBoundModifier::Maybe(_) => {
+                hasher.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitItem {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            TraitItem::Const(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            TraitItem::Method(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            TraitItem::Type(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            TraitItem::Macro(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            TraitItem::Verbatim(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitItemConst {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.ty.stable_hash(hasher);
+        self.default.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitItemMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.mac.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitItemMethod {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.sig.stable_hash(hasher);
+        self.default.stable_hash(hasher);
+        self.semi_token.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TraitItemType {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.generics.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+        self.default.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Type {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Type::Array(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Type::BareFn(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Group(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Type::ImplTrait(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Infer(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Macro(v0) => {
+                hasher.write_u8(5u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Never(v0) => {
+                hasher.write_u8(6u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Paren(v0) => {
+                hasher.write_u8(7u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Path(v0) => {
+                hasher.write_u8(8u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Ptr(v0) => {
+                hasher.write_u8(9u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Reference(v0) => {
+                hasher.write_u8(10u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Slice(v0) => {
+                hasher.write_u8(11u8);
+                v0.stable_hash(hasher);
+            }
+            Type::TraitObject(v0) => {
+                hasher.write_u8(12u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Tuple(v0) => {
+                hasher.write_u8(13u8);
+                v0.stable_hash(hasher);
+            }
+            Type::Verbatim(v0) => {
+                hasher.write_u8(14u8);
+                v0.stable_hash(hasher);
+            }
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeArray {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.elem.stable_hash(hasher);
+        self.len.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeBareFn {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lifetimes.stable_hash(hasher);
+        self.unsafety.stable_hash(hasher);
+        self.abi.stable_hash(hasher);
+        self.inputs.stable_hash(hasher);
+        self.variadic.stable_hash(hasher);
+        self.output.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeGroup {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.elem.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeImplTrait {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeInfer {
+    fn stable_hash(&self, _hasher: &mut StableHasher) {}
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeMacro {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.mac.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeNever {
+    fn stable_hash(&self, _hasher: &mut StableHasher) {}
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeParam {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.colon_token.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+        self.eq_token.stable_hash(hasher);
+        self.default.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeParamBound {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            TypeParamBound::Trait(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            TypeParamBound::Lifetime(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeParen {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.elem.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypePath {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.qself.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypePtr {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.const_token.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.elem.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeReference {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.lifetime.stable_hash(hasher);
+        self.mutability.stable_hash(hasher);
+        self.elem.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeSlice {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.elem.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeTraitObject {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.dyn_token.stable_hash(hasher);
+        self.bounds.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for TypeTuple {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.elems.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UnOp {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            UnOp::Deref(_) => {
+                hasher.write_u8(0u8);
+            }
+            UnOp::Not(_) => {
+                hasher.write_u8(1u8);
+            }
+            UnOp::Neg(_) => {
+                hasher.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UseGlob {
+    fn stable_hash(&self, _hasher: &mut StableHasher) {}
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UseGroup {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.items.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UseName {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UsePath {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.tree.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UseRename {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.ident.stable_hash(hasher);
+        self.rename.stable_hash(hasher);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for UseTree {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            UseTree::Path(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            UseTree::Name(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            UseTree::Rename(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            UseTree::Glob(v0) => {
+                hasher.write_u8(3u8);
+                v0.stable_hash(hasher);
+            }
+            UseTree::Group(v0) => {
+                hasher.write_u8(4u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Variadic {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Variant {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.attrs.stable_hash(hasher);
+        self.ident.stable_hash(hasher);
+        self.fields.stable_hash(hasher);
+        self.discriminant.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for VisCrate {
+    fn stable_hash(&self, _hasher: &mut StableHasher) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for VisPublic {
+    fn stable_hash(&self, _hasher: &mut StableHasher) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for VisRestricted {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.in_token.stable_hash(hasher);
+        self.path.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for Visibility {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Visibility::Public(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            Visibility::Crate(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            Visibility::Restricted(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+            Visibility::Inherited => {
+                hasher.write_u8(3u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for WhereClause {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.predicates.stable_hash(hasher);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl StableHash for WherePredicate {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            WherePredicate::Type(v0) => {
+                hasher.write_u8(0u8);
+                v0.stable_hash(hasher);
+            }
+            WherePredicate::Lifetime(v0) => {
+                hasher.write_u8(1u8);
+                v0.stable_hash(hasher);
+            }
+            WherePredicate::Eq(v0) => {
+                hasher.write_u8(2u8);
+                v0.stable_hash(hasher);
+            }
+        }
+    }
+}
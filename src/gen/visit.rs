@@ -2,9 +2,9 @@
 // It is not intended for manual editing.
 
 #![allow(unused_variables)]
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::gen::helper::visit::*;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::punctuated::Punctuated;
 use crate::*;
 use proc_macro2::Span;
@@ -14,7 +14,7 @@ macro_rules! full {
         $e
     };
 }
-#[cfg(all(feature = "derive", not(feature = "full")))]
+#[cfg(all(any(feature = "derive", feature = "parse-only"), not(feature = "full")))]
 macro_rules! full {
     ($e:expr) => {
         unreachable!()
@@ -31,11 +31,15 @@ macro_rules! skip {
 ///
 /// *This trait is available only if Syn is built with the `"visit"` feature.*
 pub trait Visit<'ast> {
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_abi(&mut self, i: &'ast Abi) {
         visit_abi(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_abi_name(&mut self, i: &'ast AbiName) {
+        visit_abi_name(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_angle_bracketed_generic_arguments(
         &mut self,
         i: &'ast AngleBracketedGenericArguments,
@@ -46,23 +50,23 @@ pub trait Visit<'ast> {
     fn visit_arm(&mut self, i: &'ast Arm) {
         visit_arm(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_attr_style(&mut self, i: &'ast AttrStyle) {
         visit_attr_style(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_attribute(&mut self, i: &'ast Attribute) {
         visit_attribute(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bare_fn_arg(&mut self, i: &'ast BareFnArg) {
         visit_bare_fn_arg(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bin_op(&mut self, i: &'ast BinOp) {
         visit_bin_op(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_binding(&mut self, i: &'ast Binding) {
         visit_binding(self, i);
     }
@@ -70,15 +74,35 @@ pub trait Visit<'ast> {
     fn visit_block(&mut self, i: &'ast Block) {
         visit_block(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bound_lifetimes(&mut self, i: &'ast BoundLifetimes) {
         visit_bound_lifetimes(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_all(&mut self, i: &'ast CfgAll) {
+        visit_cfg_all(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_any(&mut self, i: &'ast CfgAny) {
+        visit_cfg_any(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_expr(&mut self, i: &'ast CfgExpr) {
+        visit_cfg_expr(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_key_value(&mut self, i: &'ast CfgKeyValue) {
+        visit_cfg_key_value(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_not(&mut self, i: &'ast CfgNot) {
+        visit_cfg_not(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_const_param(&mut self, i: &'ast ConstParam) {
         visit_const_param(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_constraint(&mut self, i: &'ast Constraint) {
         visit_constraint(self, i);
     }
@@ -98,11 +122,19 @@ pub trait Visit<'ast> {
     fn visit_data_union(&mut self, i: &'ast DataUnion) {
         visit_data_union(self, i);
     }
+    #[cfg(feature = "verus")]
+    fn visit_decreases(&mut self, i: &'ast Decreases) {
+        visit_decreases(self, i);
+    }
     #[cfg(feature = "derive")]
     fn visit_derive_input(&mut self, i: &'ast DeriveInput) {
         visit_derive_input(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn visit_ensures(&mut self, i: &'ast Ensures) {
+        visit_ensures(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr(&mut self, i: &'ast Expr) {
         visit_expr(self, i);
     }
@@ -126,7 +158,7 @@ pub trait Visit<'ast> {
     fn visit_expr_await(&mut self, i: &'ast ExprAwait) {
         visit_expr_await(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_binary(&mut self, i: &'ast ExprBinary) {
         visit_expr_binary(self, i);
     }
@@ -142,11 +174,11 @@ pub trait Visit<'ast> {
     fn visit_expr_break(&mut self, i: &'ast ExprBreak) {
         visit_expr_break(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_call(&mut self, i: &'ast ExprCall) {
         visit_expr_call(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_cast(&mut self, i: &'ast ExprCast) {
         visit_expr_cast(self, i);
     }
@@ -158,7 +190,7 @@ pub trait Visit<'ast> {
     fn visit_expr_continue(&mut self, i: &'ast ExprContinue) {
         visit_expr_continue(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_field(&mut self, i: &'ast ExprField) {
         visit_expr_field(self, i);
     }
@@ -174,7 +206,7 @@ pub trait Visit<'ast> {
     fn visit_expr_if(&mut self, i: &'ast ExprIf) {
         visit_expr_if(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_index(&mut self, i: &'ast ExprIndex) {
         visit_expr_index(self, i);
     }
@@ -182,7 +214,7 @@ pub trait Visit<'ast> {
     fn visit_expr_let(&mut self, i: &'ast ExprLet) {
         visit_expr_let(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_lit(&mut self, i: &'ast ExprLit) {
         visit_expr_lit(self, i);
     }
@@ -202,11 +234,11 @@ pub trait Visit<'ast> {
     fn visit_expr_method_call(&mut self, i: &'ast ExprMethodCall) {
         visit_expr_method_call(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_paren(&mut self, i: &'ast ExprParen) {
         visit_expr_paren(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_path(&mut self, i: &'ast ExprPath) {
         visit_expr_path(self, i);
     }
@@ -246,7 +278,7 @@ pub trait Visit<'ast> {
     fn visit_expr_type(&mut self, i: &'ast ExprType) {
         visit_expr_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_unary(&mut self, i: &'ast ExprUnary) {
         visit_expr_unary(self, i);
     }
@@ -295,6 +327,10 @@ pub trait Visit<'ast> {
         visit_fn_arg(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_fn_qualifiers(&mut self, i: &'ast FnQualifiers) {
+        visit_fn_qualifiers(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_foreign_item(&mut self, i: &'ast ForeignItem) {
         visit_foreign_item(self, i);
     }
@@ -314,7 +350,7 @@ pub trait Visit<'ast> {
     fn visit_foreign_item_type(&mut self, i: &'ast ForeignItemType) {
         visit_foreign_item_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generic_argument(&mut self, i: &'ast GenericArgument) {
         visit_generic_argument(self, i);
     }
@@ -322,11 +358,11 @@ pub trait Visit<'ast> {
     fn visit_generic_method_argument(&mut self, i: &'ast GenericMethodArgument) {
         visit_generic_method_argument(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generic_param(&mut self, i: &'ast GenericParam) {
         visit_generic_param(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generics(&mut self, i: &'ast Generics) {
         visit_generics(self, i);
     }
@@ -353,10 +389,14 @@ pub trait Visit<'ast> {
     fn visit_impl_item_type(&mut self, i: &'ast ImplItemType) {
         visit_impl_item_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_index(&mut self, i: &'ast Index) {
         visit_index(self, i);
     }
+    #[cfg(feature = "verus")]
+    fn visit_invariant(&mut self, i: &'ast Invariant) {
+        visit_invariant(self, i);
+    }
     #[cfg(feature = "full")]
     fn visit_item(&mut self, i: &'ast Item) {
         visit_item(self, i);
@@ -432,7 +472,7 @@ pub trait Visit<'ast> {
     fn visit_lifetime(&mut self, i: &'ast Lifetime) {
         visit_lifetime(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_lifetime_def(&mut self, i: &'ast LifetimeDef) {
         visit_lifetime_def(self, i);
     }
@@ -464,27 +504,27 @@ pub trait Visit<'ast> {
     fn visit_local(&mut self, i: &'ast Local) {
         visit_local(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_macro(&mut self, i: &'ast Macro) {
         visit_macro(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_macro_delimiter(&mut self, i: &'ast MacroDelimiter) {
         visit_macro_delimiter(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_member(&mut self, i: &'ast Member) {
         visit_member(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta(&mut self, i: &'ast Meta) {
         visit_meta(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta_list(&mut self, i: &'ast MetaList) {
         visit_meta_list(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta_name_value(&mut self, i: &'ast MetaNameValue) {
         visit_meta_name_value(self, i);
     }
@@ -492,11 +532,11 @@ pub trait Visit<'ast> {
     fn visit_method_turbofish(&mut self, i: &'ast MethodTurbofish) {
         visit_method_turbofish(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_nested_meta(&mut self, i: &'ast NestedMeta) {
         visit_nested_meta(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_parenthesized_generic_arguments(
         &mut self,
         i: &'ast ParenthesizedGenericArguments,
@@ -567,31 +607,31 @@ pub trait Visit<'ast> {
     fn visit_pat_wild(&mut self, i: &'ast PatWild) {
         visit_pat_wild(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path(&mut self, i: &'ast Path) {
         visit_path(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path_arguments(&mut self, i: &'ast PathArguments) {
         visit_path_arguments(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path_segment(&mut self, i: &'ast PathSegment) {
         visit_path_segment(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_eq(&mut self, i: &'ast PredicateEq) {
         visit_predicate_eq(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_lifetime(&mut self, i: &'ast PredicateLifetime) {
         visit_predicate_lifetime(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_type(&mut self, i: &'ast PredicateType) {
         visit_predicate_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_qself(&mut self, i: &'ast QSelf) {
         visit_qself(self, i);
     }
@@ -603,7 +643,11 @@ pub trait Visit<'ast> {
     fn visit_receiver(&mut self, i: &'ast Receiver) {
         visit_receiver(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn visit_requires(&mut self, i: &'ast Requires) {
+        visit_requires(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_return_type(&mut self, i: &'ast ReturnType) {
         visit_return_type(self, i);
     }
@@ -615,14 +659,18 @@ pub trait Visit<'ast> {
         visit_span(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_spec_clauses(&mut self, i: &'ast SpecClauses) {
+        visit_spec_clauses(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_stmt(&mut self, i: &'ast Stmt) {
         visit_stmt(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_trait_bound(&mut self, i: &'ast TraitBound) {
         visit_trait_bound(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_trait_bound_modifier(&mut self, i: &'ast TraitBoundModifier) {
         visit_trait_bound_modifier(self, i);
     }
@@ -646,75 +694,75 @@ pub trait Visit<'ast> {
     fn visit_trait_item_type(&mut self, i: &'ast TraitItemType) {
         visit_trait_item_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type(&mut self, i: &'ast Type) {
         visit_type(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_array(&mut self, i: &'ast TypeArray) {
         visit_type_array(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_bare_fn(&mut self, i: &'ast TypeBareFn) {
         visit_type_bare_fn(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_group(&mut self, i: &'ast TypeGroup) {
         visit_type_group(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_impl_trait(&mut self, i: &'ast TypeImplTrait) {
         visit_type_impl_trait(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_infer(&mut self, i: &'ast TypeInfer) {
         visit_type_infer(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_macro(&mut self, i: &'ast TypeMacro) {
         visit_type_macro(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_never(&mut self, i: &'ast TypeNever) {
         visit_type_never(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_param(&mut self, i: &'ast TypeParam) {
         visit_type_param(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_param_bound(&mut self, i: &'ast TypeParamBound) {
         visit_type_param_bound(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_paren(&mut self, i: &'ast TypeParen) {
         visit_type_paren(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_path(&mut self, i: &'ast TypePath) {
         visit_type_path(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_ptr(&mut self, i: &'ast TypePtr) {
         visit_type_ptr(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_reference(&mut self, i: &'ast TypeReference) {
         visit_type_reference(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_slice(&mut self, i: &'ast TypeSlice) {
         visit_type_slice(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_trait_object(&mut self, i: &'ast TypeTraitObject) {
         visit_type_trait_object(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_tuple(&mut self, i: &'ast TypeTuple) {
         visit_type_tuple(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_un_op(&mut self, i: &'ast UnOp) {
         visit_un_op(self, i);
     }
@@ -742,7 +790,7 @@ pub trait Visit<'ast> {
     fn visit_use_tree(&mut self, i: &'ast UseTree) {
         visit_use_tree(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_variadic(&mut self, i: &'ast Variadic) {
         visit_variadic(self, i);
     }
@@ -766,26 +814,93 @@ pub trait Visit<'ast> {
     fn visit_visibility(&mut self, i: &'ast Visibility) {
         visit_visibility(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_where_clause(&mut self, i: &'ast WhereClause) {
         visit_where_clause(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_where_predicate(&mut self, i: &'ast WherePredicate) {
         visit_where_predicate(self, i);
     }
+    /// Visits a keyword, punctuation or delimiter token, given as
+    /// the string it is written as, like `"fn"` or `"=>"`, and its
+    /// spans.
+    ///
+    /// Tokens are visited in the order of the syntax tree rather
+    /// than the order of the source. A delimiter is visited once
+    /// for the pair, before its contents, as `"{}"`, `"[]"`,
+    /// `"()"` or as `""` for an invisible group. The default implementation visits each
+    /// span with [`visit_span`](Visit::visit_span).
+    fn visit_token(&mut self, token: &'static str, spans: &[Span]) {
+        visit_token(self, token, spans);
+    }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.extern_token.span);
+    tokens_helper(v, "extern", &node.extern_token.span);
     if let Some(it) = &node.name {
         v.visit_lit_str(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_abi_name<'ast, V>(v: &mut V, node: &'ast AbiName)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AbiName::Rust(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::C(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::CUnwind(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::System(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::SystemUnwind(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Cdecl(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Stdcall(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Fastcall(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Vectorcall(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Thiscall(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Aapcs(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Win64(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Sysv64(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Efiapi(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AbiName::Other(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        #[cfg(syn_no_non_exhaustive)]
+        _ => unreachable!(),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_angle_bracketed_generic_arguments<'ast, V>(
     v: &mut V,
     node: &'ast AngleBracketedGenericArguments,
@@ -794,17 +909,17 @@ where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.colon2_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "::", &it.spans);
     }
-    tokens_helper(v, &node.lt_token.spans);
+    tokens_helper(v, "<", &node.lt_token.spans);
     for el in Punctuated::pairs(&node.args) {
         let (it, p) = el.into_tuple();
         v.visit_generic_argument(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
-    tokens_helper(v, &node.gt_token.spans);
+    tokens_helper(v, ">", &node.gt_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_arm<'ast, V>(v: &mut V, node: &'ast Arm)
@@ -816,16 +931,16 @@ where
     }
     v.visit_pat(&node.pat);
     if let Some(it) = &node.guard {
-        tokens_helper(v, &(it).0.span);
+        tokens_helper(v, "if", &(it).0.span);
         v.visit_expr(&*(it).1);
     }
-    tokens_helper(v, &node.fat_arrow_token.spans);
+    tokens_helper(v, "=>", &node.fat_arrow_token.spans);
     v.visit_expr(&*node.body);
     if let Some(it) = &node.comma {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ",", &it.spans);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_attr_style<'ast, V>(v: &mut V, node: &'ast AttrStyle)
 where
     V: Visit<'ast> + ?Sized,
@@ -833,22 +948,22 @@ where
     match node {
         AttrStyle::Outer => {}
         AttrStyle::Inner(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "!", &_binding_0.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_attribute<'ast, V>(v: &mut V, node: &'ast Attribute)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.pound_token.spans);
+    tokens_helper(v, "#", &node.pound_token.spans);
     v.visit_attr_style(&node.style);
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     v.visit_path(&node.path);
     skip!(node.tokens);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg)
 where
     V: Visit<'ast> + ?Sized,
@@ -858,109 +973,109 @@ where
     }
     if let Some(it) = &node.name {
         v.visit_ident(&(it).0);
-        tokens_helper(v, &(it).1.spans);
+        tokens_helper(v, ":", &(it).1.spans);
     }
     v.visit_type(&node.ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bin_op<'ast, V>(v: &mut V, node: &'ast BinOp)
 where
     V: Visit<'ast> + ?Sized,
 {
     match node {
         BinOp::Add(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "+", &_binding_0.spans);
         }
         BinOp::Sub(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "-", &_binding_0.spans);
         }
         BinOp::Mul(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "*", &_binding_0.spans);
         }
         BinOp::Div(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "/", &_binding_0.spans);
         }
         BinOp::Rem(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "%", &_binding_0.spans);
         }
         BinOp::And(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "&&", &_binding_0.spans);
         }
         BinOp::Or(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "||", &_binding_0.spans);
         }
         BinOp::BitXor(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "^", &_binding_0.spans);
         }
         BinOp::BitAnd(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "&", &_binding_0.spans);
         }
         BinOp::BitOr(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "|", &_binding_0.spans);
         }
         BinOp::Shl(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "<<", &_binding_0.spans);
         }
         BinOp::Shr(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, ">>", &_binding_0.spans);
         }
         BinOp::Eq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "==", &_binding_0.spans);
         }
         BinOp::Lt(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "<", &_binding_0.spans);
         }
         BinOp::Le(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "<=", &_binding_0.spans);
         }
         BinOp::Ne(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "!=", &_binding_0.spans);
         }
         BinOp::Ge(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, ">=", &_binding_0.spans);
         }
         BinOp::Gt(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, ">", &_binding_0.spans);
         }
         BinOp::AddEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "+=", &_binding_0.spans);
         }
         BinOp::SubEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "-=", &_binding_0.spans);
         }
         BinOp::MulEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "*=", &_binding_0.spans);
         }
         BinOp::DivEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "/=", &_binding_0.spans);
         }
         BinOp::RemEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "%=", &_binding_0.spans);
         }
         BinOp::BitXorEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "^=", &_binding_0.spans);
         }
         BinOp::BitAndEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "&=", &_binding_0.spans);
         }
         BinOp::BitOrEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "|=", &_binding_0.spans);
         }
         BinOp::ShlEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "<<=", &_binding_0.spans);
         }
         BinOp::ShrEq(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, ">>=", &_binding_0.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_binding<'ast, V>(v: &mut V, node: &'ast Binding)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_type(&node.ty);
 }
 #[cfg(feature = "full")]
@@ -968,28 +1083,99 @@ pub fn visit_block<'ast, V>(v: &mut V, node: &'ast Block)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for it in &node.stmts {
         v.visit_stmt(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bound_lifetimes<'ast, V>(v: &mut V, node: &'ast BoundLifetimes)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.for_token.span);
-    tokens_helper(v, &node.lt_token.spans);
+    tokens_helper(v, "for", &node.for_token.span);
+    tokens_helper(v, "<", &node.lt_token.spans);
     for el in Punctuated::pairs(&node.lifetimes) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime_def(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
-    tokens_helper(v, &node.gt_token.spans);
+    tokens_helper(v, ">", &node.gt_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_all<'ast, V>(v: &mut V, node: &'ast CfgAll)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    tokens_helper(v, "()", &node.paren_token.span);
+    for el in Punctuated::pairs(&node.predicates) {
+        let (it, p) = el.into_tuple();
+        v.visit_cfg_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_any<'ast, V>(v: &mut V, node: &'ast CfgAny)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    tokens_helper(v, "()", &node.paren_token.span);
+    for el in Punctuated::pairs(&node.predicates) {
+        let (it, p) = el.into_tuple();
+        v.visit_cfg_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_expr<'ast, V>(v: &mut V, node: &'ast CfgExpr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        CfgExpr::Key(_binding_0) => {
+            v.visit_ident(_binding_0);
+        }
+        CfgExpr::KeyValue(_binding_0) => {
+            v.visit_cfg_key_value(_binding_0);
+        }
+        CfgExpr::All(_binding_0) => {
+            v.visit_cfg_all(_binding_0);
+        }
+        CfgExpr::Any(_binding_0) => {
+            v.visit_cfg_any(_binding_0);
+        }
+        CfgExpr::Not(_binding_0) => {
+            v.visit_cfg_not(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_key_value<'ast, V>(v: &mut V, node: &'ast CfgKeyValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.key);
+    tokens_helper(v, "=", &node.eq_token.spans);
+    v.visit_lit_str(&node.value);
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_not<'ast, V>(v: &mut V, node: &'ast CfgNot)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    tokens_helper(v, "()", &node.paren_token.span);
+    v.visit_cfg_expr(&*node.predicate);
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_const_param<'ast, V>(v: &mut V, node: &'ast ConstParam)
 where
     V: Visit<'ast> + ?Sized,
@@ -997,29 +1183,29 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.const_token.span);
+    tokens_helper(v, "const", &node.const_token.span);
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&node.ty);
     if let Some(it) = &node.eq_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "=", &it.spans);
     }
     if let Some(it) = &node.default {
         v.visit_expr(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_constraint<'ast, V>(v: &mut V, node: &'ast Constraint)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
@@ -1045,13 +1231,13 @@ pub fn visit_data_enum<'ast, V>(v: &mut V, node: &'ast DataEnum)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.enum_token.span);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "enum", &node.enum_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.variants) {
         let (it, p) = el.into_tuple();
         v.visit_variant(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -1060,10 +1246,10 @@ pub fn visit_data_struct<'ast, V>(v: &mut V, node: &'ast DataStruct)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.struct_token.span);
+    tokens_helper(v, "struct", &node.struct_token.span);
     v.visit_fields(&node.fields);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "derive")]
@@ -1071,9 +1257,23 @@ pub fn visit_data_union<'ast, V>(v: &mut V, node: &'ast DataUnion)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.union_token.span);
+    tokens_helper(v, "union", &node.union_token.span);
     v.visit_fields_named(&node.fields);
 }
+#[cfg(feature = "verus")]
+pub fn visit_decreases<'ast, V>(v: &mut V, node: &'ast Decreases)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, "decreases", &node.decreases_token.span);
+    for el in Punctuated::pairs(&node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
 #[cfg(feature = "derive")]
 pub fn visit_derive_input<'ast, V>(v: &mut V, node: &'ast DeriveInput)
 where
@@ -1087,7 +1287,21 @@ where
     v.visit_generics(&node.generics);
     v.visit_data(&node.data);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn visit_ensures<'ast, V>(v: &mut V, node: &'ast Ensures)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, "ensures", &node.ensures_token.span);
+    for el in Punctuated::pairs(&node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr)
 where
     V: Visit<'ast> + ?Sized,
@@ -1225,12 +1439,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     for el in Punctuated::pairs(&node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -1243,7 +1457,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.left);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_expr(&*node.right);
 }
 #[cfg(feature = "full")]
@@ -1266,9 +1480,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.async_token.span);
+    tokens_helper(v, "async", &node.async_token.span);
     if let Some(it) = &node.capture {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "move", &it.span);
     }
     v.visit_block(&node.block);
 }
@@ -1281,10 +1495,10 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.base);
-    tokens_helper(v, &node.dot_token.spans);
-    tokens_helper(v, &node.await_token.span);
+    tokens_helper(v, ".", &node.dot_token.spans);
+    tokens_helper(v, "await", &node.await_token.span);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_binary<'ast, V>(v: &mut V, node: &'ast ExprBinary)
 where
     V: Visit<'ast> + ?Sized,
@@ -1317,7 +1531,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.box_token.span);
+    tokens_helper(v, "box", &node.box_token.span);
     v.visit_expr(&*node.expr);
 }
 #[cfg(feature = "full")]
@@ -1328,7 +1542,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.break_token.span);
+    tokens_helper(v, "break", &node.break_token.span);
     if let Some(it) = &node.label {
         v.visit_lifetime(it);
     }
@@ -1336,7 +1550,7 @@ where
         v.visit_expr(&**it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_call<'ast, V>(v: &mut V, node: &'ast ExprCall)
 where
     V: Visit<'ast> + ?Sized,
@@ -1345,16 +1559,16 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.func);
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.args) {
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_cast<'ast, V>(v: &mut V, node: &'ast ExprCast)
 where
     V: Visit<'ast> + ?Sized,
@@ -1363,7 +1577,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.as_token.span);
+    tokens_helper(v, "as", &node.as_token.span);
     v.visit_type(&*node.ty);
 }
 #[cfg(feature = "full")]
@@ -1375,23 +1589,23 @@ where
         v.visit_attribute(it);
     }
     if let Some(it) = &node.movability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "static", &it.span);
     }
     if let Some(it) = &node.asyncness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "async", &it.span);
     }
     if let Some(it) = &node.capture {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "move", &it.span);
     }
-    tokens_helper(v, &node.or1_token.spans);
+    tokens_helper(v, "|", &node.or1_token.spans);
     for el in Punctuated::pairs(&node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
-    tokens_helper(v, &node.or2_token.spans);
+    tokens_helper(v, "|", &node.or2_token.spans);
     v.visit_return_type(&node.output);
     v.visit_expr(&*node.body);
 }
@@ -1403,12 +1617,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.continue_token.span);
+    tokens_helper(v, "continue", &node.continue_token.span);
     if let Some(it) = &node.label {
         v.visit_lifetime(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_field<'ast, V>(v: &mut V, node: &'ast ExprField)
 where
     V: Visit<'ast> + ?Sized,
@@ -1417,7 +1631,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.base);
-    tokens_helper(v, &node.dot_token.spans);
+    tokens_helper(v, ".", &node.dot_token.spans);
     v.visit_member(&node.member);
 }
 #[cfg(feature = "full")]
@@ -1431,10 +1645,13 @@ where
     if let Some(it) = &node.label {
         v.visit_label(it);
     }
-    tokens_helper(v, &node.for_token.span);
+    tokens_helper(v, "for", &node.for_token.span);
     v.visit_pat(&node.pat);
-    tokens_helper(v, &node.in_token.span);
+    tokens_helper(v, "in", &node.in_token.span);
     v.visit_expr(&*node.expr);
+    if let Some(it) = &node.spec {
+        v.visit_spec_clauses(&**it);
+    }
     v.visit_block(&node.body);
 }
 #[cfg(feature = "full")]
@@ -1445,7 +1662,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.group_token.span);
+    tokens_helper(v, "", &node.group_token.span);
     v.visit_expr(&*node.expr);
 }
 #[cfg(feature = "full")]
@@ -1456,15 +1673,15 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.if_token.span);
+    tokens_helper(v, "if", &node.if_token.span);
     v.visit_expr(&*node.cond);
     v.visit_block(&node.then_branch);
     if let Some(it) = &node.else_branch {
-        tokens_helper(v, &(it).0.span);
+        tokens_helper(v, "else", &(it).0.span);
         v.visit_expr(&*(it).1);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_index<'ast, V>(v: &mut V, node: &'ast ExprIndex)
 where
     V: Visit<'ast> + ?Sized,
@@ -1473,7 +1690,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     v.visit_expr(&*node.index);
 }
 #[cfg(feature = "full")]
@@ -1484,12 +1701,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.let_token.span);
+    tokens_helper(v, "let", &node.let_token.span);
     v.visit_pat(&node.pat);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_expr(&*node.expr);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_lit<'ast, V>(v: &mut V, node: &'ast ExprLit)
 where
     V: Visit<'ast> + ?Sized,
@@ -1510,7 +1727,10 @@ where
     if let Some(it) = &node.label {
         v.visit_label(it);
     }
-    tokens_helper(v, &node.loop_token.span);
+    tokens_helper(v, "loop", &node.loop_token.span);
+    if let Some(it) = &node.spec {
+        v.visit_spec_clauses(&**it);
+    }
     v.visit_block(&node.body);
 }
 #[cfg(feature = "full")]
@@ -1531,9 +1751,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.match_token.span);
+    tokens_helper(v, "match", &node.match_token.span);
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for it in &node.arms {
         v.visit_arm(it);
     }
@@ -1547,21 +1767,21 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.receiver);
-    tokens_helper(v, &node.dot_token.spans);
+    tokens_helper(v, ".", &node.dot_token.spans);
     v.visit_ident(&node.method);
     if let Some(it) = &node.turbofish {
         v.visit_method_turbofish(it);
     }
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.args) {
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_paren<'ast, V>(v: &mut V, node: &'ast ExprParen)
 where
     V: Visit<'ast> + ?Sized,
@@ -1569,10 +1789,10 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     v.visit_expr(&*node.expr);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_path<'ast, V>(v: &mut V, node: &'ast ExprPath)
 where
     V: Visit<'ast> + ?Sized,
@@ -1609,9 +1829,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, "&", &node.and_token.spans);
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_expr(&*node.expr);
 }
@@ -1623,9 +1843,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
     v.visit_expr(&*node.len);
 }
 #[cfg(feature = "full")]
@@ -1636,7 +1856,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.return_token.span);
+    tokens_helper(v, "return", &node.return_token.span);
     if let Some(it) = &node.expr {
         v.visit_expr(&**it);
     }
@@ -1650,16 +1870,16 @@ where
         v.visit_attribute(it);
     }
     v.visit_path(&node.path);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.fields) {
         let (it, p) = el.into_tuple();
         v.visit_field_value(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     if let Some(it) = &node.dot2_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "..", &it.spans);
     }
     if let Some(it) = &node.rest {
         v.visit_expr(&**it);
@@ -1674,7 +1894,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.question_token.spans);
+    tokens_helper(v, "?", &node.question_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_expr_try_block<'ast, V>(v: &mut V, node: &'ast ExprTryBlock)
@@ -1684,7 +1904,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.try_token.span);
+    tokens_helper(v, "try", &node.try_token.span);
     v.visit_block(&node.block);
 }
 #[cfg(feature = "full")]
@@ -1695,12 +1915,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -1713,10 +1933,10 @@ where
         v.visit_attribute(it);
     }
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&*node.ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_unary<'ast, V>(v: &mut V, node: &'ast ExprUnary)
 where
     V: Visit<'ast> + ?Sized,
@@ -1735,7 +1955,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.unsafe_token.span);
+    tokens_helper(v, "unsafe", &node.unsafe_token.span);
     v.visit_block(&node.block);
 }
 #[cfg(feature = "full")]
@@ -1749,8 +1969,11 @@ where
     if let Some(it) = &node.label {
         v.visit_label(it);
     }
-    tokens_helper(v, &node.while_token.span);
+    tokens_helper(v, "while", &node.while_token.span);
     v.visit_expr(&*node.cond);
+    if let Some(it) = &node.spec {
+        v.visit_spec_clauses(&**it);
+    }
     v.visit_block(&node.body);
 }
 #[cfg(feature = "full")]
@@ -1761,7 +1984,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.yield_token.span);
+    tokens_helper(v, "yield", &node.yield_token.span);
     if let Some(it) = &node.expr {
         v.visit_expr(&**it);
     }
@@ -1779,7 +2002,7 @@ where
         v.visit_ident(it);
     }
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     v.visit_type(&node.ty);
 }
@@ -1793,7 +2016,7 @@ where
     }
     v.visit_member(&node.member);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     v.visit_pat(&*node.pat);
 }
@@ -1807,7 +2030,7 @@ where
     }
     v.visit_member(&node.member);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     v.visit_expr(&node.expr);
 }
@@ -1831,12 +2054,12 @@ pub fn visit_fields_named<'ast, V>(v: &mut V, node: &'ast FieldsNamed)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.named) {
         let (it, p) = el.into_tuple();
         v.visit_field(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -1845,12 +2068,12 @@ pub fn visit_fields_unnamed<'ast, V>(v: &mut V, node: &'ast FieldsUnnamed)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.unnamed) {
         let (it, p) = el.into_tuple();
         v.visit_field(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -1882,6 +2105,24 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_fn_qualifiers<'ast, V>(v: &mut V, node: &'ast FnQualifiers)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.constness {
+        tokens_helper(v, "const", &it.span);
+    }
+    if let Some(it) = &node.asyncness {
+        tokens_helper(v, "async", &it.span);
+    }
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, "unsafe", &it.span);
+    }
+    if let Some(it) = &node.abi {
+        v.visit_abi(it);
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_foreign_item<'ast, V>(v: &mut V, node: &'ast ForeignItem)
 where
     V: Visit<'ast> + ?Sized,
@@ -1916,7 +2157,7 @@ where
     }
     v.visit_visibility(&node.vis);
     v.visit_signature(&node.sig);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_foreign_item_macro<'ast, V>(v: &mut V, node: &'ast ForeignItemMacro)
@@ -1928,7 +2169,7 @@ where
     }
     v.visit_macro(&node.mac);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -1940,14 +2181,14 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.static_token.span);
+    tokens_helper(v, "static", &node.static_token.span);
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&*node.ty);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_foreign_item_type<'ast, V>(v: &mut V, node: &'ast ForeignItemType)
@@ -1958,11 +2199,11 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.type_token.span);
+    tokens_helper(v, "type", &node.type_token.span);
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generic_argument<'ast, V>(v: &mut V, node: &'ast GenericArgument)
 where
     V: Visit<'ast> + ?Sized,
@@ -2002,7 +2243,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generic_param<'ast, V>(v: &mut V, node: &'ast GenericParam)
 where
     V: Visit<'ast> + ?Sized,
@@ -2019,23 +2260,23 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generics<'ast, V>(v: &mut V, node: &'ast Generics)
 where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.lt_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "<", &it.spans);
     }
     for el in Punctuated::pairs(&node.params) {
         let (it, p) = el.into_tuple();
         v.visit_generic_param(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     if let Some(it) = &node.gt_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ">", &it.spans);
     }
     if let Some(it) = &node.where_clause {
         v.visit_where_clause(it);
@@ -2082,15 +2323,15 @@ where
     }
     v.visit_visibility(&node.vis);
     if let Some(it) = &node.defaultness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "default", &it.span);
     }
-    tokens_helper(v, &node.const_token.span);
+    tokens_helper(v, "const", &node.const_token.span);
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&node.ty);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_expr(&node.expr);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_impl_item_macro<'ast, V>(v: &mut V, node: &'ast ImplItemMacro)
@@ -2102,7 +2343,7 @@ where
     }
     v.visit_macro(&node.mac);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2115,7 +2356,7 @@ where
     }
     v.visit_visibility(&node.vis);
     if let Some(it) = &node.defaultness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "default", &it.span);
     }
     v.visit_signature(&node.sig);
     v.visit_block(&node.block);
@@ -2130,16 +2371,16 @@ where
     }
     v.visit_visibility(&node.vis);
     if let Some(it) = &node.defaultness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "default", &it.span);
     }
-    tokens_helper(v, &node.type_token.span);
+    tokens_helper(v, "type", &node.type_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_type(&node.ty);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_index<'ast, V>(v: &mut V, node: &'ast Index)
 where
     V: Visit<'ast> + ?Sized,
@@ -2147,6 +2388,20 @@ where
     skip!(node.index);
     v.visit_span(&node.span);
 }
+#[cfg(feature = "verus")]
+pub fn visit_invariant<'ast, V>(v: &mut V, node: &'ast Invariant)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, "invariant", &node.invariant_token.span);
+    for el in Punctuated::pairs(&node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
 #[cfg(feature = "full")]
 pub fn visit_item<'ast, V>(v: &mut V, node: &'ast Item)
 where
@@ -2217,13 +2472,13 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.const_token.span);
+    tokens_helper(v, "const", &node.const_token.span);
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&*node.ty);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_enum<'ast, V>(v: &mut V, node: &'ast ItemEnum)
@@ -2234,15 +2489,15 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.enum_token.span);
+    tokens_helper(v, "enum", &node.enum_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.variants) {
         let (it, p) = el.into_tuple();
         v.visit_variant(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -2255,14 +2510,14 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.extern_token.span);
-    tokens_helper(v, &node.crate_token.span);
+    tokens_helper(v, "extern", &node.extern_token.span);
+    tokens_helper(v, "crate", &node.crate_token.span);
     v.visit_ident(&node.ident);
     if let Some(it) = &node.rename {
-        tokens_helper(v, &(it).0.span);
+        tokens_helper(v, "as", &(it).0.span);
         v.visit_ident(&(it).1);
     }
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_fn<'ast, V>(v: &mut V, node: &'ast ItemFn)
@@ -2285,7 +2540,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_abi(&node.abi);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for it in &node.items {
         v.visit_foreign_item(it);
     }
@@ -2299,22 +2554,22 @@ where
         v.visit_attribute(it);
     }
     if let Some(it) = &node.defaultness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "default", &it.span);
     }
     if let Some(it) = &node.unsafety {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "unsafe", &it.span);
     }
-    tokens_helper(v, &node.impl_token.span);
+    tokens_helper(v, "impl", &node.impl_token.span);
     v.visit_generics(&node.generics);
     if let Some(it) = &node.trait_ {
         if let Some(it) = &(it).0 {
-            tokens_helper(v, &it.spans);
+            tokens_helper(v, "!", &it.spans);
         }
         v.visit_path(&(it).1);
-        tokens_helper(v, &(it).2.span);
+        tokens_helper(v, "for", &(it).2.span);
     }
     v.visit_type(&*node.self_ty);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for it in &node.items {
         v.visit_impl_item(it);
     }
@@ -2332,7 +2587,7 @@ where
     }
     v.visit_macro(&node.mac);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2344,7 +2599,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.macro_token.span);
+    tokens_helper(v, "macro", &node.macro_token.span);
     v.visit_ident(&node.ident);
     skip!(node.rules);
 }
@@ -2357,16 +2612,16 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.mod_token.span);
+    tokens_helper(v, "mod", &node.mod_token.span);
     v.visit_ident(&node.ident);
     if let Some(it) = &node.content {
-        tokens_helper(v, &(it).0.span);
+        tokens_helper(v, "{}", &(it).0.span);
         for it in &(it).1 {
             v.visit_item(it);
         }
     }
     if let Some(it) = &node.semi {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2378,16 +2633,16 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.static_token.span);
+    tokens_helper(v, "static", &node.static_token.span);
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&*node.ty);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_expr(&*node.expr);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_struct<'ast, V>(v: &mut V, node: &'ast ItemStruct)
@@ -2398,12 +2653,12 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.struct_token.span);
+    tokens_helper(v, "struct", &node.struct_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
     v.visit_fields(&node.fields);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2416,25 +2671,25 @@ where
     }
     v.visit_visibility(&node.vis);
     if let Some(it) = &node.unsafety {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "unsafe", &it.span);
     }
     if let Some(it) = &node.auto_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "auto", &it.span);
     }
-    tokens_helper(v, &node.trait_token.span);
+    tokens_helper(v, "trait", &node.trait_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     for el in Punctuated::pairs(&node.supertraits) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for it in &node.items {
         v.visit_trait_item(it);
     }
@@ -2448,18 +2703,18 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.trait_token.span);
+    tokens_helper(v, "trait", &node.trait_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_type<'ast, V>(v: &mut V, node: &'ast ItemType)
@@ -2470,12 +2725,12 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.type_token.span);
+    tokens_helper(v, "type", &node.type_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_type(&*node.ty);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_union<'ast, V>(v: &mut V, node: &'ast ItemUnion)
@@ -2486,7 +2741,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.union_token.span);
+    tokens_helper(v, "union", &node.union_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
     v.visit_fields_named(&node.fields);
@@ -2500,12 +2755,12 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
-    tokens_helper(v, &node.use_token.span);
+    tokens_helper(v, "use", &node.use_token.span);
     if let Some(it) = &node.leading_colon {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "::", &it.spans);
     }
     v.visit_use_tree(&node.tree);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_label<'ast, V>(v: &mut V, node: &'ast Label)
@@ -2513,7 +2768,7 @@ where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_lifetime(&node.name);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
 }
 pub fn visit_lifetime<'ast, V>(v: &mut V, node: &'ast Lifetime)
 where
@@ -2522,7 +2777,7 @@ where
     v.visit_span(&node.apostrophe);
     v.visit_ident(&node.ident);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_lifetime_def<'ast, V>(v: &mut V, node: &'ast LifetimeDef)
 where
     V: Visit<'ast> + ?Sized,
@@ -2532,13 +2787,13 @@ where
     }
     v.visit_lifetime(&node.lifetime);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
@@ -2612,42 +2867,42 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.let_token.span);
+    tokens_helper(v, "let", &node.let_token.span);
     v.visit_pat(&node.pat);
     if let Some(it) = &node.init {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "=", &(it).0.spans);
         v.visit_expr(&*(it).1);
     }
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_path(&node.path);
-    tokens_helper(v, &node.bang_token.spans);
+    tokens_helper(v, "!", &node.bang_token.spans);
     v.visit_macro_delimiter(&node.delimiter);
     skip!(node.tokens);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_macro_delimiter<'ast, V>(v: &mut V, node: &'ast MacroDelimiter)
 where
     V: Visit<'ast> + ?Sized,
 {
     match node {
         MacroDelimiter::Paren(_binding_0) => {
-            tokens_helper(v, &_binding_0.span);
+            tokens_helper(v, "()", &_binding_0.span);
         }
         MacroDelimiter::Brace(_binding_0) => {
-            tokens_helper(v, &_binding_0.span);
+            tokens_helper(v, "{}", &_binding_0.span);
         }
         MacroDelimiter::Bracket(_binding_0) => {
-            tokens_helper(v, &_binding_0.span);
+            tokens_helper(v, "[]", &_binding_0.span);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_member<'ast, V>(v: &mut V, node: &'ast Member)
 where
     V: Visit<'ast> + ?Sized,
@@ -2661,7 +2916,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta<'ast, V>(v: &mut V, node: &'ast Meta)
 where
     V: Visit<'ast> + ?Sized,
@@ -2678,28 +2933,28 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_path(&node.path);
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.nested) {
         let (it, p) = el.into_tuple();
         v.visit_nested_meta(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta_name_value<'ast, V>(v: &mut V, node: &'ast MetaNameValue)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_path(&node.path);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_lit(&node.lit);
 }
 #[cfg(feature = "full")]
@@ -2707,18 +2962,18 @@ pub fn visit_method_turbofish<'ast, V>(v: &mut V, node: &'ast MethodTurbofish)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.colon2_token.spans);
-    tokens_helper(v, &node.lt_token.spans);
+    tokens_helper(v, "::", &node.colon2_token.spans);
+    tokens_helper(v, "<", &node.lt_token.spans);
     for el in Punctuated::pairs(&node.args) {
         let (it, p) = el.into_tuple();
         v.visit_generic_method_argument(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
-    tokens_helper(v, &node.gt_token.spans);
+    tokens_helper(v, ">", &node.gt_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_nested_meta<'ast, V>(v: &mut V, node: &'ast NestedMeta)
 where
     V: Visit<'ast> + ?Sized,
@@ -2732,7 +2987,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_parenthesized_generic_arguments<'ast, V>(
     v: &mut V,
     node: &'ast ParenthesizedGenericArguments,
@@ -2740,12 +2995,12 @@ pub fn visit_parenthesized_generic_arguments<'ast, V>(
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_type(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     v.visit_return_type(&node.output);
@@ -2816,7 +3071,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.box_token.span);
+    tokens_helper(v, "box", &node.box_token.span);
     v.visit_pat(&*node.pat);
 }
 #[cfg(feature = "full")]
@@ -2828,14 +3083,14 @@ where
         v.visit_attribute(it);
     }
     if let Some(it) = &node.by_ref {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "ref", &it.span);
     }
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_ident(&node.ident);
     if let Some(it) = &node.subpat {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "@", &(it).0.spans);
         v.visit_pat(&*(it).1);
     }
 }
@@ -2868,13 +3123,13 @@ where
         v.visit_attribute(it);
     }
     if let Some(it) = &node.leading_vert {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "|", &it.spans);
     }
     for el in Punctuated::pairs(&node.cases) {
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "|", &p.spans);
         }
     }
 }
@@ -2911,9 +3166,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, "&", &node.and_token.spans);
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_pat(&*node.pat);
 }
@@ -2925,7 +3180,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.dot2_token.spans);
+    tokens_helper(v, "..", &node.dot2_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_pat_slice<'ast, V>(v: &mut V, node: &'ast PatSlice)
@@ -2935,12 +3190,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     for el in Punctuated::pairs(&node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -2953,16 +3208,16 @@ where
         v.visit_attribute(it);
     }
     v.visit_path(&node.path);
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.fields) {
         let (it, p) = el.into_tuple();
         v.visit_field_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     if let Some(it) = &node.dot2_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "..", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2973,12 +3228,12 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -3002,7 +3257,7 @@ where
         v.visit_attribute(it);
     }
     v.visit_pat(&*node.pat);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&*node.ty);
 }
 #[cfg(feature = "full")]
@@ -3013,25 +3268,25 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.underscore_token.spans);
+    tokens_helper(v, "_", &node.underscore_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path<'ast, V>(v: &mut V, node: &'ast Path)
 where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.leading_colon {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "::", &it.spans);
     }
     for el in Punctuated::pairs(&node.segments) {
         let (it, p) = el.into_tuple();
         v.visit_path_segment(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "::", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path_arguments<'ast, V>(v: &mut V, node: &'ast PathArguments)
 where
     V: Visit<'ast> + ?Sized,
@@ -3046,7 +3301,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path_segment<'ast, V>(v: &mut V, node: &'ast PathSegment)
 where
     V: Visit<'ast> + ?Sized,
@@ -3054,31 +3309,31 @@ where
     v.visit_ident(&node.ident);
     v.visit_path_arguments(&node.arguments);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_eq<'ast, V>(v: &mut V, node: &'ast PredicateEq)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_type(&node.lhs_ty);
-    tokens_helper(v, &node.eq_token.spans);
+    tokens_helper(v, "=", &node.eq_token.spans);
     v.visit_type(&node.rhs_ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_lifetime<'ast, V>(v: &mut V, node: &'ast PredicateLifetime)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_lifetime(&node.lifetime);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_type<'ast, V>(v: &mut V, node: &'ast PredicateType)
 where
     V: Visit<'ast> + ?Sized,
@@ -3087,27 +3342,27 @@ where
         v.visit_bound_lifetimes(it);
     }
     v.visit_type(&node.bounded_ty);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_qself<'ast, V>(v: &mut V, node: &'ast QSelf)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.lt_token.spans);
+    tokens_helper(v, "<", &node.lt_token.spans);
     v.visit_type(&*node.ty);
     skip!(node.position);
     if let Some(it) = &node.as_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "as", &it.span);
     }
-    tokens_helper(v, &node.gt_token.spans);
+    tokens_helper(v, ">", &node.gt_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_range_limits<'ast, V>(v: &mut V, node: &'ast RangeLimits)
@@ -3116,10 +3371,10 @@ where
 {
     match node {
         RangeLimits::HalfOpen(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "..", &_binding_0.spans);
         }
         RangeLimits::Closed(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "..=", &_binding_0.spans);
         }
     }
 }
@@ -3132,17 +3387,31 @@ where
         v.visit_attribute(it);
     }
     if let Some(it) = &node.reference {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "&", &(it).0.spans);
         if let Some(it) = &(it).1 {
             v.visit_lifetime(it);
         }
     }
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
-    tokens_helper(v, &node.self_token.span);
+    tokens_helper(v, "self", &node.self_token.span);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn visit_requires<'ast, V>(v: &mut V, node: &'ast Requires)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, "requires", &node.requires_token.span);
+    for el in Punctuated::pairs(&node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_return_type<'ast, V>(v: &mut V, node: &'ast ReturnType)
 where
     V: Visit<'ast> + ?Sized,
@@ -3150,7 +3419,7 @@ where
     match node {
         ReturnType::Default => {}
         ReturnType::Type(_binding_0, _binding_1) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "->", &_binding_0.spans);
             v.visit_type(&**_binding_1);
         }
     }
@@ -3161,38 +3430,63 @@ where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.constness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "const", &it.span);
     }
     if let Some(it) = &node.asyncness {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "async", &it.span);
     }
     if let Some(it) = &node.unsafety {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "unsafe", &it.span);
     }
     if let Some(it) = &node.abi {
         v.visit_abi(it);
     }
-    tokens_helper(v, &node.fn_token.span);
+    tokens_helper(v, "fn", &node.fn_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_fn_arg(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     if let Some(it) = &node.variadic {
         v.visit_variadic(it);
     }
     v.visit_return_type(&node.output);
+    if let Some(it) = &node.spec {
+        v.visit_spec_clauses(&**it);
+    }
 }
 pub fn visit_span<'ast, V>(v: &mut V, node: &Span)
 where
     V: Visit<'ast> + ?Sized,
 {}
 #[cfg(feature = "full")]
+pub fn visit_spec_clauses<'ast, V>(v: &mut V, node: &'ast SpecClauses)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    #[cfg(feature = "verus")]
+    if let Some(it) = &node.requires {
+        v.visit_requires(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &node.ensures {
+        v.visit_ensures(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &node.invariant {
+        v.visit_invariant(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &node.decreases {
+        v.visit_decreases(it);
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_stmt<'ast, V>(v: &mut V, node: &'ast Stmt)
 where
     V: Visit<'ast> + ?Sized,
@@ -3209,17 +3503,17 @@ where
         }
         Stmt::Semi(_binding_0, _binding_1) => {
             v.visit_expr(_binding_0);
-            tokens_helper(v, &_binding_1.spans);
+            tokens_helper(v, ";", &_binding_1.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_trait_bound<'ast, V>(v: &mut V, node: &'ast TraitBound)
 where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.paren_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "()", &it.span);
     }
     v.visit_trait_bound_modifier(&node.modifier);
     if let Some(it) = &node.lifetimes {
@@ -3227,7 +3521,7 @@ where
     }
     v.visit_path(&node.path);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_trait_bound_modifier<'ast, V>(v: &mut V, node: &'ast TraitBoundModifier)
 where
     V: Visit<'ast> + ?Sized,
@@ -3235,7 +3529,7 @@ where
     match node {
         TraitBoundModifier::None => {}
         TraitBoundModifier::Maybe(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "?", &_binding_0.spans);
         }
     }
 }
@@ -3272,15 +3566,15 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.const_token.span);
+    tokens_helper(v, "const", &node.const_token.span);
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
+    tokens_helper(v, ":", &node.colon_token.spans);
     v.visit_type(&node.ty);
     if let Some(it) = &node.default {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "=", &(it).0.spans);
         v.visit_expr(&(it).1);
     }
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_trait_item_macro<'ast, V>(v: &mut V, node: &'ast TraitItemMacro)
@@ -3292,7 +3586,7 @@ where
     }
     v.visit_macro(&node.mac);
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -3308,7 +3602,7 @@ where
         v.visit_block(it);
     }
     if let Some(it) = &node.semi_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ";", &it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -3319,26 +3613,26 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.type_token.span);
+    tokens_helper(v, "type", &node.type_token.span);
     v.visit_ident(&node.ident);
     v.visit_generics(&node.generics);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
     if let Some(it) = &node.default {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "=", &(it).0.spans);
         v.visit_type(&(it).1);
     }
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type<'ast, V>(v: &mut V, node: &'ast Type)
 where
     V: Visit<'ast> + ?Sized,
@@ -3393,17 +3687,17 @@ where
         _ => unreachable!(),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_array<'ast, V>(v: &mut V, node: &'ast TypeArray)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     v.visit_type(&*node.elem);
-    tokens_helper(v, &node.semi_token.spans);
+    tokens_helper(v, ";", &node.semi_token.spans);
     v.visit_expr(&node.len);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_bare_fn<'ast, V>(v: &mut V, node: &'ast TypeBareFn)
 where
     V: Visit<'ast> + ?Sized,
@@ -3412,18 +3706,18 @@ where
         v.visit_bound_lifetimes(it);
     }
     if let Some(it) = &node.unsafety {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "unsafe", &it.span);
     }
     if let Some(it) = &node.abi {
         v.visit_abi(it);
     }
-    tokens_helper(v, &node.fn_token.span);
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "fn", &node.fn_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_bare_fn_arg(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
     if let Some(it) = &node.variadic {
@@ -3431,50 +3725,50 @@ where
     }
     v.visit_return_type(&node.output);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_group<'ast, V>(v: &mut V, node: &'ast TypeGroup)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.group_token.span);
+    tokens_helper(v, "", &node.group_token.span);
     v.visit_type(&*node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_impl_trait<'ast, V>(v: &mut V, node: &'ast TypeImplTrait)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.impl_token.span);
+    tokens_helper(v, "impl", &node.impl_token.span);
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_infer<'ast, V>(v: &mut V, node: &'ast TypeInfer)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.underscore_token.spans);
+    tokens_helper(v, "_", &node.underscore_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_macro<'ast, V>(v: &mut V, node: &'ast TypeMacro)
 where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_macro(&node.mac);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_never<'ast, V>(v: &mut V, node: &'ast TypeNever)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.bang_token.spans);
+    tokens_helper(v, "!", &node.bang_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_param<'ast, V>(v: &mut V, node: &'ast TypeParam)
 where
     V: Visit<'ast> + ?Sized,
@@ -3484,23 +3778,23 @@ where
     }
     v.visit_ident(&node.ident);
     if let Some(it) = &node.colon_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, ":", &it.spans);
     }
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
     if let Some(it) = &node.eq_token {
-        tokens_helper(v, &it.spans);
+        tokens_helper(v, "=", &it.spans);
     }
     if let Some(it) = &node.default {
         v.visit_type(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_param_bound<'ast, V>(v: &mut V, node: &'ast TypeParamBound)
 where
     V: Visit<'ast> + ?Sized,
@@ -3514,15 +3808,15 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_paren<'ast, V>(v: &mut V, node: &'ast TypeParen)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     v.visit_type(&*node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_path<'ast, V>(v: &mut V, node: &'ast TypePath)
 where
     V: Visit<'ast> + ?Sized,
@@ -3532,86 +3826,86 @@ where
     }
     v.visit_path(&node.path);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_ptr<'ast, V>(v: &mut V, node: &'ast TypePtr)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.star_token.spans);
+    tokens_helper(v, "*", &node.star_token.spans);
     if let Some(it) = &node.const_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "const", &it.span);
     }
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_type(&*node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_reference<'ast, V>(v: &mut V, node: &'ast TypeReference)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, "&", &node.and_token.spans);
     if let Some(it) = &node.lifetime {
         v.visit_lifetime(it);
     }
     if let Some(it) = &node.mutability {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "mut", &it.span);
     }
     v.visit_type(&*node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_slice<'ast, V>(v: &mut V, node: &'ast TypeSlice)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.bracket_token.span);
+    tokens_helper(v, "[]", &node.bracket_token.span);
     v.visit_type(&*node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_trait_object<'ast, V>(v: &mut V, node: &'ast TypeTraitObject)
 where
     V: Visit<'ast> + ?Sized,
 {
     if let Some(it) = &node.dyn_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "dyn", &it.span);
     }
     for el in Punctuated::pairs(&node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, "+", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_tuple<'ast, V>(v: &mut V, node: &'ast TypeTuple)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     for el in Punctuated::pairs(&node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_type(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_un_op<'ast, V>(v: &mut V, node: &'ast UnOp)
 where
     V: Visit<'ast> + ?Sized,
 {
     match node {
         UnOp::Deref(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "*", &_binding_0.spans);
         }
         UnOp::Not(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "!", &_binding_0.spans);
         }
         UnOp::Neg(_binding_0) => {
-            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, "-", &_binding_0.spans);
         }
     }
 }
@@ -3620,19 +3914,19 @@ pub fn visit_use_glob<'ast, V>(v: &mut V, node: &'ast UseGlob)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.star_token.spans);
+    tokens_helper(v, "*", &node.star_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_use_group<'ast, V>(v: &mut V, node: &'ast UseGroup)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.brace_token.span);
+    tokens_helper(v, "{}", &node.brace_token.span);
     for el in Punctuated::pairs(&node.items) {
         let (it, p) = el.into_tuple();
         v.visit_use_tree(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
@@ -3649,7 +3943,7 @@ where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon2_token.spans);
+    tokens_helper(v, "::", &node.colon2_token.spans);
     v.visit_use_tree(&*node.tree);
 }
 #[cfg(feature = "full")]
@@ -3658,7 +3952,7 @@ where
     V: Visit<'ast> + ?Sized,
 {
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.as_token.span);
+    tokens_helper(v, "as", &node.as_token.span);
     v.visit_ident(&node.rename);
 }
 #[cfg(feature = "full")]
@@ -3684,7 +3978,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_variadic<'ast, V>(v: &mut V, node: &'ast Variadic)
 where
     V: Visit<'ast> + ?Sized,
@@ -3692,7 +3986,7 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
-    tokens_helper(v, &node.dots.spans);
+    tokens_helper(v, "...", &node.dots.spans);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_variant<'ast, V>(v: &mut V, node: &'ast Variant)
@@ -3705,7 +3999,7 @@ where
     v.visit_ident(&node.ident);
     v.visit_fields(&node.fields);
     if let Some(it) = &node.discriminant {
-        tokens_helper(v, &(it).0.spans);
+        tokens_helper(v, "=", &(it).0.spans);
         v.visit_expr(&(it).1);
     }
 }
@@ -3714,24 +4008,24 @@ pub fn visit_vis_crate<'ast, V>(v: &mut V, node: &'ast VisCrate)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.crate_token.span);
+    tokens_helper(v, "crate", &node.crate_token.span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_vis_public<'ast, V>(v: &mut V, node: &'ast VisPublic)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.pub_token.span);
+    tokens_helper(v, "pub", &node.pub_token.span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_vis_restricted<'ast, V>(v: &mut V, node: &'ast VisRestricted)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.pub_token.span);
-    tokens_helper(v, &node.paren_token.span);
+    tokens_helper(v, "pub", &node.pub_token.span);
+    tokens_helper(v, "()", &node.paren_token.span);
     if let Some(it) = &node.in_token {
-        tokens_helper(v, &it.span);
+        tokens_helper(v, "in", &it.span);
     }
     v.visit_path(&*node.path);
 }
@@ -3753,21 +4047,21 @@ where
         Visibility::Inherited => {}
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_where_clause<'ast, V>(v: &mut V, node: &'ast WhereClause)
 where
     V: Visit<'ast> + ?Sized,
 {
-    tokens_helper(v, &node.where_token.span);
+    tokens_helper(v, "where", &node.where_token.span);
     for el in Punctuated::pairs(&node.predicates) {
         let (it, p) = el.into_tuple();
         v.visit_where_predicate(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            tokens_helper(v, ",", &p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_where_predicate<'ast, V>(v: &mut V, node: &'ast WherePredicate)
 where
     V: Visit<'ast> + ?Sized,
@@ -3784,3 +4078,11 @@ where
         }
     }
 }
+pub fn visit_token<'ast, V>(v: &mut V, token: &'static str, spans: &[Span])
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for span in spans {
+        v.visit_span(span);
+    }
+}
@@ -2,9 +2,9 @@
 // It is not intended for manual editing.
 
 #![allow(unused_variables)]
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::gen::helper::visit_mut::*;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::punctuated::Punctuated;
 use crate::*;
 use proc_macro2::Span;
@@ -14,7 +14,7 @@ macro_rules! full {
         $e
     };
 }
-#[cfg(all(feature = "derive", not(feature = "full")))]
+#[cfg(all(any(feature = "derive", feature = "parse-only"), not(feature = "full")))]
 macro_rules! full {
     ($e:expr) => {
         unreachable!()
@@ -32,11 +32,15 @@ macro_rules! skip {
 ///
 /// *This trait is available only if Syn is built with the `"visit-mut"` feature.*
 pub trait VisitMut {
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_abi_mut(&mut self, i: &mut Abi) {
         visit_abi_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_abi_name_mut(&mut self, i: &mut AbiName) {
+        visit_abi_name_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_angle_bracketed_generic_arguments_mut(
         &mut self,
         i: &mut AngleBracketedGenericArguments,
@@ -47,23 +51,23 @@ pub trait VisitMut {
     fn visit_arm_mut(&mut self, i: &mut Arm) {
         visit_arm_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_attr_style_mut(&mut self, i: &mut AttrStyle) {
         visit_attr_style_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_attribute_mut(&mut self, i: &mut Attribute) {
         visit_attribute_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bare_fn_arg_mut(&mut self, i: &mut BareFnArg) {
         visit_bare_fn_arg_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bin_op_mut(&mut self, i: &mut BinOp) {
         visit_bin_op_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_binding_mut(&mut self, i: &mut Binding) {
         visit_binding_mut(self, i);
     }
@@ -71,15 +75,35 @@ pub trait VisitMut {
     fn visit_block_mut(&mut self, i: &mut Block) {
         visit_block_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_bound_lifetimes_mut(&mut self, i: &mut BoundLifetimes) {
         visit_bound_lifetimes_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_all_mut(&mut self, i: &mut CfgAll) {
+        visit_cfg_all_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_any_mut(&mut self, i: &mut CfgAny) {
+        visit_cfg_any_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_expr_mut(&mut self, i: &mut CfgExpr) {
+        visit_cfg_expr_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_key_value_mut(&mut self, i: &mut CfgKeyValue) {
+        visit_cfg_key_value_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+    fn visit_cfg_not_mut(&mut self, i: &mut CfgNot) {
+        visit_cfg_not_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_const_param_mut(&mut self, i: &mut ConstParam) {
         visit_const_param_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_constraint_mut(&mut self, i: &mut Constraint) {
         visit_constraint_mut(self, i);
     }
@@ -99,11 +123,19 @@ pub trait VisitMut {
     fn visit_data_union_mut(&mut self, i: &mut DataUnion) {
         visit_data_union_mut(self, i);
     }
+    #[cfg(feature = "verus")]
+    fn visit_decreases_mut(&mut self, i: &mut Decreases) {
+        visit_decreases_mut(self, i);
+    }
     #[cfg(feature = "derive")]
     fn visit_derive_input_mut(&mut self, i: &mut DeriveInput) {
         visit_derive_input_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn visit_ensures_mut(&mut self, i: &mut Ensures) {
+        visit_ensures_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_mut(&mut self, i: &mut Expr) {
         visit_expr_mut(self, i);
     }
@@ -127,7 +159,7 @@ pub trait VisitMut {
     fn visit_expr_await_mut(&mut self, i: &mut ExprAwait) {
         visit_expr_await_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_binary_mut(&mut self, i: &mut ExprBinary) {
         visit_expr_binary_mut(self, i);
     }
@@ -143,11 +175,11 @@ pub trait VisitMut {
     fn visit_expr_break_mut(&mut self, i: &mut ExprBreak) {
         visit_expr_break_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_call_mut(&mut self, i: &mut ExprCall) {
         visit_expr_call_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_cast_mut(&mut self, i: &mut ExprCast) {
         visit_expr_cast_mut(self, i);
     }
@@ -159,7 +191,7 @@ pub trait VisitMut {
     fn visit_expr_continue_mut(&mut self, i: &mut ExprContinue) {
         visit_expr_continue_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_field_mut(&mut self, i: &mut ExprField) {
         visit_expr_field_mut(self, i);
     }
@@ -175,7 +207,7 @@ pub trait VisitMut {
     fn visit_expr_if_mut(&mut self, i: &mut ExprIf) {
         visit_expr_if_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_index_mut(&mut self, i: &mut ExprIndex) {
         visit_expr_index_mut(self, i);
     }
@@ -183,7 +215,7 @@ pub trait VisitMut {
     fn visit_expr_let_mut(&mut self, i: &mut ExprLet) {
         visit_expr_let_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_lit_mut(&mut self, i: &mut ExprLit) {
         visit_expr_lit_mut(self, i);
     }
@@ -203,11 +235,11 @@ pub trait VisitMut {
     fn visit_expr_method_call_mut(&mut self, i: &mut ExprMethodCall) {
         visit_expr_method_call_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_paren_mut(&mut self, i: &mut ExprParen) {
         visit_expr_paren_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_path_mut(&mut self, i: &mut ExprPath) {
         visit_expr_path_mut(self, i);
     }
@@ -247,7 +279,7 @@ pub trait VisitMut {
     fn visit_expr_type_mut(&mut self, i: &mut ExprType) {
         visit_expr_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_expr_unary_mut(&mut self, i: &mut ExprUnary) {
         visit_expr_unary_mut(self, i);
     }
@@ -296,6 +328,10 @@ pub trait VisitMut {
         visit_fn_arg_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_fn_qualifiers_mut(&mut self, i: &mut FnQualifiers) {
+        visit_fn_qualifiers_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_foreign_item_mut(&mut self, i: &mut ForeignItem) {
         visit_foreign_item_mut(self, i);
     }
@@ -315,7 +351,7 @@ pub trait VisitMut {
     fn visit_foreign_item_type_mut(&mut self, i: &mut ForeignItemType) {
         visit_foreign_item_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generic_argument_mut(&mut self, i: &mut GenericArgument) {
         visit_generic_argument_mut(self, i);
     }
@@ -323,11 +359,11 @@ pub trait VisitMut {
     fn visit_generic_method_argument_mut(&mut self, i: &mut GenericMethodArgument) {
         visit_generic_method_argument_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generic_param_mut(&mut self, i: &mut GenericParam) {
         visit_generic_param_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_generics_mut(&mut self, i: &mut Generics) {
         visit_generics_mut(self, i);
     }
@@ -354,10 +390,14 @@ pub trait VisitMut {
     fn visit_impl_item_type_mut(&mut self, i: &mut ImplItemType) {
         visit_impl_item_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_index_mut(&mut self, i: &mut Index) {
         visit_index_mut(self, i);
     }
+    #[cfg(feature = "verus")]
+    fn visit_invariant_mut(&mut self, i: &mut Invariant) {
+        visit_invariant_mut(self, i);
+    }
     #[cfg(feature = "full")]
     fn visit_item_mut(&mut self, i: &mut Item) {
         visit_item_mut(self, i);
@@ -433,7 +473,7 @@ pub trait VisitMut {
     fn visit_lifetime_mut(&mut self, i: &mut Lifetime) {
         visit_lifetime_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_lifetime_def_mut(&mut self, i: &mut LifetimeDef) {
         visit_lifetime_def_mut(self, i);
     }
@@ -465,27 +505,27 @@ pub trait VisitMut {
     fn visit_local_mut(&mut self, i: &mut Local) {
         visit_local_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_macro_mut(&mut self, i: &mut Macro) {
         visit_macro_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_macro_delimiter_mut(&mut self, i: &mut MacroDelimiter) {
         visit_macro_delimiter_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_member_mut(&mut self, i: &mut Member) {
         visit_member_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta_mut(&mut self, i: &mut Meta) {
         visit_meta_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta_list_mut(&mut self, i: &mut MetaList) {
         visit_meta_list_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_meta_name_value_mut(&mut self, i: &mut MetaNameValue) {
         visit_meta_name_value_mut(self, i);
     }
@@ -493,11 +533,11 @@ pub trait VisitMut {
     fn visit_method_turbofish_mut(&mut self, i: &mut MethodTurbofish) {
         visit_method_turbofish_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_nested_meta_mut(&mut self, i: &mut NestedMeta) {
         visit_nested_meta_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_parenthesized_generic_arguments_mut(
         &mut self,
         i: &mut ParenthesizedGenericArguments,
@@ -568,31 +608,31 @@ pub trait VisitMut {
     fn visit_pat_wild_mut(&mut self, i: &mut PatWild) {
         visit_pat_wild_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path_mut(&mut self, i: &mut Path) {
         visit_path_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path_arguments_mut(&mut self, i: &mut PathArguments) {
         visit_path_arguments_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_path_segment_mut(&mut self, i: &mut PathSegment) {
         visit_path_segment_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_eq_mut(&mut self, i: &mut PredicateEq) {
         visit_predicate_eq_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_lifetime_mut(&mut self, i: &mut PredicateLifetime) {
         visit_predicate_lifetime_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_predicate_type_mut(&mut self, i: &mut PredicateType) {
         visit_predicate_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_qself_mut(&mut self, i: &mut QSelf) {
         visit_qself_mut(self, i);
     }
@@ -604,7 +644,11 @@ pub trait VisitMut {
     fn visit_receiver_mut(&mut self, i: &mut Receiver) {
         visit_receiver_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(feature = "verus")]
+    fn visit_requires_mut(&mut self, i: &mut Requires) {
+        visit_requires_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_return_type_mut(&mut self, i: &mut ReturnType) {
         visit_return_type_mut(self, i);
     }
@@ -616,14 +660,18 @@ pub trait VisitMut {
         visit_span_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_spec_clauses_mut(&mut self, i: &mut SpecClauses) {
+        visit_spec_clauses_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_stmt_mut(&mut self, i: &mut Stmt) {
         visit_stmt_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_trait_bound_mut(&mut self, i: &mut TraitBound) {
         visit_trait_bound_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_trait_bound_modifier_mut(&mut self, i: &mut TraitBoundModifier) {
         visit_trait_bound_modifier_mut(self, i);
     }
@@ -647,75 +695,75 @@ pub trait VisitMut {
     fn visit_trait_item_type_mut(&mut self, i: &mut TraitItemType) {
         visit_trait_item_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_mut(&mut self, i: &mut Type) {
         visit_type_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_array_mut(&mut self, i: &mut TypeArray) {
         visit_type_array_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_bare_fn_mut(&mut self, i: &mut TypeBareFn) {
         visit_type_bare_fn_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_group_mut(&mut self, i: &mut TypeGroup) {
         visit_type_group_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_impl_trait_mut(&mut self, i: &mut TypeImplTrait) {
         visit_type_impl_trait_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_infer_mut(&mut self, i: &mut TypeInfer) {
         visit_type_infer_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_macro_mut(&mut self, i: &mut TypeMacro) {
         visit_type_macro_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_never_mut(&mut self, i: &mut TypeNever) {
         visit_type_never_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_param_mut(&mut self, i: &mut TypeParam) {
         visit_type_param_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_param_bound_mut(&mut self, i: &mut TypeParamBound) {
         visit_type_param_bound_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_paren_mut(&mut self, i: &mut TypeParen) {
         visit_type_paren_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_path_mut(&mut self, i: &mut TypePath) {
         visit_type_path_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_ptr_mut(&mut self, i: &mut TypePtr) {
         visit_type_ptr_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_reference_mut(&mut self, i: &mut TypeReference) {
         visit_type_reference_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_slice_mut(&mut self, i: &mut TypeSlice) {
         visit_type_slice_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_trait_object_mut(&mut self, i: &mut TypeTraitObject) {
         visit_type_trait_object_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_type_tuple_mut(&mut self, i: &mut TypeTuple) {
         visit_type_tuple_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_un_op_mut(&mut self, i: &mut UnOp) {
         visit_un_op_mut(self, i);
     }
@@ -743,7 +791,7 @@ pub trait VisitMut {
     fn visit_use_tree_mut(&mut self, i: &mut UseTree) {
         visit_use_tree_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_variadic_mut(&mut self, i: &mut Variadic) {
         visit_variadic_mut(self, i);
     }
@@ -767,26 +815,93 @@ pub trait VisitMut {
     fn visit_visibility_mut(&mut self, i: &mut Visibility) {
         visit_visibility_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_where_clause_mut(&mut self, i: &mut WhereClause) {
         visit_where_clause_mut(self, i);
     }
-    #[cfg(any(feature = "derive", feature = "full"))]
+    #[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
     fn visit_where_predicate_mut(&mut self, i: &mut WherePredicate) {
         visit_where_predicate_mut(self, i);
     }
+    /// Visits a keyword, punctuation or delimiter token, given as
+    /// the string it is written as, like `"fn"` or `"=>"`, and its
+    /// spans.
+    ///
+    /// Tokens are visited in the order of the syntax tree rather
+    /// than the order of the source. A delimiter is visited once
+    /// for the pair, before its contents, as `"{}"`, `"[]"`,
+    /// `"()"` or as `""` for an invisible group. The default implementation visits each
+    /// span with [`visit_span_mut`](VisitMut::visit_span_mut).
+    fn visit_token_mut(&mut self, token: &'static str, spans: &mut [Span]) {
+        visit_token_mut(self, token, spans);
+    }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_abi_mut<V>(v: &mut V, node: &mut Abi)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.extern_token.span);
+    tokens_helper(v, "extern", &mut node.extern_token.span);
     if let Some(it) = &mut node.name {
         v.visit_lit_str_mut(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_abi_name_mut<V>(v: &mut V, node: &mut AbiName)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AbiName::Rust(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::C(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::CUnwind(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::System(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::SystemUnwind(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Cdecl(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Stdcall(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Fastcall(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Vectorcall(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Thiscall(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Aapcs(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Win64(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Sysv64(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Efiapi(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AbiName::Other(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        #[cfg(syn_no_non_exhaustive)]
+        _ => unreachable!(),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_angle_bracketed_generic_arguments_mut<V>(
     v: &mut V,
     node: &mut AngleBracketedGenericArguments,
@@ -795,17 +910,17 @@ where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.colon2_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "::", &mut it.spans);
     }
-    tokens_helper(v, &mut node.lt_token.spans);
+    tokens_helper(v, "<", &mut node.lt_token.spans);
     for el in Punctuated::pairs_mut(&mut node.args) {
         let (it, p) = el.into_tuple();
         v.visit_generic_argument_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.gt_token.spans);
+    tokens_helper(v, ">", &mut node.gt_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_arm_mut<V>(v: &mut V, node: &mut Arm)
@@ -817,16 +932,16 @@ where
     }
     v.visit_pat_mut(&mut node.pat);
     if let Some(it) = &mut node.guard {
-        tokens_helper(v, &mut (it).0.span);
+        tokens_helper(v, "if", &mut (it).0.span);
         v.visit_expr_mut(&mut *(it).1);
     }
-    tokens_helper(v, &mut node.fat_arrow_token.spans);
+    tokens_helper(v, "=>", &mut node.fat_arrow_token.spans);
     v.visit_expr_mut(&mut *node.body);
     if let Some(it) = &mut node.comma {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ",", &mut it.spans);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_attr_style_mut<V>(v: &mut V, node: &mut AttrStyle)
 where
     V: VisitMut + ?Sized,
@@ -834,22 +949,22 @@ where
     match node {
         AttrStyle::Outer => {}
         AttrStyle::Inner(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "!", &mut _binding_0.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_attribute_mut<V>(v: &mut V, node: &mut Attribute)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.pound_token.spans);
+    tokens_helper(v, "#", &mut node.pound_token.spans);
     v.visit_attr_style_mut(&mut node.style);
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     v.visit_path_mut(&mut node.path);
     skip!(node.tokens);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bare_fn_arg_mut<V>(v: &mut V, node: &mut BareFnArg)
 where
     V: VisitMut + ?Sized,
@@ -859,109 +974,109 @@ where
     }
     if let Some(it) = &mut node.name {
         v.visit_ident_mut(&mut (it).0);
-        tokens_helper(v, &mut (it).1.spans);
+        tokens_helper(v, ":", &mut (it).1.spans);
     }
     v.visit_type_mut(&mut node.ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bin_op_mut<V>(v: &mut V, node: &mut BinOp)
 where
     V: VisitMut + ?Sized,
 {
     match node {
         BinOp::Add(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "+", &mut _binding_0.spans);
         }
         BinOp::Sub(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "-", &mut _binding_0.spans);
         }
         BinOp::Mul(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "*", &mut _binding_0.spans);
         }
         BinOp::Div(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "/", &mut _binding_0.spans);
         }
         BinOp::Rem(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "%", &mut _binding_0.spans);
         }
         BinOp::And(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "&&", &mut _binding_0.spans);
         }
         BinOp::Or(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "||", &mut _binding_0.spans);
         }
         BinOp::BitXor(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "^", &mut _binding_0.spans);
         }
         BinOp::BitAnd(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "&", &mut _binding_0.spans);
         }
         BinOp::BitOr(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "|", &mut _binding_0.spans);
         }
         BinOp::Shl(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "<<", &mut _binding_0.spans);
         }
         BinOp::Shr(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, ">>", &mut _binding_0.spans);
         }
         BinOp::Eq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "==", &mut _binding_0.spans);
         }
         BinOp::Lt(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "<", &mut _binding_0.spans);
         }
         BinOp::Le(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "<=", &mut _binding_0.spans);
         }
         BinOp::Ne(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "!=", &mut _binding_0.spans);
         }
         BinOp::Ge(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, ">=", &mut _binding_0.spans);
         }
         BinOp::Gt(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, ">", &mut _binding_0.spans);
         }
         BinOp::AddEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "+=", &mut _binding_0.spans);
         }
         BinOp::SubEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "-=", &mut _binding_0.spans);
         }
         BinOp::MulEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "*=", &mut _binding_0.spans);
         }
         BinOp::DivEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "/=", &mut _binding_0.spans);
         }
         BinOp::RemEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "%=", &mut _binding_0.spans);
         }
         BinOp::BitXorEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "^=", &mut _binding_0.spans);
         }
         BinOp::BitAndEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "&=", &mut _binding_0.spans);
         }
         BinOp::BitOrEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "|=", &mut _binding_0.spans);
         }
         BinOp::ShlEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "<<=", &mut _binding_0.spans);
         }
         BinOp::ShrEq(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, ">>=", &mut _binding_0.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_binding_mut<V>(v: &mut V, node: &mut Binding)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_type_mut(&mut node.ty);
 }
 #[cfg(feature = "full")]
@@ -969,28 +1084,99 @@ pub fn visit_block_mut<V>(v: &mut V, node: &mut Block)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for it in &mut node.stmts {
         v.visit_stmt_mut(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_bound_lifetimes_mut<V>(v: &mut V, node: &mut BoundLifetimes)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.for_token.span);
-    tokens_helper(v, &mut node.lt_token.spans);
+    tokens_helper(v, "for", &mut node.for_token.span);
+    tokens_helper(v, "<", &mut node.lt_token.spans);
     for el in Punctuated::pairs_mut(&mut node.lifetimes) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime_def_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.gt_token.spans);
+    tokens_helper(v, ">", &mut node.gt_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_all_mut<V>(v: &mut V, node: &mut CfgAll)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    tokens_helper(v, "()", &mut node.paren_token.span);
+    for el in Punctuated::pairs_mut(&mut node.predicates) {
+        let (it, p) = el.into_tuple();
+        v.visit_cfg_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_any_mut<V>(v: &mut V, node: &mut CfgAny)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    tokens_helper(v, "()", &mut node.paren_token.span);
+    for el in Punctuated::pairs_mut(&mut node.predicates) {
+        let (it, p) = el.into_tuple();
+        v.visit_cfg_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_expr_mut<V>(v: &mut V, node: &mut CfgExpr)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        CfgExpr::Key(_binding_0) => {
+            v.visit_ident_mut(_binding_0);
+        }
+        CfgExpr::KeyValue(_binding_0) => {
+            v.visit_cfg_key_value_mut(_binding_0);
+        }
+        CfgExpr::All(_binding_0) => {
+            v.visit_cfg_all_mut(_binding_0);
+        }
+        CfgExpr::Any(_binding_0) => {
+            v.visit_cfg_any_mut(_binding_0);
+        }
+        CfgExpr::Not(_binding_0) => {
+            v.visit_cfg_not_mut(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_key_value_mut<V>(v: &mut V, node: &mut CfgKeyValue)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.key);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
+    v.visit_lit_str_mut(&mut node.value);
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub fn visit_cfg_not_mut<V>(v: &mut V, node: &mut CfgNot)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    tokens_helper(v, "()", &mut node.paren_token.span);
+    v.visit_cfg_expr_mut(&mut *node.predicate);
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_const_param_mut<V>(v: &mut V, node: &mut ConstParam)
 where
     V: VisitMut + ?Sized,
@@ -998,29 +1184,29 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.const_token.span);
+    tokens_helper(v, "const", &mut node.const_token.span);
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut node.ty);
     if let Some(it) = &mut node.eq_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "=", &mut it.spans);
     }
     if let Some(it) = &mut node.default {
         v.visit_expr_mut(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_constraint_mut<V>(v: &mut V, node: &mut Constraint)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
@@ -1046,13 +1232,13 @@ pub fn visit_data_enum_mut<V>(v: &mut V, node: &mut DataEnum)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.enum_token.span);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "enum", &mut node.enum_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.variants) {
         let (it, p) = el.into_tuple();
         v.visit_variant_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -1061,10 +1247,10 @@ pub fn visit_data_struct_mut<V>(v: &mut V, node: &mut DataStruct)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.struct_token.span);
+    tokens_helper(v, "struct", &mut node.struct_token.span);
     v.visit_fields_mut(&mut node.fields);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "derive")]
@@ -1072,9 +1258,23 @@ pub fn visit_data_union_mut<V>(v: &mut V, node: &mut DataUnion)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.union_token.span);
+    tokens_helper(v, "union", &mut node.union_token.span);
     v.visit_fields_named_mut(&mut node.fields);
 }
+#[cfg(feature = "verus")]
+pub fn visit_decreases_mut<V>(v: &mut V, node: &mut Decreases)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, "decreases", &mut node.decreases_token.span);
+    for el in Punctuated::pairs_mut(&mut node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
 #[cfg(feature = "derive")]
 pub fn visit_derive_input_mut<V>(v: &mut V, node: &mut DeriveInput)
 where
@@ -1088,7 +1288,21 @@ where
     v.visit_generics_mut(&mut node.generics);
     v.visit_data_mut(&mut node.data);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn visit_ensures_mut<V>(v: &mut V, node: &mut Ensures)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, "ensures", &mut node.ensures_token.span);
+    for el in Punctuated::pairs_mut(&mut node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_mut<V>(v: &mut V, node: &mut Expr)
 where
     V: VisitMut + ?Sized,
@@ -1226,12 +1440,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     for el in Punctuated::pairs_mut(&mut node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -1244,7 +1458,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.left);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_expr_mut(&mut *node.right);
 }
 #[cfg(feature = "full")]
@@ -1267,9 +1481,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.async_token.span);
+    tokens_helper(v, "async", &mut node.async_token.span);
     if let Some(it) = &mut node.capture {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "move", &mut it.span);
     }
     v.visit_block_mut(&mut node.block);
 }
@@ -1282,10 +1496,10 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.base);
-    tokens_helper(v, &mut node.dot_token.spans);
-    tokens_helper(v, &mut node.await_token.span);
+    tokens_helper(v, ".", &mut node.dot_token.spans);
+    tokens_helper(v, "await", &mut node.await_token.span);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_binary_mut<V>(v: &mut V, node: &mut ExprBinary)
 where
     V: VisitMut + ?Sized,
@@ -1318,7 +1532,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.box_token.span);
+    tokens_helper(v, "box", &mut node.box_token.span);
     v.visit_expr_mut(&mut *node.expr);
 }
 #[cfg(feature = "full")]
@@ -1329,7 +1543,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.break_token.span);
+    tokens_helper(v, "break", &mut node.break_token.span);
     if let Some(it) = &mut node.label {
         v.visit_lifetime_mut(it);
     }
@@ -1337,7 +1551,7 @@ where
         v.visit_expr_mut(&mut **it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_call_mut<V>(v: &mut V, node: &mut ExprCall)
 where
     V: VisitMut + ?Sized,
@@ -1346,16 +1560,16 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.func);
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.args) {
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_cast_mut<V>(v: &mut V, node: &mut ExprCast)
 where
     V: VisitMut + ?Sized,
@@ -1364,7 +1578,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.as_token.span);
+    tokens_helper(v, "as", &mut node.as_token.span);
     v.visit_type_mut(&mut *node.ty);
 }
 #[cfg(feature = "full")]
@@ -1376,23 +1590,23 @@ where
         v.visit_attribute_mut(it);
     }
     if let Some(it) = &mut node.movability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "static", &mut it.span);
     }
     if let Some(it) = &mut node.asyncness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "async", &mut it.span);
     }
     if let Some(it) = &mut node.capture {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "move", &mut it.span);
     }
-    tokens_helper(v, &mut node.or1_token.spans);
+    tokens_helper(v, "|", &mut node.or1_token.spans);
     for el in Punctuated::pairs_mut(&mut node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.or2_token.spans);
+    tokens_helper(v, "|", &mut node.or2_token.spans);
     v.visit_return_type_mut(&mut node.output);
     v.visit_expr_mut(&mut *node.body);
 }
@@ -1404,12 +1618,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.continue_token.span);
+    tokens_helper(v, "continue", &mut node.continue_token.span);
     if let Some(it) = &mut node.label {
         v.visit_lifetime_mut(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_field_mut<V>(v: &mut V, node: &mut ExprField)
 where
     V: VisitMut + ?Sized,
@@ -1418,7 +1632,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.base);
-    tokens_helper(v, &mut node.dot_token.spans);
+    tokens_helper(v, ".", &mut node.dot_token.spans);
     v.visit_member_mut(&mut node.member);
 }
 #[cfg(feature = "full")]
@@ -1432,10 +1646,13 @@ where
     if let Some(it) = &mut node.label {
         v.visit_label_mut(it);
     }
-    tokens_helper(v, &mut node.for_token.span);
+    tokens_helper(v, "for", &mut node.for_token.span);
     v.visit_pat_mut(&mut node.pat);
-    tokens_helper(v, &mut node.in_token.span);
+    tokens_helper(v, "in", &mut node.in_token.span);
     v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.spec {
+        v.visit_spec_clauses_mut(&mut **it);
+    }
     v.visit_block_mut(&mut node.body);
 }
 #[cfg(feature = "full")]
@@ -1446,7 +1663,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.group_token.span);
+    tokens_helper(v, "", &mut node.group_token.span);
     v.visit_expr_mut(&mut *node.expr);
 }
 #[cfg(feature = "full")]
@@ -1457,15 +1674,15 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.if_token.span);
+    tokens_helper(v, "if", &mut node.if_token.span);
     v.visit_expr_mut(&mut *node.cond);
     v.visit_block_mut(&mut node.then_branch);
     if let Some(it) = &mut node.else_branch {
-        tokens_helper(v, &mut (it).0.span);
+        tokens_helper(v, "else", &mut (it).0.span);
         v.visit_expr_mut(&mut *(it).1);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_index_mut<V>(v: &mut V, node: &mut ExprIndex)
 where
     V: VisitMut + ?Sized,
@@ -1474,7 +1691,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     v.visit_expr_mut(&mut *node.index);
 }
 #[cfg(feature = "full")]
@@ -1485,12 +1702,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.let_token.span);
+    tokens_helper(v, "let", &mut node.let_token.span);
     v.visit_pat_mut(&mut node.pat);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_expr_mut(&mut *node.expr);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_lit_mut<V>(v: &mut V, node: &mut ExprLit)
 where
     V: VisitMut + ?Sized,
@@ -1511,7 +1728,10 @@ where
     if let Some(it) = &mut node.label {
         v.visit_label_mut(it);
     }
-    tokens_helper(v, &mut node.loop_token.span);
+    tokens_helper(v, "loop", &mut node.loop_token.span);
+    if let Some(it) = &mut node.spec {
+        v.visit_spec_clauses_mut(&mut **it);
+    }
     v.visit_block_mut(&mut node.body);
 }
 #[cfg(feature = "full")]
@@ -1532,9 +1752,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.match_token.span);
+    tokens_helper(v, "match", &mut node.match_token.span);
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for it in &mut node.arms {
         v.visit_arm_mut(it);
     }
@@ -1548,21 +1768,21 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.receiver);
-    tokens_helper(v, &mut node.dot_token.spans);
+    tokens_helper(v, ".", &mut node.dot_token.spans);
     v.visit_ident_mut(&mut node.method);
     if let Some(it) = &mut node.turbofish {
         v.visit_method_turbofish_mut(it);
     }
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.args) {
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_paren_mut<V>(v: &mut V, node: &mut ExprParen)
 where
     V: VisitMut + ?Sized,
@@ -1570,10 +1790,10 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     v.visit_expr_mut(&mut *node.expr);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_path_mut<V>(v: &mut V, node: &mut ExprPath)
 where
     V: VisitMut + ?Sized,
@@ -1610,9 +1830,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, "&", &mut node.and_token.spans);
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_expr_mut(&mut *node.expr);
 }
@@ -1624,9 +1844,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
     v.visit_expr_mut(&mut *node.len);
 }
 #[cfg(feature = "full")]
@@ -1637,7 +1857,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.return_token.span);
+    tokens_helper(v, "return", &mut node.return_token.span);
     if let Some(it) = &mut node.expr {
         v.visit_expr_mut(&mut **it);
     }
@@ -1651,16 +1871,16 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_path_mut(&mut node.path);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.fields) {
         let (it, p) = el.into_tuple();
         v.visit_field_value_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.dot2_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "..", &mut it.spans);
     }
     if let Some(it) = &mut node.rest {
         v.visit_expr_mut(&mut **it);
@@ -1675,7 +1895,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.question_token.spans);
+    tokens_helper(v, "?", &mut node.question_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_expr_try_block_mut<V>(v: &mut V, node: &mut ExprTryBlock)
@@ -1685,7 +1905,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.try_token.span);
+    tokens_helper(v, "try", &mut node.try_token.span);
     v.visit_block_mut(&mut node.block);
 }
 #[cfg(feature = "full")]
@@ -1696,12 +1916,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -1714,10 +1934,10 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_expr_unary_mut<V>(v: &mut V, node: &mut ExprUnary)
 where
     V: VisitMut + ?Sized,
@@ -1736,7 +1956,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.unsafe_token.span);
+    tokens_helper(v, "unsafe", &mut node.unsafe_token.span);
     v.visit_block_mut(&mut node.block);
 }
 #[cfg(feature = "full")]
@@ -1750,8 +1970,11 @@ where
     if let Some(it) = &mut node.label {
         v.visit_label_mut(it);
     }
-    tokens_helper(v, &mut node.while_token.span);
+    tokens_helper(v, "while", &mut node.while_token.span);
     v.visit_expr_mut(&mut *node.cond);
+    if let Some(it) = &mut node.spec {
+        v.visit_spec_clauses_mut(&mut **it);
+    }
     v.visit_block_mut(&mut node.body);
 }
 #[cfg(feature = "full")]
@@ -1762,7 +1985,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.yield_token.span);
+    tokens_helper(v, "yield", &mut node.yield_token.span);
     if let Some(it) = &mut node.expr {
         v.visit_expr_mut(&mut **it);
     }
@@ -1780,7 +2003,7 @@ where
         v.visit_ident_mut(it);
     }
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     v.visit_type_mut(&mut node.ty);
 }
@@ -1794,7 +2017,7 @@ where
     }
     v.visit_member_mut(&mut node.member);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     v.visit_pat_mut(&mut *node.pat);
 }
@@ -1808,7 +2031,7 @@ where
     }
     v.visit_member_mut(&mut node.member);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     v.visit_expr_mut(&mut node.expr);
 }
@@ -1832,12 +2055,12 @@ pub fn visit_fields_named_mut<V>(v: &mut V, node: &mut FieldsNamed)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.named) {
         let (it, p) = el.into_tuple();
         v.visit_field_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -1846,12 +2069,12 @@ pub fn visit_fields_unnamed_mut<V>(v: &mut V, node: &mut FieldsUnnamed)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.unnamed) {
         let (it, p) = el.into_tuple();
         v.visit_field_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -1883,6 +2106,24 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_fn_qualifiers_mut<V>(v: &mut V, node: &mut FnQualifiers)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.constness {
+        tokens_helper(v, "const", &mut it.span);
+    }
+    if let Some(it) = &mut node.asyncness {
+        tokens_helper(v, "async", &mut it.span);
+    }
+    if let Some(it) = &mut node.unsafety {
+        tokens_helper(v, "unsafe", &mut it.span);
+    }
+    if let Some(it) = &mut node.abi {
+        v.visit_abi_mut(it);
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_foreign_item_mut<V>(v: &mut V, node: &mut ForeignItem)
 where
     V: VisitMut + ?Sized,
@@ -1917,7 +2158,7 @@ where
     }
     v.visit_visibility_mut(&mut node.vis);
     v.visit_signature_mut(&mut node.sig);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_foreign_item_macro_mut<V>(v: &mut V, node: &mut ForeignItemMacro)
@@ -1929,7 +2170,7 @@ where
     }
     v.visit_macro_mut(&mut node.mac);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -1941,14 +2182,14 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.static_token.span);
+    tokens_helper(v, "static", &mut node.static_token.span);
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_foreign_item_type_mut<V>(v: &mut V, node: &mut ForeignItemType)
@@ -1959,11 +2200,11 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.type_token.span);
+    tokens_helper(v, "type", &mut node.type_token.span);
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generic_argument_mut<V>(v: &mut V, node: &mut GenericArgument)
 where
     V: VisitMut + ?Sized,
@@ -2000,7 +2241,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generic_param_mut<V>(v: &mut V, node: &mut GenericParam)
 where
     V: VisitMut + ?Sized,
@@ -2017,23 +2258,23 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_generics_mut<V>(v: &mut V, node: &mut Generics)
 where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.lt_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "<", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.params) {
         let (it, p) = el.into_tuple();
         v.visit_generic_param_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.gt_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ">", &mut it.spans);
     }
     if let Some(it) = &mut node.where_clause {
         v.visit_where_clause_mut(it);
@@ -2082,15 +2323,15 @@ where
     }
     v.visit_visibility_mut(&mut node.vis);
     if let Some(it) = &mut node.defaultness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "default", &mut it.span);
     }
-    tokens_helper(v, &mut node.const_token.span);
+    tokens_helper(v, "const", &mut node.const_token.span);
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut node.ty);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_expr_mut(&mut node.expr);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_impl_item_macro_mut<V>(v: &mut V, node: &mut ImplItemMacro)
@@ -2102,7 +2343,7 @@ where
     }
     v.visit_macro_mut(&mut node.mac);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2115,7 +2356,7 @@ where
     }
     v.visit_visibility_mut(&mut node.vis);
     if let Some(it) = &mut node.defaultness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "default", &mut it.span);
     }
     v.visit_signature_mut(&mut node.sig);
     v.visit_block_mut(&mut node.block);
@@ -2130,16 +2371,16 @@ where
     }
     v.visit_visibility_mut(&mut node.vis);
     if let Some(it) = &mut node.defaultness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "default", &mut it.span);
     }
-    tokens_helper(v, &mut node.type_token.span);
+    tokens_helper(v, "type", &mut node.type_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_type_mut(&mut node.ty);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_index_mut<V>(v: &mut V, node: &mut Index)
 where
     V: VisitMut + ?Sized,
@@ -2147,6 +2388,20 @@ where
     skip!(node.index);
     v.visit_span_mut(&mut node.span);
 }
+#[cfg(feature = "verus")]
+pub fn visit_invariant_mut<V>(v: &mut V, node: &mut Invariant)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, "invariant", &mut node.invariant_token.span);
+    for el in Punctuated::pairs_mut(&mut node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
 #[cfg(feature = "full")]
 pub fn visit_item_mut<V>(v: &mut V, node: &mut Item)
 where
@@ -2217,13 +2472,13 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.const_token.span);
+    tokens_helper(v, "const", &mut node.const_token.span);
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_enum_mut<V>(v: &mut V, node: &mut ItemEnum)
@@ -2234,15 +2489,15 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.enum_token.span);
+    tokens_helper(v, "enum", &mut node.enum_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.variants) {
         let (it, p) = el.into_tuple();
         v.visit_variant_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -2255,14 +2510,14 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.extern_token.span);
-    tokens_helper(v, &mut node.crate_token.span);
+    tokens_helper(v, "extern", &mut node.extern_token.span);
+    tokens_helper(v, "crate", &mut node.crate_token.span);
     v.visit_ident_mut(&mut node.ident);
     if let Some(it) = &mut node.rename {
-        tokens_helper(v, &mut (it).0.span);
+        tokens_helper(v, "as", &mut (it).0.span);
         v.visit_ident_mut(&mut (it).1);
     }
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_fn_mut<V>(v: &mut V, node: &mut ItemFn)
@@ -2285,7 +2540,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_abi_mut(&mut node.abi);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for it in &mut node.items {
         v.visit_foreign_item_mut(it);
     }
@@ -2299,22 +2554,22 @@ where
         v.visit_attribute_mut(it);
     }
     if let Some(it) = &mut node.defaultness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "default", &mut it.span);
     }
     if let Some(it) = &mut node.unsafety {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "unsafe", &mut it.span);
     }
-    tokens_helper(v, &mut node.impl_token.span);
+    tokens_helper(v, "impl", &mut node.impl_token.span);
     v.visit_generics_mut(&mut node.generics);
     if let Some(it) = &mut node.trait_ {
         if let Some(it) = &mut (it).0 {
-            tokens_helper(v, &mut it.spans);
+            tokens_helper(v, "!", &mut it.spans);
         }
         v.visit_path_mut(&mut (it).1);
-        tokens_helper(v, &mut (it).2.span);
+        tokens_helper(v, "for", &mut (it).2.span);
     }
     v.visit_type_mut(&mut *node.self_ty);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for it in &mut node.items {
         v.visit_impl_item_mut(it);
     }
@@ -2332,7 +2587,7 @@ where
     }
     v.visit_macro_mut(&mut node.mac);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2344,7 +2599,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.macro_token.span);
+    tokens_helper(v, "macro", &mut node.macro_token.span);
     v.visit_ident_mut(&mut node.ident);
     skip!(node.rules);
 }
@@ -2357,16 +2612,16 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.mod_token.span);
+    tokens_helper(v, "mod", &mut node.mod_token.span);
     v.visit_ident_mut(&mut node.ident);
     if let Some(it) = &mut node.content {
-        tokens_helper(v, &mut (it).0.span);
+        tokens_helper(v, "{}", &mut (it).0.span);
         for it in &mut (it).1 {
             v.visit_item_mut(it);
         }
     }
     if let Some(it) = &mut node.semi {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2378,16 +2633,16 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.static_token.span);
+    tokens_helper(v, "static", &mut node.static_token.span);
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_expr_mut(&mut *node.expr);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_struct_mut<V>(v: &mut V, node: &mut ItemStruct)
@@ -2398,12 +2653,12 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.struct_token.span);
+    tokens_helper(v, "struct", &mut node.struct_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
     v.visit_fields_mut(&mut node.fields);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2416,25 +2671,25 @@ where
     }
     v.visit_visibility_mut(&mut node.vis);
     if let Some(it) = &mut node.unsafety {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "unsafe", &mut it.span);
     }
     if let Some(it) = &mut node.auto_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "auto", &mut it.span);
     }
-    tokens_helper(v, &mut node.trait_token.span);
+    tokens_helper(v, "trait", &mut node.trait_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.supertraits) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for it in &mut node.items {
         v.visit_trait_item_mut(it);
     }
@@ -2448,18 +2703,18 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.trait_token.span);
+    tokens_helper(v, "trait", &mut node.trait_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_type_mut<V>(v: &mut V, node: &mut ItemType)
@@ -2470,12 +2725,12 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.type_token.span);
+    tokens_helper(v, "type", &mut node.type_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_union_mut<V>(v: &mut V, node: &mut ItemUnion)
@@ -2486,7 +2741,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.union_token.span);
+    tokens_helper(v, "union", &mut node.union_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
     v.visit_fields_named_mut(&mut node.fields);
@@ -2500,12 +2755,12 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
-    tokens_helper(v, &mut node.use_token.span);
+    tokens_helper(v, "use", &mut node.use_token.span);
     if let Some(it) = &mut node.leading_colon {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "::", &mut it.spans);
     }
     v.visit_use_tree_mut(&mut node.tree);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_label_mut<V>(v: &mut V, node: &mut Label)
@@ -2513,7 +2768,7 @@ where
     V: VisitMut + ?Sized,
 {
     v.visit_lifetime_mut(&mut node.name);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
 }
 pub fn visit_lifetime_mut<V>(v: &mut V, node: &mut Lifetime)
 where
@@ -2522,7 +2777,7 @@ where
     v.visit_span_mut(&mut node.apostrophe);
     v.visit_ident_mut(&mut node.ident);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_lifetime_def_mut<V>(v: &mut V, node: &mut LifetimeDef)
 where
     V: VisitMut + ?Sized,
@@ -2532,13 +2787,13 @@ where
     }
     v.visit_lifetime_mut(&mut node.lifetime);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
@@ -2612,42 +2867,42 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.let_token.span);
+    tokens_helper(v, "let", &mut node.let_token.span);
     v.visit_pat_mut(&mut node.pat);
     if let Some(it) = &mut node.init {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "=", &mut (it).0.spans);
         v.visit_expr_mut(&mut *(it).1);
     }
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_macro_mut<V>(v: &mut V, node: &mut Macro)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_path_mut(&mut node.path);
-    tokens_helper(v, &mut node.bang_token.spans);
+    tokens_helper(v, "!", &mut node.bang_token.spans);
     v.visit_macro_delimiter_mut(&mut node.delimiter);
     skip!(node.tokens);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_macro_delimiter_mut<V>(v: &mut V, node: &mut MacroDelimiter)
 where
     V: VisitMut + ?Sized,
 {
     match node {
         MacroDelimiter::Paren(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.span);
+            tokens_helper(v, "()", &mut _binding_0.span);
         }
         MacroDelimiter::Brace(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.span);
+            tokens_helper(v, "{}", &mut _binding_0.span);
         }
         MacroDelimiter::Bracket(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.span);
+            tokens_helper(v, "[]", &mut _binding_0.span);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_member_mut<V>(v: &mut V, node: &mut Member)
 where
     V: VisitMut + ?Sized,
@@ -2661,7 +2916,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta_mut<V>(v: &mut V, node: &mut Meta)
 where
     V: VisitMut + ?Sized,
@@ -2678,28 +2933,28 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta_list_mut<V>(v: &mut V, node: &mut MetaList)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_path_mut(&mut node.path);
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.nested) {
         let (it, p) = el.into_tuple();
         v.visit_nested_meta_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_meta_name_value_mut<V>(v: &mut V, node: &mut MetaNameValue)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_path_mut(&mut node.path);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_lit_mut(&mut node.lit);
 }
 #[cfg(feature = "full")]
@@ -2707,18 +2962,18 @@ pub fn visit_method_turbofish_mut<V>(v: &mut V, node: &mut MethodTurbofish)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.colon2_token.spans);
-    tokens_helper(v, &mut node.lt_token.spans);
+    tokens_helper(v, "::", &mut node.colon2_token.spans);
+    tokens_helper(v, "<", &mut node.lt_token.spans);
     for el in Punctuated::pairs_mut(&mut node.args) {
         let (it, p) = el.into_tuple();
         v.visit_generic_method_argument_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
-    tokens_helper(v, &mut node.gt_token.spans);
+    tokens_helper(v, ">", &mut node.gt_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_nested_meta_mut<V>(v: &mut V, node: &mut NestedMeta)
 where
     V: VisitMut + ?Sized,
@@ -2732,7 +2987,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_parenthesized_generic_arguments_mut<V>(
     v: &mut V,
     node: &mut ParenthesizedGenericArguments,
@@ -2740,12 +2995,12 @@ pub fn visit_parenthesized_generic_arguments_mut<V>(
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_type_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     v.visit_return_type_mut(&mut node.output);
@@ -2816,7 +3071,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.box_token.span);
+    tokens_helper(v, "box", &mut node.box_token.span);
     v.visit_pat_mut(&mut *node.pat);
 }
 #[cfg(feature = "full")]
@@ -2828,14 +3083,14 @@ where
         v.visit_attribute_mut(it);
     }
     if let Some(it) = &mut node.by_ref {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "ref", &mut it.span);
     }
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_ident_mut(&mut node.ident);
     if let Some(it) = &mut node.subpat {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "@", &mut (it).0.spans);
         v.visit_pat_mut(&mut *(it).1);
     }
 }
@@ -2868,13 +3123,13 @@ where
         v.visit_attribute_mut(it);
     }
     if let Some(it) = &mut node.leading_vert {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "|", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.cases) {
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "|", &mut p.spans);
         }
     }
 }
@@ -2911,9 +3166,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, "&", &mut node.and_token.spans);
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_pat_mut(&mut *node.pat);
 }
@@ -2925,7 +3180,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.dot2_token.spans);
+    tokens_helper(v, "..", &mut node.dot2_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_pat_slice_mut<V>(v: &mut V, node: &mut PatSlice)
@@ -2935,12 +3190,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     for el in Punctuated::pairs_mut(&mut node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -2953,16 +3208,16 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_path_mut(&mut node.path);
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.fields) {
         let (it, p) = el.into_tuple();
         v.visit_field_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.dot2_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "..", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -2973,12 +3228,12 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -3002,7 +3257,7 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_pat_mut(&mut *node.pat);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
 }
 #[cfg(feature = "full")]
@@ -3013,25 +3268,25 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.underscore_token.spans);
+    tokens_helper(v, "_", &mut node.underscore_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path_mut<V>(v: &mut V, node: &mut Path)
 where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.leading_colon {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "::", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.segments) {
         let (it, p) = el.into_tuple();
         v.visit_path_segment_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "::", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path_arguments_mut<V>(v: &mut V, node: &mut PathArguments)
 where
     V: VisitMut + ?Sized,
@@ -3046,7 +3301,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_path_segment_mut<V>(v: &mut V, node: &mut PathSegment)
 where
     V: VisitMut + ?Sized,
@@ -3054,31 +3309,31 @@ where
     v.visit_ident_mut(&mut node.ident);
     v.visit_path_arguments_mut(&mut node.arguments);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_eq_mut<V>(v: &mut V, node: &mut PredicateEq)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_type_mut(&mut node.lhs_ty);
-    tokens_helper(v, &mut node.eq_token.spans);
+    tokens_helper(v, "=", &mut node.eq_token.spans);
     v.visit_type_mut(&mut node.rhs_ty);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_lifetime_mut<V>(v: &mut V, node: &mut PredicateLifetime)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_lifetime_mut(&mut node.lifetime);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_lifetime_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_predicate_type_mut<V>(v: &mut V, node: &mut PredicateType)
 where
     V: VisitMut + ?Sized,
@@ -3087,27 +3342,27 @@ where
         v.visit_bound_lifetimes_mut(it);
     }
     v.visit_type_mut(&mut node.bounded_ty);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_qself_mut<V>(v: &mut V, node: &mut QSelf)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.lt_token.spans);
+    tokens_helper(v, "<", &mut node.lt_token.spans);
     v.visit_type_mut(&mut *node.ty);
     skip!(node.position);
     if let Some(it) = &mut node.as_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "as", &mut it.span);
     }
-    tokens_helper(v, &mut node.gt_token.spans);
+    tokens_helper(v, ">", &mut node.gt_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_range_limits_mut<V>(v: &mut V, node: &mut RangeLimits)
@@ -3116,10 +3371,10 @@ where
 {
     match node {
         RangeLimits::HalfOpen(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "..", &mut _binding_0.spans);
         }
         RangeLimits::Closed(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "..=", &mut _binding_0.spans);
         }
     }
 }
@@ -3132,17 +3387,31 @@ where
         v.visit_attribute_mut(it);
     }
     if let Some(it) = &mut node.reference {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "&", &mut (it).0.spans);
         if let Some(it) = &mut (it).1 {
             v.visit_lifetime_mut(it);
         }
     }
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
-    tokens_helper(v, &mut node.self_token.span);
+    tokens_helper(v, "self", &mut node.self_token.span);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "verus")]
+pub fn visit_requires_mut<V>(v: &mut V, node: &mut Requires)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, "requires", &mut node.requires_token.span);
+    for el in Punctuated::pairs_mut(&mut node.exprs) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, ",", &mut p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_return_type_mut<V>(v: &mut V, node: &mut ReturnType)
 where
     V: VisitMut + ?Sized,
@@ -3150,7 +3419,7 @@ where
     match node {
         ReturnType::Default => {}
         ReturnType::Type(_binding_0, _binding_1) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "->", &mut _binding_0.spans);
             v.visit_type_mut(&mut **_binding_1);
         }
     }
@@ -3161,38 +3430,63 @@ where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.constness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "const", &mut it.span);
     }
     if let Some(it) = &mut node.asyncness {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "async", &mut it.span);
     }
     if let Some(it) = &mut node.unsafety {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "unsafe", &mut it.span);
     }
     if let Some(it) = &mut node.abi {
         v.visit_abi_mut(it);
     }
-    tokens_helper(v, &mut node.fn_token.span);
+    tokens_helper(v, "fn", &mut node.fn_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_fn_arg_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.variadic {
         v.visit_variadic_mut(it);
     }
     v.visit_return_type_mut(&mut node.output);
+    if let Some(it) = &mut node.spec {
+        v.visit_spec_clauses_mut(&mut **it);
+    }
 }
 pub fn visit_span_mut<V>(v: &mut V, node: &mut Span)
 where
     V: VisitMut + ?Sized,
 {}
 #[cfg(feature = "full")]
+pub fn visit_spec_clauses_mut<V>(v: &mut V, node: &mut SpecClauses)
+where
+    V: VisitMut + ?Sized,
+{
+    #[cfg(feature = "verus")]
+    if let Some(it) = &mut node.requires {
+        v.visit_requires_mut(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &mut node.ensures {
+        v.visit_ensures_mut(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &mut node.invariant {
+        v.visit_invariant_mut(it);
+    }
+    #[cfg(feature = "verus")]
+    if let Some(it) = &mut node.decreases {
+        v.visit_decreases_mut(it);
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_stmt_mut<V>(v: &mut V, node: &mut Stmt)
 where
     V: VisitMut + ?Sized,
@@ -3209,17 +3503,17 @@ where
         }
         Stmt::Semi(_binding_0, _binding_1) => {
             v.visit_expr_mut(_binding_0);
-            tokens_helper(v, &mut _binding_1.spans);
+            tokens_helper(v, ";", &mut _binding_1.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_trait_bound_mut<V>(v: &mut V, node: &mut TraitBound)
 where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.paren_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "()", &mut it.span);
     }
     v.visit_trait_bound_modifier_mut(&mut node.modifier);
     if let Some(it) = &mut node.lifetimes {
@@ -3227,7 +3521,7 @@ where
     }
     v.visit_path_mut(&mut node.path);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_trait_bound_modifier_mut<V>(v: &mut V, node: &mut TraitBoundModifier)
 where
     V: VisitMut + ?Sized,
@@ -3235,7 +3529,7 @@ where
     match node {
         TraitBoundModifier::None => {}
         TraitBoundModifier::Maybe(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "?", &mut _binding_0.spans);
         }
     }
 }
@@ -3272,15 +3566,15 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.const_token.span);
+    tokens_helper(v, "const", &mut node.const_token.span);
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
+    tokens_helper(v, ":", &mut node.colon_token.spans);
     v.visit_type_mut(&mut node.ty);
     if let Some(it) = &mut node.default {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "=", &mut (it).0.spans);
         v.visit_expr_mut(&mut (it).1);
     }
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_trait_item_macro_mut<V>(v: &mut V, node: &mut TraitItemMacro)
@@ -3292,7 +3586,7 @@ where
     }
     v.visit_macro_mut(&mut node.mac);
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -3308,7 +3602,7 @@ where
         v.visit_block_mut(it);
     }
     if let Some(it) = &mut node.semi_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ";", &mut it.spans);
     }
 }
 #[cfg(feature = "full")]
@@ -3319,26 +3613,26 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.type_token.span);
+    tokens_helper(v, "type", &mut node.type_token.span);
     v.visit_ident_mut(&mut node.ident);
     v.visit_generics_mut(&mut node.generics);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.default {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "=", &mut (it).0.spans);
         v.visit_type_mut(&mut (it).1);
     }
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_mut<V>(v: &mut V, node: &mut Type)
 where
     V: VisitMut + ?Sized,
@@ -3393,17 +3687,17 @@ where
         _ => unreachable!(),
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_array_mut<V>(v: &mut V, node: &mut TypeArray)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     v.visit_type_mut(&mut *node.elem);
-    tokens_helper(v, &mut node.semi_token.spans);
+    tokens_helper(v, ";", &mut node.semi_token.spans);
     v.visit_expr_mut(&mut node.len);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_bare_fn_mut<V>(v: &mut V, node: &mut TypeBareFn)
 where
     V: VisitMut + ?Sized,
@@ -3412,18 +3706,18 @@ where
         v.visit_bound_lifetimes_mut(it);
     }
     if let Some(it) = &mut node.unsafety {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "unsafe", &mut it.span);
     }
     if let Some(it) = &mut node.abi {
         v.visit_abi_mut(it);
     }
-    tokens_helper(v, &mut node.fn_token.span);
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "fn", &mut node.fn_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.inputs) {
         let (it, p) = el.into_tuple();
         v.visit_bare_fn_arg_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.variadic {
@@ -3431,50 +3725,50 @@ where
     }
     v.visit_return_type_mut(&mut node.output);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_group_mut<V>(v: &mut V, node: &mut TypeGroup)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.group_token.span);
+    tokens_helper(v, "", &mut node.group_token.span);
     v.visit_type_mut(&mut *node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_impl_trait_mut<V>(v: &mut V, node: &mut TypeImplTrait)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.impl_token.span);
+    tokens_helper(v, "impl", &mut node.impl_token.span);
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_infer_mut<V>(v: &mut V, node: &mut TypeInfer)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.underscore_token.spans);
+    tokens_helper(v, "_", &mut node.underscore_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_macro_mut<V>(v: &mut V, node: &mut TypeMacro)
 where
     V: VisitMut + ?Sized,
 {
     v.visit_macro_mut(&mut node.mac);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_never_mut<V>(v: &mut V, node: &mut TypeNever)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.bang_token.spans);
+    tokens_helper(v, "!", &mut node.bang_token.spans);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_param_mut<V>(v: &mut V, node: &mut TypeParam)
 where
     V: VisitMut + ?Sized,
@@ -3484,23 +3778,23 @@ where
     }
     v.visit_ident_mut(&mut node.ident);
     if let Some(it) = &mut node.colon_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, ":", &mut it.spans);
     }
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.eq_token {
-        tokens_helper(v, &mut it.spans);
+        tokens_helper(v, "=", &mut it.spans);
     }
     if let Some(it) = &mut node.default {
         v.visit_type_mut(it);
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_param_bound_mut<V>(v: &mut V, node: &mut TypeParamBound)
 where
     V: VisitMut + ?Sized,
@@ -3514,15 +3808,15 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_paren_mut<V>(v: &mut V, node: &mut TypeParen)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     v.visit_type_mut(&mut *node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_path_mut<V>(v: &mut V, node: &mut TypePath)
 where
     V: VisitMut + ?Sized,
@@ -3532,86 +3826,86 @@ where
     }
     v.visit_path_mut(&mut node.path);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_ptr_mut<V>(v: &mut V, node: &mut TypePtr)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.star_token.spans);
+    tokens_helper(v, "*", &mut node.star_token.spans);
     if let Some(it) = &mut node.const_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "const", &mut it.span);
     }
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_type_mut(&mut *node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_reference_mut<V>(v: &mut V, node: &mut TypeReference)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, "&", &mut node.and_token.spans);
     if let Some(it) = &mut node.lifetime {
         v.visit_lifetime_mut(it);
     }
     if let Some(it) = &mut node.mutability {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "mut", &mut it.span);
     }
     v.visit_type_mut(&mut *node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_slice_mut<V>(v: &mut V, node: &mut TypeSlice)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.bracket_token.span);
+    tokens_helper(v, "[]", &mut node.bracket_token.span);
     v.visit_type_mut(&mut *node.elem);
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_trait_object_mut<V>(v: &mut V, node: &mut TypeTraitObject)
 where
     V: VisitMut + ?Sized,
 {
     if let Some(it) = &mut node.dyn_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "dyn", &mut it.span);
     }
     for el in Punctuated::pairs_mut(&mut node.bounds) {
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, "+", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_type_tuple_mut<V>(v: &mut V, node: &mut TypeTuple)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     for el in Punctuated::pairs_mut(&mut node.elems) {
         let (it, p) = el.into_tuple();
         v.visit_type_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_un_op_mut<V>(v: &mut V, node: &mut UnOp)
 where
     V: VisitMut + ?Sized,
 {
     match node {
         UnOp::Deref(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "*", &mut _binding_0.spans);
         }
         UnOp::Not(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "!", &mut _binding_0.spans);
         }
         UnOp::Neg(_binding_0) => {
-            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, "-", &mut _binding_0.spans);
         }
     }
 }
@@ -3620,19 +3914,19 @@ pub fn visit_use_glob_mut<V>(v: &mut V, node: &mut UseGlob)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.star_token.spans);
+    tokens_helper(v, "*", &mut node.star_token.spans);
 }
 #[cfg(feature = "full")]
 pub fn visit_use_group_mut<V>(v: &mut V, node: &mut UseGroup)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.brace_token.span);
+    tokens_helper(v, "{}", &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.items) {
         let (it, p) = el.into_tuple();
         v.visit_use_tree_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
@@ -3649,7 +3943,7 @@ where
     V: VisitMut + ?Sized,
 {
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon2_token.spans);
+    tokens_helper(v, "::", &mut node.colon2_token.spans);
     v.visit_use_tree_mut(&mut *node.tree);
 }
 #[cfg(feature = "full")]
@@ -3658,7 +3952,7 @@ where
     V: VisitMut + ?Sized,
 {
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.as_token.span);
+    tokens_helper(v, "as", &mut node.as_token.span);
     v.visit_ident_mut(&mut node.rename);
 }
 #[cfg(feature = "full")]
@@ -3684,7 +3978,7 @@ where
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_variadic_mut<V>(v: &mut V, node: &mut Variadic)
 where
     V: VisitMut + ?Sized,
@@ -3692,7 +3986,7 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
-    tokens_helper(v, &mut node.dots.spans);
+    tokens_helper(v, "...", &mut node.dots.spans);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_variant_mut<V>(v: &mut V, node: &mut Variant)
@@ -3705,7 +3999,7 @@ where
     v.visit_ident_mut(&mut node.ident);
     v.visit_fields_mut(&mut node.fields);
     if let Some(it) = &mut node.discriminant {
-        tokens_helper(v, &mut (it).0.spans);
+        tokens_helper(v, "=", &mut (it).0.spans);
         v.visit_expr_mut(&mut (it).1);
     }
 }
@@ -3714,24 +4008,24 @@ pub fn visit_vis_crate_mut<V>(v: &mut V, node: &mut VisCrate)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.crate_token.span);
+    tokens_helper(v, "crate", &mut node.crate_token.span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_vis_public_mut<V>(v: &mut V, node: &mut VisPublic)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.pub_token.span);
+    tokens_helper(v, "pub", &mut node.pub_token.span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_vis_restricted_mut<V>(v: &mut V, node: &mut VisRestricted)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.pub_token.span);
-    tokens_helper(v, &mut node.paren_token.span);
+    tokens_helper(v, "pub", &mut node.pub_token.span);
+    tokens_helper(v, "()", &mut node.paren_token.span);
     if let Some(it) = &mut node.in_token {
-        tokens_helper(v, &mut it.span);
+        tokens_helper(v, "in", &mut it.span);
     }
     v.visit_path_mut(&mut *node.path);
 }
@@ -3753,21 +4047,21 @@ where
         Visibility::Inherited => {}
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_where_clause_mut<V>(v: &mut V, node: &mut WhereClause)
 where
     V: VisitMut + ?Sized,
 {
-    tokens_helper(v, &mut node.where_token.span);
+    tokens_helper(v, "where", &mut node.where_token.span);
     for el in Punctuated::pairs_mut(&mut node.predicates) {
         let (it, p) = el.into_tuple();
         v.visit_where_predicate_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            tokens_helper(v, ",", &mut p.spans);
         }
     }
 }
-#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
 pub fn visit_where_predicate_mut<V>(v: &mut V, node: &mut WherePredicate)
 where
     V: VisitMut + ?Sized,
@@ -3784,3 +4078,11 @@ where
         }
     }
 }
+pub fn visit_token_mut<V>(v: &mut V, token: &'static str, spans: &mut [Span])
+where
+    V: VisitMut + ?Sized,
+{
+    for span in spans {
+        v.visit_span_mut(span);
+    }
+}
@@ -75,39 +75,41 @@ pub mod fold {
 pub mod visit {
     use crate::visit::Visit;
     use proc_macro2::Span;
+    use std::slice;
 
-    pub fn tokens_helper<'ast, V: Visit<'ast> + ?Sized, S: Spans>(visitor: &mut V, spans: &S) {
-        spans.visit(visitor);
+    pub fn tokens_helper<'ast, V: Visit<'ast> + ?Sized, S: Spans>(
+        visitor: &mut V,
+        token: &'static str,
+        spans: &S,
+    ) {
+        visitor.visit_token(token, spans.spans());
     }
 
     pub trait Spans {
-        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V);
+        fn spans(&self) -> &[Span];
     }
 
     impl Spans for Span {
-        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V) {
-            visitor.visit_span(self);
+        fn spans(&self) -> &[Span] {
+            slice::from_ref(self)
         }
     }
 
     impl Spans for [Span; 1] {
-        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V) {
-            visitor.visit_span(&self[0]);
+        fn spans(&self) -> &[Span] {
+            self
         }
     }
 
     impl Spans for [Span; 2] {
-        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V) {
-            visitor.visit_span(&self[0]);
-            visitor.visit_span(&self[1]);
+        fn spans(&self) -> &[Span] {
+            self
         }
     }
 
     impl Spans for [Span; 3] {
-        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V) {
-            visitor.visit_span(&self[0]);
-            visitor.visit_span(&self[1]);
-            visitor.visit_span(&self[2]);
+        fn spans(&self) -> &[Span] {
+            self
         }
     }
 }
@@ -116,39 +118,41 @@ pub mod visit {
 pub mod visit_mut {
     use crate::visit_mut::VisitMut;
     use proc_macro2::Span;
+    use std::slice;
 
-    pub fn tokens_helper<V: VisitMut + ?Sized, S: Spans>(visitor: &mut V, spans: &mut S) {
-        spans.visit_mut(visitor);
+    pub fn tokens_helper<V: VisitMut + ?Sized, S: Spans>(
+        visitor: &mut V,
+        token: &'static str,
+        spans: &mut S,
+    ) {
+        visitor.visit_token_mut(token, spans.spans_mut());
     }
 
     pub trait Spans {
-        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);
+        fn spans_mut(&mut self) -> &mut [Span];
     }
 
     impl Spans for Span {
-        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
-            visitor.visit_span_mut(self);
+        fn spans_mut(&mut self) -> &mut [Span] {
+            slice::from_mut(self)
         }
     }
 
     impl Spans for [Span; 1] {
-        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
-            visitor.visit_span_mut(&mut self[0]);
+        fn spans_mut(&mut self) -> &mut [Span] {
+            self
         }
     }
 
     impl Spans for [Span; 2] {
-        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
-            visitor.visit_span_mut(&mut self[0]);
-            visitor.visit_span_mut(&mut self[1]);
+        fn spans_mut(&mut self) -> &mut [Span] {
+            self
         }
     }
 
     impl Spans for [Span; 3] {
-        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
-            visitor.visit_span_mut(&mut self[0]);
-            visitor.visit_span_mut(&mut self[1]);
-            visitor.visit_span_mut(&mut self[2]);
+        fn spans_mut(&mut self) -> &mut [Span] {
+            self
         }
     }
 }
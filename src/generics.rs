@@ -1,17 +1,21 @@
 use super::*;
 use crate::punctuated::{Iter, IterMut, Punctuated};
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+use crate::visit_mut::VisitMut;
+use proc_macro2::Span;
 #[cfg(all(feature = "printing", feature = "extra-traits"))]
 use std::fmt::{self, Debug};
 #[cfg(all(feature = "printing", feature = "extra-traits"))]
 use std::hash::{Hash, Hasher};
+use std::mem;
 
 ast_struct! {
     /// Lifetimes and type parameters attached to a declaration of a function,
     /// enum, trait, etc.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Generics {
         pub lt_token: Option<Token![<]>,
         pub params: Punctuated<GenericParam, Token![,]>,
@@ -24,15 +28,15 @@ ast_enum_of_structs! {
     /// A generic type parameter, lifetime, or const generic: `T: Into<String>`,
     /// `'a: 'b`, `const LEN: usize`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// # Syntax tree enum
     ///
     /// This type is a [syntax tree enum].
     ///
     /// [syntax tree enum]: Expr#syntax-tree-enums
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum GenericParam {
         /// A generic type parameter: `T: Into<String>`.
         Type(TypeParam),
@@ -48,9 +52,9 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A generic type parameter: `T: Into<String>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeParam {
         pub attrs: Vec<Attribute>,
         pub ident: Ident,
@@ -64,9 +68,9 @@ ast_struct! {
 ast_struct! {
     /// A lifetime definition: `'a: 'b + 'c + 'd`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct LifetimeDef {
         pub attrs: Vec<Attribute>,
         pub lifetime: Lifetime,
@@ -78,9 +82,9 @@ ast_struct! {
 ast_struct! {
     /// A const generic parameter: `const LENGTH: usize`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ConstParam {
         pub attrs: Vec<Attribute>,
         pub const_token: Token![const],
@@ -179,6 +183,221 @@ impl Generics {
     }
 }
 
+impl Generics {
+    /// The type parameters that are relaxed by an explicit `?Sized` bound, in
+    /// the order in which they are declared.
+    ///
+    /// The relaxation counts whether it is written in the list of parameters,
+    /// as in `<T: ?Sized>`, or in the where-clause, as in `where T: ?Sized`.
+    ///
+    /// ```
+    /// use syn::{parse_quote, Generics};
+    ///
+    /// let mut generics: Generics = parse_quote!(<'a, T: ?Sized, U, V>);
+    /// generics.where_clause = Some(parse_quote!(where V: ?Sized + Debug));
+    ///
+    /// let params: Vec<String> = generics.maybe_sized_params().iter().map(|p| p.to_string()).collect();
+    /// assert_eq!(params, ["T", "V"]);
+    /// ```
+    pub fn maybe_sized_params(&self) -> Vec<&Ident> {
+        self.type_params()
+            .map(|param| &param.ident)
+            .filter(|ident| self.param_bounds(ident).any(is_maybe_sized))
+            .collect()
+    }
+
+    /// Whether the type parameter `param` may be instantiated with a
+    /// dynamically sized type like `str` or `dyn Trait`.
+    ///
+    /// That is the case if it is relaxed by `?Sized` and not bound by `Sized`
+    /// or by `Copy`, `Clone` or `Default`, which are traits of the standard
+    /// library that require `Sized`. Other traits that require `Sized` cannot
+    /// be recognized by their name alone, so this is only an upper bound.
+    ///
+    /// Returns false if `param` is not a type parameter of these generics.
+    pub fn may_be_unsized(&self, param: &Ident) -> bool {
+        let mut relaxed = false;
+        for bound in self.param_bounds(param) {
+            if is_maybe_sized(bound) {
+                relaxed = true;
+            } else if requires_sized(bound) {
+                return false;
+            }
+        }
+        relaxed
+    }
+
+    /// Whether a bound `ty: ?Sized` is accepted in the where-clause of these
+    /// generics, which is the case only if `ty` is one of their type
+    /// parameters.
+    pub fn permits_maybe_sized(&self, ty: &Type) -> bool {
+        self.type_params().any(|param| is_param(ty, &param.ident))
+    }
+
+    /// Adds a `?Sized` bound to the type parameter `param`, unless it already
+    /// has one in the list of parameters or in the where-clause.
+    ///
+    /// Returns whether the bound was added, which it is not if the parameter
+    /// is already relaxed or is not a type parameter of these generics.
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Generics, Ident};
+    ///
+    /// let mut generics: Generics = parse_quote!(<T: Display, U>);
+    /// let t: Ident = parse_quote!(T);
+    /// assert!(generics.add_maybe_sized(&t));
+    /// assert!(!generics.add_maybe_sized(&t));
+    ///
+    /// let expected = quote!(<T: Display + ?Sized, U>);
+    /// assert_eq!(quote!(#generics).to_string(), expected.to_string());
+    /// ```
+    pub fn add_maybe_sized(&mut self, param: &Ident) -> bool {
+        if self.param_bounds(param).any(is_maybe_sized) {
+            return false;
+        }
+        let type_param = match self
+            .type_params_mut()
+            .find(|type_param| type_param.ident == *param)
+        {
+            Some(type_param) => type_param,
+            None => return false,
+        };
+        if type_param.colon_token.is_none() {
+            type_param.colon_token = Some(<Token![:]>::default());
+        }
+        type_param.bounds.push(TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::Maybe(<Token![?]>::default()),
+            lifetimes: None,
+            path: Path::from(Ident::new("Sized", Span::call_site())),
+        }));
+        true
+    }
+
+    /// Removes every `?Sized` bound of the type parameter `param`, from the
+    /// list of parameters and from the where-clause.
+    ///
+    /// A where-predicate that is left without bounds is removed, and so is a
+    /// where-clause that is left without predicates. Returns whether any
+    /// bound was removed.
+    pub fn remove_maybe_sized(&mut self, param: &Ident) -> bool {
+        let mut removed = false;
+        for type_param in self.type_params_mut() {
+            if type_param.ident == *param {
+                removed |= remove_maybe_sized(&mut type_param.bounds);
+                if type_param.bounds.is_empty() {
+                    type_param.colon_token = None;
+                }
+            }
+        }
+        if let Some(where_clause) = &mut self.where_clause {
+            let mut removed_predicate = false;
+            for predicate in where_clause.predicates.iter_mut() {
+                if let WherePredicate::Type(predicate) = predicate {
+                    if is_param(&predicate.bounded_ty, param) {
+                        removed |= remove_maybe_sized(&mut predicate.bounds);
+                        removed_predicate |= predicate.bounds.is_empty();
+                    }
+                }
+            }
+            if removed_predicate {
+                let predicates = mem::replace(&mut where_clause.predicates, Punctuated::new());
+                where_clause.predicates = predicates
+                    .into_iter()
+                    .filter(|predicate| match predicate {
+                        WherePredicate::Type(predicate) => !predicate.bounds.is_empty(),
+                        _ => true,
+                    })
+                    .collect();
+                if where_clause.predicates.is_empty() {
+                    self.where_clause = None;
+                }
+            }
+        }
+        removed
+    }
+
+    // The bounds of the type parameter `param` from the list of parameters
+    // and from the where-predicates that bound the parameter alone.
+    fn param_bounds<'a>(&'a self, param: &'a Ident) -> impl Iterator<Item = &'a TypeParamBound> {
+        let inline = self
+            .type_params()
+            .filter(move |type_param| type_param.ident == *param)
+            .flat_map(|type_param| &type_param.bounds);
+        let predicates = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates)
+            .filter_map(move |predicate| match predicate {
+                WherePredicate::Type(predicate) if is_param(&predicate.bounded_ty, param) => {
+                    Some(&predicate.bounds)
+                }
+                _ => None,
+            })
+            .flatten();
+        inline.chain(predicates)
+    }
+}
+
+fn is_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.leading_colon.is_none()
+                && ty.path.segments.len() == 1
+                && ty.path.segments[0].ident == *param
+                && ty.path.segments[0].arguments.is_none()
+        }
+        Type::Paren(ty) => is_param(&ty.elem, param),
+        Type::Group(ty) => is_param(&ty.elem, param),
+        _ => false,
+    }
+}
+
+fn trait_name(bound: &TraitBound) -> Option<&Ident> {
+    let last = bound.path.segments.last()?;
+    if last.arguments.is_none() {
+        Some(&last.ident)
+    } else {
+        None
+    }
+}
+
+fn is_maybe_sized(bound: &TypeParamBound) -> bool {
+    match bound {
+        TypeParamBound::Trait(bound) => match bound.modifier {
+            TraitBoundModifier::Maybe(_) => trait_name(bound).map_or(false, |name| name == "Sized"),
+            TraitBoundModifier::None => false,
+        },
+        TypeParamBound::Lifetime(_) => false,
+    }
+}
+
+fn requires_sized(bound: &TypeParamBound) -> bool {
+    match bound {
+        TypeParamBound::Trait(bound) => match bound.modifier {
+            TraitBoundModifier::None => trait_name(bound).map_or(false, |name| {
+                name == "Sized" || name == "Copy" || name == "Clone" || name == "Default"
+            }),
+            TraitBoundModifier::Maybe(_) => false,
+        },
+        TypeParamBound::Lifetime(_) => false,
+    }
+}
+
+fn remove_maybe_sized(bounds: &mut Punctuated<TypeParamBound, Token![+]>) -> bool {
+    if !bounds.iter().any(is_maybe_sized) {
+        return false;
+    }
+    let old = mem::replace(bounds, Punctuated::new());
+    *bounds = old
+        .into_iter()
+        .filter(|bound| !is_maybe_sized(bound))
+        .collect();
+    true
+}
+
 pub struct TypeParams<'a>(Iter<'a, GenericParam>);
 
 impl<'a> Iterator for TypeParams<'a> {
@@ -289,34 +508,43 @@ impl<'a> Iterator for ConstParamsMut<'a> {
 
 /// Returned by `Generics::split_for_impl`.
 ///
-/// *This type is available only if Syn is built with the `"derive"` or `"full"`
-/// feature and the `"printing"` feature.*
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"printing"` feature.*
 #[cfg(feature = "printing")]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "printing"
+    )))
 )]
 pub struct ImplGenerics<'a>(&'a Generics);
 
 /// Returned by `Generics::split_for_impl`.
 ///
-/// *This type is available only if Syn is built with the `"derive"` or `"full"`
-/// feature and the `"printing"` feature.*
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"printing"` feature.*
 #[cfg(feature = "printing")]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "printing"
+    )))
 )]
 pub struct TypeGenerics<'a>(&'a Generics);
 
 /// Returned by `TypeGenerics::as_turbofish`.
 ///
-/// *This type is available only if Syn is built with the `"derive"` or `"full"`
-/// feature and the `"printing"` feature.*
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"printing"` feature.*
 #[cfg(feature = "printing")]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "printing"
+    )))
 )]
 pub struct Turbofish<'a>(&'a Generics);
 
@@ -341,11 +569,14 @@ impl Generics {
     /// # ;
     /// ```
     ///
-    /// *This method is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature and the `"printing"` feature.*
+    /// *This method is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature and the `"printing"` feature.*
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+        doc(cfg(all(
+            any(feature = "full", feature = "derive", feature = "parse-only"),
+            feature = "printing"
+        )))
     )]
     pub fn split_for_impl(&self) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
         (
@@ -411,19 +642,229 @@ generics_wrapper_impls!(Turbofish);
 impl<'a> TypeGenerics<'a> {
     /// Turn a type's generics like `<X, Y>` into a turbofish like `::<X, Y>`.
     ///
-    /// *This method is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature and the `"printing"` feature.*
+    /// *This method is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature and the `"printing"` feature.*
     pub fn as_turbofish(&self) -> Turbofish {
         Turbofish(self.0)
     }
 }
 
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+impl Generics {
+    /// Adds the parameters and where-predicates of `other` to these generics,
+    /// renaming any parameter of `other` whose name is already taken.
+    ///
+    /// This is for macros that combine the generics of user code with
+    /// parameters of their own, for example to generate a wrapper type that is
+    /// generic over both. A colliding type or const parameter `T` is renamed
+    /// to the first of `T1`, `T2`, ... that is free, and a lifetime `'a` to
+    /// the first free one of `'a1`, `'a2`, .... The renaming is applied to the
+    /// bounds, defaults and where-predicates taken over from `other`.
+    ///
+    /// Types written in terms of the parameters of `other`, for example field
+    /// types of the generated wrapper, need the same renaming. The returned
+    /// [`GenericsRenames`] applies it.
+    ///
+    /// The lifetimes of `other` are placed after the existing lifetimes, and
+    /// its other parameters after all existing parameters.
+    ///
+    /// *This method is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature and the `"clone-impls"` and
+    /// `"visit-mut"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Generics, Type};
+    ///
+    /// let mut generics: Generics = parse_quote!(<'a, T: Clone>);
+    /// let helper: Generics = parse_quote!(<'a, T: Iterator<Item = &'a u8>, F>);
+    /// let renames = generics.merge(&helper);
+    ///
+    /// let expected = quote!(<'a, 'a1, T: Clone, T1: Iterator<Item = &'a1 u8>, F>);
+    /// assert_eq!(quote!(#generics).to_string(), expected.to_string());
+    ///
+    /// let mut field: Type = parse_quote!(&'a mut T);
+    /// renames.rename_type(&mut field);
+    /// assert_eq!(quote!(#field).to_string(), quote!(&'a1 mut T1).to_string());
+    /// ```
+    pub fn merge(&mut self, other: &Generics) -> GenericsRenames {
+        let mut taken_idents: Vec<Ident> = Vec::new();
+        let mut taken_lifetimes: Vec<Ident> = Vec::new();
+        for param in self.params.iter().chain(&other.params) {
+            match param_name(param) {
+                (ident, false) => taken_idents.push(ident.clone()),
+                (ident, true) => taken_lifetimes.push(ident.clone()),
+            }
+        }
+
+        let mut renames = GenericsRenames {
+            idents: Vec::new(),
+            lifetimes: Vec::new(),
+        };
+        for param in &other.params {
+            let (ident, is_lifetime) = param_name(param);
+            let collides = self
+                .params
+                .iter()
+                .any(|existing| param_name(existing) == (ident, is_lifetime));
+            if !collides {
+                continue;
+            }
+            let taken = if is_lifetime {
+                &mut taken_lifetimes
+            } else {
+                &mut taken_idents
+            };
+            let fresh = fresh_ident(ident, taken);
+            taken.push(fresh.clone());
+            match param {
+                GenericParam::Lifetime(param) => renames.lifetimes.push((
+                    param.lifetime.clone(),
+                    Lifetime {
+                        apostrophe: param.lifetime.apostrophe,
+                        ident: fresh,
+                    },
+                )),
+                _ => renames.idents.push((ident.clone(), fresh)),
+            }
+        }
+
+        let mut other = other.clone();
+        for param in &mut other.params {
+            match param {
+                GenericParam::Type(param) => renames.rename_ident(&mut param.ident),
+                GenericParam::Const(param) => renames.rename_ident(&mut param.ident),
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+        renames.rename(|visitor| visitor.visit_generics_mut(&mut other));
+
+        for param in other.params {
+            if let GenericParam::Lifetime(_) = param {
+                let index = self.lifetimes().count();
+                self.params.insert(index, param);
+            } else {
+                self.params.push(param);
+            }
+        }
+        if let Some(where_clause) = other.where_clause {
+            self.make_where_clause()
+                .predicates
+                .extend(where_clause.predicates);
+        }
+        renames
+    }
+}
+
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+fn param_name(param: &GenericParam) -> (&Ident, bool) {
+    match param {
+        GenericParam::Type(param) => (&param.ident, false),
+        GenericParam::Const(param) => (&param.ident, false),
+        GenericParam::Lifetime(param) => (&param.lifetime.ident, true),
+    }
+}
+
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+fn fresh_ident(ident: &Ident, taken: &[Ident]) -> Ident {
+    let name = ident.to_string();
+    let mut n = 1;
+    loop {
+        let candidate = Ident::new(&format!("{}{}", name, n), ident.span());
+        if !taken.contains(&candidate) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// The renaming performed by [`Generics::merge`].
+///
+/// *This type is available only if Syn is built with the `"derive"`, `"full"`
+/// or `"parse-only"` feature and the `"clone-impls"` and `"visit-mut"`
+/// features.*
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "clone-impls",
+        feature = "visit-mut"
+    )))
+)]
+pub struct GenericsRenames {
+    /// Renamed type and const parameters, from the old name to the new one.
+    pub idents: Vec<(Ident, Ident)>,
+    /// Renamed lifetime parameters, from the old lifetime to the new one.
+    pub lifetimes: Vec<(Lifetime, Lifetime)>,
+}
+
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+impl GenericsRenames {
+    /// Whether no parameter had to be renamed.
+    pub fn is_empty(&self) -> bool {
+        self.idents.is_empty() && self.lifetimes.is_empty()
+    }
+
+    /// Rewrites references to the renamed parameters in a type.
+    pub fn rename_type(&self, ty: &mut Type) {
+        self.rename(|visitor| visitor.visit_type_mut(ty));
+    }
+
+    /// Rewrites references to the renamed parameters in a path, including a
+    /// path that starts with a parameter like `T::Item`.
+    pub fn rename_path(&self, path: &mut Path) {
+        self.rename(|visitor| visitor.visit_path_mut(path));
+    }
+
+    /// Rewrites references to the renamed parameters in a where-predicate.
+    pub fn rename_where_predicate(&self, predicate: &mut WherePredicate) {
+        self.rename(|visitor| visitor.visit_where_predicate_mut(predicate));
+    }
+
+    fn rename(&self, f: impl FnOnce(&mut Renamer)) {
+        if !self.is_empty() {
+            f(&mut Renamer(self));
+        }
+    }
+
+    fn rename_ident(&self, ident: &mut Ident) {
+        if let Some((_old, new)) = self.idents.iter().find(|(old, _new)| old == ident) {
+            *ident = Ident::new(&new.to_string(), ident.span());
+        }
+    }
+}
+
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+struct Renamer<'a>(&'a GenericsRenames);
+
+#[cfg(all(feature = "clone-impls", feature = "visit-mut"))]
+impl<'a> crate::visit_mut::VisitMut for Renamer<'a> {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        if path.leading_colon.is_none() {
+            if let Some(first) = path.segments.first_mut() {
+                self.0.rename_ident(&mut first.ident);
+            }
+        }
+        crate::visit_mut::visit_path_mut(self, path);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        let renamed = self.0.lifetimes.iter().find(|(old, _new)| old == lifetime);
+        if let Some((_old, new)) = renamed {
+            lifetime.ident = Ident::new(&new.ident.to_string(), lifetime.ident.span());
+        }
+    }
+}
+
 ast_struct! {
     /// A set of bound lifetimes: `for<'a, 'b, 'c>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct BoundLifetimes {
         pub for_token: Token![for],
         pub lt_token: Token![<],
@@ -470,9 +911,9 @@ impl From<Ident> for TypeParam {
 ast_enum_of_structs! {
     /// A trait or lifetime used as a bound on a type parameter.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum TypeParamBound {
         Trait(TraitBound),
         Lifetime(Lifetime),
@@ -482,9 +923,9 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A trait used as a bound on a type parameter.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TraitBound {
         pub paren_token: Option<token::Paren>,
         pub modifier: TraitBoundModifier,
@@ -499,9 +940,9 @@ ast_enum! {
     /// A modifier on a trait bound, currently only used for the `?` in
     /// `?Sized`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum TraitBoundModifier {
         None,
         Maybe(Token![?]),
@@ -512,9 +953,9 @@ ast_struct! {
     /// A `where` clause in a definition: `where T: Deserialize<'de>, D:
     /// 'static`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct WhereClause {
         pub where_token: Token![where],
         pub predicates: Punctuated<WherePredicate, Token![,]>,
@@ -524,15 +965,15 @@ ast_struct! {
 ast_enum_of_structs! {
     /// A single predicate in a `where` clause: `T: Deserialize<'de>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// # Syntax tree enum
     ///
     /// This type is a [syntax tree enum].
     ///
     /// [syntax tree enum]: Expr#syntax-tree-enums
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum WherePredicate {
         /// A type predicate in a `where` clause: `for<'c> Foo<'c>: Trait<'c>`.
         Type(PredicateType),
@@ -540,7 +981,7 @@ ast_enum_of_structs! {
         /// A lifetime predicate in a `where` clause: `'a: 'b + 'c`.
         Lifetime(PredicateLifetime),
 
-        /// An equality predicate in a `where` clause (unsupported).
+        /// An equality predicate in a `where` clause: `T::Output = u32`.
         Eq(PredicateEq),
     }
 }
@@ -548,9 +989,9 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A type predicate in a `where` clause: `for<'c> Foo<'c>: Trait<'c>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct PredicateType {
         /// Any lifetimes from a `for` binding
         pub lifetimes: Option<BoundLifetimes>,
@@ -565,9 +1006,9 @@ ast_struct! {
 ast_struct! {
     /// A lifetime predicate in a `where` clause: `'a: 'b + 'c`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct PredicateLifetime {
         pub lifetime: Lifetime,
         pub colon_token: Token![:],
@@ -576,11 +1017,14 @@ ast_struct! {
 }
 
 ast_struct! {
-    /// An equality predicate in a `where` clause (unsupported).
+    /// An equality predicate in a `where` clause: `T::Output = u32`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// Equality predicates are not accepted by stable compilers, but are parsed
+    /// so that macros can see them and produce their own diagnostics.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct PredicateEq {
         pub lhs_ty: Type,
         pub eq_token: Token![=],
@@ -985,9 +1429,24 @@ pub mod parsing {
                     },
                 }))
             } else {
+                let lifetimes: Option<BoundLifetimes> = input.parse()?;
+                let bounded_ty: Type = input.parse()?;
+                if input.peek(Token![=]) && !input.peek(Token![==]) {
+                    if let Some(lifetimes) = lifetimes {
+                        return Err(Error::new(
+                            lifetimes.for_token.span,
+                            "`for<...>` is not allowed on an equality predicate",
+                        ));
+                    }
+                    return Ok(WherePredicate::Eq(PredicateEq {
+                        lhs_ty: bounded_ty,
+                        eq_token: input.parse()?,
+                        rhs_ty: input.parse()?,
+                    }));
+                }
                 Ok(WherePredicate::Type(PredicateType {
-                    lifetimes: input.parse()?,
-                    bounded_ty: input.parse()?,
+                    lifetimes,
+                    bounded_ty,
                     colon_token: input.parse()?,
                     bounds: {
                         let mut bounds = Punctuated::new();
@@ -25,7 +25,7 @@ pub struct Brackets<'a> {
 }
 
 // Not public API.
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 #[doc(hidden)]
 pub struct Group<'a> {
     pub token: token::Group,
@@ -59,7 +59,7 @@ pub fn parse_brackets<'a>(input: &ParseBuffer<'a>) -> Result<Brackets<'a>> {
     })
 }
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub(crate) fn parse_group<'a>(input: &ParseBuffer<'a>) -> Result<Group<'a>> {
     parse_delimited(input, Delimiter::None).map(|(span, content)| Group {
         token: token::Group(span),
@@ -75,8 +75,7 @@ fn parse_delimited<'a>(
         if let Some((content, span, rest)) = cursor.group(delimiter) {
             let scope = crate::buffer::close_span_of_group(*cursor);
             let nested = crate::parse::advance_step_cursor(cursor, content);
-            let unexpected = crate::parse::get_unexpected(input);
-            let content = crate::parse::new_parse_buffer(scope, nested, unexpected);
+            let content = crate::parse::new_nested_parse_buffer(input, scope, nested);
             Ok(((span, content), rest))
         } else {
             let message = match delimiter {
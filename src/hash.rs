@@ -0,0 +1,320 @@
+//! Structural hashing of syntax trees that is stable across builds.
+//!
+//! The [`Hash`] impls of the syntax tree types hash a tree by its structure,
+//! leaving out spans, but the hashers of the standard library are seeded per
+//! process or make no promise about their output from one release of Rust to
+//! the next, and neither the standard library nor `proc-macro2` promises
+//! what its own `Hash` impls write to the hasher. A build tool that caches
+//! the result of expensive processing of a syntax tree on disk needs a key
+//! that does not change between runs. The functions in this module hash a
+//! tree through the [`StableHash`] impls, which write every length, name
+//! and literal to [`StableHasher`] in a fixed encoding, and the hasher
+//! depends on nothing but the bytes it is given.
+//!
+//! [`Hash`]: std::hash::Hash
+//!
+//! # Stability
+//!
+//! The hash of a syntax tree is the same on every platform, in every process
+//! and with every version of Rust and `proc-macro2` for releases of Syn with
+//! the same minor version, that is, all of 1.0.x. A release with a new minor version may change the hash of any
+//! tree. Within a minor version, two trees have the same hash if they are
+//! equal by their [`PartialEq`] impls, which compare neither spans nor the
+//! tokens of punctuation and delimiters, and trees that are not equal have
+//! different hashes but for collisions.
+//!
+//! *This module is available only if Syn is built with the `"extra-traits"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::ItemFn;
+//!
+//! let a: ItemFn = syn::parse_str("fn f(x: u8) -> u8 { x + 1 }").unwrap();
+//! let b: ItemFn = syn::parse_str("fn f(x:u8)->u8{x+1}").unwrap();
+//! let c: ItemFn = syn::parse_str("fn f(x: u8) -> u8 { x + 2 }").unwrap();
+//!
+//! assert_eq!(syn::hash::hash128(&a), syn::hash::hash128(&b));
+//! assert_ne!(syn::hash::hash128(&a), syn::hash::hash128(&c));
+//! ```
+
+use crate::punctuated::Punctuated;
+use proc_macro2::{Delimiter, Ident, Literal, Spacing, TokenStream, TokenTree};
+use std::hash::Hasher;
+use std::mem;
+
+/// Returns the 64-bit structural hash of a syntax tree.
+///
+/// See the [module documentation](self) for the stability of the hash.
+///
+/// *This function is available only if Syn is built with the
+/// `"extra-traits"` feature.*
+pub fn hash64<T: ?Sized + StableHash>(node: &T) -> u64 {
+    let mut hasher = StableHasher::new();
+    node.stable_hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the 128-bit structural hash of a syntax tree.
+///
+/// See the [module documentation](self) for the stability of the hash.
+///
+/// *This function is available only if Syn is built with the
+/// `"extra-traits"` feature.*
+pub fn hash128<T: ?Sized + StableHash>(node: &T) -> u128 {
+    let mut hasher = StableHasher::new();
+    node.stable_hash(&mut hasher);
+    hasher.finish128()
+}
+
+/// Hashing of a syntax tree with a [`StableHasher`].
+///
+/// Unlike [`Hash`](std::hash::Hash), whose impls in the standard library and
+/// in `proc-macro2` are free to change what they write to the hasher, the
+/// impls of this trait spell out every byte they write. A sequence writes
+/// its length as a `u64` followed by its elements, a string writes its
+/// length and its UTF-8 bytes, an identifier or literal writes the string it
+/// is printed as, and an enum writes the index of its variant as a `u8`.
+///
+/// This trait is implemented for every syntax tree node and token, as well
+/// as for the [`Ident`], [`Literal`] and [`TokenStream`] of `proc-macro2`.
+///
+/// *This trait is available only if Syn is built with the `"extra-traits"`
+/// feature.*
+pub trait StableHash {
+    /// Writes the structure of `self` to `hasher`, leaving out spans.
+    fn stable_hash(&self, hasher: &mut StableHasher);
+}
+
+impl StableHash for str {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u64(self.len() as u64);
+        hasher.write(self.as_bytes());
+    }
+}
+
+impl StableHash for String {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+impl StableHash for bool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u8(*self as u8);
+    }
+}
+
+impl StableHash for u32 {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u32(*self);
+    }
+}
+
+impl StableHash for usize {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u64(*self as u64);
+    }
+}
+
+impl StableHash for Ident {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.to_string().stable_hash(hasher);
+    }
+}
+
+impl StableHash for Literal {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.to_string().stable_hash(hasher);
+    }
+}
+
+// Hashes the same way as the Hash impl of the helper that the syntax tree
+// types use for their token streams, but with explicit lengths.
+impl StableHash for TokenStream {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        let tts: Vec<TokenTree> = self.clone().into_iter().collect();
+        tts.stable_hash(hasher);
+    }
+}
+
+impl StableHash for TokenTree {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            TokenTree::Group(group) => {
+                hasher.write_u8(0);
+                hasher.write_u8(match group.delimiter() {
+                    Delimiter::Parenthesis => 0,
+                    Delimiter::Brace => 1,
+                    Delimiter::Bracket => 2,
+                    Delimiter::None => 3,
+                });
+                group.stream().stable_hash(hasher);
+            }
+            TokenTree::Punct(punct) => {
+                hasher.write_u8(1);
+                hasher.write_u32(punct.as_char() as u32);
+                hasher.write_u8(match punct.spacing() {
+                    Spacing::Alone => 0,
+                    Spacing::Joint => 1,
+                });
+            }
+            TokenTree::Literal(literal) => {
+                hasher.write_u8(2);
+                literal.stable_hash(hasher);
+            }
+            TokenTree::Ident(ident) => {
+                hasher.write_u8(3);
+                ident.stable_hash(hasher);
+            }
+        }
+    }
+}
+
+impl<T: StableHash> StableHash for Option<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            None => hasher.write_u8(0),
+            Some(value) => {
+                hasher.write_u8(1);
+                value.stable_hash(hasher);
+            }
+        }
+    }
+}
+
+impl<T: ?Sized + StableHash> StableHash for Box<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+impl<T: StableHash> StableHash for [T] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u64(self.len() as u64);
+        for value in self {
+            value.stable_hash(hasher);
+        }
+    }
+}
+
+impl<T: StableHash> StableHash for Vec<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_hash(hasher);
+    }
+}
+
+// The punctuation itself is not hashed, as its tokens compare equal, but
+// whether there is a trailing one is.
+impl<T: StableHash, P> StableHash for Punctuated<T, P> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write_u64(self.len() as u64);
+        for value in self {
+            value.stable_hash(hasher);
+        }
+        self.trailing_punct().stable_hash(hasher);
+    }
+}
+
+impl<A: StableHash, B: StableHash> StableHash for (A, B) {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.0.stable_hash(hasher);
+        self.1.stable_hash(hasher);
+    }
+}
+
+impl<A: StableHash, B: StableHash, C: StableHash> StableHash for (A, B, C) {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.0.stable_hash(hasher);
+        self.1.stable_hash(hasher);
+        self.2.stable_hash(hasher);
+    }
+}
+
+const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+/// A hasher whose output depends only on the bytes written to it.
+///
+/// This is the 128-bit FNV-1a hash, with integers written to it in
+/// little-endian byte order and `usize` and `isize` widened to 64 bits, so
+/// that the output is the same on every platform. It is not a cryptographic
+/// hash, and does not resist collisions crafted on purpose.
+///
+/// *This type is available only if Syn is built with the `"extra-traits"`
+/// feature.*
+pub struct StableHasher {
+    state: u128,
+}
+
+impl StableHasher {
+    /// Creates a hasher to which nothing has been written.
+    pub fn new() -> Self {
+        StableHasher {
+            state: OFFSET_BASIS,
+        }
+    }
+
+    /// Returns the 128-bit hash of the bytes written so far.
+    pub fn finish128(&self) -> u128 {
+        self.state
+    }
+
+    fn write_le(&mut self, i: u128, len: usize) {
+        for n in 0..len {
+            self.write(&[(i >> (8 * n)) as u8]);
+        }
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher::new()
+    }
+}
+
+impl Clone for StableHasher {
+    fn clone(&self) -> Self {
+        StableHasher { state: self.state }
+    }
+}
+
+impl Hasher for StableHasher {
+    /// Returns the 64-bit hash of the bytes written so far, which is the two
+    /// halves of the 128-bit hash combined with exclusive or.
+    fn finish(&self) -> u64 {
+        (self.state >> 64) as u64 ^ self.state as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u128::from(*byte);
+            self.state = self.state.wrapping_mul(PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_le(u128::from(i), mem::size_of::<u16>());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_le(u128::from(i), mem::size_of::<u32>());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write_le(u128::from(i), mem::size_of::<u64>());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_le(i, mem::size_of::<u128>());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as i64 as u64);
+    }
+}
@@ -1,13 +1,16 @@
 #[cfg(feature = "parsing")]
 use crate::buffer::Cursor;
+use crate::error::{Error, Result};
 #[cfg(feature = "parsing")]
 use crate::lookahead;
 #[cfg(feature = "parsing")]
-use crate::parse::{Parse, ParseStream, Result};
+use crate::parse::{Parse, ParseStream};
 #[cfg(feature = "parsing")]
 use crate::token::Token;
 
 pub use proc_macro2::Ident;
+use proc_macro2::Span;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "parsing")]
 #[doc(hidden)]
@@ -16,8 +19,8 @@ pub fn Ident(marker: lookahead::TokenMarker) -> Ident {
     match marker {}
 }
 
-#[cfg(feature = "parsing")]
-fn accept_as_ident(ident: &Ident) -> bool {
+#[cfg(any(feature = "parsing", all(feature = "full", feature = "visit")))]
+pub(crate) fn accept_as_ident(ident: &Ident) -> bool {
     match ident.to_string().as_str() {
         "_" |
         // Based on https://doc.rust-lang.org/grammar.html#keywords
@@ -86,6 +89,76 @@ impl From<Token![_]> for Ident {
     }
 }
 
+/// Creates a fresh identifier for use in macro-generated code that cannot
+/// collide with identifiers written by the user of the macro.
+///
+/// The identifier has [`Span::mixed_site()`] hygiene, so a local variable
+/// introduced with it is invisible to code written by the user even if the
+/// user happens to write the same name, and vice versa. Items and fields do
+/// not get this protection from hygiene, so in addition each call returns a
+/// name that has not been returned before in the same process: the prefix,
+/// preceded by `__` and followed by a unique number.
+///
+/// On compilers older than Rust 1.45, which have no mixed-site hygiene, the
+/// identifier has a call-site span, and only the unique name keeps it from
+/// colliding with the user's identifiers.
+///
+/// # Errors
+///
+/// Returns an error if `prefix` contains characters that are not allowed in
+/// an identifier.
+///
+/// # Example
+///
+/// ```
+/// # use quote::quote;
+/// #
+/// let value = syn::gensym("value").unwrap();
+/// assert!(value.to_string().starts_with("__value"));
+///
+/// let tokens = quote! {
+///     let #value = compute();
+///     consume(&#value);
+/// };
+/// # let _ = tokens;
+/// ```
+pub fn gensym(prefix: &str) -> Result<Ident> {
+    gensym_at(prefix, Span::call_site())
+}
+
+/// Creates a fresh identifier like [`gensym`] that is located at the given
+/// span for the purpose of error messages.
+///
+/// Name resolution of the returned identifier still follows
+/// [`Span::mixed_site()`] hygiene regardless of where `span` came from.
+pub fn gensym_at(prefix: &str, span: Span) -> Result<Ident> {
+    if !xid_ok(&format!("__{}", prefix)) {
+        return Err(Error::new(
+            span,
+            format!("{:?} is not a valid identifier prefix", prefix),
+        ));
+    }
+    Ok(fresh_ident(prefix, span))
+}
+
+// Like `gensym_at`, for prefixes known to be valid.
+pub(crate) fn fresh_ident(prefix: &str, span: Span) -> Ident {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let name = format!("__{}{}", prefix, id);
+    Ident::new(&name, hygienic(span))
+}
+
+#[cfg(not(syn_no_hygiene))]
+fn hygienic(span: Span) -> Span {
+    span.resolved_at(Span::mixed_site())
+}
+
+#[cfg(syn_no_hygiene)]
+fn hygienic(span: Span) -> Span {
+    span
+}
+
 pub fn xid_ok(symbol: &str) -> bool {
     let mut chars = symbol.chars();
     let first = chars.next().unwrap();
@@ -0,0 +1,282 @@
+//! Matching of paths and calls against a known function, through the `use`
+//! items of a file.
+//!
+//! Tools that audit code for calls of dangerous functions, such as
+//! `std::mem::transmute` or `std::ptr::read`, miss every call that is not
+//! written out in full if they compare the path of the callee as written:
+//! after `use std::mem;` the call is `mem::transmute(x)`, and after
+//! `use std::mem::transmute as cast;` it is `cast(x)`. [`Imports`] collects
+//! the `use` items of a file, and is given to [`Expr::is_call_to_in`] and
+//! [`ExprPath::refers_to`] to expand the first segment of a path through
+//! them before comparing it to the path of the function.
+//!
+//! The resolution is purely syntactic, and errs on the side of reporting a
+//! match:
+//!
+//! - The `use` items of every module and block of the file are collected
+//!   into one set, without regard to which scope they are in.
+//! - A path below a glob import `use std::mem::*;` may name an item of the
+//!   glob, so `transmute` after it matches `std::mem::transmute`, even if
+//!   the file also defines a `transmute` of its own.
+//! - The crates `std`, `core` and `alloc` are taken to be the same, since
+//!   `std` reexports the items of the other two.
+//! - Generic arguments are ignored, so `transmute::<u32, f32>` is a path to
+//!   `std::mem::transmute`.
+//!
+//! Reexports from other files, `extern crate` renames and macros that
+//! expand to a call are not seen.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"visit"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::imports::Imports;
+//! use syn::{parse_quote, Expr, File};
+//!
+//! let file: File = parse_quote! {
+//!     use std::mem::{self, transmute as cast};
+//! };
+//! let imports = Imports::from_file(&file);
+//!
+//! let calls: Vec<Expr> = vec![
+//!     parse_quote!(::core::mem::transmute(x)),
+//!     parse_quote!(mem::transmute::<u32, f32>(x)),
+//!     parse_quote!(cast(x)),
+//!     parse_quote!(transmute(x)),
+//! ];
+//! let flagged: Vec<bool> = calls
+//!     .iter()
+//!     .map(|call| call.is_call_to_in(&["std", "mem", "transmute"], &imports))
+//!     .collect();
+//! assert_eq!(flagged, [true, true, true, false]);
+//! ```
+
+use super::*;
+use crate::visit::Visit;
+
+// How many `use` items deep an alias is followed, as in `use std::mem;`
+// followed by `use mem::transmute;`.
+const MAX_DEPTH: usize = 4;
+
+/// The `use` items of a file, through which paths are resolved.
+///
+/// See the [module documentation](self) for how a path is resolved.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+pub struct Imports {
+    // The name that each import brings into scope, and the path that it
+    // refers to.
+    aliases: Vec<(String, Vec<String>)>,
+    // The path of the module of each glob import.
+    globs: Vec<Vec<String>>,
+}
+
+impl Imports {
+    /// Creates a set of imports that is empty, through which every path
+    /// resolves to itself.
+    pub fn new() -> Self {
+        Imports {
+            aliases: Vec::new(),
+            globs: Vec::new(),
+        }
+    }
+
+    /// Collects the `use` items anywhere in a file, including those in
+    /// inline modules and in the bodies of functions.
+    pub fn from_file(file: &File) -> Self {
+        let mut collector = UseCollector {
+            imports: Imports::new(),
+        };
+        collector.visit_file(file);
+        collector.imports
+    }
+
+    /// Adds the imports of one `use` item.
+    pub fn add(&mut self, item: &ItemUse) {
+        let mut prefix = Vec::new();
+        self.add_tree(&mut prefix, &item.tree);
+    }
+
+    fn add_tree(&mut self, prefix: &mut Vec<String>, tree: &UseTree) {
+        match tree {
+            UseTree::Path(tree) => {
+                prefix.push(tree.ident.to_string());
+                self.add_tree(prefix, &tree.tree);
+                prefix.pop();
+            }
+            UseTree::Name(tree) => {
+                if tree.ident == "self" {
+                    if let Some(last) = prefix.last() {
+                        self.aliases.push((last.clone(), prefix.clone()));
+                    }
+                } else {
+                    let mut path = prefix.clone();
+                    path.push(tree.ident.to_string());
+                    self.aliases.push((tree.ident.to_string(), path));
+                }
+            }
+            UseTree::Rename(tree) => {
+                if tree.rename == "_" {
+                    return;
+                }
+                let mut path = prefix.clone();
+                if tree.ident != "self" {
+                    path.push(tree.ident.to_string());
+                }
+                if !path.is_empty() {
+                    self.aliases.push((tree.rename.to_string(), path));
+                }
+            }
+            UseTree::Glob(_) => {
+                if !prefix.is_empty() {
+                    self.globs.push(prefix.clone());
+                }
+            }
+            UseTree::Group(tree) => {
+                for tree in &tree.items {
+                    self.add_tree(prefix, tree);
+                }
+            }
+        }
+    }
+
+    /// Returns every path that a path may refer to, as the names of its
+    /// segments, beginning with the path as written.
+    ///
+    /// A path with a leading `::` resolves only to itself.
+    pub fn resolve(&self, path: &Path) -> Vec<Vec<String>> {
+        let segments: Vec<String> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let mut resolved = Vec::new();
+        if path.leading_colon.is_some() {
+            resolved.push(segments);
+        } else {
+            self.expand(segments, MAX_DEPTH, &mut resolved);
+        }
+        resolved
+    }
+
+    fn expand(&self, segments: Vec<String>, depth: usize, resolved: &mut Vec<Vec<String>>) {
+        if segments.is_empty() || resolved.contains(&segments) {
+            return;
+        }
+        resolved.push(segments.clone());
+        if depth > 0 {
+            for (name, path) in &self.aliases {
+                if *name == segments[0] {
+                    let mut expanded = path.clone();
+                    expanded.extend(segments[1..].iter().cloned());
+                    self.expand(expanded, depth - 1, resolved);
+                }
+            }
+            for glob in &self.globs {
+                let mut expanded = glob.clone();
+                expanded.extend(segments.iter().cloned());
+                self.expand(expanded, depth - 1, resolved);
+            }
+        }
+    }
+
+    /// Returns whether a path may refer to the item at `target`, such as
+    /// `["std", "mem", "transmute"]`.
+    pub fn refers_to(&self, path: &Path, target: &[&str]) -> bool {
+        self.resolve(path)
+            .iter()
+            .any(|segments| same_path(segments, target))
+    }
+}
+
+impl Default for Imports {
+    fn default() -> Self {
+        Imports::new()
+    }
+}
+
+impl Clone for Imports {
+    fn clone(&self) -> Self {
+        Imports {
+            aliases: self.aliases.clone(),
+            globs: self.globs.clone(),
+        }
+    }
+}
+
+struct UseCollector {
+    imports: Imports,
+}
+
+impl<'ast> Visit<'ast> for UseCollector {
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        self.imports.add(item);
+    }
+}
+
+fn same_path(segments: &[String], target: &[&str]) -> bool {
+    segments.len() == target.len()
+        && segments
+            .iter()
+            .zip(target)
+            .enumerate()
+            .all(|(i, (a, b))| a == b || i == 0 && is_std_crate(a) && is_std_crate(b))
+}
+
+fn is_std_crate(name: &str) -> bool {
+    name == "std" || name == "core" || name == "alloc"
+}
+
+impl Expr {
+    /// Returns whether this is a call of the function at `target`, written
+    /// as a path to it, such as `["std", "mem", "transmute"]`.
+    ///
+    /// The path of the callee is compared as written, other than that the
+    /// crates `std`, `core` and `alloc` are taken to be the same. Use
+    /// [`is_call_to_in`](Expr::is_call_to_in) to resolve it through the `use`
+    /// items of a file.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"visit"` features.*
+    pub fn is_call_to(&self, target: &[&str]) -> bool {
+        self.is_call_to_in(target, &Imports::new())
+    }
+
+    /// Returns whether this is a call of the function at `target`, with the
+    /// path of the callee resolved through `imports`.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"visit"` features.*
+    pub fn is_call_to_in(&self, target: &[&str], imports: &Imports) -> bool {
+        let mut func = match self {
+            Expr::Call(call) => &*call.func,
+            _ => return false,
+        };
+        loop {
+            match func {
+                Expr::Paren(expr) => func = &expr.expr,
+                Expr::Group(expr) => func = &expr.expr,
+                Expr::Path(path) => return path.refers_to(target, imports),
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl ExprPath {
+    /// Returns whether this path, resolved through `imports`, may refer to
+    /// the item at `target`, as when a function is passed by name to
+    /// `map`.
+    ///
+    /// A path with a qualified self type, like `<T as Trait>::f`, refers to
+    /// none.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"visit"` features.*
+    pub fn refers_to(&self, target: &[&str], imports: &Imports) -> bool {
+        self.qself.is_none() && imports.refers_to(&self.path, target)
+    }
+}
@@ -1,7 +1,8 @@
 use super::*;
 use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
-use crate::punctuated::Punctuated;
-use proc_macro2::TokenStream;
+use crate::punctuated::{self, Punctuated};
+use proc_macro2::{Span, TokenStream};
+use std::iter;
 
 #[cfg(feature = "parsing")]
 use std::mem;
@@ -433,6 +434,151 @@ impl From<ItemStruct> for DeriveInput {
     }
 }
 
+impl ItemEnum {
+    /// Returns a pattern for each variant of the enum, in order, that matches
+    /// the variant as `Self::Variant` and binds each of its fields.
+    ///
+    /// See [`FieldsPattern`] for an example.
+    pub fn variant_patterns(&self) -> Vec<FieldsPattern<'_>> {
+        self.variants.iter().map(Variant::pattern).collect()
+    }
+}
+
+impl ItemImpl {
+    /// Finds the associated item of the given kind and name.
+    ///
+    /// Macro invocations and verbatim items have no name and are never found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ImplItemKind, ItemImpl};
+    ///
+    /// let item: ItemImpl = parse_quote! {
+    ///     impl Iterator for Counter {
+    ///         type Item = u32;
+    ///         fn next(&mut self) -> Option<u32> { None }
+    ///     }
+    /// };
+    ///
+    /// assert!(item.get_item(ImplItemKind::Type, "Item").is_some());
+    /// assert!(item.get_item(ImplItemKind::Method, "Item").is_none());
+    /// assert!(item.method("next").is_some());
+    /// ```
+    pub fn get_item<T>(&self, kind: ImplItemKind, name: T) -> Option<&ImplItem>
+    where
+        T: AsRef<str>,
+    {
+        let name = name.as_ref();
+        self.items.iter().find(|item| {
+            item.kind() == kind
+                && match impl_item_ident(item) {
+                    Some(ident) => ident == name,
+                    None => false,
+                }
+        })
+    }
+
+    /// Finds the method with the given name.
+    pub fn method<T: AsRef<str>>(&self, name: T) -> Option<&ImplItemMethod> {
+        match self.get_item(ImplItemKind::Method, name) {
+            Some(ImplItem::Method(method)) => Some(method),
+            _ => None,
+        }
+    }
+
+    /// Finds the associated type with the given name.
+    pub fn associated_type<T: AsRef<str>>(&self, name: T) -> Option<&ImplItemType> {
+        match self.get_item(ImplItemKind::Type, name) {
+            Some(ImplItem::Type(ty)) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Finds the associated constant with the given name.
+    pub fn associated_const<T: AsRef<str>>(&self, name: T) -> Option<&ImplItemConst> {
+        match self.get_item(ImplItemKind::Const, name) {
+            Some(ImplItem::Const(item)) => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Whether this is an impl of the given trait, as opposed to an inherent
+    /// impl or a negative impl `impl !Trait for Type`.
+    ///
+    /// Paths are compared segment by segment, ignoring generic arguments, so
+    /// `impl From<u8> for Type` implements `From` and `From<u16>` alike. The
+    /// comparison is syntactic: `fmt::Debug` is not the same trait as `Debug`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemImpl};
+    ///
+    /// let item: ItemImpl = parse_quote! {
+    ///     impl<'a> From<&'a str> for Name {
+    ///         fn from(s: &'a str) -> Self { Name(s.to_owned()) }
+    ///     }
+    /// };
+    ///
+    /// assert!(item.implements_trait(&parse_quote!(From)));
+    /// assert!(!item.implements_trait(&parse_quote!(Into)));
+    /// ```
+    pub fn implements_trait(&self, trait_path: &Path) -> bool {
+        let path = match &self.trait_ {
+            Some((None, path, _)) => path,
+            Some((Some(_), _, _)) | None => return false,
+        };
+        path.leading_colon.is_some() == trait_path.leading_colon.is_some()
+            && path.segments.len() == trait_path.segments.len()
+            && path
+                .segments
+                .iter()
+                .zip(&trait_path.segments)
+                .all(|(a, b)| a.ident == b.ident)
+    }
+
+    /// The path of the self type, such as `Wrapper<T>` in `impl<T> Trait for
+    /// Wrapper<T>`, looking through parentheses and invisible groups.
+    ///
+    /// Returns `None` if the self type is not a path, such as for `impl Trait
+    /// for [T]`, or if it is a qualified path `<T as Trait>::Assoc`.
+    pub fn self_ty_path(&self) -> Option<&Path> {
+        let mut ty = &*self.self_ty;
+        loop {
+            match ty {
+                Type::Paren(paren) => ty = &paren.elem,
+                Type::Group(group) => ty = &group.elem,
+                Type::Path(TypePath { qself: None, path }) => return Some(path),
+                _ => return None,
+            }
+        }
+    }
+
+    /// The name of the self type, such as `Wrapper` in `impl<T> Trait for
+    /// some::Wrapper<T>`.
+    ///
+    /// This is the last segment of [`self_ty_path`](ItemImpl::self_ty_path),
+    /// which is how impls of the same type get matched up by tools that merge
+    /// them.
+    pub fn self_ty_ident(&self) -> Option<&Ident> {
+        let path = self.self_ty_path()?;
+        path.segments.last().map(|segment| &segment.ident)
+    }
+}
+
+fn impl_item_ident(item: &ImplItem) -> Option<&Ident> {
+    match item {
+        ImplItem::Const(item) => Some(&item.ident),
+        ImplItem::Method(item) => Some(&item.sig.ident),
+        ImplItem::Type(item) => Some(&item.ident),
+        ImplItem::Macro(_) | ImplItem::Verbatim(_) => None,
+
+        #[cfg(syn_no_non_exhaustive)]
+        _ => unreachable!(),
+    }
+}
+
 impl From<ItemEnum> for DeriveInput {
     fn from(input: ItemEnum) -> DeriveInput {
         DeriveInput {
@@ -464,6 +610,132 @@ impl From<ItemUnion> for DeriveInput {
     }
 }
 
+#[cfg(feature = "clone-impls")]
+impl DeriveInput {
+    /// Creates an empty `impl Trait for Type` block for the input type, to
+    /// which the caller adds associated items.
+    ///
+    /// The impl is generic over the input's generic parameters, with their
+    /// defaults removed, and carries over the input's where-clause. The self
+    /// type names the input type applied to its parameters, as in `impl<'a,
+    /// T: Clone> Trait for Wrapper<'a, T>`.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, DeriveInput};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     struct Wrapper<'a, T: ?Sized = str> where T: Debug {
+    ///         inner: &'a T,
+    ///     }
+    /// };
+    ///
+    /// let mut item = input.impl_trait(parse_quote!(MyDebug));
+    /// item.items.push(parse_quote! {
+    ///     fn my_debug(&self) {}
+    /// });
+    ///
+    /// let expected = quote! {
+    ///     impl<'a, T: ?Sized> MyDebug for Wrapper<'a, T> where T: Debug {
+    ///         fn my_debug(&self) {}
+    ///     }
+    /// };
+    /// assert_eq!(quote!(#item).to_string(), expected.to_string());
+    /// ```
+    pub fn impl_trait(&self, trait_path: Path) -> ItemImpl {
+        self.impl_trait_with_generics(trait_path, &Generics::default())
+    }
+
+    /// Like [`impl_trait`](DeriveInput::impl_trait) but with additional
+    /// generic parameters and where-predicates for the impl, such as the
+    /// `'de` in `impl<'de> Deserialize<'de> for Type`.
+    ///
+    /// The additional lifetimes are placed after the input's own lifetimes
+    /// and the other additional parameters after all of the input's
+    /// parameters. They are expected not to collide with the input's
+    /// parameters.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    pub fn impl_trait_with_generics(&self, trait_path: Path, extra: &Generics) -> ItemImpl {
+        let span = Span::call_site();
+
+        let mut generics = self.generics.clone();
+        for param in &extra.params {
+            if let GenericParam::Lifetime(_) = param {
+                let index = generics.lifetimes().count();
+                generics.params.insert(index, param.clone());
+            } else {
+                generics.params.push(param.clone());
+            }
+        }
+        for param in &mut generics.params {
+            match param {
+                GenericParam::Type(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Const(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+        if let Some(extra) = &extra.where_clause {
+            let where_clause = generics.make_where_clause();
+            for predicate in &extra.predicates {
+                where_clause.predicates.push(predicate.clone());
+            }
+        }
+
+        let mut args = Punctuated::new();
+        for param in &self.generics.params {
+            args.push(match param {
+                GenericParam::Lifetime(param) => GenericArgument::Lifetime(param.lifetime.clone()),
+                GenericParam::Type(param) => GenericArgument::Type(Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(param.ident.clone()),
+                })),
+                GenericParam::Const(param) => GenericArgument::Type(Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(param.ident.clone()),
+                })),
+            });
+        }
+        let mut self_ty = Path::from(self.ident.clone());
+        if !args.is_empty() {
+            self_ty.segments[0].arguments =
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Token![<](span),
+                    args,
+                    gt_token: Token![>](span),
+                });
+        }
+
+        ItemImpl {
+            attrs: Vec::new(),
+            defaultness: None,
+            unsafety: None,
+            impl_token: Token![impl](span),
+            generics,
+            trait_: Some((None, trait_path, Token![for](span))),
+            self_ty: Box::new(Type::Path(TypePath {
+                qself: None,
+                path: self_ty,
+            })),
+            brace_token: token::Brace(span),
+            items: Vec::new(),
+        }
+    }
+}
+
 ast_enum_of_structs! {
     /// A suffix of an import tree in a `use` item: `Type as Renamed` or `*`.
     ///
@@ -899,6 +1171,94 @@ ast_struct! {
         pub inputs: Punctuated<FnArg, Token![,]>,
         pub variadic: Option<Variadic>,
         pub output: ReturnType,
+        pub spec: Option<Box<SpecClauses>>,
+    }
+}
+
+ast_struct! {
+    /// The verification clauses of a function signature or a loop: `requires
+    /// x < 10 ensures result > x`.
+    ///
+    /// The fields exist, and are parsed, only if Syn is built with the
+    /// `"verus"` feature. A signature has `requires`, `ensures` and
+    /// `decreases` clauses and a loop has `invariant` and `decreases` clauses.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct SpecClauses {
+        #[cfg(feature = "verus")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+        pub requires: Option<Requires>,
+        #[cfg(feature = "verus")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+        pub ensures: Option<Ensures>,
+        #[cfg(feature = "verus")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+        pub invariant: Option<Invariant>,
+        #[cfg(feature = "verus")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+        pub decreases: Option<Decreases>,
+    }
+}
+
+ast_struct! {
+    /// The qualifiers that may precede the `fn` keyword of a function
+    /// signature: `const async unsafe extern "C"`.
+    ///
+    /// Its [`Parse`] implementation is the one used for parsing a [`Signature`],
+    /// for use by macros whose input mimics function syntax. Each qualifier is
+    /// optional, but they must come in the order above. Qualifiers in a different
+    /// order are rejected with an error that suggests the correct order, and a
+    /// repeated qualifier with an error pointing at the repetition.
+    ///
+    /// [`Parse`]: crate::parse::Parse
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{FnQualifiers, Ident, Token};
+    /// use syn::parse::{Parse, ParseStream, Result};
+    ///
+    /// // Parses `unsafe task name;`.
+    /// struct Task {
+    ///     qualifiers: FnQualifiers,
+    ///     name: Ident,
+    /// }
+    ///
+    /// impl Parse for Task {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let qualifiers: FnQualifiers = input.parse()?;
+    ///         let _task: Ident = input.parse()?;
+    ///         let name: Ident = input.parse()?;
+    ///         input.parse::<Token![;]>()?;
+    ///         Ok(Task { qualifiers, name })
+    ///     }
+    /// }
+    ///
+    /// let task: Task = syn::parse_str("const unsafe task t;").unwrap();
+    /// assert!(task.qualifiers.constness.is_some() && task.qualifiers.unsafety.is_some());
+    ///
+    /// let err = syn::parse_str::<Task>("unsafe const task t;").err().unwrap();
+    /// assert_eq!(err.to_string(), "qualifiers in wrong order, expected `const unsafe`");
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct FnQualifiers {
+        pub constness: Option<Token![const]>,
+        pub asyncness: Option<Token![async]>,
+        pub unsafety: Option<Token![unsafe]>,
+        pub abi: Option<Abi>,
+    }
+}
+
+impl FnQualifiers {
+    /// Whether none of the qualifiers are present.
+    pub fn is_empty(&self) -> bool {
+        self.constness.is_none()
+            && self.asyncness.is_none()
+            && self.unsafety.is_none()
+            && self.abi.is_none()
     }
 }
 
@@ -918,6 +1278,382 @@ impl Signature {
             }
         }
     }
+
+    /// The arguments of the function other than the `self` receiver, if any.
+    pub fn inputs_without_receiver(&self) -> iter::Skip<punctuated::Iter<'_, FnArg>> {
+        let receivers = if self.receiver().is_some() { 1 } else { 0 };
+        self.inputs.iter().skip(receivers)
+    }
+
+    /// Whether this is an `async fn`.
+    pub fn is_async(&self) -> bool {
+        self.asyncness.is_some()
+    }
+
+    /// Whether this is a `const fn`.
+    pub fn is_const(&self) -> bool {
+        self.constness.is_some()
+    }
+
+    /// Whether this is an `unsafe fn`.
+    pub fn is_unsafe(&self) -> bool {
+        self.unsafety.is_some()
+    }
+
+    /// The declared return type, or `()` if the signature has no `->`.
+    ///
+    /// The parentheses of a synthesized `()` take the span of the argument
+    /// list's parentheses.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn output_type_or_unit(&self) -> Type {
+        match &self.output {
+            ReturnType::Type(_, ty) => (**ty).clone(),
+            ReturnType::Default => Type::Tuple(TypeTuple {
+                paren_token: token::Paren {
+                    span: self.paren_token.span,
+                },
+                elems: Punctuated::new(),
+            }),
+        }
+    }
+
+    /// The function pointer type matching this signature, such as
+    /// `unsafe extern "C" fn(&Self, u8) -> u16` for `unsafe extern "C" fn
+    /// f(&self, x: u8) -> u16`.
+    ///
+    /// Argument patterns are dropped and a `self` receiver becomes an argument
+    /// of type `Self`, `&Self`, `&mut Self` or its explicitly written type.
+    /// The lifetime parameters of the signature become a `for<...>` binder on
+    /// the function pointer, without their bounds. Other types are used as
+    /// written, so the signature is expected not to have type parameters or
+    /// `impl Trait` arguments, which a function pointer cannot express, and
+    /// the `const` and `async` keywords are left out.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Signature};
+    ///
+    /// let sig: Signature = parse_quote! {
+    ///     unsafe fn get<'a>(&'a self, (index, _): (usize, bool)) -> &'a u8
+    /// };
+    ///
+    /// let ty = sig.to_bare_fn();
+    /// let expected = quote!(for<'a> unsafe fn(&'a Self, (usize, bool)) -> &'a u8);
+    /// assert_eq!(quote!(#ty).to_string(), expected.to_string());
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn to_bare_fn(&self) -> TypeBareFn {
+        TypeBareFn {
+            lifetimes: self.bound_lifetimes(),
+            unsafety: self.unsafety,
+            abi: self.abi.clone(),
+            fn_token: self.fn_token,
+            paren_token: self.paren_token,
+            inputs: self
+                .input_types()
+                .into_iter()
+                .map(|ty| BareFnArg {
+                    attrs: Vec::new(),
+                    name: None,
+                    ty,
+                })
+                .collect(),
+            variadic: self.variadic.clone(),
+            output: self.output.clone(),
+        }
+    }
+
+    /// A bound of the given `Fn`-like trait matching this signature, such as
+    /// `for<'a> FnMut(&'a str) -> &'a str` for `fn f<'a>(s: &'a str) -> &'a
+    /// str` and `FnMut`.
+    ///
+    /// The arguments and lifetimes are converted as in
+    /// [`to_bare_fn`](Signature::to_bare_fn). The `unsafe` keyword, the ABI
+    /// and C variadic arguments have no counterpart in a trait bound and are
+    /// left out.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Signature};
+    ///
+    /// let sig: Signature = parse_quote!(fn callback(&mut self, event: Event) -> bool);
+    ///
+    /// let bound = sig.to_fn_bound(parse_quote!(FnMut));
+    /// let expected = quote!(FnMut(&mut Self, Event) -> bool);
+    /// assert_eq!(quote!(#bound).to_string(), expected.to_string());
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn to_fn_bound(&self, trait_name: Ident) -> TraitBound {
+        let arguments = ParenthesizedGenericArguments {
+            paren_token: self.paren_token,
+            inputs: self.input_types().into_iter().collect(),
+            output: self.output.clone(),
+        };
+        let mut path = Path::from(trait_name);
+        path.segments[0].arguments = PathArguments::Parenthesized(arguments);
+        TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: self.bound_lifetimes(),
+            path,
+        }
+    }
+
+    #[cfg(feature = "clone-impls")]
+    fn bound_lifetimes(&self) -> Option<BoundLifetimes> {
+        let mut lifetimes = self.generics.lifetimes().peekable();
+        lifetimes.peek()?;
+        let mut bound_lifetimes = BoundLifetimes::default();
+        for def in lifetimes {
+            bound_lifetimes
+                .lifetimes
+                .push(LifetimeDef::new(def.lifetime.clone()));
+        }
+        Some(bound_lifetimes)
+    }
+
+    #[cfg(feature = "clone-impls")]
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|arg| match arg {
+                FnArg::Typed(arg) => (*arg.ty).clone(),
+                FnArg::Receiver(receiver) => {
+                    let self_ty = Type::Path(TypePath {
+                        qself: None,
+                        path: Path::from(Ident::new("Self", receiver.self_token.span)),
+                    });
+                    match &receiver.reference {
+                        Some((and_token, lifetime)) => Type::Reference(TypeReference {
+                            and_token: *and_token,
+                            lifetime: lifetime.clone(),
+                            mutability: receiver.mutability,
+                            elem: Box::new(self_ty),
+                        }),
+                        // A `mut self` receiver is passed by value too.
+                        None => self_ty,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// A copy of this signature with the `async` keyword removed.
+    ///
+    /// The return type is left as written; callers desugaring an `async fn`
+    /// are expected to wrap it in a future type themselves.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn without_asyncness(&self) -> Signature {
+        Signature {
+            asyncness: None,
+            ..self.clone()
+        }
+    }
+
+    /// Wraps the body of a function with this signature into an expression
+    /// that evaluates to the function's return value, such that `return` and
+    /// `?` in the body leave the expression rather than the function.
+    ///
+    /// This is the building block for macros that need to run code after the
+    /// original body on every path out of it, such as timing or tracing the
+    /// result. See also [`Block::append_stmts`].
+    ///
+    /// - A plain function's body becomes an immediately called closure, `(||
+    ///   -> T { ... })()`. The closure does not `move` its captures, so the
+    ///   body can use the function's arguments exactly as before.
+    /// - An `async fn`'s body becomes an awaited async block, `async { let
+    ///   __ret: T = { ... }; __ret }.await`. The binding gives `?` and
+    ///   `return` in the body a known type to convert into.
+    /// - A `const fn` cannot call a closure, so its body is returned as a
+    ///   plain block expression and `return` still leaves the function.
+    ///
+    /// Any `impl Trait` in the return type is replaced with `_` in the
+    /// annotation, because `impl Trait` is not allowed there.
+    ///
+    /// *This method is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, ItemFn};
+    ///
+    /// let item: ItemFn = parse_quote! {
+    ///     fn parse(s: &str) -> Result<impl Display, ParseIntError> {
+    ///         Ok(s.parse::<i32>()?)
+    ///     }
+    /// };
+    /// let body = item.sig.wrap_body(*item.block);
+    ///
+    /// let expected = quote! {
+    ///     (| | -> Result<_, ParseIntError> {
+    ///         Ok(s.parse::<i32>()?)
+    ///     })()
+    /// };
+    /// assert_eq!(quote!(#body).to_string(), expected.to_string());
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn wrap_body(&self, body: Block) -> Expr {
+        let span = Span::call_site();
+        let body = Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block: body,
+        });
+        let mut output = self.output.clone();
+        if let ReturnType::Type(_, ty) = &mut output {
+            erase_impl_trait(ty);
+        }
+
+        if self.constness.is_some() {
+            body
+        } else if self.asyncness.is_some() {
+            let ret = crate::ident::fresh_ident("ret", span);
+            let ty = match output {
+                ReturnType::Type(_, ty) => *ty,
+                ReturnType::Default => self.output_type_or_unit(),
+            };
+            let block = Block {
+                brace_token: token::Brace(span),
+                stmts: vec![
+                    Stmt::Local(Local {
+                        attrs: Vec::new(),
+                        let_token: Token![let](span),
+                        pat: Pat::Type(PatType {
+                            attrs: Vec::new(),
+                            pat: Box::new(ident_pat(ret.clone())),
+                            colon_token: Token![:](span),
+                            ty: Box::new(ty),
+                        }),
+                        init: Some((Token![=](span), Box::new(body))),
+                        semi_token: Token![;](span),
+                    }),
+                    Stmt::Expr(Expr::Path(ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: Path::from(ret),
+                    })),
+                ],
+            };
+            Expr::Await(ExprAwait {
+                attrs: Vec::new(),
+                base: Box::new(Expr::Async(ExprAsync {
+                    attrs: Vec::new(),
+                    async_token: Token![async](span),
+                    capture: None,
+                    block,
+                })),
+                dot_token: Token![.](span),
+                await_token: Token![await](span),
+            })
+        } else {
+            let closure = Expr::Closure(ExprClosure {
+                attrs: Vec::new(),
+                movability: None,
+                asyncness: None,
+                capture: None,
+                or1_token: Token![|](span),
+                inputs: Punctuated::new(),
+                or2_token: Token![|](span),
+                output,
+                body: Box::new(body),
+            });
+            Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Paren(ExprParen {
+                    attrs: Vec::new(),
+                    paren_token: token::Paren(span),
+                    expr: Box::new(closure),
+                })),
+                paren_token: token::Paren(span),
+                args: Punctuated::new(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "clone-impls")]
+pub(crate) fn ident_pat(ident: Ident) -> Pat {
+    Pat::Ident(PatIdent {
+        attrs: Vec::new(),
+        by_ref: None,
+        mutability: None,
+        ident,
+        subpat: None,
+    })
+}
+
+#[cfg(feature = "clone-impls")]
+fn erase_impl_trait(ty: &mut Type) {
+    match ty {
+        Type::ImplTrait(impl_trait) => {
+            let span = match impl_trait.bounds.first() {
+                Some(TypeParamBound::Trait(bound)) => bound.path.segments[0].ident.span(),
+                Some(TypeParamBound::Lifetime(lifetime)) => lifetime.apostrophe,
+                None => impl_trait.impl_token.span,
+            };
+            *ty = Type::Infer(TypeInfer {
+                underscore_token: Token![_](span),
+            });
+        }
+        Type::Array(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Group(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Paren(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Ptr(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Reference(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Slice(ty) => erase_impl_trait(&mut ty.elem),
+        Type::Tuple(ty) => ty.elems.iter_mut().for_each(erase_impl_trait),
+        Type::Path(ty) => {
+            if let Some(qself) = &mut ty.qself {
+                erase_impl_trait(&mut qself.ty);
+            }
+            for segment in &mut ty.path.segments {
+                match &mut segment.arguments {
+                    PathArguments::None => {}
+                    PathArguments::AngleBracketed(bracketed) => {
+                        for arg in &mut bracketed.args {
+                            match arg {
+                                GenericArgument::Type(ty) => erase_impl_trait(ty),
+                                GenericArgument::Binding(binding) => {
+                                    erase_impl_trait(&mut binding.ty);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    PathArguments::Parenthesized(parenthesized) => {
+                        parenthesized.inputs.iter_mut().for_each(erase_impl_trait);
+                        if let ReturnType::Type(_, ty) = &mut parenthesized.output {
+                            erase_impl_trait(ty);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
 }
 
 ast_enum_of_structs! {
@@ -969,6 +1705,7 @@ pub mod parsing {
     use crate::parse::{Parse, ParseBuffer, ParseStream, Result};
     use crate::token::Brace;
     use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenTree};
+    use std::cmp;
     use std::iter::{self, FromIterator};
 
     crate::custom_keyword!(macro_rules);
@@ -1488,22 +2225,125 @@ pub mod parsing {
         ])
     }
 
+    // Qualifiers are accepted in any order here, so that a signature with
+    // misordered qualifiers gets the error from parsing FnQualifiers rather
+    // than failing to be recognized as a signature.
     fn peek_signature(input: ParseStream) -> bool {
         let fork = input.fork();
-        fork.parse::<Option<Token![const]>>().is_ok()
-            && fork.parse::<Option<Token![async]>>().is_ok()
-            && fork.parse::<Option<Token![unsafe]>>().is_ok()
-            && fork.parse::<Option<Abi>>().is_ok()
-            && fork.peek(Token![fn])
+        loop {
+            let parsed = if fork.peek(Token![const]) {
+                fork.parse::<Token![const]>().is_ok()
+            } else if fork.peek(Token![async]) {
+                fork.parse::<Token![async]>().is_ok()
+            } else if fork.peek(Token![unsafe]) {
+                fork.parse::<Token![unsafe]>().is_ok()
+            } else if fork.peek(Token![extern]) {
+                fork.parse::<Abi>().is_ok()
+            } else {
+                return fork.peek(Token![fn]);
+            };
+            if !parsed {
+                return false;
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for FnQualifiers {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let mut qualifiers = FnQualifiers {
+                constness: None,
+                asyncness: None,
+                unsafety: None,
+                abi: None,
+            };
+            // The first qualifier that comes after one which should follow it.
+            let mut misplaced = None;
+            let mut last = 0;
+            loop {
+                let (position, span) = if input.peek(Token![const]) {
+                    let token: Token![const] = input.parse()?;
+                    let span = token.span;
+                    check_repeated(&qualifiers.constness, span, "const")?;
+                    qualifiers.constness = Some(token);
+                    (1, span)
+                } else if input.peek(Token![async]) {
+                    let token: Token![async] = input.parse()?;
+                    let span = token.span;
+                    check_repeated(&qualifiers.asyncness, span, "async")?;
+                    qualifiers.asyncness = Some(token);
+                    (2, span)
+                } else if input.peek(Token![unsafe]) {
+                    let token: Token![unsafe] = input.parse()?;
+                    let span = token.span;
+                    check_repeated(&qualifiers.unsafety, span, "unsafe")?;
+                    qualifiers.unsafety = Some(token);
+                    (3, span)
+                } else if input.peek(Token![extern]) {
+                    let abi: Abi = input.parse()?;
+                    let span = abi.extern_token.span;
+                    check_repeated(&qualifiers.abi, span, "extern")?;
+                    qualifiers.abi = Some(abi);
+                    (4, span)
+                } else {
+                    break;
+                };
+                if position < last && misplaced.is_none() {
+                    misplaced = Some(span);
+                }
+                last = cmp::max(last, position);
+            }
+
+            match misplaced {
+                None => Ok(qualifiers),
+                Some(span) => {
+                    let mut expected = Vec::new();
+                    if qualifiers.constness.is_some() {
+                        expected.push("const".to_owned());
+                    }
+                    if qualifiers.asyncness.is_some() {
+                        expected.push("async".to_owned());
+                    }
+                    if qualifiers.unsafety.is_some() {
+                        expected.push("unsafe".to_owned());
+                    }
+                    if let Some(abi) = &qualifiers.abi {
+                        expected.push(match &abi.name {
+                            Some(name) => format!("extern {}", name.token()),
+                            None => "extern".to_owned(),
+                        });
+                    }
+                    Err(Error::new(
+                        span,
+                        format!(
+                            "qualifiers in wrong order, expected `{}`",
+                            expected.join(" "),
+                        ),
+                    ))
+                }
+            }
+        }
+    }
+
+    fn check_repeated<T>(previous: &Option<T>, span: Span, qualifier: &str) -> Result<()> {
+        match previous {
+            Some(_) => Err(Error::new(
+                span,
+                format!("duplicate `{}` qualifier", qualifier),
+            )),
+            None => Ok(()),
+        }
     }
 
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for Signature {
         fn parse(input: ParseStream) -> Result<Self> {
-            let constness: Option<Token![const]> = input.parse()?;
-            let asyncness: Option<Token![async]> = input.parse()?;
-            let unsafety: Option<Token![unsafe]> = input.parse()?;
-            let abi: Option<Abi> = input.parse()?;
+            let FnQualifiers {
+                constness,
+                asyncness,
+                unsafety,
+                abi,
+            } = input.parse()?;
             let fn_token: Token![fn] = input.parse()?;
             let ident: Ident = input.parse()?;
             let mut generics: Generics = input.parse()?;
@@ -1515,6 +2355,10 @@ pub mod parsing {
 
             let output: ReturnType = input.parse()?;
             generics.where_clause = input.parse()?;
+            #[cfg(feature = "verus")]
+            let spec = spec::parsing::signature_clauses(input)?;
+            #[cfg(not(feature = "verus"))]
+            let spec = None;
 
             Ok(Signature {
                 constness,
@@ -1528,6 +2372,7 @@ pub mod parsing {
                 inputs,
                 variadic,
                 output,
+                spec,
             })
         }
     }
@@ -2741,15 +3586,6 @@ pub mod parsing {
         }
     }
 
-    impl Visibility {
-        fn is_inherited(&self) -> bool {
-            match *self {
-                Visibility::Inherited => true,
-                _ => false,
-            }
-        }
-    }
-
     impl MacroDelimiter {
         fn is_brace(&self) -> bool {
             match *self {
@@ -3271,6 +4107,16 @@ mod printing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for FnQualifiers {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.constness.to_tokens(tokens);
+            self.asyncness.to_tokens(tokens);
+            self.unsafety.to_tokens(tokens);
+            self.abi.to_tokens(tokens);
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for Signature {
         fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -3303,6 +4149,21 @@ mod printing {
             });
             self.output.to_tokens(tokens);
             self.generics.where_clause.to_tokens(tokens);
+            self.spec.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for SpecClauses {
+        #[cfg_attr(not(feature = "verus"), allow(unused_variables))]
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            #[cfg(feature = "verus")]
+            {
+                self.requires.to_tokens(tokens);
+                self.ensures.to_tokens(tokens);
+                self.invariant.to_tokens(tokens);
+                self.decreases.to_tokens(tokens);
+            }
         }
     }
 
@@ -0,0 +1,350 @@
+//! Syntactic analysis of loops and their labels.
+//!
+//! Macros that wrap the body of a user's loop in code of their own, for
+//! example to retry or time each iteration, change what an unlabeled `break`
+//! or `continue` in the body refers to as soon as the wrapper contains a loop
+//! of its own. The functions in this module find the loops in a block with
+//! [`loops`], pick a label that does not clash with any label or lifetime of
+//! the user's code with [`fresh_label`], and point `break` and `continue`
+//! expressions at that label with [`retarget_breaks`].
+//!
+//! The analysis is purely syntactic. Closures, async blocks and nested items
+//! are not looked into, as a `break` or `continue` inside of them cannot
+//! refer to a loop outside of them. Neither are the bodies of macro
+//! invocations, other than for the labels they contain.
+//!
+//! *This module is available only if Syn is built with the `"full"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, Block};
+//!
+//! let mut body: Block = parse_quote!({
+//!     if item.skip {
+//!         continue;
+//!     }
+//!     for part in item.parts() {
+//!         if part.is_empty() {
+//!             break;
+//!         }
+//!     }
+//!     process(item);
+//! });
+//!
+//! // The macro times each iteration in a loop of its own, which would
+//! // capture the user's `continue`.
+//! let label = syn::label::fresh_label(&body, "item");
+//! let retargeted = syn::label::retarget_breaks(&mut body, None, &label);
+//! assert_eq!(retargeted, 1);
+//!
+//! let instrumented = quote! {
+//!     #label: for item in items {
+//!         let start = Instant::now();
+//!         loop {
+//!             #body
+//!             break;
+//!         }
+//!         record(start.elapsed());
+//!     }
+//! };
+//! # let _ = instrumented;
+//! ```
+
+#[cfg(any(feature = "visit", feature = "visit-mut"))]
+use super::*;
+#[cfg(feature = "visit")]
+use crate::visit::{self, Visit};
+#[cfg(feature = "visit-mut")]
+use crate::visit_mut::{self, VisitMut};
+#[cfg(feature = "visit")]
+use proc_macro2::{Span, TokenStream, TokenTree};
+#[cfg(feature = "visit")]
+use std::collections::HashSet;
+
+/// A loop found by [`loops`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub struct Loop<'a> {
+    /// The `for`, `while` or `loop` expression.
+    pub expr: &'a Expr,
+    /// The label of the loop, like `'outer` in `'outer: loop {}`.
+    pub label: Option<&'a Lifetime>,
+    /// The number of loops of the block that the loop is nested in, which is
+    /// 0 for a loop that an unlabeled `break` directly in the block would
+    /// exit.
+    pub depth: usize,
+}
+
+/// Returns the loops within a block, in source order.
+///
+/// See the [module documentation](self) for which parts of the block are
+/// looked into.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, Block};
+///
+/// let body: Block = parse_quote!({
+///     'rows: for row in rows {
+///         while !row.done() {
+///             row.step();
+///         }
+///     }
+/// });
+///
+/// let loops = syn::label::loops(&body);
+/// assert_eq!(loops.len(), 2);
+/// assert_eq!(loops[0].label.unwrap().ident, "rows");
+/// assert_eq!(loops[1].depth, 1);
+/// ```
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub fn loops<'a>(block: &'a Block) -> Vec<Loop<'a>> {
+    let mut collector = LoopCollector {
+        loops: Vec::new(),
+        depth: 0,
+    };
+    collector.visit_block(block);
+    collector.loops
+}
+
+/// Returns a loop label for use around a block that is not the name of any
+/// label or lifetime within the block.
+///
+/// The label is `base` itself if that is free, and otherwise `base_1`,
+/// `base_2`, and so on, with a call site span. Labels inside of the bodies
+/// of macro invocations are taken into account too.
+///
+/// # Panics
+///
+/// Panics if `base` is not a valid identifier, such as `"retry"`, to use as
+/// the name of a label.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub fn fresh_label(block: &Block, base: &str) -> Lifetime {
+    let mut names = LabelCollector(HashSet::new());
+    names.visit_block(block);
+    let mut name = base.to_owned();
+    let mut n = 1;
+    while names.0.contains(&name) {
+        name = format!("{}_{}", base, n);
+        n += 1;
+    }
+    Lifetime::new(&format!("'{}", name), Span::call_site())
+}
+
+/// Makes the `break` and `continue` expressions of a block that refer to a
+/// given loop refer to the label `to` instead.
+///
+/// With `from` of `None`, these are the unlabeled `break` and `continue`
+/// expressions that exit or restart the loop around the block, leaving out
+/// those of loops nested in the block. With `from` of `Some` label, these
+/// are the expressions that name the label, leaving out those in a nested
+/// loop or labeled block that shadows it.
+///
+/// Returns the number of expressions changed. The labels of the loops and
+/// blocks themselves are left alone.
+///
+/// See the [module documentation](self) for which parts of the block are
+/// looked into.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+#[cfg(feature = "visit-mut")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit-mut"))))]
+pub fn retarget_breaks(block: &mut Block, from: Option<&Lifetime>, to: &Lifetime) -> usize {
+    let mut retargeter = BreakRetargeter {
+        from: from.map(|label| label.ident.to_string()),
+        to,
+        count: 0,
+    };
+    retargeter.visit_block_mut(block);
+    retargeter.count
+}
+
+#[cfg(feature = "visit")]
+struct LoopCollector<'ast> {
+    loops: Vec<Loop<'ast>>,
+    depth: usize,
+}
+
+#[cfg(feature = "visit")]
+impl<'ast> LoopCollector<'ast> {
+    fn push(&mut self, expr: &'ast Expr, label: &'ast Option<Label>) {
+        self.loops.push(Loop {
+            expr,
+            label: label.as_ref().map(|label| &label.name),
+            depth: self.depth,
+        });
+    }
+
+    fn visit_body(&mut self, body: &'ast Block) {
+        self.depth += 1;
+        self.visit_block(body);
+        self.depth -= 1;
+    }
+}
+
+#[cfg(feature = "visit")]
+impl<'ast> Visit<'ast> for LoopCollector<'ast> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::ForLoop(e) => {
+                self.push(expr, &e.label);
+                self.visit_expr(&e.expr);
+                self.visit_body(&e.body);
+            }
+            Expr::While(e) => {
+                self.push(expr, &e.label);
+                self.visit_expr(&e.cond);
+                self.visit_body(&e.body);
+            }
+            Expr::Loop(e) => {
+                self.push(expr, &e.label);
+                self.visit_body(&e.body);
+            }
+            Expr::Async(_) | Expr::Closure(_) => {}
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_item(&mut self, _item: &'ast Item) {}
+
+    fn visit_macro(&mut self, _mac: &'ast Macro) {}
+}
+
+#[cfg(feature = "visit")]
+struct LabelCollector(HashSet<String>);
+
+#[cfg(feature = "visit")]
+impl<'ast> Visit<'ast> for LabelCollector {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.0.insert(lifetime.ident.to_string());
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        visit::visit_macro(self, mac);
+        collect_token_labels(mac.tokens.clone(), &mut self.0);
+    }
+}
+
+// A label or lifetime in a token stream is a `'` followed by an identifier.
+#[cfg(feature = "visit")]
+fn collect_token_labels(tokens: TokenStream, names: &mut HashSet<String>) {
+    let mut after_quote = false;
+    for tt in tokens {
+        match &tt {
+            TokenTree::Ident(ident) if after_quote => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_token_labels(group.stream(), names),
+            _ => {}
+        }
+        after_quote = match &tt {
+            TokenTree::Punct(punct) => punct.as_char() == '\'',
+            _ => false,
+        };
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+struct BreakRetargeter<'a> {
+    from: Option<String>,
+    to: &'a Lifetime,
+    count: usize,
+}
+
+#[cfg(feature = "visit-mut")]
+impl<'a> BreakRetargeter<'a> {
+    fn retarget(&mut self, label: &mut Option<Lifetime>) {
+        let matches = match (label.as_ref(), &self.from) {
+            (None, None) => true,
+            (Some(label), Some(from)) => label.ident == from,
+            _ => false,
+        };
+        if matches {
+            *label = Some(self.to.clone());
+            self.count += 1;
+        }
+    }
+
+    // Whether the `break` and `continue` expressions in the body of a nested
+    // loop can still refer to the loop being retargeted.
+    fn enters_loop(&self, label: &Option<Label>) -> bool {
+        match &self.from {
+            None => false,
+            Some(from) => !is_label(label, from),
+        }
+    }
+
+    fn enters_block(&self, label: &Option<Label>) -> bool {
+        match &self.from {
+            None => true,
+            Some(from) => !is_label(label, from),
+        }
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+fn is_label(label: &Option<Label>, name: &str) -> bool {
+    match label {
+        Some(label) => label.name.ident == name,
+        None => false,
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+impl<'a> VisitMut for BreakRetargeter<'a> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Break(e) => {
+                self.retarget(&mut e.label);
+                if let Some(value) = &mut e.expr {
+                    self.visit_expr_mut(value);
+                }
+            }
+            Expr::Continue(e) => self.retarget(&mut e.label),
+            Expr::ForLoop(e) => {
+                self.visit_expr_mut(&mut e.expr);
+                if self.enters_loop(&e.label) {
+                    self.visit_block_mut(&mut e.body);
+                }
+            }
+            Expr::While(e) => {
+                self.visit_expr_mut(&mut e.cond);
+                if self.enters_loop(&e.label) {
+                    self.visit_block_mut(&mut e.body);
+                }
+            }
+            Expr::Loop(e) => {
+                if self.enters_loop(&e.label) {
+                    self.visit_block_mut(&mut e.body);
+                }
+            }
+            Expr::Block(e) => {
+                if self.enters_block(&e.label) {
+                    self.visit_block_mut(&mut e.block);
+                }
+            }
+            Expr::Async(_) | Expr::Closure(_) => {}
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {}
+
+    fn visit_macro_mut(&mut self, _mac: &mut Macro) {}
+}
@@ -0,0 +1,947 @@
+//! Tokenizing of Rust source text without going through a `TokenStream`'s
+//! string parser.
+//!
+//! Standalone tools that parse source files, such as linters or code
+//! generators, normally hand the text to [`parse_str`](crate::parse_str) or
+//! [`parse_file`](crate::parse_file), which tokenize it with
+//! `proc_macro2::TokenStream::from_str` and then copy the tokens into Syn's
+//! internal [`TokenBuffer`]. The lexer in this module fills the buffer
+//! directly from the source text and remembers the byte range of every
+//! token, which is available through [`Source::byte_range`] even when
+//! `proc_macro2`'s `span-locations` feature is off.
+//!
+//! The spans of the tokens produced by this lexer are all
+//! [`Span::call_site()`], so the byte ranges are the only way to map a token
+//! back to the source text. Errors produced by the lexer, and the error for
+//! unexpected trailing tokens, mention the byte offset at which they occur.
+//!
+//! Tools that are not procedural macros, such as syntax highlighters or diff
+//! tools, can exchange tokens with Syn as a flat list of [`FlatToken`]s,
+//! each a kind, a text and a byte range, instead of through `proc_macro2`'s
+//! types. [`Source::tokens`] lists the tokens of source text,
+//! [`Source::from_tokens`] makes a source to parse out of such a list, and
+//! [`print_tokens`] lists the tokens of a syntax tree as it is printed.
+//!
+//! *This module is available only if Syn is built with the `"lexer"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::lexer::Source;
+//! use syn::parse::ParseStream;
+//! use syn::{Ident, Result, Token};
+//!
+//! let text = "let answer = 42;";
+//! let source = Source::new(text)?;
+//!
+//! // Find the byte range of the identifier after `let`.
+//! let range = source.parse_with(|input: ParseStream| {
+//!     input.parse::<Token![let]>()?;
+//!     let range = source.byte_range(input.cursor()).unwrap();
+//!     input.parse::<Ident>()?;
+//!     input.parse::<proc_macro2::TokenStream>()?;
+//!     Ok(range)
+//! })?;
+//! assert_eq!(&text[range], "answer");
+//! # Ok::<(), syn::Error>(())
+//! ```
+
+use crate::buffer::{self, Cursor, TokenBuffer};
+use crate::error::{Error, Result};
+use crate::parse::{self, Parse, ParseStream};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Source text that has been split into tokens, ready to be parsed any number
+/// of times.
+///
+/// *This type is available only if Syn is built with the `"lexer"` feature.*
+pub struct Source {
+    buffer: TokenBuffer,
+    // Byte range of the token at each entry of the buffer, keyed by the
+    // entry's address.
+    ranges: HashMap<usize, Range<usize>>,
+}
+
+impl Source {
+    /// Splits the source text into tokens.
+    ///
+    /// Returns an error if the text contains a character that cannot start a
+    /// token, an unterminated literal or block comment, or unbalanced
+    /// delimiters.
+    pub fn new(text: &str) -> Result<Self> {
+        Source::from_offset(text, 0)
+    }
+
+    fn from_offset(text: &str, offset: usize) -> Result<Self> {
+        let (tokens, located) = Lexer { text, pos: offset }.tokenize()?;
+        Ok(Source::from_located(tokens, &located))
+    }
+
+    fn from_located(tokens: TokenStream, located: &[Located]) -> Self {
+        let buffer = TokenBuffer::new2(tokens);
+        let mut ranges = HashMap::new();
+        record_ranges(buffer.begin(), located, &mut ranges);
+        Source { buffer, ranges }
+    }
+
+    /// Builds a source out of a flat list of tokens, such as one produced by
+    /// the lexer of another tool.
+    ///
+    /// The byte ranges of the tokens are kept for [`Source::byte_range`], and
+    /// a punctuation character is joint with a following one if the ranges
+    /// of the two are adjacent, as in `+=`. The ranges need not refer to any
+    /// text, but must be in order for that to work.
+    ///
+    /// Returns an error if a token's text is not a single token of its kind,
+    /// or the delimiters are unbalanced.
+    pub fn from_tokens(tokens: &[FlatToken]) -> Result<Self> {
+        let mut stack: Vec<(Delimiter, usize, Level)> = Vec::new();
+        let mut level = Level::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let range = token.byte_range.clone();
+            match token.kind {
+                TokenKind::Open => {
+                    let delimiter = match token.text.as_str() {
+                        "(" => Delimiter::Parenthesis,
+                        "[" => Delimiter::Bracket,
+                        "{" => Delimiter::Brace,
+                        _ => return Err(error_at(range.start, "invalid opening delimiter")),
+                    };
+                    stack.push((delimiter, range.start, level));
+                    level = Level::new();
+                }
+                TokenKind::Close => {
+                    let (delimiter, open, mut outer) = match stack.pop() {
+                        Some(frame) => frame,
+                        None => return Err(error_at(range.start, "unexpected closing delimiter")),
+                    };
+                    if token.text != delimiter_text(delimiter).1 {
+                        return Err(error_at(range.start, "mismatched closing delimiter"));
+                    }
+                    let stream: TokenStream = level.tokens.into_iter().collect();
+                    outer
+                        .tokens
+                        .push(TokenTree::Group(Group::new(delimiter, stream)));
+                    outer.located.push(Located {
+                        range: open..range.end,
+                        group: Some((delimiter, level.located)),
+                    });
+                    level = outer;
+                }
+                TokenKind::Punct => {
+                    let mut chars = token.text.chars();
+                    let ch = match (chars.next(), chars.next()) {
+                        (Some(ch), None) if is_punct(ch) || ch == '\'' => ch,
+                        _ => return Err(error_at(range.start, "invalid punctuation")),
+                    };
+                    let spacing = match tokens.get(i + 1) {
+                        Some(next)
+                            if next.byte_range.start == range.end
+                                && (ch == '\'' || next.kind == TokenKind::Punct) =>
+                        {
+                            Spacing::Joint
+                        }
+                        _ => Spacing::Alone,
+                    };
+                    level.push(TokenTree::Punct(Punct::new(ch, spacing)), range);
+                }
+                TokenKind::Ident | TokenKind::Literal => {
+                    let lexer = Lexer {
+                        text: &token.text,
+                        pos: 0,
+                    };
+                    let mut trees = match lexer.tokenize() {
+                        Ok((stream, _located)) => stream.into_iter(),
+                        Err(_) => return Err(error_at(range.start, "invalid token")),
+                    };
+                    match (trees.next(), trees.next()) {
+                        (Some(TokenTree::Ident(ident)), None) if token.kind == TokenKind::Ident => {
+                            level.push(TokenTree::Ident(ident), range);
+                        }
+                        (Some(TokenTree::Literal(lit)), None)
+                            if token.kind == TokenKind::Literal =>
+                        {
+                            level.push(TokenTree::Literal(lit), range);
+                        }
+                        _ => return Err(error_at(range.start, "invalid token")),
+                    }
+                }
+            }
+        }
+        if let Some((_delimiter, open, _level)) = stack.last() {
+            return Err(error_at(*open, "unclosed delimiter"));
+        }
+        let stream: TokenStream = level.tokens.into_iter().collect();
+        Ok(Source::from_located(stream, &level.located))
+    }
+
+    /// Creates a cursor referencing the first token of the source.
+    pub fn begin(&self) -> Cursor<'_> {
+        self.buffer.begin()
+    }
+
+    /// Returns the byte range in the source text of the token that the
+    /// cursor points to.
+    ///
+    /// The cursor may come from [`Source::begin`] or from the
+    /// [`ParseStream::cursor`] of a parser run by [`Source::parse_with`].
+    /// Returns `None` if the cursor is at the end of its stream or belongs to
+    /// a different buffer, such as one created while parsing the arguments
+    /// of an attribute.
+    ///
+    /// For a group, the range extends from the opening to the closing
+    /// delimiter. A doc comment is turned into the tokens of the equivalent
+    /// `#[doc = "..."]` attribute, all of which have the range of the
+    /// comment. A lifetime consists of an apostrophe followed by an
+    /// identifier, each with its own range.
+    pub fn byte_range(&self, cursor: Cursor) -> Option<Range<usize>> {
+        self.ranges.get(&buffer::entry_id(cursor)).cloned()
+    }
+
+    /// Returns the tokens of the source as a flat list, with the delimiters
+    /// of groups as tokens of their own.
+    ///
+    /// The tokens of a doc comment, including the brackets of its attribute,
+    /// all have the range of the comment, as for [`Source::byte_range`].
+    pub fn tokens(&self) -> Vec<FlatToken> {
+        let mut tokens = Vec::new();
+        self.flatten(self.buffer.begin(), &mut tokens);
+        tokens
+    }
+
+    fn flatten(&self, mut cursor: Cursor, tokens: &mut Vec<FlatToken>) {
+        while let Some((tt, next)) = cursor.token_tree() {
+            let range = self.byte_range(cursor).unwrap();
+            match tt {
+                TokenTree::Group(group) => {
+                    let delimiter = group.delimiter();
+                    // Other than for a doc comment, the group's range is
+                    // never that of the token before it.
+                    let doc = tokens.last().map_or(false, |last| last.byte_range == range);
+                    let (open, close) = if doc {
+                        (range.clone(), range)
+                    } else {
+                        (range.start..range.start + 1, range.end - 1..range.end)
+                    };
+                    let (open_text, close_text) = delimiter_text(delimiter);
+                    tokens.push(FlatToken::new(TokenKind::Open, open_text, open));
+                    let (inside, _span, _after) = cursor.group(delimiter).unwrap();
+                    self.flatten(inside, tokens);
+                    tokens.push(FlatToken::new(TokenKind::Close, close_text, close));
+                }
+                TokenTree::Ident(ident) => {
+                    tokens.push(FlatToken::new(TokenKind::Ident, &ident.to_string(), range));
+                }
+                TokenTree::Punct(punct) => {
+                    let text = punct.as_char().to_string();
+                    tokens.push(FlatToken::new(TokenKind::Punct, &text, range));
+                }
+                TokenTree::Literal(lit) => {
+                    tokens.push(FlatToken::new(TokenKind::Literal, &lit.to_string(), range));
+                }
+            }
+            cursor = next;
+        }
+    }
+
+    /// Parses the source into a syntax tree node of type `T`.
+    pub fn parse<T: Parse>(&self) -> Result<T> {
+        self.parse_with(T::parse)
+    }
+
+    /// Parses the source with a parser function, which may look up the byte
+    /// range of the tokens it consumes with [`Source::byte_range`].
+    ///
+    /// This function checks that the source is fully parsed. If there are any
+    /// unparsed tokens at the end, an error is returned.
+    pub fn parse_with<F, T>(&self, parser: F) -> Result<T>
+    where
+        F: FnOnce(ParseStream) -> Result<T>,
+    {
+        let unexpected = parse::UnexpectedSlots::default();
+        let state = parse::tokens_to_parse_buffer(&self.buffer, &unexpected);
+        let node = parser(&state)?;
+        state.check_unexpected()?;
+        let rest = state.cursor();
+        if rest.eof() {
+            return Ok(node);
+        }
+        let message = match self.byte_range(rest) {
+            Some(range) => format!("unexpected token at byte {}", range.start),
+            None => "unexpected token".to_owned(),
+        };
+        Err(Error::new(rest.span(), message))
+    }
+}
+
+/// The kind of a [`FlatToken`].
+///
+/// *This type is available only if Syn is built with the `"lexer"` feature.*
+pub enum TokenKind {
+    /// An identifier or keyword, such as `x`, `fn` or `r#type`.
+    Ident,
+    /// A single punctuation character, such as `+` or the `'` of a lifetime.
+    /// An operator of several characters, like `+=`, is a token per
+    /// character.
+    Punct,
+    /// A literal, such as `1u8`, `'a'` or `"text"`.
+    Literal,
+    /// An opening delimiter `(`, `[` or `{`.
+    Open,
+    /// A closing delimiter `)`, `]` or `}`.
+    Close,
+}
+
+impl Copy for TokenKind {}
+
+impl Clone for TokenKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for TokenKind {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl Eq for TokenKind {}
+
+impl Debug for TokenKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            TokenKind::Ident => "Ident",
+            TokenKind::Punct => "Punct",
+            TokenKind::Literal => "Literal",
+            TokenKind::Open => "Open",
+            TokenKind::Close => "Close",
+        })
+    }
+}
+
+/// A token in a flat list of tokens, for tools such as syntax highlighters
+/// that work on tokens and their positions rather than on token trees.
+///
+/// A list of these is produced from source text by [`Source::tokens`], or
+/// from a syntax tree by [`print_tokens`], and turned back into something
+/// that can be parsed by [`Source::from_tokens`].
+///
+/// *This type is available only if Syn is built with the `"lexer"` feature.*
+pub struct FlatToken {
+    /// The kind of the token.
+    pub kind: TokenKind,
+    /// The text of the token as it is written in the source.
+    pub text: String,
+    /// The byte range of the token in the source text.
+    pub byte_range: Range<usize>,
+}
+
+impl FlatToken {
+    fn new(kind: TokenKind, text: &str, byte_range: Range<usize>) -> Self {
+        FlatToken {
+            kind,
+            text: text.to_owned(),
+            byte_range,
+        }
+    }
+}
+
+impl Clone for FlatToken {
+    fn clone(&self) -> Self {
+        FlatToken {
+            kind: self.kind,
+            text: self.text.clone(),
+            byte_range: self.byte_range.clone(),
+        }
+    }
+}
+
+impl PartialEq for FlatToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.text == other.text && self.byte_range == other.byte_range
+    }
+}
+
+impl Eq for FlatToken {}
+
+impl Debug for FlatToken {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("FlatToken")
+            .field("kind", &self.kind)
+            .field("text", &self.text)
+            .field("byte_range", &self.byte_range)
+            .finish()
+    }
+}
+
+/// Prints a syntax tree as source text, returning the text together with
+/// its tokens as a flat list whose byte ranges refer to the text.
+///
+/// The tokens are separated by a single space, other than a punctuation
+/// character that is joint with the token after it. Invisible groups are
+/// printed as their contents.
+///
+/// *This function is available only if Syn is built with the `"lexer"` and
+/// `"printing"` features.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub fn print_tokens<T: ?Sized + ToTokens>(node: &T) -> (String, Vec<FlatToken>) {
+    let mut printer = Printer {
+        text: String::new(),
+        tokens: Vec::new(),
+        joint: false,
+    };
+    printer.print(node.to_token_stream());
+    (printer.text, printer.tokens)
+}
+
+#[cfg(feature = "printing")]
+struct Printer {
+    text: String,
+    tokens: Vec<FlatToken>,
+    // Whether the last token was joint with the next one.
+    joint: bool,
+}
+
+#[cfg(feature = "printing")]
+impl Printer {
+    fn print(&mut self, tokens: TokenStream) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(group) => {
+                    let delimiter = group.delimiter();
+                    if delimiter == Delimiter::None {
+                        self.print(group.stream());
+                        continue;
+                    }
+                    let (open, close) = delimiter_text(delimiter);
+                    self.push(TokenKind::Open, open);
+                    self.print(group.stream());
+                    self.push(TokenKind::Close, close);
+                }
+                TokenTree::Ident(ident) => self.push(TokenKind::Ident, &ident.to_string()),
+                TokenTree::Punct(punct) => {
+                    self.push(TokenKind::Punct, &punct.as_char().to_string());
+                    self.joint = punct.spacing() == Spacing::Joint;
+                }
+                TokenTree::Literal(lit) => self.push(TokenKind::Literal, &lit.to_string()),
+            }
+        }
+    }
+
+    fn push(&mut self, kind: TokenKind, text: &str) {
+        if !self.text.is_empty() && !self.joint {
+            self.text.push(' ');
+        }
+        let start = self.text.len();
+        self.text.push_str(text);
+        let range = start..self.text.len();
+        self.tokens.push(FlatToken::new(kind, text, range));
+        self.joint = false;
+    }
+}
+
+fn delimiter_text(delimiter: Delimiter) -> (&'static str, &'static str) {
+    match delimiter {
+        Delimiter::Parenthesis => ("(", ")"),
+        Delimiter::Bracket => ("[", "]"),
+        Delimiter::Brace => ("{", "}"),
+        Delimiter::None => ("", ""),
+    }
+}
+
+fn error_at(offset: usize, message: &str) -> Error {
+    Error::new(Span::call_site(), format!("{} at byte {}", message, offset))
+}
+
+/// Splits source text into a `TokenStream`.
+///
+/// The result is the same as that of `TokenStream::from_str`, except that
+/// all spans are [`Span::call_site()`].
+///
+/// *This function is available only if Syn is built with the `"lexer"`
+/// feature.*
+pub fn tokenize(text: &str) -> Result<TokenStream> {
+    let (tokens, _located) = Lexer { text, pos: 0 }.tokenize()?;
+    Ok(tokens)
+}
+
+/// Parses a string of Rust code into the chosen syntax tree node, like
+/// [`syn::parse_str`](crate::parse_str) but using the lexer of this module.
+///
+/// *This function is available only if Syn is built with the `"lexer"`
+/// feature.*
+pub fn parse_str<T: Parse>(text: &str) -> Result<T> {
+    Source::new(text)?.parse()
+}
+
+/// Parses the content of a Rust source file, like
+/// [`syn::parse_file`](crate::parse_file) but using the lexer of this
+/// module.
+///
+/// *This function is available only if Syn is built with the `"lexer"` and
+/// `"full"` features.*
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub fn parse_file(content: &str) -> Result<crate::File> {
+    let mut offset = 0;
+    const BOM: &str = "\u{feff}";
+    if content.starts_with(BOM) {
+        offset = BOM.len();
+    }
+
+    let mut shebang = None;
+    let rest = &content[offset..];
+    if rest.starts_with("#!") && !crate::whitespace::skip(&rest[2..]).starts_with('[') {
+        let end = rest.find('\n').unwrap_or(rest.len());
+        shebang = Some(rest[..end].to_owned());
+        offset += end;
+    }
+
+    let mut file: crate::File = Source::from_offset(content, offset)?.parse()?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+// The location of a token tree in the source text.
+struct Located {
+    range: Range<usize>,
+    // For a group, its delimiter and the locations of its contents.
+    group: Option<(Delimiter, Vec<Located>)>,
+}
+
+fn record_ranges(
+    mut cursor: Cursor,
+    located: &[Located],
+    ranges: &mut HashMap<usize, Range<usize>>,
+) {
+    for token in located {
+        ranges.insert(buffer::entry_id(cursor), token.range.clone());
+        cursor = match &token.group {
+            Some((delimiter, contents)) => {
+                let (inside, _span, next) = cursor.group(*delimiter).unwrap();
+                record_ranges(inside, contents, ranges);
+                next
+            }
+            None => cursor.token_tree().unwrap().1,
+        };
+    }
+}
+
+// Tokens of one level of delimiters, together with their locations.
+struct Level {
+    tokens: Vec<TokenTree>,
+    located: Vec<Located>,
+}
+
+impl Level {
+    fn new() -> Self {
+        Level {
+            tokens: Vec::new(),
+            located: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, token: TokenTree, range: Range<usize>) {
+        self.tokens.push(token);
+        self.located.push(Located { range, group: None });
+    }
+}
+
+struct Lexer<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn error(&self, offset: usize, message: &str) -> Error {
+        error_at(offset, message)
+    }
+
+    fn tokenize(mut self) -> Result<(TokenStream, Vec<Located>)> {
+        let mut stack: Vec<(Delimiter, usize, Level)> = Vec::new();
+        let mut level = Level::new();
+        loop {
+            self.skip_trivia(&mut level)?;
+            let start = self.pos;
+            let ch = match self.peek() {
+                Some(ch) => ch,
+                None => break,
+            };
+            let open = match ch {
+                '(' => Some(Delimiter::Parenthesis),
+                '[' => Some(Delimiter::Bracket),
+                '{' => Some(Delimiter::Brace),
+                _ => None,
+            };
+            if let Some(delimiter) = open {
+                self.bump();
+                stack.push((delimiter, start, level));
+                level = Level::new();
+                continue;
+            }
+            let close = match ch {
+                ')' => Some(Delimiter::Parenthesis),
+                ']' => Some(Delimiter::Bracket),
+                '}' => Some(Delimiter::Brace),
+                _ => None,
+            };
+            if let Some(delimiter) = close {
+                self.bump();
+                let (open_delimiter, open, mut outer) = match stack.pop() {
+                    Some(frame) => frame,
+                    None => return Err(self.error(start, "unexpected closing delimiter")),
+                };
+                if open_delimiter != delimiter {
+                    return Err(self.error(start, "mismatched closing delimiter"));
+                }
+                let stream: TokenStream = level.tokens.into_iter().collect();
+                outer
+                    .tokens
+                    .push(TokenTree::Group(Group::new(delimiter, stream)));
+                outer.located.push(Located {
+                    range: open..self.pos,
+                    group: Some((delimiter, level.located)),
+                });
+                level = outer;
+                continue;
+            }
+            self.token(&mut level)?;
+        }
+        if let Some((_delimiter, open, _level)) = stack.last() {
+            return Err(self.error(*open, "unclosed delimiter"));
+        }
+        Ok((level.tokens.into_iter().collect(), level.located))
+    }
+
+    // Skips whitespace and comments, turning doc comments into attributes.
+    fn skip_trivia(&mut self, level: &mut Level) -> Result<()> {
+        loop {
+            let start = self.pos;
+            let rest = self.rest();
+            if rest.starts_with("//") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                let comment = &rest[..end];
+                self.pos += end;
+                let inner = comment.starts_with("//!");
+                let outer = comment.starts_with("///") && !comment.starts_with("////");
+                if inner || outer {
+                    let text = comment[3..].trim_end_matches('\r');
+                    self.doc_comment(level, text, inner, start..self.pos);
+                }
+            } else if rest.starts_with("/*") {
+                let end = self.block_comment_end(start)?;
+                let comment = &self.text[start..end];
+                self.pos = end;
+                let inner = comment.starts_with("/*!");
+                let outer =
+                    comment.starts_with("/**") && !comment.starts_with("/***") && comment != "/**/";
+                if inner || outer {
+                    let text = &comment[3..comment.len() - 2];
+                    self.doc_comment(level, text, inner, start..end);
+                }
+            } else {
+                match self.peek() {
+                    Some(ch) if ch.is_whitespace() => {
+                        self.bump();
+                    }
+                    _ => return Ok(()),
+                }
+            }
+        }
+    }
+
+    // Returns the end offset of the possibly nested block comment starting at
+    // `start`.
+    fn block_comment_end(&self, start: usize) -> Result<usize> {
+        let bytes = self.text.as_bytes();
+        let mut depth = 0;
+        let mut i = start;
+        while i + 1 < bytes.len() {
+            match (bytes[i], bytes[i + 1]) {
+                (b'/', b'*') => {
+                    depth += 1;
+                    i += 2;
+                }
+                (b'*', b'/') => {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        return Ok(i);
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+        Err(self.error(start, "unterminated block comment"))
+    }
+
+    fn doc_comment(&self, level: &mut Level, text: &str, inner: bool, range: Range<usize>) {
+        level.push(
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            range.clone(),
+        );
+        if inner {
+            level.push(
+                TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+                range.clone(),
+            );
+        }
+        let mut contents = Level::new();
+        contents.push(
+            TokenTree::Ident(Ident::new("doc", Span::call_site())),
+            range.clone(),
+        );
+        contents.push(
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            range.clone(),
+        );
+        contents.push(TokenTree::Literal(Literal::string(text)), range.clone());
+        let stream: TokenStream = contents.tokens.into_iter().collect();
+        level
+            .tokens
+            .push(TokenTree::Group(Group::new(Delimiter::Bracket, stream)));
+        level.located.push(Located {
+            range,
+            group: Some((Delimiter::Bracket, contents.located)),
+        });
+    }
+
+    fn token(&mut self, level: &mut Level) -> Result<()> {
+        let start = self.pos;
+        let rest = self.rest();
+        let ch = self.peek().unwrap();
+
+        let is_raw_string =
+            rest.starts_with("r\"") || rest.starts_with("r#") && !self.is_raw_ident();
+        if is_raw_string || rest.starts_with("br\"") || rest.starts_with("br#") {
+            if ch == 'b' {
+                self.bump();
+            }
+            self.bump();
+            self.raw_string(start)?;
+            return self.literal(level, start);
+        }
+        if rest.starts_with("b\"") || rest.starts_with("b'") {
+            self.bump();
+            let quote = self.bump().unwrap();
+            self.quoted(start, quote)?;
+            return self.literal(level, start);
+        }
+        if ch == '"' {
+            self.bump();
+            self.quoted(start, '"')?;
+            return self.literal(level, start);
+        }
+        if ch == '\'' {
+            return self.apostrophe(level);
+        }
+        if ch.is_ascii_digit() {
+            self.number();
+            return self.literal(level, start);
+        }
+        if is_ident_start(ch) {
+            let raw = self.is_raw_ident();
+            if raw {
+                self.pos += 2;
+            }
+            let name_start = self.pos;
+            self.ident_continue();
+            let name = &self.text[name_start..self.pos];
+            let ident = if raw {
+                if name == "_"
+                    || name == "crate"
+                    || name == "self"
+                    || name == "super"
+                    || name == "Self"
+                {
+                    return Err(self.error(start, "invalid raw identifier"));
+                }
+                Ident::new_raw(name, Span::call_site())
+            } else {
+                Ident::new(name, Span::call_site())
+            };
+            level.push(TokenTree::Ident(ident), start..self.pos);
+            return Ok(());
+        }
+        if is_punct(ch) {
+            self.bump();
+            let spacing = match self.peek() {
+                Some(next) if is_punct(next) => Spacing::Joint,
+                _ => Spacing::Alone,
+            };
+            level.push(TokenTree::Punct(Punct::new(ch, spacing)), start..self.pos);
+            return Ok(());
+        }
+        Err(self.error(start, &format!("unexpected character `{}`", ch)))
+    }
+
+    fn is_raw_ident(&self) -> bool {
+        let rest = self.rest();
+        rest.starts_with("r#") && rest[2..].chars().next().map_or(false, is_ident_start)
+    }
+
+    fn ident_continue(&mut self) {
+        while let Some(ch) = self.peek() {
+            if is_ident_continue(ch) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    // Lexes the rest of a literal starting with the given quote character,
+    // up to and including the closing quote.
+    fn quoted(&mut self, start: usize, quote: char) -> Result<()> {
+        while let Some(ch) = self.bump() {
+            if ch == quote {
+                return Ok(());
+            }
+            if ch == '\\' {
+                self.bump();
+            }
+        }
+        Err(self.error(start, "unterminated literal"))
+    }
+
+    fn raw_string(&mut self, start: usize) -> Result<()> {
+        let mut hashes = 0;
+        while self.peek() == Some('#') {
+            self.bump();
+            hashes += 1;
+        }
+        if self.bump() != Some('"') {
+            return Err(self.error(start, "invalid raw string literal"));
+        }
+        let terminator: String = Some('"')
+            .into_iter()
+            .chain((0..hashes).map(|_| '#'))
+            .collect();
+        match self.rest().find(&terminator) {
+            Some(end) => {
+                self.pos += end + terminator.len();
+                Ok(())
+            }
+            None => Err(self.error(start, "unterminated raw string literal")),
+        }
+    }
+
+    fn number(&mut self) {
+        let rest = self.rest();
+        let radix = rest.starts_with("0x") || rest.starts_with("0o") || rest.starts_with("0b");
+        let mut seen_letter = false;
+        let mut seen_dot = false;
+        while let Some(ch) = self.peek() {
+            if ch.is_ascii_alphanumeric() || ch == '_' {
+                self.bump();
+                let exponent = !radix && !seen_letter && (ch == 'e' || ch == 'E');
+                if exponent {
+                    let sign = self.peek().map_or(false, |next| next == '+' || next == '-');
+                    if sign && self.peek_nth(1).map_or(false, |next| next.is_ascii_digit()) {
+                        self.bump();
+                    }
+                }
+                if ch.is_ascii_alphabetic() {
+                    seen_letter = true;
+                }
+            } else if ch == '.' && !radix && !seen_letter && !seen_dot {
+                // `1.0` and `1.` are floats, but not `1..2`, `1.foo()` or
+                // `1._0`.
+                match self.peek_nth(1) {
+                    Some(next) if next == '.' || is_ident_start(next) => break,
+                    _ => {}
+                }
+                self.bump();
+                seen_dot = true;
+            } else {
+                break;
+            }
+        }
+    }
+
+    // Lexes a lifetime or a character literal.
+    fn apostrophe(&mut self, level: &mut Level) -> Result<()> {
+        let start = self.pos;
+        let first = self.peek_nth(1);
+        let second = self.peek_nth(2);
+        let is_char = first == Some('\\') || second == Some('\'');
+        if is_char {
+            self.bump();
+            self.quoted(start, '\'')?;
+            return self.literal(level, start);
+        }
+        match first {
+            Some(first) if is_ident_start(first) => {
+                self.bump();
+                let apostrophe = Punct::new('\'', Spacing::Joint);
+                level.push(TokenTree::Punct(apostrophe), start..self.pos);
+                let name_start = self.pos;
+                self.ident_continue();
+                let name = &self.text[name_start..self.pos];
+                let ident = Ident::new(name, Span::call_site());
+                level.push(TokenTree::Ident(ident), name_start..self.pos);
+                Ok(())
+            }
+            _ => Err(self.error(start, "invalid character literal")),
+        }
+    }
+
+    // Finishes a literal whose text starts at `start`, including any suffix.
+    fn literal(&mut self, level: &mut Level, start: usize) -> Result<()> {
+        if self.peek().map_or(false, is_ident_start) {
+            self.ident_continue();
+        }
+        let repr = &self.text[start..self.pos];
+        match Literal::from_str(repr) {
+            Ok(literal) => {
+                level.push(TokenTree::Literal(literal), start..self.pos);
+                Ok(())
+            }
+            Err(_) => Err(self.error(start, "invalid literal")),
+        }
+    }
+}
+
+fn is_ident_start(ch: char) -> bool {
+    ch == '_' || unicode_ident::is_xid_start(ch)
+}
+
+fn is_ident_continue(ch: char) -> bool {
+    unicode_ident::is_xid_continue(ch)
+}
+
+fn is_punct(ch: char) -> bool {
+    match ch {
+        '=' | '<' | '>' | '!' | '~' | '+' | '-' | '*' | '/' | '%' | '^' | '&' | '|' | '@' | '.'
+        | ',' | ';' | ':' | '#' | '$' | '?' | '\'' => true,
+        _ => false,
+    }
+}
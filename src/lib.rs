@@ -234,6 +234,20 @@
 //!   possible input to a derive macro, including structs and enums and types.
 //! - **`full`** — Data structures for representing the syntax tree of all valid
 //!   Rust source code, including items and expressions.
+//! - **`parse-only`** — The parser traits, the token types and the grammar
+//!   of expressions and types that `derive` has, without `DeriveInput`, the
+//!   data structures of struct fields and enum variants, or printing. For
+//!   function-like macros that want the fewest features compiled. Implies
+//!   `parsing`.
+//!
+//!   **This feature must be used with `default-features = false`.** The
+//!   default features include `derive` and `printing`, so with them enabled
+//!   `parse-only` leaves nothing out:
+//!
+//!   ```toml
+//!   [dependencies]
+//!   syn = { version = "1.0", default-features = false, features = ["parse-only"] }
+//!   ```
 //! - **`parsing`** *(enabled by default)* — Ability to parse input tokens into
 //!   a syntax tree node of a chosen type.
 //! - **`printing`** *(enabled by default)* — Ability to print a syntax tree
@@ -247,7 +261,23 @@
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
-//!   dynamic library libproc_macro from rustc toolchain.
+//!   dynamic library libproc_macro from rustc toolchain. Has no effect when
+//!   building for a target that does not provide the `proc_macro` crate.
+//! - **`lexer`** — Tokenizer for Rust source text that fills Syn's token
+//!   buffer directly and records the byte range of every token.
+//! - **`verus`** — Parsing of the `requires`, `ensures` and `decreases`
+//!   clauses of a function signature and the `invariant` and `decreases`
+//!   clauses of a loop, for verification tools. They go in the boxed `spec`
+//!   field of the signature or loop, which is `None` without this feature,
+//!   so the size and fields of the syntax tree nodes are the same either
+//!   way. The fields of `SpecClauses` and the keywords that begin the
+//!   clauses exist only with this feature. Implies `full` and `parsing`.
+//! - **`serde`** — Serialize and Deserialize impls for all syntax tree types.
+//!   Tokens and spans are left out, an optional token is a bool, and a
+//!   punctuated field is a list of its elements plus a `<field>_trailing`
+//!   bool. Identifiers, lifetimes, literals and token streams are their
+//!   source text, and enums are externally tagged. Every span of a
+//!   deserialized syntax tree is `Span::call_site()`.
 
 // Syn types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/syn/1.0.95")]
@@ -286,10 +316,7 @@
     clippy::wildcard_imports
 )]
 
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
 extern crate proc_macro;
 extern crate proc_macro2;
 
@@ -309,13 +336,27 @@ pub mod group;
 pub mod token;
 
 mod ident;
-pub use crate::ident::Ident;
+pub use crate::ident::{gensym, gensym_at, Ident};
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod api;
+
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod attr;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::attr::{
-    AttrStyle, Attribute, AttributeArgs, Meta, MetaList, MetaNameValue, NestedMeta,
+    AttrStyle, Attribute, AttributeArgs, CfgAll, CfgAny, CfgExpr, CfgKeyValue, CfgNot, Meta,
+    MetaList, MetaNameValue, NestedMeta,
+};
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "parsing"
+))]
+pub use crate::attr::{LintAttr, LintLevel};
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub use crate::attr::{
+    DeriveList, DerivedTrait, ParsedAttributes, ParsedAttributesIter, ReprAttr, ReprInt,
 };
 
 mod bigint;
@@ -324,17 +365,17 @@ mod bigint;
 mod data;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::data::{
-    Field, Fields, FieldsNamed, FieldsUnnamed, Variant, VisCrate, VisPublic, VisRestricted,
-    Visibility,
+    Field, Fields, FieldsNamed, FieldsPattern, FieldsUnnamed, Variant, VisCrate, VisPublic,
+    VisRestricted, Visibility,
 };
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod expr;
 #[cfg(feature = "full")]
 pub use crate::expr::{
     Arm, FieldValue, GenericMethodArgument, Label, MethodTurbofish, RangeLimits,
 };
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::expr::{
     Expr, ExprArray, ExprAssign, ExprAssignOp, ExprAsync, ExprAwait, ExprBinary, ExprBlock,
     ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprContinue, ExprField, ExprForLoop,
@@ -343,29 +384,46 @@ pub use crate::expr::{
     ExprTryBlock, ExprTuple, ExprType, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, Index, Member,
 };
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod generics;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "clone-impls",
+    feature = "visit-mut"
+))]
+pub use crate::generics::GenericsRenames;
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::generics::{
     BoundLifetimes, ConstParam, GenericParam, Generics, LifetimeDef, PredicateEq,
     PredicateLifetime, PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound,
     WhereClause, WherePredicate,
 };
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "printing"
+))]
 pub use crate::generics::{ImplGenerics, Turbofish, TypeGenerics};
 
 #[cfg(feature = "full")]
 mod item;
 #[cfg(feature = "full")]
 pub use crate::item::{
-    FnArg, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic, ForeignItemType,
-    ImplItem, ImplItemConst, ImplItemMacro, ImplItemMethod, ImplItemType, Item, ItemConst,
-    ItemEnum, ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2, ItemMod,
-    ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse, Receiver,
-    Signature, TraitItem, TraitItemConst, TraitItemMacro, TraitItemMethod, TraitItemType, UseGlob,
-    UseGroup, UseName, UsePath, UseRename, UseTree,
+    FnArg, FnQualifiers, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic,
+    ForeignItemType, ImplItem, ImplItemConst, ImplItemMacro, ImplItemMethod, ImplItemType, Item,
+    ItemConst, ItemEnum, ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2,
+    ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse,
+    Receiver, Signature, SpecClauses, TraitItem, TraitItemConst, TraitItemMacro, TraitItemMethod,
+    TraitItemType, UseGlob, UseGroup, UseName, UsePath, UseRename, UseTree,
 };
 
+#[cfg(all(feature = "full", feature = "parsing"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub mod builtin_macros;
+
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod elision;
+
 #[cfg(feature = "full")]
 mod file;
 #[cfg(feature = "full")]
@@ -376,12 +434,12 @@ pub use crate::lifetime::Lifetime;
 
 mod lit;
 pub use crate::lit::{
-    Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, StrStyle,
+    Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, LitValue, Radix, StrStyle,
 };
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod mac;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::mac::{Macro, MacroDelimiter};
 
 #[cfg(any(feature = "full", feature = "derive"))]
@@ -389,36 +447,94 @@ mod derive;
 #[cfg(feature = "derive")]
 pub use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod module_tree;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "printing"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive", feature = "parse-only"),
+        feature = "printing"
+    )))
+)]
+pub mod normalize;
+
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod op;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::op::{BinOp, UnOp};
 
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod exhaustive;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+)]
+pub mod ffi;
+
+#[cfg(all(feature = "full", feature = "visit"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub mod imports;
+
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod label;
+
+#[cfg(all(feature = "full", feature = "visit"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub mod place;
+
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod scope;
+
+#[cfg(all(feature = "full", feature = "printing"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "printing"))))]
+pub mod semver;
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit-mut"))))]
+pub mod split;
+
+#[cfg(feature = "verus")]
+mod spec;
+#[cfg(feature = "verus")]
+pub use crate::spec::{Decreases, Ensures, Invariant, Requires};
+
 #[cfg(feature = "full")]
 mod stmt;
 #[cfg(feature = "full")]
 pub use crate::stmt::{Block, Local, Stmt};
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod ty;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::ty::{
-    Abi, BareFnArg, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer,
-    TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject,
-    TypeTuple, Variadic,
+    Abi, AbiName, BareFnArg, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait,
+    TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice,
+    TypeTraitObject, TypeTuple, Variadic,
 };
 
 #[cfg(feature = "full")]
 mod pat;
 #[cfg(feature = "full")]
 pub use crate::pat::{
-    FieldPat, Pat, PatBox, PatIdent, PatLit, PatMacro, PatOr, PatPath, PatRange, PatReference,
-    PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType, PatWild,
+    FieldPat, Pat, PatBinding, PatBox, PatIdent, PatLit, PatMacro, PatOr, PatPath, PatRange,
+    PatReference, PatRest, PatSlice, PatStep, PatStruct, PatTuple, PatTupleStruct, PatType,
+    PatWild,
 };
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 mod path;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub use crate::path::{
     AngleBracketedGenericArguments, Binding, Constraint, GenericArgument,
     ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf,
@@ -430,8 +546,14 @@ pub mod buffer;
 #[cfg(feature = "parsing")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
 pub mod ext;
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+pub mod hash;
 pub mod punctuated;
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "extra-traits"
+))]
 mod tt;
 
 // Not public API except the `parse_quote!` macro.
@@ -440,21 +562,31 @@ mod tt;
 pub mod parse_quote;
 
 // Not public API except the `parse_macro_input!` macro.
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "parsing",
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "parsing", feature = "proc-macro"))]
 #[doc(hidden)]
 pub mod parse_macro_input;
 
+pub mod span;
+
 #[cfg(all(feature = "parsing", feature = "printing"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "printing"))))]
 pub mod spanned;
 
+pub mod token_stream;
+
+#[cfg(all(feature = "full", feature = "printing"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "printing"))))]
+pub mod unparse;
+#[cfg(all(feature = "full", feature = "printing"))]
+pub use crate::unparse::unparse;
+
 #[cfg(all(feature = "parsing", feature = "full"))]
 mod whitespace;
 
+#[cfg(feature = "lexer")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "lexer")))]
+pub mod lexer;
+
 mod gen {
     /// Syntax tree traversal to walk a shared borrow of a syntax tree.
     ///
@@ -496,6 +628,26 @@ mod gen {
     /// /* ... */
     /// ```
     ///
+    /// Below the level of syntax tree nodes, every keyword, punctuation and
+    /// delimiter token of the tree is passed to [`Visit::visit_token`]. Tokens
+    /// are visited in the order of the syntax tree, not in the order of the
+    /// source: the tokens and child nodes of a node are visited in the order
+    /// of the node's fields. Where a node is printed in a different order than
+    /// its fields, the two orders differ:
+    ///
+    /// - A delimiter is visited once for the pair, before the tokens it
+    ///   encloses.
+    /// - The `where` clause of an item is visited along with its generic
+    ///   parameters.
+    /// - Inner attributes are visited along with the outer attributes of the
+    ///   same node.
+    ///
+    /// To walk the tokens of a node in source order, print it with
+    /// [`ToTokens`] and walk the resulting `TokenStream` instead.
+    ///
+    /// [`Visit::visit_token`]: visit::Visit::visit_token
+    /// [`ToTokens`]: quote::ToTokens
+    ///
     /// *This module is available only if Syn is built with the `"visit"` feature.*
     ///
     /// <br>
@@ -618,6 +770,14 @@ mod gen {
     /// /* ... */
     /// ```
     ///
+    /// Every keyword, punctuation and delimiter token of the tree is passed to
+    /// [`VisitMut::visit_token_mut`], which can be overridden to rewrite the
+    /// spans of the tokens. Tokens are visited in the order of the syntax
+    /// tree, which is not always the order of the source, as described for
+    /// the `visit` module.
+    ///
+    /// [`VisitMut::visit_token_mut`]: visit_mut::VisitMut::visit_token_mut
+    ///
     /// *This module is available only if Syn is built with the `"visit-mut"`
     /// feature.*
     ///
@@ -674,6 +834,104 @@ mod gen {
     #[rustfmt::skip]
     pub mod visit_mut;
 
+    /// Fusing of several syntax tree passes into a single traversal.
+    ///
+    /// Running a number of [`VisitMut`] or [`Fold`] passes over a large tree
+    /// walks the whole tree once per pass. Passes that only rewrite each node
+    /// in place, without needing to control the traversal of its children,
+    /// can instead be written as a [`Pass`], whose methods are hooks that run
+    /// as a node is entered and as it is exited, and are empty by default.
+    /// [`Passes`] is a [`VisitMut`] that walks the tree once and runs the hooks
+    /// of all of its passes at each node.
+    ///
+    /// At each node, the `enter_*` hooks of the passes run in the order in
+    /// which the passes were added, then the children of the node are visited,
+    /// then the `exit_*` hooks run, again in the order in which the passes
+    /// were added. A hook sees the changes that the hooks before it made to
+    /// the node, and the walk descends into the children as they are after
+    /// the `enter_*` hooks. An `enter_*` hook that wraps the node in another
+    /// node of the same type therefore enters the original node again, and
+    /// has to recognize it to not wrap it forever.
+    ///
+    /// This is not in general the same result as running the passes one after
+    /// another. An `exit_*` hook sees the children of its node as rewritten
+    /// by all of the passes, including the ones added after it, and an
+    /// `enter_*` hook sees them before any pass has rewritten them, whereas
+    /// run on its own a pass would see them as the passes before it left
+    /// them. The results agree when no hook looks at the changes that later passes make
+    /// to the children of its node, such as when each pass looks at and
+    /// rewrites a different kind of node. Passes that depend on each other in
+    /// this way have to run as separate traversals.
+    ///
+    /// [`VisitMut`]: visit_mut::VisitMut
+    /// [`Fold`]: fold::Fold
+    /// [`Pass`]: pass::Pass
+    /// [`Passes`]: pass::Passes
+    ///
+    /// *This module is available only if Syn is built with the `"visit-mut"`
+    /// feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // quote = "1.0"
+    /// // syn = { version = "1.0", features = ["full", "visit-mut"] }
+    ///
+    /// use quote::quote;
+    /// use syn::pass::{Pass, Passes};
+    /// use syn::visit_mut::VisitMut;
+    /// use syn::{parse_quote, Expr, File, Lit, LitInt};
+    ///
+    /// // Replaces every integer literal by its value plus one.
+    /// struct Increment;
+    ///
+    /// impl Pass for Increment {
+    ///     fn exit_lit(&mut self, node: &mut Lit) {
+    ///         if let Lit::Int(int) = node {
+    ///             let value = int.base10_parse::<u64>().unwrap() + 1;
+    ///             *int = LitInt::new(&value.to_string(), int.span());
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // Counts the calls of functions.
+    /// struct CountCalls(usize);
+    ///
+    /// impl Pass for CountCalls {
+    ///     fn exit_expr(&mut self, node: &mut Expr) {
+    ///         if let Expr::Call(_) = node {
+    ///             self.0 += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut file: File = parse_quote! {
+    ///         fn f() -> u64 {
+    ///             g(1) + h(2, 3)
+    ///         }
+    ///     };
+    ///
+    ///     let mut calls = CountCalls(0);
+    ///     Passes::new()
+    ///         .with(&mut Increment)
+    ///         .with(&mut calls)
+    ///         .visit_file_mut(&mut file);
+    ///
+    ///     assert_eq!(calls.0, 2);
+    ///     println!("{}", quote!(#file));
+    ///
+    ///     // Output: fn f() -> u64 { g(2) + h(3, 4) }
+    /// }
+    /// ```
+    #[cfg(feature = "visit-mut")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "visit-mut")))]
+    #[rustfmt::skip]
+    pub mod pass;
+
     /// Syntax tree traversal to transform the nodes of an owned syntax tree.
     ///
     /// Each method of the [`Fold`] trait is a hook that can be overridden to
@@ -761,6 +1019,9 @@ mod gen {
     #[rustfmt::skip]
     pub mod fold;
 
+    #[rustfmt::skip]
+    mod accessor;
+
     #[cfg(feature = "clone-impls")]
     #[rustfmt::skip]
     mod clone;
@@ -773,11 +1034,27 @@ mod gen {
     #[rustfmt::skip]
     mod hash;
 
+    #[cfg(feature = "extra-traits")]
+    #[rustfmt::skip]
+    mod stable_hash;
+
     #[cfg(feature = "extra-traits")]
     #[rustfmt::skip]
     mod debug;
 
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(feature = "printing")]
+    #[rustfmt::skip]
+    mod respan;
+
+    #[rustfmt::skip]
+    mod kind;
+    pub use self::kind::*;
+
+    #[cfg(feature = "serde")]
+    #[rustfmt::skip]
+    mod serde;
+
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
     #[path = "../gen_helper.rs"]
     mod helper;
 }
@@ -791,7 +1068,7 @@ pub mod __private;
 mod custom_keyword;
 mod custom_punctuation;
 mod sealed;
-mod span;
+mod spans;
 mod thread;
 
 #[cfg(feature = "parsing")]
@@ -804,10 +1081,19 @@ pub mod parse;
 #[cfg(feature = "full")]
 mod reserved;
 
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg(feature = "serde")]
+mod serde_helper;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "parsing"
+))]
 mod verbatim;
 
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+#[cfg(all(
+    any(feature = "full", feature = "derive", feature = "parse-only"),
+    feature = "printing"
+))]
 mod print;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -864,11 +1150,7 @@ pub use crate::error::{Error, Result};
 ///     expanded.into()
 /// }
 /// ```
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "parsing",
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "parsing", feature = "proc-macro"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "proc-macro"))))]
 pub fn parse<T: parse::Parse>(tokens: proc_macro::TokenStream) -> Result<T> {
     parse::Parser::parse(T::parse, tokens)
@@ -962,7 +1244,15 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    parse_file_with(<File as parse::Parse>::parse, content)
+}
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub(crate) fn parse_file_with<P>(parser: P, mut content: &str) -> Result<File>
+where
+    P: parse::Parser<Output = File>,
+{
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -983,7 +1273,7 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
+    let mut file = parser.parse_str(content)?;
     file.shebang = shebang;
     Ok(file)
 }
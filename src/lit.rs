@@ -1,3 +1,5 @@
+#[cfg(feature = "extra-traits")]
+use crate::hash::{StableHash, StableHasher};
 #[cfg(feature = "parsing")]
 use crate::lookahead;
 #[cfg(feature = "parsing")]
@@ -6,6 +8,7 @@ use crate::{Error, Result};
 use proc_macro2::{Ident, Literal, Span};
 #[cfg(feature = "parsing")]
 use proc_macro2::{TokenStream, TokenTree};
+use std::borrow::Cow;
 use std::fmt::{self, Display};
 #[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
@@ -51,7 +54,7 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A UTF-8 string literal: `"foo"`.
     pub struct LitStr {
-        repr: Box<LitRepr>,
+        repr: Box<LitStrRepr>,
     }
 }
 
@@ -81,6 +84,13 @@ struct LitRepr {
     suffix: Box<str>,
 }
 
+struct LitStrRepr {
+    token: Literal,
+    // The text of the token, kept so that the value can be borrowed from it.
+    text: Box<str>,
+    suffix: Box<str>,
+}
+
 ast_struct! {
     /// An integer literal: `1` or `1u16`.
     pub struct LitInt {
@@ -122,7 +132,8 @@ impl LitStr {
         let mut token = Literal::string(value);
         token.set_span(span);
         LitStr {
-            repr: Box::new(LitRepr {
+            repr: Box::new(LitStrRepr {
+                text: token.to_string().into_boxed_str(),
                 token,
                 suffix: Box::<str>::default(),
             }),
@@ -130,9 +141,34 @@ impl LitStr {
     }
 
     pub fn value(&self) -> String {
-        let repr = self.repr.token.to_string();
-        let (value, _suffix) = value::parse_lit_str(&repr);
-        String::from(value)
+        self.value_cow().into_owned()
+    }
+
+    /// The value of the literal, borrowed from the text of the token unless
+    /// the literal contains escapes or line breaks that make the value differ
+    /// from the text between its quotes.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use syn::{parse_quote, LitStr};
+    ///
+    /// let plain: LitStr = parse_quote!("serde_json");
+    /// assert!(match plain.value_cow() {
+    ///     Cow::Borrowed(value) => value == "serde_json",
+    ///     Cow::Owned(_) => false,
+    /// });
+    ///
+    /// let escaped: LitStr = parse_quote!("line\n");
+    /// assert_eq!(escaped.value_cow(), "line\n");
+    /// ```
+    pub fn value_cow(&self) -> Cow<'_, str> {
+        match value::borrow_lit_str(&self.repr.text, self.repr.suffix.len()) {
+            Some(value) => Cow::Borrowed(value),
+            None => {
+                let (value, _suffix) = value::parse_lit_str(&self.repr.text);
+                Cow::Owned(String::from(value))
+            }
+        }
     }
 
     /// Parse a syntax tree node from the content of this string literal.
@@ -242,6 +278,21 @@ impl LitStr {
         &self.repr.suffix
     }
 
+    /// Whether the literal is a raw string like `r"..."` or `r#"..."#`.
+    ///
+    /// The literal is printed the way it was written, so a raw string stays
+    /// raw, with the same number of `#` symbols, through parsing and
+    /// printing.
+    pub fn is_raw(&self) -> bool {
+        value::raw_hashes(&self.repr.text).is_some()
+    }
+
+    /// The number of `#` symbols around the quotes of a raw string, which is
+    /// 0 for `r"..."` and for a string that is not raw.
+    pub fn raw_hashes(&self) -> usize {
+        value::raw_hashes(&self.repr.text).unwrap_or(0)
+    }
+
     pub fn token(&self) -> Literal {
         self.repr.token.clone()
     }
@@ -277,6 +328,18 @@ impl LitByteStr {
         &self.repr.suffix
     }
 
+    /// Whether the literal is a raw byte string like `br"..."` or
+    /// `br#"..."#`.
+    pub fn is_raw(&self) -> bool {
+        value::raw_hashes(&self.repr.token.to_string()).is_some()
+    }
+
+    /// The number of `#` symbols around the quotes of a raw byte string,
+    /// which is 0 for `br"..."` and for a byte string that is not raw.
+    pub fn raw_hashes(&self) -> usize {
+        value::raw_hashes(&self.repr.token.to_string()).unwrap_or(0)
+    }
+
     pub fn token(&self) -> Literal {
         self.repr.token.clone()
     }
@@ -378,6 +441,31 @@ impl LitInt {
         &self.repr.digits
     }
 
+    /// The radix in which the literal is written, according to its `0b`,
+    /// `0o` or `0x` prefix.
+    pub fn radix(&self) -> Radix {
+        let repr = self.repr.token.to_string();
+        split_radix(repr.trim_start_matches('-')).0
+    }
+
+    /// The digits of the literal as written, including underscores, but
+    /// without the sign, the radix prefix and the suffix.
+    ///
+    /// For `0x_FF_u8` this is `_FF_`.
+    pub fn raw_digits(&self) -> String {
+        let repr = self.repr.token.to_string();
+        let unsigned = repr.trim_start_matches('-');
+        let digits = split_radix(unsigned).1;
+        digits[..digits.len() - self.repr.suffix.len()].to_owned()
+    }
+
+    /// The digits of the literal in its own radix, without underscores.
+    ///
+    /// For `0x_FF_u8` this is `FF`.
+    pub fn normalized_digits(&self) -> String {
+        self.raw_digits().replace('_', "")
+    }
+
     /// Parses the literal into a selected number type.
     ///
     /// This is equivalent to `lit.base10_digits().parse()` except that the
@@ -476,6 +564,24 @@ impl LitFloat {
         &self.repr.digits
     }
 
+    /// The digits of the literal as written, including underscores and any
+    /// exponent, but without the sign and the suffix.
+    ///
+    /// Float literals are always written in decimal. For `1_000.5e3_f64` this
+    /// is `1_000.5e3_`.
+    pub fn raw_digits(&self) -> String {
+        let repr = self.repr.token.to_string();
+        let digits = repr.trim_start_matches('-');
+        digits[..digits.len() - self.repr.suffix.len()].to_owned()
+    }
+
+    /// The digits of the literal without underscores.
+    ///
+    /// For `1_000.5e3_f64` this is `1000.5e3`.
+    pub fn normalized_digits(&self) -> String {
+        self.raw_digits().replace('_', "")
+    }
+
     pub fn base10_parse<N>(&self) -> Result<N>
     where
         N: FromStr,
@@ -625,6 +731,21 @@ mod debug_impls {
     }
 }
 
+impl LitStr {
+    // A copy of the literal, which unlike `clone` does not need the
+    // "clone-impls" feature.
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
+    pub(crate) fn duplicate(&self) -> Self {
+        LitStr {
+            repr: Box::new(LitStrRepr {
+                token: self.repr.token.clone(),
+                text: self.repr.text.clone(),
+                suffix: self.repr.suffix.clone(),
+            }),
+        }
+    }
+}
+
 #[cfg(feature = "clone-impls")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for LitRepr {
@@ -636,6 +757,18 @@ impl Clone for LitRepr {
     }
 }
 
+#[cfg(feature = "clone-impls")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for LitStrRepr {
+    fn clone(&self) -> Self {
+        LitStrRepr {
+            token: self.token.clone(),
+            text: self.text.clone(),
+            suffix: self.suffix.clone(),
+        }
+    }
+}
+
 #[cfg(feature = "clone-impls")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for LitIntRepr {
@@ -691,6 +824,14 @@ macro_rules! lit_extra_traits {
             }
         }
 
+        #[cfg(feature = "extra-traits")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+        impl StableHash for $ty {
+            fn stable_hash(&self, hasher: &mut StableHasher) {
+                self.repr.token.to_string().stable_hash(hasher);
+            }
+        }
+
         #[cfg(feature = "parsing")]
         #[doc(hidden)]
         #[allow(non_snake_case)]
@@ -727,6 +868,174 @@ ast_enum! {
     }
 }
 
+/// The radix of an integer literal.
+pub enum Radix {
+    /// A binary literal like `0b1010`.
+    Binary,
+    /// An octal literal like `0o777`.
+    Octal,
+    /// A decimal literal like `42`.
+    Decimal,
+    /// A hexadecimal literal like `0xFF`.
+    Hexadecimal,
+}
+
+impl Radix {
+    /// The base of the radix: 2, 8, 10 or 16.
+    pub fn base(self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+        }
+    }
+
+    /// The prefix which introduces a literal in this radix, or the empty
+    /// string for decimal.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Radix::Binary => "0b",
+            Radix::Octal => "0o",
+            Radix::Decimal => "",
+            Radix::Hexadecimal => "0x",
+        }
+    }
+}
+
+impl Copy for Radix {}
+
+impl Clone for Radix {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Eq for Radix {}
+
+impl PartialEq for Radix {
+    fn eq(&self, other: &Self) -> bool {
+        self.base() == other.base()
+    }
+}
+
+impl fmt::Debug for Radix {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            Radix::Binary => "Binary",
+            Radix::Octal => "Octal",
+            Radix::Decimal => "Decimal",
+            Radix::Hexadecimal => "Hexadecimal",
+        })
+    }
+}
+
+/// The value of a literal, as returned by [`Lit::value`].
+pub enum LitValue {
+    /// A string literal like `"foo"`.
+    Str(String),
+    /// A byte string literal like `b"foo"`.
+    ByteStr(Vec<u8>),
+    /// A byte literal like `b'f'`.
+    Byte(u8),
+    /// A character literal like `'a'`.
+    Char(char),
+    /// An integer literal like `1` or `1u16`.
+    Int {
+        value: u128,
+        /// The suffix, or the empty string if there is none.
+        suffix: String,
+    },
+    /// A floating point literal like `1f64` or `1.0e10f64`.
+    Float {
+        value: f64,
+        /// The suffix, or the empty string if there is none.
+        suffix: String,
+    },
+    /// A boolean literal: `true` or `false`.
+    Bool(bool),
+}
+
+impl Clone for LitValue {
+    fn clone(&self) -> Self {
+        match self {
+            LitValue::Str(value) => LitValue::Str(value.clone()),
+            LitValue::ByteStr(value) => LitValue::ByteStr(value.clone()),
+            LitValue::Byte(value) => LitValue::Byte(*value),
+            LitValue::Char(value) => LitValue::Char(*value),
+            LitValue::Int { value, suffix } => LitValue::Int {
+                value: *value,
+                suffix: suffix.clone(),
+            },
+            LitValue::Float { value, suffix } => LitValue::Float {
+                value: *value,
+                suffix: suffix.clone(),
+            },
+            LitValue::Bool(value) => LitValue::Bool(*value),
+        }
+    }
+}
+
+impl PartialEq for LitValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (LitValue::Str(this), LitValue::Str(other)) => this == other,
+            (LitValue::ByteStr(this), LitValue::ByteStr(other)) => this == other,
+            (LitValue::Byte(this), LitValue::Byte(other)) => this == other,
+            (LitValue::Char(this), LitValue::Char(other)) => this == other,
+            (
+                LitValue::Int { value, suffix },
+                LitValue::Int {
+                    value: other_value,
+                    suffix: other_suffix,
+                },
+            ) => value == other_value && suffix == other_suffix,
+            (
+                LitValue::Float { value, suffix },
+                LitValue::Float {
+                    value: other_value,
+                    suffix: other_suffix,
+                },
+            ) => value == other_value && suffix == other_suffix,
+            (LitValue::Bool(this), LitValue::Bool(other)) => this == other,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for LitValue {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LitValue::Str(value) => formatter.debug_tuple("Str").field(value).finish(),
+            LitValue::ByteStr(value) => formatter.debug_tuple("ByteStr").field(value).finish(),
+            LitValue::Byte(value) => formatter.debug_tuple("Byte").field(value).finish(),
+            LitValue::Char(value) => formatter.debug_tuple("Char").field(value).finish(),
+            LitValue::Int { value, suffix } => formatter
+                .debug_struct("Int")
+                .field("value", value)
+                .field("suffix", suffix)
+                .finish(),
+            LitValue::Float { value, suffix } => formatter
+                .debug_struct("Float")
+                .field("value", value)
+                .field("suffix", suffix)
+                .finish(),
+            LitValue::Bool(value) => formatter.debug_tuple("Bool").field(value).finish(),
+        }
+    }
+}
+
+// Splits an integer literal without sign into its radix and the rest.
+fn split_radix(repr: &str) -> (Radix, &str) {
+    let radixes = [Radix::Binary, Radix::Octal, Radix::Hexadecimal];
+    for &radix in &radixes {
+        if repr.starts_with(radix.prefix()) {
+            return (radix, &repr[2..]);
+        }
+    }
+    (Radix::Decimal, repr)
+}
+
 #[cfg(feature = "parsing")]
 #[doc(hidden)]
 #[allow(non_snake_case)]
@@ -962,7 +1271,11 @@ mod value {
                 b'"' | b'r' => {
                     let (_, suffix) = parse_lit_str(&repr);
                     return Lit::Str(LitStr {
-                        repr: Box::new(LitRepr { token, suffix }),
+                        repr: Box::new(LitStrRepr {
+                            token,
+                            text: repr.into_boxed_str(),
+                            suffix,
+                        }),
                     });
                 }
                 b'b' => match byte(&repr, 1) {
@@ -1057,6 +1370,44 @@ mod value {
                 Lit::Verbatim(lit) => lit.set_span(span),
             }
         }
+
+        /// Interprets the literal as a value of the matching Rust type.
+        ///
+        /// Returns an error spanned to the literal if it is a negative integer
+        /// or does not fit in a `u128`, or if it is a `Lit::Verbatim`.
+        ///
+        /// ```
+        /// use syn::{Lit, LitValue};
+        ///
+        /// let lit: Lit = syn::parse_quote!(0x10_u8);
+        /// match lit.value().unwrap() {
+        ///     LitValue::Int { value, suffix } => {
+        ///         assert_eq!(value, 16);
+        ///         assert_eq!(suffix, "u8");
+        ///     }
+        ///     _ => unreachable!(),
+        /// }
+        /// ```
+        pub fn value(&self) -> Result<LitValue> {
+            Ok(match self {
+                Lit::Str(lit) => LitValue::Str(lit.value()),
+                Lit::ByteStr(lit) => LitValue::ByteStr(lit.value()),
+                Lit::Byte(lit) => LitValue::Byte(lit.value()),
+                Lit::Char(lit) => LitValue::Char(lit.value()),
+                Lit::Int(lit) => LitValue::Int {
+                    value: lit.base10_parse()?,
+                    suffix: lit.suffix().to_owned(),
+                },
+                Lit::Float(lit) => LitValue::Float {
+                    value: lit.base10_parse()?,
+                    suffix: lit.suffix().to_owned(),
+                },
+                Lit::Bool(lit) => LitValue::Bool(lit.value),
+                Lit::Verbatim(lit) => {
+                    return Err(Error::new(lit.span(), "unsupported literal"));
+                }
+            })
+        }
     }
 
     /// Get the byte at offset idx, or a default of `b'\0'` if we're looking
@@ -1147,6 +1498,34 @@ mod value {
         (content, suffix)
     }
 
+    // The number of `#` symbols of the string or byte string literal `s` if
+    // it is raw.
+    pub fn raw_hashes(s: &str) -> Option<usize> {
+        let s = s.trim_start_matches('b');
+        if byte(s, 0) == b'r' {
+            Some(s[1..].bytes().take_while(|b| *b == b'#').count())
+        } else {
+            None
+        }
+    }
+
+    // The value of the string literal `s`, whose suffix is `suffix_len` bytes
+    // long, if it is the same as the text between the quotes.
+    pub fn borrow_lit_str(s: &str, suffix_len: usize) -> Option<&str> {
+        let s = &s[..s.len() - suffix_len];
+        if byte(s, 0) == b'r' {
+            let pounds = s[1..].bytes().take_while(|b| *b == b'#').count();
+            Some(&s[pounds + 2..s.len() - pounds - 1])
+        } else {
+            let content = &s[1..s.len() - 1];
+            if content.contains('\\') || content.contains('\r') {
+                None
+            } else {
+                Some(content)
+            }
+        }
+    }
+
     fn parse_lit_str_raw(mut s: &str) -> (Box<str>, Box<str>) {
         assert_eq!(byte(s, 0), b'r');
         s = &s[1..];
@@ -1,7 +1,7 @@
 use crate::buffer::Cursor;
 use crate::error::{self, Error};
 use crate::sealed::lookahead::Sealed;
-use crate::span::IntoSpans;
+use crate::spans::IntoSpans;
 use crate::token::Token;
 use proc_macro2::{Delimiter, Span};
 use std::cell::RefCell;
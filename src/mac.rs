@@ -10,9 +10,9 @@ use crate::parse::{Parse, ParseStream, Parser, Result};
 ast_struct! {
     /// A macro invocation: `println!("{}", mac)`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Macro {
         pub path: Path,
         pub bang_token: Token![!],
@@ -24,9 +24,9 @@ ast_struct! {
 ast_enum! {
     /// A grouping token that surrounds a macro body: `m!(...)` or `m!{...}` or `m![...]`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum MacroDelimiter {
         Paren(Paren),
         Brace(Brace),
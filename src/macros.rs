@@ -1,5 +1,5 @@
 #[cfg_attr(
-    not(any(feature = "full", feature = "derive")),
+    not(any(feature = "full", feature = "derive", feature = "parse-only")),
     allow(unknown_lints, unused_macro_rules)
 )]
 macro_rules! ast_struct {
@@ -117,7 +117,7 @@ macro_rules! ast_enum_from_struct {
 
 #[cfg(feature = "printing")]
 #[cfg_attr(
-    not(any(feature = "full", feature = "derive")),
+    not(any(feature = "full", feature = "derive", feature = "parse-only")),
     allow(unknown_lints, unused_macro_rules)
 )]
 macro_rules! generate_to_tokens {
@@ -0,0 +1,322 @@
+//! Assembly of the files of a crate into a single module hierarchy.
+//!
+//! Each file of a crate is parsed into its own [`File`], and an out-of-line
+//! module declaration like `mod util;` only names the module whose items are
+//! in another file. A [`ModuleTree`] puts the files back together: the file
+//! of the crate root is given first, and every other file is inserted under
+//! the path of the module that it contains, such as `"util"` or
+//! `"util::io"`. The tree can then be checked for items that are defined
+//! twice in the same module with [`ModuleTree::duplicates`], or turned into a
+//! single `File` in which every out-of-line module is an inline one with
+//! [`ModuleTree::into_file`], which is what a bundler producing a single
+//! source file needs.
+//!
+//! Modules are matched up by their names only. Where the files came from,
+//! including any `#[path]` attribute, is up to the caller, and `#[cfg]`
+//! attributes are not evaluated.
+//!
+//! *This module is available only if Syn is built with the `"full"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::module_tree::ModuleTree;
+//!
+//! # fn main() -> syn::Result<()> {
+//! let mut tree = ModuleTree::new(syn::parse_file("mod util;\nfn main() {}")?);
+//! tree.insert("util", syn::parse_file("mod io;\npub fn helper() {}")?);
+//! tree.insert("util::io", syn::parse_file("pub fn read() {}")?);
+//! assert!(tree.duplicates().is_empty());
+//!
+//! let file = tree.into_file()?;
+//! let expected = quote! {
+//!     mod util {
+//!         mod io {
+//!             pub fn read() {}
+//!         }
+//!         pub fn helper() {}
+//!     }
+//!     fn main() {}
+//! };
+//! assert_eq!(quote!(#file).to_string(), expected.to_string());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use proc_macro2::Span;
+use std::collections::HashMap;
+
+/// The files of a crate, keyed by the path of the module that each of them
+/// contains.
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+pub struct ModuleTree {
+    root: File,
+    files: HashMap<Vec<String>, File>,
+}
+
+/// An item defined with the same name as an earlier item of the same module,
+/// as found by [`ModuleTree::duplicates`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+pub struct Duplicate {
+    /// The path of the module containing both items, such as `util::io`, or
+    /// an empty string for the crate root.
+    pub module: String,
+    /// The name of the earlier item.
+    pub first: Ident,
+    /// The name of the later item.
+    pub second: Ident,
+}
+
+impl ModuleTree {
+    /// Creates a tree from the file of the crate root, without the files of
+    /// any out-of-line modules.
+    pub fn new(root: File) -> Self {
+        ModuleTree {
+            root,
+            files: HashMap::new(),
+        }
+    }
+
+    /// Adds the file containing the module at the given path below the crate
+    /// root, with the segments separated by `::` as in `"util::io"`.
+    ///
+    /// Returns the file previously inserted for the same module, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is empty or one of its segments is empty.
+    pub fn insert(&mut self, module: &str, file: File) -> Option<File> {
+        self.files.insert(parse_module_path(module), file)
+    }
+
+    /// The file of the crate root.
+    pub fn root(&self) -> &File {
+        &self.root
+    }
+
+    /// The file inserted for the module at the given path, if any.
+    pub fn get(&self, module: &str) -> Option<&File> {
+        self.files.get(&parse_module_path(module))
+    }
+
+    /// Finds the items that are defined more than once in the same module,
+    /// following both inline modules and the inserted files of out-of-line
+    /// ones.
+    ///
+    /// Items are duplicates if they have the same name and are in the same
+    /// namespace. Modules, types and traits share the type namespace, and
+    /// functions, constants and statics the value namespace, as do the
+    /// constructors of unit and tuple structs. Imports, macros and `const _`
+    /// items are not considered.
+    ///
+    /// The duplicates are returned in the order that the later items appear
+    /// in, modules before the modules nested in them.
+    pub fn duplicates(&self) -> Vec<Duplicate> {
+        let mut duplicates = Vec::new();
+        let mut path = Vec::new();
+        self.find_duplicates(&mut path, &self.root.items, &mut duplicates);
+        duplicates
+    }
+
+    fn find_duplicates(
+        &self,
+        path: &mut Vec<String>,
+        items: &[Item],
+        duplicates: &mut Vec<Duplicate>,
+    ) {
+        let mut types = HashMap::new();
+        let mut values = HashMap::new();
+        for item in items {
+            let (ty, value) = namespaces(item);
+            if let Some(ident) = ty {
+                check_duplicate(path, &mut types, ident, duplicates);
+            }
+            if let Some(ident) = value {
+                check_duplicate(path, &mut values, ident, duplicates);
+            }
+        }
+
+        for item in items {
+            if let Item::Mod(item) = item {
+                path.push(unraw(&item.ident));
+                match &item.content {
+                    Some((_brace, items)) => self.find_duplicates(path, items, duplicates),
+                    None => {
+                        if let Some(file) = self.files.get(path) {
+                            self.find_duplicates(path, &file.items, duplicates);
+                        }
+                    }
+                }
+                path.pop();
+            }
+        }
+    }
+
+    /// Replaces every out-of-line module declaration with an inline module
+    /// containing the items of the module's file, resulting in a single file
+    /// for the whole crate.
+    ///
+    /// The inner attributes of a module's file become inner attributes of
+    /// the inline module. A `#[path]` attribute of the declaration is
+    /// dropped, as there is no longer a file for it to refer to.
+    ///
+    /// Returns an error if an out-of-line module has no inserted file, or if
+    /// a file was inserted for a module that is not declared out of line.
+    pub fn into_file(self) -> Result<File> {
+        let ModuleTree {
+            mut root,
+            mut files,
+        } = self;
+        let mut path = Vec::new();
+        let mut errors = Vec::new();
+        inline_modules(&mut path, &mut root.items, &mut files, &mut errors);
+
+        let mut unused: Vec<Vec<String>> = files.into_iter().map(|(path, _file)| path).collect();
+        unused.sort();
+        for path in unused {
+            errors.push(Error::new(
+                Span::call_site(),
+                format!(
+                    "file inserted for module `{}`, which is not declared as `mod {};`",
+                    path.join("::"),
+                    path.last().unwrap(),
+                ),
+            ));
+        }
+
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            None => Ok(root),
+            Some(mut error) => {
+                for rest in errors {
+                    error.combine(rest);
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+impl Duplicate {
+    /// An error pointing at the later item, for reporting the duplicate from
+    /// a procedural macro or a build script.
+    pub fn error(&self) -> Error {
+        let message = if self.module.is_empty() {
+            format!("the name `{}` is defined multiple times", self.second)
+        } else {
+            format!(
+                "the name `{}` is defined multiple times in module `{}`",
+                self.second, self.module,
+            )
+        };
+        Error::new(self.second.span(), message)
+    }
+}
+
+fn check_duplicate<'a>(
+    path: &[String],
+    seen: &mut HashMap<String, &'a Ident>,
+    ident: &'a Ident,
+    duplicates: &mut Vec<Duplicate>,
+) {
+    let name = unraw(ident);
+    match seen.get(&name) {
+        Some(first) => duplicates.push(Duplicate {
+            module: path.join("::"),
+            first: (*first).clone(),
+            second: ident.clone(),
+        }),
+        None => {
+            seen.insert(name, ident);
+        }
+    }
+}
+
+fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    if name.starts_with("r#") {
+        name[2..].to_owned()
+    } else {
+        name
+    }
+}
+
+fn is_path_attr(attr: &Attribute) -> bool {
+    attr.path.leading_colon.is_none()
+        && attr.path.segments.len() == 1
+        && attr.path.segments[0].ident == "path"
+}
+
+fn parse_module_path(module: &str) -> Vec<String> {
+    let path: Vec<String> = module
+        .split("::")
+        .map(|segment| segment.trim().to_owned())
+        .collect();
+    if path.iter().any(String::is_empty) {
+        panic!("invalid module path: {:?}", module);
+    }
+    path
+}
+
+// The names that an item defines in the type namespace and in the value
+// namespace.
+fn namespaces(item: &Item) -> (Option<&Ident>, Option<&Ident>) {
+    match item {
+        Item::Mod(item) => (Some(&item.ident), None),
+        Item::Enum(item) => (Some(&item.ident), None),
+        Item::Union(item) => (Some(&item.ident), None),
+        Item::Trait(item) => (Some(&item.ident), None),
+        Item::TraitAlias(item) => (Some(&item.ident), None),
+        Item::Type(item) => (Some(&item.ident), None),
+        Item::Struct(item) => match item.fields {
+            Fields::Named(_) => (Some(&item.ident), None),
+            Fields::Unnamed(_) | Fields::Unit => (Some(&item.ident), Some(&item.ident)),
+        },
+        Item::Fn(item) => (None, Some(&item.sig.ident)),
+        Item::Const(item) if item.ident != "_" => (None, Some(&item.ident)),
+        Item::Static(item) => (None, Some(&item.ident)),
+        _ => (None, None),
+    }
+}
+
+fn inline_modules(
+    path: &mut Vec<String>,
+    items: &mut [Item],
+    files: &mut HashMap<Vec<String>, File>,
+    errors: &mut Vec<Error>,
+) {
+    for item in items {
+        let item = match item {
+            Item::Mod(item) => item,
+            _ => continue,
+        };
+        path.push(unraw(&item.ident));
+        if item.content.is_none() {
+            match files.remove(path) {
+                Some(file) => {
+                    item.attrs.retain(|attr| !is_path_attr(attr));
+                    item.attrs.extend(file.attrs);
+                    let span = match &item.semi {
+                        Some(semi) => semi.span,
+                        None => item.ident.span(),
+                    };
+                    item.content = Some((token::Brace(span), file.items));
+                    item.semi = None;
+                }
+                None => errors.push(Error::new(
+                    item.ident.span(),
+                    format!("no file inserted for module `{}`", path.join("::")),
+                )),
+            }
+        }
+        if let Some((_brace, items)) = &mut item.content {
+            inline_modules(path, items, files, errors);
+        }
+        path.pop();
+    }
+}
@@ -0,0 +1,181 @@
+//! Canonical forms of syntax tree nodes for hashing and equality.
+//!
+//! Two types that are written differently can still be the same type as far
+//! as a derive macro is concerned: `Vec<(u8)>` and `Vec<u8>` only differ in
+//! redundant parentheses, and a type that went through a `macro_rules!`
+//! fragment is wrapped in an invisible group. Comparing `Type`s directly, or
+//! comparing their `to_token_stream().to_string()`, treats such types as
+//! different. [`type_key`] produces a key that is the same for them.
+//!
+//! The normalization is purely syntactic. Paths are compared as written, so
+//! `Vec<T>` and `std::vec::Vec<T>` have different keys, as do a type alias
+//! and the type that it names.
+//!
+//! *This module is available only if Syn is built with the `"derive"`, `"full"`
+//! or `"parse-only"` feature and the `"printing"` feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use syn::normalize::type_key;
+//! use syn::{parse_quote, Type};
+//!
+//! let field_types: Vec<Type> = vec![
+//!     parse_quote!(Vec<u8>),
+//!     parse_quote!(Vec < (u8) >),
+//!     parse_quote!(Option<u8>),
+//! ];
+//!
+//! let mut seen = HashSet::new();
+//! let distinct: Vec<&Type> = field_types
+//!     .iter()
+//!     .filter(|ty| seen.insert(type_key(ty)))
+//!     .collect();
+//! assert_eq!(distinct.len(), 2);
+//! ```
+
+use crate::ty::Type;
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+
+/// The canonical form of a type, as returned by [`type_key`].
+///
+/// Keys are equal if the types only differ in whitespace, redundant
+/// parentheses and invisible groups. They are displayed as the tokens of the
+/// normalized type.
+pub struct TypeKey {
+    repr: String,
+}
+
+/// Computes the canonical form of a type.
+///
+/// Parentheses around a single type are dropped, except for the arguments of
+/// `Fn(A) -> B` style bounds, and so are the invisible groups that
+/// `macro_rules!` puts around interpolated fragments. This applies at every
+/// level of the type, including generic arguments and bounds.
+pub fn type_key(ty: &Type) -> TypeKey {
+    TypeKey {
+        repr: tokens_key(ty),
+    }
+}
+
+impl TypeKey {
+    /// The normalized tokens of the type as a string.
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+}
+
+impl Clone for TypeKey {
+    fn clone(&self) -> Self {
+        TypeKey {
+            repr: self.repr.clone(),
+        }
+    }
+}
+
+impl Eq for TypeKey {}
+
+impl PartialEq for TypeKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl Hash for TypeKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state);
+    }
+}
+
+impl Debug for TypeKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_tuple("TypeKey").field(&self.repr).finish()
+    }
+}
+
+impl Display for TypeKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.repr)
+    }
+}
+
+// A string that is the same for two nodes if they only differ in whitespace,
+// invisible groups and redundant parentheses.
+pub(crate) fn tokens_key<T: ToTokens>(node: &T) -> String {
+    let mut normalized = String::new();
+    write_normalized(node.to_token_stream(), &mut normalized);
+    normalized.truncate(normalized.trim_end().len());
+    normalized
+}
+
+fn write_normalized(tokens: TokenStream, out: &mut String) {
+    // Whether the previous token is an identifier that a parenthesized group
+    // belongs to, as in `Fn(A)`, rather than being a parenthesized type.
+    let mut after_path = false;
+    for tt in tokens {
+        let is_path = match &tt {
+            TokenTree::Ident(ident) => {
+                ident != "mut" && ident != "const" && ident != "dyn" && ident != "impl"
+            }
+            _ => false,
+        };
+        match tt {
+            TokenTree::Group(group) => {
+                let redundant = match group.delimiter() {
+                    Delimiter::None => true,
+                    Delimiter::Parenthesis => !after_path && is_single_type(group.stream()),
+                    Delimiter::Brace | Delimiter::Bracket => false,
+                };
+                if redundant {
+                    write_normalized(group.stream(), out);
+                } else {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ('(', ')'),
+                        Delimiter::Brace => ('{', '}'),
+                        Delimiter::Bracket => ('[', ']'),
+                        Delimiter::None => unreachable!(),
+                    };
+                    out.push(open);
+                    write_normalized(group.stream(), out);
+                    out.truncate(out.trim_end().len());
+                    out.push(close);
+                    out.push(' ');
+                }
+            }
+            TokenTree::Ident(ident) => {
+                out.push_str(&ident.to_string());
+                out.push(' ');
+            }
+            TokenTree::Punct(punct) => {
+                out.push(punct.as_char());
+                if punct.spacing() == Spacing::Alone {
+                    out.push(' ');
+                }
+            }
+            TokenTree::Literal(lit) => {
+                out.push_str(&lit.to_string());
+                out.push(' ');
+            }
+        }
+        after_path = is_path;
+    }
+}
+
+// Whether the contents of a parenthesized group are a single type in
+// parentheses rather than a tuple type like `()` or `(T,)`.
+fn is_single_type(tokens: TokenStream) -> bool {
+    let mut is_empty = true;
+    for tt in tokens {
+        if let TokenTree::Punct(punct) = &tt {
+            if punct.as_char() == ',' {
+                return false;
+            }
+        }
+        is_empty = false;
+    }
+    !is_empty
+}
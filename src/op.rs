@@ -1,9 +1,9 @@
 ast_enum! {
     /// A binary operator: `+`, `+=`, `&`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum BinOp {
         /// The `+` operator (addition)
         Add(Token![+]),
@@ -67,9 +67,9 @@ ast_enum! {
 ast_enum! {
     /// A unary operator: `*`, `!`, `-`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum UnOp {
         /// The `*` operator for dereferencing
         Deref(Token![*]),
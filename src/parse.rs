@@ -186,26 +186,29 @@
 #[path = "discouraged.rs"]
 pub mod discouraged;
 
+#[path = "combinator.rs"]
+pub mod combinator;
+
 use crate::buffer::{Cursor, TokenBuffer};
 use crate::error;
 use crate::lookahead;
-#[cfg(all(
-    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-    feature = "proc-macro"
-))]
+#[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
 use crate::proc_macro;
 use crate::punctuated::Punctuated;
 use crate::token::Token;
-use proc_macro2::{self, Delimiter, Group, Literal, Punct, Span, TokenStream, TokenTree};
-use std::cell::Cell;
+use proc_macro2::{self, Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::cell::{Cell, RefCell};
+use std::cmp;
 use std::fmt::{self, Debug, Display};
 #[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::mem;
 use std::ops::Deref;
-use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 
 pub use crate::error::{Error, Result};
 pub use crate::lookahead::{Lookahead1, Peek};
@@ -263,16 +266,34 @@ pub struct ParseBuffer<'a> {
     // Cursor<'a> and trusts that it lives as long as the cursor currently in
     // the cell.
     cell: Cell<Cursor<'static>>,
+    // Where the stream started, for finding its last token. Same lifetime
+    // caveats as `cell`.
+    start: Cursor<'static>,
     marker: PhantomData<Cursor<'a>>,
-    unexpected: Cell<Option<Rc<Cell<Unexpected>>>>,
+    // Where leftover tokens are recorded, shared by every parse buffer into
+    // the same token buffer.
+    unexpected: &'a UnexpectedSlots,
+    // This buffer's slot in `unexpected`, allocated the first time something
+    // other than this buffer could observe it: a group parsed from this
+    // buffer, a checkpoint, or `advance_to`. Until then nothing can ask about
+    // the tokens this buffer leaves unparsed.
+    slot: Cell<Option<usize>>,
 }
 
 impl<'a> Drop for ParseBuffer<'a> {
     fn drop(&mut self) {
-        if let Some(unexpected_span) = span_of_unexpected_ignoring_nones(self.cursor()) {
-            let (inner, old_span) = inner_unexpected(self);
-            if old_span.is_none() {
-                inner.set(Unexpected::Some(unexpected_span));
+        if let Some(slot) = self.slot.get() {
+            if unexpected_ignoring_nones(self.cursor()).is_some() {
+                let (inner, old_leftover) = self.unexpected.resolve(slot);
+                if old_leftover.is_none() {
+                    self.unexpected.set(
+                        inner,
+                        Unexpected::Some(Leftover {
+                            start: self.start,
+                            rest: self.cell.get(),
+                        }),
+                    );
+                }
             }
         }
     }
@@ -384,72 +405,144 @@ pub(crate) fn advance_step_cursor<'c, 'a>(proof: StepCursor<'c, 'a>, to: Cursor<
     unsafe { mem::transmute::<Cursor<'c>, Cursor<'a>>(to) }
 }
 
-pub(crate) fn new_parse_buffer(
+pub(crate) fn new_parse_buffer<'a>(
     scope: Span,
-    cursor: Cursor,
-    unexpected: Rc<Cell<Unexpected>>,
-) -> ParseBuffer {
+    cursor: Cursor<'a>,
+    unexpected: &'a UnexpectedSlots,
+) -> ParseBuffer<'a> {
     ParseBuffer {
         scope,
         // See comment on `cell` in the struct definition.
         cell: Cell::new(unsafe { mem::transmute::<Cursor, Cursor<'static>>(cursor) }),
+        start: unsafe { mem::transmute::<Cursor, Cursor<'static>>(cursor) },
         marker: PhantomData,
-        unexpected: Cell::new(Some(unexpected)),
+        unexpected,
+        slot: Cell::new(None),
     }
 }
 
-pub(crate) enum Unexpected {
-    None,
-    Some(Span),
-    Chain(Rc<Cell<Unexpected>>),
+// A parse buffer for the contents of a group parsed from `input`, which
+// reports its leftover tokens through the slot of `input`.
+pub(crate) fn new_nested_parse_buffer<'a>(
+    input: &ParseBuffer<'a>,
+    scope: Span,
+    cursor: Cursor<'a>,
+) -> ParseBuffer<'a> {
+    let nested = new_parse_buffer(scope, cursor, input.unexpected);
+    nested.slot.set(Some(get_unexpected(input)));
+    nested
 }
 
-impl Default for Unexpected {
+/// The leftover tokens recorded by the parse buffers into one token buffer.
+///
+/// Instead of every parse buffer owning its own reference counted cell, the
+/// cells live here and parse buffers refer to them by index. The slots are
+/// cleared along with the token buffer they are about, so the allocation is
+/// reused across the streams of `Parser::parse_many`.
+pub(crate) struct UnexpectedSlots {
+    slots: RefCell<Vec<Unexpected>>,
+}
+
+impl Default for UnexpectedSlots {
     fn default() -> Self {
-        Unexpected::None
+        UnexpectedSlots {
+            slots: RefCell::new(Vec::new()),
+        }
     }
 }
 
-impl Clone for Unexpected {
-    fn clone(&self) -> Self {
-        match self {
-            Unexpected::None => Unexpected::None,
-            Unexpected::Some(span) => Unexpected::Some(*span),
-            Unexpected::Chain(next) => Unexpected::Chain(next.clone()),
+impl UnexpectedSlots {
+    fn push(&self) -> usize {
+        let mut slots = self.slots.borrow_mut();
+        slots.push(Unexpected::None);
+        slots.len() - 1
+    }
+
+    fn get(&self, slot: usize) -> Unexpected {
+        self.slots.borrow()[slot]
+    }
+
+    fn set(&self, slot: usize, unexpected: Unexpected) {
+        self.slots.borrow_mut()[slot] = unexpected;
+    }
+
+    // Follows the chain from `slot` to the slot that holds the leftover
+    // tokens, if any.
+    fn resolve(&self, mut slot: usize) -> (usize, Option<Leftover>) {
+        loop {
+            match self.get(slot) {
+                Unexpected::None => return (slot, None),
+                Unexpected::Some(leftover) => return (slot, Some(leftover)),
+                Unexpected::Chain(next) => slot = next,
+            }
         }
     }
+
+    fn clear(&mut self) {
+        self.slots.get_mut().clear();
+    }
+}
+
+pub(crate) enum Unexpected {
+    None,
+    Some(Leftover),
+    Chain(usize),
+}
+
+// Tokens left unparsed at the end of a stream, recorded without building the
+// error message, since most of these are from forks and peeks whose leftover
+// tokens nobody asks about.
+//
+// Same lifetime caveats as `cell` in ParseBuffer. The cursors are only read
+// while parsing the token buffer they are into, as every parse buffer that
+// shares an `UnexpectedSlots` is into the same token buffer.
+pub(crate) struct Leftover {
+    start: Cursor<'static>,
+    rest: Cursor<'static>,
+}
+
+impl Copy for Leftover {}
+
+impl Clone for Leftover {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-// We call this on Cell<Unexpected> and Cell<Option<T>> where temporarily
-// swapping in a None is cheap.
-fn cell_clone<T: Default + Clone>(cell: &Cell<T>) -> T {
-    let prev = cell.take();
-    let ret = prev.clone();
-    cell.set(prev);
-    ret
+impl Leftover {
+    fn error(self) -> Error {
+        unexpected_token(self.start, self.rest)
+    }
 }
 
-fn inner_unexpected(buffer: &ParseBuffer) -> (Rc<Cell<Unexpected>>, Option<Span>) {
-    let mut unexpected = get_unexpected(buffer);
-    loop {
-        match cell_clone(&unexpected) {
-            Unexpected::None => return (unexpected, None),
-            Unexpected::Some(span) => return (unexpected, Some(span)),
-            Unexpected::Chain(next) => unexpected = next,
-        }
+impl Copy for Unexpected {}
+
+impl Clone for Unexpected {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-pub(crate) fn get_unexpected(buffer: &ParseBuffer) -> Rc<Cell<Unexpected>> {
-    cell_clone(&buffer.unexpected).unwrap()
+// The slot of `buffer`, allocating it if this is the first time it is needed.
+pub(crate) fn get_unexpected(buffer: &ParseBuffer) -> usize {
+    match buffer.slot.get() {
+        Some(slot) => slot,
+        None => {
+            let slot = buffer.unexpected.push();
+            buffer.slot.set(Some(slot));
+            slot
+        }
+    }
 }
 
-fn span_of_unexpected_ignoring_nones(mut cursor: Cursor) -> Option<Span> {
+// The first token of the cursor that is not the delimiter of an invisible
+// group, if any.
+fn unexpected_ignoring_nones(mut cursor: Cursor) -> Option<Cursor> {
     if cursor.eof() {
         return None;
     }
     while let Some((inner, _span, rest)) = cursor.group(Delimiter::None) {
-        if let Some(unexpected) = span_of_unexpected_ignoring_nones(inner) {
+        if let Some(unexpected) = unexpected_ignoring_nones(inner) {
             return Some(unexpected);
         }
         cursor = rest;
@@ -457,7 +550,100 @@ fn span_of_unexpected_ignoring_nones(mut cursor: Cursor) -> Option<Span> {
     if cursor.eof() {
         None
     } else {
-        Some(cursor.span())
+        Some(cursor)
+    }
+}
+
+// The error for the tokens starting at `rest` that were left unparsed in the
+// stream starting at `start`. It names the first of them and, if the parsed
+// tokens are separated by commas or semicolons, suggests the one that is
+// likely missing in front of it.
+fn unexpected_token(start: Cursor, rest: Cursor) -> Error {
+    let token = match unexpected_ignoring_nones(rest) {
+        Some(token) => token,
+        None => return Error::new(rest.span(), "unexpected token"),
+    };
+    let mut message = format!("unexpected token `{}`", describe_token(token));
+    if let Some(separator) = missing_separator(start, rest, token) {
+        message.push_str(&format!(", perhaps a `{}` is missing before it", separator));
+    }
+    Error::new(token.span(), message)
+}
+
+fn describe_token(cursor: Cursor) -> String {
+    if let Some((lifetime, _rest)) = cursor.lifetime() {
+        return lifetime.to_string();
+    }
+    if let Some((punct, mut rest)) = cursor.punct() {
+        // The whole operator, like `=>` rather than just `=`.
+        let mut op = punct.as_char().to_string();
+        let mut spacing = punct.spacing();
+        while spacing == Spacing::Joint {
+            match rest.punct() {
+                Some((punct, next)) => {
+                    op.push(punct.as_char());
+                    spacing = punct.spacing();
+                    rest = next;
+                }
+                None => break,
+            }
+        }
+        return op;
+    }
+    match cursor.token_tree() {
+        Some((TokenTree::Group(group), _rest)) => match group.delimiter() {
+            Delimiter::Parenthesis => "(".to_owned(),
+            Delimiter::Brace => "{".to_owned(),
+            Delimiter::Bracket => "[".to_owned(),
+            Delimiter::None => String::new(),
+        },
+        Some((tt, _rest)) => tt.to_string(),
+        None => String::new(),
+    }
+}
+
+fn missing_separator(start: Cursor, rest: Cursor, token: Cursor) -> Option<char> {
+    if token.punct().is_some() {
+        return None;
+    }
+    let mut separator = None;
+    let mut after_separator = false;
+    let mut cursor = start;
+    while cursor < rest {
+        after_separator = match cursor.punct() {
+            Some((punct, _rest)) => match punct.as_char() {
+                ',' | ';' if punct.spacing() == Spacing::Alone => {
+                    separator = Some(punct.as_char());
+                    true
+                }
+                _ => false,
+            },
+            None => false,
+        };
+        cursor = cursor.skip()?;
+    }
+    if after_separator {
+        None
+    } else {
+        separator
+    }
+}
+
+/// A position in a parse stream to go back to, obtained from
+/// [`ParseBuffer::checkpoint`].
+pub struct Checkpoint<'a> {
+    cursor: Cursor<'a>,
+    unexpected: usize,
+    unexpected_state: Unexpected,
+}
+
+impl<'a> Clone for Checkpoint<'a> {
+    fn clone(&self) -> Self {
+        Checkpoint {
+            cursor: self.cursor,
+            unexpected: self.unexpected,
+            unexpected_state: self.unexpected_state,
+        }
     }
 }
 
@@ -465,6 +651,7 @@ impl<'a> ParseBuffer<'a> {
     /// Parses a syntax tree node of type `T`, advancing the position of our
     /// parse stream past it.
     pub fn parse<T: Parse>(&self) -> Result<T> {
+        let _recursion = enter_recursion(self)?;
         T::parse(self)
     }
 
@@ -505,6 +692,7 @@ impl<'a> ParseBuffer<'a> {
     /// }
     /// ```
     pub fn call<T>(&self, function: fn(ParseStream) -> Result<T>) -> Result<T> {
+        let _recursion = enter_recursion(self)?;
         function(self)
     }
 
@@ -703,6 +891,90 @@ impl<'a> ParseBuffer<'a> {
         Punctuated::parse_terminated_with(self, parser)
     }
 
+    /// Parses a syntax tree node of type `T`, recovering from a parse error
+    /// by skipping ahead to the next synchronization token.
+    ///
+    /// On failure the error is added to `errors` with [`Error::combine`], the
+    /// tokens up to but not including the next token matching `sync` are
+    /// skipped, and `None` is returned. The skipped tokens are whole token
+    /// trees, so a `sync` token inside of a delimited group does not stop the
+    /// skipping. If there is no `sync` token, the rest of this stream is
+    /// skipped.
+    ///
+    /// This lets a parser report every independent error in its input instead
+    /// of only the first. Once the input has been consumed, the collected
+    /// error can be returned from the parser, and
+    /// [`Error::to_compile_error`] turns it into one `compile_error!`
+    /// invocation per message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use quote::quote;
+    /// #
+    /// use syn::{Expr, Ident, Result, Token};
+    /// use syn::parse::{Parse, ParseStream, Parser};
+    ///
+    /// struct Assignment {
+    ///     name: Ident,
+    ///     eq_token: Token![=],
+    ///     value: Expr,
+    /// }
+    ///
+    /// impl Parse for Assignment {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         Ok(Assignment {
+    ///             name: input.parse()?,
+    ///             eq_token: input.parse()?,
+    ///             value: input.parse()?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// // Parse a list of assignments like `a = 1; b = 2;`, reporting every
+    /// // malformed assignment.
+    /// fn assignments(input: ParseStream) -> Result<Vec<Assignment>> {
+    ///     let mut assignments = Vec::new();
+    ///     let mut errors = None;
+    ///     while !input.is_empty() {
+    ///         if let Some(assignment) = input.parse_recoverable(Token![;], &mut errors) {
+    ///             assignments.push(assignment);
+    ///         }
+    ///         if !input.is_empty() {
+    ///             input.parse::<Token![;]>()?;
+    ///         }
+    ///     }
+    ///     match errors {
+    ///         Some(errors) => Err(errors),
+    ///         None => Ok(assignments),
+    ///     }
+    /// }
+    ///
+    /// let input = quote!(a = 1; b 2; c = 3; = 4;);
+    /// let errors = assignments.parse2(input).err().unwrap();
+    /// let messages: Vec<String> = errors.into_iter().map(|err| err.to_string()).collect();
+    /// assert_eq!(messages, ["expected `=`", "expected identifier"]);
+    /// ```
+    pub fn parse_recoverable<T: Parse, S: Peek>(
+        &self,
+        sync: S,
+        errors: &mut Option<Error>,
+    ) -> Option<T> {
+        match self.parse::<T>() {
+            Ok(node) => Some(node),
+            Err(error) => {
+                match errors {
+                    Some(errors) => errors.combine(error),
+                    None => *errors = Some(error),
+                }
+                while !self.is_empty() && !self.peek(sync) {
+                    let _ = self.parse::<TokenTree>();
+                }
+                None
+            }
+        }
+    }
+
     /// Returns whether there are tokens remaining in this stream.
     ///
     /// This method returns true at the end of the content of a set of
@@ -919,13 +1191,93 @@ impl<'a> ParseBuffer<'a> {
         ParseBuffer {
             scope: self.scope,
             cell: self.cell.clone(),
+            start: self.start,
             marker: PhantomData,
-            // Not the parent's unexpected. Nothing cares whether the clone
-            // parses all the way unless we `advance_to`.
-            unexpected: Cell::new(Some(Rc::new(Cell::new(Unexpected::None)))),
+            unexpected: self.unexpected,
+            // Not the parent's slot. Nothing cares whether the clone parses
+            // all the way unless we `advance_to`.
+            slot: Cell::new(None),
+        }
+    }
+
+    /// Records the current position of the parse stream, for going back to it
+    /// later with [`rewind`].
+    ///
+    /// [`rewind`]: ParseBuffer::rewind
+    ///
+    /// Together these let a parser written as a sequence of steps on a single
+    /// stream try one alternative and backtrack if it does not work out,
+    /// without forking. Where a parser can instead decide what to parse by
+    /// peeking or by parsing a [`fork`](ParseBuffer::fork), that gives better
+    /// errors and should be preferred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Expr, Ident, Result, Token};
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // Either `name = expr` or just `expr`.
+    /// struct Arg {
+    ///     name: Option<Ident>,
+    ///     value: Expr,
+    /// }
+    ///
+    /// impl Parse for Arg {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let checkpoint = input.checkpoint();
+    ///         if let Ok(name) = input.parse::<Ident>() {
+    ///             if input.parse::<Token![=]>().is_ok() {
+    ///                 let value = input.parse()?;
+    ///                 return Ok(Arg { name: Some(name), value });
+    ///             }
+    ///         }
+    ///         input.rewind(&checkpoint);
+    ///         let value = input.parse()?;
+    ///         Ok(Arg { name: None, value })
+    ///     }
+    /// }
+    /// #
+    /// # let arg: Arg = syn::parse_str("x + y").unwrap();
+    /// # assert!(arg.name.is_none());
+    /// # let arg: Arg = syn::parse_str("x = y").unwrap();
+    /// # assert!(arg.name.is_some());
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint<'a> {
+        let (unexpected, _span) = self.unexpected.resolve(get_unexpected(self));
+        let unexpected_state = self.unexpected.get(unexpected);
+        Checkpoint {
+            cursor: self.cursor(),
+            unexpected,
+            unexpected_state,
         }
     }
 
+    /// Moves the parse stream back (or forward) to a position recorded by
+    /// [`checkpoint`].
+    ///
+    /// [`checkpoint`]: ParseBuffer::checkpoint
+    ///
+    /// Tokens that groups parsed since the checkpoint left unparsed are
+    /// forgotten along with everything else that was parsed, so they do not
+    /// end up reported as unexpected once the stream is done.
+    ///
+    /// # Panics
+    ///
+    /// The checkpoint must have been obtained from this parse stream, or from
+    /// a fork of it. Rewinding to any other checkpoint will cause a panic.
+    pub fn rewind(&self, checkpoint: &Checkpoint<'a>) {
+        if !crate::buffer::same_scope(self.cursor(), checkpoint.cursor) {
+            panic!("Checkpoint was not taken from the rewinding parse stream");
+        }
+        self.unexpected
+            .set(checkpoint.unexpected, checkpoint.unexpected_state);
+
+        // See comment on `cell` in the struct definition.
+        self.cell
+            .set(unsafe { mem::transmute::<Cursor, Cursor<'static>>(checkpoint.cursor) });
+    }
+
     /// Triggers an error at the current position of the parse stream.
     ///
     /// # Example
@@ -1045,6 +1397,80 @@ impl<'a> ParseBuffer<'a> {
         }
     }
 
+    /// Returns a `Span` covering all of the tokens remaining in this parse
+    /// stream, or the same span as [`end_span`] if there are none.
+    ///
+    /// Spans can only be joined when the compiler supports it, which at the
+    /// moment means on a nightly compiler with `procmacro2_semver_exempt`.
+    /// Otherwise this is the span of just the next token.
+    ///
+    /// [`end_span`]: ParseBuffer::end_span
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::Result;
+    /// use syn::parse::ParseStream;
+    ///
+    /// // Reject the rest of the input as a whole rather than pointing at
+    /// // only its first token.
+    /// fn reject_trailing(input: ParseStream) -> Result<()> {
+    ///     if input.is_empty() {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(syn::Error::new(input.remaining_span(), "unexpected trailing tokens"))
+    ///     }
+    /// }
+    /// ```
+    pub fn remaining_span(&self) -> Span {
+        let cursor = self.cursor();
+        if cursor.eof() {
+            return self.end_span();
+        }
+        let first = cursor.span();
+        match crate::buffer::last_span(cursor) {
+            Some(last) => crate::span::join(first, last),
+            None => first,
+        }
+    }
+
+    /// Returns the `Span` at which the input of this parse stream ends.
+    ///
+    /// When parsing the contents of a group, such as inside of
+    /// [`parenthesized!`], this is the span of the group's closing delimiter.
+    /// Otherwise it is the span of the last token of the input, falling back to
+    /// the span that the stream was created with (usually
+    /// `Span::call_site()`) if the input is empty. Use it for errors about
+    /// missing tokens, which would otherwise point at the whole macro
+    /// invocation.
+    ///
+    /// [`parenthesized!`]: crate::parenthesized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Ident, Result, Token};
+    /// use syn::parse::ParseStream;
+    ///
+    /// // Parses `name = value`, where the value must not be left out.
+    /// fn assignment(input: ParseStream) -> Result<(Ident, Ident)> {
+    ///     let name: Ident = input.parse()?;
+    ///     input.parse::<Token![=]>()?;
+    ///     if input.is_empty() {
+    ///         return Err(syn::Error::new(input.end_span(), "expected a value after `=`"));
+    ///     }
+    ///     let value: Ident = input.parse()?;
+    ///     Ok((name, value))
+    /// }
+    /// ```
+    pub fn end_span(&self) -> Span {
+        if crate::buffer::scope_is_group(self.cursor()) {
+            self.scope
+        } else {
+            crate::buffer::last_span(self.start).unwrap_or(self.scope)
+        }
+    }
+
     /// Provides low-level access to the token representation underlying this
     /// parse stream.
     ///
@@ -1054,9 +1480,13 @@ impl<'a> ParseBuffer<'a> {
         self.cell.get()
     }
 
-    fn check_unexpected(&self) -> Result<()> {
-        match inner_unexpected(self).1 {
-            Some(span) => Err(Error::new(span, "unexpected token")),
+    pub(crate) fn check_unexpected(&self) -> Result<()> {
+        let slot = match self.slot.get() {
+            Some(slot) => slot,
+            None => return Ok(()),
+        };
+        match self.unexpected.resolve(slot).1 {
+            Some(leftover) => Err(leftover.error()),
             None => Ok(()),
         }
     }
@@ -1156,10 +1586,7 @@ pub trait Parser: Sized {
     ///
     /// *This method is available only if Syn is built with both the `"parsing"` and
     /// `"proc-macro"` features.*
-    #[cfg(all(
-        not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
-        feature = "proc-macro"
-    ))]
+    #[cfg(all(not(syn_no_proc_macro), feature = "proc-macro"))]
     fn parse(self, tokens: proc_macro::TokenStream) -> Result<Self::Output> {
         self.parse2(proc_macro2::TokenStream::from(tokens))
     }
@@ -1177,9 +1604,45 @@ pub trait Parser: Sized {
         self.parse2(proc_macro2::TokenStream::from_str(s)?)
     }
 
+    /// Parse each of many proc-macro2 token streams into the chosen syntax
+    /// tree node, stopping at the first stream that fails to parse.
+    ///
+    /// This is the same as calling [`parse2`][Parser::parse2] on a clone of
+    /// the parser for each stream, but the parsers for closures and functions
+    /// reuse one token buffer and the rest of the state of the parse for all
+    /// of the streams, which makes a difference when the streams are many and
+    /// small, like the attributes of every field of a large struct.
+    ///
+    /// ```
+    /// use proc_macro2::TokenStream;
+    /// use quote::quote;
+    /// use syn::parse::Parser;
+    /// use syn::punctuated::Punctuated;
+    /// use syn::{Ident, Result, Token};
+    ///
+    /// fn parse_args(args: Vec<TokenStream>) -> Result<usize> {
+    ///     let parser = Punctuated::<Ident, Token![,]>::parse_terminated;
+    ///     let parsed = parser.parse_many(args)?;
+    ///     Ok(parsed.iter().map(Punctuated::len).sum())
+    /// }
+    ///
+    /// let args = vec![quote!(a, b), quote!(c), quote!()];
+    /// assert_eq!(parse_args(args).unwrap(), 3);
+    /// ```
+    fn parse_many<I>(self, streams: I) -> Result<Vec<Self::Output>>
+    where
+        Self: Clone,
+        I: IntoIterator<Item = TokenStream>,
+    {
+        streams
+            .into_iter()
+            .map(|tokens| self.clone().parse2(tokens))
+            .collect()
+    }
+
     // Not public API.
     #[doc(hidden)]
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
     fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Self::Output> {
         let _ = scope;
         self.parse2(tokens)
@@ -1187,16 +1650,18 @@ pub trait Parser: Sized {
 
     // Not public API.
     #[doc(hidden)]
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
     fn __parse_stream(self, input: ParseStream) -> Result<Self::Output> {
         input.parse().and_then(|tokens| self.parse2(tokens))
     }
 }
 
-fn tokens_to_parse_buffer(tokens: &TokenBuffer) -> ParseBuffer {
+pub(crate) fn tokens_to_parse_buffer<'a>(
+    tokens: &'a TokenBuffer,
+    unexpected: &'a UnexpectedSlots,
+) -> ParseBuffer<'a> {
     let scope = Span::call_site();
     let cursor = tokens.begin();
-    let unexpected = Rc::new(Cell::new(Unexpected::None));
     new_parse_buffer(scope, cursor, unexpected)
 }
 
@@ -1208,47 +1673,506 @@ where
 
     fn parse2(self, tokens: TokenStream) -> Result<T> {
         let buf = TokenBuffer::new2(tokens);
-        let state = tokens_to_parse_buffer(&buf);
-        let node = self(&state)?;
-        state.check_unexpected()?;
-        if let Some(unexpected_span) = span_of_unexpected_ignoring_nones(state.cursor()) {
-            Err(Error::new(unexpected_span, "unexpected token"))
-        } else {
-            Ok(node)
+        let unexpected = UnexpectedSlots::default();
+        parse_all(self, Span::call_site(), &buf, &unexpected)
+    }
+
+    fn parse_many<I>(self, streams: I) -> Result<Vec<T>>
+    where
+        Self: Clone,
+        I: IntoIterator<Item = TokenStream>,
+    {
+        let mut buf = TokenBuffer::new2(TokenStream::new());
+        let mut unexpected = UnexpectedSlots::default();
+        let mut nodes = Vec::new();
+        for tokens in streams {
+            // Any leftover tokens recorded by the previous parse point into
+            // the buffer that is about to be refilled.
+            unexpected.clear();
+            buf.reset(tokens);
+            let node = parse_all(self.clone(), Span::call_site(), &buf, &unexpected)?;
+            nodes.push(node);
         }
+        Ok(nodes)
     }
 
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
     fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Self::Output> {
         let buf = TokenBuffer::new2(tokens);
-        let cursor = buf.begin();
-        let unexpected = Rc::new(Cell::new(Unexpected::None));
-        let state = new_parse_buffer(scope, cursor, unexpected);
-        let node = self(&state)?;
-        state.check_unexpected()?;
-        if let Some(unexpected_span) = span_of_unexpected_ignoring_nones(state.cursor()) {
-            Err(Error::new(unexpected_span, "unexpected token"))
-        } else {
-            Ok(node)
-        }
+        let unexpected = UnexpectedSlots::default();
+        parse_all(self, scope, &buf, &unexpected)
     }
 
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
     fn __parse_stream(self, input: ParseStream) -> Result<Self::Output> {
         self(input)
     }
 }
 
-#[cfg(any(feature = "full", feature = "derive"))]
+// Runs `f` on the whole of `buf`, failing if it leaves any tokens unparsed.
+fn parse_all<F, T>(f: F, scope: Span, buf: &TokenBuffer, unexpected: &UnexpectedSlots) -> Result<T>
+where
+    F: FnOnce(ParseStream) -> Result<T>,
+{
+    let state = new_parse_buffer(scope, buf.begin(), unexpected);
+    let node = f(&state)?;
+    state.check_unexpected()?;
+    if unexpected_ignoring_nones(state.cursor()).is_some() {
+        Err(unexpected_token(state.start, state.cursor()))
+    } else {
+        Ok(node)
+    }
+}
+
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub(crate) fn parse_scoped<F: Parser>(f: F, scope: Span, tokens: TokenStream) -> Result<F::Output> {
     f.__parse_scoped(scope, tokens)
 }
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 pub(crate) fn parse_stream<F: Parser>(f: F, input: ParseStream) -> Result<F::Output> {
     f.__parse_stream(input)
 }
 
+/// Limits on the input accepted by a parser, for parsing untrusted code.
+///
+/// A `ParserConfig` is applied to any [`Parser`] with [`ParserConfig::apply`].
+/// Every limit is unset by default.
+///
+/// - The **token count** is the number of token trees in the input, counting
+///   a delimited group as one token in addition to its contents.
+/// - The **nesting** is the depth of delimited groups in the input. With a
+///   maximum nesting of 0 the input may not contain any parentheses, brackets
+///   or braces.
+/// - The **recursion depth** is the number of nested calls to
+///   [`ParseBuffer::parse`], [`ParseBuffer::call`] and Syn's recursive
+///   expression parser that are active at once. This catches input that is
+///   deeply nested without any delimiters, like a long sequence of unary
+///   operators.
+///
+/// The token count and nesting are checked before parsing begins. The error
+/// for an exceeded limit is spanned at the offending token.
+///
+/// A parse can also be abandoned while it runs, by a [`CancellationToken`] or
+/// when a deadline passes. It then fails with an error for which
+/// [`Error::is_cancelled`] returns true.
+///
+/// ```
+/// use syn::parse::{Parse, Parser, ParserConfig};
+/// use syn::Expr;
+///
+/// let config = ParserConfig::new()
+///     .max_tokens(100)
+///     .max_nesting(4)
+///     .max_recursion_depth(64);
+///
+/// let expr = config.apply(Expr::parse).parse_str("(a + b) * c");
+/// assert!(expr.is_ok());
+///
+/// let err = config.apply(Expr::parse).parse_str("((((((x))))))").unwrap_err();
+/// assert_eq!(err.to_string(), "nesting limit exceeded");
+/// ```
+pub struct ParserConfig {
+    max_recursion_depth: Option<usize>,
+    max_tokens: Option<usize>,
+    max_nesting: Option<usize>,
+    cancellation: Option<CancellationToken>,
+    deadline: Option<Instant>,
+}
+
+impl ParserConfig {
+    /// Creates a configuration without any limits.
+    pub fn new() -> Self {
+        ParserConfig {
+            max_recursion_depth: None,
+            max_tokens: None,
+            max_nesting: None,
+            cancellation: None,
+            deadline: None,
+        }
+    }
+
+    /// Sets the maximum recursion depth of the parser.
+    pub fn max_recursion_depth(mut self, depth: usize) -> Self {
+        self.max_recursion_depth = Some(depth);
+        self
+    }
+
+    /// Sets the maximum number of token trees in the input.
+    pub fn max_tokens(mut self, count: usize) -> Self {
+        self.max_tokens = Some(count);
+        self
+    }
+
+    /// Sets the maximum depth of delimited groups in the input.
+    pub fn max_nesting(mut self, depth: usize) -> Self {
+        self.max_nesting = Some(depth);
+        self
+    }
+
+    /// Makes the parser stop as soon as the token is cancelled.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Makes the parser stop once the deadline has passed.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Wraps a parser so that it enforces the limits of this configuration.
+    pub fn apply<P: Parser>(&self, parser: P) -> Limited<P> {
+        Limited {
+            config: self.clone(),
+            parser,
+        }
+    }
+
+    /// Parses the content of a file of Rust code like
+    /// [`syn::parse_file`](crate::parse_file), enforcing the limits of this
+    /// configuration.
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// and `"full"` features.*
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub fn parse_file(&self, content: &str) -> Result<crate::File> {
+        crate::parse_file_with(self.apply(<crate::File as Parse>::parse), content)
+    }
+
+    fn check_tokens(&self, tokens: &TokenStream) -> Result<()> {
+        if self.max_tokens.is_none() && self.max_nesting.is_none() {
+            return Ok(());
+        }
+        let mut count = 0;
+        let mut stack = vec![tokens.clone().into_iter()];
+        while let Some(iter) = stack.last_mut() {
+            let token = match iter.next() {
+                Some(token) => token,
+                None => {
+                    stack.pop();
+                    continue;
+                }
+            };
+            count += 1;
+            if self.max_tokens.map_or(false, |max| count > max) {
+                return Err(Error::new(token.span(), "token limit exceeded"));
+            }
+            if let TokenTree::Group(group) = token {
+                if self.max_nesting.map_or(false, |max| stack.len() > max) {
+                    return Err(Error::new(group.span(), "nesting limit exceeded"));
+                }
+                stack.push(group.stream().into_iter());
+            }
+        }
+        Ok(())
+    }
+
+    fn enter(&self) -> LimitScope {
+        LIMITS.with(|limits| {
+            let mut limits = limits.borrow_mut();
+            let mut next = Limits {
+                depth: match (limits.depth, self.max_recursion_depth) {
+                    (Some(current), Some(max)) => Some(cmp::min(current, max)),
+                    (current, max) => current.or(max),
+                },
+                cancellations: limits.cancellations.clone(),
+                deadline: match (limits.deadline, self.deadline) {
+                    (Some(current), Some(deadline)) => Some(cmp::min(current, deadline)),
+                    (current, deadline) => current.or(deadline),
+                },
+                calls: 0,
+                cancelled: false,
+            };
+            next.cancellations.extend(self.cancellation.clone());
+            let counted = !next.is_unlimited();
+            if counted {
+                LIMITED_SCOPES.fetch_add(1, Ordering::Relaxed);
+            }
+            LimitScope {
+                previous: Some(mem::replace(&mut *limits, next)),
+                counted,
+            }
+        })
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        ParserConfig::new()
+    }
+}
+
+impl Clone for ParserConfig {
+    fn clone(&self) -> Self {
+        ParserConfig {
+            max_recursion_depth: self.max_recursion_depth,
+            max_tokens: self.max_tokens,
+            max_nesting: self.max_nesting,
+            cancellation: self.cancellation.clone(),
+            deadline: self.deadline,
+        }
+    }
+}
+
+impl Debug for ParserConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParserConfig")
+            .field("max_recursion_depth", &self.max_recursion_depth)
+            .field("max_tokens", &self.max_tokens)
+            .field("max_nesting", &self.max_nesting)
+            .field("cancellation", &self.cancellation)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// A flag shared between a parser and another thread that wants to stop it.
+///
+/// Clones of a `CancellationToken` refer to the same flag. Pass one clone to
+/// [`ParserConfig::cancellation`] and call [`cancel`](Self::cancel) on another
+/// to make the parse fail with an error for which [`Error::is_cancelled`]
+/// returns true.
+///
+/// ```
+/// use syn::parse::{CancellationToken, Parse, Parser, ParserConfig};
+/// use syn::DeriveInput;
+///
+/// let token = CancellationToken::new();
+/// let config = ParserConfig::new().cancellation(token.clone());
+///
+/// // For example, a newer request arrived in a language server.
+/// token.cancel();
+///
+/// let parser = config.apply(DeriveInput::parse);
+/// let err = parser.parse_str("struct S;").unwrap_err();
+/// assert!(err.is_cancelled());
+/// ```
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        CancellationToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Cancels every parse using this token or one of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        CancellationToken::new()
+    }
+}
+
+impl Clone for CancellationToken {
+    fn clone(&self) -> Self {
+        CancellationToken {
+            cancelled: Arc::clone(&self.cancelled),
+        }
+    }
+}
+
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// A parser that enforces the limits of a [`ParserConfig`].
+///
+/// This type is returned by [`ParserConfig::apply`].
+pub struct Limited<P> {
+    config: ParserConfig,
+    parser: P,
+}
+
+impl<P: Clone> Clone for Limited<P> {
+    fn clone(&self) -> Self {
+        Limited {
+            config: self.config.clone(),
+            parser: self.parser.clone(),
+        }
+    }
+}
+
+impl<P: Parser> Parser for Limited<P> {
+    type Output = P::Output;
+
+    fn parse2(self, tokens: TokenStream) -> Result<P::Output> {
+        let scope = self.config.enter();
+        scope.check_cancelled()?;
+        self.config.check_tokens(&tokens)?;
+        let result = self.parser.parse2(tokens);
+        scope.finish(result)
+    }
+
+    #[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
+    fn __parse_scoped(self, span: Span, tokens: TokenStream) -> Result<P::Output> {
+        let scope = self.config.enter();
+        scope.check_cancelled()?;
+        self.config.check_tokens(&tokens)?;
+        let result = self.parser.__parse_scoped(span, tokens);
+        scope.finish(result)
+    }
+}
+
+// The limits of the innermost `Limited` parser running on this thread.
+struct Limits {
+    // Number of further levels of recursion allowed, or `None` if unlimited.
+    depth: Option<usize>,
+    cancellations: Vec<CancellationToken>,
+    deadline: Option<Instant>,
+    // Number of recursion checks so far, used to poll the clock only
+    // occasionally.
+    calls: usize,
+    // Whether the parse has been cancelled. Once set, every further recursion
+    // check fails.
+    cancelled: bool,
+}
+
+impl Limits {
+    fn is_unlimited(&self) -> bool {
+        self.depth.is_none() && !self.can_be_cancelled()
+    }
+
+    fn can_be_cancelled(&self) -> bool {
+        !self.cancellations.is_empty() || self.deadline.is_some()
+    }
+
+    // Polls the cancellation tokens and the deadline, if there are any, so a
+    // parse limited only in its depth never reads the clock.
+    fn poll_cancelled(&mut self) -> bool {
+        if !self.cancelled && self.can_be_cancelled() {
+            let poll_clock = self.calls % 64 == 0;
+            self.calls = self.calls.wrapping_add(1);
+            self.cancelled = self
+                .cancellations
+                .iter()
+                .any(CancellationToken::is_cancelled)
+                || poll_clock
+                    && self
+                        .deadline
+                        .map_or(false, |deadline| Instant::now() >= deadline);
+        }
+        self.cancelled
+    }
+}
+
+thread_local! {
+    static LIMITS: RefCell<Limits> = RefCell::new(Limits {
+        depth: None,
+        cancellations: Vec::new(),
+        deadline: None,
+        calls: 0,
+        cancelled: false,
+    });
+}
+
+// Number of `LimitScope`s on any thread whose limits are not unlimited. While
+// it is zero, which is always the case for code that never uses a
+// `ParserConfig`, recursion checks return without touching `LIMITS`.
+static LIMITED_SCOPES: AtomicUsize = AtomicUsize::new(0);
+
+// Restores the limits in effect before a `Limited` parser started.
+struct LimitScope {
+    previous: Option<Limits>,
+    // Whether this scope is counted in `LIMITED_SCOPES`.
+    counted: bool,
+}
+
+impl LimitScope {
+    fn check_cancelled(&self) -> Result<()> {
+        let cancelled = LIMITS.with(|limits| limits.borrow_mut().poll_cancelled());
+        if cancelled {
+            Err(error::new_cancelled(Span::call_site()))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Replaces the result of a parse that was cancelled, because the error
+    // reported by the parser may come from a fallback after a speculative
+    // parse was cancelled.
+    fn finish<T>(self, result: Result<T>) -> Result<T> {
+        let cancelled = LIMITS.with(|limits| limits.borrow().cancelled);
+        match result {
+            Err(err) if err.is_cancelled() => Err(err),
+            _ if cancelled => Err(error::new_cancelled(Span::call_site())),
+            result => result,
+        }
+    }
+}
+
+impl Drop for LimitScope {
+    fn drop(&mut self) {
+        let mut previous = self.previous.take().unwrap();
+        LIMITS.with(|limits| {
+            let mut limits = limits.borrow_mut();
+            previous.cancelled |= limits.cancelled;
+            *limits = previous;
+        });
+        if self.counted {
+            LIMITED_SCOPES.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+// Holds one level of the recursion budget until dropped.
+pub(crate) struct Recursion {
+    active: bool,
+}
+
+pub(crate) fn enter_recursion(input: ParseStream) -> Result<Recursion> {
+    if LIMITED_SCOPES.load(Ordering::Relaxed) == 0 {
+        return Ok(Recursion { active: false });
+    }
+    LIMITS.with(|limits| {
+        let mut limits = limits.borrow_mut();
+        if limits.is_unlimited() {
+            return Ok(Recursion { active: false });
+        }
+        if limits.poll_cancelled() {
+            return Err(error::new_cancelled(input.cursor().span()));
+        }
+        match limits.depth {
+            None => Ok(Recursion { active: false }),
+            Some(0) => Err(input.error("recursion limit exceeded")),
+            Some(depth) => {
+                limits.depth = Some(depth - 1);
+                Ok(Recursion { active: true })
+            }
+        }
+    })
+}
+
+impl Drop for Recursion {
+    fn drop(&mut self) {
+        if self.active {
+            LIMITS.with(|limits| {
+                let mut limits = limits.borrow_mut();
+                limits.depth = limits.depth.map(|depth| depth + 1);
+            });
+        }
+    }
+}
+
 /// An empty syntax tree node that consumes no tokens when parsed.
 ///
 /// This is useful for attribute macros that want to ensure they are not
@@ -1273,7 +2197,7 @@ pub(crate) fn parse_stream<F: Parser>(f: F, input: ParseStream) -> Result<F::Out
 /// ```
 ///
 /// ```text
-/// error: unexpected token
+/// error: unexpected token `asdf`
 ///  --> src/main.rs:3:19
 ///   |
 /// 3 | #[my_attr(asdf)]
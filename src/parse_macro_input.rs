@@ -154,10 +154,10 @@ impl<T: Parse> ParseMacroInput for T {
 ////////////////////////////////////////////////////////////////////////////////
 // Any other types that we want `parse_macro_input!` to be able to parse.
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::AttributeArgs;
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 impl ParseMacroInput for AttributeArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut metas = Vec::new();
@@ -137,12 +137,12 @@ impl<T: Parse> ParseQuote for T {
 // Any other types that we want `parse_quote!` to be able to parse.
 
 use crate::punctuated::Punctuated;
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use crate::{attr, Attribute};
 #[cfg(feature = "full")]
 use crate::{Block, Stmt};
 
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 impl ParseQuote for Attribute {
     fn parse(input: ParseStream) -> Result<Self> {
         if input.peek(Token![#]) && input.peek2(Token![!]) {
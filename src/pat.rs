@@ -308,6 +308,202 @@ ast_struct! {
     }
 }
 
+/// A variable bound by a pattern, as returned by [`Pat::bindings`].
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub struct PatBinding<'a> {
+    /// The name of the variable.
+    pub ident: &'a Ident,
+    /// Whether the variable is bound by reference: `ref x`.
+    pub by_ref: bool,
+    /// Whether the variable is mutable: `mut x`.
+    pub mutable: bool,
+    /// Where the bound value sits within the value matched by the whole
+    /// pattern, outermost step first. This is empty for a pattern like `x`
+    /// or `x @ Some(_)` that binds the whole value.
+    pub path: Vec<PatStep<'a>>,
+}
+
+/// One step from a value matched by a pattern to a part of it bound by a
+/// variable, as found in [`PatBinding::path`].
+///
+/// For example the `y` in `(_, Point { y, .. })` is bound to the part of the
+/// value reached by `Index(1)` followed by `Field(y)`.
+///
+/// *This type is available only if Syn is built with the `"full"` feature.*
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub enum PatStep<'a> {
+    /// A named or numbered field of a struct pattern: `Point { x, .. }`.
+    Field(&'a Member),
+    /// An element of a tuple, tuple struct or slice pattern, counted from 0
+    /// at the start.
+    Index(usize),
+    /// An element after the `..` of a tuple, tuple struct or slice pattern,
+    /// counted from 1 for the last element.
+    IndexFromEnd(usize),
+    /// The elements matched by the `..` of a slice pattern: `[x, rest @ ..]`.
+    Rest,
+    /// The value behind a reference pattern `&x` or a box pattern `box x`.
+    Deref,
+}
+
+impl<'a> Copy for PatStep<'a> {}
+
+impl<'a> Clone for PatStep<'a> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Pat {
+    /// Returns the variables bound by the pattern, in source order.
+    ///
+    /// An identifier pattern without `ref`, `mut` or `@`, other than a field
+    /// shorthand like the `x` in `Point { x, .. }`, that does not start
+    /// with a lowercase letter or an underscore, like `None` or `MAX`, is
+    /// taken to name a unit variant or constant rather than to bind a
+    /// variable. The alternatives of an or-pattern bind the same variables,
+    /// so only those of the first alternative are returned.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Pat, PatStep};
+    ///
+    /// let pat: Pat = parse_quote!((ref key, Some(Entry { mut count, .. })));
+    /// let bindings = pat.bindings();
+    ///
+    /// assert_eq!(bindings[0].ident, "key");
+    /// assert!(bindings[0].by_ref);
+    ///
+    /// assert_eq!(bindings[1].ident, "count");
+    /// assert!(bindings[1].mutable);
+    /// match bindings[1].path.as_slice() {
+    ///     [PatStep::Index(1), PatStep::Index(0), PatStep::Field(field)] => {
+    ///         assert_eq!(quote::quote!(#field).to_string(), "count");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn bindings(&self) -> Vec<PatBinding<'_>> {
+        let mut bindings = Vec::new();
+        collect_bindings(self, &mut Vec::new(), &mut bindings);
+        bindings
+    }
+}
+
+fn collect_bindings<'a>(
+    pat: &'a Pat,
+    path: &mut Vec<PatStep<'a>>,
+    bindings: &mut Vec<PatBinding<'a>>,
+) {
+    match pat {
+        Pat::Ident(pat) => {
+            let is_binding = pat.by_ref.is_some()
+                || pat.mutability.is_some()
+                || pat.subpat.is_some()
+                || is_variable_name(&pat.ident);
+            if is_binding {
+                bindings.push(PatBinding {
+                    ident: &pat.ident,
+                    by_ref: pat.by_ref.is_some(),
+                    mutable: pat.mutability.is_some(),
+                    path: path.clone(),
+                });
+            }
+            if let Some((_at_token, subpat)) = &pat.subpat {
+                collect_bindings(subpat, path, bindings);
+            }
+        }
+        Pat::Box(pat) => collect_deref_bindings(&pat.pat, path, bindings),
+        Pat::Reference(pat) => collect_deref_bindings(&pat.pat, path, bindings),
+        Pat::Or(pat) => {
+            if let Some(case) = pat.cases.first() {
+                collect_bindings(case, path, bindings);
+            }
+        }
+        Pat::Slice(pat) => collect_element_bindings(&pat.elems, path, bindings),
+        Pat::Struct(pat) => {
+            for field in &pat.fields {
+                path.push(PatStep::Field(&field.member));
+                match &*field.pat {
+                    // A field shorthand binds a variable of the field's name,
+                    // whatever its spelling.
+                    Pat::Ident(ident) if field.colon_token.is_none() => {
+                        bindings.push(PatBinding {
+                            ident: &ident.ident,
+                            by_ref: ident.by_ref.is_some(),
+                            mutable: ident.mutability.is_some(),
+                            path: path.clone(),
+                        });
+                    }
+                    _ => collect_bindings(&field.pat, path, bindings),
+                }
+                path.pop();
+            }
+        }
+        Pat::Tuple(pat) => collect_element_bindings(&pat.elems, path, bindings),
+        Pat::TupleStruct(pat) => collect_element_bindings(&pat.pat.elems, path, bindings),
+        Pat::Type(pat) => collect_bindings(&pat.pat, path, bindings),
+        _ => {}
+    }
+}
+
+fn collect_deref_bindings<'a>(
+    pat: &'a Pat,
+    path: &mut Vec<PatStep<'a>>,
+    bindings: &mut Vec<PatBinding<'a>>,
+) {
+    path.push(PatStep::Deref);
+    collect_bindings(pat, path, bindings);
+    path.pop();
+}
+
+fn collect_element_bindings<'a>(
+    elems: &'a Punctuated<Pat, Token![,]>,
+    path: &mut Vec<PatStep<'a>>,
+    bindings: &mut Vec<PatBinding<'a>>,
+) {
+    let rest = elems.iter().position(is_rest);
+    for (i, elem) in elems.iter().enumerate() {
+        let step = match rest {
+            Some(rest) if i == rest => PatStep::Rest,
+            Some(rest) if i > rest => PatStep::IndexFromEnd(elems.len() - i),
+            _ => PatStep::Index(i),
+        };
+        path.push(step);
+        collect_bindings(elem, path, bindings);
+        path.pop();
+    }
+}
+
+// Whether an element pattern is `..`, possibly bound as in `rest @ ..`.
+fn is_rest(pat: &Pat) -> bool {
+    match pat {
+        Pat::Rest(_) => true,
+        Pat::Ident(pat) => match &pat.subpat {
+            Some((_at_token, subpat)) => is_rest(subpat),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+// Whether an identifier is spelled like a variable rather than like a
+// constant, a unit struct or an enum variant.
+fn is_variable_name(ident: &Ident) -> bool {
+    let name = ident.to_string();
+    let name = name.trim_start_matches("r#");
+    match name.chars().next() {
+        Some(ch) => ch == '_' || ch.is_lowercase(),
+        None => false,
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
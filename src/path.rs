@@ -1,12 +1,13 @@
 use super::*;
 use crate::punctuated::Punctuated;
+use std::mem;
 
 ast_struct! {
     /// A path at which a named item is exported (e.g. `std::collections::HashMap`).
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Path {
         pub leading_colon: Option<Token![::]>,
         pub segments: Punctuated<PathSegment, Token![::]>,
@@ -30,9 +31,9 @@ where
 ast_struct! {
     /// A segment of a path together with any path arguments on that segment.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct PathSegment {
         pub ident: Ident,
         pub arguments: PathArguments,
@@ -51,11 +52,41 @@ where
     }
 }
 
+impl PathSegment {
+    /// The angle bracketed arguments of this segment, such as the `<K, V>` in
+    /// `HashMap<K, V>`, if it has any.
+    pub fn args(&self) -> Option<&AngleBracketedGenericArguments> {
+        self.arguments.angle_bracketed()
+    }
+
+    /// The angle bracketed arguments of this segment, which are added as an
+    /// empty `<>` if the segment has no arguments.
+    ///
+    /// Parenthesized arguments are first converted to their angle bracketed
+    /// form, so `Fn(A) -> B` becomes `Fn<(A,), Output = B>`.
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Path};
+    ///
+    /// let mut path: Path = parse_quote!(std::collections::HashMap);
+    /// let segment = path.segments.last_mut().unwrap();
+    /// segment.args_mut().push_type(parse_quote!(String));
+    /// segment.args_mut().push_type(parse_quote!(u64));
+    ///
+    /// let expected = quote!(std::collections::HashMap<String, u64>);
+    /// assert_eq!(quote!(#path).to_string(), expected.to_string());
+    /// ```
+    pub fn args_mut(&mut self) -> &mut AngleBracketedGenericArguments {
+        self.arguments.args_mut()
+    }
+}
+
 ast_enum! {
     /// Angle bracketed or parenthesized arguments of a path segment.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// ## Angle bracketed
     ///
@@ -64,7 +95,7 @@ ast_enum! {
     /// ## Parenthesized
     ///
     /// The `(A, B) -> C` in `Fn(A, B) -> C`.
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum PathArguments {
         None,
         /// The `<'a, T>` in `std::slice::iter<'a, T>`.
@@ -81,6 +112,8 @@ impl Default for PathArguments {
 }
 
 impl PathArguments {
+    /// Whether there are no arguments, either because there are no brackets
+    /// or because the angle brackets are empty.
     pub fn is_empty(&self) -> bool {
         match self {
             PathArguments::None => true,
@@ -89,21 +122,81 @@ impl PathArguments {
         }
     }
 
-    #[cfg(feature = "parsing")]
-    fn is_none(&self) -> bool {
+    pub(crate) fn is_none(&self) -> bool {
         match *self {
             PathArguments::None => true,
             PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => false,
         }
     }
+
+    /// The arguments if they are angle bracketed.
+    pub fn angle_bracketed(&self) -> Option<&AngleBracketedGenericArguments> {
+        match self {
+            PathArguments::AngleBracketed(bracketed) => Some(bracketed),
+            PathArguments::None | PathArguments::Parenthesized(_) => None,
+        }
+    }
+
+    /// The arguments if they are parenthesized.
+    pub fn parenthesized(&self) -> Option<&ParenthesizedGenericArguments> {
+        match self {
+            PathArguments::Parenthesized(parenthesized) => Some(parenthesized),
+            PathArguments::None | PathArguments::AngleBracketed(_) => None,
+        }
+    }
+
+    /// The arguments in angle bracketed form, converting them if needed.
+    ///
+    /// No arguments become an empty `<>`, and parenthesized arguments like
+    /// `(A) -> B` become `<(A,), Output = B>`.
+    pub fn args_mut(&mut self) -> &mut AngleBracketedGenericArguments {
+        let bracketed = match mem::replace(self, PathArguments::None) {
+            PathArguments::None => AngleBracketedGenericArguments::default(),
+            PathArguments::AngleBracketed(bracketed) => bracketed,
+            PathArguments::Parenthesized(parenthesized) => {
+                AngleBracketedGenericArguments::from(parenthesized)
+            }
+        };
+        *self = PathArguments::AngleBracketed(bracketed);
+        match self {
+            PathArguments::AngleBracketed(bracketed) => bracketed,
+            PathArguments::None | PathArguments::Parenthesized(_) => unreachable!(),
+        }
+    }
+
+    /// Converts the arguments to parenthesized form, if they have one.
+    ///
+    /// No arguments become an empty `()`, and angle bracketed arguments like
+    /// `<(A,), Output = B>` become `(A) -> B`. Angle bracketed arguments other
+    /// than a tuple type, optionally followed by an `Output` binding, have no
+    /// parenthesized form; for them `None` is returned and the arguments are
+    /// left unchanged.
+    pub fn parenthesized_mut(&mut self) -> Option<&mut ParenthesizedGenericArguments> {
+        let parenthesized = match mem::replace(self, PathArguments::None) {
+            PathArguments::None => ParenthesizedGenericArguments::default(),
+            PathArguments::AngleBracketed(bracketed) => match bracketed.into_parenthesized() {
+                Ok(parenthesized) => parenthesized,
+                Err(bracketed) => {
+                    *self = PathArguments::AngleBracketed(bracketed);
+                    return None;
+                }
+            },
+            PathArguments::Parenthesized(parenthesized) => parenthesized,
+        };
+        *self = PathArguments::Parenthesized(parenthesized);
+        match self {
+            PathArguments::Parenthesized(parenthesized) => Some(parenthesized),
+            PathArguments::None | PathArguments::AngleBracketed(_) => unreachable!(),
+        }
+    }
 }
 
 ast_enum! {
     /// An individual generic argument, like `'a`, `T`, or `Item = T`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum GenericArgument {
         /// A lifetime argument.
         Lifetime(Lifetime),
@@ -126,9 +219,9 @@ ast_struct! {
     /// Angle bracketed arguments of a path segment: the `<K, V>` in `HashMap<K,
     /// V>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct AngleBracketedGenericArguments {
         pub colon2_token: Option<Token![::]>,
         pub lt_token: Token![<],
@@ -137,12 +230,139 @@ ast_struct! {
     }
 }
 
+/// Empty angle brackets: `<>`.
+impl Default for AngleBracketedGenericArguments {
+    fn default() -> Self {
+        AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: <Token![<]>::default(),
+            args: Punctuated::new(),
+            gt_token: <Token![>]>::default(),
+        }
+    }
+}
+
+impl AngleBracketedGenericArguments {
+    /// Adds a type argument after the other lifetime, type and const
+    /// arguments, and before any bindings and constraints.
+    pub fn push_type(&mut self, ty: Type) {
+        let index = self
+            .args
+            .iter()
+            .take_while(|arg| match arg {
+                GenericArgument::Lifetime(_)
+                | GenericArgument::Type(_)
+                | GenericArgument::Const(_) => true,
+                GenericArgument::Binding(_) | GenericArgument::Constraint(_) => false,
+            })
+            .count();
+        self.args.insert(index, GenericArgument::Type(ty));
+    }
+
+    /// Adds a lifetime argument after the other lifetime arguments, which
+    /// come before all other arguments.
+    pub fn push_lifetime(&mut self, lifetime: Lifetime) {
+        let index = self
+            .args
+            .iter()
+            .take_while(|arg| match arg {
+                GenericArgument::Lifetime(_) => true,
+                _ => false,
+            })
+            .count();
+        self.args.insert(index, GenericArgument::Lifetime(lifetime));
+    }
+
+    /// The type bound to the associated type `ident`, like the `u8` in
+    /// `Iterator<Item = u8>`.
+    pub fn binding<I>(&self, ident: &I) -> Option<&Type>
+    where
+        I: ?Sized,
+        Ident: PartialEq<I>,
+    {
+        self.args.iter().find_map(|arg| match arg {
+            GenericArgument::Binding(binding) if binding.ident == *ident => Some(&binding.ty),
+            _ => None,
+        })
+    }
+
+    /// Binds the associated type `ident` to `ty`, replacing the type it is
+    /// bound to or adding the binding after all other arguments.
+    pub fn set_binding(&mut self, ident: Ident, ty: Type) {
+        for arg in self.args.iter_mut() {
+            if let GenericArgument::Binding(binding) = arg {
+                if binding.ident == ident {
+                    binding.ty = ty;
+                    return;
+                }
+            }
+        }
+        self.args.push(GenericArgument::Binding(Binding {
+            ident,
+            eq_token: <Token![=]>::default(),
+            ty,
+        }));
+    }
+
+    /// Converts the arguments of a function trait, like `<(A,), Output = B>`,
+    /// to their parenthesized form `(A) -> B`.
+    ///
+    /// The arguments have a parenthesized form only if they are a tuple type,
+    /// optionally followed by an `Output` binding. Otherwise they are returned
+    /// unchanged as the error.
+    pub fn into_parenthesized(self) -> std::result::Result<ParenthesizedGenericArguments, Self> {
+        let legal = match self.args.len() {
+            1 => is_tuple(&self.args[0]),
+            2 => is_tuple(&self.args[0]) && is_output(&self.args[1]),
+            _ => false,
+        };
+        if !legal {
+            return Err(self);
+        }
+        let mut args = self.args.into_iter();
+        let mut inputs = match args.next() {
+            Some(GenericArgument::Type(Type::Tuple(tuple))) => tuple,
+            _ => unreachable!(),
+        };
+        let output = match args.next() {
+            Some(GenericArgument::Binding(binding)) => {
+                ReturnType::Type(Token![->](binding.eq_token.spans[0]), Box::new(binding.ty))
+            }
+            _ => ReturnType::Default,
+        };
+        // The trailing comma of a tuple of one type is not needed in the
+        // parenthesized form.
+        if let Some(pair) = inputs.elems.pop() {
+            inputs.elems.push_value(pair.into_value());
+        }
+        Ok(ParenthesizedGenericArguments {
+            paren_token: inputs.paren_token,
+            inputs: inputs.elems,
+            output,
+        })
+    }
+}
+
+fn is_tuple(arg: &GenericArgument) -> bool {
+    match arg {
+        GenericArgument::Type(Type::Tuple(_)) => true,
+        _ => false,
+    }
+}
+
+fn is_output(arg: &GenericArgument) -> bool {
+    match arg {
+        GenericArgument::Binding(binding) => binding.ident == "Output",
+        _ => false,
+    }
+}
+
 ast_struct! {
     /// A binding (equality constraint) on an associated type: `Item = u8`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Binding {
         pub ident: Ident,
         pub eq_token: Token![=],
@@ -153,9 +373,9 @@ ast_struct! {
 ast_struct! {
     /// An associated type bound: `Iterator<Item: Display>`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Constraint {
         pub ident: Ident,
         pub colon_token: Token![:],
@@ -167,9 +387,9 @@ ast_struct! {
     /// Arguments of a function path segment: the `(A, B) -> C` in `Fn(A,B) ->
     /// C`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct ParenthesizedGenericArguments {
         pub paren_token: token::Paren,
         /// `(A, B)`
@@ -179,6 +399,49 @@ ast_struct! {
     }
 }
 
+/// Empty parentheses without a return type: `()`.
+impl Default for ParenthesizedGenericArguments {
+    fn default() -> Self {
+        ParenthesizedGenericArguments {
+            paren_token: token::Paren::default(),
+            inputs: Punctuated::new(),
+            output: ReturnType::Default,
+        }
+    }
+}
+
+/// The angle bracketed form of the arguments of a function trait: `(A) -> B`
+/// becomes `<(A,), Output = B>`.
+impl From<ParenthesizedGenericArguments> for AngleBracketedGenericArguments {
+    fn from(parenthesized: ParenthesizedGenericArguments) -> Self {
+        let span = parenthesized.paren_token.span;
+        let mut elems = parenthesized.inputs;
+        // A tuple of one type needs a trailing comma.
+        if elems.len() == 1 {
+            elems.push_punct(Token![,](span));
+        }
+        let inputs = Type::Tuple(TypeTuple {
+            paren_token: parenthesized.paren_token,
+            elems,
+        });
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(inputs));
+        if let ReturnType::Type(arrow, ty) = parenthesized.output {
+            args.push(GenericArgument::Binding(Binding {
+                ident: Ident::new("Output", arrow.spans[0]),
+                eq_token: Token![=](arrow.spans[0]),
+                ty: *ty,
+            }));
+        }
+        AngleBracketedGenericArguments {
+            colon2_token: None,
+            lt_token: Token![<](span),
+            args,
+            gt_token: Token![>](span),
+        }
+    }
+}
+
 ast_struct! {
     /// The explicit Self type in a qualified path: the `T` in `<T as
     /// Display>::fmt`.
@@ -197,9 +460,9 @@ ast_struct! {
     ///  ty       position = 0
     /// ```
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct QSelf {
         pub lt_token: Token![<],
         pub ty: Box<Type>,
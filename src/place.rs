@@ -0,0 +1,233 @@
+//! Syntactic analysis of place expressions.
+//!
+//! A place expression, also known as an lvalue, is an expression that refers
+//! to a memory location rather than producing a value, like the `v[i].len`
+//! in `v[i].len += 1`. Macros that instrument user code around its borrows
+//! and writes, for example to record which state a function mutates, need to
+//! tell places apart from values, which a plain [`Visit`] does not do for
+//! them. The functions in this module find every expression that is in a
+//! place context:
+//!
+//! - the left-hand side of an assignment `a = b` or of a compound assignment
+//!   `a += b`,
+//! - the operand of a borrow `&a` or `&mut a`,
+//! - the base of an index expression `a[i]` or of a field access `a.f`.
+//!
+//! [`Visit`]: crate::visit::Visit
+//!
+//! The analysis is purely syntactic. The receiver of a method call may be
+//! borrowed implicitly, as in `v.push(x)`, but is only taken to be a place
+//! if it is otherwise in a place context, because whether the method takes
+//! `self` by reference is not known from the syntax. Nested items and the
+//! bodies of macro invocations are not looked into.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"visit"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, Expr};
+//!
+//! let body: syn::Block = parse_quote!({
+//!     state.counts[key] += 1;
+//!     log(&state.name);
+//! });
+//!
+//! // The local variables that the code modifies.
+//! let mutated: Vec<String> = syn::place::places_in_block(&body)
+//!     .into_iter()
+//!     .filter(|place| place.mutable)
+//!     .filter_map(|place| match place.expr {
+//!         Expr::Path(path) => Some(quote!(#path).to_string()),
+//!         _ => None,
+//!     })
+//!     .collect();
+//! assert_eq!(mutated, ["state"]);
+//! ```
+
+use super::*;
+use crate::visit::Visit;
+use std::fmt;
+
+/// The kind of place context that an expression is in.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+pub enum PlaceContext {
+    /// The left-hand side of an assignment: `a = b`.
+    Assign,
+    /// The left-hand side of a compound assignment: `a += b`.
+    CompoundAssign,
+    /// The operand of a shared borrow: `&a`.
+    Borrow,
+    /// The operand of a mutable borrow: `&mut a`.
+    BorrowMut,
+    /// The indexed operand of an index expression: `a[i]`.
+    IndexBase,
+    /// The base of a field access: `a.f`.
+    FieldBase,
+}
+
+/// An expression in a place context, as found by [`places`] and
+/// [`places_in_block`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+pub struct Place<'a> {
+    /// The place expression as written, including any parentheses.
+    pub expr: &'a Expr,
+    /// The context that the expression is in.
+    pub context: PlaceContext,
+    /// Whether the place is written to or mutably borrowed, either directly
+    /// or because it is the base of a field or index expression that is.
+    ///
+    /// For example both `a.b` and `a` are mutable in `a.b[i] = x`, but
+    /// neither is in `f(&a.b[i])`.
+    pub mutable: bool,
+}
+
+/// Returns the expressions in a place context within an expression, in
+/// source order.
+///
+/// The expression itself is not included, since whether it is a place
+/// depends on where it is used. A place with a field or index expression as
+/// its base is listed before the base.
+///
+/// See the [module documentation](self) for the limitations of the analysis.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+pub fn places<'a>(expr: &'a Expr) -> Vec<Place<'a>> {
+    let mut collector = PlaceCollector { places: Vec::new() };
+    collector.visit_expr(expr);
+    collector.places
+}
+
+/// Returns the expressions in a place context within a block, in source
+/// order.
+///
+/// This is the same as [`places`] for a block expression.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+pub fn places_in_block<'a>(block: &'a Block) -> Vec<Place<'a>> {
+    let mut collector = PlaceCollector { places: Vec::new() };
+    collector.visit_block(block);
+    collector.places
+}
+
+struct PlaceCollector<'ast> {
+    places: Vec<Place<'ast>>,
+}
+
+impl<'ast> PlaceCollector<'ast> {
+    // Records a place and continues into it. The base of a field or index
+    // expression in a place context is itself a place of the same
+    // mutability, so it is recorded here rather than by the visitor.
+    fn place(&mut self, expr: &'ast Expr, context: PlaceContext, mutable: bool) {
+        self.places.push(Place {
+            expr,
+            context,
+            mutable,
+        });
+        match expr {
+            Expr::Field(field) => {
+                for attr in &field.attrs {
+                    self.visit_attribute(attr);
+                }
+                self.place(&field.base, PlaceContext::FieldBase, mutable);
+                self.visit_member(&field.member);
+            }
+            Expr::Index(index) => {
+                for attr in &index.attrs {
+                    self.visit_attribute(attr);
+                }
+                self.place(&index.expr, PlaceContext::IndexBase, mutable);
+                self.visit_expr(&index.index);
+            }
+            _ => self.visit_expr(expr),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for PlaceCollector<'ast> {
+    fn visit_expr_assign(&mut self, expr: &'ast ExprAssign) {
+        for attr in &expr.attrs {
+            self.visit_attribute(attr);
+        }
+        self.place(&expr.left, PlaceContext::Assign, true);
+        self.visit_expr(&expr.right);
+    }
+
+    fn visit_expr_assign_op(&mut self, expr: &'ast ExprAssignOp) {
+        for attr in &expr.attrs {
+            self.visit_attribute(attr);
+        }
+        self.place(&expr.left, PlaceContext::CompoundAssign, true);
+        self.visit_expr(&expr.right);
+    }
+
+    fn visit_expr_reference(&mut self, expr: &'ast ExprReference) {
+        for attr in &expr.attrs {
+            self.visit_attribute(attr);
+        }
+        let mutable = expr.mutability.is_some();
+        let context = if mutable {
+            PlaceContext::BorrowMut
+        } else {
+            PlaceContext::Borrow
+        };
+        self.place(&expr.expr, context, mutable);
+    }
+
+    fn visit_expr_index(&mut self, expr: &'ast ExprIndex) {
+        for attr in &expr.attrs {
+            self.visit_attribute(attr);
+        }
+        self.place(&expr.expr, PlaceContext::IndexBase, false);
+        self.visit_expr(&expr.index);
+    }
+
+    fn visit_expr_field(&mut self, expr: &'ast ExprField) {
+        for attr in &expr.attrs {
+            self.visit_attribute(attr);
+        }
+        self.place(&expr.base, PlaceContext::FieldBase, false);
+        self.visit_member(&expr.member);
+    }
+
+    fn visit_item(&mut self, _item: &'ast Item) {}
+
+    fn visit_macro(&mut self, _mac: &'ast Macro) {}
+}
+
+impl Copy for PlaceContext {}
+
+impl Clone for PlaceContext {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Eq for PlaceContext {}
+
+impl PartialEq for PlaceContext {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl fmt::Debug for PlaceContext {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            PlaceContext::Assign => "Assign",
+            PlaceContext::CompoundAssign => "CompoundAssign",
+            PlaceContext::Borrow => "Borrow",
+            PlaceContext::BorrowMut => "BorrowMut",
+            PlaceContext::IndexBase => "IndexBase",
+            PlaceContext::FieldBase => "FieldBase",
+        })
+    }
+}
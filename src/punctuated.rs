@@ -24,7 +24,7 @@
 use std::fmt::{self, Debug};
 #[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
-#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg(any(feature = "full", feature = "derive", feature = "parse-only"))]
 use std::iter;
 use std::iter::FromIterator;
 use std::ops::{Index, IndexMut};
@@ -310,6 +310,88 @@ impl<T, P> Punctuated<T, P> {
         Ok(punctuated)
     }
 
+    /// Parses zero or more occurrences of `T` separated by punctuation of type
+    /// `P`, with optional trailing punctuation, up to a terminator.
+    ///
+    /// Parsing stops without consuming anything further as soon as
+    /// `is_terminator` returns true in a position where a `T` could begin, or
+    /// at the end of the stream. This suits a list that is followed by more
+    /// input in the same stream, such as the bounds of a header that precede
+    /// its body.
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Ident, Result, Token};
+    /// use syn::parse::ParseStream;
+    /// use syn::punctuated::Punctuated;
+    ///
+    /// // Parses `uses a, b, c; rest`, leaving `rest` in the stream.
+    /// fn uses(input: ParseStream) -> Result<Punctuated<Ident, Token![,]>> {
+    ///     input.parse::<Ident>()?;
+    ///     let names = Punctuated::parse_separated_until(input, |input| input.peek(Token![;]))?;
+    ///     input.parse::<Token![;]>()?;
+    ///     Ok(names)
+    /// }
+    /// #
+    /// # use syn::parse::Parser;
+    /// # let names = uses.parse_str("uses a, b, c,;").unwrap();
+    /// # assert_eq!(names.len(), 3);
+    /// ```
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_separated_until(
+        input: ParseStream,
+        is_terminator: fn(ParseStream) -> bool,
+    ) -> Result<Self>
+    where
+        T: Parse,
+        P: Parse,
+    {
+        Self::parse_separated_until_with(input, T::parse, is_terminator)
+    }
+
+    /// Parses zero or more occurrences of `T` using the given parse function,
+    /// separated by punctuation of type `P`, with optional trailing
+    /// punctuation, up to a terminator.
+    ///
+    /// Like [`parse_separated_until`], the terminator is not consumed.
+    ///
+    /// [`parse_separated_until`]: Punctuated::parse_separated_until
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_separated_until_with(
+        input: ParseStream,
+        parser: fn(ParseStream) -> Result<T>,
+        is_terminator: fn(ParseStream) -> bool,
+    ) -> Result<Self>
+    where
+        P: Parse,
+    {
+        let mut punctuated = Punctuated::new();
+
+        loop {
+            if input.is_empty() || is_terminator(input) {
+                break;
+            }
+            let value = parser(input)?;
+            punctuated.push_value(value);
+            if input.is_empty() || is_terminator(input) {
+                break;
+            }
+            let punct = input.parse()?;
+            punctuated.push_punct(punct);
+        }
+
+        Ok(punctuated)
+    }
+
     /// Parses one or more occurrences of `T` separated by punctuation of type
     /// `P`, not accepting trailing punctuation.
     ///
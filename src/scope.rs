@@ -0,0 +1,845 @@
+//! Syntactic analysis of local variable scopes.
+//!
+//! Procedural macros that splice user code together with code of their own
+//! have no way to make the two use separate namespaces for local variables,
+//! other than [`Span::mixed_site()`] which only works for identifiers that
+//! the macro creates itself. The functions in this module follow the block
+//! structure of Rust code to work out which local variable each identifier
+//! refers to, so that a macro can rename the user's bindings out of the way
+//! of its own with [`rename_locals`], or find out which variables of the
+//! surrounding code a block of user code uses with [`free_variables`].
+//!
+//! [`Span::mixed_site()`]: proc_macro2::Span::mixed_site
+//!
+//! The analysis is purely syntactic and has the following limitations:
+//!
+//! - Nested items such as functions or constants defined inside of a block
+//!   are not looked into, as they cannot refer to the block's local
+//!   variables anyway.
+//! - An identifier pattern is only taken to be a binding if it starts with a
+//!   lowercase letter or an underscore or has `ref`, `mut` or `@`, because
+//!   a pattern like `None` or `MAX` names a constant or a unit variant.
+//! - The body of a macro invocation is treated as a plain sequence of tokens
+//!   in which every identifier not preceded by a `.` may refer to a local
+//!   variable. Identifiers that a macro captures from inside of a string
+//!   literal, such as the `x` in `println!("{x}")`, are not seen.
+//!
+//! *This module is available only if Syn is built with the `"full"`
+//! feature.*
+
+use super::*;
+#[cfg(feature = "visit")]
+use crate::punctuated::Punctuated;
+#[cfg(feature = "visit")]
+use crate::visit::Visit;
+#[cfg(feature = "visit-mut")]
+use crate::visit_mut::{self, VisitMut};
+#[cfg(feature = "visit-mut")]
+use proc_macro2::Group;
+#[cfg(feature = "visit")]
+use proc_macro2::Spacing;
+#[cfg(any(feature = "visit", feature = "visit-mut"))]
+use proc_macro2::{TokenStream, TokenTree};
+#[cfg(any(feature = "visit", feature = "visit-mut"))]
+use std::collections::HashSet;
+#[cfg(feature = "visit-mut")]
+use std::iter::FromIterator;
+#[cfg(feature = "visit-mut")]
+use std::mem;
+
+/// A binding renamed by [`rename_locals`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+#[cfg(feature = "visit-mut")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit-mut"))))]
+pub struct Renaming {
+    /// The identifier of the binding as originally written.
+    pub original: Ident,
+    /// The identifier the binding and its uses were renamed to.
+    pub renamed: Ident,
+}
+
+/// Renames the local variables bound inside of a block whose name is one of
+/// `reserved`, together with every use of them.
+///
+/// A macro that inserts its own code into a user's block, or the user's block
+/// into its own code, can pass the names of its own local variables as
+/// `reserved` to make sure that neither shadows the other. Each binding of a
+/// reserved name gets a fresh name of the form `name_1`, `name_2`, and so
+/// on, that does not occur anywhere in the block or in `reserved`.
+///
+/// Returns one [`Renaming`] per renamed binding, in source order. Alternatives
+/// of an or-pattern like `Ok(x) | Err(x)` bind the same variable and are
+/// reported once.
+///
+/// Bindings of the block that are not reserved and variables defined outside
+/// of the block are left alone, as are fields, methods and paths that happen
+/// to have a reserved name. A struct expression or pattern using the field
+/// shorthand, like `Point { x }`, is expanded to `Point { x: x_1 }`.
+///
+/// See the [module documentation](self) for the limitations of the analysis.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::{format_ident, quote};
+/// use syn::{parse_quote, Block};
+///
+/// let mut user: Block = parse_quote!({
+///     let start = compute();
+///     start.max(limit)
+/// });
+///
+/// // The macro wants to time the user's block in a variable called `start`.
+/// let start = format_ident!("start");
+/// let renamings = syn::scope::rename_locals(&mut user, &[start.clone()]);
+/// assert_eq!(renamings[0].renamed, "start_1");
+///
+/// let expected = quote!({
+///     let start_1 = compute();
+///     start_1.max(limit)
+/// });
+/// assert_eq!(quote!(#user).to_string(), expected.to_string());
+///
+/// let instrumented = quote! {{
+///     let #start = Instant::now();
+///     let result = #user;
+///     report(#start.elapsed());
+///     result
+/// }};
+/// # let _ = instrumented;
+/// ```
+#[cfg(feature = "visit-mut")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit-mut"))))]
+pub fn rename_locals(block: &mut Block, reserved: &[Ident]) -> Vec<Renaming> {
+    let mut names = NameCollector(HashSet::new());
+    names.visit_block_mut(block);
+    let mut taken = names.0;
+    taken.extend(reserved.iter().map(Ident::to_string));
+
+    let mut renamer = AlphaRenamer {
+        reserved: reserved.iter().map(Ident::to_string).collect(),
+        taken,
+        scopes: Vec::new(),
+        renamings: Vec::new(),
+    };
+    renamer.visit_block_mut(block);
+    renamer.renamings
+}
+
+/// The variables and paths that an expression or block refers to without
+/// defining them, as computed by [`free_variables`] and
+/// [`free_variables_in_block`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub struct FreeVariables {
+    /// Identifiers used like a local variable without being bound by the
+    /// code, such as `x` in `x + 1` or `self` in `self.len()`, in the order
+    /// of their first use.
+    ///
+    /// The function in a call like `f(x)` is listed as well, since it may
+    /// just as well be a closure stored in a local variable.
+    pub locals: Vec<Ident>,
+    /// Paths referring to something other than a local variable, such as
+    /// `Vec::new` in `Vec::new()`, `Some` in the pattern `Some(x)` or `MAX`
+    /// in `x < MAX`, in the order of their first use and with any generic
+    /// arguments removed.
+    ///
+    /// Paths that start with the name of an item defined inside of the code
+    /// are not listed. Neither are paths inside of types, like the `u8` in
+    /// `x as u8`, or the paths of macro invocations.
+    pub paths: Vec<Path>,
+}
+
+#[cfg(feature = "visit")]
+impl FreeVariables {
+    /// Whether the code uses a local variable of the given name without
+    /// binding it.
+    pub fn contains_local<I>(&self, name: &I) -> bool
+    where
+        I: ?Sized,
+        Ident: PartialEq<I>,
+    {
+        self.locals.iter().any(|local| local == name)
+    }
+}
+
+/// Returns the variables that an expression uses from its surroundings.
+///
+/// Macros that move a piece of user code into a closure, a separate function
+/// or another thread need to know which local variables of the surrounding
+/// code it refers to in order to capture or pass them explicitly.
+///
+/// See the [module documentation](self) for the limitations of the analysis.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, Expr};
+///
+/// let body: Expr = parse_quote! {
+///     items.iter().map(|item| item.weight * factor).sum::<u64>() + Offset::DEFAULT
+/// };
+///
+/// let free = syn::scope::free_variables(&body);
+/// let locals = &free.locals;
+/// assert_eq!(quote!(#(#locals),*).to_string(), "items , factor");
+///
+/// // Hand the captured variables to the closure explicitly.
+/// let lifted = quote! {
+///     spawn((#(#locals,)*), move |(#(#locals,)*)| #body)
+/// };
+/// # let _ = lifted;
+/// ```
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub fn free_variables(expr: &Expr) -> FreeVariables {
+    let mut collector = FreeVariableCollector::new();
+    collector.visit_expr(expr);
+    collector.free
+}
+
+/// Returns the variables that a block uses from its surroundings.
+///
+/// This is the same as [`free_variables`] for a block expression.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit"` features.*
+#[cfg(feature = "visit")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit"))))]
+pub fn free_variables_in_block(block: &Block) -> FreeVariables {
+    let mut collector = FreeVariableCollector::new();
+    collector.visit_block(block);
+    collector.free
+}
+
+// Whether an identifier is spelled like a local variable rather than like a
+// constant, a unit struct or an enum variant.
+#[cfg(any(feature = "visit", feature = "visit-mut"))]
+fn is_variable_name(ident: &Ident) -> bool {
+    let name = ident.to_string();
+    let name = name.trim_start_matches("r#");
+    match name.chars().next() {
+        Some(ch) => ch == '_' || ch.is_lowercase(),
+        None => false,
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+struct NameCollector(HashSet<String>);
+
+#[cfg(feature = "visit-mut")]
+impl VisitMut for NameCollector {
+    fn visit_ident_mut(&mut self, ident: &mut Ident) {
+        self.0.insert(ident.to_string());
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        visit_mut::visit_macro_mut(self, mac);
+        collect_token_idents(mac.tokens.clone(), &mut self.0);
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+fn collect_token_idents(tokens: TokenStream, names: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_token_idents(group.stream(), names),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+struct AlphaRenamer {
+    reserved: HashSet<String>,
+    taken: HashSet<String>,
+    // Renamed bindings in scope, innermost scope last.
+    scopes: Vec<Vec<(String, Ident)>>,
+    renamings: Vec<Renaming>,
+}
+
+#[cfg(feature = "visit-mut")]
+impl AlphaRenamer {
+    fn lookup(&self, ident: &Ident) -> Option<&Ident> {
+        let name = ident.to_string();
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(original, _renamed)| *original == name)
+            .map(|(_original, renamed)| renamed)
+    }
+
+    fn in_scope<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        self.scopes.push(Vec::new());
+        f(self);
+        self.scopes.pop();
+    }
+
+    // Renames the reserved bindings of a pattern and brings them into scope.
+    fn bind(&mut self, pat: &mut Pat) {
+        let mut bindings = Vec::new();
+        self.bind_pat(pat, &mut bindings);
+        if self.scopes.is_empty() {
+            self.scopes.push(Vec::new());
+        }
+        self.scopes.last_mut().unwrap().extend(bindings);
+    }
+
+    fn bind_pat(&mut self, pat: &mut Pat, bindings: &mut Vec<(String, Ident)>) {
+        match pat {
+            Pat::Ident(pat) => {
+                if let Some((_at_token, subpat)) = &mut pat.subpat {
+                    self.bind_pat(subpat, bindings);
+                }
+                let is_binding = pat.by_ref.is_some()
+                    || pat.mutability.is_some()
+                    || pat.subpat.is_some()
+                    || is_variable_name(&pat.ident);
+                if is_binding {
+                    self.bind_ident(&mut pat.ident, bindings);
+                }
+            }
+            Pat::Box(pat) => self.bind_pat(&mut pat.pat, bindings),
+            Pat::Or(pat) => {
+                for case in &mut pat.cases {
+                    self.bind_pat(case, bindings);
+                }
+            }
+            Pat::Reference(pat) => self.bind_pat(&mut pat.pat, bindings),
+            Pat::Slice(pat) => {
+                for elem in &mut pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::Struct(pat) => {
+                for field in &mut pat.fields {
+                    let shorthand = field.colon_token.is_none();
+                    self.bind_pat(&mut field.pat, bindings);
+                    if shorthand {
+                        if let (Member::Named(member), Pat::Ident(pat)) =
+                            (&field.member, &*field.pat)
+                        {
+                            if *member != pat.ident {
+                                field.colon_token = Some(Token![:](member.span()));
+                            }
+                        }
+                    }
+                }
+            }
+            Pat::Tuple(pat) => {
+                for elem in &mut pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::TupleStruct(pat) => {
+                for elem in &mut pat.pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::Type(pat) => self.bind_pat(&mut pat.pat, bindings),
+            _ => {}
+        }
+    }
+
+    fn bind_ident(&mut self, ident: &mut Ident, bindings: &mut Vec<(String, Ident)>) {
+        let name = ident.to_string();
+        if !self.reserved.contains(&name) {
+            return;
+        }
+        // Alternatives of an or-pattern share their bindings.
+        if let Some((_original, renamed)) = bindings.iter().find(|(original, _)| *original == name)
+        {
+            *ident = Ident::new(&renamed.to_string(), ident.span());
+            return;
+        }
+        let base = name.trim_start_matches("r#");
+        let mut n = 1;
+        let fresh = loop {
+            let candidate = format!("{}_{}", base, n);
+            if !self.taken.contains(&candidate) {
+                break candidate;
+            }
+            n += 1;
+        };
+        self.taken.insert(fresh.clone());
+        let renamed = Ident::new(&fresh, ident.span());
+        self.renamings.push(Renaming {
+            original: ident.clone(),
+            renamed: renamed.clone(),
+        });
+        bindings.push((name, renamed.clone()));
+        *ident = renamed;
+    }
+
+    fn rename_tokens(&self, tokens: TokenStream) -> TokenStream {
+        let mut after_dot = false;
+        TokenStream::from_iter(tokens.into_iter().map(|tt| {
+            let tt = match tt {
+                TokenTree::Ident(ident) if !after_dot => match self.lookup(&ident) {
+                    Some(renamed) => {
+                        TokenTree::Ident(Ident::new(&renamed.to_string(), ident.span()))
+                    }
+                    None => TokenTree::Ident(ident),
+                },
+                TokenTree::Group(group) => {
+                    let mut renamed =
+                        Group::new(group.delimiter(), self.rename_tokens(group.stream()));
+                    renamed.set_span(group.span());
+                    TokenTree::Group(renamed)
+                }
+                tt => tt,
+            };
+            after_dot = match &tt {
+                TokenTree::Punct(punct) => punct.as_char() == '.',
+                _ => false,
+            };
+            tt
+        }))
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+impl VisitMut for AlphaRenamer {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        self.in_scope(|this| {
+            for stmt in &mut block.stmts {
+                this.visit_stmt_mut(stmt);
+            }
+        });
+    }
+
+    fn visit_local_mut(&mut self, local: &mut Local) {
+        for attr in &mut local.attrs {
+            self.visit_attribute_mut(attr);
+        }
+        // The initializer is evaluated before the new bindings come into
+        // scope: `let x = x + 1`.
+        if let Some((_eq_token, init)) = &mut local.init {
+            self.visit_expr_mut(init);
+        }
+        self.bind(&mut local.pat);
+    }
+
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        self.in_scope(|this| {
+            for input in &mut closure.inputs {
+                this.bind(input);
+            }
+            this.visit_expr_mut(&mut closure.body);
+        });
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        self.in_scope(|this| {
+            this.bind(&mut arm.pat);
+            if let Some((_if_token, guard)) = &mut arm.guard {
+                this.visit_expr_mut(guard);
+            }
+            this.visit_expr_mut(&mut arm.body);
+        });
+    }
+
+    fn visit_expr_for_loop_mut(&mut self, expr: &mut ExprForLoop) {
+        self.visit_expr_mut(&mut expr.expr);
+        self.in_scope(|this| {
+            this.bind(&mut expr.pat);
+            this.visit_block_mut(&mut expr.body);
+        });
+    }
+
+    fn visit_expr_if_mut(&mut self, expr: &mut ExprIf) {
+        // Bindings of `if let` are in scope in the then-branch only.
+        self.in_scope(|this| {
+            this.visit_expr_mut(&mut expr.cond);
+            this.visit_block_mut(&mut expr.then_branch);
+        });
+        if let Some((_else_token, else_branch)) = &mut expr.else_branch {
+            self.visit_expr_mut(else_branch);
+        }
+    }
+
+    fn visit_expr_while_mut(&mut self, expr: &mut ExprWhile) {
+        self.in_scope(|this| {
+            this.visit_expr_mut(&mut expr.cond);
+            this.visit_block_mut(&mut expr.body);
+        });
+    }
+
+    fn visit_expr_let_mut(&mut self, expr: &mut ExprLet) {
+        self.visit_expr_mut(&mut expr.expr);
+        self.bind(&mut expr.pat);
+    }
+
+    fn visit_expr_path_mut(&mut self, expr: &mut ExprPath) {
+        if expr.qself.is_none()
+            && expr.path.leading_colon.is_none()
+            && expr.path.segments.len() == 1
+        {
+            let segment = &mut expr.path.segments[0];
+            if segment.arguments.is_empty() {
+                if let Some(renamed) = self.lookup(&segment.ident) {
+                    segment.ident = Ident::new(&renamed.to_string(), segment.ident.span());
+                }
+            }
+        }
+    }
+
+    fn visit_field_value_mut(&mut self, field: &mut FieldValue) {
+        if field.colon_token.is_none() {
+            if let Member::Named(member) = &field.member {
+                if self.lookup(member).is_some() {
+                    field.colon_token = Some(Token![:](member.span()));
+                }
+            }
+        }
+        self.visit_expr_mut(&mut field.expr);
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        let tokens = mem::replace(&mut mac.tokens, TokenStream::new());
+        mac.tokens = self.rename_tokens(tokens);
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {
+        // Nested items cannot refer to the local variables of the block.
+    }
+}
+
+#[cfg(feature = "visit")]
+struct FreeVariableCollector {
+    // Names of bindings and items in scope, innermost scope last.
+    scopes: Vec<Vec<String>>,
+    seen_paths: HashSet<String>,
+    free: FreeVariables,
+}
+
+#[cfg(feature = "visit")]
+impl FreeVariableCollector {
+    fn new() -> Self {
+        FreeVariableCollector {
+            scopes: Vec::new(),
+            seen_paths: HashSet::new(),
+            free: FreeVariables {
+                locals: Vec::new(),
+                paths: Vec::new(),
+            },
+        }
+    }
+
+    fn is_bound(&self, ident: &Ident) -> bool {
+        let name = ident.to_string();
+        self.scopes.iter().any(|scope| scope.contains(&name))
+    }
+
+    fn in_scope<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        self.scopes.push(Vec::new());
+        f(self);
+        self.scopes.pop();
+    }
+
+    fn use_local(&mut self, ident: &Ident) {
+        if !self.is_bound(ident) && !self.free.contains_local(ident) {
+            self.free.locals.push(ident.clone());
+        }
+    }
+
+    fn use_path(&mut self, path: &Path) {
+        let first = &path.segments[0].ident;
+        if path.leading_colon.is_none() && self.is_bound(first) {
+            return;
+        }
+        let mut key = String::new();
+        let mut stripped = Path {
+            leading_colon: path
+                .leading_colon
+                .as_ref()
+                .map(|colon| Token![::](colon.spans)),
+            segments: Punctuated::new(),
+        };
+        if path.leading_colon.is_some() {
+            key.push_str("::");
+        }
+        for pair in path.segments.pairs() {
+            let segment = pair.value();
+            key.push_str(&segment.ident.to_string());
+            stripped
+                .segments
+                .push_value(PathSegment::from(segment.ident.clone()));
+            if let Some(colon) = pair.punct() {
+                key.push_str("::");
+                stripped.segments.push_punct(Token![::](colon.spans));
+            }
+        }
+        if self.seen_paths.insert(key) {
+            self.free.paths.push(stripped);
+        }
+    }
+
+    fn bind(&mut self, pat: &Pat) {
+        let mut bindings = Vec::new();
+        self.bind_pat(pat, &mut bindings);
+        if self.scopes.is_empty() {
+            self.scopes.push(Vec::new());
+        }
+        self.scopes.last_mut().unwrap().extend(bindings);
+    }
+
+    fn bind_pat(&mut self, pat: &Pat, bindings: &mut Vec<String>) {
+        match pat {
+            Pat::Ident(pat) => {
+                if let Some((_at_token, subpat)) = &pat.subpat {
+                    self.bind_pat(subpat, bindings);
+                }
+                let is_binding = pat.by_ref.is_some()
+                    || pat.mutability.is_some()
+                    || pat.subpat.is_some()
+                    || is_variable_name(&pat.ident);
+                if is_binding {
+                    bindings.push(pat.ident.to_string());
+                } else {
+                    self.use_path(&Path::from(pat.ident.clone()));
+                }
+            }
+            Pat::Box(pat) => self.bind_pat(&pat.pat, bindings),
+            Pat::Lit(pat) => self.visit_expr(&pat.expr),
+            Pat::Macro(pat) => self.visit_macro(&pat.mac),
+            Pat::Or(pat) => {
+                for case in &pat.cases {
+                    self.bind_pat(case, bindings);
+                }
+            }
+            Pat::Path(pat) => self.use_path(&pat.path),
+            Pat::Range(pat) => {
+                self.visit_expr(&pat.lo);
+                self.visit_expr(&pat.hi);
+            }
+            Pat::Reference(pat) => self.bind_pat(&pat.pat, bindings),
+            Pat::Slice(pat) => {
+                for elem in &pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::Struct(pat) => {
+                self.use_path(&pat.path);
+                for field in &pat.fields {
+                    self.bind_pat(&field.pat, bindings);
+                }
+            }
+            Pat::Tuple(pat) => {
+                for elem in &pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::TupleStruct(pat) => {
+                self.use_path(&pat.path);
+                for elem in &pat.pat.elems {
+                    self.bind_pat(elem, bindings);
+                }
+            }
+            Pat::Type(pat) => self.bind_pat(&pat.pat, bindings),
+            _ => {}
+        }
+    }
+
+    fn use_tokens(&mut self, tokens: TokenStream) {
+        let mut tokens = tokens.into_iter().peekable();
+        // Whether the previous token was a `.` or the end of a `::`.
+        let mut after_separator = false;
+        let mut after_joint_colon = false;
+        while let Some(tt) = tokens.next() {
+            match &tt {
+                TokenTree::Ident(ident) => {
+                    let before_separator = match tokens.peek() {
+                        Some(TokenTree::Punct(punct)) => {
+                            punct.as_char() == '!'
+                                || punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                        }
+                        _ => false,
+                    };
+                    let is_variable = (ident == "self" || ident::accept_as_ident(ident))
+                        && is_variable_name(ident);
+                    if !after_separator && !before_separator && is_variable {
+                        self.use_local(ident);
+                    }
+                }
+                TokenTree::Group(group) => self.use_tokens(group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+            }
+            let (separator, joint_colon) = match &tt {
+                TokenTree::Punct(punct) => match punct.as_char() {
+                    '.' => (true, false),
+                    ':' => (after_joint_colon, punct.spacing() == Spacing::Joint),
+                    _ => (false, false),
+                },
+                _ => (false, false),
+            };
+            after_separator = separator;
+            after_joint_colon = joint_colon;
+        }
+    }
+}
+
+#[cfg(feature = "visit")]
+impl<'ast> Visit<'ast> for FreeVariableCollector {
+    fn visit_block(&mut self, block: &'ast Block) {
+        self.in_scope(|this| {
+            // Items are in scope throughout the block they are defined in.
+            for stmt in &block.stmts {
+                if let Stmt::Item(item) = stmt {
+                    item_names(item, this.scopes.last_mut().unwrap());
+                }
+            }
+            for stmt in &block.stmts {
+                this.visit_stmt(stmt);
+            }
+        });
+    }
+
+    fn visit_local(&mut self, local: &'ast Local) {
+        for attr in &local.attrs {
+            self.visit_attribute(attr);
+        }
+        if let Some((_eq_token, init)) = &local.init {
+            self.visit_expr(init);
+        }
+        self.bind(&local.pat);
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        self.in_scope(|this| {
+            for input in &closure.inputs {
+                this.bind(input);
+            }
+            this.visit_expr(&closure.body);
+        });
+    }
+
+    fn visit_arm(&mut self, arm: &'ast Arm) {
+        self.in_scope(|this| {
+            this.bind(&arm.pat);
+            if let Some((_if_token, guard)) = &arm.guard {
+                this.visit_expr(guard);
+            }
+            this.visit_expr(&arm.body);
+        });
+    }
+
+    fn visit_expr_for_loop(&mut self, expr: &'ast ExprForLoop) {
+        self.visit_expr(&expr.expr);
+        self.in_scope(|this| {
+            this.bind(&expr.pat);
+            this.visit_block(&expr.body);
+        });
+    }
+
+    fn visit_expr_if(&mut self, expr: &'ast ExprIf) {
+        self.in_scope(|this| {
+            this.visit_expr(&expr.cond);
+            this.visit_block(&expr.then_branch);
+        });
+        if let Some((_else_token, else_branch)) = &expr.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_while(&mut self, expr: &'ast ExprWhile) {
+        self.in_scope(|this| {
+            this.visit_expr(&expr.cond);
+            this.visit_block(&expr.body);
+        });
+    }
+
+    fn visit_expr_let(&mut self, expr: &'ast ExprLet) {
+        self.visit_expr(&expr.expr);
+        self.bind(&expr.pat);
+    }
+
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        let path = &expr.path;
+        let is_local = expr.qself.is_none()
+            && path.leading_colon.is_none()
+            && path.segments.len() == 1
+            && path.segments[0].arguments.is_empty()
+            && is_variable_name(&path.segments[0].ident);
+        if is_local {
+            self.use_local(&path.segments[0].ident);
+        } else {
+            self.use_path(path);
+        }
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast ExprStruct) {
+        self.use_path(&expr.path);
+        for field in &expr.fields {
+            self.visit_expr(&field.expr);
+        }
+        if let Some(rest) = &expr.rest {
+            self.visit_expr(rest);
+        }
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        self.use_tokens(mac.tokens.clone());
+    }
+
+    fn visit_type(&mut self, _ty: &'ast Type) {}
+
+    fn visit_item(&mut self, _item: &'ast Item) {}
+}
+
+#[cfg(feature = "visit")]
+fn item_names(item: &Item, names: &mut Vec<String>) {
+    let ident = match item {
+        Item::Const(item) => &item.ident,
+        Item::Enum(item) => &item.ident,
+        Item::ExternCrate(item) => match &item.rename {
+            Some((_as_token, rename)) => rename,
+            None => &item.ident,
+        },
+        Item::Fn(item) => &item.sig.ident,
+        Item::Macro(ItemMacro {
+            ident: Some(ident), ..
+        }) => ident,
+        Item::Mod(item) => &item.ident,
+        Item::Static(item) => &item.ident,
+        Item::Struct(item) => &item.ident,
+        Item::Trait(item) => &item.ident,
+        Item::TraitAlias(item) => &item.ident,
+        Item::Type(item) => &item.ident,
+        Item::Union(item) => &item.ident,
+        Item::Use(item) => return use_tree_names(&item.tree, names),
+        _ => return,
+    };
+    names.push(ident.to_string());
+}
+
+#[cfg(feature = "visit")]
+fn use_tree_names(tree: &UseTree, names: &mut Vec<String>) {
+    match tree {
+        UseTree::Path(tree) => use_tree_names(&tree.tree, names),
+        UseTree::Name(tree) => names.push(tree.ident.to_string()),
+        UseTree::Rename(tree) => names.push(tree.rename.to_string()),
+        UseTree::Glob(_) => {}
+        UseTree::Group(tree) => {
+            for tree in &tree.items {
+                use_tree_names(tree, names);
+            }
+        }
+    }
+}
@@ -0,0 +1,574 @@
+//! Classification of changes to function signatures and generic parameters
+//! by their effect on semantic versioning.
+//!
+//! Tools that compare the public API of two versions of a crate can parse
+//! both versions with [`parse_file`], match up the items by name, and pass
+//! the signatures of matching functions to [`compare_signatures`]. Each
+//! difference is reported as a [`Change`] that is either compatible, such as
+//! a function becoming `const` or a type parameter gaining a default, or
+//! breaking, such as a changed argument type.
+//!
+//! [`parse_file`]: crate::parse_file
+//!
+//! The comparison is purely syntactic. Types are compared by their tokens,
+//! ignoring whitespace and redundant parentheses, so `Vec<(u8)>` is the
+//! same as `Vec<u8>` but `Vec<T>` and `std::vec::Vec<T>` are different.
+//! Generic parameters are matched by position, so renaming a parameter that
+//! is mentioned in a bound shows up as a change to that bound.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"printing"` features.*
+
+use super::*;
+use crate::normalize::tokens_key as normalize;
+use crate::punctuated::Punctuated;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{self, Debug};
+
+/// Whether a change to an API can break code that uses it.
+pub enum Compatibility {
+    /// Code that compiled against the old API compiles against the new one.
+    Compatible,
+    /// Some code that compiled against the old API no longer compiles.
+    Breaking,
+}
+
+impl Copy for Compatibility {}
+
+impl Clone for Compatibility {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for Compatibility {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Compatibility {}
+
+impl Debug for Compatibility {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl Compatibility {
+    fn as_str(self) -> &'static str {
+        match self {
+            Compatibility::Compatible => "Compatible",
+            Compatibility::Breaking => "Breaking",
+        }
+    }
+}
+
+/// A single difference between two versions of a signature or of a list of
+/// generic parameters.
+pub struct Change {
+    /// Whether the change can break users of the API.
+    pub compatibility: Compatibility,
+    /// A description of the change, such as ``type of argument 1 changed
+    /// from `u8` to `u16` ``.
+    pub message: String,
+    /// The location of the change in the new version, or of the item as a
+    /// whole if the change is a removal.
+    pub span: Span,
+}
+
+impl Change {
+    /// Whether this is a breaking change.
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility == Compatibility::Breaking
+    }
+
+    fn compatible(span: Span, message: String) -> Self {
+        Change {
+            compatibility: Compatibility::Compatible,
+            message,
+            span,
+        }
+    }
+
+    fn breaking(span: Span, message: String) -> Self {
+        Change {
+            compatibility: Compatibility::Breaking,
+            message,
+            span,
+        }
+    }
+}
+
+impl Debug for Change {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Change")
+            .field("compatibility", &self.compatibility)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+/// Compares the old and the new version of a function signature.
+///
+/// Returns the list of differences, which is empty if the two signatures are
+/// the same up to the names of the arguments, whitespace and redundant
+/// parentheses. The following changes are reported:
+///
+/// - renaming the function, changing its ABI, or adding or removing `async`,
+///   a receiver, an argument or a variadic `...` is breaking;
+/// - changing the type of the receiver, of an argument, or of the return
+///   value is breaking;
+/// - making the function `const` or no longer `unsafe` is compatible, while
+///   the opposite is breaking;
+/// - changes to the generic parameters are classified as by
+///   [`compare_generics`].
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::parse_quote;
+/// use syn::semver::{self, Compatibility};
+/// use syn::Signature;
+///
+/// let old: Signature = parse_quote! {
+///     fn read(&self, buf: &mut [u8]) -> Result<usize>
+/// };
+/// let new: Signature = parse_quote! {
+///     const fn read(&self, buffer: &mut [(u8)]) -> Result<u64>
+/// };
+///
+/// let changes = semver::compare_signatures(&old, &new);
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0].compatibility, Compatibility::Compatible);
+/// assert_eq!(changes[0].message, "function is now `const`");
+/// assert!(changes[1].is_breaking());
+/// assert_eq!(
+///     changes[1].message,
+///     "return type changed from `Result < usize >` to `Result < u64 >`",
+/// );
+/// ```
+pub fn compare_signatures(old: &Signature, new: &Signature) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let span = new.ident.span();
+
+    if old.ident != new.ident {
+        changes.push(Change::breaking(
+            span,
+            format!("function renamed from `{}` to `{}`", old.ident, new.ident),
+        ));
+    }
+
+    match (old.constness.is_some(), new.constness.is_some()) {
+        (false, true) => changes.push(Change::compatible(
+            span_of(&new.constness),
+            "function is now `const`".to_owned(),
+        )),
+        (true, false) => changes.push(Change::breaking(
+            span,
+            "function is no longer `const`".to_owned(),
+        )),
+        _ => {}
+    }
+
+    match (old.asyncness.is_some(), new.asyncness.is_some()) {
+        (false, true) => changes.push(Change::breaking(
+            span_of(&new.asyncness),
+            "function is now `async`".to_owned(),
+        )),
+        (true, false) => changes.push(Change::breaking(
+            span,
+            "function is no longer `async`".to_owned(),
+        )),
+        _ => {}
+    }
+
+    match (old.unsafety.is_some(), new.unsafety.is_some()) {
+        (false, true) => changes.push(Change::breaking(
+            span_of(&new.unsafety),
+            "function is now `unsafe`".to_owned(),
+        )),
+        (true, false) => changes.push(Change::compatible(
+            span,
+            "function is no longer `unsafe`".to_owned(),
+        )),
+        _ => {}
+    }
+
+    let old_abi = abi_name(&old.abi);
+    let new_abi = abi_name(&new.abi);
+    if old_abi != new_abi {
+        changes.push(Change::breaking(
+            span_of(&new.abi),
+            format!("ABI changed from \"{}\" to \"{}\"", old_abi, new_abi),
+        ));
+    }
+
+    changes.extend(compare_generics(&old.generics, &new.generics));
+
+    match (old.receiver(), new.receiver()) {
+        (Some(old_receiver), Some(new_receiver)) => {
+            let old_ty = receiver_type(old_receiver);
+            let new_ty = receiver_type(new_receiver);
+            if normalize(&old_ty) != normalize(&new_ty) {
+                changes.push(Change::breaking(
+                    span_of(new_receiver),
+                    format!("receiver changed from `{}` to `{}`", old_ty, new_ty),
+                ));
+            }
+        }
+        (None, Some(new_receiver)) => changes.push(Change::breaking(
+            span_of(new_receiver),
+            "function now takes `self`".to_owned(),
+        )),
+        (Some(_), None) => changes.push(Change::breaking(
+            span,
+            "function no longer takes `self`".to_owned(),
+        )),
+        (None, None) => {}
+    }
+
+    let old_args = typed_args(old);
+    let new_args = typed_args(new);
+    if old_args.len() != new_args.len() {
+        changes.push(Change::breaking(
+            span,
+            format!(
+                "number of arguments changed from {} to {}",
+                old_args.len(),
+                new_args.len(),
+            ),
+        ));
+    }
+    for (i, (old_arg, new_arg)) in old_args.iter().zip(&new_args).enumerate() {
+        if normalize(&old_arg.ty) != normalize(&new_arg.ty) {
+            changes.push(Change::breaking(
+                span_of(&new_arg.ty),
+                format!(
+                    "type of argument {} changed from `{}` to `{}`",
+                    i + 1,
+                    old_arg.ty.to_token_stream(),
+                    new_arg.ty.to_token_stream(),
+                ),
+            ));
+        }
+    }
+
+    match (old.variadic.is_some(), new.variadic.is_some()) {
+        (false, true) => changes.push(Change::breaking(
+            span_of(&new.variadic),
+            "function is now variadic".to_owned(),
+        )),
+        (true, false) => changes.push(Change::breaking(
+            span,
+            "function is no longer variadic".to_owned(),
+        )),
+        _ => {}
+    }
+
+    let old_output = return_type(&old.output);
+    let new_output = return_type(&new.output);
+    if normalize(&old_output) != normalize(&new_output) {
+        changes.push(Change::breaking(
+            span_of(&new.output),
+            format!(
+                "return type changed from `{}` to `{}`",
+                old_output, new_output,
+            ),
+        ));
+    }
+
+    changes
+}
+
+/// Compares the old and the new version of the generic parameters of an
+/// item.
+///
+/// Parameters of each kind are matched up by position. The following changes
+/// are reported:
+///
+/// - adding or removing a lifetime parameter is breaking;
+/// - adding a type or const parameter is compatible if it has a default, and
+///   breaking otherwise, and removing one is breaking;
+/// - adding a default to an existing parameter is compatible, while removing
+///   or changing it is breaking, as is changing the type of a const
+///   parameter;
+/// - adding a bound, either on a parameter or in the where clause, is
+///   breaking and removing one is compatible, except for `?Sized` where it is
+///   the other way around.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub fn compare_generics(old: &Generics, new: &Generics) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let span = match &new.lt_token {
+        Some(lt_token) => lt_token.span,
+        None => Span::call_site(),
+    };
+
+    let old_lifetimes: Vec<&LifetimeDef> = old.lifetimes().collect();
+    let new_lifetimes: Vec<&LifetimeDef> = new.lifetimes().collect();
+    if old_lifetimes.len() != new_lifetimes.len() {
+        changes.push(Change::breaking(
+            span,
+            format!(
+                "number of lifetime parameters changed from {} to {}",
+                old_lifetimes.len(),
+                new_lifetimes.len(),
+            ),
+        ));
+    }
+    for (old_param, new_param) in old_lifetimes.iter().zip(&new_lifetimes) {
+        let what = format!("lifetime parameter `{}`", new_param.lifetime);
+        let span = new_param.lifetime.span();
+        compare_bounds(
+            &mut changes,
+            &what,
+            &old_param.bounds,
+            &new_param.bounds,
+            span,
+        );
+    }
+
+    let old_types: Vec<&TypeParam> = old.type_params().collect();
+    let new_types: Vec<&TypeParam> = new.type_params().collect();
+    for (old_param, new_param) in old_types.iter().zip(&new_types) {
+        let what = format!("type parameter `{}`", new_param.ident);
+        let span = new_param.ident.span();
+        compare_bounds(
+            &mut changes,
+            &what,
+            &old_param.bounds,
+            &new_param.bounds,
+            span,
+        );
+        compare_defaults(
+            &mut changes,
+            &what,
+            old_param.default.as_ref(),
+            new_param.default.as_ref(),
+            span,
+        );
+    }
+    for old_param in old_types.iter().skip(new_types.len()) {
+        changes.push(Change::breaking(
+            span,
+            format!("type parameter `{}` removed", old_param.ident),
+        ));
+    }
+    for new_param in new_types.iter().skip(old_types.len()) {
+        added_param(
+            &mut changes,
+            "type",
+            &new_param.ident,
+            new_param.default.is_some(),
+        );
+    }
+
+    let old_consts: Vec<&ConstParam> = old.const_params().collect();
+    let new_consts: Vec<&ConstParam> = new.const_params().collect();
+    for (old_param, new_param) in old_consts.iter().zip(&new_consts) {
+        let what = format!("const parameter `{}`", new_param.ident);
+        if normalize(&old_param.ty) != normalize(&new_param.ty) {
+            changes.push(Change::breaking(
+                span_of(&new_param.ty),
+                format!(
+                    "type of {} changed from `{}` to `{}`",
+                    what,
+                    old_param.ty.to_token_stream(),
+                    new_param.ty.to_token_stream(),
+                ),
+            ));
+        }
+        compare_defaults(
+            &mut changes,
+            &what,
+            old_param.default.as_ref(),
+            new_param.default.as_ref(),
+            new_param.ident.span(),
+        );
+    }
+    for old_param in old_consts.iter().skip(new_consts.len()) {
+        changes.push(Change::breaking(
+            span,
+            format!("const parameter `{}` removed", old_param.ident),
+        ));
+    }
+    for new_param in new_consts.iter().skip(old_consts.len()) {
+        added_param(
+            &mut changes,
+            "const",
+            &new_param.ident,
+            new_param.default.is_some(),
+        );
+    }
+
+    let old_predicates = where_predicates(old);
+    let new_predicates = where_predicates(new);
+    for predicate in &new_predicates {
+        let key = normalize(predicate);
+        if !old_predicates.iter().any(|old| normalize(old) == key) {
+            changes.push(Change::breaking(
+                span_of(predicate),
+                format!(
+                    "where clause has new predicate `{}`",
+                    predicate.to_token_stream(),
+                ),
+            ));
+        }
+    }
+    for predicate in &old_predicates {
+        let key = normalize(predicate);
+        if !new_predicates.iter().any(|new| normalize(new) == key) {
+            changes.push(Change::compatible(
+                span,
+                format!(
+                    "where clause no longer has predicate `{}`",
+                    predicate.to_token_stream(),
+                ),
+            ));
+        }
+    }
+
+    changes
+}
+
+fn added_param(changes: &mut Vec<Change>, kind: &str, ident: &Ident, has_default: bool) {
+    if has_default {
+        changes.push(Change::compatible(
+            ident.span(),
+            format!("{} parameter `{}` added with a default", kind, ident),
+        ));
+    } else {
+        changes.push(Change::breaking(
+            ident.span(),
+            format!("{} parameter `{}` added", kind, ident),
+        ));
+    }
+}
+
+fn compare_bounds<T: ToTokens>(
+    changes: &mut Vec<Change>,
+    what: &str,
+    old: &Punctuated<T, Token![+]>,
+    new: &Punctuated<T, Token![+]>,
+    span: Span,
+) {
+    // Adding `?Sized` relaxes the requirements on users instead of adding
+    // to them.
+    let is_maybe = |key: &str| key.starts_with('?');
+    for bound in new {
+        let key = normalize(bound);
+        if !old.iter().any(|old| normalize(old) == key) {
+            let message = format!("{} has new bound `{}`", what, bound.to_token_stream());
+            changes.push(if is_maybe(&key) {
+                Change::compatible(span_of(bound), message)
+            } else {
+                Change::breaking(span_of(bound), message)
+            });
+        }
+    }
+    for bound in old {
+        let key = normalize(bound);
+        if !new.iter().any(|new| normalize(new) == key) {
+            let message = format!("{} no longer has bound `{}`", what, bound.to_token_stream());
+            changes.push(if is_maybe(&key) {
+                Change::breaking(span, message)
+            } else {
+                Change::compatible(span, message)
+            });
+        }
+    }
+}
+
+fn compare_defaults<T: ToTokens>(
+    changes: &mut Vec<Change>,
+    what: &str,
+    old: Option<&T>,
+    new: Option<&T>,
+    span: Span,
+) {
+    match (old, new) {
+        (None, Some(new)) => changes.push(Change::compatible(
+            span_of(new),
+            format!("{} now has a default", what),
+        )),
+        (Some(_), None) => changes.push(Change::breaking(
+            span,
+            format!("{} no longer has a default", what),
+        )),
+        (Some(old), Some(new)) => {
+            if normalize(old) != normalize(new) {
+                changes.push(Change::breaking(
+                    span_of(new),
+                    format!(
+                        "default of {} changed from `{}` to `{}`",
+                        what,
+                        old.to_token_stream(),
+                        new.to_token_stream(),
+                    ),
+                ));
+            }
+        }
+        (None, None) => {}
+    }
+}
+
+fn abi_name(abi: &Option<Abi>) -> String {
+    match abi {
+        None => "Rust".to_owned(),
+        Some(abi) => abi.name_value().unwrap_or_else(|| "C".to_owned()),
+    }
+}
+
+// The type of `self`, such as `&mut Self` for `&mut self`.
+fn receiver_type(receiver: &FnArg) -> TokenStream {
+    match receiver {
+        FnArg::Receiver(receiver) => match &receiver.reference {
+            Some((_and_token, lifetime)) => match receiver.mutability {
+                Some(_) => quote!(&#lifetime mut Self),
+                None => quote!(&#lifetime Self),
+            },
+            None => quote!(Self),
+        },
+        FnArg::Typed(pat_type) => pat_type.ty.to_token_stream(),
+    }
+}
+
+fn typed_args(sig: &Signature) -> Vec<&PatType> {
+    let has_receiver = sig.receiver().is_some();
+    sig.inputs
+        .iter()
+        .skip(has_receiver as usize)
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+fn return_type(output: &ReturnType) -> TokenStream {
+    match output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_arrow, ty) => ty.to_token_stream(),
+    }
+}
+
+fn where_predicates(generics: &Generics) -> Vec<&WherePredicate> {
+    match &generics.where_clause {
+        Some(where_clause) => where_clause.predicates.iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+fn span_of<T: ToTokens>(node: &T) -> Span {
+    match node.to_token_stream().into_iter().next() {
+        Some(tt) => tt.span(),
+        None => Span::call_site(),
+    }
+}
@@ -0,0 +1,587 @@
+// Serde support for the types appearing in the syntax tree that are not syntax
+// tree nodes themselves. The impls for syntax tree nodes are generated into
+// src/gen/serde.rs and build on the `Ser` and `De` traits defined here, which
+// can be implemented for non-local types like `Option<Ident>` where
+// `Serialize` and `Deserialize` cannot.
+
+use crate::punctuated::Punctuated;
+use crate::{Lifetime, Lit, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use serde::de::{self, Expected, IgnoredAny, SeqAccess, Unexpected, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+pub(crate) trait Ser {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+pub(crate) trait De<'de>: Sized {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+pub(crate) struct Wrap<'a, T>(pub &'a T);
+
+impl<'a, T: Ser> Serialize for Wrap<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.ser(serializer)
+    }
+}
+
+pub(crate) struct Unwrap<T>(pub T);
+
+impl<'de, T: De<'de>> Deserialize<'de> for Unwrap<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::de(deserializer).map(Unwrap)
+    }
+}
+
+// The name or index of a struct field or enum variant.
+pub(crate) enum Key {
+    Name(String),
+    Index(u64),
+}
+
+impl Key {
+    pub(crate) fn index(&self, names: &[&str]) -> Option<usize> {
+        match self {
+            Key::Name(name) => names.iter().position(|candidate| candidate == name),
+            Key::Index(index) => {
+                if *index < names.len() as u64 {
+                    Some(*index as usize)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub(crate) fn unknown_variant<E: de::Error>(&self, names: &'static [&'static str]) -> E {
+        match self {
+            Key::Name(name) => E::unknown_variant(name, names),
+            Key::Index(index) => E::invalid_value(Unexpected::Unsigned(*index), &"variant index"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyVisitor;
+
+        impl<'de> Visitor<'de> for KeyVisitor {
+            type Value = Key;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("field or variant identifier")
+            }
+
+            fn visit_u64<E: de::Error>(self, index: u64) -> Result<Key, E> {
+                Ok(Key::Index(index))
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Key, E> {
+                Ok(Key::Name(name.to_owned()))
+            }
+
+            fn visit_bytes<E: de::Error>(self, name: &[u8]) -> Result<Key, E> {
+                Ok(Key::Name(String::from_utf8_lossy(name).into_owned()))
+            }
+        }
+
+        deserializer.deserialize_identifier(KeyVisitor)
+    }
+}
+
+pub(crate) fn next_value<'de, A, T>(
+    map: &mut A,
+    slot: &mut Option<T>,
+    name: &'static str,
+) -> Result<(), A::Error>
+where
+    A: de::MapAccess<'de>,
+    T: De<'de>,
+{
+    if slot.is_some() {
+        return Err(de::Error::duplicate_field(name));
+    }
+    *slot = Some(map.next_value::<Unwrap<T>>()?.0);
+    Ok(())
+}
+
+pub(crate) fn next_element<'de, A, T>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn Expected,
+) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: De<'de>,
+{
+    match seq.next_element::<Unwrap<T>>()? {
+        Some(element) => Ok(element.0),
+        None => Err(de::Error::invalid_length(index, expected)),
+    }
+}
+
+pub(crate) fn required<T, E: de::Error>(slot: Option<T>, name: &'static str) -> Result<T, E> {
+    match slot {
+        Some(value) => Ok(value),
+        None => Err(E::missing_field(name)),
+    }
+}
+
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub(crate) fn flag<T: Default>(present: bool) -> Option<T> {
+    if present {
+        Some(T::default())
+    } else {
+        None
+    }
+}
+
+#[cfg(any(feature = "derive", feature = "full", feature = "parse-only"))]
+pub(crate) fn trailing<T, P: Default>(
+    mut punctuated: Punctuated<T, P>,
+    trailing: bool,
+) -> Punctuated<T, P> {
+    if trailing && !punctuated.empty_or_trailing() {
+        punctuated.push_punct(P::default());
+    }
+    punctuated
+}
+
+pub(crate) struct TupleVisitor<T>(PhantomData<T>);
+
+impl<T> TupleVisitor<T> {
+    pub(crate) fn new() -> Self {
+        TupleVisitor(PhantomData)
+    }
+}
+
+impl<'de, A: De<'de>, B: De<'de>> Visitor<'de> for TupleVisitor<(A, B)> {
+    type Value = (A, B);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tuple of size 2")
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Self::Value, V::Error> {
+        let a = next_element(&mut seq, 0, &self)?;
+        let b = next_element(&mut seq, 1, &self)?;
+        Ok((a, b))
+    }
+}
+
+impl<'de, A: De<'de>, B: De<'de>, C: De<'de>> Visitor<'de> for TupleVisitor<(A, B, C)> {
+    type Value = (A, B, C);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tuple of size 3")
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Self::Value, V::Error> {
+        let a = next_element(&mut seq, 0, &self)?;
+        let b = next_element(&mut seq, 1, &self)?;
+        let c = next_element(&mut seq, 2, &self)?;
+        Ok((a, b, c))
+    }
+}
+
+impl<T: Ser> Ser for Box<T> {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).ser(serializer)
+    }
+}
+
+impl<'de, T: De<'de>> De<'de> for Box<T> {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::de(deserializer).map(Box::new)
+    }
+}
+
+impl<T: Ser> Ser for Option<T> {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Some(value) => serializer.serialize_some(&Wrap(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: De<'de>> De<'de> for Option<T> {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OptionVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: De<'de>> Visitor<'de> for OptionVisitor<T> {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("option")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                T::de(deserializer).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor(PhantomData))
+    }
+}
+
+impl<T: Ser> Ser for Vec<T> {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter().map(Wrap))
+    }
+}
+
+impl<'de, T: De<'de>> De<'de> for Vec<T> {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VecVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: De<'de>> Visitor<'de> for VecVisitor<T> {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut vec = Vec::new();
+                while let Some(element) = seq.next_element::<Unwrap<T>>()? {
+                    vec.push(element.0);
+                }
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor(PhantomData))
+    }
+}
+
+// Only the elements are serialized. Deserializing puts punctuation between
+// them, and the generated code for the containing struct adds any trailing
+// punctuation.
+impl<T: Ser, P> Ser for Punctuated<T, P> {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter().map(Wrap))
+    }
+}
+
+impl<'de, T: De<'de>, P: Default> De<'de> for Punctuated<T, P> {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<T>::de(deserializer).map(|elements| elements.into_iter().collect())
+    }
+}
+
+impl<A: Ser, B: Ser> Ser for (A, B) {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Wrap(&self.0))?;
+        tuple.serialize_element(&Wrap(&self.1))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: De<'de>, B: De<'de>> De<'de> for (A, B) {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, TupleVisitor::<(A, B)>::new())
+    }
+}
+
+impl<A: Ser, B: Ser, C: Ser> Ser for (A, B, C) {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&Wrap(&self.0))?;
+        tuple.serialize_element(&Wrap(&self.1))?;
+        tuple.serialize_element(&Wrap(&self.2))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: De<'de>, B: De<'de>, C: De<'de>> De<'de> for (A, B, C) {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, TupleVisitor::<(A, B, C)>::new())
+    }
+}
+
+macro_rules! serde_delegate {
+    ($($ty:ty)*) => {
+        $(
+            impl Ser for $ty {
+                fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.serialize(serializer)
+                }
+            }
+
+            impl<'de> De<'de> for $ty {
+                fn de<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Self::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+serde_delegate! {
+    bool u32 usize String
+    Lifetime LitStr LitByteStr LitByte LitChar LitInt LitFloat
+}
+
+impl Ser for Ident {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> De<'de> for Ident {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        let (raw, name) = if string.starts_with("r#") {
+            (true, &string[2..])
+        } else {
+            (false, &string[..])
+        };
+        let valid = is_ident(name)
+            && !(raw
+                && (name == "_"
+                    || name == "crate"
+                    || name == "self"
+                    || name == "Self"
+                    || name == "super"));
+        if !valid {
+            return Err(de::Error::invalid_value(
+                Unexpected::Str(&string),
+                &"an identifier",
+            ));
+        }
+        Ok(if raw {
+            Ident::new_raw(name, Span::call_site())
+        } else {
+            Ident::new(name, Span::call_site())
+        })
+    }
+}
+
+fn is_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => {
+            (first == '_' || unicode_ident::is_xid_start(first))
+                && chars.all(unicode_ident::is_xid_continue)
+        }
+        None => false,
+    }
+}
+
+impl Ser for Span {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> De<'de> for Span {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Span::call_site())
+    }
+}
+
+impl Ser for TokenStream {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> De<'de> for TokenStream {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        match string.parse() {
+            Ok(tokens) => Ok(tokens),
+            Err(_) => Err(de::Error::invalid_value(
+                Unexpected::Str(&string),
+                &"a token stream",
+            )),
+        }
+    }
+}
+
+impl Ser for Literal {
+    fn ser<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> De<'de> for Literal {
+    fn de<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        parse_literal(&string, &"a literal")
+    }
+}
+
+fn parse_literal<E: de::Error>(string: &str, expected: &dyn Expected) -> Result<Literal, E> {
+    match string.parse() {
+        Ok(literal) => Ok(literal),
+        Err(_) => Err(E::invalid_value(Unexpected::Str(string), expected)),
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Lifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Lifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        if string.starts_with('\'') && is_ident(&string[1..]) {
+            Ok(Lifetime::new(&string, Span::call_site()))
+        } else {
+            Err(de::Error::invalid_value(
+                Unexpected::Str(&string),
+                &"a lifetime",
+            ))
+        }
+    }
+}
+
+macro_rules! serde_lit {
+    ($($ty:ident: $variant:ident, $expected:expr;)*) => {
+        $(
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(&self.token())
+                }
+            }
+
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let string = String::deserialize(deserializer)?;
+                    match Lit::new(parse_literal(&string, &$expected)?) {
+                        Lit::$variant(lit) => Ok(lit),
+                        _ => Err(de::Error::invalid_value(
+                            Unexpected::Str(&string),
+                            &$expected,
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+serde_lit! {
+    LitStr: Str, "a string literal";
+    LitByteStr: ByteStr, "a byte string literal";
+    LitByte: Byte, "a byte literal";
+    LitChar: Char, "a character literal";
+    LitInt: Int, "an integer literal";
+    LitFloat: Float, "a floating point literal";
+}
@@ -1,67 +1,408 @@
+//! Joining of spans, with a fallback for compilers that cannot join them.
+//!
+//! [`Span::join`] only succeeds in some environments. Inside of a procedural
+//! macro it needs a nightly compiler, and outside of one, such as in a build
+//! script or a test, it needs `proc-macro2` to be built with its
+//! `"span-locations"` feature so that spans know where they are in the
+//! source. Everywhere else it returns `None`, and the functions in this
+//! module fall back to the span of the first token, which is also what
+//! [`Spanned`] does. An error reported at the fallback span still points at
+//! the right place, only less of it is underlined.
+//!
+//! [`Span::join`]: proc_macro2::Span::join
+//! [`Spanned`]: crate::spanned::Spanned
+//!
+//! # Example
+//!
+//! ```
+//! use proc_macro2::Span;
+//! use syn::{Field, Fields, Ident};
+//!
+//! // A span covering the names of all the fields, for an error about them
+//! // as a group.
+//! fn field_names_span(fields: &Fields) -> Span {
+//!     syn::span::join_all(fields.iter().filter_map(|field: &Field| {
+//!         field.ident.as_ref().map(Ident::span)
+//!     }))
+//! }
+//! ```
+
+#[cfg(feature = "printing")]
+use crate::punctuated::Punctuated;
 use proc_macro2::Span;
+#[cfg(feature = "printing")]
+use proc_macro2::{Delimiter, Group, Ident, Literal, TokenStream, TokenTree};
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+#[cfg(feature = "printing")]
+use std::mem;
 
-pub trait IntoSpans<S> {
-    fn into_spans(self) -> S;
+/// Joins two spans into one covering both, or returns `first` if they
+/// cannot be joined.
+///
+/// Spans can be joined if the environment supports it, as described in the
+/// [module documentation](self), and both are from the same file.
+pub fn join(first: Span, second: Span) -> Span {
+    first.join(second).unwrap_or(first)
 }
 
-impl IntoSpans<[Span; 1]> for Span {
-    fn into_spans(self) -> [Span; 1] {
-        [self]
+/// Joins a sequence of spans, in source order, into one covering all of them.
+///
+/// Returns the first span if the spans cannot be joined, or
+/// [`Span::call_site()`] if there are no spans.
+///
+/// [`Span::call_site()`]: proc_macro2::Span::call_site
+pub fn join_all<I>(spans: I) -> Span
+where
+    I: IntoIterator<Item = Span>,
+{
+    let mut spans = spans.into_iter();
+    let first = match spans.next() {
+        Some(first) => first,
+        None => return Span::call_site(),
+    };
+    let mut joined = first;
+    for span in spans {
+        match joined.join(span) {
+            Some(span) => joined = span,
+            None => return first,
+        }
     }
+    joined
+}
+
+/// A token found by [`find_call_site`] or [`find_spans`].
+///
+/// *This type is available only if Syn is built with the `"printing"`
+/// feature.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub struct Finding {
+    /// The token as written, or just the delimiter if the span is that of
+    /// one of the delimiters of a group.
+    pub token: String,
+    /// The offending span.
+    pub span: Span,
+}
+
+/// Finds the tokens of a syntax tree whose span is [`Span::call_site()`].
+///
+/// A macro that builds part of its output with `quote!` rather than
+/// `quote_spanned!`, or from tokens it made up with `Span::call_site()`,
+/// gets errors in that part reported at the macro invocation as a whole.
+/// Running this over the parts of the output that are supposed to point at the
+/// macro's input, for example in a test of the macro, finds the tokens that
+/// lost their span. The delimiters of groups are checked too.
+///
+/// Spans have no equality, so they are compared by their `Debug`
+/// representation. This only tells spans apart where the representation
+/// includes the location, which is the case inside of a procedural macro and
+/// with the `"span-locations"` feature of `proc-macro2`. Elsewhere all spans
+/// look the same and every token is reported.
+///
+/// [`Span::call_site()`]: proc_macro2::Span::call_site
+///
+/// *This function is available only if Syn is built with the `"printing"`
+/// feature.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub fn find_call_site<T: ToTokens>(node: &T) -> Vec<Finding> {
+    find_spans(node, &[Span::call_site()])
+}
+
+/// Finds the tokens of a syntax tree whose span is one of `forbidden`, such
+/// as the spans of the macro's own attribute that should not end up in the
+/// generated code.
+///
+/// Spans are compared as in [`find_call_site`].
+///
+/// *This function is available only if Syn is built with the `"printing"`
+/// feature.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub fn find_spans<T: ToTokens>(node: &T, forbidden: &[Span]) -> Vec<Finding> {
+    let forbidden: Vec<String> = forbidden.iter().map(span_key).collect();
+    let mut tokens = Vec::new();
+    spanned_tokens(node.to_token_stream(), &mut tokens);
+    tokens
+        .into_iter()
+        .filter(|(_token, span)| forbidden.contains(&span_key(span)))
+        .map(|(token, span)| Finding { token, span })
+        .collect()
+}
+
+/// A token whose span differs between two trees, found by [`diff_spans`].
+///
+/// *This type is available only if Syn is built with the `"printing"`
+/// feature.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub struct SpanDiff {
+    /// The token as written, or just the delimiter if the span is that of
+    /// one of the delimiters of a group.
+    pub token: String,
+    /// The index of the token among the tokens of the trees, counting each
+    /// delimiter as a token.
+    pub index: usize,
+    /// The span of the token in the first tree.
+    pub before: Span,
+    /// The span of the token in the second tree.
+    pub after: Span,
 }
 
-impl IntoSpans<[Span; 2]> for Span {
-    fn into_spans(self) -> [Span; 2] {
-        [self, self]
+/// Finds the tokens whose spans differ between two syntax trees that print
+/// to the same tokens.
+///
+/// This is meant for tests of a macro that pin down where its diagnostics
+/// point: comparing the output of the macro before and after a refactor, or
+/// against a tree built by hand, shows every token that moved to a different
+/// span, such as one that went from the span of the user's input to
+/// [`Span::call_site()`] and with it from the user's hygiene context to the
+/// macro's. The delimiters of groups are compared too.
+///
+/// Returns `None` if the trees do not print to the same tokens, in which case
+/// there is no correspondence between their tokens to go by. Invisible
+/// groups are looked through, so they need not match.
+///
+/// Spans are compared as in [`find_call_site`], so outside of a procedural
+/// macro and without the `"span-locations"` feature of `proc-macro2`, no
+/// token is reported.
+///
+/// [`Span::call_site()`]: proc_macro2::Span::call_site
+///
+/// *This function is available only if Syn is built with the `"printing"`
+/// feature.*
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub fn diff_spans<T: ToTokens>(before: &T, after: &T) -> Option<Vec<SpanDiff>> {
+    let mut before_tokens = Vec::new();
+    spanned_tokens(before.to_token_stream(), &mut before_tokens);
+    let mut after_tokens = Vec::new();
+    spanned_tokens(after.to_token_stream(), &mut after_tokens);
+    if before_tokens.len() != after_tokens.len() {
+        return None;
     }
+
+    let mut diffs = Vec::new();
+    let pairs = before_tokens.into_iter().zip(after_tokens);
+    for (index, ((token, before), (after_token, after))) in pairs.enumerate() {
+        if token != after_token {
+            return None;
+        }
+        if span_key(&before) != span_key(&after) {
+            diffs.push(SpanDiff {
+                token,
+                index,
+                before,
+                after,
+            });
+        }
+    }
+    Some(diffs)
+}
+
+/// Printing of a syntax tree with every token given the same span.
+///
+/// `quote_spanned!` only sets the span of the tokens written out in the macro
+/// invocation; a syntax tree interpolated into it with `#var` keeps the spans
+/// it has. This trait instead gives the span to every token of the tree,
+/// including the delimiters of groups, as it is printed, which is how a
+/// macro makes generated code that mentions the user's input report errors
+/// at one place of its choosing.
+///
+/// This trait is implemented for every syntax tree node and token, as well as
+/// for the [`Ident`], [`Literal`] and [`TokenStream`] of `proc-macro2`. Its
+/// impls for the syntax tree nodes are generated along with the other
+/// traversals of the tree, and set the span of each token of a node in
+/// place. Printing then emits the tokens with the span already set, instead
+/// of rebuilding every group of the printed token stream to respan it.
+///
+/// [`Ident`]: proc_macro2::Ident
+/// [`Literal`]: proc_macro2::Literal
+///
+/// *This trait is available only if Syn is built with the `"printing"`
+/// feature.*
+///
+/// # Example
+///
+/// ```
+/// use proc_macro2::{Span, TokenStream};
+/// use quote::quote;
+/// use syn::span::ToTokensSpanned;
+/// use syn::{parse_quote, Type};
+///
+/// // Any error about the bound is reported at `span`, rather than at the
+/// // parts of the type that the compiler happens to point at.
+/// fn assert_send(ty: &Type, span: Span) -> TokenStream {
+///     let mut bounded = TokenStream::new();
+///     ty.to_tokens_spanned(span, &mut bounded);
+///     quote!(const _: fn() = || { fn f<T: Send>() {} f::<#bounded>(); };)
+/// }
+///
+/// let ty: Type = parse_quote!(Vec<Rc<u8>>);
+/// let tokens = assert_send(&ty, Span::call_site());
+/// # let _ = tokens;
+/// ```
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub trait ToTokensSpanned {
+    /// Appends the tokens of `self` to `tokens`, with `span` as the span of
+    /// every one of them.
+    ///
+    /// The tokens are printed from a copy of `self` whose spans have been set
+    /// by [`set_spans`](ToTokensSpanned::set_spans).
+    fn to_tokens_spanned(&self, span: Span, tokens: &mut TokenStream)
+    where
+        Self: Clone + ToTokens,
+    {
+        let mut spanned = self.clone();
+        spanned.set_spans(span);
+        spanned.to_tokens(tokens);
+    }
+
+    /// Sets `span` as the span of every token of `self`, including the
+    /// delimiters of groups.
+    fn set_spans(&mut self, span: Span);
 }
 
-impl IntoSpans<[Span; 3]> for Span {
-    fn into_spans(self) -> [Span; 3] {
-        [self, self, self]
+#[cfg(feature = "printing")]
+impl ToTokensSpanned for Span {
+    fn set_spans(&mut self, span: Span) {
+        *self = span;
     }
 }
 
-impl IntoSpans<[Span; 1]> for [Span; 1] {
-    fn into_spans(self) -> [Span; 1] {
-        self
+#[cfg(feature = "printing")]
+impl ToTokensSpanned for Ident {
+    fn set_spans(&mut self, span: Span) {
+        self.set_span(span);
     }
 }
 
-impl IntoSpans<[Span; 2]> for [Span; 2] {
-    fn into_spans(self) -> [Span; 2] {
-        self
+#[cfg(feature = "printing")]
+impl ToTokensSpanned for Literal {
+    fn set_spans(&mut self, span: Span) {
+        self.set_span(span);
     }
 }
 
-impl IntoSpans<[Span; 3]> for [Span; 3] {
-    fn into_spans(self) -> [Span; 3] {
-        self
+#[cfg(feature = "printing")]
+impl ToTokensSpanned for TokenStream {
+    fn set_spans(&mut self, span: Span) {
+        let stream = mem::replace(self, TokenStream::new());
+        *self = stream.into_iter().map(|tt| respan(tt, span)).collect();
     }
 }
 
-#[cfg(feature = "parsing")]
-pub trait FromSpans: Sized {
-    fn from_spans(spans: &[Span]) -> Self;
+macro_rules! lit_set_spans {
+    ($($lit:ident)*) => {
+        $(
+            #[cfg(feature = "printing")]
+            impl ToTokensSpanned for crate::$lit {
+                fn set_spans(&mut self, span: Span) {
+                    self.set_span(span);
+                }
+            }
+        )*
+    };
 }
 
-#[cfg(feature = "parsing")]
-impl FromSpans for [Span; 1] {
-    fn from_spans(spans: &[Span]) -> Self {
-        [spans[0]]
+lit_set_spans!(LitStr LitByteStr LitByte LitChar LitInt LitFloat);
+
+#[cfg(feature = "printing")]
+impl<T: ToTokensSpanned> ToTokensSpanned for Option<T> {
+    fn set_spans(&mut self, span: Span) {
+        if let Some(value) = self {
+            value.set_spans(span);
+        }
     }
 }
 
-#[cfg(feature = "parsing")]
-impl FromSpans for [Span; 2] {
-    fn from_spans(spans: &[Span]) -> Self {
-        [spans[0], spans[1]]
+#[cfg(feature = "printing")]
+impl<T: ?Sized + ToTokensSpanned> ToTokensSpanned for Box<T> {
+    fn set_spans(&mut self, span: Span) {
+        (**self).set_spans(span);
     }
 }
 
-#[cfg(feature = "parsing")]
-impl FromSpans for [Span; 3] {
-    fn from_spans(spans: &[Span]) -> Self {
-        [spans[0], spans[1], spans[2]]
+#[cfg(feature = "printing")]
+impl<T: ToTokensSpanned> ToTokensSpanned for Vec<T> {
+    fn set_spans(&mut self, span: Span) {
+        for value in self {
+            value.set_spans(span);
+        }
     }
 }
+
+#[cfg(feature = "printing")]
+impl<T: ToTokensSpanned, P: ToTokensSpanned> ToTokensSpanned for Punctuated<T, P> {
+    fn set_spans(&mut self, span: Span) {
+        for pair in self.pairs_mut() {
+            let (value, punct) = pair.into_tuple();
+            value.set_spans(span);
+            if let Some(punct) = punct {
+                punct.set_spans(span);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+impl<A: ToTokensSpanned, B: ToTokensSpanned> ToTokensSpanned for (A, B) {
+    fn set_spans(&mut self, span: Span) {
+        self.0.set_spans(span);
+        self.1.set_spans(span);
+    }
+}
+
+#[cfg(feature = "printing")]
+impl<A: ToTokensSpanned, B: ToTokensSpanned, C: ToTokensSpanned> ToTokensSpanned for (A, B, C) {
+    fn set_spans(&mut self, span: Span) {
+        self.0.set_spans(span);
+        self.1.set_spans(span);
+        self.2.set_spans(span);
+    }
+}
+
+#[cfg(feature = "printing")]
+fn respan(mut tt: TokenTree, span: Span) -> TokenTree {
+    match &mut tt {
+        TokenTree::Group(group) => {
+            let stream = group.stream().into_iter().map(|tt| respan(tt, span));
+            *group = Group::new(group.delimiter(), stream.collect());
+            group.set_span(span);
+        }
+        tt => tt.set_span(span),
+    }
+    tt
+}
+
+// The tokens of a stream with their spans, with the delimiters of visible
+// groups as tokens of their own.
+#[cfg(feature = "printing")]
+fn spanned_tokens(tokens: TokenStream, out: &mut Vec<(String, Span)>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => {
+                        spanned_tokens(group.stream(), out);
+                        continue;
+                    }
+                };
+                out.push((open.to_owned(), group.span_open()));
+                spanned_tokens(group.stream(), out);
+                out.push((close.to_owned(), group.span_close()));
+            }
+            tt => out.push((tt.to_string(), tt.span())),
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+fn span_key(span: &Span) -> String {
+    format!("{:?}", span)
+}
@@ -0,0 +1,213 @@
+use super::*;
+use crate::punctuated::Punctuated;
+
+ast_struct! {
+    /// The preconditions of a function: `requires x < 10, y > 0`.
+    ///
+    /// *This type is available only if Syn is built with the `"verus"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+    pub struct Requires {
+        pub requires_token: Token![requires],
+        pub exprs: Punctuated<Expr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// The postconditions of a function: `ensures result > x`.
+    ///
+    /// *This type is available only if Syn is built with the `"verus"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+    pub struct Ensures {
+        pub ensures_token: Token![ensures],
+        pub exprs: Punctuated<Expr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// The termination measure of a recursive function or a loop:
+    /// `decreases n - i`.
+    ///
+    /// *This type is available only if Syn is built with the `"verus"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+    pub struct Decreases {
+        pub decreases_token: Token![decreases],
+        pub exprs: Punctuated<Expr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// The invariants of a loop: `invariant i <= n, sum == i * x`.
+    ///
+    /// *This type is available only if Syn is built with the `"verus"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "verus")))]
+    pub struct Invariant {
+        pub invariant_token: Token![invariant],
+        pub exprs: Punctuated<Expr, Token![,]>,
+    }
+}
+
+#[cfg(feature = "parsing")]
+pub mod parsing {
+    use super::*;
+    use crate::parse::{Parse, ParseStream, Peek, Result};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for Requires {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Requires {
+                requires_token: input.parse()?,
+                exprs: parse_exprs(input)?,
+            })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for Ensures {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Ensures {
+                ensures_token: input.parse()?,
+                exprs: parse_exprs(input)?,
+            })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for Decreases {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Decreases {
+                decreases_token: input.parse()?,
+                exprs: parse_exprs(input)?,
+            })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for Invariant {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Invariant {
+                invariant_token: input.parse()?,
+                exprs: parse_exprs(input)?,
+            })
+        }
+    }
+
+    // One or more expressions separated by commas, with an optional trailing
+    // comma. The clause ends at the body of the function or loop, at the
+    // semicolon of a function without a body, or at the next clause.
+    fn parse_exprs(input: ParseStream) -> Result<Punctuated<Expr, Token![,]>> {
+        let mut exprs = Punctuated::new();
+        loop {
+            exprs.push_value(Expr::parse_without_eager_brace(input)?);
+            if !input.peek(Token![,]) {
+                break;
+            }
+            exprs.push_punct(input.parse()?);
+            if is_clause_end(input) {
+                break;
+            }
+        }
+        Ok(exprs)
+    }
+
+    fn is_clause_end(input: ParseStream) -> bool {
+        input.is_empty()
+            || input.peek(token::Brace)
+            || input.peek(Token![;])
+            || input.peek(Token![requires])
+            || input.peek(Token![ensures])
+            || input.peek(Token![decreases])
+            || input.peek(Token![invariant])
+    }
+
+    // The requires, ensures and decreases clauses of a function signature.
+    pub(crate) fn signature_clauses(input: ParseStream) -> Result<Option<Box<SpecClauses>>> {
+        let spec = SpecClauses {
+            requires: parse_optional(input, Token![requires])?,
+            ensures: parse_optional(input, Token![ensures])?,
+            invariant: None,
+            decreases: parse_optional(input, Token![decreases])?,
+        };
+        Ok(boxed(spec))
+    }
+
+    // The invariant and decreases clauses of a loop.
+    pub(crate) fn loop_clauses(input: ParseStream) -> Result<Option<Box<SpecClauses>>> {
+        let spec = SpecClauses {
+            requires: None,
+            ensures: None,
+            invariant: parse_optional(input, Token![invariant])?,
+            decreases: parse_optional(input, Token![decreases])?,
+        };
+        Ok(boxed(spec))
+    }
+
+    fn boxed(spec: SpecClauses) -> Option<Box<SpecClauses>> {
+        if spec.requires.is_none()
+            && spec.ensures.is_none()
+            && spec.invariant.is_none()
+            && spec.decreases.is_none()
+        {
+            None
+        } else {
+            Some(Box::new(spec))
+        }
+    }
+
+    // Parses a clause if the next token is its keyword.
+    fn parse_optional<T: Parse, K: Peek>(input: ParseStream, keyword: K) -> Result<Option<T>> {
+        if input.peek(keyword) {
+            input.parse().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+mod printing {
+    use super::*;
+    use crate::expr::printing::wrap_bare_struct;
+    use proc_macro2::TokenStream;
+    use quote::ToTokens;
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Requires {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.requires_token.to_tokens(tokens);
+            exprs_to_tokens(&self.exprs, tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Ensures {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.ensures_token.to_tokens(tokens);
+            exprs_to_tokens(&self.exprs, tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Decreases {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.decreases_token.to_tokens(tokens);
+            exprs_to_tokens(&self.exprs, tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for Invariant {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.invariant_token.to_tokens(tokens);
+            exprs_to_tokens(&self.exprs, tokens);
+        }
+    }
+
+    // A struct literal would be taken as the start of the body when parsed
+    // back, so it needs parentheses.
+    fn exprs_to_tokens(exprs: &Punctuated<Expr, Token![,]>, tokens: &mut TokenStream) {
+        for pair in exprs.pairs() {
+            wrap_bare_struct(tokens, pair.value());
+            pair.punct().to_tokens(tokens);
+        }
+    }
+}
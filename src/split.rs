@@ -0,0 +1,427 @@
+//! Splitting of deeply nested expressions into `let` statements.
+//!
+//! Code generated from a table, such as a builder chain with a method call
+//! per row or a sum with a term per entry, easily nests expressions deeper
+//! than rustc is able to compile, which fails with an error about reaching
+//! the recursion limit. The functions in this module keep the nesting under
+//! a maximum depth by moving subexpressions into `let` statements in front
+//! of the statement they are in, binding them to identifiers that are not
+//! used anywhere else in the block, and using the identifiers in their place.
+//!
+//! The depth of an expression counts the expressions nested in one another
+//! along the subexpressions that are looked into, which are the elements,
+//! arguments, operands and receivers of the expression that are always
+//! evaluated, in order, when the expression is. The subexpressions that are
+//! evaluated conditionally, such as the right-hand side of `&&` or the
+//! branches of an `if`, are left where they are, as are the bodies of
+//! closures, async blocks and macro invocations. The blocks nested in the
+//! code are split on their own, each with its own `let` statements.
+//!
+//! A subexpression that is moved out runs ahead of the rest of its
+//! statement, so every subexpression that would have run before it is moved
+//! out too, other than a literal or a path, which are taken to have no
+//! effects. Place expressions such as `v[i]` or the operand of a borrow are
+//! never moved out, because a `let` would move out of the place instead of
+//! referring to it, and neither are closures, whose parameter types may be
+//! inferred from where they are used. Splitting is therefore best effort,
+//! and an expression may stay deeper than the maximum where no subexpression
+//! can be moved out.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"visit-mut"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, Expr};
+//!
+//! let expr: Expr = parse_quote!(f(g(h(x)), 1));
+//! let split = syn::split::split_expr(expr, 3);
+//!
+//! let expected = quote!({
+//!     let __split = g(h(x));
+//!     f(__split, 1)
+//! });
+//! assert_eq!(quote!(#split).to_string(), expected.to_string());
+//! ```
+
+use super::*;
+use crate::punctuated::Punctuated;
+use crate::visit_mut::{self, VisitMut};
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use std::cmp;
+use std::collections::HashSet;
+use std::mem;
+
+/// Splits the expressions of the statements in a block, and of the blocks
+/// nested in it, to a depth of at most `max_depth`.
+///
+/// The identifiers of the `let` statements are `__split`, `__split_1`,
+/// `__split_2`, and so on, leaving out those that already occur anywhere in
+/// the block, including inside of the bodies of macro invocations. Returns
+/// the number of `let` statements added.
+///
+/// See the [module documentation](self) for which subexpressions are moved
+/// out.
+///
+/// # Panics
+///
+/// Panics if `max_depth` is less than 2, which leaves no room for an
+/// expression to have subexpressions.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+pub fn split_block(block: &mut Block, max_depth: usize) -> usize {
+    assert!(max_depth >= 2, "the maximum depth must be at least 2");
+    let mut names = NameCollector(HashSet::new());
+    names.visit_block_mut(block);
+    let mut splitter = Splitter {
+        max_depth,
+        names: names.0,
+        next: 0,
+        frozen: false,
+        count: 0,
+    };
+    splitter.visit_block_mut(block);
+    splitter.count
+}
+
+/// Splits an expression to a depth of at most `max_depth`.
+///
+/// If any subexpression is moved out, the result is a block expression with
+/// the `let` statements followed by the expression, and otherwise it is the
+/// expression itself. This is the same as [`split_block`] on a block of only
+/// the expression.
+///
+/// # Panics
+///
+/// Panics if `max_depth` is less than 2.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+pub fn split_expr(expr: Expr, max_depth: usize) -> Expr {
+    let mut block = Block {
+        brace_token: token::Brace::default(),
+        stmts: vec![Stmt::Expr(expr)],
+    };
+    if split_block(&mut block, max_depth) > 0 {
+        return Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block,
+        });
+    }
+    match block.stmts.pop() {
+        Some(Stmt::Expr(expr)) => expr,
+        _ => unreachable!(),
+    }
+}
+
+struct NameCollector(HashSet<String>);
+
+impl VisitMut for NameCollector {
+    fn visit_ident_mut(&mut self, ident: &mut Ident) {
+        self.0.insert(ident.to_string());
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        visit_mut::visit_macro_mut(self, mac);
+        collect_token_names(mac.tokens.clone(), &mut self.0);
+    }
+}
+
+fn collect_token_names(tokens: TokenStream, names: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_token_names(group.stream(), names),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+// How a subexpression is used by the expression it is in.
+enum Position {
+    // Evaluated to a value.
+    Value,
+    // The receiver of a method call or the base of a field access or index,
+    // which may be borrowed mutably.
+    Base,
+    // The operand of a borrow or the left-hand side of an assignment, which
+    // must stay where it is.
+    Place,
+}
+
+impl Copy for Position {}
+
+impl Clone for Position {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+struct Splitter {
+    max_depth: usize,
+    names: HashSet<String>,
+    next: usize,
+    // Whether the expressions are only measured, because moving any of them
+    // out would reorder them with an expression that cannot be moved out.
+    frozen: bool,
+    count: usize,
+}
+
+struct Child<'a> {
+    expr: &'a mut Expr,
+    position: Position,
+    // Whether the expression may be moved out, which is never the case for
+    // one that runs after an expression that cannot be.
+    movable: bool,
+    depth: usize,
+    lets: Vec<Stmt>,
+}
+
+impl Splitter {
+    // Splits the subexpressions of an expression, adding the statements that
+    // have to run before it to `lets`, and returns the remaining depth of the
+    // expression.
+    fn split(&mut self, expr: &mut Expr, position: Position, lets: &mut Vec<Stmt>) -> usize {
+        let subexprs = subexprs(expr, position);
+        let blocked = subexprs
+            .iter()
+            .position(|(expr, position)| !is_trivial(expr) && !can_move(expr, *position))
+            .unwrap_or(subexprs.len());
+
+        let mut children = Vec::new();
+        for (i, (expr, position)) in subexprs.into_iter().enumerate() {
+            let movable = !self.frozen && i < blocked && can_move(expr, position);
+            let mut child_lets = Vec::new();
+            let frozen = self.frozen;
+            self.frozen |= i > blocked;
+            let depth = self.split(expr, position, &mut child_lets);
+            self.frozen = frozen;
+            children.push(Child {
+                expr,
+                position,
+                movable,
+                depth,
+                lets: child_lets,
+            });
+        }
+
+        let max_depth = self.max_depth;
+        let too_deep = |child: &Child| child.movable && child.depth >= max_depth;
+        let last = children
+            .iter()
+            .rposition(|child| !child.lets.is_empty() || too_deep(child));
+
+        let mut depth = 0;
+        for (i, mut child) in children.into_iter().enumerate() {
+            lets.append(&mut child.lets);
+            let runs_early = last.map_or(false, |last| i < last) && !is_trivial(child.expr);
+            if too_deep(&child) || runs_early {
+                self.move_out(child.expr, child.position, lets);
+                child.depth = 1;
+            }
+            depth = cmp::max(depth, child.depth);
+        }
+        depth + 1
+    }
+
+    fn move_out(&mut self, expr: &mut Expr, position: Position, lets: &mut Vec<Stmt>) {
+        let ident = self.fresh();
+        let value = mem::replace(
+            expr,
+            Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(ident.clone()),
+            }),
+        );
+        let mut attrs = Vec::new();
+        let mut mutability = None;
+        if let Position::Base = position {
+            attrs.push(allow_unused_mut());
+            mutability = Some(<Token![mut]>::default());
+        }
+        lets.push(Stmt::Local(Local {
+            attrs,
+            let_token: <Token![let]>::default(),
+            pat: Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability,
+                ident,
+                subpat: None,
+            }),
+            init: Some((<Token![=]>::default(), Box::new(value))),
+            semi_token: <Token![;]>::default(),
+        }));
+        self.count += 1;
+    }
+
+    fn fresh(&mut self) -> Ident {
+        loop {
+            let name = match self.next {
+                0 => "__split".to_owned(),
+                n => format!("__split_{}", n),
+            };
+            self.next += 1;
+            if !self.names.contains(&name) {
+                return Ident::new(&name, Span::call_site());
+            }
+        }
+    }
+}
+
+impl VisitMut for Splitter {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        visit_mut::visit_block_mut(self, block);
+        for mut stmt in mem::replace(&mut block.stmts, Vec::new()) {
+            let mut lets = Vec::new();
+            match &mut stmt {
+                Stmt::Local(local) => {
+                    if let Some((_, init)) = &mut local.init {
+                        self.split(init, Position::Value, &mut lets);
+                    }
+                }
+                Stmt::Expr(expr) | Stmt::Semi(expr, _) => {
+                    self.split(expr, Position::Value, &mut lets);
+                }
+                Stmt::Item(_) => {}
+            }
+            block.stmts.append(&mut lets);
+            block.stmts.push(stmt);
+        }
+    }
+}
+
+// The subexpressions that are always evaluated with an expression, in the
+// order in which they are evaluated.
+fn subexprs(expr: &mut Expr, position: Position) -> Vec<(&mut Expr, Position)> {
+    let mut subexprs = Vec::new();
+    match expr {
+        Expr::Array(e) => values(&mut subexprs, &mut e.elems),
+        Expr::Assign(e) => {
+            subexprs.push((&mut *e.right, Position::Value));
+            subexprs.push((&mut *e.left, Position::Place));
+        }
+        Expr::AssignOp(e) => {
+            subexprs.push((&mut *e.left, Position::Place));
+            subexprs.push((&mut *e.right, Position::Value));
+        }
+        Expr::Await(e) => subexprs.push((&mut *e.base, Position::Value)),
+        Expr::Binary(e) => {
+            subexprs.push((&mut *e.left, Position::Value));
+            match e.op {
+                BinOp::And(_) | BinOp::Or(_) => {}
+                _ => subexprs.push((&mut *e.right, Position::Value)),
+            }
+        }
+        Expr::Box(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        Expr::Break(e) => {
+            if let Some(expr) = &mut e.expr {
+                subexprs.push((&mut **expr, Position::Value));
+            }
+        }
+        Expr::Call(e) => values(&mut subexprs, &mut e.args),
+        Expr::Cast(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        Expr::Field(e) => subexprs.push((&mut *e.base, Position::Base)),
+        Expr::Group(e) => subexprs.push((&mut *e.expr, position)),
+        Expr::Index(e) => {
+            subexprs.push((&mut *e.expr, Position::Base));
+            subexprs.push((&mut *e.index, Position::Value));
+        }
+        Expr::MethodCall(e) => {
+            subexprs.push((&mut *e.receiver, Position::Base));
+            values(&mut subexprs, &mut e.args);
+        }
+        Expr::Paren(e) => subexprs.push((&mut *e.expr, position)),
+        Expr::Range(e) => {
+            if let Some(from) = &mut e.from {
+                subexprs.push((&mut **from, Position::Value));
+            }
+            if let Some(to) = &mut e.to {
+                subexprs.push((&mut **to, Position::Value));
+            }
+        }
+        Expr::Reference(e) => subexprs.push((&mut *e.expr, Position::Place)),
+        Expr::Repeat(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        Expr::Return(e) => {
+            if let Some(expr) = &mut e.expr {
+                subexprs.push((&mut **expr, Position::Value));
+            }
+        }
+        Expr::Struct(e) => {
+            for field in &mut e.fields {
+                subexprs.push((&mut field.expr, Position::Value));
+            }
+            if let Some(rest) = &mut e.rest {
+                subexprs.push((&mut **rest, Position::Value));
+            }
+        }
+        Expr::Try(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        Expr::Tuple(e) => values(&mut subexprs, &mut e.elems),
+        Expr::Type(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        Expr::Unary(e) => subexprs.push((&mut *e.expr, Position::Value)),
+        _ => {}
+    }
+    subexprs
+}
+
+fn values<'a, P>(subexprs: &mut Vec<(&'a mut Expr, Position)>, exprs: &'a mut Punctuated<Expr, P>) {
+    for expr in exprs {
+        subexprs.push((expr, Position::Value));
+    }
+}
+
+// Whether an expression has no effects, so that its order relative to other
+// expressions does not matter.
+fn is_trivial(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) | Expr::Path(_) => true,
+        Expr::Paren(e) => is_trivial(&e.expr),
+        Expr::Group(e) => is_trivial(&e.expr),
+        _ => false,
+    }
+}
+
+fn is_place(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) | Expr::Field(_) | Expr::Index(_) => true,
+        Expr::Unary(e) => match e.op {
+            UnOp::Deref(_) => true,
+            _ => false,
+        },
+        Expr::Paren(e) => is_place(&e.expr),
+        Expr::Group(e) => is_place(&e.expr),
+        _ => false,
+    }
+}
+
+fn can_move(expr: &Expr, position: Position) -> bool {
+    match expr {
+        Expr::Closure(_) | Expr::Let(_) | Expr::Macro(_) | Expr::Verbatim(_) => false,
+        _ => match position {
+            Position::Place => false,
+            Position::Value | Position::Base => !is_place(expr),
+        },
+    }
+}
+
+fn allow_unused_mut() -> Attribute {
+    let span = Span::call_site();
+    let lint = TokenTree::Ident(Ident::new("unused_mut", span));
+    Attribute {
+        pound_token: <Token![#]>::default(),
+        style: AttrStyle::Outer,
+        bracket_token: token::Bracket::default(),
+        path: Path::from(Ident::new("allow", span)),
+        tokens: TokenStream::from(TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenStream::from(lint),
+        ))),
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use proc_macro2::Span;
+use std::mem;
 
 ast_struct! {
     /// A braced block containing Rust statements.
@@ -46,6 +48,88 @@ ast_struct! {
     }
 }
 
+impl Block {
+    /// Inserts statements at the start of the block, before all of its
+    /// existing statements.
+    ///
+    /// *This function is available only if Syn is built with the `"full"`
+    /// feature.*
+    pub fn prepend_stmts<I>(&mut self, stmts: I)
+    where
+        I: IntoIterator<Item = Stmt>,
+    {
+        let rest = mem::replace(&mut self.stmts, Vec::new());
+        self.stmts.extend(stmts);
+        self.stmts.extend(rest);
+    }
+
+    /// Arranges for statements to run after the body of a function with the
+    /// given signature, however the body is exited.
+    ///
+    /// The body is replaced by `{ let __result = <body>; <stmts> __result }`
+    /// where `<body>` is the original body wrapped by
+    /// [`Signature::wrap_body`], so the statements also run after an early
+    /// `return` or a `?` that fails, and the function still returns what the
+    /// original body evaluated to. The statements cannot refer to the
+    /// `__result` binding, which is hygienic, and are therefore unaffected by
+    /// the body's own variables.
+    ///
+    /// The body of a `const fn` cannot be wrapped, so `return` in it skips the
+    /// appended statements.
+    ///
+    /// *This function is available only if Syn is built with the `"full"` and
+    /// `"clone-impls"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemFn};
+    ///
+    /// let mut item: ItemFn = parse_quote! {
+    ///     fn lookup(&self, key: &str) -> Option<&Value> {
+    ///         let index = self.index.get(key)?;
+    ///         Some(&self.values[*index])
+    ///     }
+    /// };
+    ///
+    /// item.block.prepend_stmts(vec![parse_quote!(let start = Instant::now();)]);
+    /// item.block.append_stmts(
+    ///     &item.sig,
+    ///     vec![parse_quote!(record("lookup", start.elapsed());)],
+    /// );
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn append_stmts<I>(&mut self, sig: &Signature, stmts: I)
+    where
+        I: IntoIterator<Item = Stmt>,
+    {
+        let span = Span::call_site();
+        let brace_token = self.brace_token;
+        let body = mem::replace(
+            self,
+            Block {
+                brace_token,
+                stmts: Vec::new(),
+            },
+        );
+        let result = crate::ident::fresh_ident("result", span);
+        self.stmts.push(Stmt::Local(Local {
+            attrs: Vec::new(),
+            let_token: Token![let](span),
+            pat: item::ident_pat(result.clone()),
+            init: Some((Token![=](span), Box::new(sig.wrap_body(body)))),
+            semi_token: Token![;](span),
+        }));
+        self.stmts.extend(stmts);
+        self.stmts.push(Stmt::Expr(Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: Path::from(result),
+        })));
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
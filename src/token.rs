@@ -101,7 +101,7 @@ use crate::lit::{Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, L
 use crate::lookahead;
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, ParseStream};
-use crate::span::IntoSpans;
+use crate::spans::IntoSpans;
 #[cfg(any(feature = "parsing", feature = "printing"))]
 use proc_macro2::Ident;
 use proc_macro2::Span;
@@ -152,13 +152,11 @@ impl private::Sealed for Ident {}
 
 #[cfg(feature = "parsing")]
 fn peek_impl(cursor: Cursor, peek: fn(ParseStream) -> bool) -> bool {
-    use crate::parse::Unexpected;
-    use std::cell::Cell;
-    use std::rc::Rc;
+    use crate::parse::UnexpectedSlots;
 
     let scope = Span::call_site();
-    let unexpected = Rc::new(Cell::new(Unexpected::None));
-    let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected);
+    let unexpected = UnexpectedSlots::default();
+    let buffer = crate::parse::new_parse_buffer(scope, cursor, &unexpected);
     peek(&buffer)
 }
 
@@ -305,6 +303,20 @@ macro_rules! define_keywords {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "extra-traits")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+            impl crate::hash::StableHash for $name {
+                fn stable_hash(&self, _hasher: &mut crate::hash::StableHasher) {}
+            }
+
+            #[cfg(feature = "printing")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+            impl crate::span::ToTokensSpanned for $name {
+                fn set_spans(&mut self, span: Span) {
+                    self.span = span;
+                }
+            }
+
             #[cfg(feature = "printing")]
             #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
             impl ToTokens for $name {
@@ -428,6 +440,20 @@ macro_rules! define_punctuation_structs {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "extra-traits")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+            impl crate::hash::StableHash for $name {
+                fn stable_hash(&self, _hasher: &mut crate::hash::StableHasher) {}
+            }
+
+            #[cfg(feature = "printing")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+            impl crate::span::ToTokensSpanned for $name {
+                fn set_spans(&mut self, span: Span) {
+                    self.spans = [span; $len];
+                }
+            }
+
             impl_deref_if_len_is_1!($name/$len);
         )*
     };
@@ -537,6 +563,20 @@ macro_rules! define_delimiters {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "extra-traits")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+            impl crate::hash::StableHash for $name {
+                fn stable_hash(&self, _hasher: &mut crate::hash::StableHasher) {}
+            }
+
+            #[cfg(feature = "printing")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+            impl crate::span::ToTokensSpanned for $name {
+                fn set_spans(&mut self, span: Span) {
+                    self.span = span;
+                }
+            }
+
             impl $name {
                 #[cfg(feature = "printing")]
                 pub fn surround<F>(&self, tokens: &mut TokenStream, f: F)
@@ -704,6 +744,16 @@ define_keywords! {
     "yield"       pub struct Yield        /// `yield`
 }
 
+// The keywords of the clauses parsed by the "verus" feature. Without it, these
+// are ordinary identifiers.
+#[cfg(feature = "verus")]
+define_keywords! {
+    "decreases"   pub struct Decreases    /// `decreases`
+    "ensures"     pub struct Ensures      /// `ensures`
+    "invariant"   pub struct Invariant    /// `invariant`
+    "requires"    pub struct Requires     /// `requires`
+}
+
 define_punctuation! {
     "+"           pub struct Add/1        /// `+`
     "+="          pub struct AddEq/2      /// `+=`
@@ -783,11 +833,13 @@ macro_rules! export_token_macro {
             [const]       => { $crate::token::Const };
             [continue]    => { $crate::token::Continue };
             [crate]       => { $crate::token::Crate };
+            [decreases]   => { $crate::token::Decreases };
             [default]     => { $crate::token::Default };
             [do]          => { $crate::token::Do };
             [dyn]         => { $crate::token::Dyn };
             [else]        => { $crate::token::Else };
             [enum]        => { $crate::token::Enum };
+            [ensures]     => { $crate::token::Ensures };
             [extern]      => { $crate::token::Extern };
             [final]       => { $crate::token::Final };
             [fn]          => { $crate::token::Fn };
@@ -795,6 +847,7 @@ macro_rules! export_token_macro {
             [if]          => { $crate::token::If };
             [impl]        => { $crate::token::Impl };
             [in]          => { $crate::token::In };
+            [invariant]   => { $crate::token::Invariant };
             [let]         => { $crate::token::Let };
             [loop]        => { $crate::token::Loop };
             [macro]       => { $crate::token::Macro };
@@ -806,6 +859,7 @@ macro_rules! export_token_macro {
             [priv]        => { $crate::token::Priv };
             [pub]         => { $crate::token::Pub };
             [ref]         => { $crate::token::Ref };
+            [requires]    => { $crate::token::Requires };
             [return]      => { $crate::token::Return };
             [Self]        => { $crate::token::SelfType };
             [self]        => { $crate::token::SelfValue };
@@ -890,7 +944,7 @@ pub mod parsing {
     use crate::buffer::Cursor;
     use crate::error::{Error, Result};
     use crate::parse::ParseStream;
-    use crate::span::FromSpans;
+    use crate::spans::FromSpans;
     use proc_macro2::{Spacing, Span};
 
     pub fn keyword(input: ParseStream, token: &str) -> Result<Span> {
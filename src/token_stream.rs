@@ -0,0 +1,280 @@
+//! Comparison and splitting of token streams by their tokens alone.
+//!
+//! Tests of procedural macros commonly check the generated code by comparing
+//! the `to_string()` of its token stream against that of the expected tokens.
+//! The strings can differ where the tokens do not, for example when one of
+//! the streams went through an invisible group from a `macro_rules!`
+//! fragment, or when the spacing of a punctuation character that is followed
+//! by an identifier was recorded differently. [`eq`] compares the tokens
+//! themselves.
+//!
+//! Function-like macros whose input is a list of arbitrary tokens can split
+//! it into its elements with [`split_top_level`], without parsing the
+//! elements or counting the depth of brackets by hand.
+//!
+//! # Example
+//!
+//! ```
+//! use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+//! use quote::quote;
+//!
+//! // `a + b` with `b` in an invisible group, as if it had been an `$b:expr`.
+//! let b = TokenTree::Group(Group::new(Delimiter::None, quote!(b)));
+//! let generated = quote!(a + #b);
+//! let expected: TokenStream = "a + b".parse().unwrap();
+//!
+//! assert!(syn::token_stream::eq(&generated, &expected));
+//! assert!(!syn::token_stream::eq(&generated, &quote!(a - b)));
+//! ```
+
+#[cfg(feature = "parsing")]
+use crate::buffer::{Cursor, TokenBuffer};
+#[cfg(feature = "parsing")]
+use crate::lookahead::Peek;
+#[cfg(feature = "parsing")]
+use crate::token::Token;
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+#[cfg(feature = "parsing")]
+use proc_macro2::{Punct, Span};
+#[cfg(feature = "parsing")]
+use std::mem;
+
+/// Whether two token streams consist of the same tokens.
+///
+/// Spans are ignored, as are the delimiters of invisible groups, whose
+/// contents are compared as if they were not in a group. The spacing of a
+/// punctuation character is only compared where it is followed by another
+/// punctuation character, which is where it tells `<<` apart from `< <`.
+/// Literals are compared by how they are written, so `1u8` and `1_u8` are
+/// different tokens.
+pub fn eq(a: &TokenStream, b: &TokenStream) -> bool {
+    let mut a_atoms = Vec::new();
+    flatten(a.clone(), &mut a_atoms);
+    let mut b_atoms = Vec::new();
+    flatten(b.clone(), &mut b_atoms);
+    a_atoms.len() == b_atoms.len()
+        && a_atoms
+            .iter()
+            .enumerate()
+            .all(|(i, atom)| same(atom, &b_atoms[i], a_atoms.get(i + 1)))
+}
+
+// A token stream as a flat sequence, with the delimiters of visible groups
+// as tokens of their own.
+enum Atom {
+    Open(Delimiter),
+    Close(Delimiter),
+    Punct(char, Spacing),
+    Ident(String),
+    Literal(String),
+}
+
+fn flatten(stream: TokenStream, atoms: &mut Vec<Atom>) {
+    for tt in stream {
+        match tt {
+            TokenTree::Group(group) => {
+                let delimiter = group.delimiter();
+                if delimiter == Delimiter::None {
+                    flatten(group.stream(), atoms);
+                } else {
+                    atoms.push(Atom::Open(delimiter));
+                    flatten(group.stream(), atoms);
+                    atoms.push(Atom::Close(delimiter));
+                }
+            }
+            TokenTree::Punct(punct) => atoms.push(Atom::Punct(punct.as_char(), punct.spacing())),
+            TokenTree::Ident(ident) => atoms.push(Atom::Ident(ident.to_string())),
+            TokenTree::Literal(lit) => atoms.push(Atom::Literal(lit.to_string())),
+        }
+    }
+}
+
+fn same(a: &Atom, b: &Atom, next: Option<&Atom>) -> bool {
+    match (a, b) {
+        (Atom::Open(a), Atom::Open(b)) | (Atom::Close(a), Atom::Close(b)) => a == b,
+        (Atom::Punct(a, a_spacing), Atom::Punct(b, b_spacing)) => {
+            a == b
+                && match next {
+                    Some(Atom::Punct(..)) => a_spacing == b_spacing,
+                    _ => true,
+                }
+        }
+        (Atom::Ident(a), Atom::Ident(b)) | (Atom::Literal(a), Atom::Literal(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Splits a token stream on each occurrence of a separator that is not
+/// inside of a group.
+///
+/// Returns the tokens between the separators, each with the span from its
+/// first token to its last, or just the first where spans cannot be joined.
+/// An empty element between two separators is given the span of the
+/// separator after it. Like in a [`Punctuated`] list, a separator at the end
+/// of the stream does not begin another element, and an empty stream has no
+/// elements.
+///
+/// The tokens inside of parentheses, square brackets, braces and invisible
+/// groups are never split. Angle brackets are not delimiters of groups, so a
+/// comma between generic arguments like in `HashMap<K, V>` does separate two
+/// elements. A separator of punctuation only matches a whole operator, the
+/// way the lexer of Rust splits punctuation into operators, so `=` does not
+/// split `a == b` and `<` does not split `a <= b`.
+///
+/// [`Punctuated`]: crate::punctuated::Punctuated
+///
+/// ```
+/// use quote::quote;
+/// use syn::Token;
+///
+/// let input = quote!(f(a, b), [c, d], e + 1,);
+/// let exprs = syn::token_stream::split_top_level(input, Token![,]);
+///
+/// let exprs: Vec<String> = exprs.iter().map(|(tokens, _span)| tokens.to_string()).collect();
+/// assert_eq!(exprs, ["f (a , b)", "[c , d]", "e + 1"]);
+/// ```
+///
+/// *This function is available only if Syn is built with the `"parsing"`
+/// feature.*
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+pub fn split_top_level<T: Peek>(tokens: TokenStream, separator: T) -> Vec<(TokenStream, Span)> {
+    let _ = separator;
+    let display = T::Token::display();
+    let punct = display.len() > 2
+        && display.starts_with('`')
+        && display[1..display.len() - 1]
+            .bytes()
+            .all(|b| b.is_ascii_punctuation() && b != b'_');
+
+    let buffer = TokenBuffer::new2(tokens);
+    let mut cursor = buffer.begin();
+    let mut splitter = Splitter {
+        elements: Vec::new(),
+        element: TokenStream::new(),
+        span: None,
+    };
+    while let Some((tt, rest)) = cursor.token_tree() {
+        match tt {
+            TokenTree::Punct(_) if punct => {
+                let separator = &display[1..display.len() - 1];
+                let (run, rest) = punct_run(cursor);
+                for op in operators(run, separator) {
+                    if op.len() == separator.len()
+                        && op.iter().map(Punct::as_char).eq(separator.chars())
+                    {
+                        splitter.separate(op[0].span());
+                    } else {
+                        for punct in op {
+                            splitter.push(TokenTree::Punct(punct));
+                        }
+                    }
+                }
+                cursor = rest;
+            }
+            // Peeking looks through invisible groups, but a separator inside
+            // of one is not at the top level.
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::None => {
+                splitter.push(tt);
+                cursor = rest;
+            }
+            _ if !punct && T::Token::peek(cursor) => {
+                splitter.separate(cursor.span());
+                cursor = match cursor.lifetime() {
+                    Some((_lifetime, rest)) => rest,
+                    None => rest,
+                };
+            }
+            _ => {
+                splitter.push(tt);
+                cursor = rest;
+            }
+        }
+    }
+    splitter.finish()
+}
+
+#[cfg(feature = "parsing")]
+struct Splitter {
+    elements: Vec<(TokenStream, Span)>,
+    element: TokenStream,
+    span: Option<Span>,
+}
+
+#[cfg(feature = "parsing")]
+impl Splitter {
+    fn push(&mut self, tt: TokenTree) {
+        let tt_span = tt.span();
+        self.span = Some(match self.span {
+            Some(span) => span.join(tt_span).unwrap_or(span),
+            None => tt_span,
+        });
+        self.element.extend(Some(tt));
+    }
+
+    fn separate(&mut self, separator: Span) {
+        let span = self.span.take().unwrap_or(separator);
+        let element = mem::replace(&mut self.element, TokenStream::new());
+        self.elements.push((element, span));
+    }
+
+    fn finish(mut self) -> Vec<(TokenStream, Span)> {
+        if let Some(span) = self.span {
+            self.elements.push((self.element, span));
+        }
+        self.elements
+    }
+}
+
+// The punctuation characters from `cursor` up to the first that is not
+// joined to the next, or the quote of a lifetime by itself.
+#[cfg(feature = "parsing")]
+fn punct_run(mut cursor: Cursor) -> (Vec<Punct>, Cursor) {
+    let mut run = Vec::new();
+    while let Some((TokenTree::Punct(punct), rest)) = cursor.token_tree() {
+        let lifetime = punct.as_char() == '\'' && cursor.lifetime().is_some();
+        if lifetime && !run.is_empty() {
+            break;
+        }
+        let spacing = punct.spacing();
+        run.push(punct);
+        cursor = rest;
+        if spacing == Spacing::Alone || lifetime {
+            break;
+        }
+    }
+    (run, cursor)
+}
+
+// Operators made of more than one punctuation character, longest first.
+#[cfg(feature = "parsing")]
+const OPERATORS: &[&str] = &[
+    "<<=", ">>=", "...", "..=", "&&", "||", "<<", ">>", "==", "!=", "<=", ">=", "+=", "-=", "*=",
+    "/=", "%=", "^=", "&=", "|=", "->", "<-", "=>", "::", "..",
+];
+
+// Splits a run of joined punctuation characters into operators the way the
+// lexer does, taking the longest at each position, with the separator, which
+// may be custom punctuation, counting as an operator too.
+#[cfg(feature = "parsing")]
+fn operators(run: Vec<Punct>, separator: &str) -> Vec<Vec<Punct>> {
+    let chars: String = run.iter().map(Punct::as_char).collect();
+    let mut lens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let rest = &chars[i..];
+        let len = OPERATORS
+            .iter()
+            .chain(Some(&separator))
+            .filter(|op| rest.starts_with(**op))
+            .map(|op| op.len())
+            .max()
+            .unwrap_or(1);
+        lens.push(len);
+        i += len;
+    }
+    let mut run = run.into_iter();
+    lens.into_iter()
+        .map(|len| run.by_ref().take(len).collect())
+        .collect()
+}
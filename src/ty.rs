@@ -5,15 +5,15 @@ use proc_macro2::TokenStream;
 ast_enum_of_structs! {
     /// The possible types that a Rust value could have.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
     ///
     /// # Syntax tree enum
     ///
     /// This type is a [syntax tree enum].
     ///
     /// [syntax tree enum]: Expr#syntax-tree-enums
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     #[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
     pub enum Type {
         /// A fixed size array type: `[T; n]`.
@@ -91,9 +91,9 @@ ast_enum_of_structs! {
 ast_struct! {
     /// A fixed size array type: `[T; n]`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeArray {
         pub bracket_token: token::Bracket,
         pub elem: Box<Type>,
@@ -105,9 +105,9 @@ ast_struct! {
 ast_struct! {
     /// A bare function type: `fn(usize) -> bool`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeBareFn {
         pub lifetimes: Option<BoundLifetimes>,
         pub unsafety: Option<Token![unsafe]>,
@@ -123,9 +123,9 @@ ast_struct! {
 ast_struct! {
     /// A type contained within invisible delimiters.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeGroup {
         pub group_token: token::Group,
         pub elem: Box<Type>,
@@ -136,9 +136,9 @@ ast_struct! {
     /// An `impl Bound1 + Bound2 + Bound3` type where `Bound` is a trait or
     /// a lifetime.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeImplTrait {
         pub impl_token: Token![impl],
         pub bounds: Punctuated<TypeParamBound, Token![+]>,
@@ -148,9 +148,9 @@ ast_struct! {
 ast_struct! {
     /// Indication that a type should be inferred by the compiler: `_`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeInfer {
         pub underscore_token: Token![_],
     }
@@ -159,9 +159,9 @@ ast_struct! {
 ast_struct! {
     /// A macro in the type position.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeMacro {
         pub mac: Macro,
     }
@@ -170,9 +170,9 @@ ast_struct! {
 ast_struct! {
     /// The never type: `!`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeNever {
         pub bang_token: Token![!],
     }
@@ -181,9 +181,9 @@ ast_struct! {
 ast_struct! {
     /// A parenthesized type equivalent to the inner type.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeParen {
         pub paren_token: token::Paren,
         pub elem: Box<Type>,
@@ -194,9 +194,9 @@ ast_struct! {
     /// A path like `std::slice::Iter`, optionally qualified with a
     /// self-type as in `<Vec<T> as SomeTrait>::Associated`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypePath {
         pub qself: Option<QSelf>,
         pub path: Path,
@@ -206,9 +206,9 @@ ast_struct! {
 ast_struct! {
     /// A raw pointer type: `*const T` or `*mut T`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypePtr {
         pub star_token: Token![*],
         pub const_token: Option<Token![const]>,
@@ -220,9 +220,9 @@ ast_struct! {
 ast_struct! {
     /// A reference type: `&'a T` or `&'a mut T`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeReference {
         pub and_token: Token![&],
         pub lifetime: Option<Lifetime>,
@@ -234,9 +234,9 @@ ast_struct! {
 ast_struct! {
     /// A dynamically sized slice type: `[T]`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeSlice {
         pub bracket_token: token::Bracket,
         pub elem: Box<Type>,
@@ -247,9 +247,9 @@ ast_struct! {
     /// A trait object type `dyn Bound1 + Bound2 + Bound3` where `Bound` is a
     /// trait or a lifetime.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeTraitObject {
         pub dyn_token: Option<Token![dyn]>,
         pub bounds: Punctuated<TypeParamBound, Token![+]>,
@@ -259,9 +259,9 @@ ast_struct! {
 ast_struct! {
     /// A tuple type: `(A, B, C, String)`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or
-    /// `"full"` feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`,
+    /// `"full"` or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct TypeTuple {
         pub paren_token: token::Paren,
         pub elems: Punctuated<Type, Token![,]>,
@@ -271,21 +271,442 @@ ast_struct! {
 ast_struct! {
     /// The binary interface of a function: `extern "C"`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Abi {
         pub extern_token: Token![extern],
         pub name: Option<LitStr>,
     }
 }
 
+ast_enum! {
+    /// The name of a binary interface: the `"C"` in `extern "C"`, as returned
+    /// by [`Abi::kind`].
+    ///
+    /// Every variant holds the string literal as written, so that the span and
+    /// exact spelling of the name are preserved. Names not recognized by Syn
+    /// are represented by `AbiName::Other`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
+    #[cfg_attr(not(syn_no_non_exhaustive), non_exhaustive)]
+    pub enum AbiName {
+        /// `extern "Rust"`
+        Rust(LitStr),
+        /// `extern "C"`
+        C(LitStr),
+        /// `extern "C-unwind"`
+        CUnwind(LitStr),
+        /// `extern "system"`
+        System(LitStr),
+        /// `extern "system-unwind"`
+        SystemUnwind(LitStr),
+        /// `extern "cdecl"`
+        Cdecl(LitStr),
+        /// `extern "stdcall"`
+        Stdcall(LitStr),
+        /// `extern "fastcall"`
+        Fastcall(LitStr),
+        /// `extern "vectorcall"`
+        Vectorcall(LitStr),
+        /// `extern "thiscall"`
+        Thiscall(LitStr),
+        /// `extern "aapcs"`
+        Aapcs(LitStr),
+        /// `extern "win64"`
+        Win64(LitStr),
+        /// `extern "sysv64"`
+        Sysv64(LitStr),
+        /// `extern "efiapi"`
+        Efiapi(LitStr),
+        /// Any other ABI name, such as `extern "rust-call"`.
+        ///
+        /// A name that is recognized by a later version of Syn gets a variant
+        /// of its own, so code that handles a name by matching on `Other`
+        /// should match on [`AbiName::value`] instead.
+        Other(LitStr),
+
+        // Not public API.
+        //
+        // For testing exhaustiveness in downstream code, use the idiom
+        // documented on `Type`.
+        #[cfg(syn_no_non_exhaustive)]
+        #[doc(hidden)]
+        __NonExhaustive,
+    }
+}
+
+impl Type {
+    /// A reference type `&'a mut T`, with the lifetime and `mut` only if
+    /// given.
+    ///
+    /// All tokens are spanned at `Span::call_site()`, as are the tokens of the
+    /// other constructors below.
+    pub fn reference(lifetime: Option<Lifetime>, mutability: bool, inner: Type) -> Self {
+        Type::Reference(TypeReference {
+            and_token: Default::default(),
+            lifetime,
+            mutability: if mutability {
+                Some(Default::default())
+            } else {
+                None
+            },
+            elem: Box::new(inner),
+        })
+    }
+
+    /// A slice type `[T]`.
+    pub fn slice(inner: Type) -> Self {
+        Type::Slice(TypeSlice {
+            bracket_token: Default::default(),
+            elem: Box::new(inner),
+        })
+    }
+
+    /// A tuple type `(A, B, C)` of the given element types.
+    ///
+    /// A tuple of one element gets the trailing comma that distinguishes it
+    /// from a parenthesized type, as in `(A,)`.
+    pub fn tuple<I: IntoIterator<Item = Type>>(elems: I) -> Self {
+        let mut elems: Punctuated<Type, Token![,]> = elems.into_iter().collect();
+        if elems.len() == 1 {
+            elems.push_punct(Default::default());
+        }
+        Type::Tuple(TypeTuple {
+            paren_token: Default::default(),
+            elems,
+        })
+    }
+
+    /// The unit type `()`.
+    pub fn unit() -> Self {
+        Type::tuple(None)
+    }
+}
+
+impl TypeArray {
+    /// Evaluates the length of the array if it is an integer expression of
+    /// literals, such as the `4` in `[u8; 4]` or the `2 * 8 + 1` in `[u8; 2 *
+    /// 8 + 1]`.
+    ///
+    /// The expression may consist of integer literals, parentheses, unary
+    /// `-` and the binary arithmetic and bitwise operators. Returns `None` for
+    /// any other expression, or if the arithmetic overflows or the length
+    /// comes out negative.
+    pub fn len_value(&self) -> Option<u128> {
+        self.len_value_with(|_path| None)
+    }
+
+    /// Evaluates the length of the array like [`len_value`], with the values
+    /// of named constants, such as the const generic parameter in `[u8; N]`,
+    /// looked up by a function.
+    ///
+    /// [`len_value`]: TypeArray::len_value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use syn::{parse_quote, TypeArray};
+    /// #
+    /// let ty: TypeArray = parse_quote!([u8; N + 1]);
+    /// assert_eq!(ty.len_value(), None);
+    ///
+    /// let len = ty.len_value_with(|path| if path.is_ident("N") { Some(31) } else { None });
+    /// assert_eq!(len, Some(32));
+    /// ```
+    pub fn len_value_with<F>(&self, mut lookup: F) -> Option<u128>
+    where
+        F: FnMut(&Path) -> Option<u128>,
+    {
+        let len = ArrayLen::of(&self.len, &mut lookup)?;
+        if !len.terms.is_empty() || len.constant < 0 {
+            return None;
+        }
+        Some(len.constant as u128)
+    }
+
+    /// Returns the path of the named constant that is the length of the
+    /// array, such as the `N` in `[u8; N]`.
+    ///
+    /// Returns `None` if the length is any other expression, including
+    /// arithmetic on named constants.
+    pub fn len_const(&self) -> Option<&Path> {
+        let mut len = &self.len;
+        loop {
+            len = match len {
+                Expr::Paren(expr) => &expr.expr,
+                #[cfg(feature = "full")]
+                Expr::Group(expr) => &expr.expr,
+                Expr::Path(expr) if expr.qself.is_none() => return Some(&expr.path),
+                _ => return None,
+            };
+        }
+    }
+
+    /// Compares the lengths of two arrays without knowing the values of the
+    /// named constants in them.
+    ///
+    /// The lengths are taken to be sums of integer multiples of named
+    /// constants, plus an integer, in which the named constants are
+    /// distinguished by their path, which must not have generic arguments.
+    /// Returns `Some(true)` if the sums are the same, as for `[u8; N + 1]`
+    /// and `[u8; 1 + N]`, `Some(false)` if they differ by an integer, as for
+    /// `[u8; N]` and `[u8; N + 1]`, and `None` otherwise, as for `[u8; N]`
+    /// and `[u8; M]` or for lengths that are not of that form, like `[u8; N *
+    /// M]`.
+    pub fn same_len(&self, other: &TypeArray) -> Option<bool> {
+        let mut lookup = |_: &Path| None;
+        let a = ArrayLen::of(&self.len, &mut lookup)?;
+        let b = ArrayLen::of(&other.len, &mut lookup)?;
+        if a.terms != b.terms {
+            None
+        } else {
+            Some(a.constant == b.constant)
+        }
+    }
+}
+
+// The length of an array as an integer plus the sum of integer multiples of
+// named constants, sorted by the names of the constants and with no zero
+// multiples.
+struct ArrayLen {
+    constant: i128,
+    terms: Vec<(String, i128)>,
+}
+
+impl ArrayLen {
+    fn of(expr: &Expr, lookup: &mut dyn FnMut(&Path) -> Option<u128>) -> Option<Self> {
+        match expr {
+            Expr::Lit(expr) => match &expr.lit {
+                Lit::Int(int) => Some(ArrayLen::constant(int.base10_digits().parse().ok()?)),
+                _ => None,
+            },
+            Expr::Path(expr) if expr.qself.is_none() && is_plain_path(&expr.path) => {
+                match lookup(&expr.path) {
+                    Some(value) if value <= i128::max_value() as u128 => {
+                        Some(ArrayLen::constant(value as i128))
+                    }
+                    Some(_) => None,
+                    None => Some(ArrayLen {
+                        constant: 0,
+                        terms: vec![(path_key(&expr.path), 1)],
+                    }),
+                }
+            }
+            Expr::Paren(expr) => ArrayLen::of(&expr.expr, lookup),
+            #[cfg(feature = "full")]
+            Expr::Group(expr) => ArrayLen::of(&expr.expr, lookup),
+            Expr::Unary(expr) => match expr.op {
+                UnOp::Neg(_) => ArrayLen::of(&expr.expr, lookup)?.scale(-1),
+                _ => None,
+            },
+            Expr::Binary(expr) => {
+                let left = ArrayLen::of(&expr.left, lookup)?;
+                let right = ArrayLen::of(&expr.right, lookup)?;
+                match &expr.op {
+                    BinOp::Add(_) => left.add(right),
+                    BinOp::Sub(_) => left.add(right.scale(-1)?),
+                    BinOp::Mul(_) if left.terms.is_empty() => right.scale(left.constant),
+                    BinOp::Mul(_) if right.terms.is_empty() => left.scale(right.constant),
+                    _ if !left.terms.is_empty() || !right.terms.is_empty() => None,
+                    op => {
+                        let (a, b) = (left.constant, right.constant);
+                        let value = match op {
+                            BinOp::Div(_) => a.checked_div(b)?,
+                            BinOp::Rem(_) => a.checked_rem(b)?,
+                            BinOp::BitXor(_) => a ^ b,
+                            BinOp::BitAnd(_) => a & b,
+                            BinOp::BitOr(_) => a | b,
+                            BinOp::Shl(_) if b >= 0 && b < 127 => a.checked_mul(1 << b)?,
+                            BinOp::Shr(_) if b >= 0 && b < 128 => a >> b,
+                            _ => return None,
+                        };
+                        Some(ArrayLen::constant(value))
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn constant(constant: i128) -> Self {
+        ArrayLen {
+            constant,
+            terms: Vec::new(),
+        }
+    }
+
+    fn scale(mut self, factor: i128) -> Option<Self> {
+        self.constant = self.constant.checked_mul(factor)?;
+        for term in &mut self.terms {
+            term.1 = term.1.checked_mul(factor)?;
+        }
+        self.terms.retain(|term| term.1 != 0);
+        Some(self)
+    }
+
+    fn add(mut self, other: Self) -> Option<Self> {
+        self.constant = self.constant.checked_add(other.constant)?;
+        for (name, factor) in other.terms {
+            match self.terms.binary_search_by(|term| term.0.cmp(&name)) {
+                Ok(i) => self.terms[i].1 = self.terms[i].1.checked_add(factor)?,
+                Err(i) => self.terms.insert(i, (name, factor)),
+            }
+        }
+        self.terms.retain(|term| term.1 != 0);
+        Some(self)
+    }
+}
+
+fn is_plain_path(path: &Path) -> bool {
+    path.segments
+        .iter()
+        .all(|segment| segment.arguments.is_none())
+}
+
+fn path_key(path: &Path) -> String {
+    let mut key = String::new();
+    if path.leading_colon.is_some() {
+        key.push_str("::");
+    }
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i > 0 {
+            key.push_str("::");
+        }
+        key.push_str(&segment.ident.to_string());
+    }
+    key
+}
+
+impl Abi {
+    /// Returns the ABI name as a string, or `None` for a bare `extern` with no
+    /// name.
+    pub fn name_value(&self) -> Option<String> {
+        self.name.as_ref().map(LitStr::value)
+    }
+
+    /// Returns the ABI name classified by its value, or `None` for a bare
+    /// `extern` with no name.
+    ///
+    /// ```
+    /// use syn::{AbiName, TypeBareFn};
+    ///
+    /// let ty: TypeBareFn = syn::parse_str("extern \"C-unwind\" fn()").unwrap();
+    /// match ty.abi.unwrap().kind() {
+    ///     Some(AbiName::CUnwind(_)) => {}
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn kind(&self) -> Option<AbiName> {
+        self.name
+            .as_ref()
+            .map(|name| AbiName::from(name.duplicate()))
+    }
+
+    /// Whether this is the C ABI, either written explicitly as `extern "C"`
+    /// or implied by a bare `extern`.
+    pub fn is_c(&self) -> bool {
+        self.name
+            .as_ref()
+            .map_or(true, |name| name.value_cow() == "C")
+    }
+
+    /// Whether this is `extern "Rust"`.
+    pub fn is_rust(&self) -> bool {
+        self.name
+            .as_ref()
+            .map_or(false, |name| name.value_cow() == "Rust")
+    }
+
+    /// Whether this is `extern "system"`.
+    pub fn is_system(&self) -> bool {
+        self.name
+            .as_ref()
+            .map_or(false, |name| name.value_cow() == "system")
+    }
+}
+
+impl AbiName {
+    /// Returns the string literal holding the name, as written.
+    pub fn lit(&self) -> &LitStr {
+        match self {
+            AbiName::Rust(lit)
+            | AbiName::C(lit)
+            | AbiName::CUnwind(lit)
+            | AbiName::System(lit)
+            | AbiName::SystemUnwind(lit)
+            | AbiName::Cdecl(lit)
+            | AbiName::Stdcall(lit)
+            | AbiName::Fastcall(lit)
+            | AbiName::Vectorcall(lit)
+            | AbiName::Thiscall(lit)
+            | AbiName::Aapcs(lit)
+            | AbiName::Win64(lit)
+            | AbiName::Sysv64(lit)
+            | AbiName::Efiapi(lit)
+            | AbiName::Other(lit) => lit,
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the name as a string, for example `"C-unwind"`.
+    pub fn value(&self) -> String {
+        self.lit().value()
+    }
+
+    /// Whether the name is one of the ABIs recognized by Syn, as opposed to
+    /// `AbiName::Other`.
+    pub fn is_known(&self) -> bool {
+        match self {
+            AbiName::Other(_) => false,
+            _ => true,
+        }
+    }
+
+    /// Whether the ABI permits unwinding out of the function:
+    /// `"Rust"`, `"C-unwind"`, or `"system-unwind"`.
+    pub fn is_unwind(&self) -> bool {
+        match self {
+            AbiName::Rust(_) | AbiName::CUnwind(_) | AbiName::SystemUnwind(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<LitStr> for AbiName {
+    /// Classifies an ABI string literal, producing `AbiName::Other` for any
+    /// name not recognized by Syn.
+    fn from(lit: LitStr) -> Self {
+        match lit.value().as_str() {
+            "Rust" => AbiName::Rust(lit),
+            "C" => AbiName::C(lit),
+            "C-unwind" => AbiName::CUnwind(lit),
+            "system" => AbiName::System(lit),
+            "system-unwind" => AbiName::SystemUnwind(lit),
+            "cdecl" => AbiName::Cdecl(lit),
+            "stdcall" => AbiName::Stdcall(lit),
+            "fastcall" => AbiName::Fastcall(lit),
+            "vectorcall" => AbiName::Vectorcall(lit),
+            "thiscall" => AbiName::Thiscall(lit),
+            "aapcs" => AbiName::Aapcs(lit),
+            "win64" => AbiName::Win64(lit),
+            "sysv64" => AbiName::Sysv64(lit),
+            "efiapi" => AbiName::Efiapi(lit),
+            _ => AbiName::Other(lit),
+        }
+    }
+}
+
 ast_struct! {
     /// An argument in a function type: the `usize` in `fn(usize) -> bool`.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct BareFnArg {
         pub attrs: Vec<Attribute>,
         pub name: Option<(Ident, Token![:])>,
@@ -306,9 +727,9 @@ ast_struct! {
     /// }
     /// ```
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub struct Variadic {
         pub attrs: Vec<Attribute>,
         pub dots: Token![...],
@@ -318,9 +739,9 @@ ast_struct! {
 ast_enum! {
     /// Return type of a function signature.
     ///
-    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
-    /// feature.*
-    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    /// *This type is available only if Syn is built with the `"derive"`, `"full"`
+    /// or `"parse-only"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive", feature = "parse-only"))))]
     pub enum ReturnType {
         /// Return type is not specified.
         ///
@@ -1049,10 +1470,47 @@ pub mod parsing {
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for Abi {
         fn parse(input: ParseStream) -> Result<Self> {
-            Ok(Abi {
-                extern_token: input.parse()?,
-                name: input.parse()?,
-            })
+            let extern_token = input.parse()?;
+            let name = if input.peek(LitStr) {
+                Some(abi_name(input)?)
+            } else {
+                None
+            };
+            Ok(Abi { extern_token, name })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for AbiName {
+        fn parse(input: ParseStream) -> Result<Self> {
+            abi_name(input).map(AbiName::from)
+        }
+    }
+
+    // The string literal naming an ABI, which must be non-empty and have no
+    // suffix.
+    fn abi_name(input: ParseStream) -> Result<LitStr> {
+        let lit: LitStr = input.parse()?;
+        if !lit.suffix().is_empty() {
+            return Err(Error::new(
+                lit.span(),
+                "invalid ABI: string literal must not have a suffix",
+            ));
+        }
+        if lit.value().is_empty() {
+            return Err(Error::new(lit.span(), "invalid ABI: found empty string"));
+        }
+        Ok(lit)
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for Option<AbiName> {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(LitStr) {
+                input.parse().map(Some)
+            } else {
+                Ok(None)
+            }
         }
     }
 
@@ -1252,4 +1710,11 @@ mod printing {
             self.name.to_tokens(tokens);
         }
     }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AbiName {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.lit().to_tokens(tokens);
+        }
+    }
 }
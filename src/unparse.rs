@@ -0,0 +1,2490 @@
+//! Pretty-printing of syntax trees as formatted Rust source.
+//!
+//! Printing a syntax tree through [`ToTokens`] produces a [`TokenStream`]
+//! whose `Display` puts the whole tree on a single line, which is hard to
+//! read when debugging generated code and makes golden-file tests fragile.
+//! The printer in this module walks the syntax tree instead and lays the
+//! code out the way rustfmt would in the common cases: one item, statement,
+//! field, variant and match arm per line, blocks indented by four spaces,
+//! `where` clauses and the `requires`, `ensures`, `decreases` and
+//! `invariant` clauses on lines of their own, and doc attributes written as
+//! `///` comments.
+//!
+//! [`ToTokens`]: quote::ToTokens
+//! [`TokenStream`]: proc_macro2::TokenStream
+//!
+//! The output parses back to the same syntax tree. Lines are not wrapped at
+//! any width by default, so a long expression stays on one line; the width,
+//! the indentation and the placement of braces and trailing commas can be
+//! set with [`PrintOptions`] to match a project's rustfmt configuration.
+//! Comments other than doc comments are not part of the syntax tree and are
+//! not printed. The
+//! bodies of macro invocations are plain tokens, which are spaced by
+//! looking at the neighbouring tokens only and broken into lines after each
+//! `;` of a braced body.
+//!
+//! *This module is available only if Syn is built with the `"full"` and
+//! `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::parse_quote;
+//!
+//! let file: syn::File = parse_quote! {
+//!     /// Adds one.
+//!     pub fn inc<T>(x: T) -> T where T: std::ops::Add<Output = T> + From<u8> {
+//!         let one = T::from(1);
+//!         x + one
+//!     }
+//! };
+//!
+//! let expected = "\
+//! /// Adds one.
+//! pub fn inc<T>(x: T) -> T
+//! where
+//!     T: std::ops::Add<Output = T> + From<u8>,
+//! {
+//!     let one = T::from(1);
+//!     x + one
+//! }
+//! ";
+//! assert_eq!(syn::unparse(&file), expected);
+//!
+//! let expr: syn::Expr = parse_quote!(if ready { go() } else { wait() });
+//! assert_eq!(
+//!     syn::unparse::pretty(&expr).to_string(),
+//!     "if ready {\n    go()\n} else {\n    wait()\n}",
+//! );
+//! ```
+
+use super::*;
+use crate::attr::FilterAttrs;
+use crate::expr::requires_terminator;
+use crate::punctuated::Punctuated;
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::cmp;
+use std::fmt::{self, Debug, Display};
+
+/// Renders a file as formatted Rust source, ending in a newline.
+///
+/// See the [module documentation](self) for how the code is laid out.
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub fn unparse(file: &File) -> String {
+    PrintOptions::new().unparse(file)
+}
+
+/// Renders a syntax tree node as formatted Rust source when displayed, as
+/// returned by [`pretty`].
+///
+/// `Display` is implemented for [`File`], [`Item`], [`Stmt`], [`Expr`],
+/// [`Pat`] and [`Type`]. Unlike [`unparse`], the output does not end in a
+/// newline.
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub struct Pretty<'a, T: 'a> {
+    node: &'a T,
+    options: PrintOptions,
+}
+
+/// Wraps a syntax tree node for displaying as formatted Rust source.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, Item};
+///
+/// let item: Item = parse_quote!(enum E { A, B(u8) });
+/// println!("{}", syn::unparse::pretty(&item));
+/// ```
+///
+/// *This function is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub fn pretty<'a, T>(node: &'a T) -> Pretty<'a, T> {
+    PrintOptions::new().pretty(node)
+}
+
+macro_rules! pretty_display {
+    ($($ty:ident => $print:ident,)*) => {
+        $(
+            impl<'a> Display for Pretty<'a, $ty> {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    let mut printer = Printer::new(self.options);
+                    printer.$print(self.node);
+                    formatter.write_str(&printer.finish())
+                }
+            }
+        )*
+    };
+}
+
+pretty_display! {
+    File => file,
+    Item => item,
+    Stmt => stmt,
+    Expr => expr,
+    Pat => pat,
+    Type => ty,
+}
+
+/// Style options of the printer, corresponding to the rustfmt options of
+/// the same names.
+///
+/// The default options lay code out the way the rustfmt defaults do, other
+/// than that lines are not wrapped at any width.
+///
+/// # Example
+///
+/// ```
+/// use syn::parse_quote;
+/// use syn::unparse::{BraceStyle, PrintOptions, TrailingComma};
+///
+/// let file: syn::File = parse_quote! {
+///     struct Point { x: f64, y: f64 }
+///
+///     fn origin() -> Point { Point::new(0.0, 0.0) }
+/// };
+///
+/// let options = PrintOptions::new()
+///     .indent_width(2)
+///     .max_width(20)
+///     .trailing_comma(TrailingComma::Never)
+///     .brace_style(BraceStyle::AlwaysNextLine);
+///
+/// let expected = "\
+/// struct Point
+/// {
+///   x: f64,
+///   y: f64
+/// }
+///
+/// fn origin() -> Point
+/// {
+///   Point::new(
+///     0.0,
+///     0.0
+///   )
+/// }
+/// ";
+/// assert_eq!(options.unparse(&file), expected);
+/// ```
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub struct PrintOptions {
+    indent_width: usize,
+    max_width: Option<usize>,
+    trailing_comma: TrailingComma,
+    brace_style: BraceStyle,
+}
+
+impl PrintOptions {
+    /// Creates the default options: an indentation of four spaces, no
+    /// maximum width, [`TrailingComma::Vertical`] and
+    /// [`BraceStyle::SameLineWhere`].
+    pub fn new() -> Self {
+        PrintOptions {
+            indent_width: 4,
+            max_width: None,
+            trailing_comma: TrailingComma::Vertical,
+            brace_style: BraceStyle::SameLineWhere,
+        }
+    }
+
+    /// Sets the number of spaces per level of indentation.
+    pub fn indent_width(mut self, width: usize) -> Self {
+        self.indent_width = width;
+        self
+    }
+
+    /// Sets the maximum width of a line, in characters.
+    ///
+    /// The comma-separated lists of arguments, array and tuple elements,
+    /// struct literal fields and `use` groups that make a line longer than
+    /// this are broken into one element per line. A line can still be
+    /// longer if an element on its own is.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets where trailing commas are written.
+    pub fn trailing_comma(mut self, trailing_comma: TrailingComma) -> Self {
+        self.trailing_comma = trailing_comma;
+        self
+    }
+
+    /// Sets where the opening brace of the body of an item is written.
+    pub fn brace_style(mut self, brace_style: BraceStyle) -> Self {
+        self.brace_style = brace_style;
+        self
+    }
+
+    /// Renders a file as formatted Rust source with these options, ending in
+    /// a newline, like [`unparse`].
+    pub fn unparse(&self, file: &File) -> String {
+        let mut printer = Printer::new(*self);
+        printer.file(file);
+        let mut out = printer.finish();
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Wraps a syntax tree node for displaying as formatted Rust source with
+    /// these options, like [`pretty`].
+    pub fn pretty<'a, T>(&self, node: &'a T) -> Pretty<'a, T> {
+        Pretty {
+            node,
+            options: *self,
+        }
+    }
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions::new()
+    }
+}
+
+impl Copy for PrintOptions {}
+
+impl Clone for PrintOptions {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Debug for PrintOptions {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PrintOptions")
+            .field("indent_width", &self.indent_width)
+            .field("max_width", &self.max_width)
+            .field("trailing_comma", &self.trailing_comma)
+            .field("brace_style", &self.brace_style)
+            .finish()
+    }
+}
+
+/// Where the printer writes a comma after the last element of a list, set
+/// with [`PrintOptions::trailing_comma`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub enum TrailingComma {
+    /// After the last element of a list laid out with one element per line,
+    /// such as the fields of a struct. This is the default.
+    Vertical,
+    /// Also after the last element of an argument list, array, tuple, struct
+    /// literal or `use` group on one line.
+    Always,
+    /// Never after the last element.
+    Never,
+}
+
+/// Where the printer writes the opening brace of the body of an item such as
+/// a function, struct or impl block, set with [`PrintOptions::brace_style`].
+///
+/// *This type is available only if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub enum BraceStyle {
+    /// On the same line as the item's header, or on a line of its own after
+    /// a `where` clause. This is the default.
+    SameLineWhere,
+    /// Always on a line of its own.
+    AlwaysNextLine,
+    /// On the same line as the item's header, or as the last predicate of
+    /// its `where` clause.
+    PreferSameLine,
+}
+
+impl Copy for TrailingComma {}
+
+impl Clone for TrailingComma {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Eq for TrailingComma {}
+
+impl PartialEq for TrailingComma {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl Debug for TrailingComma {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            TrailingComma::Vertical => "Vertical",
+            TrailingComma::Always => "Always",
+            TrailingComma::Never => "Never",
+        })
+    }
+}
+
+impl Copy for BraceStyle {}
+
+impl Clone for BraceStyle {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Eq for BraceStyle {}
+
+impl PartialEq for BraceStyle {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl Debug for BraceStyle {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            BraceStyle::SameLineWhere => "SameLineWhere",
+            BraceStyle::AlwaysNextLine => "AlwaysNextLine",
+            BraceStyle::PreferSameLine => "PreferSameLine",
+        })
+    }
+}
+
+struct Printer {
+    options: PrintOptions,
+    out: String,
+    indent: usize,
+    // Whether nothing has been written on the current line yet, in which case
+    // the indentation is written before the next word.
+    line_start: bool,
+}
+
+impl Printer {
+    fn new(options: PrintOptions) -> Self {
+        Printer {
+            options,
+            out: String::new(),
+            indent: 0,
+            line_start: true,
+        }
+    }
+
+    fn finish(mut self) -> String {
+        while self.out.ends_with('\n') {
+            self.out.pop();
+        }
+        self.out
+    }
+
+    fn word(&mut self, word: &str) {
+        if self.line_start {
+            for _ in 0..self.indent * self.options.indent_width {
+                self.out.push(' ');
+            }
+            self.line_start = false;
+        }
+        self.out.push_str(word);
+    }
+
+    // A space between two words, unless at the start of a line.
+    fn space(&mut self) {
+        if !self.line_start {
+            self.out.push(' ');
+        }
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.line_start = true;
+    }
+
+    fn begin_block(&mut self) {
+        self.word("{");
+        self.indent += 1;
+        self.newline();
+    }
+
+    fn end_block(&mut self) {
+        if !self.line_start {
+            self.newline();
+        }
+        self.indent -= 1;
+        self.word("}");
+    }
+
+    fn display<T: Display>(&mut self, value: T) {
+        self.word(&value.to_string());
+    }
+
+    fn tokens_of<T: ToTokens>(&mut self, node: &T) {
+        self.word(&node.to_token_stream().to_string());
+    }
+
+    fn comma_separated<'a, T: 'a, I, F>(&mut self, items: I, mut print: F)
+    where
+        I: IntoIterator<Item = &'a T>,
+        F: FnMut(&mut Self, &'a T),
+    {
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                self.word(", ");
+            }
+            print(self, item);
+        }
+    }
+
+    // A comma-separated list between delimiters, on one line if that fits in
+    // the maximum width and otherwise with one element per line. The
+    // delimiters of a list on one line may have spaces inside of them, as in
+    // `" { "`, which are left out when it is broken into lines.
+    fn list<'a, T: 'a, I, F>(&mut self, open: &str, items: I, mut print: F, close: &str)
+    where
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: Clone,
+        F: FnMut(&mut Self, &'a T),
+    {
+        let items = items.into_iter();
+        let len = items.clone().count();
+        let start = self.out.len();
+        let line_start = self.line_start;
+        self.word(open);
+        self.comma_separated(items.clone(), &mut print);
+        if len > 0 && self.options.trailing_comma == TrailingComma::Always {
+            self.word(",");
+        }
+        self.word(close);
+        if len == 0 || self.fits(start) {
+            return;
+        }
+
+        self.out.truncate(start);
+        self.line_start = line_start;
+        self.word(open.trim_end());
+        self.indent += 1;
+        for (i, item) in items.enumerate() {
+            self.newline();
+            print(self, item);
+            self.trailing_comma(i + 1 == len);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word(close.trim_start());
+    }
+
+    // The comma after an element of a list with one element per line.
+    fn trailing_comma(&mut self, last: bool) {
+        if !last || self.options.trailing_comma != TrailingComma::Never {
+            self.word(",");
+        }
+    }
+
+    // Whether the lines written since `start`, including the whole of the
+    // line that it is on, are within the maximum width.
+    fn fits(&self, start: usize) -> bool {
+        let max_width = match self.options.max_width {
+            Some(max_width) => max_width,
+            None => return true,
+        };
+        let line_begin = self.out[..start].rfind('\n').map_or(0, |i| i + 1);
+        self.out[line_begin..]
+            .lines()
+            .all(|line| line.chars().count() <= max_width)
+    }
+
+    // The separator between the header of an item, with any where clause or
+    // other clauses that were printed after it, and the item's braced body.
+    fn before_body(&mut self, has_clauses: bool) {
+        let next_line = match self.options.brace_style {
+            BraceStyle::SameLineWhere => has_clauses,
+            BraceStyle::AlwaysNextLine => true,
+            BraceStyle::PreferSameLine => false,
+        };
+        if next_line {
+            self.newline();
+        } else {
+            self.word(" ");
+        }
+    }
+
+    // Items separated by blank lines, except for runs of items of the same
+    // group such as consecutive `use` declarations.
+    fn item_list<T>(&mut self, items: &[T], print: fn(&mut Self, &T), group: fn(&T) -> Option<u8>) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                let prev = group(&items[i - 1]);
+                if prev.is_none() || prev != group(item) {
+                    self.newline();
+                }
+            }
+            print(self, item);
+            self.newline();
+        }
+    }
+
+    // Opens a braced body of inner attributes and items, or prints `{}` if
+    // there are none.
+    fn item_body<T>(
+        &mut self,
+        attrs: &[Attribute],
+        items: &[T],
+        print: fn(&mut Self, &T),
+        group: fn(&T) -> Option<u8>,
+    ) {
+        let has_inner = attrs.inner().next().is_some();
+        if !has_inner && items.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.begin_block();
+        self.inner_attrs(attrs);
+        if has_inner && !items.is_empty() {
+            self.newline();
+        }
+        self.item_list(items, print, group);
+        self.end_block();
+    }
+
+    fn file(&mut self, file: &File) {
+        if let Some(shebang) = &file.shebang {
+            self.word(shebang);
+            self.newline();
+        }
+        self.inner_attrs(&file.attrs);
+        if file.attrs.inner().next().is_some() && !file.items.is_empty() {
+            self.newline();
+        }
+        self.item_list(&file.items, Printer::item, item_group);
+    }
+
+    fn outer_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr(attr, true);
+            self.newline();
+        }
+    }
+
+    fn inner_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.inner() {
+            self.attr(attr, true);
+            self.newline();
+        }
+    }
+
+    // Outer attributes on the same line as the node they apply to.
+    fn outer_attrs_inline(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr(attr, false);
+            self.word(" ");
+        }
+    }
+
+    fn attr(&mut self, attr: &Attribute, own_line: bool) {
+        let inner = match attr.style {
+            AttrStyle::Outer => false,
+            AttrStyle::Inner(_) => true,
+        };
+        if own_line {
+            if let Some(doc) = doc_comment(attr) {
+                self.word(if inner { "//!" } else { "///" });
+                self.word(&doc);
+                return;
+            }
+        }
+        self.word(if inner { "#![" } else { "#[" });
+        self.path(&attr.path, false);
+        self.token_stream(attr.tokens.clone(), false, Prev::Word);
+        self.word("]");
+    }
+
+    fn vis(&mut self, vis: &Visibility) {
+        match vis {
+            Visibility::Public(_) => self.word("pub "),
+            Visibility::Crate(_) => self.word("crate "),
+            Visibility::Restricted(vis) => {
+                self.word("pub(");
+                if vis.in_token.is_some() {
+                    self.word("in ");
+                }
+                self.path(&vis.path, false);
+                self.word(") ");
+            }
+            Visibility::Inherited => {}
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        match item {
+            Item::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("const ");
+                self.display(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            Item::Enum(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("enum ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                self.where_before_body(&item.generics.where_clause);
+                self.variants(&item.variants);
+            }
+            Item::ExternCrate(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("extern crate ");
+                self.display(&item.ident);
+                if let Some((_as_token, rename)) = &item.rename {
+                    self.word(" as ");
+                    self.display(rename);
+                }
+                self.word(";");
+            }
+            Item::Fn(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.signature(&item.sig);
+                self.signature_before_body(&item.sig);
+                self.block(&item.block, &item.attrs);
+            }
+            Item::ForeignMod(item) => {
+                self.outer_attrs(&item.attrs);
+                self.abi(&item.abi);
+                self.item_body(&item.attrs, &item.items, Printer::foreign_item, |_| Some(0));
+            }
+            Item::Impl(item) => {
+                self.outer_attrs(&item.attrs);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                if item.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                self.word("impl");
+                self.generics(&item.generics);
+                self.word(" ");
+                if let Some((bang, path, _for_token)) = &item.trait_ {
+                    if bang.is_some() {
+                        self.word("!");
+                    }
+                    self.path(path, false);
+                    self.word(" for ");
+                }
+                self.ty(&item.self_ty);
+                self.where_before_body(&item.generics.where_clause);
+                self.item_body(
+                    &item.attrs,
+                    &item.items,
+                    Printer::impl_item,
+                    impl_item_group,
+                );
+            }
+            Item::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, item.ident.as_ref());
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            Item::Macro2(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("macro ");
+                self.display(&item.ident);
+                self.token_stream(item.rules.clone(), false, Prev::Word);
+            }
+            Item::Mod(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("mod ");
+                self.display(&item.ident);
+                match &item.content {
+                    Some((_brace, items)) => {
+                        self.before_body(false);
+                        self.item_body(&item.attrs, items, Printer::item, item_group);
+                    }
+                    None => self.word(";"),
+                }
+            }
+            Item::Static(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("static ");
+                if item.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.display(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            Item::Struct(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("struct ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                match &item.fields {
+                    Fields::Named(fields) => {
+                        self.where_before_body(&item.generics.where_clause);
+                        self.fields_named(fields);
+                    }
+                    Fields::Unnamed(fields) => {
+                        self.fields_unnamed(fields);
+                        self.where_clause(&item.generics.where_clause, false);
+                        self.word(";");
+                    }
+                    Fields::Unit => {
+                        self.where_clause(&item.generics.where_clause, false);
+                        self.word(";");
+                    }
+                }
+            }
+            Item::Trait(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                if item.auto_token.is_some() {
+                    self.word("auto ");
+                }
+                self.word("trait ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                if !item.supertraits.is_empty() {
+                    self.word(": ");
+                    self.bounds(&item.supertraits);
+                }
+                self.where_before_body(&item.generics.where_clause);
+                self.item_body(
+                    &item.attrs,
+                    &item.items,
+                    Printer::trait_item,
+                    trait_item_group,
+                );
+            }
+            Item::TraitAlias(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("trait ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                self.word(" = ");
+                self.bounds(&item.bounds);
+                self.where_clause(&item.generics.where_clause, false);
+                self.word(";");
+            }
+            Item::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("type ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                self.where_inline(&item.generics.where_clause);
+                self.word(" = ");
+                self.ty(&item.ty);
+                self.word(";");
+            }
+            Item::Union(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("union ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                self.where_before_body(&item.generics.where_clause);
+                self.fields_named(&item.fields);
+            }
+            Item::Use(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("use ");
+                if item.leading_colon.is_some() {
+                    self.word("::");
+                }
+                self.use_tree(&item.tree);
+                self.word(";");
+            }
+            Item::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn foreign_item(&mut self, item: &ForeignItem) {
+        match item {
+            ForeignItem::Fn(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.signature(&item.sig);
+                self.signature_without_body(&item.sig);
+            }
+            ForeignItem::Static(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("static ");
+                if item.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.display(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(";");
+            }
+            ForeignItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("type ");
+                self.display(&item.ident);
+                self.word(";");
+            }
+            ForeignItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ForeignItem::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn impl_item(&mut self, item: &ImplItem) {
+        match item {
+            ImplItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("const ");
+                self.display(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            ImplItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.signature(&item.sig);
+                if is_verbatim_semi(&item.block) {
+                    self.signature_without_body(&item.sig);
+                } else {
+                    self.signature_before_body(&item.sig);
+                    self.block(&item.block, &item.attrs);
+                }
+            }
+            ImplItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("type ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                self.where_inline(&item.generics.where_clause);
+                self.word(" = ");
+                self.ty(&item.ty);
+                self.word(";");
+            }
+            ImplItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ImplItem::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn trait_item(&mut self, item: &TraitItem) {
+        match item {
+            TraitItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("const ");
+                self.display(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                if let Some((_eq_token, default)) = &item.default {
+                    self.word(" = ");
+                    self.expr(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.signature(&item.sig);
+                match &item.default {
+                    Some(block) => {
+                        self.signature_before_body(&item.sig);
+                        self.block(block, &item.attrs);
+                    }
+                    None => self.signature_without_body(&item.sig),
+                }
+            }
+            TraitItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("type ");
+                self.display(&item.ident);
+                self.generics(&item.generics);
+                if !item.bounds.is_empty() {
+                    self.word(": ");
+                    self.bounds(&item.bounds);
+                }
+                self.where_inline(&item.generics.where_clause);
+                if let Some((_eq_token, default)) = &item.default {
+                    self.word(" = ");
+                    self.ty(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            TraitItem::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn variants(&mut self, variants: &Punctuated<Variant, Token![,]>) {
+        if variants.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.begin_block();
+        for (i, variant) in variants.iter().enumerate() {
+            self.outer_attrs(&variant.attrs);
+            self.display(&variant.ident);
+            match &variant.fields {
+                Fields::Named(fields) => {
+                    self.word(" ");
+                    self.fields_named(fields);
+                }
+                Fields::Unnamed(fields) => self.fields_unnamed(fields),
+                Fields::Unit => {}
+            }
+            if let Some((_eq_token, discriminant)) = &variant.discriminant {
+                self.word(" = ");
+                self.expr(discriminant);
+            }
+            self.trailing_comma(i + 1 == variants.len());
+            self.newline();
+        }
+        self.end_block();
+    }
+
+    fn fields_named(&mut self, fields: &FieldsNamed) {
+        if fields.named.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.begin_block();
+        for (i, field) in fields.named.iter().enumerate() {
+            self.outer_attrs(&field.attrs);
+            self.vis(&field.vis);
+            if let Some(ident) = &field.ident {
+                self.display(ident);
+                self.word(": ");
+            }
+            self.ty(&field.ty);
+            self.trailing_comma(i + 1 == fields.named.len());
+            self.newline();
+        }
+        self.end_block();
+    }
+
+    fn fields_unnamed(&mut self, fields: &FieldsUnnamed) {
+        self.word("(");
+        self.comma_separated(&fields.unnamed, |printer, field| {
+            printer.outer_attrs_inline(&field.attrs);
+            printer.vis(&field.vis);
+            printer.ty(&field.ty);
+        });
+        self.word(")");
+    }
+
+    fn use_tree(&mut self, tree: &UseTree) {
+        match tree {
+            UseTree::Path(tree) => {
+                self.display(&tree.ident);
+                self.word("::");
+                self.use_tree(&tree.tree);
+            }
+            UseTree::Name(tree) => self.display(&tree.ident),
+            UseTree::Rename(tree) => {
+                self.display(&tree.ident);
+                self.word(" as ");
+                self.display(&tree.rename);
+            }
+            UseTree::Glob(_) => self.word("*"),
+            UseTree::Group(tree) => self.list("{", &tree.items, Printer::use_tree, "}"),
+        }
+    }
+
+    fn abi(&mut self, abi: &Abi) {
+        self.word("extern ");
+        if let Some(name) = &abi.name {
+            self.tokens_of(name);
+            self.word(" ");
+        }
+    }
+
+    fn signature(&mut self, sig: &Signature) {
+        if sig.constness.is_some() {
+            self.word("const ");
+        }
+        if sig.asyncness.is_some() {
+            self.word("async ");
+        }
+        if sig.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if let Some(abi) = &sig.abi {
+            self.abi(abi);
+        }
+        self.word("fn ");
+        self.display(&sig.ident);
+        self.generics(&sig.generics);
+        match &sig.variadic {
+            None => self.list("(", &sig.inputs, Printer::fn_arg, ")"),
+            Some(variadic) => {
+                self.word("(");
+                self.comma_separated(&sig.inputs, Printer::fn_arg);
+                if !sig.inputs.is_empty() {
+                    self.word(", ");
+                }
+                self.variadic(variadic);
+                self.word(")");
+            }
+        }
+        self.return_type(&sig.output);
+    }
+
+    // Prints the where clause and the spec clauses of a signature, if any,
+    // each on lines of their own. Returns whether anything was printed.
+    fn signature_clauses(&mut self, sig: &Signature, trailing_comma: bool) -> bool {
+        let has_clauses = sig.spec.is_some();
+        let has_where =
+            self.where_clause(&sig.generics.where_clause, trailing_comma && !has_clauses);
+        if let Some(spec) = &sig.spec {
+            self.spec_clauses(spec);
+        }
+        has_where || has_clauses
+    }
+
+    #[cfg_attr(not(feature = "verus"), allow(unused_variables))]
+    fn spec_clauses(&mut self, spec: &SpecClauses) {
+        self.indent += 1;
+        #[cfg(feature = "verus")]
+        {
+            if let Some(requires) = &spec.requires {
+                self.newline();
+                self.spec_clause("requires", &requires.exprs);
+            }
+            if let Some(ensures) = &spec.ensures {
+                self.newline();
+                self.spec_clause("ensures", &ensures.exprs);
+            }
+            if let Some(invariant) = &spec.invariant {
+                self.newline();
+                self.spec_clause("invariant", &invariant.exprs);
+            }
+            if let Some(decreases) = &spec.decreases {
+                self.newline();
+                self.spec_clause("decreases", &decreases.exprs);
+            }
+        }
+        self.indent -= 1;
+    }
+
+    fn signature_before_body(&mut self, sig: &Signature) {
+        let has_clauses = self.signature_clauses(sig, true);
+        self.before_body(has_clauses);
+    }
+
+    fn signature_without_body(&mut self, sig: &Signature) {
+        self.signature_clauses(sig, false);
+        self.word(";");
+    }
+
+    #[cfg(feature = "verus")]
+    fn spec_clause(&mut self, keyword: &str, exprs: &Punctuated<Expr, Token![,]>) {
+        self.word(keyword);
+        self.word(" ");
+        self.comma_separated(exprs, Printer::expr_without_struct);
+    }
+
+    // Prints the clauses of a loop on lines of their own, followed by the
+    // separator before the loop body.
+    fn loop_clauses(&mut self, spec: &Option<Box<SpecClauses>>) {
+        match spec {
+            Some(spec) => {
+                self.spec_clauses(spec);
+                self.newline();
+            }
+            None => self.word(" "),
+        }
+    }
+
+    fn fn_arg(&mut self, arg: &FnArg) {
+        match arg {
+            FnArg::Receiver(receiver) => {
+                self.outer_attrs_inline(&receiver.attrs);
+                if let Some((_and_token, lifetime)) = &receiver.reference {
+                    self.word("&");
+                    if let Some(lifetime) = lifetime {
+                        self.display(lifetime);
+                        self.word(" ");
+                    }
+                }
+                if receiver.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.word("self");
+            }
+            FnArg::Typed(pat) => self.pat_type(pat),
+        }
+    }
+
+    fn variadic(&mut self, variadic: &Variadic) {
+        self.outer_attrs_inline(&variadic.attrs);
+        self.word("...");
+    }
+
+    fn return_type(&mut self, output: &ReturnType) {
+        if let ReturnType::Type(_arrow, ty) = output {
+            self.word(" -> ");
+            self.ty(ty);
+        }
+    }
+
+    fn generics(&mut self, generics: &Generics) {
+        if generics.params.is_empty() {
+            return;
+        }
+        self.word("<");
+        self.comma_separated(&generics.params, |printer, param| match param {
+            GenericParam::Type(param) => {
+                printer.outer_attrs_inline(&param.attrs);
+                printer.display(&param.ident);
+                if !param.bounds.is_empty() {
+                    printer.word(": ");
+                    printer.bounds(&param.bounds);
+                }
+                if let Some(default) = &param.default {
+                    printer.word(" = ");
+                    printer.ty(default);
+                }
+            }
+            GenericParam::Lifetime(param) => printer.lifetime_def(param),
+            GenericParam::Const(param) => {
+                printer.outer_attrs_inline(&param.attrs);
+                printer.word("const ");
+                printer.display(&param.ident);
+                printer.word(": ");
+                printer.ty(&param.ty);
+                if let Some(default) = &param.default {
+                    printer.word(" = ");
+                    printer.expr(default);
+                }
+            }
+        });
+        self.word(">");
+    }
+
+    fn lifetime_def(&mut self, def: &LifetimeDef) {
+        self.outer_attrs_inline(&def.attrs);
+        self.display(&def.lifetime);
+        if !def.bounds.is_empty() {
+            self.word(": ");
+            self.lifetime_bounds(&def.bounds);
+        }
+    }
+
+    fn lifetime_bounds(&mut self, bounds: &Punctuated<Lifetime, Token![+]>) {
+        for (i, lifetime) in bounds.iter().enumerate() {
+            if i > 0 {
+                self.word(" + ");
+            }
+            self.display(lifetime);
+        }
+    }
+
+    fn bound_lifetimes(&mut self, lifetimes: &Option<BoundLifetimes>) {
+        if let Some(lifetimes) = lifetimes {
+            self.word("for<");
+            self.comma_separated(&lifetimes.lifetimes, Printer::lifetime_def);
+            self.word("> ");
+        }
+    }
+
+    fn bounds(&mut self, bounds: &Punctuated<TypeParamBound, Token![+]>) {
+        for (i, bound) in bounds.iter().enumerate() {
+            if i > 0 {
+                self.word(" + ");
+            }
+            match bound {
+                TypeParamBound::Trait(bound) => {
+                    if bound.paren_token.is_some() {
+                        self.word("(");
+                    }
+                    if let TraitBoundModifier::Maybe(_) = bound.modifier {
+                        self.word("?");
+                    }
+                    self.bound_lifetimes(&bound.lifetimes);
+                    self.path(&bound.path, false);
+                    if bound.paren_token.is_some() {
+                        self.word(")");
+                    }
+                }
+                TypeParamBound::Lifetime(lifetime) => self.display(lifetime),
+            }
+        }
+    }
+
+    // Prints a where clause on lines of its own, one predicate per line.
+    // Returns whether anything was printed.
+    fn where_clause(&mut self, where_clause: &Option<WhereClause>, trailing_comma: bool) -> bool {
+        let where_clause = match where_clause {
+            Some(where_clause) if !where_clause.predicates.is_empty() => where_clause,
+            _ => return false,
+        };
+        self.newline();
+        self.word("where");
+        self.indent += 1;
+        let last = where_clause.predicates.len() - 1;
+        for (i, predicate) in where_clause.predicates.iter().enumerate() {
+            self.newline();
+            self.where_predicate(predicate);
+            if i < last {
+                self.word(",");
+            } else if trailing_comma {
+                self.trailing_comma(true);
+            }
+        }
+        self.indent -= 1;
+        true
+    }
+
+    // Prints the where clause of an item followed by the separator before
+    // the item's braced body.
+    fn where_before_body(&mut self, where_clause: &Option<WhereClause>) {
+        let has_where = self.where_clause(where_clause, true);
+        self.before_body(has_where);
+    }
+
+    fn where_inline(&mut self, where_clause: &Option<WhereClause>) {
+        if let Some(where_clause) = where_clause {
+            if !where_clause.predicates.is_empty() {
+                self.word(" where ");
+                self.comma_separated(&where_clause.predicates, Printer::where_predicate);
+            }
+        }
+    }
+
+    fn where_predicate(&mut self, predicate: &WherePredicate) {
+        match predicate {
+            WherePredicate::Type(predicate) => {
+                self.bound_lifetimes(&predicate.lifetimes);
+                self.ty(&predicate.bounded_ty);
+                self.word(": ");
+                self.bounds(&predicate.bounds);
+            }
+            WherePredicate::Lifetime(predicate) => {
+                self.display(&predicate.lifetime);
+                self.word(": ");
+                self.lifetime_bounds(&predicate.bounds);
+            }
+            WherePredicate::Eq(predicate) => {
+                self.ty(&predicate.lhs_ty);
+                self.word(" = ");
+                self.ty(&predicate.rhs_ty);
+            }
+        }
+    }
+
+    // Paths in expressions and patterns need `::` before generic arguments,
+    // as in `Vec::<u8>::new()`.
+    fn path(&mut self, path: &Path, expr_style: bool) {
+        if path.leading_colon.is_some() {
+            self.word("::");
+        }
+        for (i, segment) in path.segments.iter().enumerate() {
+            if i > 0 {
+                self.word("::");
+            }
+            self.path_segment(segment, expr_style);
+        }
+    }
+
+    fn path_segment(&mut self, segment: &PathSegment, expr_style: bool) {
+        self.display(&segment.ident);
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(arguments) => {
+                if expr_style || arguments.colon2_token.is_some() {
+                    self.word("::");
+                }
+                self.word("<");
+                self.comma_separated(&arguments.args, Printer::generic_argument);
+                self.word(">");
+            }
+            PathArguments::Parenthesized(arguments) => {
+                self.word("(");
+                self.comma_separated(&arguments.inputs, Printer::ty);
+                self.word(")");
+                self.return_type(&arguments.output);
+            }
+        }
+    }
+
+    fn generic_argument(&mut self, argument: &GenericArgument) {
+        match argument {
+            GenericArgument::Lifetime(lifetime) => self.display(lifetime),
+            GenericArgument::Type(ty) => self.ty(ty),
+            GenericArgument::Binding(binding) => {
+                self.display(&binding.ident);
+                self.word(" = ");
+                self.ty(&binding.ty);
+            }
+            GenericArgument::Constraint(constraint) => {
+                self.display(&constraint.ident);
+                self.word(": ");
+                self.bounds(&constraint.bounds);
+            }
+            GenericArgument::Const(expr) => self.const_argument(expr),
+        }
+    }
+
+    // A const generic argument other than a literal or a block needs braces.
+    fn const_argument(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Lit(_) | Expr::Block(_) => self.expr(expr),
+            _ => {
+                self.word("{ ");
+                self.expr(expr);
+                self.word(" }");
+            }
+        }
+    }
+
+    fn qpath(&mut self, qself: &Option<QSelf>, path: &Path, expr_style: bool) {
+        let qself = match qself {
+            Some(qself) => qself,
+            None => return self.path(path, expr_style),
+        };
+        self.word("<");
+        self.ty(&qself.ty);
+        let position = cmp::min(qself.position, path.segments.len());
+        let mut segments = path.segments.iter();
+        if position > 0 {
+            self.word(" as ");
+            if path.leading_colon.is_some() {
+                self.word("::");
+            }
+            for (i, segment) in segments.by_ref().take(position).enumerate() {
+                if i > 0 {
+                    self.word("::");
+                }
+                self.path_segment(segment, false);
+            }
+        }
+        self.word(">");
+        for segment in segments {
+            self.word("::");
+            self.path_segment(segment, expr_style);
+        }
+    }
+
+    fn ty(&mut self, ty: &Type) {
+        match ty {
+            Type::Array(ty) => {
+                self.word("[");
+                self.ty(&ty.elem);
+                self.word("; ");
+                self.expr(&ty.len);
+                self.word("]");
+            }
+            Type::BareFn(ty) => {
+                self.bound_lifetimes(&ty.lifetimes);
+                if ty.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                if let Some(abi) = &ty.abi {
+                    self.abi(abi);
+                }
+                self.word("fn(");
+                self.comma_separated(&ty.inputs, |printer, arg| {
+                    printer.outer_attrs_inline(&arg.attrs);
+                    if let Some((name, _colon_token)) = &arg.name {
+                        printer.display(name);
+                        printer.word(": ");
+                    }
+                    printer.ty(&arg.ty);
+                });
+                if let Some(variadic) = &ty.variadic {
+                    if !ty.inputs.is_empty() {
+                        self.word(", ");
+                    }
+                    self.variadic(variadic);
+                }
+                self.word(")");
+                self.return_type(&ty.output);
+            }
+            Type::Group(ty) => {
+                // The invisible delimiters act as parentheses around a type
+                // with bounds, as in `&$ty` for a `$ty` of `dyn A + B`.
+                let parenthesize = match &*ty.elem {
+                    Type::TraitObject(elem) => elem.bounds.len() > 1,
+                    Type::ImplTrait(elem) => elem.bounds.len() > 1,
+                    _ => false,
+                };
+                if parenthesize {
+                    self.word("(");
+                }
+                self.ty(&ty.elem);
+                if parenthesize {
+                    self.word(")");
+                }
+            }
+            Type::ImplTrait(ty) => {
+                self.word("impl ");
+                self.bounds(&ty.bounds);
+            }
+            Type::Infer(_) => self.word("_"),
+            Type::Macro(ty) => self.mac(&ty.mac, None),
+            Type::Never(_) => self.word("!"),
+            Type::Paren(ty) => {
+                self.word("(");
+                self.ty(&ty.elem);
+                self.word(")");
+            }
+            Type::Path(ty) => self.qpath(&ty.qself, &ty.path, false),
+            Type::Ptr(ty) => {
+                self.word(if ty.mutability.is_some() {
+                    "*mut "
+                } else {
+                    "*const "
+                });
+                self.ty(&ty.elem);
+            }
+            Type::Reference(ty) => {
+                self.word("&");
+                if let Some(lifetime) = &ty.lifetime {
+                    self.display(lifetime);
+                    self.word(" ");
+                }
+                if ty.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.ty(&ty.elem);
+            }
+            Type::Slice(ty) => {
+                self.word("[");
+                self.ty(&ty.elem);
+                self.word("]");
+            }
+            Type::TraitObject(ty) => {
+                if ty.dyn_token.is_some() {
+                    self.word("dyn ");
+                }
+                self.bounds(&ty.bounds);
+            }
+            Type::Tuple(ty) => {
+                self.word("(");
+                self.comma_separated(&ty.elems, Printer::ty);
+                if ty.elems.len() == 1 {
+                    self.word(",");
+                }
+                self.word(")");
+            }
+            Type::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn pat(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Box(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("box ");
+                self.pat(&pat.pat);
+            }
+            Pat::Ident(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                if pat.by_ref.is_some() {
+                    self.word("ref ");
+                }
+                if pat.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.display(&pat.ident);
+                if let Some((_at_token, subpat)) = &pat.subpat {
+                    self.word(" @ ");
+                    self.pat(subpat);
+                }
+            }
+            Pat::Lit(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.expr(&pat.expr);
+            }
+            Pat::Macro(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.mac(&pat.mac, None);
+            }
+            Pat::Or(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                if pat.leading_vert.is_some() {
+                    self.word("| ");
+                }
+                for (i, case) in pat.cases.iter().enumerate() {
+                    if i > 0 {
+                        self.word(" | ");
+                    }
+                    self.pat(case);
+                }
+            }
+            Pat::Path(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.qpath(&pat.qself, &pat.path, true);
+            }
+            Pat::Range(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.expr(&pat.lo);
+                self.range_limits(&pat.limits);
+                self.expr(&pat.hi);
+            }
+            Pat::Reference(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("&");
+                if pat.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.pat(&pat.pat);
+            }
+            Pat::Rest(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("..");
+            }
+            Pat::Slice(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("[");
+                self.comma_separated(&pat.elems, Printer::pat);
+                self.word("]");
+            }
+            Pat::Struct(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.path(&pat.path, true);
+                if pat.fields.is_empty() && pat.dot2_token.is_none() {
+                    self.word(" {}");
+                    return;
+                }
+                self.word(" { ");
+                self.comma_separated(&pat.fields, |printer, field| {
+                    printer.outer_attrs_inline(&field.attrs);
+                    if field.colon_token.is_some() {
+                        printer.member(&field.member);
+                        printer.word(": ");
+                    }
+                    printer.pat(&field.pat);
+                });
+                if pat.dot2_token.is_some() {
+                    if !pat.fields.is_empty() {
+                        self.word(", ");
+                    }
+                    self.word("..");
+                }
+                self.word(" }");
+            }
+            Pat::Tuple(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.pat_tuple(pat, true);
+            }
+            Pat::TupleStruct(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.path(&pat.path, true);
+                self.pat_tuple(&pat.pat, false);
+            }
+            Pat::Type(pat) => self.pat_type(pat),
+            Pat::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+            Pat::Wild(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("_");
+            }
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    // A tuple pattern of one element other than `..` needs a trailing comma
+    // to not be a parenthesized pattern, unlike the fields of a tuple struct
+    // pattern.
+    fn pat_tuple(&mut self, pat: &PatTuple, is_tuple: bool) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("(");
+        self.comma_separated(&pat.elems, Printer::pat);
+        if is_tuple && pat.elems.len() == 1 {
+            if let Some(Pat::Rest(_)) = pat.elems.first() {
+            } else {
+                self.word(",");
+            }
+        }
+        self.word(")");
+    }
+
+    fn pat_type(&mut self, pat: &PatType) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.pat(&pat.pat);
+        self.word(": ");
+        self.ty(&pat.ty);
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Local(local) => {
+                self.outer_attrs(&local.attrs);
+                self.word("let ");
+                self.pat(&local.pat);
+                if let Some((_eq_token, init)) = &local.init {
+                    self.word(" = ");
+                    self.expr(init);
+                }
+                self.word(";");
+            }
+            Stmt::Item(item) => self.item(item),
+            Stmt::Expr(expr) => self.expr(expr),
+            Stmt::Semi(expr, _semi) => {
+                self.expr(expr);
+                self.word(";");
+            }
+        }
+    }
+
+    // A block, with the inner attributes among `attrs` at the start of it.
+    fn block(&mut self, block: &Block, attrs: &[Attribute]) {
+        let has_inner = attrs.inner().next().is_some();
+        if !has_inner && block.stmts.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.begin_block();
+        self.inner_attrs(attrs);
+        for stmt in &block.stmts {
+            self.stmt(stmt);
+            self.newline();
+        }
+        self.end_block();
+    }
+
+    fn label(&mut self, label: &Option<Label>) {
+        if let Some(label) = label {
+            self.display(&label.name);
+            self.word(": ");
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Array(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.list("[", &expr.elems, Printer::expr, "]");
+            }
+            Expr::Assign(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.left);
+                self.word(" = ");
+                self.expr(&expr.right);
+            }
+            Expr::AssignOp(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.left);
+                self.word(" ");
+                self.word(bin_op(&expr.op));
+                self.word(" ");
+                self.expr(&expr.right);
+            }
+            Expr::Async(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("async ");
+                if expr.capture.is_some() {
+                    self.word("move ");
+                }
+                self.block(&expr.block, &[]);
+            }
+            Expr::Await(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.base);
+                self.word(".await");
+            }
+            Expr::Binary(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.left);
+                self.word(" ");
+                self.word(bin_op(&expr.op));
+                self.word(" ");
+                self.expr(&expr.right);
+            }
+            Expr::Block(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.block(&expr.block, &expr.attrs);
+            }
+            Expr::Box(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("box ");
+                self.expr(&expr.expr);
+            }
+            Expr::Break(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("break");
+                if let Some(label) = &expr.label {
+                    self.word(" ");
+                    self.display(label);
+                }
+                if let Some(value) = &expr.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+            Expr::Call(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.func);
+                self.list("(", &expr.args, Printer::expr, ")");
+            }
+            Expr::Cast(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word(" as ");
+                self.ty(&expr.ty);
+            }
+            Expr::Closure(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                if expr.movability.is_some() {
+                    self.word("static ");
+                }
+                if expr.asyncness.is_some() {
+                    self.word("async ");
+                }
+                if expr.capture.is_some() {
+                    self.word("move ");
+                }
+                self.word("|");
+                self.comma_separated(&expr.inputs, Printer::pat);
+                self.word("|");
+                self.return_type(&expr.output);
+                self.word(" ");
+                self.expr(&expr.body);
+            }
+            Expr::Continue(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("continue");
+                if let Some(label) = &expr.label {
+                    self.word(" ");
+                    self.display(label);
+                }
+            }
+            Expr::Field(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.base);
+                self.word(".");
+                self.member(&expr.member);
+            }
+            Expr::ForLoop(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("for ");
+                self.pat(&expr.pat);
+                self.word(" in ");
+                self.expr_without_struct(&expr.expr);
+                self.loop_clauses(&expr.spec);
+                self.block(&expr.body, &expr.attrs);
+            }
+            Expr::Group(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                // The invisible delimiters act as parentheses, as in `$e * 2`
+                // for an `$e` of `a + b`.
+                let parenthesize = !is_atomic(&expr.expr);
+                if parenthesize {
+                    self.word("(");
+                }
+                self.expr(&expr.expr);
+                if parenthesize {
+                    self.word(")");
+                }
+            }
+            Expr::If(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("if ");
+                self.expr_without_struct(&expr.cond);
+                self.word(" ");
+                self.block(&expr.then_branch, &[]);
+                if let Some((_else_token, else_branch)) = &expr.else_branch {
+                    self.word(" else ");
+                    match &**else_branch {
+                        Expr::If(_) | Expr::Block(_) => self.expr(else_branch),
+                        _ => {
+                            self.begin_block();
+                            self.expr(else_branch);
+                            self.end_block();
+                        }
+                    }
+                }
+            }
+            Expr::Index(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word("[");
+                self.expr(&expr.index);
+                self.word("]");
+            }
+            Expr::Let(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("let ");
+                self.pat(&expr.pat);
+                self.word(" = ");
+                self.expr_without_struct(&expr.expr);
+            }
+            Expr::Lit(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.tokens_of(&expr.lit);
+            }
+            Expr::Loop(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("loop");
+                self.loop_clauses(&expr.spec);
+                self.block(&expr.body, &expr.attrs);
+            }
+            Expr::Macro(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.mac(&expr.mac, None);
+            }
+            Expr::Match(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("match ");
+                self.expr_without_struct(&expr.expr);
+                self.word(" ");
+                let has_inner = expr.attrs.inner().next().is_some();
+                if !has_inner && expr.arms.is_empty() {
+                    self.word("{}");
+                    return;
+                }
+                self.begin_block();
+                self.inner_attrs(&expr.attrs);
+                for arm in &expr.arms {
+                    self.arm(arm);
+                    self.newline();
+                }
+                self.end_block();
+            }
+            Expr::MethodCall(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.receiver);
+                self.word(".");
+                self.display(&expr.method);
+                if let Some(turbofish) = &expr.turbofish {
+                    self.word("::<");
+                    self.comma_separated(&turbofish.args, |printer, arg| match arg {
+                        GenericMethodArgument::Type(ty) => printer.ty(ty),
+                        GenericMethodArgument::Const(expr) => printer.const_argument(expr),
+                    });
+                    self.word(">");
+                }
+                self.list("(", &expr.args, Printer::expr, ")");
+            }
+            Expr::Paren(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("(");
+                self.expr(&expr.expr);
+                self.word(")");
+            }
+            Expr::Path(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.qpath(&expr.qself, &expr.path, true);
+            }
+            Expr::Range(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                if let Some(from) = &expr.from {
+                    self.expr(from);
+                }
+                self.range_limits(&expr.limits);
+                if let Some(to) = &expr.to {
+                    self.expr(to);
+                }
+            }
+            Expr::Reference(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("&");
+                if expr.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.expr(&expr.expr);
+            }
+            Expr::Repeat(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("[");
+                self.expr(&expr.expr);
+                self.word("; ");
+                self.expr(&expr.len);
+                self.word("]");
+            }
+            Expr::Return(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("return");
+                if let Some(value) = &expr.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+            Expr::Struct(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.path(&expr.path, true);
+                if expr.fields.is_empty() && expr.dot2_token.is_none() {
+                    self.word(" {}");
+                    return;
+                }
+                let field_value = |printer: &mut Self, field: &FieldValue| {
+                    printer.outer_attrs_inline(&field.attrs);
+                    if field.colon_token.is_some() || !field.can_be_shorthand() {
+                        printer.member(&field.member);
+                        printer.word(": ");
+                    }
+                    printer.expr(&field.expr);
+                };
+                if expr.dot2_token.is_none() {
+                    self.list(" { ", &expr.fields, field_value, " }");
+                    return;
+                }
+                self.word(" { ");
+                self.comma_separated(&expr.fields, field_value);
+                if !expr.fields.is_empty() {
+                    self.word(", ");
+                }
+                self.word("..");
+                if let Some(rest) = &expr.rest {
+                    self.expr(rest);
+                }
+                self.word(" }");
+            }
+            Expr::Try(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word("?");
+            }
+            Expr::TryBlock(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("try ");
+                self.block(&expr.block, &[]);
+            }
+            Expr::Tuple(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                if expr.elems.len() == 1 {
+                    self.word("(");
+                    self.expr(&expr.elems[0]);
+                    self.word(",)");
+                } else {
+                    self.list("(", &expr.elems, Printer::expr, ")");
+                }
+            }
+            Expr::Type(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word(": ");
+                self.ty(&expr.ty);
+            }
+            Expr::Unary(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word(match expr.op {
+                    UnOp::Deref(_) => "*",
+                    UnOp::Not(_) => "!",
+                    UnOp::Neg(_) => "-",
+                });
+                self.expr(&expr.expr);
+            }
+            Expr::Unsafe(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("unsafe ");
+                self.block(&expr.block, &expr.attrs);
+            }
+            Expr::Verbatim(tokens) => self.token_stream(tokens.clone(), false, Prev::Start),
+            Expr::While(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("while ");
+                self.expr_without_struct(&expr.cond);
+                self.loop_clauses(&expr.spec);
+                self.block(&expr.body, &expr.attrs);
+            }
+            Expr::Yield(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("yield");
+                if let Some(value) = &expr.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+
+            #[cfg(syn_no_non_exhaustive)]
+            _ => unreachable!(),
+        }
+    }
+
+    // A struct literal where a block may follow, such as in the condition of
+    // an `if`, needs parentheses.
+    fn expr_without_struct(&mut self, expr: &Expr) {
+        if let Expr::Struct(_) = expr {
+            self.word("(");
+            self.expr(expr);
+            self.word(")");
+        } else {
+            self.expr(expr);
+        }
+    }
+
+    fn arm(&mut self, arm: &Arm) {
+        self.outer_attrs(&arm.attrs);
+        self.pat(&arm.pat);
+        if let Some((_if_token, guard)) = &arm.guard {
+            self.word(" if ");
+            self.expr(guard);
+        }
+        self.word(" => ");
+        self.expr(&arm.body);
+        if requires_terminator(&arm.body) || arm.comma.is_some() {
+            self.word(",");
+        }
+    }
+
+    fn member(&mut self, member: &Member) {
+        match member {
+            Member::Named(ident) => self.display(ident),
+            Member::Unnamed(index) => self.display(index.index),
+        }
+    }
+
+    fn range_limits(&mut self, limits: &RangeLimits) {
+        self.word(match limits {
+            RangeLimits::HalfOpen(_) => "..",
+            RangeLimits::Closed(_) => "..=",
+        });
+    }
+
+    fn mac(&mut self, mac: &Macro, ident: Option<&Ident>) {
+        self.path(&mac.path, false);
+        self.word("!");
+        if let Some(ident) = ident {
+            self.word(" ");
+            self.display(ident);
+        }
+        let delimiter = match mac.delimiter {
+            MacroDelimiter::Paren(_) => Delimiter::Parenthesis,
+            MacroDelimiter::Brace(_) => {
+                self.word(" ");
+                Delimiter::Brace
+            }
+            MacroDelimiter::Bracket(_) => Delimiter::Bracket,
+        };
+        self.delimited(delimiter, mac.tokens.clone());
+    }
+
+    fn token_stream(&mut self, tokens: TokenStream, multiline: bool, prev: Prev) {
+        let mut state = TokenState {
+            prev,
+            metavar: false,
+            generic_depth: 0,
+        };
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            self.token(&token, &mut state);
+            if multiline && tokens.peek().is_some() && ends_line(&token, tokens.peek()) {
+                self.newline();
+                state = TokenState {
+                    prev: Prev::Start,
+                    metavar: false,
+                    generic_depth: 0,
+                };
+            }
+        }
+    }
+
+    fn token(&mut self, token: &TokenTree, state: &mut TokenState) {
+        if space_before(state, token) {
+            self.space();
+        }
+        let metavar = match (&state.prev, token) {
+            (Prev::Punct { ch: '$', .. }, TokenTree::Ident(_)) => true,
+            _ => false,
+        };
+        state.prev = match token {
+            TokenTree::Group(group) => {
+                self.delimited(group.delimiter(), group.stream());
+                Prev::Word
+            }
+            TokenTree::Ident(ident) => {
+                let word = ident.to_string();
+                self.word(&word);
+                if is_keyword(&word) {
+                    Prev::Keyword
+                } else {
+                    // The name is kept for telling the `<` of generic
+                    // arguments after a type name from a comparison.
+                    Prev::Ident(word)
+                }
+            }
+            TokenTree::Literal(literal) => {
+                self.display(literal);
+                Prev::Word
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                let joint = punct.spacing() == Spacing::Joint;
+                let tight = match ch {
+                    '.' | '#' | '$' | '!' => true,
+                    '&' | '*' | '-' => match state.prev {
+                        Prev::Word | Prev::Ident(_) | Prev::Punct { joint: true, .. } => false,
+                        _ => true,
+                    },
+                    ':' => match state.prev {
+                        Prev::Punct {
+                            ch: ':',
+                            joint: true,
+                            ..
+                        } => true,
+                        _ => state.metavar,
+                    },
+                    '<' => {
+                        let generic = match &state.prev {
+                            Prev::Punct { ch: ':', tight, .. } => *tight,
+                            Prev::Ident(name) => name.starts_with(char::is_uppercase),
+                            _ => false,
+                        };
+                        if generic {
+                            state.generic_depth += 1;
+                        }
+                        generic
+                    }
+                    '>' => {
+                        // Not the `>` of `->` or `=>`.
+                        let arrow = match state.prev {
+                            Prev::Punct {
+                                ch, joint: true, ..
+                            } => ch == '-' || ch == '=',
+                            _ => false,
+                        };
+                        if state.generic_depth > 0 && !arrow {
+                            state.generic_depth -= 1;
+                        }
+                        false
+                    }
+                    _ => false,
+                };
+                self.out_punct(ch);
+                Prev::Punct { ch, joint, tight }
+            }
+        };
+        state.metavar = metavar;
+    }
+
+    fn out_punct(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.word(ch.encode_utf8(&mut buf));
+    }
+
+    fn delimited(&mut self, delimiter: Delimiter, tokens: TokenStream) {
+        match delimiter {
+            Delimiter::Parenthesis => {
+                self.word("(");
+                self.token_stream(tokens, false, Prev::Start);
+                self.word(")");
+            }
+            Delimiter::Bracket => {
+                self.word("[");
+                self.token_stream(tokens, false, Prev::Start);
+                self.word("]");
+            }
+            Delimiter::Brace => {
+                if tokens.is_empty() {
+                    self.word("{}");
+                } else if is_multiline(&tokens) {
+                    self.begin_block();
+                    self.token_stream(tokens, true, Prev::Start);
+                    self.end_block();
+                } else {
+                    self.word("{ ");
+                    self.token_stream(tokens, false, Prev::Start);
+                    self.word(" }");
+                }
+            }
+            Delimiter::None => self.token_stream(tokens, false, Prev::Start),
+        }
+    }
+}
+
+// What came before a token in a token stream, which decides whether there is
+// a space before the token.
+enum Prev {
+    // The start of a stream or group.
+    Start,
+    // A literal or a group, or an identifier as `Prev::Ident`.
+    Word,
+    Ident(String),
+    Keyword,
+    // A punctuation character, and whether it is joined to the next one or
+    // is followed by its operand without a space, like a unary `-`.
+    Punct { ch: char, joint: bool, tight: bool },
+}
+
+struct TokenState {
+    prev: Prev,
+    // Whether the previous token is the name of a macro metavariable, which
+    // is joined to its fragment specifier as in `$x:expr`.
+    metavar: bool,
+    // The number of `<` of generic arguments that have not been closed.
+    generic_depth: usize,
+}
+
+fn space_before(state: &TokenState, token: &TokenTree) -> bool {
+    let after_word = match state.prev {
+        Prev::Word | Prev::Ident(_) => true,
+        _ => false,
+    };
+    match &state.prev {
+        Prev::Start => return false,
+        Prev::Punct { joint: true, .. } => return false,
+        Prev::Punct {
+            ch, tight: true, ..
+        } => {
+            return match (ch, token) {
+                // A space between `m!` and a brace, as in `m! { ... }`.
+                ('!', TokenTree::Group(group)) => group.delimiter() == Delimiter::Brace,
+                // Only a fragment specifier is joined to a metavariable,
+                // not the type in `$x: &T`.
+                (':', TokenTree::Ident(_)) => false,
+                (':', _) => true,
+                _ => false,
+            };
+        }
+        _ => {}
+    }
+    match token {
+        TokenTree::Punct(punct) => match punct.as_char() {
+            ',' | ';' => false,
+            '!' if punct.spacing() == Spacing::Joint => true,
+            '.' | '?' | ':' | '!' => !after_word,
+            '<' => match &state.prev {
+                Prev::Ident(name) => !name.starts_with(char::is_uppercase),
+                _ => true,
+            },
+            '>' => state.generic_depth == 0,
+            _ => true,
+        },
+        TokenTree::Group(group) => match group.delimiter() {
+            Delimiter::Parenthesis | Delimiter::Bracket => !after_word,
+            Delimiter::Brace | Delimiter::None => true,
+        },
+        TokenTree::Ident(_) | TokenTree::Literal(_) => true,
+    }
+}
+
+// Within a braced body of statements, a line ends after each `;` and after
+// a braced group followed by the start of another statement or item.
+fn ends_line(token: &TokenTree, next: Option<&TokenTree>) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == ';',
+        TokenTree::Group(group) => {
+            group.delimiter() == Delimiter::Brace
+                && match next {
+                    Some(TokenTree::Ident(_)) => true,
+                    Some(TokenTree::Punct(punct)) => match punct.as_char() {
+                        '#' | '$' => true,
+                        _ => false,
+                    },
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+// A braced body is broken into lines if it holds statements, directly or
+// within a nested group.
+fn is_multiline(tokens: &TokenStream) -> bool {
+    tokens.clone().into_iter().any(|token| match token {
+        TokenTree::Punct(punct) => punct.as_char() == ';',
+        TokenTree::Group(group) => is_multiline(&group.stream()),
+        _ => false,
+    })
+}
+
+fn is_keyword(word: &str) -> bool {
+    match word {
+        "as" | "async" | "await" | "break" | "const" | "continue" | "dyn" | "else" | "enum"
+        | "extern" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+        | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct" | "trait" | "type"
+        | "unsafe" | "use" | "where" | "while" | "yield" => true,
+        _ => false,
+    }
+}
+
+// The text of a `#[doc = "..."]` attribute, if it can be written as a doc
+// comment. A line comment cannot hold a line break, and one starting with
+// `////` is not a doc comment.
+fn doc_comment(attr: &Attribute) -> Option<String> {
+    if attr.path.leading_colon.is_some()
+        || attr.path.segments.len() != 1
+        || attr.path.segments[0].ident != "doc"
+    {
+        return None;
+    }
+    let mut tokens = attr.tokens.clone().into_iter();
+    match tokens.next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '=' => {}
+        _ => return None,
+    }
+    let lit = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => Lit::new(literal),
+        _ => return None,
+    };
+    if tokens.next().is_some() {
+        return None;
+    }
+    match lit {
+        Lit::Str(lit) => {
+            let value = lit.value();
+            if value.contains('\n') || value.contains('\r') || value.starts_with('/') {
+                None
+            } else {
+                Some(value)
+            }
+        }
+        _ => None,
+    }
+}
+
+// Whether an expression binds at least as tightly as a method call, so that
+// it can stand in for invisible delimiters without parentheses.
+fn is_atomic(expr: &Expr) -> bool {
+    match expr {
+        Expr::Array(_)
+        | Expr::Await(_)
+        | Expr::Block(_)
+        | Expr::Call(_)
+        | Expr::Field(_)
+        | Expr::Index(_)
+        | Expr::Lit(_)
+        | Expr::Macro(_)
+        | Expr::MethodCall(_)
+        | Expr::Paren(_)
+        | Expr::Path(_)
+        | Expr::Repeat(_)
+        | Expr::Struct(_)
+        | Expr::Try(_)
+        | Expr::Tuple(_) => true,
+        Expr::Group(expr) => is_atomic(&expr.expr),
+        _ => false,
+    }
+}
+
+fn bin_op(op: &BinOp) -> &'static str {
+    match op {
+        BinOp::Add(_) => "+",
+        BinOp::Sub(_) => "-",
+        BinOp::Mul(_) => "*",
+        BinOp::Div(_) => "/",
+        BinOp::Rem(_) => "%",
+        BinOp::And(_) => "&&",
+        BinOp::Or(_) => "||",
+        BinOp::BitXor(_) => "^",
+        BinOp::BitAnd(_) => "&",
+        BinOp::BitOr(_) => "|",
+        BinOp::Shl(_) => "<<",
+        BinOp::Shr(_) => ">>",
+        BinOp::Eq(_) => "==",
+        BinOp::Lt(_) => "<",
+        BinOp::Le(_) => "<=",
+        BinOp::Ne(_) => "!=",
+        BinOp::Ge(_) => ">=",
+        BinOp::Gt(_) => ">",
+        BinOp::AddEq(_) => "+=",
+        BinOp::SubEq(_) => "-=",
+        BinOp::MulEq(_) => "*=",
+        BinOp::DivEq(_) => "/=",
+        BinOp::RemEq(_) => "%=",
+        BinOp::BitXorEq(_) => "^=",
+        BinOp::BitAndEq(_) => "&=",
+        BinOp::BitOrEq(_) => "|=",
+        BinOp::ShlEq(_) => "<<=",
+        BinOp::ShrEq(_) => ">>=",
+    }
+}
+
+// Items that are kept together without blank lines between them.
+fn item_group(item: &Item) -> Option<u8> {
+    match item {
+        Item::Use(_) => Some(0),
+        Item::ExternCrate(_) => Some(1),
+        Item::Mod(item) if item.content.is_none() => Some(2),
+        _ => None,
+    }
+}
+
+fn impl_item_group(item: &ImplItem) -> Option<u8> {
+    match item {
+        ImplItem::Method(_) => None,
+        _ => Some(0),
+    }
+}
+
+fn trait_item_group(item: &TraitItem) -> Option<u8> {
+    match item {
+        TraitItem::Method(_) => None,
+        _ => Some(0),
+    }
+}
+
+// The body of a method without one, like `fn f();` in an impl, which is
+// parsed as a block holding only a verbatim `;`.
+fn is_verbatim_semi(block: &Block) -> bool {
+    if block.stmts.len() == 1 {
+        if let Stmt::Item(Item::Verbatim(verbatim)) = &block.stmts[0] {
+            return verbatim.to_string() == ";";
+        }
+    }
+    false
+}
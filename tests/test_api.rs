@@ -0,0 +1,92 @@
+use syn::api::{self, ApiItem, PublicItem};
+use syn::{parse_quote, File, Item};
+
+fn paths(items: &[PublicItem]) -> Vec<String> {
+    items.iter().map(PublicItem::path_string).collect()
+}
+
+#[test]
+fn test_public_items() {
+    let file: File = parse_quote! {
+        pub mod shapes {
+            pub use self::circle::*;
+            pub use super::util::{self, scale as resize};
+
+            mod circle {
+                pub struct Circle;
+                pub fn area(circle: &Circle) -> f64 { 0.0 }
+                fn private() {}
+            }
+
+            mod hidden {
+                pub struct Hidden;
+            }
+        }
+
+        mod util {
+            pub fn scale(factor: f64) {}
+
+            impl crate::shapes::Circle {
+                pub const UNIT: f64 = 1.0;
+                pub fn radius(&self) -> f64 { 1.0 }
+            }
+        }
+
+        extern "C" {
+            pub fn abs(x: i32) -> i32;
+        }
+
+        extern "C" {
+            pub static ERRNO: i32;
+        }
+
+        #[macro_export]
+        macro_rules! shape {
+            () => {};
+        }
+
+        mod inner {
+            #[macro_export]
+            macro_rules! inner_shape {
+                () => {};
+            }
+        }
+
+        pub(crate) struct Internal;
+    };
+
+    let items = api::public_items(&file);
+    assert_eq!(
+        paths(&items),
+        [
+            "shapes",
+            "shapes::Circle",
+            "shapes::area",
+            "shapes::util",
+            "shapes::util::scale",
+            "shapes::resize",
+            "abs",
+            "ERRNO",
+            "shapes::Circle::UNIT",
+            "shapes::Circle::radius",
+            "shape",
+            "inner_shape",
+        ],
+    );
+
+    let reexports: Vec<bool> = items.iter().map(|item| item.reexport).collect();
+    assert_eq!(
+        reexports,
+        [false, false, false, true, false, true, false, false, false, false, false, false],
+    );
+
+    let area = items[2].signature().unwrap();
+    assert_eq!(area.ident, "area");
+    assert_eq!(area.inputs.len(), 1);
+    assert!(items[1].signature().is_none());
+    assert!(items[10].vis.is_none());
+    match items[10].item {
+        ApiItem::Item(Item::Macro(_)) => {}
+        _ => panic!("expected macro_rules"),
+    }
+}
@@ -1,8 +1,12 @@
 #[macro_use]
 mod macros;
 
+use quote::ToTokens;
 use syn::parse::Parser;
-use syn::{Attribute, Meta};
+use syn::{
+    Attribute, CfgExpr, DeriveList, Ident, LintAttr, LintLevel, Meta, ParsedAttributes, Path,
+    ReprAttr, ReprInt,
+};
 
 #[test]
 fn test_meta_item_word() {
@@ -326,6 +330,268 @@ fn test_negative_lit() {
     "###);
 }
 
+#[test]
+fn test_parsed_attributes() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[foo] #[bar = 1] #[foo(x)] #[baz + 1]")
+        .unwrap();
+    let mut parsed = ParsedAttributes::new(&attrs);
+
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(parsed.iter().len(), 4);
+    assert!(parsed.get(4).is_none());
+
+    let foo: Vec<_> = parsed.find("foo").collect();
+    assert_eq!(foo.len(), 2);
+    assert!(matches!(foo[0].1, Ok(Meta::Path(_))));
+    assert!(matches!(foo[1].1, Ok(Meta::List(_))));
+
+    let (attr, meta) = parsed.get(1).unwrap();
+    assert!(attr.path.is_ident("bar"));
+    assert!(matches!(meta, Ok(Meta::NameValue(_))));
+
+    let (_attr, meta) = parsed.get(3).unwrap();
+    assert!(meta.is_err());
+    assert!(parsed.find("baz").next().unwrap().1.is_err());
+
+    let args: Vec<_> = parsed.find_args::<Ident>("foo").collect();
+    assert_eq!(args.len(), 2);
+    assert!(args[0].1.is_err());
+    assert_eq!(args[1].1.as_ref().unwrap(), "x");
+
+    // The same arguments parsed as another type are cached separately.
+    let (_attr, args) = parsed.get_args::<Path>(2).unwrap();
+    assert!(args.as_ref().unwrap().is_ident("x"));
+    let (_attr, args) = parsed.get_args::<Ident>(2).unwrap();
+    assert_eq!(args.as_ref().unwrap(), "x");
+}
+
+#[test]
+fn test_derive_list() {
+    let attrs = Attribute::parse_outer
+        .parse_str(
+            r#"
+            #[derive(Debug, Clone,)]
+            #[doc = "..."]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(deny_unknown_fields))]
+            #[cfg_attr(unix, cfg_attr(test, derive(PartialEq), allow(dead_code)))]
+            #[derive(::core::hash::Hash)]
+            "#,
+        )
+        .unwrap();
+    let derives = DeriveList::from_attrs(&attrs).unwrap();
+
+    let traits: Vec<String> = derives
+        .traits
+        .iter()
+        .map(|derived| {
+            let cfg: Vec<String> = derived
+                .cfg
+                .iter()
+                .map(|meta| meta.to_token_stream().to_string())
+                .collect();
+            format!("{} {:?}", derived.path.to_token_stream(), cfg)
+        })
+        .collect();
+    assert_eq!(
+        traits,
+        [
+            "Debug []",
+            "Clone []",
+            r#"serde :: Serialize ["feature = \"serde\""]"#,
+            r#"PartialEq ["unix", "test"]"#,
+            ":: core :: hash :: Hash []",
+        ],
+    );
+
+    assert!(derives.contains("Serialize"));
+    assert!(derives.contains("Hash"));
+    assert!(!derives.contains("Eq"));
+}
+
+#[test]
+fn test_derive_list_errors() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[derive(Debug = 1)] #[cfg_attr(unix)] #[derive(Clone)]")
+        .unwrap();
+    let errors: Vec<String> = match DeriveList::from_attrs(&attrs) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    };
+    assert_eq!(errors, ["expected `,`", "expected `,`"]);
+}
+
+#[test]
+fn test_repr() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[repr(C, packed(2))] #[doc = \"...\"] #[repr(i32)]")
+        .unwrap();
+    let repr = ReprAttr::from_attrs(&attrs).unwrap();
+    assert!(repr.is_c());
+    assert!(!repr.is_transparent());
+    assert_eq!(repr.packed.map(|(n, _span)| n), Some(2));
+    assert_eq!(repr.int.map(|(int, _span)| int), Some(ReprInt::I32));
+    assert!(repr.int.unwrap().0.is_signed());
+    assert!(repr.align.is_none());
+
+    let attrs = Attribute::parse_outer
+        .parse_str("#[repr(transparent)] #[repr(packed)]")
+        .unwrap();
+    assert_eq!(
+        repr_errors(&attrs),
+        ["transparent representation cannot have other repr hints"],
+    );
+
+    let attrs = Attribute::parse_outer
+        .parse_str("#[repr(u8, u16, align(3), packed(1, 2), simd(x), foo)] #[repr]")
+        .unwrap();
+    assert_eq!(
+        repr_errors(&attrs),
+        [
+            "conflicting representation hints",
+            "invalid `repr(align)`: not a power of two up to 2^29",
+            "`packed` takes exactly one integer argument",
+            "unrecognized representation hint",
+            "unrecognized representation hint",
+            "expected attribute arguments in parentheses: #[repr(...)]",
+        ],
+    );
+}
+
+fn repr_errors(attrs: &[Attribute]) -> Vec<String> {
+    match ReprAttr::from_attrs(attrs) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_cfg_expr() {
+    let attrs = Attribute::parse_outer
+        .parse_str(r#"#[cfg(all(unix, not(test), any(feature = "a", target_os = "linux",),))]"#)
+        .unwrap();
+    let cfg: CfgExpr = attrs[0].parse_args().unwrap();
+
+    let tokens: proc_macro2::TokenStream = attrs[0].parse_args().unwrap();
+    assert_eq!(cfg.to_token_stream().to_string(), tokens.to_string());
+
+    snapshot!(cfg, @r###"
+    All(CfgAll {
+        ident: "all",
+        predicates: [
+            Key("unix"),
+            Not(CfgNot {
+                ident: "not",
+                predicate: Key("test"),
+            }),
+            Any(CfgAny {
+                ident: "any",
+                predicates: [
+                    KeyValue(CfgKeyValue {
+                        key: "feature",
+                        value: "a",
+                    }),
+                    KeyValue(CfgKeyValue {
+                        key: "target_os",
+                        value: "linux",
+                    }),
+                ],
+            }),
+        ],
+    })
+    "###);
+
+    for (input, message) in &[
+        ("foo(a)", "invalid predicate `foo`"),
+        ("not(a, b)", "expected 1 cfg-pattern"),
+        ("feature = 1", "expected string literal"),
+    ] {
+        match syn::parse_str::<CfgExpr>(input) {
+            Ok(_) => panic!("expected {} to fail", input),
+            Err(err) => assert_eq!(err.to_string(), *message),
+        }
+    }
+}
+
+#[test]
+fn test_lint_attr() {
+    let attrs = Attribute::parse_outer
+        .parse_str(
+            r#"
+            #[warn(missing_docs,)]
+            #[expect(clippy::pedantic, unused, reason = "...")]
+            #[forbid()]
+            #[must_use]
+            "#,
+        )
+        .unwrap();
+    let lint_attrs = LintAttr::from_attrs(&attrs).unwrap();
+    let summary: Vec<String> = lint_attrs
+        .iter()
+        .map(|lint_attr| {
+            let lints: Vec<String> = lint_attr
+                .lints
+                .iter()
+                .map(|path| path.to_token_stream().to_string())
+                .collect();
+            format!(
+                "{:?} {:?} {:?}",
+                lint_attr.level,
+                lints,
+                lint_attr.reason.as_ref().map(|reason| reason.value()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            r#"Warn ["missing_docs"] None"#,
+            r#"Expect ["clippy :: pedantic", "unused"] Some("...")"#,
+            "Forbid [] None",
+        ],
+    );
+    assert_eq!(LintLevel::Deny.as_str(), "deny");
+    assert!(LintAttr::from_attr(&attrs[3]).is_none());
+
+    let attrs = Attribute::parse_outer
+        .parse_str(r#"#[allow(reason = "...", dead_code)] #[deny(lint = "x")] #[warn]"#)
+        .unwrap();
+    let errors: Vec<String> = match LintAttr::from_attrs(&attrs) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    };
+    assert_eq!(
+        errors,
+        [
+            "reason in lint attribute must come last",
+            "expected `reason`",
+            "expected attribute arguments in parentheses: #[warn(...)]",
+        ],
+    );
+}
+
+#[test]
+fn test_parse_outer_inner() {
+    let parse_both = |input: syn::parse::ParseStream| {
+        let inner = input.call(Attribute::parse_inner)?;
+        let outer = input.call(Attribute::parse_outer)?;
+        Ok((inner, outer))
+    };
+    let (inner, outer) = parse_both
+        .parse_str("//! Docs.\n#![a] /// More.\n#[b]")
+        .unwrap();
+    assert_eq!(inner.len(), 2);
+    assert_eq!(outer.len(), 2);
+    assert!(inner.iter().all(|attr| attr.style != syn::AttrStyle::Outer));
+    assert!(outer.iter().all(|attr| attr.style == syn::AttrStyle::Outer));
+
+    let err = Attribute::parse_outer.parse_str("#[a] #![b]").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected outer attribute, found inner attribute",
+    );
+}
+
 fn test(input: &str) -> Meta {
     let attrs = Attribute::parse_outer.parse_str(input).unwrap();
 
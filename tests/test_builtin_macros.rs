@@ -0,0 +1,396 @@
+use quote::quote;
+use syn::builtin_macros::{
+    Assert, BuiltinMacro, FormatAlign, FormatArgRef, FormatArgTarget, FormatArgs, FormatCount,
+    FormatPiece, FormatSign, FormatString, LazyStatic, MacroKind, MacroRules, Matches, ThreadLocal,
+    VecMacro,
+};
+use syn::{parse_quote, Expr, ItemMacro, Lit, LitStr, Macro, MacroDelimiter, Pat, Visibility};
+
+#[test]
+fn test_format_args() {
+    let args: FormatArgs =
+        syn::parse2(quote!("{} {x} {y:w$}", a, x = 1, y = b == c, w = 5)).unwrap();
+    assert!(matches!(args.format, Expr::Lit(_)));
+    assert_eq!(args.args.len(), 4);
+    assert!(args.args[0].name.is_none());
+    let names: Vec<String> = args
+        .args
+        .iter()
+        .filter_map(|arg| arg.name.as_ref())
+        .map(|(name, _eq)| name.to_string())
+        .collect();
+    assert_eq!(names, ["x", "y", "w"]);
+    assert!(matches!(args.args[2].expr, Expr::Binary(_)));
+
+    let args: FormatArgs = syn::parse2(quote!("plain")).unwrap();
+    assert!(args.comma.is_none());
+    assert!(args.args.is_empty());
+
+    let args: FormatArgs = syn::parse2(quote!("{}", a,)).unwrap();
+    assert_eq!(args.args.len(), 1);
+    assert!(args.args.trailing_punct());
+
+    // A comparison is a positional argument, not a named one.
+    let args: FormatArgs = syn::parse2(quote!("{}", a == b)).unwrap();
+    assert!(args.args[0].name.is_none());
+}
+
+#[test]
+fn test_vec() {
+    let vec: VecMacro = syn::parse2(quote!()).unwrap();
+    assert!(matches!(vec, VecMacro::Elems(ref elems) if elems.is_empty()));
+
+    let vec: VecMacro = syn::parse2(quote!(1, 2, 3,)).unwrap();
+    match vec {
+        VecMacro::Elems(elems) => assert_eq!(elems.len(), 3),
+        VecMacro::Repeat { .. } => panic!("expected elements"),
+    }
+
+    let vec: VecMacro = syn::parse2(quote!(0u8; n * 2)).unwrap();
+    match vec {
+        VecMacro::Repeat { elem, len, .. } => {
+            assert!(matches!(*elem, Expr::Lit(_)));
+            assert!(matches!(*len, Expr::Binary(_)));
+        }
+        VecMacro::Elems(_) => panic!("expected repetition"),
+    }
+
+    assert!(syn::parse2::<VecMacro>(quote!(1; 2; 3)).is_err());
+}
+
+#[test]
+fn test_matches() {
+    let matches: Matches = syn::parse2(quote!(c, 'a'..='z' | 'A'..='Z' if c != 'x',)).unwrap();
+    assert!(matches!(matches.pat, Pat::Or(_)));
+    assert!(matches.guard.is_some());
+    assert!(matches.trailing_comma.is_some());
+
+    let matches: Matches = syn::parse2(quote!(opt, Some(_))).unwrap();
+    assert!(matches!(matches.pat, Pat::TupleStruct(_)));
+    assert!(matches.guard.is_none());
+
+    assert!(syn::parse2::<Matches>(quote!(opt)).is_err());
+}
+
+#[test]
+fn test_assert() {
+    let assert: Assert = syn::parse2(quote!(x > 0)).unwrap();
+    assert!(assert.message.is_none());
+
+    let assert: Assert = syn::parse2(quote!(x > 0,)).unwrap();
+    assert!(assert.comma.is_some());
+    assert!(assert.message.is_none());
+
+    let assert: Assert = syn::parse2(quote!(x > 0, "x was {}", x)).unwrap();
+    let message = assert.message.unwrap();
+    assert!(matches!(message.format, Expr::Lit(ref lit) if matches!(lit.lit, Lit::Str(_))));
+    assert_eq!(message.args.len(), 1);
+}
+
+#[test]
+fn test_from_macro() {
+    let mac: Macro = parse_quote!(std::vec![1, 2]);
+    assert!(matches!(
+        BuiltinMacro::from_macro(&mac),
+        Some(Ok(BuiltinMacro::Vec(_)))
+    ));
+
+    let mac: Macro = parse_quote!(debug_assert!(ok));
+    assert!(matches!(
+        BuiltinMacro::from_macro(&mac),
+        Some(Ok(BuiltinMacro::Assert(_)))
+    ));
+
+    let mac: Macro = parse_quote!(panic!());
+    assert!(BuiltinMacro::from_macro(&mac).is_none());
+
+    let mac: Macro = parse_quote!(my_macro!(anything goes));
+    assert!(BuiltinMacro::from_macro(&mac).is_none());
+
+    let mac: Macro = parse_quote!(matches!(x));
+    assert!(matches!(BuiltinMacro::from_macro(&mac), Some(Err(_))));
+
+    // Printing reproduces the original body.
+    let mac: Macro = parse_quote!(println!("{}", x = 1));
+    match BuiltinMacro::from_macro(&mac) {
+        Some(Ok(parsed)) => assert_eq!(quote!(#parsed).to_string(), mac.tokens.to_string()),
+        _ => panic!("expected println! to be recognized"),
+    }
+}
+
+#[test]
+fn test_macro_kind() {
+    let kinds = [
+        (quote!(std::thread_local!()), Some(MacroKind::ThreadLocal)),
+        (
+            quote!(lazy_static::lazy_static!()),
+            Some(MacroKind::LazyStatic),
+        ),
+        (
+            quote!(
+                macro_rules! m {}
+            ),
+            Some(MacroKind::MacroRules),
+        ),
+        (quote!(eprintln!()), Some(MacroKind::FormatArgs)),
+        (quote!(my_macro!()), None),
+    ];
+    for (tokens, kind) in kinds.iter() {
+        let mac = match syn::parse2::<ItemMacro>(tokens.clone()) {
+            Ok(item) => item.mac,
+            Err(_) => syn::parse2::<Macro>(tokens.clone()).unwrap(),
+        };
+        assert_eq!(MacroKind::of(&mac), *kind, "{}", tokens);
+    }
+    assert!(MacroKind::ThreadLocal.is_item());
+    assert!(!MacroKind::Vec.is_item());
+}
+
+#[test]
+fn test_thread_local() {
+    let body: ThreadLocal = syn::parse2(quote! {
+        /// Documented.
+        pub static COUNT: Cell<u32> = Cell::new(0);
+        static NAME: RefCell<String> = const { RefCell::new(String::new()) }
+    })
+    .unwrap();
+    assert_eq!(body.statics.len(), 2);
+    assert_eq!(body.statics[0].attrs.len(), 1);
+    assert!(matches!(body.statics[0].vis, Visibility::Public(_)));
+    assert!(body.statics[0].const_token.is_none());
+    assert!(matches!(*body.statics[0].expr, Expr::Call(_)));
+    assert_eq!(body.statics[1].ident, "NAME");
+    assert!(body.statics[1].const_token.is_some());
+    assert!(matches!(*body.statics[1].expr, Expr::Block(_)));
+    assert!(body.statics[1].semi_token.is_none());
+
+    let body: ThreadLocal = syn::parse2(quote!()).unwrap();
+    assert!(body.statics.is_empty());
+
+    // Only the last static may go without a semicolon.
+    assert!(syn::parse2::<ThreadLocal>(quote! {
+        static A: u8 = 0
+        static B: u8 = 0;
+    })
+    .is_err());
+}
+
+#[test]
+fn test_lazy_static() {
+    let item: ItemMacro = parse_quote! {
+        lazy_static! {
+            static ref RE: Regex = Regex::new("a+").unwrap();
+            pub(crate) static ref MAP: HashMap<u8, u8> = {
+                let mut map = HashMap::new();
+                map.insert(1, 2);
+                map
+            };
+        }
+    };
+    let body = match BuiltinMacro::from_macro(&item.mac) {
+        Some(Ok(BuiltinMacro::LazyStatic(body))) => body,
+        _ => panic!("expected lazy_static! to be recognized"),
+    };
+    let names: Vec<String> = body.statics.iter().map(|s| s.ident.to_string()).collect();
+    assert_eq!(names, ["RE", "MAP"]);
+    assert!(matches!(body.statics[1].vis, Visibility::Restricted(_)));
+    assert_eq!(quote!(#body).to_string(), item.mac.tokens.to_string());
+
+    // Unlike `thread_local!`, every static needs a semicolon.
+    assert!(syn::parse2::<LazyStatic>(quote!(static ref X: u8 = 0)).is_err());
+}
+
+#[test]
+fn test_macro_rules() {
+    let item: ItemMacro = parse_quote! {
+        macro_rules! square {
+            () => {};
+            ($x:expr) => { $x * $x };
+            [$($x:expr),*] => ( vec![$(square!($x)),*] )
+        }
+    };
+    assert_eq!(item.ident.unwrap(), "square");
+    let body: MacroRules = item.mac.parse_body().unwrap();
+    assert_eq!(body.rules.len(), 3);
+    assert!(body.rules[0].matcher.1.is_empty());
+    assert!(matches!(
+        body.rules[2].matcher.0,
+        MacroDelimiter::Bracket(_)
+    ));
+    assert!(matches!(
+        body.rules[2].transcriber.0,
+        MacroDelimiter::Paren(_)
+    ));
+    assert!(body.rules[2].semi_token.is_none());
+    assert_eq!(quote!(#body).to_string(), item.mac.tokens.to_string());
+
+    assert!(syn::parse2::<MacroRules>(quote!(($x:expr) { $x })).is_err());
+}
+
+fn format_string(repr: &str) -> FormatString {
+    let lit: LitStr = syn::parse_str(repr).unwrap();
+    FormatString::parse(&lit).unwrap()
+}
+
+#[test]
+fn test_format_string_pieces() {
+    let format = format_string(r#""a {{b}} {} {0} {name}""#);
+    let pieces: Vec<String> = format
+        .pieces
+        .iter()
+        .map(|piece| match piece {
+            FormatPiece::Text(text) => format!("text {:?} {:?}", text.text, text.range),
+            FormatPiece::Placeholder(placeholder) => {
+                format!("{:?} {:?}", placeholder.arg, placeholder.range)
+            }
+        })
+        .collect();
+    assert_eq!(
+        pieces,
+        [
+            r#"text "a {b} " 0..8"#,
+            "Next 8..10",
+            r#"text " " 10..11"#,
+            "Index(0) 11..14",
+            r#"text " " 14..15"#,
+            r#"Name("name") 15..21"#,
+        ],
+    );
+}
+
+#[test]
+fn test_format_spec() {
+    let format = format_string(r#""{:*^+#010.3e} {x:>width$.prec$?} {:.*} {:0$} {:x?} {:<}""#);
+    let specs: Vec<_> = format.placeholders().map(|p| &p.spec).collect();
+
+    assert_eq!(specs[0].fill, Some('*'));
+    assert_eq!(specs[0].align, Some(FormatAlign::Center));
+    assert_eq!(specs[0].sign, Some(FormatSign::Plus));
+    assert!(specs[0].alternate);
+    assert!(specs[0].zero_pad);
+    assert_eq!(specs[0].width, Some(FormatCount::Literal(10)));
+    assert_eq!(specs[0].precision, Some(FormatCount::Literal(3)));
+    assert_eq!(specs[0].ty, "e");
+    assert_eq!(specs[0].range, 2..12);
+
+    assert_eq!(specs[1].fill, None);
+    assert_eq!(specs[1].align, Some(FormatAlign::Right));
+    assert_eq!(
+        specs[1].width,
+        Some(FormatCount::Arg(
+            FormatArgRef::Name("width".to_owned()),
+            18..23
+        )),
+    );
+    assert_eq!(
+        specs[1].precision,
+        Some(FormatCount::Arg(
+            FormatArgRef::Name("prec".to_owned()),
+            25..29
+        )),
+    );
+    assert_eq!(specs[1].ty, "?");
+
+    assert_eq!(
+        specs[2].precision,
+        Some(FormatCount::Arg(FormatArgRef::Next, 36..37)),
+    );
+
+    assert!(!specs[3].zero_pad);
+    assert_eq!(
+        specs[3].width,
+        Some(FormatCount::Arg(FormatArgRef::Index(0), 41..42)),
+    );
+
+    assert_eq!(specs[4].ty, "x?");
+    assert_eq!(specs[4].width, None);
+
+    assert_eq!(specs[5].fill, None);
+    assert_eq!(specs[5].align, Some(FormatAlign::Left));
+}
+
+#[test]
+fn test_format_string_errors() {
+    for (repr, message) in &[
+        (
+            r#""{""#,
+            "invalid format string: expected `}` but string was terminated",
+        ),
+        (r#""}""#, "invalid format string: unmatched `}` found"),
+        (
+            r#""{0 }""#,
+            "invalid format string: expected `}`, found ` `",
+        ),
+        (
+            r#""{:.}""#,
+            "invalid format string: expected precision after `.`",
+        ),
+    ] {
+        let lit: LitStr = syn::parse_str(repr).unwrap();
+        match FormatString::parse(&lit) {
+            Ok(_) => panic!("expected {} to fail", repr),
+            Err(err) => assert_eq!(err.to_string(), *message),
+        }
+    }
+}
+
+#[test]
+fn test_format_escaped_literal() {
+    // Ranges are in terms of the value, not the source, of the literal.
+    let format = format_string(r#""\u{1F600} \n{}""#);
+    let placeholder = format.placeholders().next().unwrap();
+    assert_eq!(placeholder.range, 6..8);
+
+    let format = format_string(r###"r#"{}"#"###);
+    assert_eq!(format.placeholders().count(), 1);
+}
+
+#[test]
+fn test_map_arguments() {
+    let args: FormatArgs = syn::parse2(quote!(
+        "{} {:.*} {1} {x} {y} {:w$}",
+        a,
+        2,
+        b,
+        c,
+        x = d,
+        w = 4
+    ))
+    .unwrap();
+    let (_format, uses) = args.map_arguments().unwrap();
+    let targets: Vec<String> = uses
+        .iter()
+        .map(|arg_use| match &arg_use.target {
+            FormatArgTarget::Index(index) => index.to_string(),
+            FormatArgTarget::Captured(ident) => ident.to_string(),
+        })
+        .collect();
+    assert_eq!(targets, ["0", "1", "2", "1", "4", "y", "5", "3"]);
+
+    let args: FormatArgs = syn::parse2(quote!("{} {}", a)).unwrap();
+    let err = map_arguments_err(&args);
+    assert_eq!(
+        err.to_string(),
+        "invalid reference to positional argument 1 (there is 1 argument)",
+    );
+
+    let args: FormatArgs = syn::parse2(quote!("{}", a, b, c = 1)).unwrap();
+    let messages: Vec<String> = map_arguments_err(&args)
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        ["argument never used", "named argument never used"]
+    );
+
+    let args: FormatArgs = syn::parse2(quote!(concat!("{}", "{}"), a, b)).unwrap();
+    assert!(args.format_string().is_err());
+}
+
+fn map_arguments_err(args: &FormatArgs) -> syn::Error {
+    match args.map_arguments() {
+        Ok(_) => panic!("expected map_arguments to fail"),
+        Err(err) => err,
+    }
+}
@@ -4,7 +4,7 @@
 mod macros;
 
 use quote::quote;
-use syn::{Data, DeriveInput};
+use syn::{parse_quote, Data, DeriveInput, Fields, Generics};
 
 #[test]
 fn test_unit() {
@@ -892,3 +892,94 @@ fn test_ambiguous_crate() {
     }
     "###);
 }
+
+#[test]
+fn test_impl_trait() {
+    let input: DeriveInput = parse_quote! {
+        enum Either<L, R, const N: usize = 0> {
+            Left(L),
+            Right(R),
+        }
+    };
+    let mut extra: Generics = parse_quote!(<'de>);
+    extra.where_clause = Some(parse_quote!(where L: Deserialize<'de>));
+    let item = input.impl_trait_with_generics(parse_quote!(serde::Deserialize<'de>), &extra);
+    let expected = quote! {
+        impl<'de, L, R, const N: usize> serde::Deserialize<'de> for Either<L, R, N>
+        where
+            L: Deserialize<'de>
+        {}
+    };
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+
+    let input: DeriveInput = parse_quote!(
+        struct Unit;
+    );
+    let item = input.impl_trait(parse_quote!(Default));
+    assert_eq!(
+        quote!(#item).to_string(),
+        quote!(impl Default for Unit {}).to_string(),
+    );
+}
+
+#[test]
+fn test_variant_patterns() {
+    let input: DeriveInput = parse_quote! {
+        enum Shape {
+            Circle { r#type: u8, radius: f64 },
+            Line(Point, Point),
+            Empty,
+        }
+    };
+
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => unreachable!(),
+    };
+    let patterns = data.variant_patterns();
+    let printed: Vec<String> = patterns
+        .iter()
+        .map(|pat| quote!(#pat).to_string())
+        .collect();
+    let expected = [
+        "Self :: Circle { r#type : __binding_0 , radius : __binding_1 }",
+        "Self :: Line (__binding_0 , __binding_1)",
+        "Self :: Empty",
+    ];
+    assert_eq!(printed, expected);
+
+    let fields: Vec<String> = patterns[0]
+        .iter()
+        .map(|(field, binding)| format!("{}={}", field.ident.as_ref().unwrap(), binding))
+        .collect();
+    assert_eq!(fields, ["r#type=__binding_0", "radius=__binding_1"]);
+
+    let pattern = Fields::Unit.pattern(parse_quote!(Unit));
+    assert!(pattern.bindings.is_empty());
+    assert_eq!(quote!(#pattern).to_string(), "Unit");
+}
+
+#[test]
+fn test_struct_accessors() {
+    let mut item: syn::ItemFn = parse_quote!(fn f() {});
+    assert!(item.attrs().is_empty());
+    assert!(item.vis().is_inherited());
+    assert_eq!(item.sig().ident, "f");
+
+    item.set_vis(parse_quote!(pub));
+    item.attrs_mut().push(parse_quote!(#[inline]));
+    item.block_mut().stmts.push(parse_quote!(g();));
+    assert_eq!(item.block().stmts.len(), 1);
+    let expected: syn::ItemFn = parse_quote!(#[inline] pub fn f() { g(); });
+    assert_eq!(item, expected);
+
+    // Boxed fields are accessed and replaced without the box, and fields
+    // named after keywords drop the underscore in the other method names.
+    let mut item: syn::ItemImpl = parse_quote!(impl Trait for S {});
+    item.set_self_ty(parse_quote!(T));
+    assert_eq!(*item.self_ty(), parse_quote!(T));
+    item.trait_mut().take();
+    item.set_trait(None);
+    let expected: syn::ItemImpl = parse_quote!(impl T {});
+    assert_eq!(item, expected);
+}
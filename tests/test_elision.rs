@@ -0,0 +1,108 @@
+use syn::elision::{ObjectContext, ObjectLifetime, OutputSource};
+use syn::{Lifetime, Signature, TypeTraitObject};
+
+fn parse(s: &str) -> Signature {
+    syn::parse_str(s).unwrap()
+}
+
+#[test]
+fn test_receiver() {
+    let sig = parse("fn f(&mut self, x: &u8) -> &u8");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.inputs.len(), 2);
+    assert!(elision.inputs[0].is_receiver);
+    assert!(!elision.inputs[1].is_receiver);
+    assert_eq!(elision.output.len(), 1);
+    assert_eq!(elision.output_source, OutputSource::Receiver(0));
+    assert!(elision.is_valid());
+}
+
+#[test]
+fn test_typed_receiver() {
+    let sig = parse("fn f(self: Pin<&'a mut Self>, x: &u8) -> &u8");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.output_source, OutputSource::Receiver(0));
+    let lifetime = elision.output_lifetime().unwrap().lifetime.unwrap();
+    assert_eq!(lifetime.ident, "a");
+
+    let sig = parse("fn f(self: Box<Self>, x: &u8) -> &u8");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.output_source, OutputSource::SoleInput(0));
+}
+
+#[test]
+fn test_sole_input() {
+    let sig = parse("fn f<'a>(x: &'a u8, y: Cow<'a, str>) -> &str");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.inputs.len(), 2);
+    assert_eq!(elision.elided_inputs().count(), 0);
+    assert_eq!(elision.output_source, OutputSource::SoleInput(0));
+    assert!(elision.is_valid());
+}
+
+#[test]
+fn test_invalid() {
+    let sig = parse("fn f(x: &u8, y: &u8) -> &u8");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.elided_inputs().count(), 2);
+    assert_eq!(elision.output_source, OutputSource::Ambiguous);
+    assert!(!elision.is_valid());
+
+    let sig = parse("fn f() -> &'_ str");
+    let elision = sig.lifetime_elision();
+    assert_eq!(elision.output_source, OutputSource::NoInputs);
+    assert!(!elision.is_valid());
+
+    let sig = parse("fn f() -> &'static str");
+    assert!(sig.lifetime_elision().is_valid());
+}
+
+#[test]
+fn test_separate_scopes() {
+    let sig = parse("fn f(x: fn(&u8) -> &u8, y: Box<dyn for<'a> Fn(&'a u8) -> &'a u8>) -> u8");
+    let elision = sig.lifetime_elision();
+    assert!(elision.inputs.is_empty());
+    assert!(elision.output.is_empty());
+}
+
+#[test]
+fn test_object_lifetime() {
+    let object: TypeTraitObject = syn::parse_str("dyn Trait").unwrap();
+    let explicit: TypeTraitObject = syn::parse_str("dyn Trait + 'b").unwrap();
+    let a: Lifetime = syn::parse_str("'a").unwrap();
+    let b: Lifetime = syn::parse_str("'b").unwrap();
+    let bounds = [a.clone(), b];
+
+    match explicit.default_lifetime(ObjectContext::Other, &[]) {
+        ObjectLifetime::Explicit(lifetime) => assert_eq!(lifetime.ident, "b"),
+        _ => panic!("expected explicit lifetime"),
+    }
+    match object.default_lifetime(ObjectContext::Reference(Some(&a)), &[]) {
+        ObjectLifetime::Default(lifetime) => assert_eq!(lifetime.ident, "a"),
+        _ => panic!("expected reference lifetime"),
+    }
+    match object.default_lifetime(ObjectContext::Reference(None), &[]) {
+        ObjectLifetime::Reference => {}
+        _ => panic!("expected elided reference lifetime"),
+    }
+    match object.default_lifetime(ObjectContext::TypeArgument(&[]), &[]) {
+        ObjectLifetime::Static => {}
+        _ => panic!("expected 'static"),
+    }
+    match object.default_lifetime(ObjectContext::TypeArgument(&bounds[..1]), &[]) {
+        ObjectLifetime::Default(lifetime) => assert_eq!(lifetime.ident, "a"),
+        _ => panic!("expected type parameter bound"),
+    }
+    match object.default_lifetime(ObjectContext::TypeArgument(&bounds), &[]) {
+        ObjectLifetime::Ambiguous => {}
+        _ => panic!("expected ambiguous"),
+    }
+    match object.default_lifetime(ObjectContext::Expression, &[]) {
+        ObjectLifetime::Inferred => {}
+        _ => panic!("expected inferred"),
+    }
+    match object.default_lifetime(ObjectContext::Expression, &bounds[..1]) {
+        ObjectLifetime::Default(lifetime) => assert_eq!(lifetime.ident, "a"),
+        _ => panic!("expected trait bound"),
+    }
+}
@@ -0,0 +1,94 @@
+use syn::{parse_quote, ExprMatch, ItemEnum, Pat};
+
+fn enum_item() -> ItemEnum {
+    parse_quote! {
+        enum Shape {
+            Point,
+            Circle(f64),
+            Rect { w: f64, h: f64 },
+            Poly(Vec<(f64, f64)>),
+        }
+    }
+}
+
+fn arm_pats(expr: ExprMatch) -> Vec<Pat> {
+    expr.arms.into_iter().map(|arm| arm.pat).collect()
+}
+
+fn names(variants: &[&syn::Variant]) -> Vec<String> {
+    variants.iter().map(|v| v.ident.to_string()).collect()
+}
+
+#[test]
+fn test_exhaustive() {
+    let item = enum_item();
+    let pats = arm_pats(parse_quote! {
+        match shape {
+            Shape::Point | Self::Circle(_) => {}
+            &Shape::Rect { w, h: ref height } => {}
+            Poly(points) => {}
+        }
+    });
+    let coverage = syn::exhaustive::check(&item, &pats);
+    assert!(coverage.missing.is_empty());
+    assert!(coverage.partial.is_empty());
+    assert!(coverage.unreachable.is_empty());
+}
+
+#[test]
+fn test_missing_and_partial() {
+    let item = enum_item();
+    let pats = arm_pats(parse_quote! {
+        match shape {
+            Shape::Circle(r) => {}
+            Shape::Rect { w: 0.0, .. } => {}
+        }
+    });
+    let coverage = syn::exhaustive::check(&item, &pats);
+    assert_eq!(names(&coverage.missing), ["Point", "Poly"]);
+    assert_eq!(names(&coverage.partial), ["Rect"]);
+    assert!(coverage.unreachable.is_empty());
+}
+
+#[test]
+fn test_unreachable() {
+    let item = enum_item();
+    let pats = arm_pats(parse_quote! {
+        match shape {
+            Shape::Circle(_) => {}
+            Shape::Circle(1.0) => {}
+            Shape::Point | Shape::Rect { .. } | Shape::Poly(..) => {}
+            _ => {}
+            Shape::Point => {}
+        }
+    });
+    let coverage = syn::exhaustive::check(&item, &pats);
+    assert!(coverage.missing.is_empty());
+    assert_eq!(coverage.unreachable, [1, 3, 4]);
+
+    // A binding after some of the variants still matches the others.
+    let more = arm_pats(parse_quote! {
+        match shape {
+            Shape::Point => {}
+            rest => {}
+        }
+    });
+    let coverage = syn::exhaustive::check(&item, &more);
+    assert!(coverage.missing.is_empty());
+    assert!(coverage.unreachable.is_empty());
+}
+
+#[test]
+fn test_unknown_patterns() {
+    let item = enum_item();
+    let pats = arm_pats(parse_quote! {
+        match shape {
+            Shape::Point => {}
+            shape!(circle) => {}
+            other::Poly(_) => {}
+        }
+    });
+    let coverage = syn::exhaustive::check(&item, &pats);
+    assert!(coverage.missing.is_empty());
+    assert!(coverage.unreachable.is_empty());
+}
@@ -4,7 +4,8 @@ mod macros;
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
 use std::iter::FromIterator;
-use syn::{Expr, ExprRange};
+use std::collections::HashMap;
+use syn::{parse_quote, Arm, Expr, ExprKind, ExprMatch, ExprRange, Type, TypeKind};
 
 #[test]
 fn test_expr_parse() {
@@ -324,3 +325,141 @@ fn test_postfix_operator_after_cast() {
     syn::parse_str::<Expr>("|| &x as T[0]").unwrap_err();
     syn::parse_str::<Expr>("|| () as ()()").unwrap_err();
 }
+
+#[test]
+fn test_match_builders() {
+    let unit = Arm::new(parse_quote!(()), parse_quote!(unit()));
+    assert!(unit.comma.is_some());
+    let block = Arm::new(parse_quote!(_), parse_quote!({}));
+    assert!(block.comma.is_none());
+
+    // An arm without a comma gets one once another arm follows it.
+    let mut last = Arm::new(parse_quote!(x), parse_quote!(x));
+    last.comma = None;
+    let mut expr = ExprMatch::new(parse_quote!(Struct {}), vec![block, last]);
+    expr.push_arm(unit.with_guard(parse_quote!(true)));
+    assert!(expr.arms[1].comma.is_some());
+
+    let expected = quote! {
+        match (Struct {}) {
+            _ => {}
+            x => x,
+            () if true => unit(),
+        }
+    };
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+
+    // The built expression parses back to the same arms.
+    let reparsed: ExprMatch = syn::parse2(quote!(#expr)).unwrap();
+    assert_eq!(reparsed.arms.len(), 3);
+}
+
+#[test]
+fn test_kind() {
+    let exprs: Vec<Expr> = vec![
+        parse_quote!(a),
+        parse_quote!(a + b),
+        parse_quote!(f(x)),
+        parse_quote!(b),
+    ];
+    let mut counts = HashMap::new();
+    for expr in &exprs {
+        *counts.entry(expr.kind()).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&ExprKind::Path], 2);
+    assert_eq!(counts[&ExprKind::Binary], 1);
+    assert_eq!(counts[&ExprKind::Call], 1);
+    assert_eq!(format!("{:?}", exprs[2].kind()), "Call");
+
+    let ty: Type = parse_quote!(&'a str);
+    assert_eq!(ty.kind(), TypeKind::Reference);
+    assert_ne!(ty.kind(), TypeKind::Ptr);
+}
+
+#[test]
+fn test_accessors() {
+    let expr: Expr = parse_quote!(f(x));
+    assert!(expr.is_call());
+    assert!(!expr.is_path());
+    let call = expr.as_call().unwrap();
+    assert!(call.func.is_path());
+    assert_eq!(call.args[0].as_path().unwrap().path.segments.len(), 1);
+    assert!(expr.as_lit().is_none());
+
+    let ret: syn::ReturnType = parse_quote!(-> u8);
+    let (_arrow, ty) = ret.as_type().unwrap();
+    assert!(ty.is_path());
+    assert!(syn::ReturnType::Default.is_default());
+}
+
+#[test]
+fn test_field_shorthand() {
+    let mut expr: syn::ExprStruct = parse_quote!(S {
+        a,
+        b: b,
+        c: d,
+        0: e
+    });
+    let fields: Vec<(bool, bool)> = expr
+        .fields
+        .iter()
+        .map(|field| (field.is_shorthand(), field.can_be_shorthand()))
+        .collect();
+    assert_eq!(
+        fields,
+        [(true, true), (false, true), (false, false), (false, false)],
+    );
+
+    let shortened: Vec<bool> = expr
+        .fields
+        .iter_mut()
+        .map(|field| field.use_shorthand())
+        .collect();
+    assert_eq!(shortened, [true, true, false, false]);
+    let expected = quote!(S { a, b, c: d, 0: e });
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+
+    // A shorthand field whose value is replaced is printed in full.
+    expr.fields[0].expr = parse_quote!(a + 1);
+    expr.fields[1].expand_shorthand();
+    let expected = quote!(S {
+        a: a + 1,
+        b: b,
+        c: d,
+        0: e
+    });
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_struct_update() {
+    let item: syn::ItemStruct = parse_quote! {
+        struct S { a: u8, b: u8, c: u8 }
+    };
+    let a: Ident = parse_quote!(a);
+    let expr = syn::ExprStruct::new(parse_quote!(S))
+        .with_field(syn::FieldValue::new(a.into(), parse_quote!(a)))
+        .with_field(syn::FieldValue::new(
+            parse_quote!(c),
+            parse_quote!(self.c),
+        ))
+        .with_rest(parse_quote!(S::default()));
+    let expected = quote!(S { a, c: self.c, ..S::default() });
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+
+    let omitted: Vec<String> = expr
+        .omitted_fields(&item.fields)
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap().to_string())
+        .collect();
+    assert_eq!(omitted, ["b"]);
+
+    let item: syn::ItemStruct = parse_quote!(struct T(u8, u8, u8););
+    let expr: syn::ExprStruct = parse_quote!(T { 1: x, ..y });
+    let omitted: Vec<Option<&Ident>> = expr
+        .omitted_fields(&item.fields)
+        .into_iter()
+        .map(|field| field.ident.as_ref())
+        .collect();
+    assert_eq!(omitted, [None, None]);
+}
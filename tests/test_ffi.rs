@@ -0,0 +1,119 @@
+use syn::ffi::FfiTypes;
+use syn::{parse_quote, DeriveInput, File, Signature, Type};
+
+fn messages(result: syn::Result<()>) -> Vec<String> {
+    match result {
+        Ok(()) => Vec::new(),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_safe_types() {
+    let types = FfiTypes::new();
+    let safe: Vec<Type> = vec![
+        parse_quote!(u8),
+        parse_quote!(f64),
+        parse_quote!(bool),
+        parse_quote!(std::os::raw::c_int),
+        parse_quote!(*const c_void),
+        parse_quote!(*mut [u8; 16]),
+        parse_quote!(*const *mut String),
+        parse_quote!(NonNull<Vec<u8>>),
+        parse_quote!(Option<NonNull<u8>>),
+        parse_quote!(Option<unsafe extern "C" fn(c_int) -> c_int>),
+        parse_quote!(extern "C" fn() -> !),
+    ];
+    for ty in &safe {
+        assert_eq!(messages(types.check_type(ty)), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn test_unsafe_types() {
+    let types = FfiTypes::new();
+    let cases: Vec<(Type, &str)> = vec![
+        (parse_quote!(&u8), "references are not FFI-safe, use a raw pointer instead"),
+        (parse_quote!(Box<dyn Fn()>), "type `Box` is not known to be FFI-safe, it needs `#[repr(C)]` or `#[repr(transparent)]`"),
+        (parse_quote!(*const dyn Fn()), "pointers to unsized types are not FFI-safe"),
+        (parse_quote!(*const str), "pointers to unsized types are not FFI-safe"),
+        (parse_quote!([u8; 4]), "arrays cannot be passed by value through FFI, use a pointer instead"),
+        (parse_quote!((u8, u8)), "tuples are not FFI-safe, use a `#[repr(C)]` struct instead"),
+        (parse_quote!(char), "`char` is not FFI-safe, use `u32` instead"),
+        (parse_quote!(u128), "128-bit integers do not have a stable ABI"),
+        (parse_quote!(fn(u8)), "function pointers need a non-Rust ABI, like `extern \"C\" fn`"),
+        (parse_quote!(Option<u32>), "`Option` is only FFI-safe around a function pointer or `NonNull`"),
+        (parse_quote!(<T as Trait>::Output), "associated types cannot be checked for FFI safety"),
+    ];
+    for (ty, message) in &cases {
+        assert_eq!(messages(types.check_type(ty)), [*message]);
+    }
+}
+
+#[test]
+fn test_repr_items() {
+    let file: File = parse_quote! {
+        #[repr(C)]
+        struct Point(f64, f64);
+        #[repr(u8)]
+        enum Color { Red, Green }
+        struct Plain;
+        mod inner {
+            #[repr(transparent)]
+            union Handle { raw: usize }
+        }
+        #[repr(packed)]
+        struct Packed(u8);
+    };
+    let mut types = FfiTypes::new();
+    types.add_items(&file.items);
+    for name in &["Point", "Color", "Handle"] {
+        let ty: Type = syn::parse_str(name).unwrap();
+        assert!(types.check_type(&ty).is_ok(), "{}", name);
+    }
+    for name in &["Plain", "Packed"] {
+        let ty: Type = syn::parse_str(name).unwrap();
+        assert!(types.check_type(&ty).is_err(), "{}", name);
+    }
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(Clone)]
+        #[repr(C, align(8))]
+        struct Aligned<T> { value: T }
+    };
+    assert!(types.add_item(&input));
+    let ty: Type = parse_quote!(Aligned<&str>);
+    assert_eq!(
+        messages(types.check_type(&ty)),
+        ["references are not FFI-safe, use a raw pointer instead"],
+    );
+}
+
+#[test]
+fn test_signature() {
+    let types = FfiTypes::new();
+    let sig: Signature = parse_quote! {
+        extern "C" fn callback(data: *mut c_void, len: usize) -> c_int
+    };
+    assert!(types.check_signature(&sig).is_ok());
+
+    let sig: Signature = parse_quote!(fn unit());
+    assert!(types.check_signature(&sig).is_ok());
+
+    let sig: Signature = parse_quote! {
+        async extern "Rust" fn method<'a, T, const N: usize>(&self, value: T, x: &'a u8) -> (u8,)
+    };
+    assert_eq!(
+        messages(types.check_signature(&sig)),
+        [
+            "the Rust ABI is not FFI-safe",
+            "async functions cannot be called through FFI",
+            "generic functions cannot be called through FFI",
+            "generic functions cannot be called through FFI",
+            "`self` is not FFI-safe",
+            "generic type parameter `T` is not FFI-safe",
+            "references are not FFI-safe, use a raw pointer instead",
+            "tuples are not FFI-safe, use a `#[repr(C)]` struct instead",
+        ],
+    );
+}
@@ -3,8 +3,12 @@
 #[macro_use]
 mod macros;
 
+use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{DeriveInput, ItemFn, TypeParamBound, WhereClause, WherePredicate};
+use syn::{
+    parse_quote, DeriveInput, Generics, ItemFn, Path, Type, TypeParamBound, WhereClause,
+    WherePredicate,
+};
 
 #[test]
 fn test_split_for_impl() {
@@ -283,3 +287,130 @@ fn test_where_clause_at_end_of_input() {
 
     assert_eq!(input.predicates.len(), 0);
 }
+
+#[test]
+fn test_where_clause_equality_predicate() {
+    let input = quote! {
+        where T::Output = u32
+    };
+
+    snapshot!(input as WhereClause, @r###"
+    WhereClause {
+        predicates: [
+            Eq(PredicateEq {
+                lhs_ty: Type::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "T",
+                                arguments: None,
+                            },
+                            PathSegment {
+                                ident: "Output",
+                                arguments: None,
+                            },
+                        ],
+                    },
+                },
+                rhs_ty: Type::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "u32",
+                                arguments: None,
+                            },
+                        ],
+                    },
+                },
+            }),
+        ],
+    }
+    "###);
+
+    syn::parse_str::<WhereClause>("where for<'a> T::Output<'a> = &'a u32").unwrap_err();
+}
+
+#[test]
+fn test_merge() {
+    let mut generics: Generics = parse_quote!(<'a, T, T1, const N: usize>);
+    generics.where_clause = Some(parse_quote!(where T: 'a));
+    let mut other: Generics = parse_quote!(<'a, 'b: 'a, T: Into<N>, N = [T; 1], U>);
+    other.where_clause = Some(parse_quote!(where U: Fn(T) -> &'a T));
+    let renames = generics.merge(&other);
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    assert_eq!(
+        quote!(#impl_generics #where_clause).to_string(),
+        quote! {
+            <'a, 'a1, 'b: 'a1, T, T1, const N: usize, T2: Into<N1>, N1, U>
+            where
+                T: 'a,
+                U: Fn(T2) -> &'a1 T2
+        }
+        .to_string(),
+    );
+
+    let renamed: Vec<String> = renames
+        .idents
+        .iter()
+        .map(|(old, new)| format!("{} -> {}", old, new))
+        .collect();
+    assert_eq!(renamed, ["T -> T2", "N -> N1"]);
+    assert_eq!(renames.lifetimes.len(), 1);
+
+    let mut ty: Type = parse_quote!(<T as Trait<'a>>::Assoc<[U; N]>);
+    renames.rename_type(&mut ty);
+    let expected: Type = parse_quote!(<T2 as Trait<'a1>>::Assoc<[U; N1]>);
+    assert_eq!(quote!(#ty).to_string(), quote!(#expected).to_string());
+
+    let mut path: Path = parse_quote!(T::Item);
+    renames.rename_path(&mut path);
+    assert_eq!(quote!(#path).to_string(), quote!(T2::Item).to_string());
+
+    let mut generics: Generics = parse_quote!(<T>);
+    let renames = generics.merge(&parse_quote!(<U>));
+    assert!(renames.is_empty());
+}
+
+#[test]
+fn test_maybe_sized() {
+    let mut generics: Generics = parse_quote!(<'a, T: ?Sized, U: ?Sized + Clone, V, W: Debug>);
+    generics.where_clause = Some(parse_quote!(where V: ?Sized + 'a, Vec<W>: Debug));
+    let params: Vec<String> = generics
+        .maybe_sized_params()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(params, ["T", "U", "V"]);
+
+    let ident = |name: &str| Ident::new(name, Span::call_site());
+    assert!(generics.may_be_unsized(&ident("T")));
+    assert!(!generics.may_be_unsized(&ident("U")));
+    assert!(generics.may_be_unsized(&ident("V")));
+    assert!(!generics.may_be_unsized(&ident("W")));
+    assert!(!generics.may_be_unsized(&ident("X")));
+
+    assert!(generics.permits_maybe_sized(&parse_quote!(W)));
+    assert!(!generics.permits_maybe_sized(&parse_quote!(Vec<W>)));
+    assert!(!generics.permits_maybe_sized(&parse_quote!(X)));
+
+    assert!(generics.add_maybe_sized(&ident("W")));
+    assert!(!generics.add_maybe_sized(&ident("V")));
+    assert!(!generics.add_maybe_sized(&ident("X")));
+    assert!(generics.remove_maybe_sized(&ident("T")));
+    assert!(generics.remove_maybe_sized(&ident("V")));
+    assert!(!generics.remove_maybe_sized(&ident("V")));
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    assert_eq!(
+        quote!(#impl_generics #where_clause).to_string(),
+        quote!(<'a, T, U: ?Sized + Clone, V, W: Debug + ?Sized> where V: 'a, Vec<W>: Debug)
+            .to_string(),
+    );
+
+    // A where-clause left without predicates goes away.
+    let mut generics: Generics = parse_quote!(<T>);
+    generics.where_clause = Some(parse_quote!(where T: ?Sized));
+    assert!(generics.remove_maybe_sized(&ident("T")));
+    assert!(generics.where_clause.is_none());
+}
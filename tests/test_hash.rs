@@ -0,0 +1,69 @@
+use proc_macro2::{Ident, Span};
+use std::hash::Hasher;
+use syn::hash::StableHasher;
+use syn::{parse_quote, Expr, Type};
+
+#[test]
+fn test_fnv_vectors() {
+    let hasher = StableHasher::new();
+    assert_eq!(hasher.finish128(), 0x6c62272e07bb014262b821756295c58d);
+
+    let mut hasher = StableHasher::new();
+    hasher.write(b"a");
+    assert_eq!(hasher.finish128(), 0xd228cb696f1a8caf78912b704e4a8964);
+    assert_eq!(hasher.finish(), 0xd228cb696f1a8caf ^ 0x78912b704e4a8964);
+}
+
+#[test]
+fn test_integers_little_endian() {
+    let mut a = StableHasher::new();
+    a.write_u32(0x0403_0201);
+    let mut b = StableHasher::new();
+    b.write(&[1, 2, 3, 4]);
+    assert_eq!(a.finish128(), b.finish128());
+
+    let mut a = StableHasher::new();
+    a.write_usize(1);
+    let mut b = StableHasher::new();
+    b.write_u64(1);
+    assert_eq!(a.finish128(), b.finish128());
+}
+
+#[test]
+fn test_ignores_spans() {
+    let a = Ident::new("value", Span::call_site());
+    let b = Ident::new("value", Span::mixed_site());
+    assert_eq!(syn::hash::hash64(&a), syn::hash::hash64(&b));
+
+    let a: Type = parse_quote!(Vec<(u8, String)>);
+    let b: Type = syn::parse_str("Vec < ( u8 , String ) >").unwrap();
+    assert_eq!(syn::hash::hash128(&a), syn::hash::hash128(&b));
+}
+
+#[test]
+fn test_distinguishes_structure() {
+    let a: Expr = parse_quote!(a + b * c);
+    let b: Expr = parse_quote!((a + b) * c);
+    assert_ne!(syn::hash::hash128(&a), syn::hash::hash128(&b));
+    assert_ne!(syn::hash::hash64(&a), syn::hash::hash64(&b));
+}
+
+#[test]
+fn test_explicit_encoding() {
+    let ident = Ident::new("abc", Span::call_site());
+    let mut hasher = StableHasher::new();
+    hasher.write_u64(3);
+    hasher.write(b"abc");
+    assert_eq!(syn::hash::hash128(&ident), hasher.finish128());
+
+    let a: Type = syn::parse_str("fn(u8, u8)").unwrap();
+    let b: Type = syn::parse_str("fn(u8, u8,)").unwrap();
+    assert_ne!(syn::hash::hash128(&a), syn::hash::hash128(&b));
+}
+
+#[test]
+fn test_pinned() {
+    // Changing this hash breaks the stability guarantee of the hash module.
+    let ty: Type = parse_quote!(Vec<u8>);
+    assert_eq!(syn::hash::hash64(&ty), 0xa471_eab4_a73f_9e4c);
+}
@@ -83,3 +83,104 @@ fn ident_new_number() {
 fn ident_new_invalid() {
     new("a#");
 }
+
+#[test]
+fn gensym_is_unique() {
+    let a = syn::gensym("tmp").unwrap();
+    let b = syn::gensym("tmp").unwrap();
+    assert_ne!(a, b);
+    assert!(a.to_string().starts_with("__tmp"));
+    assert!(b.to_string().starts_with("__tmp"));
+}
+
+#[test]
+fn gensym_invalid_prefix() {
+    let err = syn::gensym("a-b").unwrap_err();
+    assert_eq!(err.to_string(), "\"a-b\" is not a valid identifier prefix");
+}
+
+#[test]
+fn case_conversion() {
+    use syn::ext::IdentExt;
+
+    fn check(input: &str, snake: &str, camel: &str, screaming: &str) {
+        let ident = parse(input).unwrap();
+        assert_eq!(ident.to_snake_case().to_string(), snake, "{}", input);
+        assert_eq!(ident.to_camel_case().to_string(), camel, "{}", input);
+        assert_eq!(
+            ident.to_screaming_snake_case().to_string(),
+            screaming,
+            "{}",
+            input,
+        );
+    }
+
+    check("MyType", "my_type", "MyType", "MY_TYPE");
+    check("my_type", "my_type", "MyType", "MY_TYPE");
+    check("MAX_VALUE", "max_value", "MaxValue", "MAX_VALUE");
+    check("HTTPServer", "http_server", "HttpServer", "HTTP_SERVER");
+    check("Http2Server", "http2_server", "Http2Server", "HTTP2_SERVER");
+    check(
+        "http2_server",
+        "http2_server",
+        "Http2Server",
+        "HTTP2_SERVER",
+    );
+    check("Vec3", "vec3", "Vec3", "VEC3");
+    check(
+        "_private_field",
+        "_private_field",
+        "_PrivateField",
+        "_PRIVATE_FIELD",
+    );
+    check("a__b", "a_b", "AB", "A_B");
+    check("r#Loop", "r#loop", "Loop", "LOOP");
+    check("r#type", "r#type", "Type", "TYPE");
+    check("Async", "r#async", "Async", "ASYNC");
+    check("SELF", "self_", "Self_", "SELF");
+}
+
+#[test]
+fn try_new() {
+    use syn::ext::IdentExt;
+
+    let span = Span::call_site();
+    assert_eq!(Ident::try_new("x", span).unwrap(), "x");
+    assert_eq!(Ident::try_new("_", span).unwrap(), "_");
+    assert_eq!(Ident::try_new("ünïcödé", span).unwrap(), "ünïcödé");
+    assert_eq!(Ident::try_new("r#match", span).unwrap(), "r#match");
+
+    let errors = [
+        ("", "`` is not a valid identifier"),
+        ("1x", "`1x` is not a valid identifier"),
+        ("a b", "`a b` is not a valid identifier"),
+        ("'a", "`'a` is not a valid identifier"),
+        ("r#", "`r#` is not a valid identifier"),
+        ("r#self", "`self` cannot be a raw identifier"),
+    ];
+    for (string, message) in &errors {
+        let err = Ident::try_new(string, span).unwrap_err();
+        assert_eq!(err.to_string(), *message, "{}", string);
+    }
+}
+
+#[test]
+fn new_sanitized() {
+    use syn::ext::IdentExt;
+
+    let span = Span::call_site();
+    let check = |string: &str, expected: &str| {
+        let ident = Ident::new_sanitized(string, span);
+        assert_eq!(ident.to_string(), expected, "{}", string);
+    };
+    check("field_name", "field_name");
+    check("field-name", "field_name");
+    check("2d", "_2d");
+    check("-x", "_x");
+    check("a.b c", "a_b_c");
+    check("type", "r#type");
+    check("r#async", "r#async");
+    check("self", "self_");
+    check("Self", "Self_");
+    check("", "_");
+}
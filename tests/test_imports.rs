@@ -0,0 +1,77 @@
+use syn::imports::Imports;
+use syn::{parse_quote, Expr, ExprPath, File};
+
+const TRANSMUTE: &[&str] = &["std", "mem", "transmute"];
+
+#[test]
+fn test_call_as_written() {
+    let calls: Vec<Expr> = vec![
+        parse_quote!(std::mem::transmute(x)),
+        parse_quote!(::core::mem::transmute::<u32, f32>(x)),
+        parse_quote!((std::mem::transmute)(x)),
+    ];
+    for call in &calls {
+        assert!(call.is_call_to(TRANSMUTE));
+    }
+
+    let others: Vec<Expr> = vec![
+        parse_quote!(mem::transmute(x)),
+        parse_quote!(std::mem::transmute_copy(x)),
+        parse_quote!(std::mem::transmute),
+        parse_quote!(x.transmute()),
+        parse_quote!(<T as std::mem::Trait>::transmute(x)),
+    ];
+    for expr in &others {
+        assert!(!expr.is_call_to(TRANSMUTE));
+    }
+}
+
+#[test]
+fn test_call_through_imports() {
+    let file: File = parse_quote! {
+        use std::mem;
+        use core::ptr::{self as p, read};
+
+        mod inner {
+            use std::mem::transmute as cast;
+        }
+
+        fn f() {
+            use std::intrinsics::*;
+            use mem::forget as leak;
+        }
+    };
+    let imports = Imports::from_file(&file);
+
+    let expected: Vec<(Expr, &[&str])> = vec![
+        (parse_quote!(mem::transmute(x)), TRANSMUTE),
+        (parse_quote!(cast(x)), TRANSMUTE),
+        (parse_quote!(p::write(x)), &["std", "ptr", "write"]),
+        (parse_quote!(read(x)), &["std", "ptr", "read"]),
+        (parse_quote!(leak(x)), &["std", "mem", "forget"]),
+        (parse_quote!(copy(x)), &["std", "intrinsics", "copy"]),
+    ];
+    for (call, target) in &expected {
+        assert!(call.is_call_to_in(target, &imports));
+        assert!(!call.is_call_to(target));
+    }
+
+    // A path with a leading `::` is not resolved through the imports.
+    let call: Expr = parse_quote!(::mem::transmute(x));
+    assert!(!call.is_call_to_in(TRANSMUTE, &imports));
+}
+
+#[test]
+fn test_path_refers_to() {
+    let file: File = parse_quote! {
+        use std::mem::transmute;
+    };
+    let imports = Imports::from_file(&file);
+
+    let path: ExprPath = parse_quote!(transmute);
+    assert!(path.refers_to(TRANSMUTE, &imports));
+    assert!(!path.refers_to(TRANSMUTE, &Imports::new()));
+
+    let resolved = imports.resolve(&path.path);
+    assert_eq!(resolved, [vec!["transmute"], vec!["std", "mem", "transmute"]]);
+}
@@ -0,0 +1,65 @@
+use syn::{parse_quote, ImplItemKind, ItemImpl};
+
+#[test]
+fn test_lookup() {
+    let item: ItemImpl = parse_quote! {
+        impl Trait for Type {
+            const N: usize = 1;
+            type N = ();
+            fn f(&self) {}
+            mac!();
+        }
+    };
+
+    assert_eq!(
+        item.get_item(ImplItemKind::Const, "N").unwrap().kind(),
+        ImplItemKind::Const
+    );
+    assert_eq!(
+        item.get_item(ImplItemKind::Type, "N").unwrap().kind(),
+        ImplItemKind::Type
+    );
+    assert!(item.get_item(ImplItemKind::Method, "N").is_none());
+    assert!(item.get_item(ImplItemKind::Macro, "mac").is_none());
+
+    assert_eq!(item.method("f").unwrap().sig.ident, "f");
+    assert_eq!(item.associated_const("N").unwrap().ident, "N");
+    assert_eq!(item.associated_type(String::from("N")).unwrap().ident, "N");
+    assert!(item.method("g").is_none());
+}
+
+#[test]
+fn test_implements_trait() {
+    let item: ItemImpl = parse_quote!(
+        impl<T> std::convert::From<T> for Type<T> {}
+    );
+    assert!(item.implements_trait(&parse_quote!(std::convert::From)));
+    assert!(item.implements_trait(&parse_quote!(std::convert::From<u8>)));
+    assert!(!item.implements_trait(&parse_quote!(From)));
+    assert!(!item.implements_trait(&parse_quote!(::std::convert::From)));
+
+    let negative: ItemImpl = parse_quote!(impl !Send for Type {});
+    assert!(!negative.implements_trait(&parse_quote!(Send)));
+
+    let inherent: ItemImpl = parse_quote!(impl Type {});
+    assert!(!inherent.implements_trait(&parse_quote!(Type)));
+}
+
+#[test]
+fn test_self_ty() {
+    let item: ItemImpl = parse_quote!(
+        impl<T> Trait for (crate::Wrapper<T>) {}
+    );
+    let path = item.self_ty_path().unwrap();
+    assert_eq!(path.segments.len(), 2);
+    assert_eq!(item.self_ty_ident().unwrap(), "Wrapper");
+
+    let item: ItemImpl = parse_quote!(
+        impl<T> Trait for [T] {}
+    );
+    assert!(item.self_ty_path().is_none());
+    assert!(item.self_ty_ident().is_none());
+
+    let item: ItemImpl = parse_quote!(impl Trait for <T as Iterator>::Item {});
+    assert!(item.self_ty_path().is_none());
+}
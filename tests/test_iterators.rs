@@ -47,3 +47,35 @@ fn iter() {
     assert_eq!(p.iter_mut().next_back(), Some(&mut 4));
     assert_eq!(p.into_iter().next_back(), Some(4));
 }
+
+#[test]
+fn parse_separated_until() {
+    use syn::parse::{ParseStream, Parser};
+    use syn::{Ident, Token};
+
+    fn list(input: ParseStream) -> syn::Result<(Punctuated<Ident, Token![,]>, bool)> {
+        let list = Punctuated::parse_separated_until(input, |input| input.peek(Token![;]))?;
+        let semi: Option<Token![;]> = input.parse()?;
+        Ok((list, semi.is_some()))
+    }
+
+    let (idents, at_terminator) = list.parse_str("a, b, c ;").unwrap();
+    assert_eq!(idents.len(), 3);
+    assert!(!idents.trailing_punct());
+    assert!(at_terminator);
+
+    let (idents, at_terminator) = list.parse_str("a, b, ;").unwrap();
+    assert_eq!(idents.len(), 2);
+    assert!(idents.trailing_punct());
+    assert!(at_terminator);
+
+    let (idents, at_terminator) = list.parse_str(";").unwrap();
+    assert!(idents.is_empty());
+    assert!(at_terminator);
+
+    let (idents, at_terminator) = list.parse_str("a, b").unwrap();
+    assert_eq!(idents.len(), 2);
+    assert!(!at_terminator);
+
+    assert!(list.parse_str("a b ;").is_err());
+}
@@ -0,0 +1,107 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::label::Loop;
+use syn::{parse_quote, Block, Lifetime};
+
+fn describe(loops: &[Loop]) -> Vec<(Option<String>, usize)> {
+    loops
+        .iter()
+        .map(|l| (l.label.map(|label| label.to_string()), l.depth))
+        .collect()
+}
+
+#[test]
+fn test_loops() {
+    let block: Block = parse_quote!({
+        'rows: for row in loop {
+            break rows();
+        } {
+            while let Some(cell) = row.next() {
+                'inner: loop {}
+            }
+        }
+        let f = || loop {};
+        fn nested() {
+            loop {}
+        }
+        'block: {
+            while ready() {}
+        }
+    });
+
+    let loops = syn::label::loops(&block);
+    assert_eq!(
+        describe(&loops),
+        [
+            (Some("'rows".to_owned()), 0),
+            (None, 0),
+            (None, 1),
+            (Some("'inner".to_owned()), 2),
+            (None, 0),
+        ],
+    );
+}
+
+#[test]
+fn test_fresh_label() {
+    let block: Block = parse_quote!({
+        'retry: loop {
+            let s: &'retry_1 str = m!('retry_2);
+        }
+    });
+    let label = syn::label::fresh_label(&block, "retry");
+    assert_eq!(label.to_string(), "'retry_3");
+
+    let label = syn::label::fresh_label(&block, "outer");
+    assert_eq!(label.to_string(), "'outer");
+}
+
+#[test]
+fn test_retarget_breaks() {
+    let mut block: Block = parse_quote!({
+        if done {
+            break compute(|x| loop {
+                break;
+            });
+        }
+        for x in xs {
+            continue;
+        }
+        'a: {
+            continue;
+        }
+        'a: loop {
+            break 'a;
+        }
+        break 'a;
+        m!(break);
+    });
+
+    let to = Lifetime::new("'outer", Span::call_site());
+    let retargeted = syn::label::retarget_breaks(&mut block, None, &to);
+    assert_eq!(retargeted, 2);
+
+    let from = Lifetime::new("'a", Span::call_site());
+    let retargeted = syn::label::retarget_breaks(&mut block, Some(&from), &to);
+    assert_eq!(retargeted, 1);
+
+    let expected = quote!({
+        if done {
+            break 'outer compute(|x| loop {
+                break;
+            });
+        }
+        for x in xs {
+            continue;
+        }
+        'a: {
+            continue 'outer;
+        }
+        'a: loop {
+            break 'a;
+        }
+        break 'outer;
+        m!(break);
+    });
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
@@ -0,0 +1,237 @@
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::str::FromStr;
+use syn::lexer::{self, FlatToken, Source, TokenKind};
+use syn::parse::ParseStream;
+use syn::{Expr, Ident, Item, Lifetime, Token};
+
+fn flatten(tokens: TokenStream, out: &mut Vec<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                out.push(format!("{:?}", group.delimiter()));
+                flatten(group.stream(), out);
+                out.push("end".to_owned());
+            }
+            TokenTree::Punct(punct) => {
+                out.push(format!("{}{:?}", punct.as_char(), punct.spacing()))
+            }
+            other => out.push(other.to_string()),
+        }
+    }
+}
+
+fn assert_same_tokens(text: &str) {
+    let mut expected = Vec::new();
+    flatten(TokenStream::from_str(text).unwrap(), &mut expected);
+    let mut actual = Vec::new();
+    flatten(lexer::tokenize(text).unwrap(), &mut actual);
+    assert_eq!(actual, expected, "{}", text);
+}
+
+#[test]
+fn test_tokenize() {
+    assert_same_tokens("fn main() { let x = 1 + 2; }");
+    assert_same_tokens("x += 1; a..=b; a::<T>::c; |y| -> &'a str { y }");
+    assert_same_tokens("r#type r#\"raw \" string\"# br\"bytes\" b'\\n' '\\'' 'x' \"a\\\"b\"");
+    assert_same_tokens("1 1.0 1. 1..2 1.max(2) t.0.1 1e10 1E-5f64 0x1f_u8 0b1010 2.5e+3 1_000i32");
+    assert_same_tokens("/* nested /* block */ comment */ a // line\n b /**/ c");
+    assert_same_tokens("é_ident ünïcödé \"straße\"");
+    assert_same_tokens("");
+}
+
+#[test]
+fn test_doc_comments() {
+    assert_same_tokens("//! inner\n/// outer\n/** block */ struct S;");
+    assert_same_tokens("/// \"quoted\" \\ text\r\n/*! a\n * b\n */");
+
+    // Four slashes and `/***` are regular comments.
+    let tokens = lexer::tokenize("//// not doc\n/*** not doc */ x").unwrap();
+    assert_eq!(tokens.to_string(), "x");
+}
+
+#[test]
+fn test_byte_ranges() {
+    let text = "fn f<'a>(x: &'a str) {\n    x.len()\n}";
+    let source = Source::new(text).unwrap();
+
+    let mut cursor = source.begin();
+    let mut tokens = Vec::new();
+    while let Some((tree, next)) = cursor.token_tree() {
+        let range = source.byte_range(cursor).unwrap();
+        tokens.push(&text[range]);
+        if let TokenTree::Group(group) = tree {
+            if group.delimiter() == Delimiter::Parenthesis {
+                let (inside, _span, _next) = cursor.group(Delimiter::Parenthesis).unwrap();
+                let range = source.byte_range(inside).unwrap();
+                assert_eq!(&text[range], "x");
+            }
+        }
+        cursor = next;
+    }
+    assert_eq!(
+        tokens,
+        [
+            "fn",
+            "f",
+            "<",
+            "'",
+            "a",
+            ">",
+            "(x: &'a str)",
+            "{\n    x.len()\n}"
+        ],
+    );
+    assert_eq!(source.byte_range(cursor), None);
+
+    let lifetime = source
+        .parse_with(|input: ParseStream| {
+            input.parse::<Token![fn]>()?;
+            input.parse::<Ident>()?;
+            input.parse::<Token![<]>()?;
+            let start = source.byte_range(input.cursor()).unwrap().start;
+            input.parse::<Lifetime>()?;
+            let end = source.byte_range(input.cursor()).unwrap().start;
+            input.parse::<TokenStream>()?;
+            Ok(&text[start..end])
+        })
+        .unwrap();
+    assert_eq!(lifetime, "'a");
+}
+
+#[test]
+fn test_parse() {
+    let expr: Expr = lexer::parse_str("a * (b + c)").unwrap();
+    let expected: Expr = syn::parse_str("a * (b + c)").unwrap();
+    assert_eq!(expr, expected);
+
+    let item: Item = Source::new("/// Docs.\npub struct Unit;")
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(matches!(item, Item::Struct(item) if item.attrs.len() == 1));
+
+    let err = lexer::parse_str::<Expr>("a + b c").unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token at byte 6");
+}
+
+#[test]
+fn test_parse_file() {
+    let content = "\u{feff}#!/usr/bin/env rustx\nfn main() {}";
+    let file = lexer::parse_file(content).unwrap();
+    assert_eq!(file.shebang.as_deref(), Some("#!/usr/bin/env rustx"));
+    assert_eq!(file.items.len(), 1);
+
+    let file = lexer::parse_file("#![allow(dead_code)]\nfn main() {}").unwrap();
+    assert_eq!(file.shebang, None);
+    assert_eq!(file.attrs.len(), 1);
+}
+
+#[test]
+fn test_errors() {
+    let errors = [
+        ("fn f() { ]", "mismatched closing delimiter at byte 9"),
+        ("a )", "unexpected closing delimiter at byte 2"),
+        ("f(x", "unclosed delimiter at byte 1"),
+        ("x /* y", "unterminated block comment at byte 2"),
+        ("let s = \"abc", "unterminated literal at byte 8"),
+        ("r#\"abc\"", "unterminated raw string literal at byte 0"),
+        ("a € b", "unexpected character `€` at byte 2"),
+        ("r#self", "invalid raw identifier at byte 0"),
+    ];
+    for (text, message) in &errors {
+        let err = lexer::tokenize(text).unwrap_err();
+        assert_eq!(err.to_string(), *message, "{}", text);
+    }
+}
+
+#[test]
+fn test_flat_tokens() {
+    let text = "f(x) += 'a; /// doc";
+    let tokens = Source::new(text).unwrap().tokens();
+    let flat: Vec<(TokenKind, &str, &str)> = tokens
+        .iter()
+        .map(|token| {
+            (
+                token.kind,
+                token.text.as_str(),
+                &text[token.byte_range.clone()],
+            )
+        })
+        .collect();
+    assert_eq!(
+        flat,
+        [
+            (TokenKind::Ident, "f", "f"),
+            (TokenKind::Open, "(", "("),
+            (TokenKind::Ident, "x", "x"),
+            (TokenKind::Close, ")", ")"),
+            (TokenKind::Punct, "+", "+"),
+            (TokenKind::Punct, "=", "="),
+            (TokenKind::Punct, "'", "'"),
+            (TokenKind::Ident, "a", "a"),
+            (TokenKind::Punct, ";", ";"),
+            (TokenKind::Punct, "#", "/// doc"),
+            (TokenKind::Open, "[", "/// doc"),
+            (TokenKind::Ident, "doc", "/// doc"),
+            (TokenKind::Punct, "=", "/// doc"),
+            (TokenKind::Literal, "\" doc\"", "/// doc"),
+            (TokenKind::Close, "]", "/// doc"),
+        ]
+    );
+}
+
+#[test]
+fn test_from_flat_tokens() {
+    let text = "total += v[0] as u64 * 'x' as u64";
+    let tokens = Source::new(text).unwrap().tokens();
+    let source = Source::from_tokens(&tokens).unwrap();
+    let expr: Expr = source.parse().unwrap();
+    assert_eq!(expr, syn::parse_str::<Expr>(text).unwrap());
+
+    // Punctuation that is not adjacent is not joint.
+    let spaced: Vec<FlatToken> = ["a", "+", "=", "b"]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| FlatToken {
+            kind: if i % 3 == 0 {
+                TokenKind::Ident
+            } else {
+                TokenKind::Punct
+            },
+            text: text.to_string(),
+            byte_range: 2 * i..2 * i + 1,
+        })
+        .collect();
+    let source = Source::from_tokens(&spaced).unwrap();
+    assert!(source.parse::<Expr>().is_err());
+
+    let invalid = vec![FlatToken {
+        kind: TokenKind::Ident,
+        text: "1".to_owned(),
+        byte_range: 4..5,
+    }];
+    let err = Source::from_tokens(&invalid).err().unwrap();
+    assert_eq!(err.to_string(), "invalid token at byte 4");
+
+    let unbalanced = vec![FlatToken {
+        kind: TokenKind::Open,
+        text: "(".to_owned(),
+        byte_range: 0..1,
+    }];
+    let err = Source::from_tokens(&unbalanced).err().unwrap();
+    assert_eq!(err.to_string(), "unclosed delimiter at byte 0");
+}
+
+#[test]
+fn test_print_tokens() {
+    let expr: Expr = syn::parse_quote!(f(x) += 'a');
+    let (text, tokens) = lexer::print_tokens(&expr);
+    assert_eq!(text, "f ( x ) += 'a'");
+    let printed: Vec<&str> = tokens
+        .iter()
+        .map(|token| &text[token.byte_range.clone()])
+        .collect();
+    assert_eq!(printed, ["f", "(", "x", ")", "+", "=", "'a'"]);
+    assert_eq!(tokens[1].kind, TokenKind::Open);
+    assert_eq!(tokens[6].kind, TokenKind::Literal);
+}
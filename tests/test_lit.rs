@@ -5,9 +5,10 @@ mod macros;
 
 use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
 use quote::ToTokens;
+use std::borrow::Cow;
 use std::iter::FromIterator;
 use std::str::FromStr;
-use syn::{Lit, LitFloat, LitInt, LitStr};
+use syn::{Lit, LitFloat, LitInt, LitStr, LitValue, Radix};
 
 fn lit(s: &str) -> Lit {
     match TokenStream::from_str(s)
@@ -56,6 +57,76 @@ fn strings() {
     test_string("r##\"...\"##q", "...");
 }
 
+#[test]
+fn string_value_cow() {
+    fn test_cow(s: &str, value: &str, borrowed: bool) {
+        match lit(s) {
+            Lit::Str(lit) => {
+                let cow = lit.value_cow();
+                assert_eq!(cow, value);
+                assert_eq!(matches_borrowed(&cow), borrowed, "{}", s);
+            }
+            wrong => panic!("{:?}", wrong),
+        }
+    }
+
+    fn matches_borrowed(cow: &Cow<str>) -> bool {
+        match cow {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        }
+    }
+
+    test_cow("\"a\"", "a", true);
+    test_cow("\"\"", "", true);
+    test_cow("\"🐕\"", "🐕", true);
+    test_cow("\"...\"q", "...", true);
+    test_cow("\"contains\nnewlines\"", "contains\nnewlines", true);
+    test_cow("\"\\n\"", "\n", false);
+    test_cow("\"\\u{1F415}\"", "\u{1F415}", false);
+    test_cow("\"crlf\r\n\"", "crlf\n", false);
+    test_cow("r\"raw\\n\"", "raw\\n", true);
+    test_cow("r##\"...\"#\"##q", "...\"#", true);
+
+    let lit = LitStr::new("a\\b\"c", Span::call_site());
+    assert_eq!(lit.value_cow(), "a\\b\"c");
+}
+
+#[test]
+fn raw_strings() {
+    fn test_raw(s: &str, is_raw: bool, hashes: usize) {
+        match lit(s) {
+            Lit::Str(lit) => {
+                assert_eq!(lit.is_raw(), is_raw, "{}", s);
+                assert_eq!(lit.raw_hashes(), hashes, "{}", s);
+                assert_eq!(lit.into_token_stream().to_string(), s);
+            }
+            Lit::ByteStr(lit) => {
+                assert_eq!(lit.is_raw(), is_raw, "{}", s);
+                assert_eq!(lit.raw_hashes(), hashes, "{}", s);
+                assert_eq!(lit.into_token_stream().to_string(), s);
+            }
+            wrong => panic!("{:?}", wrong),
+        }
+    }
+
+    test_raw("\"a\"", false, 0);
+    test_raw("\"#\"", false, 0);
+    test_raw("r\"a\"", true, 0);
+    test_raw("r#\"a\"#", true, 1);
+    test_raw("r##\"a \"# b\"##", true, 2);
+    test_raw("r##\"...\"##q", true, 2);
+    test_raw("b\"a\"", false, 0);
+    test_raw("br\"a\"", true, 0);
+    test_raw("br###\"a\"###", true, 3);
+
+    let expr: syn::Expr = syn::parse_str("f(r##\"say \"#hi\"\"##, br#\"\\n\"#)").unwrap();
+    assert_eq!(
+        expr.into_token_stream().to_string(),
+        "f (r##\"say \"#hi\"\"## , br#\"\\n\"#)",
+    );
+}
+
 #[test]
 fn byte_strings() {
     fn test_byte_string(s: &str, value: &[u8]) {
@@ -176,6 +247,66 @@ fn ints() {
     test_int("0e1\u{5c5}", 0, "e1\u{5c5}");
 }
 
+#[test]
+fn int_digits() {
+    fn test_digits(s: &str, radix: Radix, raw: &str, normalized: &str) {
+        let lit = LitInt::new(s, Span::call_site());
+        assert_eq!(lit.radix(), radix, "{}", s);
+        assert_eq!(lit.raw_digits(), raw, "{}", s);
+        assert_eq!(lit.normalized_digits(), normalized, "{}", s);
+    }
+
+    test_digits("1_000", Radix::Decimal, "1_000", "1000");
+    test_digits("0x_FF_u8", Radix::Hexadecimal, "_FF_", "FF");
+    test_digits("0o777i64", Radix::Octal, "777", "777");
+    test_digits("0b1010_1010", Radix::Binary, "1010_1010", "10101010");
+    test_digits("-0x1F", Radix::Hexadecimal, "1F", "1F");
+    assert_eq!(Radix::Hexadecimal.base(), 16);
+    assert_eq!(Radix::Binary.prefix(), "0b");
+}
+
+#[test]
+fn float_digits() {
+    let lit = LitFloat::new("1_000.5e3_f64", Span::call_site());
+    assert_eq!(lit.raw_digits(), "1_000.5e3_");
+    assert_eq!(lit.normalized_digits(), "1000.5e3");
+
+    let lit = LitFloat::new("-2.5", Span::call_site());
+    assert_eq!(lit.raw_digits(), "2.5");
+}
+
+#[test]
+fn values() {
+    fn value(s: &str) -> LitValue {
+        lit(s).value().unwrap()
+    }
+
+    assert_eq!(value("\"a\\nb\""), LitValue::Str("a\nb".to_owned()));
+    assert_eq!(value("b\"ab\""), LitValue::ByteStr(b"ab".to_vec()));
+    assert_eq!(value("b'a'"), LitValue::Byte(b'a'));
+    assert_eq!(value("'\\u{1F600}'"), LitValue::Char('\u{1F600}'));
+    assert_eq!(
+        value("0xFF_u16"),
+        LitValue::Int {
+            value: 255,
+            suffix: "u16".to_owned(),
+        },
+    );
+    assert_eq!(
+        value("1.5e1"),
+        LitValue::Float {
+            value: 15.0,
+            suffix: String::new(),
+        },
+    );
+
+    let lit: Lit = syn::parse_str("true").unwrap();
+    assert_eq!(lit.value().unwrap(), LitValue::Bool(true));
+
+    let lit: Lit = syn::parse_str("-1").unwrap();
+    assert!(lit.value().is_err());
+}
+
 #[test]
 fn floats() {
     fn test_float(s: &str, value: f64, suffix: &str) {
@@ -0,0 +1,87 @@
+use quote::quote;
+use syn::module_tree::ModuleTree;
+
+fn file(content: &str) -> syn::File {
+    syn::parse_file(content).unwrap()
+}
+
+#[test]
+fn test_into_file() {
+    let mut tree = ModuleTree::new(file("#[path = \"a_impl.rs\"] mod a; mod b { mod c; }"));
+    assert!(tree
+        .insert("a", file("#![allow(dead_code)] fn f() {}"))
+        .is_none());
+    assert!(tree.insert("b :: c", file("struct C;")).is_none());
+    assert!(tree.insert("a", file("#![allow(dead_code)] fn g() {}")).is_some());
+    assert!(tree.get("b::c").is_some());
+    assert!(tree.get("b").is_none());
+
+    let file = tree.into_file().unwrap();
+    let expected = quote! {
+        mod a {
+            #![allow(dead_code)]
+            fn g() {}
+        }
+        mod b {
+            mod c {
+                struct C;
+            }
+        }
+    };
+    assert_eq!(quote!(#file).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_into_file_errors() {
+    let mut tree = ModuleTree::new(file("mod a; mod b {}"));
+    tree.insert("b", file(""));
+    tree.insert("c::d", file(""));
+    let messages: Vec<String> = tree
+        .into_file()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "no file inserted for module `a`",
+            "file inserted for module `b`, which is not declared as `mod b;`",
+            "file inserted for module `c::d`, which is not declared as `mod d;`",
+        ],
+    );
+}
+
+#[test]
+fn test_duplicates() {
+    let mut tree = ModuleTree::new(file(
+        "
+        mod m;
+        struct S;
+        fn S() {}
+        struct Named {}
+        fn Named() {}
+        const _: () = ();
+        const _: () = ();
+        ",
+    ));
+    tree.insert(
+        "m",
+        file("mod inner { enum E {} trait E {} } type T = u8; static r#T: u8 = 0; struct r#T;"),
+    );
+
+    let duplicates: Vec<String> = tree
+        .duplicates()
+        .iter()
+        .map(|duplicate| duplicate.error().to_string())
+        .collect();
+    assert_eq!(
+        duplicates,
+        [
+            "the name `S` is defined multiple times",
+            "the name `r#T` is defined multiple times in module `m`",
+            "the name `r#T` is defined multiple times in module `m`",
+            "the name `E` is defined multiple times in module `m::inner`",
+        ],
+    );
+}
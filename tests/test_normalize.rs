@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+use syn::normalize::type_key;
+use syn::{parse_quote, Type};
+
+fn key(ty: Type) -> String {
+    type_key(&ty).to_string()
+}
+
+#[test]
+fn test_type_key() {
+    assert_eq!(key(parse_quote!(Vec<(u8)>)), "Vec < u8 >");
+    assert_eq!(key(parse_quote!(((&'a mut T)))), "& 'a mut T");
+    assert_eq!(key(parse_quote!(std::vec::Vec<T>)), "std :: vec :: Vec < T >");
+
+    // Tuples and `Fn` arguments keep their parentheses.
+    assert_eq!(key(parse_quote!((T,))), "(T ,)");
+    assert_eq!(key(parse_quote!(())), "()");
+    assert_eq!(
+        key(parse_quote!(Box<dyn Fn(u8) -> (u8)>)),
+        "Box < dyn Fn (u8) -> u8 >"
+    );
+}
+
+#[test]
+fn test_equality() {
+    let a: Type = parse_quote!(Option<(Vec<u8>)>);
+    let b: Type = parse_quote!(Option<Vec<u8>>);
+    let c: Type = parse_quote!(Option<Vec<u16>>);
+    assert_eq!(type_key(&a), type_key(&b));
+    assert_ne!(type_key(&a), type_key(&c));
+
+    let keys: HashSet<_> = [a, b, c].iter().map(type_key).collect();
+    assert_eq!(keys.len(), 2);
+}
+
+#[test]
+fn test_invisible_group() {
+    let inner: Type = parse_quote!(u8);
+    let grouped = Type::Group(syn::TypeGroup {
+        group_token: Default::default(),
+        elem: Box::new(inner.clone()),
+    });
+    let slice: Type = parse_quote!([#grouped]);
+    assert_eq!(type_key(&grouped), type_key(&inner));
+    assert_eq!(key(slice), "[u8]");
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::non_ascii_literal)]
 
 use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::quote;
 use std::iter::FromIterator;
 use syn::parse::{discouraged::Speculative, Parse, ParseStream, Parser, Result};
 use syn::{parenthesized, Token};
@@ -90,3 +91,128 @@ fn trailing_empty_none_group() {
 
     parse.parse2(tokens).unwrap();
 }
+
+#[test]
+fn cursor_positions() {
+    use std::str::FromStr;
+    use syn::buffer::TokenBuffer;
+
+    let tokens = TokenStream::from_str("a (b c) d").unwrap();
+    let buffer = TokenBuffer::new2(tokens);
+
+    let a = buffer.begin();
+    let (_, group) = a.ident().unwrap();
+    let (inside, _, d) = group.group(Delimiter::Parenthesis).unwrap();
+    let (_, c) = inside.ident().unwrap();
+    let (_, group_end) = c.ident().unwrap();
+    let (_, end) = d.ident().unwrap();
+
+    let positions = [a, group, inside, c, group_end, d, end]
+        .iter()
+        .map(|cursor| cursor.position())
+        .collect::<Vec<_>>();
+    assert_eq!(positions, [0, 1, 2, 3, 4, 5, 6]);
+    assert!(group_end.eof() && end.eof());
+
+    assert!(a < group && inside < c && c < d && d < end);
+    assert_eq!(a.distance(d), Some(5));
+    assert_eq!(inside.distance(c), Some(1));
+    assert_eq!(d.distance(a), None);
+    assert_eq!(a.distance(a), Some(0));
+
+    let other = TokenBuffer::new2(TokenStream::from_str("a").unwrap());
+    assert_eq!(a.distance(other.begin()), None);
+}
+
+#[test]
+fn rewind_forgets_unexpected_tokens() {
+    struct Rewinding;
+    impl Parse for Rewinding {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let checkpoint = input.checkpoint();
+
+            // Leaves `b` unparsed inside of the parentheses.
+            let content;
+            parenthesized!(content in input);
+            content.parse::<syn::Ident>()?;
+            drop(content);
+
+            input.rewind(&checkpoint);
+            let content;
+            parenthesized!(content in input);
+            content.parse::<syn::Ident>()?;
+            content.parse::<syn::Ident>()?;
+            Ok(Self)
+        }
+    }
+
+    syn::parse_str::<Rewinding>("(a b)").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Checkpoint was not taken from the rewinding parse stream")]
+fn rewind_into_brackets() {
+    struct BreakRules;
+    impl Parse for BreakRules {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let a;
+            parenthesized!(a in input);
+            input.rewind(&a.checkpoint());
+            Ok(Self)
+        }
+    }
+
+    syn::parse_str::<BreakRules>("()").unwrap();
+}
+
+#[test]
+fn unexpected_token_message() {
+    let err = syn::parse_str::<syn::Expr>("f(x) => y").unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token `=>`");
+
+    struct Pair;
+    impl Parse for Pair {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let content;
+            parenthesized!(content in input);
+            content.parse::<syn::Ident>()?;
+            content.parse::<Token![,]>()?;
+            content.parse::<syn::Ident>()?;
+            Ok(Self)
+        }
+    }
+
+    let err = syn::parse_str::<Pair>("(a, b c)").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "unexpected token `c`, perhaps a `,` is missing before it",
+    );
+    let err = syn::parse_str::<Pair>("(a, b, c)").err().unwrap();
+    assert_eq!(err.to_string(), "unexpected token `,`");
+}
+
+#[test]
+fn parse_many_reuses_buffer() {
+    let parser = |input: ParseStream| {
+        let content;
+        parenthesized!(content in input);
+        let args = content.parse_terminated::<syn::Ident, Token![,]>(syn::Ident::parse)?;
+        Ok(args.len())
+    };
+
+    let streams = vec![
+        quote!((a, b, c)),
+        quote!(()),
+        quote!((a, b, c, d, e, f, g, h)),
+        quote!((a)),
+    ];
+    assert_eq!(parser.parse_many(streams).unwrap(), [3, 0, 8, 1]);
+
+    let streams = vec![quote!((a, b)), quote!((a b)), quote!((a) b)];
+    let err = parser.parse_many(streams).unwrap_err();
+    assert_eq!(err.to_string(), "expected `,`");
+
+    let streams = vec![quote!((a, b)), quote!((a) b)];
+    let err = parser.parse_many(streams).unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token `b`");
+}
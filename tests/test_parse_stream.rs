@@ -10,3 +10,148 @@ fn test_peek() {
         let _ = input.peek(Token![::]);
     };
 }
+
+#[test]
+fn test_end_spans() {
+    use syn::parenthesized;
+    use syn::parse::Parser;
+
+    let parser = |input: ParseStream| {
+        let _ = input.remaining_span();
+        let _ = input.end_span();
+        let content;
+        parenthesized!(content in input);
+        let _ = content.end_span();
+        content.parse::<Ident>()?;
+        let _ = content.remaining_span();
+        let _ = content.end_span();
+        let _ = input.remaining_span();
+        input.parse::<Token![;]>()?;
+        let _ = input.remaining_span();
+        Err::<(), _>(syn::Error::new(input.end_span(), "expected more input"))
+    };
+    let err = parser.parse_str("(a) ;").unwrap_err();
+    assert_eq!(err.to_string(), "expected more input");
+
+    let empty = |input: ParseStream| Ok((input.remaining_span(), input.end_span()));
+    empty.parse_str("").unwrap();
+}
+
+#[test]
+fn test_parse_recoverable() {
+    use syn::parse::Parser;
+    use syn::Type;
+
+    let parser = |input: ParseStream| {
+        let mut types = Vec::new();
+        let mut errors = None;
+        while !input.is_empty() {
+            if let Some(ty) = input.parse_recoverable::<Type, _>(Token![,], &mut errors) {
+                types.push(ty);
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok((types.len(), errors))
+    };
+
+    let (parsed, errors) = parser.parse_str("u8, 1 (a, b), Vec<u8>, , &").unwrap();
+    assert_eq!(parsed, 2);
+    let messages: Vec<String> = errors
+        .unwrap()
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect();
+    let expected = "expected one of: `for`, parentheses, `fn`, `unsafe`, `extern`, identifier, `::`, `<`, square brackets, `*`, `&`, `!`, `impl`, `_`, lifetime";
+    assert_eq!(
+        messages,
+        [
+            expected.to_owned(),
+            expected.to_owned(),
+            format!("unexpected end of input, {}", expected),
+        ],
+    );
+
+    let (parsed, errors) = parser.parse_str("u8, Vec<u8>").unwrap();
+    assert_eq!(parsed, 2);
+    assert!(errors.is_none());
+}
+
+#[test]
+fn test_lookahead() {
+    use syn::parse::Parser;
+
+    mod kw {
+        syn::custom_keyword!(rule);
+    }
+
+    let parser = |input: ParseStream| {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![fn]) || lookahead.peek(kw::rule) || lookahead.peek(Ident) {
+            input.parse::<proc_macro2::TokenStream>()?;
+            Ok(())
+        } else {
+            Err(lookahead.error())
+        }
+    };
+    parser.parse_str("rule x").unwrap();
+
+    let err = parser.parse_str("1").unwrap_err();
+    assert_eq!(err.to_string(), "expected one of: `fn`, `rule`, identifier");
+
+    let err = parser.parse_str("").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input, expected one of: `fn`, `rule`, identifier",
+    );
+}
+
+#[test]
+fn test_combinator() {
+    use syn::parse::combinator::Combinator;
+    use syn::parse::{Parse, Parser, Result};
+    use syn::{Attribute, LitStr};
+
+    fn ident_name(input: ParseStream) -> Result<String> {
+        input.parse::<Ident>().map(|ident| ident.to_string())
+    }
+
+    fn string_name(input: ParseStream) -> Result<String> {
+        input.parse::<LitStr>().map(|lit| lit.value())
+    }
+
+    struct Item {
+        attrs: Vec<Attribute>,
+        name: String,
+    }
+
+    impl Parse for Item {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let name = ident_name.or_else(string_name).named("a name");
+            Ok(Item {
+                attrs: input.call(Attribute::parse_outer)?,
+                name: name.parse_stream(input)?,
+            })
+        }
+    }
+
+    let item: Item = syn::parse_str("#[a] #[b] \"x y\"").unwrap();
+    assert_eq!(item.attrs.len(), 2);
+    assert_eq!(item.name, "x y");
+
+    let err = syn::parse_str::<Item>("#[a] 1").err().unwrap();
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "expected a name",
+            "expected identifier",
+            "expected string literal"
+        ],
+    );
+
+    let parser = &Attribute::parse_outer.and_then(|attrs| Ok(attrs.len()));
+    assert_eq!(parser.parse_str("#[a]").unwrap(), 1);
+    assert_eq!(parser.parse_str("#[a] #[b]").unwrap(), 2);
+}
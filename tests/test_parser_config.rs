@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::time::{Duration, Instant};
+use syn::parse::{CancellationToken, Parse, ParseStream, Parser, ParserConfig};
+use syn::{Expr, Item, Type};
+
+#[test]
+fn test_max_tokens() {
+    let config = ParserConfig::new().max_tokens(5);
+    assert!(config.apply(Expr::parse).parse_str("f(a, b)").is_ok());
+
+    let err = config
+        .apply(Expr::parse)
+        .parse_str("f(a, b, c)")
+        .unwrap_err();
+    assert_eq!(err.to_string(), "token limit exceeded");
+}
+
+#[test]
+fn test_max_nesting() {
+    let config = ParserConfig::new().max_nesting(2);
+    assert!(config
+        .apply(Item::parse)
+        .parse_str("fn f() { g() }")
+        .is_ok());
+
+    let err = config
+        .apply(Item::parse)
+        .parse_str("fn f() { g(h()) }")
+        .unwrap_err();
+    assert_eq!(err.to_string(), "nesting limit exceeded");
+
+    let config = ParserConfig::new().max_nesting(0);
+    assert!(config.apply(Type::parse).parse_str("&'a T").is_ok());
+    assert!(config.apply(Type::parse).parse_str("[T; 1]").is_err());
+}
+
+#[test]
+fn test_max_recursion_depth() {
+    let config = ParserConfig::new().max_recursion_depth(50);
+    assert!(config.apply(Expr::parse).parse_str("!!!!!!!!x").is_ok());
+
+    let deep = "!".repeat(1000) + "x";
+    let err = config.apply(Expr::parse).parse_str(&deep).unwrap_err();
+    assert_eq!(err.to_string(), "recursion limit exceeded");
+
+    let deep = "&".repeat(1000) + "T";
+    let err = config.apply(Type::parse).parse_str(&deep).unwrap_err();
+    assert_eq!(err.to_string(), "recursion limit exceeded");
+
+    // The limit does not outlive the parser it was applied to.
+    assert!(Expr::parse.parse_str(&("!".repeat(100) + "x")).is_ok());
+}
+
+#[test]
+fn test_custom_parser() {
+    let parser = |input: ParseStream| {
+        let mut count = 0;
+        while !input.is_empty() {
+            input.parse::<Expr>()?;
+            count += 1;
+        }
+        Ok(count)
+    };
+    let tokens: TokenStream = quote!(1 2 3 4);
+    let config = ParserConfig::new().max_tokens(3);
+    assert!(config.apply(parser).parse2(tokens.clone()).is_err());
+    let config = ParserConfig::new().max_tokens(4);
+    assert_eq!(config.apply(parser).parse2(tokens).unwrap(), 4);
+}
+
+#[test]
+fn test_cancellation() {
+    let token = CancellationToken::new();
+    let config = ParserConfig::new().cancellation(token.clone());
+    assert!(config.parse_file("fn main() {}").is_ok());
+
+    // Cancel between two items of a nested parse.
+    let parser = |input: ParseStream| {
+        let first: Item = input.parse()?;
+        token.cancel();
+        let second: Item = input.parse()?;
+        Ok((first, second))
+    };
+    let err = config
+        .apply(parser)
+        .parse_str("fn a() {} fn b() {}")
+        .unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(err.to_string(), "parsing cancelled");
+
+    let err = config.parse_file("fn main() {}").unwrap_err();
+    assert!(err.is_cancelled());
+
+    // Other errors are not cancellations.
+    let err = ParserConfig::new().parse_file("fn").unwrap_err();
+    assert!(!err.is_cancelled());
+
+    // Parses outside of the configuration are unaffected.
+    assert!(syn::parse_file("fn main() {}").is_ok());
+}
+
+#[test]
+fn test_deadline() {
+    let config = ParserConfig::new().deadline(Instant::now() + Duration::from_secs(3600));
+    assert!(config.parse_file("fn main() {}").is_ok());
+
+    let config = ParserConfig::new().deadline(Instant::now());
+    let err = config.apply(Expr::parse).parse_str("1 + 1").unwrap_err();
+    assert!(err.is_cancelled());
+}
@@ -0,0 +1,85 @@
+use quote::quote;
+use syn::pass::{Pass, Passes};
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, Expr, Ident};
+
+// Appends its name to every identifier when leaving it, and logs its hooks.
+struct Suffix {
+    name: &'static str,
+    log: Vec<String>,
+}
+
+impl Pass for Suffix {
+    fn enter_expr(&mut self, node: &mut Expr) {
+        self.log.push(format!("enter {}", quote!(#node)));
+    }
+
+    fn exit_expr(&mut self, node: &mut Expr) {
+        self.log.push(format!("exit {}", quote!(#node)));
+    }
+
+    fn exit_ident(&mut self, node: &mut Ident) {
+        *node = Ident::new(&format!("{}_{}", node, self.name), node.span());
+    }
+}
+
+// Rewrites `e?` to `unwrap(e)` as it is entered.
+struct Unwrap;
+
+impl Pass for Unwrap {
+    fn enter_expr(&mut self, node: &mut Expr) {
+        if let Expr::Try(e) = node {
+            let inner = &e.expr;
+            *node = parse_quote!(unwrap(#inner));
+        }
+    }
+}
+
+// Counts the calls of functions.
+struct CountCalls(usize);
+
+impl Pass for CountCalls {
+    fn exit_expr(&mut self, node: &mut Expr) {
+        if let Expr::Call(_) = node {
+            self.0 += 1;
+        }
+    }
+}
+
+#[test]
+fn test_order() {
+    let mut a = Suffix {
+        name: "a",
+        log: Vec::new(),
+    };
+    let mut b = Suffix {
+        name: "b",
+        log: Vec::new(),
+    };
+    let mut expr: Expr = parse_quote!(x.y);
+    Passes::new()
+        .with(&mut a)
+        .with(&mut b)
+        .visit_expr_mut(&mut expr);
+
+    assert_eq!(quote!(#expr).to_string(), "x_a_b . y_a_b");
+    // Unlike when run on its own, `a` exits the expressions with the changes
+    // that `b` made to their identifiers.
+    let log = ["enter x . y", "enter x", "exit x_a_b", "exit x_a_b . y_a_b"];
+    assert_eq!(a.log, log);
+    assert_eq!(b.log, log);
+}
+
+#[test]
+fn test_enter_rewrite() {
+    let mut expr: Expr = parse_quote!(f(x?)?);
+    let mut calls = CountCalls(0);
+    Passes::new()
+        .with(&mut Unwrap)
+        .with(&mut calls)
+        .visit_expr_mut(&mut expr);
+
+    // The walk descends into the expressions as rewritten on entry.
+    assert_eq!(quote!(#expr).to_string(), "unwrap (f (unwrap (x)))");
+    assert_eq!(calls.0, 3);
+}
@@ -4,7 +4,7 @@ mod macros;
 use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use quote::quote;
 use std::iter::FromIterator;
-use syn::{Item, Pat, Stmt};
+use syn::{Arm, Item, Pat, PatStep, Stmt};
 
 #[test]
 fn test_pat_ident() {
@@ -65,3 +65,53 @@ fn test_group() {
     }
     "###);
 }
+
+#[test]
+fn test_bindings() {
+    let arm: Arm = syn::parse_quote! {
+        Message { id: ref mut id, body: all @ Body(&(first, NONE, .., last)), Flags } | other => {}
+    };
+    let bindings: Vec<String> = arm
+        .pat
+        .bindings()
+        .iter()
+        .map(|binding| {
+            let path: Vec<String> = binding
+                .path
+                .iter()
+                .map(|step| match step {
+                    PatStep::Field(member) => quote!(#member).to_string(),
+                    PatStep::Index(i) => i.to_string(),
+                    PatStep::IndexFromEnd(i) => format!("-{}", i),
+                    PatStep::Rest => "..".to_owned(),
+                    PatStep::Deref => "*".to_owned(),
+                })
+                .collect();
+            format!(
+                "{}{}{} [{}]",
+                if binding.by_ref { "ref " } else { "" },
+                if binding.mutable { "mut " } else { "" },
+                binding.ident,
+                path.join(" "),
+            )
+        })
+        .collect();
+    assert_eq!(
+        bindings,
+        [
+            "ref mut id [id]",
+            "all [body]",
+            "first [body 0 * 0]",
+            "last [body 0 * -1]",
+            "Flags [Flags]",
+        ],
+    );
+
+    let pat: Pat = syn::parse_quote!([head, rest @ .., _]);
+    let paths: Vec<usize> = pat.bindings().iter().map(|b| b.path.len()).collect();
+    assert_eq!(paths, [1, 1]);
+    match pat.bindings()[1].path[0] {
+        PatStep::Rest => {}
+        _ => panic!("expected the rest of the slice"),
+    }
+}
@@ -124,3 +124,62 @@ fn parse_parenthesized_path_arguments_with_disambiguator() {
     }
     "###);
 }
+
+#[test]
+fn test_push_args() {
+    let mut ty: TypePath = parse_quote!(Map<'a, K, Item = V>);
+    let segment = ty.path.segments.last_mut().unwrap();
+    segment.args_mut().push_type(parse_quote!(S));
+    segment.args_mut().push_lifetime(parse_quote!('b));
+    let expected = quote!(Map<'a, 'b, K, S, Item = V>);
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+
+    let mut ty: TypePath = parse_quote!(Vec);
+    assert!(ty.path.segments[0].args().is_none());
+    ty.path.segments[0].args_mut().push_type(parse_quote!(u8));
+    let expected = quote!(Vec<u8>);
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_bindings() {
+    let mut ty: TypePath = parse_quote!(Iterator<Item = u8>);
+    let args = ty.path.segments[0].args_mut();
+    assert_eq!(args.binding("Item"), Some(&parse_quote!(u8)));
+    assert_eq!(args.binding("Output"), None);
+
+    args.set_binding(parse_quote!(Item), parse_quote!(u16));
+    args.set_binding(parse_quote!(Output), parse_quote!(bool));
+    let expected = quote!(Iterator<Item = u16, Output = bool>);
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_convert_args() {
+    let mut ty: TypePath = parse_quote!(Fn(A) -> B);
+    ty.path.segments[0].args_mut();
+    let expected = quote!(Fn<(A,), Output = B>);
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+
+    assert!(ty.path.segments[0].arguments.parenthesized_mut().is_some());
+    let expected = quote!(Fn(A) -> B);
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+
+    let mut ty: TypePath = parse_quote!(FnMut<(A, B)>);
+    assert!(ty.path.segments[0].arguments.parenthesized_mut().is_some());
+    let expected = quote!(FnMut(A, B));
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+
+    let mut ty: TypePath = parse_quote!(FnOnce);
+    assert!(ty.path.segments[0].arguments.parenthesized_mut().is_some());
+    let expected = quote!(FnOnce());
+    assert_eq!(ty.to_token_stream().to_string(), expected.to_string());
+
+    // Only a tuple type and an `Output` binding have a parenthesized form.
+    for input in &["Vec<T>", "Fn<(A,), Item = B>", "Fn<(A,), 'a>"] {
+        let mut ty: TypePath = syn::parse_str(input).unwrap();
+        let before = ty.to_token_stream().to_string();
+        assert!(ty.path.segments[0].arguments.parenthesized_mut().is_none());
+        assert_eq!(ty.to_token_stream().to_string(), before);
+    }
+}
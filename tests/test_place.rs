@@ -0,0 +1,53 @@
+use quote::quote;
+use syn::place::{Place, PlaceContext};
+use syn::{parse_quote, Block, Expr};
+
+fn describe(places: &[Place]) -> Vec<(String, PlaceContext, bool)> {
+    places
+        .iter()
+        .map(|place| {
+            let expr = place.expr;
+            (quote!(#expr).to_string(), place.context, place.mutable)
+        })
+        .collect()
+}
+
+#[test]
+fn test_places() {
+    let expr: Expr = parse_quote!(a.b[i] = v.len + f(&mut x, &y));
+    let places = syn::place::places(&expr);
+    assert_eq!(
+        describe(&places),
+        [
+            ("a . b [i]".to_owned(), PlaceContext::Assign, true),
+            ("a . b".to_owned(), PlaceContext::IndexBase, true),
+            ("a".to_owned(), PlaceContext::FieldBase, true),
+            ("v".to_owned(), PlaceContext::FieldBase, false),
+            ("x".to_owned(), PlaceContext::BorrowMut, true),
+            ("y".to_owned(), PlaceContext::Borrow, false),
+        ],
+    );
+}
+
+#[test]
+fn test_places_in_block() {
+    let block: Block = parse_quote!({
+        total += items[0].weight;
+        let r = &(*p).field;
+        fn nested(z: &mut u8) {
+            *z = 0;
+        }
+        vec![&mut w];
+    });
+    let places = syn::place::places_in_block(&block);
+    assert_eq!(
+        describe(&places),
+        [
+            ("total".to_owned(), PlaceContext::CompoundAssign, true),
+            ("items [0]".to_owned(), PlaceContext::FieldBase, false),
+            ("items".to_owned(), PlaceContext::IndexBase, false),
+            ("(* p) . field".to_owned(), PlaceContext::Borrow, false),
+            ("(* p)".to_owned(), PlaceContext::FieldBase, false),
+        ],
+    );
+}
@@ -0,0 +1,133 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{parse_quote, Block};
+
+fn idents(names: &[&str]) -> Vec<Ident> {
+    names
+        .iter()
+        .map(|name| Ident::new(name, Span::call_site()))
+        .collect()
+}
+
+#[test]
+fn test_rename_locals() {
+    let mut block: Block = parse_quote!({
+        let x = x + 1;
+        let x_1 = x;
+        let Point { x, y } = point(x, x_1);
+        if let Some(x) = x.checked_add(y) {
+            println!("{}", x);
+        } else {
+            drop(x);
+        }
+        match input {
+            Ok(x) | Err(x) => Wrapper { x },
+            other => Wrapper { x: other.x },
+        }
+        items.iter().map(|x| x * 2);
+        for x in x..y {
+            f(x);
+        }
+        fn nested(x: u8) -> u8 {
+            x
+        }
+        x
+    });
+
+    let renamings = syn::scope::rename_locals(&mut block, &idents(&["x"]));
+    let renamed: Vec<String> = renamings
+        .iter()
+        .map(|renaming| {
+            assert_eq!(renaming.original, "x");
+            renaming.renamed.to_string()
+        })
+        .collect();
+    assert_eq!(renamed, ["x_2", "x_3", "x_4", "x_5", "x_6", "x_7"]);
+
+    let expected = quote!({
+        let x_2 = x + 1;
+        let x_1 = x_2;
+        let Point { x: x_3, y } = point(x_2, x_1);
+        if let Some(x_4) = x_3.checked_add(y) {
+            println!("{}", x_4);
+        } else {
+            drop(x_3);
+        }
+        match input {
+            Ok(x_5) | Err(x_5) => Wrapper { x: x_5 },
+            other => Wrapper { x: other.x },
+        }
+        items.iter().map(|x_6| x_6 * 2);
+        for x_7 in x_3..y {
+            f(x_7);
+        }
+        fn nested(x: u8) -> u8 {
+            x
+        }
+        x_3
+    });
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_rename_locals_untouched() {
+    let original: Block = parse_quote!({
+        let value = MAX;
+        if let None = lookup() {
+            return;
+        }
+        value.start + start::offset() + start
+    });
+
+    let mut block = original.clone();
+    let renamings = syn::scope::rename_locals(&mut block, &idents(&["start", "MAX", "None"]));
+    assert!(renamings.is_empty());
+    assert_eq!(block, original);
+}
+
+fn paths(free: &syn::scope::FreeVariables) -> Vec<String> {
+    free.paths
+        .iter()
+        .map(|path| quote!(#path).to_string().replace(' ', ""))
+        .collect()
+}
+
+#[test]
+fn test_free_variables() {
+    let block: Block = parse_quote!({
+        let total = compute(input, self.limit);
+        let first = items.first().copied().unwrap_or_default();
+        match total.checked_add(first) {
+            Some(sum) if sum < MAX => Wrapper { sum, offset },
+            None => Wrapper::<u8>::default(),
+        }
+        let doubled: Vec<u64> = items.iter().map(|x| x * factor).collect();
+        for i in 0..count {
+            helper(i, doubled[i], ::std::mem::size_of::<u8>());
+        }
+        println!("{} {}", total, extra.len());
+        fn helper(a: usize, b: u64, c: usize) {}
+        mod util {}
+        util::run(total)
+    });
+
+    let free = syn::scope::free_variables_in_block(&block);
+    let locals: Vec<String> = free.locals.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        locals,
+        ["compute", "input", "self", "items", "offset", "factor", "count", "extra"],
+    );
+    assert!(free.contains_local("factor"));
+    assert!(!free.contains_local("total"));
+    assert_eq!(
+        paths(&free),
+        [
+            "Some",
+            "MAX",
+            "Wrapper",
+            "None",
+            "Wrapper::default",
+            "::std::mem::size_of"
+        ],
+    );
+}
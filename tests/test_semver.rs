@@ -0,0 +1,86 @@
+use syn::semver::{self, Change};
+use syn::{parse_quote, Generics, Signature};
+
+fn describe(changes: &[Change]) -> Vec<String> {
+    changes
+        .iter()
+        .map(|change| {
+            let kind = if change.is_breaking() {
+                "breaking"
+            } else {
+                "compatible"
+            };
+            format!("{}: {}", kind, change.message)
+        })
+        .collect()
+}
+
+#[test]
+fn test_compare_signatures() {
+    let old: Signature = parse_quote! {
+        unsafe fn get<T>(&self, index: usize, fallback: Option<(T)>) -> T
+    };
+
+    let same: Signature = parse_quote! {
+        unsafe fn get<T>(&self, i: usize, default: Option<T>) -> (T)
+    };
+    assert!(semver::compare_signatures(&old, &same).is_empty());
+
+    let new: Signature = parse_quote! {
+        async fn fetch<T>(&mut self, index: u64) -> T
+    };
+    assert_eq!(
+        describe(&semver::compare_signatures(&old, &new)),
+        [
+            "breaking: function renamed from `get` to `fetch`",
+            "breaking: function is now `async`",
+            "compatible: function is no longer `unsafe`",
+            "breaking: receiver changed from `& Self` to `& mut Self`",
+            "breaking: number of arguments changed from 2 to 1",
+            "breaking: type of argument 1 changed from `usize` to `u64`",
+        ],
+    );
+
+    let method: Signature = parse_quote!(fn f(self));
+    let function: Signature = parse_quote!(extern "C" fn f(this: Self) -> ());
+    assert_eq!(
+        describe(&semver::compare_signatures(&method, &function)),
+        [
+            "breaking: ABI changed from \"Rust\" to \"C\"",
+            "breaking: function no longer takes `self`",
+            "breaking: number of arguments changed from 0 to 1",
+        ],
+    );
+}
+
+#[test]
+fn test_compare_generics() {
+    let mut old: Generics = parse_quote!(<'a, T: Clone + ?Sized, const N: usize = 4>);
+    old.where_clause = Some(parse_quote!(where T: Send, T: 'a));
+    let mut new: Generics = parse_quote!(<'a, T: Clone + Debug = u8, U = (), const N: u32>);
+    new.where_clause = Some(parse_quote!(where T: Send, T: Sync));
+
+    assert_eq!(
+        describe(&semver::compare_generics(&old, &new)),
+        [
+            "breaking: type parameter `T` has new bound `Debug`",
+            "breaking: type parameter `T` no longer has bound `? Sized`",
+            "compatible: type parameter `T` now has a default",
+            "compatible: type parameter `U` added with a default",
+            "breaking: type of const parameter `N` changed from `usize` to `u32`",
+            "breaking: const parameter `N` no longer has a default",
+            "breaking: where clause has new predicate `T : Sync`",
+            "compatible: where clause no longer has predicate `T : 'a`",
+        ],
+    );
+
+    let added: Generics = parse_quote!(<'a, 'b, T, V>);
+    let old: Generics = parse_quote!(<'a, T>);
+    assert_eq!(
+        describe(&semver::compare_generics(&old, &added)),
+        [
+            "breaking: number of lifetime parameters changed from 1 to 2",
+            "breaking: type parameter `V` added",
+        ],
+    );
+}
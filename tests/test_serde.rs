@@ -0,0 +1,129 @@
+use quote::quote;
+use syn::{Expr, File, Type};
+
+fn round_trip<T>(node: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(node).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_round_trip_file() {
+    let tokens = quote! {
+        //! Crate docs.
+        use std::collections::{HashMap as Map, HashSet};
+
+        #[derive(Debug)]
+        pub struct Point<'a, T: Clone + 'a> {
+            x: &'a mut T,
+            pub(crate) y: [u8; 4],
+        }
+
+        impl<T> Trait for Point<'_, T> where T: Copy {
+            const N: usize = 1 + 2;
+            fn f(&self, r#type: u32) -> Option<(u8, String)> {
+                let s = "str\n";
+                match self.x {
+                    Some(ref v) if !v.is_empty() => v[0] as u8,
+                    _ => b'x' << 2.5f32,
+                }
+                loop { break 'outer; }
+                println!("{}", 1_000i64);
+            }
+        }
+    };
+    let file: File = syn::parse2(tokens).unwrap();
+    let reloaded = round_trip(&file);
+    assert_eq!(reloaded, file);
+    assert_eq!(quote!(#reloaded).to_string(), quote!(#file).to_string());
+}
+
+#[test]
+fn test_json_scheme() {
+    let ty: Type = syn::parse_str("(&'a mut T,)").unwrap();
+    let json = serde_json::to_value(&ty).unwrap();
+    let expected = serde_json::json!({
+        "Tuple": {
+            "elems": [{
+                "Reference": {
+                    "lifetime": "'a",
+                    "mutability": true,
+                    "elem": {
+                        "Path": {
+                            "qself": null,
+                            "path": {
+                                "leading_colon": false,
+                                "segments": [{ "ident": "T", "arguments": "None" }],
+                                "segments_trailing": false,
+                            },
+                        },
+                    },
+                },
+            }],
+            "elems_trailing": true,
+        },
+    });
+    assert_eq!(json, expected);
+    assert_eq!(serde_json::from_value::<Type>(json).unwrap(), ty);
+
+    let expr: Expr = syn::parse_str("a + -1").unwrap();
+    let json = serde_json::to_value(&expr).unwrap();
+    assert_eq!(json["Binary"]["op"], "Add");
+    assert_eq!(json["Binary"]["right"]["Unary"]["op"], "Neg");
+    assert_eq!(
+        json["Binary"]["right"]["Unary"]["expr"]["Lit"]["lit"]["Int"],
+        "1"
+    );
+}
+
+#[test]
+fn test_invalid_input() {
+    let err = serde_json::from_str::<Type>(r#"{"Path": {"qself": null, "path": {"leading_colon": false, "segments": [{"ident": "a b", "arguments": "None"}]}}}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid value: string \"a b\", expected an identifier"),
+        "{}",
+        err
+    );
+
+    let err =
+        serde_json::from_str::<Expr>(r#"{"Lit": {"attrs": [], "lit": {"Str": "1"}}}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid value: string \"1\", expected a string literal"),
+        "{}",
+        err
+    );
+
+    let err = serde_json::from_str::<Expr>(r#"{"Nope": {}}"#).unwrap_err();
+    assert!(
+        err.to_string().starts_with("unknown variant `Nope`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_serialize_error() {
+    let mut error = syn::parse_str::<Expr>("a +").unwrap_err();
+    error.combine(syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "while parsing the condition",
+    ));
+
+    // Spans do not know their location outside of a procedural macro unless
+    // proc-macro2 has its "span-locations" feature.
+    let expected = serde_json::json!({
+        "message": "unexpected end of input, expected expression",
+        "span": null,
+        "notes": [
+            {
+                "message": "while parsing the condition",
+                "span": null,
+            },
+        ],
+    });
+    assert_eq!(serde_json::to_value(&error).unwrap(), expected);
+}
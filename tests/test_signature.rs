@@ -0,0 +1,169 @@
+use quote::quote;
+use syn::{parse_quote, Expr, FnArg, ItemFn, Pat, Signature, Stmt, Type};
+
+#[test]
+fn test_inputs_without_receiver() {
+    let sig: Signature = parse_quote!(fn f(&self, a: u8, b: u16));
+    let inputs: Vec<&FnArg> = sig.inputs_without_receiver().collect();
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(quote!(#(#inputs),*).to_string(), "a : u8 , b : u16");
+
+    let sig: Signature = parse_quote!(fn f(a: u8));
+    assert_eq!(sig.inputs_without_receiver().count(), 1);
+}
+
+#[test]
+fn test_qualifiers() {
+    let sig: Signature = parse_quote!(const async unsafe fn f());
+    assert!(sig.is_const());
+    assert!(sig.is_async());
+    assert!(sig.is_unsafe());
+
+    let sig = sig.without_asyncness();
+    assert!(!sig.is_async());
+    assert_eq!(quote!(#sig).to_string(), "const unsafe fn f ()");
+}
+
+#[test]
+fn test_output_type_or_unit() {
+    let sig: Signature = parse_quote!(fn f());
+    let expected: Type = parse_quote!(());
+    assert_eq!(sig.output_type_or_unit(), expected);
+
+    let sig: Signature = parse_quote!(fn f() -> Result<(), Error>);
+    let expected: Type = parse_quote!(Result<(), Error>);
+    assert_eq!(sig.output_type_or_unit(), expected);
+}
+
+#[test]
+fn test_wrap_body() {
+    let item: ItemFn = parse_quote! {
+        async fn f(x: u8) -> Vec<impl Trait> {
+            g(x).await
+        }
+    };
+    let ret = match item.sig.wrap_body(*item.block) {
+        Expr::Await(expr) => match *expr.base {
+            Expr::Async(expr) => expr.block,
+            _ => panic!("expected an async block"),
+        },
+        _ => panic!("expected an await"),
+    };
+    let binding = match &ret.stmts[0] {
+        Stmt::Local(local) => match &local.pat {
+            Pat::Type(pat) => pat.pat.clone(),
+            _ => panic!("expected a typed pattern"),
+        },
+        _ => panic!("expected a let statement"),
+    };
+    let expected = quote!({ let #binding: Vec<_> = { g(x).await }; #binding });
+    assert_eq!(quote!(#ret).to_string(), expected.to_string());
+
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            return;
+        }
+    };
+    let body = item.sig.wrap_body(*item.block);
+    assert_eq!(quote!(#body).to_string(), "(| | { return ; }) ()");
+
+    let item: ItemFn = parse_quote! {
+        const fn f() -> u8 {
+            1
+        }
+    };
+    let body = item.sig.wrap_body(*item.block);
+    assert_eq!(quote!(#body).to_string(), quote!({ 1 }).to_string());
+}
+
+#[test]
+fn test_prepend_append_stmts() {
+    let mut item: ItemFn = parse_quote! {
+        fn f() -> Result<u8, E> {
+            let x = g()?;
+            Ok(x)
+        }
+    };
+    item.block.prepend_stmts(vec![parse_quote!(before();)]);
+    assert_eq!(item.block.stmts.len(), 3);
+
+    item.block
+        .append_stmts(&item.sig, vec![parse_quote!(after();)]);
+    let stmts = &item.block.stmts;
+    assert_eq!(stmts.len(), 3);
+    let result = match &stmts[0] {
+        Stmt::Local(local) => match &local.pat {
+            Pat::Ident(pat) => pat.ident.clone(),
+            _ => panic!("expected an identifier pattern"),
+        },
+        _ => panic!("expected a let statement"),
+    };
+    let expected = quote! {{
+        let #result = (| | -> Result<u8, E> {
+            before();
+            let x = g()?;
+            Ok(x)
+        })();
+        after();
+        #result
+    }};
+    let block = &item.block;
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_qualifier_order() {
+    let qualifiers: syn::FnQualifiers = syn::parse_str("const unsafe extern \"C\"").unwrap();
+    assert!(!qualifiers.is_empty());
+    assert_eq!(quote!(#qualifiers).to_string(), "const unsafe extern \"C\"");
+
+    let qualifiers: syn::FnQualifiers = syn::parse_str("").unwrap();
+    assert!(qualifiers.is_empty());
+
+    let errors = [
+        (
+            "unsafe const fn f() {}",
+            "qualifiers in wrong order, expected `const unsafe`",
+        ),
+        (
+            "extern \"C\" unsafe async fn f() {}",
+            "qualifiers in wrong order, expected `async unsafe extern \"C\"`",
+        ),
+        ("unsafe unsafe fn f() {}", "duplicate `unsafe` qualifier"),
+    ];
+    for (text, message) in &errors {
+        let err = syn::parse_str::<ItemFn>(text).unwrap_err();
+        assert_eq!(err.to_string(), *message, "{}", text);
+        let err = syn::parse_str::<syn::Item>(text).unwrap_err();
+        assert_eq!(err.to_string(), *message, "{}", text);
+    }
+}
+
+#[test]
+fn test_to_bare_fn() {
+    let sig: Signature = parse_quote! {
+        extern "C" fn f<'a, 'b: 'a>(mut self, x: &'a u8, ...) -> &'b u8
+    };
+    let ty = sig.to_bare_fn();
+    assert_eq!(
+        quote!(#ty).to_string(),
+        quote!(for<'a, 'b> extern "C" fn(Self, &'a u8, ...) -> &'b u8).to_string(),
+    );
+
+    let sig: Signature = parse_quote!(async fn f(self: Box<Self>, mut y: String));
+    let ty = sig.to_bare_fn();
+    assert_eq!(
+        quote!(#ty).to_string(),
+        quote!(fn(Box<Self>, String)).to_string()
+    );
+}
+
+#[test]
+fn test_to_fn_bound() {
+    let sig: Signature = parse_quote!(unsafe fn f<'a>(&'a mut self, s: &'a str));
+    let bound = sig.to_fn_bound(parse_quote!(FnOnce));
+    assert_eq!(
+        quote!(#bound).to_string(),
+        quote!(for<'a> FnOnce(&'a mut Self, &'a str)).to_string(),
+    );
+}
@@ -0,0 +1,63 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::iter::FromIterator;
+use syn::span::{self, ToTokensSpanned};
+use syn::{Expr, Type};
+
+#[test]
+fn test_find_call_site() {
+    // Tokens made by `quote!` outside of a macro all have the call site span.
+    let tokens = quote!(f(x, [y]));
+    let findings: Vec<String> = span::find_call_site(&tokens)
+        .into_iter()
+        .map(|finding| finding.token)
+        .collect();
+    assert_eq!(findings, ["f", "(", "x", ",", "[", "y", "]", ")"]);
+
+    // Invisible delimiters have nothing to report.
+    let group = Group::new(Delimiter::None, quote!(z));
+    let tokens = TokenStream::from_iter(vec![TokenTree::Group(group)]);
+    assert_eq!(span::find_call_site(&tokens).len(), 1);
+
+    assert!(span::find_spans(&quote!(f(x)), &[]).is_empty());
+}
+
+#[test]
+fn test_to_tokens_spanned() {
+    let ty: Type = syn::parse_str("Vec<(u8, [char; 2])>").unwrap();
+    let mut tokens = quote!(let x:);
+    ty.to_tokens_spanned(Span::call_site(), &mut tokens);
+    assert_eq!(
+        tokens.to_string(),
+        quote!(let x: Vec<(u8, [char; 2])>).to_string(),
+    );
+}
+
+#[test]
+fn test_diff_spans() {
+    let before: Type = syn::parse_str("Vec<(u8, [char; 2])>").unwrap();
+    let mut respanned = TokenStream::new();
+    before.to_tokens_spanned(Span::call_site(), &mut respanned);
+    let after: Type = syn::parse2(respanned).unwrap();
+
+    // Outside of a macro every span looks the same.
+    let diffs = span::diff_spans(&before, &after).unwrap();
+    assert!(diffs.is_empty());
+
+    let grouped = Group::new(Delimiter::None, quote!(u8));
+    let grouped = TokenStream::from_iter(vec![TokenTree::Group(grouped)]);
+    assert!(span::diff_spans(&quote!(u8), &grouped).unwrap().is_empty());
+
+    let other: Type = syn::parse_str("Vec<(u8, [char; 3])>").unwrap();
+    assert!(span::diff_spans(&before, &other).is_none());
+    let longer: Type = syn::parse_str("Vec<(u8, [char; 2], u8)>").unwrap();
+    assert!(span::diff_spans(&before, &longer).is_none());
+}
+
+#[test]
+fn test_set_spans() {
+    let mut expr: Expr = syn::parse_str("vec![(1, '2'), (3, '4')].len() as u8").unwrap();
+    let printed = expr.to_token_stream().to_string();
+    expr.set_spans(Span::call_site());
+    assert_eq!(expr.to_token_stream().to_string(), printed);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "verus")]
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Expr, ImplItemMethod, ItemFn, Stmt, TraitItemMethod};
+
+fn assert_round_trip<T: syn::parse::Parse + ToTokens>(text: &str) -> T {
+    let node: T = syn::parse_str(text).unwrap();
+    let expected: TokenStream = text.parse().unwrap();
+    assert_eq!(
+        node.to_token_stream().to_string(),
+        expected.to_string(),
+        "{}",
+        text,
+    );
+    node
+}
+
+#[test]
+fn test_fn_clauses() {
+    let item: ItemFn = assert_round_trip(
+        "fn f(x: u32) -> u32 where u32: Copy requires x < 10, x > 0, ensures x + 1 > x decreases x { x + 1 }",
+    );
+    let spec = *item.sig.spec.unwrap();
+    let requires = spec.requires.unwrap();
+    assert_eq!(requires.exprs.len(), 2);
+    assert!(requires.exprs.trailing_punct());
+    assert_eq!(spec.ensures.unwrap().exprs.len(), 1);
+    assert_eq!(spec.decreases.unwrap().exprs.len(), 1);
+    assert!(spec.invariant.is_none());
+    assert_eq!(item.block.stmts.len(), 1);
+
+    // An expression ending in a path is not taken as a struct literal.
+    let item: ItemFn = assert_round_trip("fn g(y: S) ensures y == S { y }");
+    assert!(item.sig.spec.unwrap().requires.is_none());
+    assert_eq!(item.block.stmts.len(), 1);
+
+    let method: TraitItemMethod = assert_round_trip("fn h(&self) -> bool requires self.ok();");
+    assert!(method.default.is_none());
+    assert!(method.sig.spec.unwrap().requires.is_some());
+
+    let method: ImplItemMethod = assert_round_trip("fn h(&self) -> bool ensures true { true }");
+    assert!(method.sig.spec.unwrap().ensures.is_some());
+
+    let item: ItemFn = assert_round_trip("fn f() {}");
+    assert!(item.sig.spec.is_none());
+}
+
+#[test]
+fn test_loop_clauses() {
+    let expr: Expr =
+        assert_round_trip("while i < n invariant i <= n, sum == i * x decreases n - i { i += 1; }");
+    match expr {
+        Expr::While(expr) => {
+            let spec = expr.spec.unwrap();
+            assert_eq!(spec.invariant.as_ref().unwrap().exprs.len(), 2);
+            assert!(spec.decreases.is_some());
+        }
+        _ => panic!("expected while loop"),
+    }
+
+    let expr: Expr = assert_round_trip("for x in v.iter() invariant ok { f(x); }");
+    match expr {
+        Expr::ForLoop(expr) => {
+            let spec = expr.spec.unwrap();
+            assert!(spec.invariant.is_some() && spec.decreases.is_none());
+        }
+        _ => panic!("expected for loop"),
+    }
+
+    let stmt: Stmt = assert_round_trip("'outer: loop decreases n { n -= 1; }");
+    match stmt {
+        Stmt::Expr(Expr::Loop(expr)) => assert!(expr.spec.unwrap().decreases.is_some()),
+        _ => panic!("expected loop"),
+    }
+
+    // Without clauses nothing changes.
+    let expr: Expr = assert_round_trip("while invariant { }");
+    match expr {
+        Expr::While(expr) => assert!(expr.spec.is_none()),
+        _ => panic!("expected while loop"),
+    }
+}
+
+#[test]
+fn test_print_struct_literal() {
+    let mut item: ItemFn = syn::parse_str("fn f() requires true {}").unwrap();
+    let requires = item.sig.spec.as_mut().unwrap().requires.as_mut().unwrap();
+    requires.exprs[0] = syn::parse_str("S { x: 1 }").unwrap();
+    let tokens = item.to_token_stream();
+    let expected = quote!(fn f() requires (S { x: 1 }) {});
+    assert_eq!(tokens.to_string(), expected.to_string());
+    syn::parse2::<ItemFn>(tokens).unwrap();
+}
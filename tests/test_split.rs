@@ -0,0 +1,84 @@
+use quote::quote;
+use syn::{parse_quote, Block, Expr};
+
+#[test]
+fn test_split_chain() {
+    let mut block: Block = parse_quote!({
+        let table = Builder::new().row(0).row(1).row(2).row(3).build();
+    });
+    let count = syn::split::split_block(&mut block, 3);
+    assert_eq!(count, 2);
+
+    let expected = quote!({
+        #[allow(unused_mut)]
+        let mut __split = Builder::new().row(0).row(1);
+        #[allow(unused_mut)]
+        let mut __split_1 = __split.row(2).row(3);
+        let table = __split_1.build();
+    });
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_split_order() {
+    // The first argument runs before the deep second argument, so it moves
+    // out ahead of it, but the literal and the path stay where they are.
+    let expr: Expr = parse_quote!(f(1, x, a(), b(c(d(e)))));
+    let split = syn::split::split_expr(expr, 3);
+
+    let expected = quote!({
+        let __split_1 = a();
+        let __split = c(d(e));
+        f(1, x, __split_1, b(__split))
+    });
+    assert_eq!(quote!(#split).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_split_fresh_names() {
+    let mut block: Block = parse_quote!({
+        let __split = m!(__split_1);
+        f(g(h(__split)))
+    });
+    syn::split::split_block(&mut block, 3);
+
+    let expected = quote!({
+        let __split = m!(__split_1);
+        let __split_2 = g(h(__split));
+        f(__split_2)
+    });
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_split_unmovable() {
+    // Borrowed operands and conditionally evaluated operands stay put, as
+    // does anything evaluated after a place that cannot move.
+    let exprs: Vec<Expr> = vec![
+        parse_quote!(&f(g(x))),
+        parse_quote!(ready() && f(g(h(x)))),
+        parse_quote!(v[i()].push(f(g(h(x))))),
+    ];
+    for expr in exprs {
+        let unchanged = expr.clone();
+        assert_eq!(syn::split::split_expr(expr, 3), unchanged);
+    }
+}
+
+#[test]
+fn test_split_nested_blocks() {
+    let mut block: Block = parse_quote!({
+        if ready {
+            f(g(h(x)));
+        }
+    });
+    syn::split::split_block(&mut block, 3);
+
+    let expected = quote!({
+        if ready {
+            let __split = g(h(x));
+            f(__split);
+        }
+    });
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+}
@@ -0,0 +1,91 @@
+use proc_macro2::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::quote;
+use std::iter::FromIterator;
+use syn::{token_stream, Token};
+
+fn stream(tokens: Vec<TokenTree>) -> TokenStream {
+    TokenStream::from_iter(tokens)
+}
+
+#[test]
+fn test_eq() {
+    let parsed: TokenStream = "f(a,b)[0]{x}".parse().unwrap();
+    assert!(token_stream::eq(&parsed, &quote!(f(a, b)[0] { x })));
+    assert!(!token_stream::eq(&parsed, &quote!(f(a, b)(0) { x })));
+    assert!(!token_stream::eq(&parsed, &quote!(f(a, b)[0] { x } y)));
+    assert!(!token_stream::eq(&quote!(1u8), &quote!(1_u8)));
+
+    // Invisible groups are transparent.
+    let group = Group::new(Delimiter::None, quote!(x + 1));
+    let grouped = stream(vec![TokenTree::Group(group)]);
+    assert!(token_stream::eq(&quote!(#grouped * 2), &quote!(x + 1 * 2)));
+
+    // Spacing only matters in front of another punctuation character.
+    let joint = stream(vec![
+        TokenTree::Punct(Punct::new('!', Spacing::Joint)),
+        TokenTree::Ident(quote::format_ident!("x")),
+    ]);
+    assert!(token_stream::eq(&joint, &quote!(!x)));
+    assert!(!token_stream::eq(&quote!(< <), &quote!(<<)));
+}
+
+#[test]
+fn test_split_top_level() {
+    fn split<T: syn::parse::Peek>(tokens: TokenStream, separator: T) -> Vec<String> {
+        token_stream::split_top_level(tokens, separator)
+            .into_iter()
+            .map(|(tokens, _span)| tokens.to_string())
+            .collect()
+    }
+
+    assert_eq!(
+        split(quote!(a, f(b, c), { d, e }, [f, g]), Token![,]),
+        ["a", "f (b , c)", "{ d , e }", "[f , g]"],
+    );
+    assert_eq!(split(quote!(a,, b,), Token![,]), ["a", "", "b"]);
+    assert!(split(quote!(), Token![,]).is_empty());
+    assert_eq!(split(quote!(,), Token![,]), [""]);
+
+    // Angle brackets do not group.
+    assert_eq!(
+        split(quote!(HashMap<K, V>), Token![,]),
+        ["HashMap < K", "V >"],
+    );
+
+    // Only whole operators separate.
+    assert_eq!(
+        split(quote!(a == b => c = d => e), Token![=>]),
+        ["a == b", "c = d", "e"],
+    );
+    assert_eq!(split(quote!(x = a == b), Token![=]), ["x", "a == b"]);
+    assert_eq!(split(quote!(a <<= b), Token![<]), ["a <<= b"]);
+
+    // Source text marks punctuation followed by more punctuation as joint.
+    let parsed: TokenStream = "Vec<T>, -1,'a".parse().unwrap();
+    assert_eq!(split(parsed, Token![,]), ["Vec < T >", "- 1", "'a"]);
+    assert_eq!(split(quote!(a; b;; c), Token![;]), ["a", "b", "", "c"]);
+
+    // Keywords separate too.
+    assert_eq!(split(quote!(x in xs in ys), Token![in]), ["x", "xs", "ys"]);
+
+    // Nothing inside of an invisible group is split.
+    let group = Group::new(Delimiter::None, quote!(b, c));
+    let grouped = stream(vec![TokenTree::Group(group)]);
+    assert_eq!(split(quote!(a, #grouped), Token![,]).len(), 2);
+}
+
+#[test]
+fn test_split_top_level_spans() {
+    let comma = Span::call_site().resolved_at(Span::mixed_site());
+    let mut tokens = quote!(a b);
+    tokens.extend(vec![TokenTree::Punct({
+        let mut punct = Punct::new(',', Spacing::Alone);
+        punct.set_span(comma);
+        punct
+    })]);
+    tokens.extend(quote!(, c));
+
+    let split = token_stream::split_top_level(tokens, Token![,]);
+    assert_eq!(split.len(), 3);
+    assert_eq!(format!("{:?}", split[1].1), format!("{:?}", comma));
+}
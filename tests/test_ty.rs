@@ -4,7 +4,7 @@ mod macros;
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
 use std::iter::FromIterator;
-use syn::Type;
+use syn::{AbiName, Lifetime, Type, TypeArray, TypeBareFn};
 
 #[test]
 fn test_mut_self() {
@@ -350,3 +350,90 @@ fn test_trailing_plus() {
     }
     "###);
 }
+
+#[test]
+fn test_abi() {
+    let tokens = quote!(extern "C-unwind" fn());
+    snapshot!(tokens as Type, @r###"
+    Type::BareFn {
+        abi: Some(Abi {
+            name: Some("C-unwind"),
+        }),
+        output: Default,
+    }
+    "###);
+
+    let ty: TypeBareFn = syn::parse_str("extern fn()").unwrap();
+    assert!(ty.abi.unwrap().is_c());
+
+    let ty: TypeBareFn = syn::parse_str("extern \"rust-call\" fn()").unwrap();
+    let abi = ty.abi.unwrap();
+    assert!(!abi.is_c());
+    match abi.kind().unwrap() {
+        AbiName::Other(lit) => assert_eq!(lit.value(), "rust-call"),
+        other => panic!("expected AbiName::Other, got {:?}", other),
+    }
+    assert_eq!(abi.name_value().unwrap(), "rust-call");
+
+    syn::parse_str::<Type>("extern \"C\"suffix fn()").unwrap_err();
+    syn::parse_str::<Type>("extern \"\" fn()").unwrap_err();
+}
+
+#[test]
+fn test_constructors() {
+    let t: Type = syn::parse_str("T").unwrap();
+    let lifetime = Lifetime::new("'a", Span::call_site());
+
+    let ty = Type::reference(Some(lifetime), true, Type::slice(t.clone()));
+    assert_eq!(ty, syn::parse_str("&'a mut [T]").unwrap());
+    assert_eq!(quote!(#ty).to_string(), quote!(&'a mut [T]).to_string());
+
+    let ty = Type::reference(None, false, t.clone());
+    assert_eq!(ty, syn::parse_str("&T").unwrap());
+
+    let ty = Type::tuple(vec![t.clone()]);
+    assert_eq!(ty, syn::parse_str("(T,)").unwrap());
+    assert_eq!(quote!(#ty).to_string(), quote!((T,)).to_string());
+
+    let ty = Type::tuple(vec![t.clone(), Type::unit()]);
+    assert_eq!(ty, syn::parse_str("(T, ())").unwrap());
+
+    assert_eq!(Type::unit(), syn::parse_str("()").unwrap());
+}
+
+#[test]
+fn test_array_len() {
+    let array = |len: &str| syn::parse_str::<TypeArray>(&format!("[u8; {}]", len)).unwrap();
+
+    assert_eq!(array("16").len_value(), Some(16));
+    assert_eq!(array("2 * (8 + 1) - 4usize").len_value(), Some(14));
+    assert_eq!(array("1 << 4 | 1").len_value(), Some(17));
+    assert_eq!(array("7 / 2 % 2").len_value(), Some(1));
+    assert_eq!(array("1 - 2").len_value(), None);
+    assert_eq!(array("1 / 0").len_value(), None);
+    assert_eq!(array("N").len_value(), None);
+    assert_eq!(array("size_of::<u64>()").len_value(), None);
+
+    let len = array("2 * N + mem::M").len_value_with(|path| {
+        if path.is_ident("N") {
+            Some(3)
+        } else if path.segments.len() == 2 {
+            Some(10)
+        } else {
+            None
+        }
+    });
+    assert_eq!(len, Some(16));
+
+    let n = array("(N)");
+    assert!(n.len_const().unwrap().is_ident("N"));
+    assert!(array("N + 1").len_const().is_none());
+
+    assert_eq!(array("N + 1").same_len(&array("1 + N")), Some(true));
+    assert_eq!(array("2 * N").same_len(&array("N + N")), Some(true));
+    assert_eq!(array("N - N + 4").same_len(&array("2 * 2")), Some(true));
+    assert_eq!(array("N").same_len(&array("N + 1")), Some(false));
+    assert_eq!(array("4").same_len(&array("5")), Some(false));
+    assert_eq!(array("N").same_len(&array("M")), None);
+    assert_eq!(array("N * M").same_len(&array("N * M")), None);
+}
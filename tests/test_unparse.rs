@@ -0,0 +1,332 @@
+use syn::unparse::{pretty, BraceStyle, PrintOptions, TrailingComma};
+use syn::{parse_quote, Expr, File, Item, Type};
+
+fn assert_unparse(input: &str, expected: &str) {
+    let file: File = syn::parse_str(input).unwrap();
+    let output = syn::unparse(&file);
+    assert_eq!(output, expected);
+
+    // The output parses, and formatting it again changes nothing.
+    let reparsed: File = syn::parse_str(&output).unwrap();
+    assert_eq!(syn::unparse(&reparsed), output);
+}
+
+#[test]
+fn test_items() {
+    assert_unparse(
+        "#![allow(dead_code)] use std::fmt; use std::io::{self, Read as _};
+         extern crate alloc; mod a; mod b;
+         /// A pair.
+         #[derive(Clone)] pub(crate) struct Pair<T> where T: Copy { pub a: T, b: (T,) }
+         struct Unit; enum E { A, B(u8, #[attr] u16), C { x: i32 } = 3 }
+         impl<T: fmt::Debug> fmt::Display for Pair<T> where T: Copy {
+             type X = u8; const N: usize = 1;
+             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Ok(()) }
+             fn other() {}
+         }
+         extern \"C\" { fn puts(s: *const u8) -> i32; static mut ERRNO: i32; }
+         trait Tr: Sized { type Out: Clone where Self: Copy; fn run(self) -> Self::Out; }
+         type Alias<'a> = &'a mut [u8; 4];",
+        "\
+#![allow(dead_code)]
+
+use std::fmt;
+use std::io::{self, Read as _};
+
+extern crate alloc;
+
+mod a;
+mod b;
+
+/// A pair.
+#[derive(Clone)]
+pub(crate) struct Pair<T>
+where
+    T: Copy,
+{
+    pub a: T,
+    b: (T,),
+}
+
+struct Unit;
+
+enum E {
+    A,
+    B(u8, #[attr] u16),
+    C {
+        x: i32,
+    } = 3,
+}
+
+impl<T: fmt::Debug> fmt::Display for Pair<T>
+where
+    T: Copy,
+{
+    type X = u8;
+    const N: usize = 1;
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+
+    fn other() {}
+}
+
+extern \"C\" {
+    fn puts(s: *const u8) -> i32;
+    static mut ERRNO: i32;
+}
+
+trait Tr: Sized {
+    type Out: Clone where Self: Copy;
+
+    fn run(self) -> Self::Out;
+}
+
+type Alias<'a> = &'a mut [u8; 4];
+",
+    );
+}
+
+#[test]
+fn test_impl_method_without_body() {
+    assert_unparse(
+        "impl T { fn f(); pub fn g(&self) -> u8; }",
+        "\
+impl T {
+    fn f();
+
+    pub fn g(&self) -> u8;
+}
+",
+    );
+}
+
+#[test]
+fn test_exprs() {
+    assert_unparse(
+        "fn main() {
+             let v = Vec::<u8>::with_capacity(2);
+             'outer: for (i,) in v.iter().enumerate() { if i > 1 { break 'outer; } else if i == 0 { continue; } else { f(i)? } }
+             while let Some(x) = (S { a: 1 }).next() { x.await; }
+             let r = match x { 0 | 1 => \"small\", n if n < 10 => { \"medium\" } _ => \"large\" };
+             let c = move |a: u8, b| -> u8 { a + b };
+             unsafe { *p = -1 }
+             <T as Tr>::f(&mut [0; 3][..]);
+         }",
+        "\
+fn main() {
+    let v = Vec::<u8>::with_capacity(2);
+    'outer: for (i,) in v.iter().enumerate() {
+        if i > 1 {
+            break 'outer;
+        } else if i == 0 {
+            continue;
+        } else {
+            f(i)?
+        }
+    }
+    while let Some(x) = (S { a: 1 }).next() {
+        x.await;
+    }
+    let r = match x {
+        0 | 1 => \"small\",
+        n if n < 10 => {
+            \"medium\"
+        }
+        _ => \"large\",
+    };
+    let c = move |a: u8, b| -> u8 {
+        a + b
+    };
+    unsafe {
+        *p = -1
+    }
+    <T as Tr>::f(&mut [0; 3][..]);
+}
+",
+    );
+}
+
+#[test]
+fn test_macros() {
+    assert_unparse(
+        "macro_rules! m { ($x:expr) => { $x + 1 }; () => {}; }
+         fn f() { println!(\"{}\", a.b); let _ = vec![1, 2]; m!(&x); }
+         thread_local! { static X: Cell<u8> = Cell::new(0); static Y: u8 = 1; }",
+        "\
+macro_rules! m {
+    ($x:expr) => { $x + 1 };
+    () => {};
+}
+
+fn f() {
+    println!(\"{}\", a.b);
+    let _ = vec![1, 2];
+    m!(&x);
+}
+
+thread_local! {
+    static X: Cell<u8> = Cell::new(0);
+    static Y: u8 = 1;
+}
+",
+    );
+}
+
+#[test]
+fn test_doc_comments() {
+    assert_unparse(
+        "//! Crate docs.
+         #[doc = \"two\\nlines\"] #[doc = \"/ slash\"] /// Plain.
+         fn f() {}",
+        "\
+//! Crate docs.
+
+#[doc = \"two\\nlines\"]
+#[doc = \"/ slash\"]
+/// Plain.
+fn f() {}
+",
+    );
+}
+
+#[test]
+#[cfg(feature = "verus")]
+fn test_spec_clauses() {
+    assert_unparse(
+        "fn f(x: u32) -> u32 where u32: Copy requires x < 10, x > 0 ensures x + 1 > x decreases x { x + 1 }
+         trait T { fn g(y: S) ensures y == (S {}); } fn h() { while i < n invariant i <= n decreases n - i { i += 1; } }",
+        "\
+fn f(x: u32) -> u32
+where
+    u32: Copy
+    requires x < 10, x > 0
+    ensures x + 1 > x
+    decreases x
+{
+    x + 1
+}
+
+trait T {
+    fn g(y: S)
+        ensures y == (S {});
+}
+
+fn h() {
+    while i < n
+        invariant i <= n
+        decreases n - i
+    {
+        i += 1;
+    }
+}
+",
+    );
+}
+
+#[test]
+fn test_pretty() {
+    let item: Item = parse_quote!(
+        struct S(u8);
+    );
+    assert_eq!(pretty(&item).to_string(), "struct S(u8);");
+
+    let ty: Type = parse_quote!(for<'a> fn(&'a str) -> Box<dyn Fn() + Send>);
+    assert_eq!(
+        pretty(&ty).to_string(),
+        "for<'a> fn(&'a str) -> Box<dyn Fn() + Send>",
+    );
+
+    let expr: Expr = parse_quote!((1,));
+    assert_eq!(pretty(&expr).to_string(), "(1,)");
+}
+
+#[test]
+fn test_max_width() {
+    let file: File = syn::parse_str(
+        "fn f() { let config = Config { name: String::from(\"server\"), port: 8080 }; \
+         start(config, vec![handler(a, b), handler(c, d)], timeout_ms(100)); }",
+    )
+    .unwrap();
+    let options = PrintOptions::new().max_width(60);
+    let output = options.unparse(&file);
+    let expected = "\
+fn f() {
+    let config = Config {
+        name: String::from(\"server\"),
+        port: 8080,
+    };
+    start(
+        config,
+        vec![handler(a, b), handler(c, d)],
+        timeout_ms(100),
+    );
+}
+";
+    assert_eq!(output, expected);
+
+    let reparsed: File = syn::parse_str(&output).unwrap();
+    assert_eq!(options.unparse(&reparsed), output);
+
+    // Without a maximum width the same code stays on one line.
+    let unwrapped = syn::unparse(&file);
+    assert_eq!(unwrapped.lines().count(), 4);
+}
+
+#[test]
+fn test_print_options() {
+    let file: File = syn::parse_str(
+        "use std::io::{Read, Write}; enum E { A, B } \
+         fn f<T>(t: T) -> (T, u8) where T: Clone { (t, 1) }",
+    )
+    .unwrap();
+
+    let options = PrintOptions::new()
+        .indent_width(2)
+        .trailing_comma(TrailingComma::Always)
+        .brace_style(BraceStyle::PreferSameLine);
+    let expected = "\
+use std::io::{Read, Write,};
+
+enum E {
+  A,
+  B,
+}
+
+fn f<T>(t: T,) -> (T, u8)
+where
+  T: Clone, {
+  (t, 1,)
+}
+";
+    assert_eq!(options.unparse(&file), expected);
+
+    let options = PrintOptions::new()
+        .trailing_comma(TrailingComma::Never)
+        .brace_style(BraceStyle::AlwaysNextLine);
+    let expected = "\
+use std::io::{Read, Write};
+
+enum E
+{
+    A,
+    B
+}
+
+fn f<T>(t: T) -> (T, u8)
+where
+    T: Clone
+{
+    (t, 1)
+}
+";
+    assert_eq!(options.unparse(&file), expected);
+
+    let expr: Expr = parse_quote!(f(a, b));
+    let options = PrintOptions::new().max_width(4);
+    assert_eq!(
+        options.pretty(&expr).to_string(),
+        "f(\n    a,\n    b,\n)",
+    );
+}
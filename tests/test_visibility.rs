@@ -146,3 +146,49 @@ fn test_empty_group_vis() {
     }
     "###);
 }
+
+#[test]
+fn test_nested_in_path() {
+    assert_vis_parse!("pub(in crate::a::b::c)", Ok(Visibility::Restricted(_)));
+    assert_vis_parse!("pub(in super::super)", Ok(Visibility::Restricted(_)));
+    assert_vis_parse!("pub(in ::a::b)", Ok(Visibility::Restricted(_)));
+}
+
+#[test]
+fn test_visibility_helpers() {
+    let vis = |s: &str| syn::parse_str::<Visibility>(s).unwrap();
+
+    assert!(vis("pub").is_public());
+    assert!(!vis("pub(crate)").is_public());
+    assert!(vis("").is_inherited());
+    assert!(!vis("pub(self)").is_inherited());
+
+    let ordered = [
+        "pub",
+        "pub(crate)",
+        "pub(in crate::a)",
+        "pub(in crate::a::b)",
+    ];
+    for (i, more) in ordered.iter().enumerate() {
+        for (j, less) in ordered.iter().enumerate() {
+            assert_eq!(
+                vis(more).is_at_least_as_visible_as(&vis(less)),
+                i <= j,
+                "{} vs {}",
+                more,
+                less,
+            );
+        }
+    }
+
+    assert!(vis("crate").is_at_least_as_visible_as(&vis("pub(in crate)")));
+    assert!(vis("pub(in crate)").is_at_least_as_visible_as(&vis("crate")));
+    assert!(vis("pub(self)").is_at_least_as_visible_as(&vis("")));
+    assert!(vis("").is_at_least_as_visible_as(&vis("pub(self)")));
+    assert!(vis("pub(in super::super)").is_at_least_as_visible_as(&vis("pub(super)")));
+    assert!(!vis("pub(super)").is_at_least_as_visible_as(&vis("pub(in super::super)")));
+    assert!(vis("pub(crate)").is_at_least_as_visible_as(&vis("pub(super)")));
+    assert!(!vis("pub(super)").is_at_least_as_visible_as(&vis("pub(crate)")));
+    assert!(!vis("pub(in crate::a)").is_at_least_as_visible_as(&vis("pub(super)")));
+    assert!(!vis("pub(in crate::a)").is_at_least_as_visible_as(&vis("pub(in crate::b)")));
+}
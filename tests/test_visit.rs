@@ -0,0 +1,77 @@
+use proc_macro2::Span;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, ItemFn};
+
+#[test]
+fn test_visit_token() {
+    struct Tokens(Vec<&'static str>);
+
+    impl<'ast> Visit<'ast> for Tokens {
+        fn visit_token(&mut self, token: &'static str, _spans: &[Span]) {
+            self.0.push(token);
+        }
+    }
+
+    let item: ItemFn = parse_quote! {
+        pub fn f(a: &[u8], b: u8) -> Option<u8> {
+            a.get(b as usize)?.checked_add(1)
+        }
+    };
+    let mut tokens = Tokens(Vec::new());
+    tokens.visit_item_fn(&item);
+    assert_eq!(
+        tokens.0,
+        [
+            "pub", "fn", "()", ":", "&", "[]", ",", ":", "->", "<", ">", "{}", ".", "()", "as",
+            "?", ".", "()",
+        ],
+    );
+}
+
+#[test]
+fn test_visit_token_tree_order() {
+    struct Tokens(Vec<&'static str>);
+
+    impl<'ast> Visit<'ast> for Tokens {
+        fn visit_token(&mut self, token: &'static str, _spans: &[Span]) {
+            self.0.push(token);
+        }
+    }
+
+    // The inner attribute is visited with the attributes of the function and
+    // the where clause with its generics, before the parentheses.
+    let item: ItemFn = parse_quote! {
+        fn f<T>(t: T) where T: Copy {
+            #![allow(unused)]
+        }
+    };
+    let mut tokens = Tokens(Vec::new());
+    tokens.visit_item_fn(&item);
+    assert_eq!(
+        tokens.0,
+        ["#", "!", "[]", "fn", "<", ">", "where", ":", "()", ":", "{}"],
+    );
+}
+
+#[test]
+fn test_visit_token_mut() {
+    struct Spans(usize);
+
+    impl VisitMut for Spans {
+        fn visit_token_mut(&mut self, token: &'static str, spans: &mut [Span]) {
+            if token == "::" {
+                self.0 += spans.len();
+            }
+        }
+    }
+
+    let mut item: ItemFn = parse_quote! {
+        fn f() -> ::std::vec::Vec<u8> {
+            Vec::new()
+        }
+    };
+    let mut spans = Spans(0);
+    spans.visit_item_fn_mut(&mut item);
+    assert_eq!(spans.0, 8);
+}